@@ -62,6 +62,10 @@ const trustedHosts = [
     'github.com',
 ]
 
+// The protocols of the links which are opened in the default browser when the app requests a new
+// window for them, e.g. the links clicked in the documentation.
+const externalProtocols = ['http:', 'https:', 'mailto:']
+
 // =====================
 // === Option Parser ===
 // =====================
@@ -193,6 +197,13 @@ optParser.options('node-labels', {
     type        : `boolean`
 })
 
+optParser.options('audio-feedback', {
+    group       : styleOptionsGroup,
+    describe    : 'Play subtle sounds on the key graph interactions. Defaults to `false`.',
+    default     : false,
+    type        : `boolean`
+})
+
 
 // === Other Options ===
 
@@ -363,7 +374,12 @@ Electron.app.on('web-contents-created', (event,contents) => {
 Electron.app.on('web-contents-created', (event,contents) => {
     contents.on('new-window', async (event,navigationUrl) => {
         event.preventDefault()
-        console.error(`Blocking new window creation request to '${navigationUrl}'`)
+        const parsedUrl = new URL(navigationUrl)
+        if (externalProtocols.includes(parsedUrl.protocol)) {
+            Electron.shell.openExternal(navigationUrl)
+        } else {
+            console.error(`Blocking new window creation request to '${navigationUrl}'`)
+        }
     })
 })
 
@@ -547,6 +563,7 @@ function createWindow() {
         crash_report_host : args.crashReportHost,
         data_gathering    : args.dataGathering,
         node_labels       : args.nodeLabels,
+        audio_feedback    : args.audioFeedback,
         verbose           : args.verbose,
     }
 
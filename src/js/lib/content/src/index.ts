@@ -812,6 +812,8 @@ class Config {
     public wasm_url: string
     public wasm_glue_url: string
     public node_labels: boolean
+    public audio_feedback: boolean
+    public deterministic_ids: boolean
     public crash_report_host: string
     public data_gathering: boolean
     public is_in_cloud: boolean
@@ -866,6 +868,12 @@ class Config {
         this.node_labels = ok(other.node_labels)
             ? tryAsBoolean(other.node_labels)
             : this.node_labels
+        this.audio_feedback = ok(other.audio_feedback)
+            ? tryAsBoolean(other.audio_feedback)
+            : this.audio_feedback
+        this.deterministic_ids = ok(other.deterministic_ids)
+            ? tryAsBoolean(other.deterministic_ids)
+            : this.deterministic_ids
         this.crash_report_host = ok(other.crash_report_host)
             ? tryAsString(other.crash_report_host)
             : this.crash_report_host
@@ -0,0 +1,144 @@
+//! Context Menu Component.
+//!
+//! A list of entries displayed at the given position, e.g. the position of a right click. The
+//! menu is closed when an entry is chosen, or when the user clicks anywhere else.
+use ensogl_core::prelude::*;
+
+use crate::list_view;
+
+use enso_frp as frp;
+use ensogl_core::application::Application;
+use ensogl_core::display;
+use ensogl_core::display::scene::layer::LayerId;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The width of the menu.
+pub const MENU_WIDTH : f32 = 180.0;
+
+
+
+// ===========
+// === FRP ===
+// ===========
+
+ensogl_core::define_endpoints! {
+    Input {
+        set_entries (list_view::entry::AnyModelProvider<Entry>),
+        /// Display the menu with its top left corner at the given position.
+        show_at     (Vector2),
+        hide        (),
+    }
+    Output {
+        visible      (bool),
+        /// Emitted when the menu is closed, whether an entry was chosen or not.
+        closed       (),
+        chosen_entry (list_view::entry::Id),
+    }
+}
+
+
+
+// =============
+// === Model ===
+// =============
+
+/// A type of Entry used in ContextMenu's ListView.
+pub type Entry = list_view::entry::Label;
+
+#[derive(Clone,CloneRef,Debug)]
+struct Model {
+    display_object : display::object::Instance,
+    list           : list_view::ListView<Entry>,
+}
+
+impl Model {
+    fn new(app:&Application) -> Self {
+        let logger         = Logger::new("context_menu");
+        let display_object = display::object::Instance::new(&logger);
+        let list           = list_view::ListView::new(app);
+        Self {display_object,list}
+    }
+
+    fn show_at(&self, position:Vector2, entry_count:usize) {
+        let size = Vector2(MENU_WIDTH,entry_count as f32 * list_view::entry::HEIGHT);
+        self.list.resize(size);
+        self.list.set_position_xy(position + Vector2(size.x,-size.y) / 2.0);
+        self.display_object.add_child(&self.list);
+    }
+
+    fn hide(&self) {
+        self.list.deselect_entries();
+        self.list.unset_parent();
+    }
+}
+
+
+
+// ===================
+// === ContextMenu ===
+// ===================
+
+/// UI entity displaying a list of entries, one of which can be chosen.
+#[allow(missing_docs)]
+#[derive(Clone,CloneRef,Debug)]
+pub struct ContextMenu {
+    model   : Model,
+    pub frp : Frp,
+}
+
+impl Deref for ContextMenu {
+    type Target = Frp;
+    fn deref(&self) -> &Self::Target { &self.frp }
+}
+
+impl ContextMenu {
+    /// Constructor.
+    pub fn new(app:&Application) -> Self {
+        let frp   = Frp::new();
+        let model = Model::new(app);
+        Self {model,frp}.init(app)
+    }
+
+    fn init(self, app:&Application) -> Self {
+        let network = &self.frp.network;
+        let frp     = &self.frp;
+        let model   = &self.model;
+        let mouse   = &app.display.scene().mouse.frp;
+
+        frp::extend! { network
+            model.list.set_entries <+ frp.set_entries;
+            eval frp.show_at ([model,frp](position)
+                model.show_at(*position,frp.set_entries.value().entry_count()));
+
+            // The list view chooses the pointed entry on the same mouse down, before the menu is
+            // dismissed.
+            chosen_entry <- model.list.chosen_entry.unwrap().gate(&frp.visible);
+            frp.source.chosen_entry <+ chosen_entry;
+            dismissed <- mouse.down_primary.gate(&frp.visible);
+            hide      <- any(&frp.hide,&dismissed);
+            eval_ hide (model.hide());
+
+            visible <- bool(&hide,&frp.show_at).on_change();
+            frp.source.visible <+ visible;
+            frp.source.closed  <+ visible.on_false();
+        }
+
+        self
+    }
+
+    /// Sets the scene layer where the labels will be placed.
+    pub fn set_label_layer(&self, layer:LayerId) {
+        self.model.list.set_label_layer(layer);
+    }
+}
+
+impl display::Object for ContextMenu {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.model.display_object
+    }
+}
@@ -17,6 +17,7 @@
 #![recursion_limit="512"]
 
 pub mod component;
+pub mod context_menu;
 pub mod drop_down_menu;
 pub mod file_browser;
 pub mod label;
@@ -37,6 +37,7 @@ pub struct Application {
     pub views            : view::Registry,
     pub themes           : theme::Manager,
     update_themes_handle : callback::Handle,
+    chord_timeout_handle : callback::Handle,
 }
 
 impl Application {
@@ -53,7 +54,9 @@ impl Application {
         display.add_child(&cursor);
         web::body().set_style_or_panic("cursor","none");
         let update_themes_handle = display.on_before_frame(f_!(themes.update()));
-        Self {logger,cursor,display,commands,shortcuts,views,themes,update_themes_handle}
+        let chord_timeout_handle = display.on_before_frame(f_!(shortcuts.check_chord_timeout()));
+        Self {logger,cursor,display,commands,shortcuts,views,themes,update_themes_handle
+             ,chord_timeout_handle}
     }
 
     /// Create a new instance of a view.
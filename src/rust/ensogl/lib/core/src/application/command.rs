@@ -30,7 +30,8 @@ pub trait View : FrpNetworkProvider + DerefToCommandApi {
     /// Application reference.
     fn app(&self) -> &Application;
 
-    /// Set of default shortcuts.
+    /// Set of default shortcuts. The shortcut rules can define chord sequences, like
+    /// `(Press, "ctrl k, ctrl c", "comment")`, see the shortcut `Registry` docs to learn more.
     fn default_shortcuts() -> Vec<Shortcut> {
         default()
     }
@@ -84,6 +85,8 @@ pub trait FrpNetworkProvider {
 pub struct Command {
     pub frp     : frp::Any,
     pub enabled : bool,
+    /// The documentation of the FRP endpoint, used by the introspection tools.
+    pub doc     : String,
 }
 
 impl Deref for Command {
@@ -95,10 +98,57 @@ impl Deref for Command {
 
 impl Command {
     /// Constructor.
-    pub fn new(frp:frp::Any<()>) -> Self {
+    pub fn new(frp:frp::Any<()>, doc:impl Into<String>) -> Self {
         let enabled = true;
-        Self {frp,enabled}
+        let doc     = doc.into();
+        Self {frp,enabled,doc}
     }
+
+    /// A weak handle of the command endpoint, for the emitters outliving the view, like web
+    /// callbacks. See the docs of `frp::WeakSource`.
+    pub fn weak(&self) -> frp::WeakSource {
+        frp::WeakSource::new(&self.frp)
+    }
+}
+
+
+
+// ===================
+// === CommandInfo ===
+// ===================
+
+/// The description of a command available in the living view instances, as listed by
+/// [`Registry::commands`].
+#[derive(Clone,Debug)]
+pub struct CommandInfo {
+    /// The label of the view providing the command, like `GraphEditor`.
+    pub view  : String,
+    /// The name of the command, being the name of the FRP endpoint it evaluates.
+    pub name  : String,
+    /// The human-readable label, like `Graph Editor: toggle fullscreen`.
+    pub label : String,
+    /// The documentation of the FRP endpoint.
+    pub doc   : String,
+}
+
+impl CommandInfo {
+    fn new(view:&str, name:&str, doc:&str) -> Self {
+        let label = format!("{}: {}",view_title(view),name.replace('_'," "));
+        Self {view:view.into(),name:name.into(),label,doc:doc.into()}
+    }
+}
+
+/// The human-readable title of the view with the given label, like `Graph Editor` for
+/// `GraphEditor`.
+pub fn view_title(view:&str) -> String {
+    let mut title = String::new();
+    for char in view.chars() {
+        if char.is_uppercase() && !title.is_empty() {
+            title.push(' ');
+        }
+        title.push(char);
+    }
+    title
 }
 
 
@@ -218,6 +268,49 @@ impl Registry {
         }
     }
 
+    /// All the commands enabled in at least one living instance of their view, sorted by their
+    /// labels. Allows discovering the commands at runtime, e.g. by the command palette. Every
+    /// command is listed once per view label, no matter how many instances of the view exist. The
+    /// instances which are no longer alive are dropped from the registry.
+    pub fn commands(&self) -> Vec<CommandInfo> {
+        let mut commands = BTreeMap::<(String,String),CommandInfo>::new();
+        for (view,instances) in self.name_map.borrow_mut().iter_mut() {
+            instances.retain(|instance| instance.check_alive());
+            for instance in instances.iter() {
+                for (name,command) in instance.command_map.borrow().iter() {
+                    if command.enabled {
+                        let key = (view.clone(),name.clone());
+                        commands.entry(key).or_insert_with(|| {
+                            CommandInfo::new(view,name,&command.doc)
+                        });
+                    }
+                }
+            }
+        }
+        let mut commands = commands.into_iter().map(|(_,command)| command).collect_vec();
+        commands.sort_by(|lhs,rhs| lhs.label.cmp(&rhs.label));
+        commands
+    }
+
+    /// Evaluate the command in all living instances of the view where it is enabled, the same way
+    /// a shortcut does.
+    pub fn execute(&self, view:&str, name:&str) {
+        let mut targets = Vec::new();
+        if let Some(instances) = self.name_map.borrow().get(view) {
+            for instance in instances.iter().filter(|instance| instance.check_alive()) {
+                if let Some(command) = instance.command_map.borrow().get(name) {
+                    if command.enabled { targets.push(command.frp.clone_ref()) }
+                }
+            }
+        }
+        if targets.is_empty() {
+            warning!(&self.logger,"The command {name} is not available in {view}.");
+        }
+        for target in targets {
+            target.emit(())
+        }
+    }
+
     /// Disables the command for the provided component instance.
     fn disable_command<T:View>(&self, instance:&T, name:impl AsRef<str>) {
         self.with_command_mut(instance,name,|command| command.enabled = false)
@@ -161,6 +161,29 @@
 ///             let command_map = Rc::new(RefCell::new(command_map));
 ///             Self {source,input,status_map,command_map,focused,output1,output2,output3}
 ///         }
+///
+///         /// The current values of all outputs.
+///         pub fn snapshot(&self) -> FrpOutputsSnapshot {
+///             let focused = self.focused.value();
+///             let output1 = self.output1.value();
+///             let output2 = self.output2.value();
+///             let output3 = self.output3.value();
+///             FrpOutputsSnapshot {focused,output1,output2,output3}
+///         }
+///
+///         /// A weak handle of the command input.
+///         pub fn weak_command(&self, name:&str) -> Option<frp::WeakSource> {
+///             self.command_map.borrow().get(name).map(|command| command.weak())
+///         }
+///     }
+///
+///     /// Values of all outputs at a given moment.
+///     #[derive(Debug,Clone)]
+///     pub struct FrpOutputsSnapshot {
+///         pub focused : bool,
+///         pub output1 : String,
+///         pub output2 : bool,
+///         pub output3 : (),
 ///     }
 ///
 ///     /// Frp output setters.
@@ -361,13 +384,43 @@ macro_rules! define_endpoints {
                 }
                 $($crate::build_status_map!
                     {status_map $out_field ($($out_field_type)*) $out_field })*
-                $($crate::build_command_map!
-                    {command_map $in_field ($($in_field_type)*) input.$in_field })*
+                $($crate::build_command_map! {
+                    command_map $in_field ($($in_field_type)*) [$($($in_doc)*),*] input.$in_field
+                })*
                 let status_map  = Rc::new(RefCell::new(status_map));
                 let command_map = Rc::new(RefCell::new(command_map));
                 let _params     = default();
                 Self {source,input,status_map,command_map,$($out_field),*,_params}
             }
+
+            /// The current values of all outputs. Useful in tests and for components which
+            /// subscribe to the outputs after their values were emitted.
+            pub fn snapshot(&self) -> FrpOutputsSnapshot $(<$($param),*>)? {
+                let _params = default();
+                FrpOutputsSnapshot {$($out_field:self.$out_field.value()),*,_params}
+            }
+
+            /// A weak handle of the command input, for the long-lived external emitters, like web
+            /// callbacks, which should not emit into the dropped network unnoticed. Returns `None`
+            /// if there is no such command. See the docs of `frp::WeakSource`.
+            pub fn weak_command(&self, name:&str) -> Option<$crate::frp::WeakSource> {
+                self.command_map.borrow().get(name).map(|command| command.weak())
+            }
+        }
+
+        /// Values of all outputs at a given moment. See `FrpEndpoints::snapshot`.
+        #[derive(Debug,Derivative)]
+        #[derivative(Clone(bound=""))]
+        #[allow(unused_parens)]
+        #[allow(missing_docs)]
+        // Clippy thinks `_param` is a field we want to add in future, but it is not: it is to
+        // suppress "not used generic param" error.
+        #[allow(clippy::manual_non_exhaustive)]
+        pub struct FrpOutputsSnapshot $(<$($param $(:$($constraints)*)?),*>)? {
+            $($(#[doc=$($out_doc)*])*
+                pub $out_field : ($($out_field_type)*),
+            )*
+            _params : ($($(PhantomData<$param>),*)?),
         }
 
         /// Frp output setters.
@@ -422,9 +475,12 @@ macro_rules! build_status_map {
 /// Internal helper of `define_endpoints` macro.
 #[macro_export]
 macro_rules! build_command_map {
-    ($map:ident $field:ident () $frp:expr) => {
-        $map.insert(stringify!($field).into(),Command::new($frp.clone_ref()));
-    };
+    ($map:ident $field:ident () [$($doc:tt)*] $frp:expr) => {{
+        let doc_lines : &[&str] = &[$($doc)*];
+        let doc_lines = doc_lines.iter().map(|line| line.trim()).collect::<Vec<_>>();
+        let command   = Command::new($frp.clone_ref(),doc_lines.join(" "));
+        $map.insert(stringify!($field).into(),command);
+    }};
     ($($ts:tt)*) => {}
 }
 
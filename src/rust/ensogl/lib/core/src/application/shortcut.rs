@@ -128,6 +128,20 @@ impl From<&str> for Condition {
     }
 }
 
+impl Display for Condition {
+    /// Formats the condition in the syntax accepted by the parser, like "a & b | !c".
+    fn fmt(&self, f:&mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Always     => Ok(()),
+            Self::Never      => write!(f,"never"),
+            Self::When(name) => write!(f,"{}",name),
+            Self::Not(a)     => write!(f,"!{}",a),
+            Self::Or(a,b)    => write!(f,"{} | {}",a,b),
+            Self::And(a,b)   => write!(f,"{} & {}",a,b),
+        }
+    }
+}
+
 
 
 // ==============
@@ -158,6 +172,21 @@ impl Action {
         let command   = command.into();
         Self {target,command,condition}
     }
+
+    /// The label of the view the command is evaluated in.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// The command evaluated in the target view.
+    pub fn command(&self) -> &Command {
+        &self.command
+    }
+
+    /// The condition which needs to be true in order for the command to be evaluated.
+    pub fn condition(&self) -> &Condition {
+        &self.condition
+    }
 }
 
 
@@ -197,6 +226,38 @@ impl Shortcut {
         let rule   = rule.into();
         Self {action,rule}
     }
+
+    /// The rule triggering the action.
+    pub fn rule(&self) -> &Rule {
+        &self.rule
+    }
+}
+
+
+
+// ====================
+// === ShortcutInfo ===
+// ====================
+
+/// The description of a shortcut evaluating a command of a living view instance, as listed by
+/// [`RegistryModel::shortcuts`].
+#[derive(Clone,Debug)]
+pub struct ShortcutInfo {
+    /// The label of the view the command is evaluated in, like `GraphEditor`.
+    pub view      : String,
+    /// The name of the evaluated command.
+    pub command   : String,
+    /// The rule pattern, like "ctrl shift s".
+    pub pattern   : String,
+    /// The action type of the rule.
+    pub tp        : ActionType,
+    /// The condition in the syntax of the shortcut definitions, like "!node_editing". Empty if the
+    /// shortcut is always enabled.
+    pub condition : String,
+    /// Whether the condition holds in at least one instance of the view at the moment.
+    pub active    : bool,
+    /// The documentation of the command's FRP endpoint.
+    pub doc       : String,
 }
 
 
@@ -211,13 +272,23 @@ impl Shortcut {
 /// dropped, the shortcut will be lazily removed. This is useful when defining shortcuts by GUI
 /// components. When a component is unloaded, all its default shortcuts should be removed as well.
 ///
+/// The rule pattern can define a chord sequence by separating its steps with commas, like
+/// "ctrl k, ctrl c". While its first steps are pressed, the `pending_chord` output contains them,
+/// so they can be displayed to the user. The chord is cancelled if the next step is not pressed in
+/// `shortcuts::CHORD_TIMEOUT_MS`, which is checked by `check_chord_timeout`.
+///
+/// All the added shortcuts can be listed with `shortcuts`, e.g. to display them to the user.
+///
 /// ## Implementation Notes
 /// There should be a layer for user shortcuts which will remember handles permanently until a
 /// shortcut is unregistered.
 #[derive(Clone,CloneRef,Debug)]
 pub struct Registry {
-    model   : RegistryModel,
-    network : frp::Network,
+    model             : RegistryModel,
+    network           : frp::Network,
+    chord_timeout     : frp::Source,
+    /// The already pressed steps of the pending chord sequence, like "ctrl k".
+    pub pending_chord : frp::Sampler<Option<String>>,
 }
 
 /// Internal representation of `Registry`.
@@ -228,6 +299,8 @@ pub struct RegistryModel {
     mouse              : Mouse,
     command_registry   : command::Registry,
     shortcuts_registry : shortcuts::HashSetRegistry<Shortcut>,
+    /// All the added shortcuts, in the order they were added, kept for introspection.
+    added              : Rc<RefCell<Vec<Shortcut>>>,
 }
 
 impl Deref for Registry {
@@ -252,8 +325,21 @@ impl Registry {
             mouse_up   <- mouse.up.map      (f!((t) model.shortcuts_registry.on_release(t.simple_name())));
             event      <- any(kb_down,kb_up,mouse_down,mouse_up);
             eval event ((m) model.process_rules(m));
+
+            chord_timeout <- source::<()>();
+            chord_changed <- any_(event,chord_timeout);
+            pending_chord <- chord_changed.map(f_!(model.shortcuts_registry.pending_chord()));
+            pending_chord <- pending_chord.on_change().sampler();
+        }
+        Self {model,network,chord_timeout,pending_chord}
+    }
+
+    /// Cancel the pending chord sequence if the timeout of its next step elapsed. Should be called
+    /// periodically, e.g. on every frame.
+    pub fn check_chord_timeout(&self) {
+        if self.model.shortcuts_registry.cancel_expired_chord() {
+            self.chord_timeout.emit(());
         }
-        Self {model,network}
     }
 }
 
@@ -270,7 +356,41 @@ impl RegistryModel {
         let mouse              = mouse.clone_ref();
         let command_registry   = command_registry.clone_ref();
         let shortcuts_registry = default();
-        Self {logger,keyboard,mouse,command_registry,shortcuts_registry}
+        let added              = default();
+        Self {logger,keyboard,mouse,command_registry,shortcuts_registry,added}
+    }
+
+    /// All the shortcuts evaluating the enabled commands of the living view instances, sorted by
+    /// their views and patterns. Allows discovering the shortcuts at runtime, e.g. to display a
+    /// cheat sheet.
+    pub fn shortcuts(&self) -> Vec<ShortcutInfo> {
+        let mut shortcuts    = Vec::<ShortcutInfo>::new();
+        let command_name_map = self.command_registry.name_map.borrow();
+        for shortcut in self.added.borrow().iter() {
+            let instances = command_name_map.get(&shortcut.target).into_iter().flatten();
+            let instances = instances.filter(|instance| instance.check_alive()).collect_vec();
+            let doc       = instances.iter().find_map(|instance| {
+                let commands = instance.command_map.borrow();
+                let command  = commands.get(&shortcut.command.name).filter(|cmd| cmd.enabled);
+                command.map(|command| command.doc.clone())
+            });
+            if let Some(doc) = doc {
+                let active = instances.iter().any(|instance| {
+                    Self::condition_checker(&shortcut.condition,&instance.status_map)
+                });
+                shortcuts.push(ShortcutInfo {
+                    view      : shortcut.target.clone(),
+                    command   : shortcut.command.name.clone(),
+                    pattern   : shortcut.rule.pattern.clone(),
+                    tp        : shortcut.rule.tp,
+                    condition : shortcut.condition.to_string(),
+                    active,
+                    doc,
+                });
+            }
+        }
+        shortcuts.sort_by(|lhs,rhs| (&lhs.view,&lhs.pattern).cmp(&(&rhs.view,&rhs.pattern)));
+        shortcuts
     }
 
     fn process_rules(&self, rules:&[Shortcut]) {
@@ -315,6 +435,38 @@ impl RegistryModel {
 impl Add<Shortcut> for &Registry {
     type Output = ();
     fn add(self, shortcut:Shortcut) {
-        self.model.shortcuts_registry.add(shortcut.rule.tp,&shortcut.rule.pattern,shortcut.clone());
+        let rule     = &shortcut.rule;
+        let is_chord = rule.pattern.contains(',');
+        if is_chord && rule.tp != ActionType::Press {
+            let pattern = &rule.pattern;
+            warning!(&self.model.logger,"Chord sequence \"{pattern}\" can be used only with the \
+                `Press` action type.");
+        }
+        self.model.shortcuts_registry.add(rule.tp,&rule.pattern,shortcut.clone());
+        let mut added = self.model.added.borrow_mut();
+        if !added.contains(&shortcut) {
+            added.push(shortcut);
+        }
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn condition_is_displayed_in_the_parsed_syntax() {
+        let conditions = ["","a","!a","a & !b","a | b & !c","!a | b & c | d"];
+        for condition in &conditions {
+            assert_eq!(Condition::parse(condition).to_string(),*condition);
+        }
+        assert_eq!(Condition::parse("  a&b |!c ").to_string(),"a & b | !c");
+        assert_eq!(Condition::Never.to_string(),"never");
     }
 }
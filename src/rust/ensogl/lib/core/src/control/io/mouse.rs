@@ -144,12 +144,14 @@ fn event_listener_options() -> web_sys::AddEventListenerOptions {
     options
 }
 
+// Pointer events are used instead of mouse ones, as only they carry the pointer id and support
+// pointer capture. They are dispatched for mouse, pen, and touch input alike.
 define_bindings! {
-    MouseEvent::mousedown  => on_down  (OnDown),
-    MouseEvent::mouseup    => on_up    (OnUp),
-    MouseEvent::mousemove  => on_move  (OnMove),
-    MouseEvent::mouseleave => on_leave (OnLeave),
-    WheelEvent::wheel      => on_wheel (OnWheel),
+    PointerEvent::pointerdown => on_down  (OnDown),
+    PointerEvent::pointerup   => on_up    (OnUp),
+    PointerEvent::pointermove => on_move  (OnMove),
+    MouseEvent::mouseleave    => on_leave (OnLeave),
+    WheelEvent::wheel         => on_wheel (OnWheel),
 }
 
 /// A handles of callbacks emitting events on bound FRP graph. See `callback::Handle`.
@@ -173,7 +175,10 @@ pub fn bind_frp_to_mouse(frp:&Mouse, mouse_manager:&MouseManager)
     });
     let on_down  = enclose!((frp.down  => frp) move |_:&OnDown | frp.emit(Button0));
     let on_up    = enclose!((frp.up    => frp) move |_:&OnUp   | frp.emit(Button0));
-    let on_wheel = enclose!((frp.wheel => frp) move |_:&OnWheel| frp.emit(()));
+    let on_wheel = enclose!((frp) move |e:&OnWheel| {
+        frp.wheel_scroll.emit(e.wheel());
+        frp.wheel.emit(());
+    });
     MouseFrpCallbackHandles {
         on_move  : mouse_manager.on_move.add(on_move),
         on_down  : mouse_manager.on_down.add(on_down),
@@ -92,10 +92,52 @@ macro_rules! define_events {
     )*};
 }
 
+macro_rules! define_pointer_events {
+    ( $( $name:ident ),* $(,)? ) => {$(
+        impl $name {
+            /// The identifier of the pointer which caused this event. Allows distinguishing between
+            /// events of simultaneous touch pointers.
+            pub fn pointer_id(&self) -> mouse::PointerId {
+                mouse::PointerId(self.raw.pointer_id())
+            }
+
+            /// The distance the pointer moved since the previous move event, in pixels. Unlike
+            /// the position, it changes also when the pointer is locked.
+            pub fn movement(&self) -> Vector2<f32> {
+                Vector2::new(self.raw.movement_x() as f32,self.raw.movement_y() as f32)
+            }
+        }
+    )*};
+}
+
 define_events! {
-    MouseEvent::OnDown,
-    MouseEvent::OnUp,
-    MouseEvent::OnMove,
+    PointerEvent::OnDown,
+    PointerEvent::OnUp,
+    PointerEvent::OnMove,
     MouseEvent::OnLeave,
     WheelEvent::OnWheel,
 }
+
+define_pointer_events! {
+    OnDown,
+    OnUp,
+    OnMove,
+}
+
+/// The height of a line scrolled by the wheel events measuring their delta in lines, in pixels.
+const WHEEL_LINE_HEIGHT : f32 = 16.0;
+
+impl OnWheel {
+    /// The scroll described by this event, with its delta converted to pixels.
+    pub fn wheel(&self) -> mouse::Wheel {
+        let scale = match self.raw.delta_mode() {
+            web_sys::WheelEvent::DOM_DELTA_LINE => WHEEL_LINE_HEIGHT,
+            web_sys::WheelEvent::DOM_DELTA_PAGE => self.shape.height,
+            _                                   => 1.0,
+        };
+        let delta = Vector2::new(self.raw.delta_x() as f32,self.raw.delta_y() as f32) * scale;
+        let shift = self.raw.shift_key();
+        let ctrl  = self.raw.ctrl_key() || self.raw.meta_key();
+        mouse::Wheel {delta,shift,ctrl}
+    }
+}
@@ -21,6 +21,8 @@ crate::define_endpoints! {
         target       (Lcha),
         target_alpha (f32),
         target_color (Lch),
+        /// Jump to the current target immediately, skipping the animation.
+        skip         (),
     }
     Output {
         value (Lcha),
@@ -62,6 +64,8 @@ impl Animation {
             target_alpha           <- any(&self.frp.target_alpha,&alpha_of_target);
             self.color_anim.target <+ target_color;
             self.alpha_anim.target <+ target_alpha;
+            self.color_anim.skip   <+ self.frp.skip;
+            self.alpha_anim.skip   <+ self.frp.skip;
             self.frp.source.value  <+ all(&self.color_anim.value,&self.alpha_anim.value).map(
                 |(color,alpha)| color.with_alpha(*alpha)
             );
@@ -0,0 +1,38 @@
+//! Registry of the display objects owning the FRP networks. It allows the debugging tools, like the
+//! FRP inspector, to point at the view component an inspected network belongs to. The registry is
+//! filled in debug builds only.
+
+use crate::prelude::*;
+
+use crate::display;
+use crate::display::scene::Scene;
+
+use enso_frp as frp;
+
+
+
+// ================
+// === Registry ===
+// ================
+
+thread_local! {
+    static OWNERS : RefCell<HashMap<frp::NetworkId,display::object::WeakInstance<Scene>>>
+        = RefCell::new(default());
+}
+
+/// Register the display object as the owner of the network. Does nothing in release builds.
+pub fn register(network:&frp::Network, owner:&impl display::Object) {
+    if cfg!(debug_assertions) {
+        let owner = owner.display_object().downgrade();
+        OWNERS.with(|owners| {
+            let mut owners = owners.borrow_mut();
+            owners.retain(|_,owner| owner.exists());
+            owners.insert(network.id(),owner);
+        })
+    }
+}
+
+/// The display object owning the network, if it was registered and is still alive.
+pub fn owner(network:frp::NetworkId) -> Option<display::object::Instance> {
+    OWNERS.with(|owners| owners.borrow().get(&network).and_then(|owner| owner.upgrade()))
+}
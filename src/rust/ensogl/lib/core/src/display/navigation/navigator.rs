@@ -2,6 +2,7 @@ mod events;
 
 use crate::prelude::*;
 
+use crate::animation::easing;
 use crate::animation::physics;
 use crate::control::callback;
 use crate::display::camera::Camera2d;
@@ -14,15 +15,75 @@ use events::ZoomEvent;
 
 
 
+// =================
+// === Constants ===
+// =================
+
+/// The minimum distance of the camera from its near clipping plane.
+const MIN_ZOOM : f32 = 10.0;
+
+/// The maximum distance of the camera from the scene.
+const MAX_ZOOM : f32 = 10000.0;
+
+
+
+// ==============
+// === Flight ===
+// ==============
+
+/// The easing of the camera flight started by [`NavigatorModel::fly_to`].
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+#[allow(missing_docs)]
+pub enum FlightEasing {
+    Linear,
+    QuadInOut,
+    CubicInOut,
+    ExpoOut,
+}
+
+impl Default for FlightEasing {
+    fn default() -> Self {
+        Self::CubicInOut
+    }
+}
+
+impl FlightEasing {
+    fn tween_fn(self) -> Box<dyn Fn(f32)->f32> {
+        match self {
+            Self::Linear     => Box::new(easing::linear()),
+            Self::QuadInOut  => Box::new(easing::quad_in_out()),
+            Self::CubicInOut => Box::new(easing::cubic_in_out()),
+            Self::ExpoOut    => Box::new(easing::expo_out()),
+        }
+    }
+}
+
+type Flight       = easing::DynAnimator<Vector3,Box<dyn Fn(f32)->f32>>;
+type SharedFlight = Rc<RefCell<Option<Flight>>>;
+
+fn stop_flight(flight:&SharedFlight) {
+    if let Some(flight) = flight.borrow_mut().take() {
+        flight.stop();
+    }
+}
+
+
+
 // ======================
 // === NavigatorModel ===
 // ======================
 
 /// Navigator enables camera navigation with mouse interactions.
-#[derive(Debug)]
+#[derive(Derivative)]
+#[derivative(Debug)]
 pub struct NavigatorModel {
-    _events         : NavigatorEvents,
+    events          : NavigatorEvents,
+    camera          : Camera2d,
     simulator       : physics::inertia::DynSimulator<Vector3>,
+    /// The camera flight started by `fly_to`. It is stopped as soon as the camera is panned or
+    /// zoomed in any other way.
+    #[derivative(Debug="ignore")]
+    flight          : SharedFlight,
     resize_callback : callback::Handle,
     zoom_speed      : SharedSwitch<f32>,
     pan_speed       : SharedSwitch<f32>,
@@ -35,13 +96,15 @@ impl NavigatorModel {
     pub fn new(scene:&Scene, camera:&Camera2d) -> Self {
         let zoom_speed             = Rc::new(Cell::new(Switch::On(10.0/1000.0)));
         let pan_speed              = Rc::new(Cell::new(Switch::On(1.0)));
-        let min_zoom               = 10.0;
-        let max_zoom               = 10000.0;
+        let min_zoom               = MIN_ZOOM;
+        let max_zoom               = MAX_ZOOM;
         let disable_events         = Rc::new(Cell::new(true));
-        let (simulator,resize_callback,_events) = Self::start_navigator_events
-            (scene,camera,min_zoom,max_zoom,Rc::clone(&zoom_speed),Rc::clone(&pan_speed),
-             Rc::clone(&disable_events));
-        Self {_events,simulator,resize_callback,zoom_speed,pan_speed,disable_events}
+        let flight                 = SharedFlight::default();
+        let camera                 = camera.clone_ref();
+        let (simulator,resize_callback,events) = Self::start_navigator_events
+            (scene,&camera,min_zoom,max_zoom,Rc::clone(&zoom_speed),Rc::clone(&pan_speed),
+             Rc::clone(&disable_events),Rc::clone(&flight));
+        Self {events,camera,simulator,flight,resize_callback,zoom_speed,pan_speed,disable_events}
     }
 
     fn create_simulator(camera:&Camera2d) -> physics::inertia::DynSimulator<Vector3> {
@@ -63,9 +126,12 @@ impl NavigatorModel {
     , zoom_speed     : SharedSwitch<f32>
     , pan_speed      : SharedSwitch<f32>
     , disable_events : Rc<Cell<bool>>
+    , flight         : SharedFlight
     ) -> (physics::inertia::DynSimulator<Vector3>,callback::Handle,NavigatorEvents) {
         let simulator        = Self::create_simulator(camera);
-        let panning_callback = enclose!((scene,camera,mut simulator,pan_speed) move |pan: PanEvent| {
+        let panning_callback = enclose!((scene,camera,mut simulator,pan_speed,flight)
+        move |pan: PanEvent| {
+            stop_flight(&flight);
             let fovy_slope                  = camera.half_fovy_slope();
             let distance                    = camera.position().z;
             let distance_to_show_full_ui    = scene.shape().value().height / 2.0 / fovy_slope;
@@ -84,7 +150,8 @@ impl NavigatorModel {
             })
         );
 
-        let zoom_callback = enclose!((scene,camera,simulator) move |zoom:ZoomEvent| {
+        let zoom_callback = enclose!((scene,camera,simulator,flight) move |zoom:ZoomEvent| {
+            stop_flight(&flight);
             let point       = zoom.focus;
             let normalized  = normalize_point2(point,scene.shape().value().into());
             let normalized  = normalized_to_range2(normalized, -1.0, 1.0);
@@ -125,6 +192,47 @@ impl NavigatorModel {
         self.zoom_speed.update(|switch| switch.switched(false));
         self.disable_events.set(false);
     }
+
+    /// Enable or disable the built-in panning and zooming with the mouse wheel. It may be disabled
+    /// to handle the wheel events differently, moving the camera with `pan` and `zoom`.
+    pub fn set_wheel_enabled(&self, enabled:bool) {
+        self.events.set_wheel_enabled(enabled);
+    }
+
+    /// Pan the camera as if the scene was dragged by the mouse by the `movement`, in pixels. Does
+    /// nothing when the navigator is disabled.
+    pub fn pan(&self, movement:Vector2<f32>) {
+        self.events.pan(movement);
+    }
+
+    /// Zoom the camera towards the `focus` point, given in pixels from the bottom left corner of
+    /// the scene. Positive amounts zoom out. Does nothing when the navigator is disabled.
+    pub fn zoom(&self, focus:Vector2<f32>, amount:f32) {
+        self.events.zoom(focus,amount);
+    }
+
+    /// Smoothly move the camera, so its center is at the `position` and its zoom is `zoom`. The
+    /// flight is interrupted when the camera is panned or zoomed in any other way, including the
+    /// next `fly_to` call.
+    pub fn fly_to(&self, position:Vector2<f32>, zoom:f32, duration_ms:f32, easing:FlightEasing) {
+        stop_flight(&self.flight);
+        let min_distance = self.camera.clipping().near + MIN_ZOOM;
+        let distance     = self.camera.z_zoom_1() / zoom.max(std::f32::EPSILON);
+        let distance     = distance.max(min_distance).min(MAX_ZOOM);
+        let start        = self.simulator.value();
+        let target       = Vector3(position.x,position.y,distance);
+        let simulator    = self.simulator.clone_ref();
+        let on_step      = Box::new(move |position:Vector3| {
+            simulator.set_value(position);
+            simulator.set_target_value(position);
+            simulator.set_velocity(default());
+        });
+        let on_end = Box::new(|_| {});
+        let flight = Flight::new_not_started(start,target,easing.tween_fn(),on_step,on_end);
+        flight.set_duration(duration_ms.max(std::f32::EPSILON));
+        flight.start();
+        *self.flight.borrow_mut() = Some(flight);
+    }
 }
 
 
@@ -72,6 +72,7 @@ struct NavigatorEventsProperties {
     zoom_speed          : SharedSwitch<f32>,
     pan_speed           : SharedSwitch<f32>,
     disable_events      : Rc<Cell<bool>>,
+    wheel_enabled       : bool,
     movement_type       : Option<MovementType>,
     last_mouse_position : Vector2<f32>,
     mouse_position      : Vector2<f32>,
@@ -102,10 +103,12 @@ impl NavigatorEventsData {
         let mouse_position      = zero();
         let last_mouse_position = zero();
         let movement_type       = None;
+        let wheel_enabled       = true;
         let properties          = RefCell::new(NavigatorEventsProperties {
             zoom_speed,
             pan_speed,
             disable_events,
+            wheel_enabled,
             movement_type,
             last_mouse_position,
             mouse_position,
@@ -152,6 +155,10 @@ impl NavigatorEventsData {
     fn events_disabled(&self) -> bool {
         self.properties.borrow().disable_events.get()
     }
+
+    fn wheel_enabled(&self) -> bool {
+        self.properties.borrow().wheel_enabled
+    }
 }
 
 
@@ -162,6 +169,10 @@ impl NavigatorEventsData {
         self.properties.borrow_mut().movement_type = movement_type;
     }
 
+    fn set_wheel_enabled(&self, enabled:bool) {
+        self.properties.borrow_mut().wheel_enabled = enabled;
+    }
+
     fn set_mouse_position(&self, mouse_position:Vector2<f32>) {
         let mut properties             = self.properties.borrow_mut();
         properties.last_mouse_position = properties.mouse_position;
@@ -221,6 +232,23 @@ impl NavigatorEvents {
         event_handler
     }
 
+    /// Enable or disable panning and zooming with the mouse wheel.
+    pub fn set_wheel_enabled(&self, enabled:bool) {
+        self.data.set_wheel_enabled(enabled);
+    }
+
+    /// Pan as if the mouse was dragged by the `movement`.
+    pub fn pan(&self, movement:Vector2<f32>) {
+        let pan_speed = self.data.pan_speed();
+        self.data.on_pan(PanEvent::new(movement * pan_speed));
+    }
+
+    /// Zoom towards the `focus` point by the `amount`. Positive amounts zoom out.
+    pub fn zoom(&self, focus:Vector2<f32>, amount:f32) {
+        let zoom_speed = self.data.zoom_speed();
+        self.data.on_zoom(ZoomEvent::new(focus,amount,zoom_speed));
+    }
+
     fn initialize_mouse_events(&mut self) {
         self.initialize_wheel_zoom();
         self.initialize_mouse_start_event();
@@ -240,6 +268,11 @@ impl NavigatorEvents {
                     // being applied to the whole IDE, thus we need to do this always when ctrl is
                     // pressed.
                     event.prevent_default();
+                }
+                if !data.wheel_enabled() {
+                    return
+                }
+                if event.ctrl_key() {
                     let position   = data.mouse_position();
                     let zoom_speed = data.zoom_speed();
                     let movement   = Vector2::new(event.delta_x() as f32, -event.delta_y() as f32);
@@ -3,6 +3,7 @@
 pub mod symbols;
 pub mod pixel_read;
 pub mod screen;
+pub mod snapshot;
 
 
 
@@ -12,5 +13,6 @@ pub mod types {
     pub use symbols::*;
     pub use pixel_read::*;
     pub use screen::*;
+    pub use snapshot::*;
 }
 pub use types::*;
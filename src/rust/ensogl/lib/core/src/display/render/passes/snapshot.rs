@@ -0,0 +1,280 @@
+//! Pass reading the whole rendered image into the memory. Used by the snapshot tests, which
+//! compare the rendered components with the golden images.
+
+use crate::prelude::*;
+
+use crate::display::render::pass;
+use crate::system::gpu::*;
+
+use web_sys::WebGlFramebuffer;
+
+
+
+// ================
+// === Snapshot ===
+// ================
+
+/// Error of parsing the snapshot image.
+#[derive(Clone,Debug,Fail)]
+#[fail(display="Invalid snapshot image: {}.",reason)]
+pub struct InvalidSnapshot {
+    reason : String,
+}
+
+impl InvalidSnapshot {
+    fn new(reason:impl Into<String>) -> Self {
+        let reason = reason.into();
+        Self {reason}
+    }
+}
+
+/// The number of bytes describing a single pixel.
+const CHANNELS : usize = 4;
+
+/// An RGBA image of the rendered scene. The rows are stored from the bottom to the top, as read
+/// from the GPU.
+#[derive(Clone,Debug,Default,Eq,PartialEq)]
+#[allow(missing_docs)]
+pub struct Snapshot {
+    pub width  : usize,
+    pub height : usize,
+    pub pixels : Vec<u8>,
+}
+
+impl Snapshot {
+    /// Constructor. The length of `pixels` must be equal to `width * height * 4`.
+    pub fn new(width:usize, height:usize, pixels:Vec<u8>) -> Self {
+        assert_eq!(pixels.len(),width * height * CHANNELS,"Invalid snapshot pixels count.");
+        Self {width,height,pixels}
+    }
+
+    /// Serialize the image to the PAM format (see http://netpbm.sourceforge.net/doc/pam.html). It
+    /// is a very simple format, which can be opened by most image editors.
+    pub fn to_pam(&self) -> Vec<u8> {
+        let header = format!("P7\nWIDTH {}\nHEIGHT {}\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\n\
+                              ENDHDR\n",self.width,self.height);
+        let rows   = self.pixels.chunks(self.width * CHANNELS).rev();
+        header.into_bytes().into_iter().chain(rows.flatten().copied()).collect()
+    }
+
+    /// Deserialize the image saved by `to_pam`.
+    pub fn from_pam(bytes:&[u8]) -> Result<Self,InvalidSnapshot> {
+        let marker     = b"ENDHDR\n";
+        let header_end = bytes.windows(marker.len()).position(|t| t == marker)
+            .ok_or_else(|| InvalidSnapshot::new("missing header end"))?;
+        let header     = std::str::from_utf8(&bytes[..header_end])
+            .map_err(|_| InvalidSnapshot::new("header is not valid UTF-8"))?;
+        let field      = |name:&str| -> Result<usize,InvalidSnapshot> {
+            let line  = header.lines().find(|line| line.starts_with(name));
+            let value = line.and_then(|line| line[name.len()..].trim().parse().ok());
+            value.ok_or_else(|| InvalidSnapshot::new(format!("missing {} field",name)))
+        };
+        if !header.starts_with("P7") || field("DEPTH")? != CHANNELS || field("MAXVAL")? != 255 {
+            return Err(InvalidSnapshot::new("only 8-bit RGBA images are supported"))
+        }
+        let width  = field("WIDTH")?;
+        let height = field("HEIGHT")?;
+        let data   = &bytes[header_end + marker.len()..];
+        if data.len() != width * height * CHANNELS {
+            return Err(InvalidSnapshot::new("pixels count does not match the size"))
+        }
+        let rows   = data.chunks(width * CHANNELS).rev();
+        let pixels = rows.flatten().copied().collect();
+        Ok(Self {width,height,pixels})
+    }
+
+    /// Compare the image with the other one. Images of different sizes differ at every pixel.
+    pub fn compare(&self, other:&Snapshot, tolerance:Tolerance) -> Comparison {
+        let pixel_count = self.width * self.height;
+        if self.width != other.width || self.height != other.height {
+            let different_pixels = pixel_count.max(other.width * other.height);
+            let max_difference   = u8::MAX;
+            return Comparison {pixel_count,different_pixels,max_difference,tolerance}
+        }
+        let pixels      = self.pixels.chunks(CHANNELS).zip(other.pixels.chunks(CHANNELS));
+        let differences = pixels.map(|(this,other)| {
+            let channels = this.iter().zip(other);
+            channels.map(|(this,other)| (*this as i16 - *other as i16).abs() as u8).max()
+        });
+        let mut different_pixels = 0;
+        let mut max_difference   = 0;
+        for difference in differences.flatten() {
+            max_difference = max_difference.max(difference);
+            if difference > tolerance.channel_difference { different_pixels += 1 }
+        }
+        Comparison {pixel_count,different_pixels,max_difference,tolerance}
+    }
+}
+
+
+
+// ==================
+// === Comparison ===
+// ==================
+
+/// Allowed difference between the compared images.
+#[derive(Clone,Copy,Debug)]
+pub struct Tolerance {
+    /// The maximum difference of a single channel of a pixel for the pixel to be considered equal.
+    /// It makes up for the antialiasing differences between GPUs.
+    pub channel_difference : u8,
+    /// The maximum fraction of pixels exceeding `channel_difference`.
+    pub different_pixels   : f32,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        let channel_difference = 8;
+        let different_pixels   = 0.001;
+        Self {channel_difference,different_pixels}
+    }
+}
+
+/// The result of comparing two snapshots.
+#[derive(Clone,Copy,Debug)]
+#[allow(missing_docs)]
+pub struct Comparison {
+    pub pixel_count      : usize,
+    /// The number of pixels exceeding the `tolerance.channel_difference`.
+    pub different_pixels : usize,
+    pub max_difference   : u8,
+    pub tolerance        : Tolerance,
+}
+
+impl Comparison {
+    /// Check whether the images are equal within the tolerance.
+    pub fn matches(&self) -> bool {
+        let allowed = (self.pixel_count as f32 * self.tolerance.different_pixels) as usize;
+        self.different_pixels <= allowed
+    }
+}
+
+
+
+// =================
+// === Snapshots ===
+// =================
+
+/// Shared state of the `SnapshotPass`: whether a snapshot was requested and the last taken one.
+#[derive(Clone,CloneRef,Debug,Default)]
+pub struct Snapshots {
+    requested : Rc<Cell<bool>>,
+    taken     : Rc<RefCell<Option<Snapshot>>>,
+}
+
+impl Snapshots {
+    /// Request taking a snapshot during the next run of the pass.
+    pub fn request(&self) {
+        self.requested.set(true)
+    }
+
+    /// Take the snapshot taken since the last request, if any. Cancels the pending request.
+    pub fn take(&self) -> Option<Snapshot> {
+        self.requested.set(false);
+        self.taken.borrow_mut().take()
+    }
+}
+
+
+
+// ====================
+// === SnapshotPass ===
+// ====================
+
+/// Reads the `'color'` output of the previous passes when a snapshot was requested. Reading the
+/// pixels stalls the GPU pipeline, so the pass does nothing unless requested.
+#[derive(Clone,Debug)]
+pub struct SnapshotPass {
+    snapshots   : Snapshots,
+    framebuffer : Option<WebGlFramebuffer>,
+}
+
+impl SnapshotPass {
+    /// Constructor.
+    pub fn new(snapshots:&Snapshots) -> Self {
+        let snapshots   = snapshots.clone_ref();
+        let framebuffer = default();
+        Self {snapshots,framebuffer}
+    }
+}
+
+impl pass::Definition for SnapshotPass {
+    fn initialize(&mut self, instance:&pass::Instance) {
+        let texture = match instance.variables.get("pass_color").unwrap() {
+            uniform::AnyUniform::Texture(t) => t,
+            _ => panic!("Pass internal error. Unmatched types.")
+        };
+        let context     = &instance.context;
+        let framebuffer = context.create_framebuffer().unwrap();
+        let target      = Context::FRAMEBUFFER;
+        let gl_texture  = texture.gl_texture();
+        context.bind_framebuffer(target,Some(&framebuffer));
+        context.framebuffer_texture_2d
+            (target,Context::COLOR_ATTACHMENT0,Context::TEXTURE_2D,Some(&gl_texture),0);
+        context.bind_framebuffer(target,None);
+        self.framebuffer = Some(framebuffer);
+    }
+
+    fn run(&mut self, instance:&pass::Instance) {
+        if self.snapshots.requested.replace(false) {
+            let context    = &instance.context;
+            let width      = instance.width as usize;
+            let height     = instance.height as usize;
+            let mut pixels = vec![0; width * height * CHANNELS];
+            let format     = Context::RGBA;
+            let item_type  = Context::UNSIGNED_BYTE;
+            // Reading to the memory fails if a pixel pack buffer is bound, as done by the
+            // `PixelReadPass`.
+            context.bind_buffer(Context::PIXEL_PACK_BUFFER,None);
+            context.bind_framebuffer(Context::FRAMEBUFFER,self.framebuffer.as_ref());
+            context.read_pixels_with_opt_u8_array
+                (0,0,instance.width,instance.height,format,item_type,Some(&mut pixels)).unwrap();
+            context.bind_framebuffer(Context::FRAMEBUFFER,None);
+            let snapshot = Snapshot::new(width,height,pixels);
+            *self.snapshots.taken.borrow_mut() = Some(snapshot);
+        }
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(pixels:&[[u8;4]]) -> Snapshot {
+        Snapshot::new(pixels.len(),1,pixels.iter().flatten().copied().collect())
+    }
+
+    #[test]
+    fn pam_round_trip() {
+        let snapshot = Snapshot::new(2,2,(0..16).collect());
+        let pam      = snapshot.to_pam();
+        assert!(pam.starts_with(b"P7\nWIDTH 2\nHEIGHT 2\n"));
+        assert_eq!(Snapshot::from_pam(&pam).unwrap(),snapshot);
+        assert!(Snapshot::from_pam(&pam[..pam.len() - 1]).is_err());
+        assert!(Snapshot::from_pam(b"P6\n").is_err());
+    }
+
+    #[test]
+    fn comparing_with_tolerance() {
+        let golden    = image(&[[0,0,0,255],[100,100,100,255],[200,200,200,255]]);
+        let similar   = image(&[[0,0,0,255],[104,100,100,255],[200,200,200,255]]);
+        let different = image(&[[0,0,0,255],[100,100,100,255],[0,0,0,255]]);
+        let strict    = Tolerance {channel_difference:2, different_pixels:0.0};
+        let lenient   = Tolerance {channel_difference:0, different_pixels:0.5};
+        assert!(golden.compare(&golden,strict).matches());
+        assert!(golden.compare(&similar,default()).matches());
+        assert!(!golden.compare(&similar,strict).matches());
+        let comparison = golden.compare(&different,default());
+        assert_eq!(comparison.different_pixels,1);
+        assert_eq!(comparison.max_difference,200);
+        assert!(!comparison.matches());
+        assert!(golden.compare(&different,lenient).matches());
+        assert!(!golden.compare(&image(&[[0,0,0,255]]),lenient).matches());
+    }
+}
@@ -43,6 +43,9 @@ use enso_frp as frp;
 use enso_frp::io::js::CurrentJsEvent;
 use enso_shapely::shared;
 use std::any::TypeId;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use wasm_bindgen::prelude::Closure;
 use web_sys::HtmlElement;
 
 
@@ -310,7 +313,8 @@ pub struct Mouse {
     pub position      : Uniform<Vector2<i32>>,
     pub hover_ids     : Uniform<Vector4<u32>>,
     pub target        : Rc<Cell<PointerTarget>>,
-    pub handles       : Rc<[callback::Handle;3]>,
+    pub handles       : Rc<[callback::Handle;4]>,
+    pub pointer_lock  : Rc<PointerLockListener>,
     pub frp           : enso_frp::io::Mouse,
     pub scene_frp     : Frp,
     pub logger        : Logger
@@ -334,6 +338,8 @@ impl Mouse {
         let frp             = frp::io::Mouse::new();
         let on_move         = mouse_manager.on_move.add(current_js_event.make_event_handler(
             f!([frp,scene_frp,position,last_position] (event:&mouse::OnMove) {
+                frp.pointer_id.emit(event.pointer_id());
+                frp.movement.emit(event.movement());
                 let shape       = scene_frp.shape.value();
                 let pixel_ratio = shape.pixel_ratio;
                 let screen_x    = event.client_x();
@@ -351,13 +357,66 @@ impl Mouse {
             }
         )));
         let on_down = mouse_manager.on_down.add(current_js_event.make_event_handler(
-            f!((event:&mouse::OnDown) frp.down.emit(event.button())))
+            f!((event:&mouse::OnDown) {
+                frp.pointer_id.emit(event.pointer_id());
+                frp.down.emit(event.button());
+            }))
         );
         let on_up = mouse_manager.on_up.add(current_js_event.make_event_handler(
-            f!((event:&mouse::OnUp) frp.up.emit(event.button())))
+            f!((event:&mouse::OnUp) {
+                frp.pointer_id.emit(event.pointer_id());
+                frp.up.emit(event.button());
+            }))
         );
-        let handles = Rc::new([on_move,on_down,on_up]);
-        Self {mouse_manager,last_position,position,hover_ids,target,handles,frp,scene_frp,logger}
+        let on_wheel = mouse_manager.on_wheel.add(current_js_event.make_event_handler(
+            f!((event:&mouse::OnWheel) {
+                frp.wheel_scroll.emit(event.wheel());
+                frp.wheel.emit(());
+            }))
+        );
+        let handles = Rc::new([on_move,on_down,on_up,on_wheel]);
+        Self::init_pointer_capture(&frp,root,&logger);
+        Self::init_pointer_lock(&frp,root);
+        let pointer_lock = Rc::new(PointerLockListener::new(&frp,root,&logger));
+        Self {mouse_manager,last_position,position,hover_ids,target,handles,pointer_lock,frp
+             ,scene_frp,logger}
+    }
+
+    /// Redirect all events of the captured pointer to the root element, so they are delivered even
+    /// if the pointer leaves the canvas (or the browser window) during a drag. The browser releases
+    /// the capture automatically when the pointer is released.
+    fn init_pointer_capture
+    (frp:&frp::io::Mouse, root:&web::dom::WithKnownShape<web::HtmlDivElement>, logger:&Logger) {
+        let network = &frp.network;
+        let root    = root.clone_ref();
+        let logger  = logger.clone();
+        frp::extend! { network
+            captured_pointer <- frp.pointer_id.sample(&frp.capture_pointer);
+            released_pointer <- captured_pointer.sample(&frp.release_pointer);
+            eval captured_pointer ([root,logger](id) {
+                if let Err(err) = root.set_pointer_capture(id.0) {
+                    warning!(logger,"Cannot capture the pointer {id:?}: {err:?}");
+                }
+            });
+            eval released_pointer ([root,logger](id) {
+                if root.has_pointer_capture(id.0) {
+                    if let Err(err) = root.release_pointer_capture(id.0) {
+                        warning!(logger,"Cannot release the pointer {id:?}: {err:?}");
+                    }
+                }
+            });
+        }
+    }
+
+    /// Lock the pointer on the root element when requested. See the `frp::io::Mouse` docs.
+    fn init_pointer_lock
+    (frp:&frp::io::Mouse, root:&web::dom::WithKnownShape<web::HtmlDivElement>) {
+        let network = &frp.network;
+        let root    = root.clone_ref();
+        frp::extend! { network
+            eval_ frp.lock_pointer   (root.request_pointer_lock());
+            eval_ frp.unlock_pointer (web::document().exit_pointer_lock());
+        }
     }
 
     /// Re-emits FRP mouse changed position event with the last mouse position value.
@@ -390,6 +449,51 @@ impl Mouse {
 
 
 
+// ===========================
+// === PointerLockListener ===
+// ===========================
+
+/// Listener of the `pointerlockchange` DOM events, reporting whether the pointer is locked on the
+/// scene root element to the `pointer_locked` mouse FRP input.
+#[derive(Debug)]
+pub struct PointerLockListener {
+    closure : Closure<dyn Fn(JsValue)>,
+}
+
+impl PointerLockListener {
+    fn new
+    (frp:&frp::io::Mouse, root:&web::dom::WithKnownShape<web::HtmlDivElement>, logger:&Logger)
+    -> Self {
+        let pointer_locked = frp.pointer_locked.clone_ref();
+        let root           = root.clone_ref();
+        let closure        = Closure::wrap(Box::new(move |_:JsValue| {
+            let element = web::document().pointer_lock_element();
+            let locked  = element.map_or(false,|element| {
+                let root:&web_sys::Element = root.deref().as_ref();
+                &element == root
+            });
+            pointer_locked.emit(locked);
+        }) as Box<dyn Fn(JsValue)>);
+        let js_closure = closure.as_ref().unchecked_ref();
+        let result     = web::document().add_event_listener_with_callback
+            ("pointerlockchange",js_closure);
+        if let Err(err) = result {
+            warning!(logger,"Cannot listen to the pointer lock changes: {err:?}");
+        }
+        Self {closure}
+    }
+}
+
+impl Drop for PointerLockListener {
+    fn drop(&mut self) {
+        let js_closure = self.closure.as_ref().unchecked_ref();
+        let _ = web::document().remove_event_listener_with_callback
+            ("pointerlockchange",js_closure);
+    }
+}
+
+
+
 // ================
 // === Keyboard ===
 // ================
@@ -621,6 +725,9 @@ pub struct HardcodedLayers {
     pub label              : Layer,
     pub above_nodes        : Layer,
     pub above_nodes_text   : Layer,
+    /// Container for the layers of the secondary viewports, mirroring the layers above with their
+    /// own cameras. It is empty unless the workspace is split.
+    pub split              : Layer,
     /// Layer containing all panels with fixed position (not moving with the panned scene)
     /// like status bar, breadcrumbs or similar.
     pub panel              : Layer,
@@ -651,6 +758,7 @@ impl HardcodedLayers {
         let label              = Layer::new_with_cam(logger.sub("label"),main_cam);
         let above_nodes        = Layer::new_with_cam(logger.sub("above_nodes"),main_cam);
         let above_nodes_text   = Layer::new_with_cam(logger.sub("above_nodes_text"),main_cam);
+        let split              = Layer::new(logger.sub("split"));
         let panel              = Layer::new(logger.sub("panel"));
         let panel_text         = Layer::new(logger.sub("panel_text"));
         let node_searcher      = Layer::new(logger.sub("node_searcher"));
@@ -669,6 +777,7 @@ impl HardcodedLayers {
              , &label
              , &above_nodes
              , &above_nodes_text
+             , &split
              , &panel
              , &panel_text
              , &node_searcher
@@ -676,8 +785,8 @@ impl HardcodedLayers {
              , &tooltip_text
              , &cursor
              ]);
-        Self {root,viz,below_main,main,port_selection,label,above_nodes,above_nodes_text,panel
-             ,panel_text,node_searcher,node_searcher_mask,tooltip,tooltip_text,cursor,mask}
+        Self {root,viz,below_main,main,port_selection,label,above_nodes,above_nodes_text,split
+             ,panel,panel_text,node_searcher,node_searcher_mask,tooltip,tooltip_text,cursor,mask}
     }
 }
 
@@ -303,6 +303,7 @@ pub struct LayerModel {
     sublayers                       : Sublayers,
     mask                            : RefCell<Option<WeakLayer>>,
     scissor_box                     : RefCell<Option<ScissorBox>>,
+    mirrored                        : RefCell<Option<WeakLayer>>,
     mem_mark                        : Rc<()>,
 }
 
@@ -344,10 +345,11 @@ impl LayerModel {
         let sublayers                       = Sublayers::new(Logger::new_sub(&logger,"registry"));
         let mask                            = default();
         let scissor_box                     = default();
+        let mirrored                        = default();
         let mem_mark                        = default();
         Self {logger,camera,shape_system_registry,shape_system_to_symbol_info_map
              ,symbol_to_shape_system_map,elements,symbols_ordered,depth_order,depth_order_dirty
-             ,parents,global_element_depth_order,sublayers,mask,scissor_box,mem_mark}
+             ,parents,global_element_depth_order,sublayers,mask,scissor_box,mirrored,mem_mark}
     }
 
     /// Unique identifier of this layer. It is memory-based, it will be unique even for layers in
@@ -360,8 +362,14 @@ impl LayerModel {
     /// dependencies. Please note that this function does not update the depth-ordering of the
     /// elements. Updates are performed by calling the `update` method on [`Group`], which usually
     /// happens once per animation frame.
+    ///
+    /// If the layer mirrors another one (see [`set_mirrored`]), the symbols of the mirrored layer
+    /// are returned instead.
     pub fn symbols(&self) -> Vec<SymbolId> {
-        self.symbols_ordered.borrow().clone()
+        match self.mirrored() {
+            Some(layer) => layer.symbols(),
+            None        => self.symbols_ordered.borrow().clone(),
+        }
     }
 
     /// Return the [`SymbolId`] of the provided [`LayerItem`] if it was added to the current
@@ -645,6 +653,18 @@ impl LayerModel {
         *self.scissor_box.borrow_mut() = scissor_box.cloned();
     }
 
+    /// The layer mirrored by this layer, if any.
+    pub fn mirrored(&self) -> Option<Layer> {
+        self.mirrored.borrow().as_ref().and_then(|t|t.upgrade())
+    }
+
+    /// Make this layer render the symbols of the `source` layer with its own camera and
+    /// [`ScissorBox`], or stop mirroring when `None` is given. It allows displaying the same
+    /// shapes in several viewports at once, without duplicating their instances.
+    pub fn set_mirrored(&self, source:Option<&Layer>) {
+        *self.mirrored.borrow_mut() = source.map(|t|t.downgrade());
+    }
+
     /// Add depth-order dependency between two [`LayerItem`]s in this layer. Returns `true`
     /// if the dependency was inserted successfully (was not already present), and `false`
     /// otherwise. All sublayers will inherit these rules.
@@ -66,6 +66,8 @@ pub struct World {
     main_loop_frame : callback::Handle,
     on_before_frame : callback::SharedRegistryMut1<animation::TimeInfo>,
     on_after_frame  : callback::SharedRegistryMut1<animation::TimeInfo>,
+    #[cfg(feature = "snapshots")]
+    snapshots       : Snapshots,
 }
 
 impl World {
@@ -82,6 +84,8 @@ impl World {
         let stats_monitor   = stats::Monitor::new(&stats);
         let on_before_frame = <callback::SharedRegistryMut1<animation::TimeInfo>>::new();
         let on_after_frame  = <callback::SharedRegistryMut1<animation::TimeInfo>>::new();
+        #[cfg(feature = "snapshots")]
+        let snapshots       = Snapshots::default();
         let main_loop_frame = main_loop.on_frame(
             f!([stats_monitor,on_before_frame,on_after_frame,uniforms,scene_dirty,scene]
             (t:animation::TimeInfo) {
@@ -99,7 +103,9 @@ impl World {
         );
 
         Self {logger,scene,scene_dirty,main_loop,uniforms,stats,stats_monitor,main_loop_frame
-             ,on_before_frame,on_after_frame}.init()
+             ,on_before_frame,on_after_frame
+             ,#[cfg(feature = "snapshots")] snapshots
+             }.init()
     }
 
     fn init(self) -> Self {
@@ -142,6 +148,9 @@ impl World {
             .add(SymbolsRenderPass::new(&logger,&self.scene,self.scene.symbols(),&self.scene.layers))
             .add(ScreenRenderPass::new(&self.scene))
             .add(pixel_read_pass);
+        // Reading the whole image is needed only by the snapshot tests.
+        #[cfg(feature = "snapshots")]
+        let pipeline = pipeline.add(SnapshotPass::new(&self.snapshots));
         self.scene.renderer.set_pipeline(pipeline);
     }
 
@@ -168,6 +177,17 @@ impl World {
         self.on_after_frame.add(move |time:&animation::TimeInfo| callback(*time))
     }
 
+    /// Render the scene immediately and read the rendered image. Used by the snapshot tests, as
+    /// it does not wait for the next animation frame. Returns `None` if the pipeline was not run,
+    /// for example because the scene has zero size. Available with the `snapshots` feature only.
+    #[cfg(feature = "snapshots")]
+    pub fn snapshot(&self) -> Option<Snapshot> {
+        self.snapshots.request();
+        self.scene.update(default());
+        self.scene.renderer.run();
+        self.snapshots.take()
+    }
+
     /// Keeps the world alive even when all references are dropped. Use only if you want to keep one
     /// instance of the world forever.
     pub fn keep_alive_forever(&self) {
@@ -13,6 +13,11 @@ use crate::display;
 use crate::frp;
 use crate::gui::style::*;
 
+pub mod registry;
+
+pub use registry::StyleRegistry;
+pub use registry::StyleRequest;
+
 
 
 // =================
@@ -27,6 +32,8 @@ pub const SIDES_PADDING      : f32 = PADDING * 2.0;
 const DEFAULT_COLOR      : color::Lcha = color::Lcha::new(0.7,0.0,0.0,0.5);
 const TEXT_CURSOR_COLOR  : color::Lcha = color::Lcha::new(0.8,0.0,0.0,0.7);
 const FADE_OUT_TIME      : f32 = 3000.0;
+/// Name of the style request set by the `set_style` input.
+pub const BASE_STYLE_REQUEST : &str = "base";
 
 #[allow(non_snake_case)]
 fn DEFAULT_SIZE() -> Vector2<f32> { Vector2(16.0,16.0) }
@@ -156,10 +163,18 @@ pub mod shape {
 
 crate::define_endpoints! {
     Input {
-        set_style (Style),
+        /// Set the style of the lowest priority. It is a shortcut for registering a request named
+        /// `BASE_STYLE_REQUEST` with `i32::MIN` priority.
+        set_style           (Style),
+        /// Register a named style request, replacing the previous request of the same name. All
+        /// the registered requests are arbitrated by `StyleRegistry` into the cursor style.
+        set_style_request   (StyleRequest),
+        unset_style_request (String),
     }
 
     Output {
+        /// The style resulting from all the registered requests.
+        style                 (Style),
         position              (Vector3),
         screen_position       (Vector3),
         scene_position        (Vector3),
@@ -184,6 +199,7 @@ pub struct CursorModel {
     pub view           : shape::View,
     pub port_selection : shape::View,
     pub style          : Rc<RefCell<Style>>,
+    pub registry       : StyleRegistry,
 }
 
 impl CursorModel {
@@ -195,6 +211,7 @@ impl CursorModel {
         let view           = shape::View::new(&logger);
         let port_selection = shape::View::new(&logger);
         let style          = default();
+        let registry       = default();
 
         display_object.add_child(&view);
         display_object.add_child(&port_selection);
@@ -209,7 +226,7 @@ impl CursorModel {
             shape_sys.shape_system.set_pointer_events(false);
         }
 
-        Self {logger,scene,display_object,view,port_selection,style}
+        Self {logger,scene,display_object,view,port_selection,style,registry}
     }
 
     fn for_each_view(&self, f:impl Fn(&shape::View)) {
@@ -283,6 +300,22 @@ impl Cursor {
         let fade_in_spring  = inactive_fade.spring();
 
         frp::extend! { network
+
+            // === Style Arbitration ===
+
+            base_request <- frp.set_style.map(|style|
+                StyleRequest::new(BASE_STYLE_REQUEST,i32::MIN,style.clone())
+            );
+            request      <- any(&frp.set_style_request,&base_request);
+            eval request                 ((request) model.registry.set(request.clone()));
+            eval frp.unset_style_request ((name)    model.registry.unset(name));
+            style_change <- any_(&request,&frp.unset_style_request);
+            style        <- style_change.map(f_!(model.registry.style()));
+            frp.source.style <+ style;
+
+
+            // === Shape ===
+
             eval press.value  ((v) model.for_each_view(|vw| vw.press.set(*v)));
             eval radius.value ((v) model.for_each_view(|vw| vw.radius.set(*v)));
             eval size.value   ([model] (v) {
@@ -302,7 +335,7 @@ impl Cursor {
                 color::Rgba::new(color.red,color.green,color.blue,color.alpha*w)
             });
 
-            eval frp.set_style([host_attached_weight,size,offset,model] (new_style) {
+            eval style([host_attached_weight,size,offset,model] (new_style) {
                 host_attached_weight.stop_and_rewind();
                 if new_style.host.is_some() { host_attached_weight.start() }
 
@@ -365,16 +398,16 @@ impl Cursor {
                 *model.style.borrow_mut() = new_style.clone();
             });
 
-            port_selection_layer_weight.target <+ frp.set_style.map(|new_style| {
+            port_selection_layer_weight.target <+ style.map(|new_style| {
                 let val_opt = new_style.port_selection_layer.as_ref().and_then(|t| t.value);
                 let val     = val_opt.unwrap_or(false);
                 if val {1.0} else {0.0}
             });
-            port_selection_layer_weight.skip <+ frp.set_style.filter(|new_style|
+            port_selection_layer_weight.skip <+ style.filter(|new_style|
                 new_style.port_selection_layer.as_ref().map_or(false, |t| !t.animate)
             ).constant(());
 
-            host_changed    <- any_(frp.set_style,scene.frp.camera_changed);
+            host_changed    <- any_(style,scene.frp.camera_changed);
             hosted_position <- host_changed.map(f_!(model.style.borrow().host_position()));
             is_not_hosted   <- hosted_position.map(|p| p.is_none());
             mouse_pos_rt    <- mouse.position.gate(&is_not_hosted);
@@ -432,11 +465,11 @@ impl Cursor {
 
             // === Fade-out when not active ===
 
-            action_event           <- any_(&mouse.position,&frp.set_style);
+            action_event           <- any_(&mouse.position,&style);
             action_time            <- scene.frp.frame_time.sample(&action_event);
             time_since_last_action <- scene.frp.frame_time.map2(&action_time,|t,s|t-s);
             check_fade_time        <- time_since_last_action.gate(&mouse.ever_moved);
-            _eval <- check_fade_time.map2(&frp.set_style, f!([inactive_fade](time,style) {
+            _eval <- check_fade_time.map2(&style, f!([inactive_fade](time,style) {
                 if *time > FADE_OUT_TIME && style.is_default() {
                     inactive_fade.set_spring(fade_out_spring);
                     inactive_fade.set_target_value(0.0)
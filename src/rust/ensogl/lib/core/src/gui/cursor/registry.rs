@@ -0,0 +1,99 @@
+//! Registry of named cursor style requests. Components register their styles under unique names
+//! and the registry arbitrates them into a single style applied to the cursor.
+
+use crate::prelude::*;
+
+use super::Style;
+
+
+
+// ====================
+// === StyleRequest ===
+// ====================
+
+/// A named request to apply a cursor style. Requests with higher priority take precedence. Fields
+/// not provided by a request are filled in by the requests with lower priorities.
+#[derive(Clone,Debug,Default,PartialEq)]
+#[allow(missing_docs)]
+pub struct StyleRequest {
+    pub name     : String,
+    pub priority : i32,
+    pub style    : Style,
+}
+
+impl StyleRequest {
+    /// Constructor.
+    pub fn new(name:impl Into<String>, priority:i32, style:Style) -> Self {
+        let name = name.into();
+        Self {name,priority,style}
+    }
+}
+
+
+
+// =====================
+// === StyleRegistry ===
+// =====================
+
+/// Set of the currently active style requests. The arbitrated style does not depend on the order
+/// in which the requests were registered: they are folded by descending priority, and requests of
+/// equal priority by their names.
+#[derive(Clone,CloneRef,Debug,Default)]
+pub struct StyleRegistry {
+    requests : Rc<RefCell<HashMap<String,StyleRequest>>>,
+}
+
+impl StyleRegistry {
+    /// Register the request, replacing the previous request of the same name.
+    pub fn set(&self, request:StyleRequest) {
+        self.requests.borrow_mut().insert(request.name.clone(),request);
+    }
+
+    /// Remove the request of the given name, if registered.
+    pub fn unset(&self, name:&str) {
+        self.requests.borrow_mut().remove(name);
+    }
+
+    /// The style resulting from all the registered requests.
+    pub fn style(&self) -> Style {
+        let requests   = self.requests.borrow();
+        let mut sorted = requests.values().collect_vec();
+        sorted.sort_by(|a,b| b.priority.cmp(&a.priority).then_with(|| a.name.cmp(&b.name)));
+        let mut style = Style::default();
+        for request in sorted { style.concat_mut(&request.style) }
+        style
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn higher_priority_takes_precedence() {
+        let registry = StyleRegistry::default();
+        let pressed  = Style::new_press();
+        let released = Style::new_with_all_fields_default();
+        registry.set(StyleRequest::new("low",0,released.clone()));
+        registry.set(StyleRequest::new("high",1,pressed.clone()));
+        assert_eq!(registry.style().press,pressed.press);
+        registry.unset("high");
+        assert_eq!(registry.style(),released);
+    }
+
+    #[test]
+    fn equal_priorities_are_ordered_by_name() {
+        let registry = StyleRegistry::default();
+        let pressed  = Style::new_press();
+        let released = Style::new_with_all_fields_default();
+        registry.set(StyleRequest::new("b",0,released));
+        registry.set(StyleRequest::new("a",0,pressed.clone()));
+        assert_eq!(registry.style().press,pressed.press);
+    }
+}
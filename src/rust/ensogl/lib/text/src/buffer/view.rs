@@ -205,6 +205,27 @@ impl ViewBuffer {
         group
     }
 
+    /// Selections covering the rectangle spanned by `start` and `end`, one per line. The columns
+    /// are snapped to the line ends. The ids of the current selections are reused, so the
+    /// selections are not re-created when the rectangle is being resized.
+    fn rectangular_selection(&self, start:Location, end:Location) -> selection::Group {
+        let last_line  = std::cmp::max(start.line,end.line).min(self.last_line_index());
+        let first_line = std::cmp::min(start.line,end.line);
+        let mut ids    = self.selection.borrow().iter().map(|s| s.id).collect_vec().into_iter();
+        (first_line.as_usize() ..= last_line.as_usize()).map(|line| {
+            let line       = Line::from(line);
+            let end_column = self.line_end_column(line).unwrap_or_default();
+            let sel_start  = Location(line,start.column.min(end_column));
+            let sel_end    = Location(line,end.column.min(end_column));
+            let id         = ids.next().unwrap_or_else(|| {
+                let id = self.next_selection_id.get();
+                self.next_selection_id.set(id+1);
+                id
+            });
+            Selection::new(sel_start,sel_end,id)
+        }).collect()
+    }
+
     /// Insert new text in the place of current selections / cursors.
     fn insert(&self, text:impl Into<Text>) -> Modification {
         self.modify(text,None)
@@ -343,6 +364,8 @@ ensogl_core::define_endpoints! {
         add_cursor                 (Location),
         set_newest_selection_end   (Location),
         set_oldest_selection_end   (Location),
+        /// Replace all selections with a rectangular (block) selection spanned by two locations.
+        set_rectangular_selection  (Location,Location),
         insert                     (String),
         paste                      (Vec<String>),
         remove_all_cursors         (),
@@ -437,6 +460,9 @@ impl View {
             sel_on_add_cursor        <- input.add_cursor.map(f!((t) m.add_cursor(*t)));
             sel_on_set_newest_end    <- input.set_newest_selection_end.map(f!((t) m.set_newest_selection_end(*t)));
             sel_on_set_oldest_end    <- input.set_oldest_selection_end.map(f!((t) m.set_oldest_selection_end(*t)));
+            sel_on_set_rectangular   <- input.set_rectangular_selection.map(f!(((start,end))
+                m.rectangular_selection(*start,*end)
+            ));
 
             sel_on_remove_all <- input.remove_all_cursors.map(|_| default());
             sel_on_undo       <= input.undo.map(f_!(m.undo()));
@@ -463,6 +489,7 @@ impl View {
             output.source.selection_non_edit_mode <+ sel_on_add_cursor;
             output.source.selection_non_edit_mode <+ sel_on_set_newest_end;
             output.source.selection_non_edit_mode <+ sel_on_set_oldest_end;
+            output.source.selection_non_edit_mode <+ sel_on_set_rectangular;
             output.source.selection_non_edit_mode <+ sel_on_remove_all;
 
             eval output.source.selection_edit_mode     ((t) m.set_selection(t));
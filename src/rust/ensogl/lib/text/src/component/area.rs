@@ -25,7 +25,6 @@ use ensogl_core::data::color;
 use ensogl_core::display::shape::*;
 use ensogl_core::display;
 use ensogl_core::gui::cursor;
-use ensogl_core::system::web::clipboard;
 use std::ops::Not;
 
 
@@ -41,6 +40,42 @@ const LINE_VERTICAL_OFFSET     : f32 = 4.0; // Set manually. May depend on font.
 
 
 
+// ===================
+// === Copy / Paste ===
+// ===================
+
+/// Join the copied selections into a single text, separating them with `RECORD_SEPARATOR`, so they
+/// can be pasted into subsequent selections. See `decode_paste`.
+pub fn encode_copy(selections:&[String]) -> String {
+    match selections {
+        []  => "".to_string(),
+        [s] => s.clone(),
+        lst => lst.join(RECORD_SEPARATOR),
+    }
+}
+
+/// Split the pasted (or dropped) text into chunks separated by `RECORD_SEPARATOR` and sanitize
+/// them. Windows line endings are normalized and control characters other than new lines and tabs
+/// are removed, as text coming from other applications often contains them.
+pub fn decode_paste(encoded:&str) -> Vec<String> {
+    encoded.split(RECORD_SEPARATOR).map(sanitize_pasted_chunk).collect()
+}
+
+fn sanitize_pasted_chunk(chunk:&str) -> String {
+    let chunk = chunk.replace("\r\n","\n");
+    chunk.chars().filter(|c| !c.is_control() || *c == '\n' || *c == '\t').collect()
+}
+
+/// Split the pasted text into single-line chunks. Empty lines are skipped and lines are trimmed.
+/// Used when every line of the pasted text is meant to become a separate item.
+pub fn decode_paste_lines(encoded:&str) -> Vec<String> {
+    let chunks = decode_paste(encoded);
+    let lines  = chunks.iter().flat_map(|chunk| chunk.lines()).map(|line| line.trim());
+    lines.filter(|line| !line.is_empty()).map(|line| line.into()).collect()
+}
+
+
+
 // ====================
 // === SelectionMap ===
 // ====================
@@ -178,6 +213,12 @@ ensogl_core::define_endpoints! {
         start_newest_selection_end_follow_mouse(),
         /// Stop changing the shape of the newest selection with the mouse position.
         stop_newest_selection_end_follow_mouse(),
+        /// Start a rectangular (block) selection at the mouse position. While active, the
+        /// selection spans from the start position to the current mouse position, with one
+        /// selection per line.
+        start_rectangular_selection(),
+        /// Stop changing the shape of the rectangular selection with the mouse position.
+        stop_rectangular_selection(),
         /// Move the cursor to the left by one character.
         cursor_move_left(),
         /// Move the cursor to the right by one character.
@@ -251,6 +292,8 @@ ensogl_core::define_endpoints! {
         paste_string          (String),
         insert                (String),
         set_color_bytes       (buffer::Range<Bytes>,color::Rgba),
+        /// Replace all the cursors with a single selection of the given byte range.
+        select_bytes          (buffer::Range<Bytes>),
         set_color_all         (color::Rgba),
         set_default_color     (color::Rgba),
         set_selection_color   (color::Rgb),
@@ -258,13 +301,15 @@ ensogl_core::define_endpoints! {
         set_content           (String),
     }
     Output {
-        pointer_style   (cursor::Style),
-        width           (f32),
-        height          (f32),
-        changed         (Vec<buffer::view::Change>),
-        content         (Text),
-        hovered         (bool),
-        selection_color (color::Rgb),
+        pointer_style    (cursor::Style),
+        width            (f32),
+        height           (f32),
+        changed          (Vec<buffer::view::Change>),
+        content          (Text),
+        hovered          (bool),
+        selection_color  (color::Rgb),
+        /// Locations of all cursors, i.e. the ends of all selections, in the text order.
+        cursor_locations (Vec<Location>),
     }
 }
 
@@ -301,15 +346,16 @@ impl Area {
     }
 
     fn init(self) -> Self {
-        let network  = &self.frp.network;
-        let model    = &self.data;
-        let scene    = model.app.display.scene();
-        let mouse    = &scene.mouse.frp;
-        let input    = &self.frp.input;
-        let out      = &self.frp.output;
-        let pos      = DEPRECATED_Animation :: <Vector2> :: new(network);
-        let keyboard = &scene.keyboard;
-        let m        = &model;
+        let network   = &self.frp.network;
+        let model     = &self.data;
+        let scene     = model.app.display.scene();
+        let mouse     = &scene.mouse.frp;
+        let input     = &self.frp.input;
+        let out       = &self.frp.output;
+        let pos       = DEPRECATED_Animation :: <Vector2> :: new(network);
+        let keyboard  = &scene.keyboard;
+        let clipboard = frp::io::Clipboard::new(network);
+        let m         = &model;
         pos.update_spring(|spring| spring*2.0);
 
         frp::extend! { network
@@ -346,6 +392,13 @@ impl Area {
 
             eval loc_on_set_cursor ((loc) m.buffer.frp.set_cursor(loc));
             eval loc_on_add_cursor ((loc) m.buffer.frp.add_cursor(loc));
+            eval input.select_bytes ([m](range) {
+                let text  = m.buffer.text();
+                let start = text.location_of_byte_offset_snapped(range.start);
+                let end   = text.location_of_byte_offset_snapped(range.end);
+                m.buffer.frp.set_cursor(start);
+                m.buffer.frp.set_newest_selection_end(end);
+            });
 
             _eval <- m.buffer.frp.selection_edit_mode.map2
                 (&scene.frp.frame_time,f!([m](selections,time) {
@@ -380,6 +433,36 @@ impl Area {
             });
 
 
+            // === Rectangular Selection ===
+
+            // The start is cleared when the selection stops, so the next one never begins with
+            // the stale rectangle.
+            rect_selecting   <- bool
+                ( &input.stop_rectangular_selection
+                , &input.start_rectangular_selection
+                );
+            rect_start_pos   <- mouse.position.sample(&input.start_rectangular_selection);
+            rect_start_set   <- rect_start_pos.map(f!((p) Some(m.get_in_text_location(*p))));
+            rect_start_reset <- input.stop_rectangular_selection.constant(None);
+            rect_start       <- any(&rect_start_set,&rect_start_reset);
+            rect_current_pos <- mouse.position.gate(&rect_selecting);
+            rect_end_pos     <- any(&rect_start_pos,&rect_current_pos);
+            rect_end         <- rect_end_pos.map(f!((p) m.get_in_text_location(*p)));
+            rect_selection   <- rect_end.map2(&rect_start,|end,start| Some(((*start)?,*end)));
+            rect_selection   <- rect_selection.unwrap();
+            eval rect_selection (((start,end)) m.buffer.frp.set_rectangular_selection(start,end));
+
+
+            // === Cursor Locations ===
+
+            let sel_edit     = m.buffer.frp.selection_edit_mode.clone_ref();
+            let sel_non_edit = m.buffer.frp.selection_non_edit_mode.clone_ref();
+            any_selection   <- any(&sel_edit,&sel_non_edit);
+            out.source.cursor_locations <+ any_selection.map(|group|
+                group.iter().map(|sel| sel.end).collect_vec()
+            );
+
+
             // === Copy / Cut / Paste ===
 
             copy_sels      <- input.copy.map(f_!(m.buffer.selections_contents()));
@@ -390,7 +473,7 @@ impl Area {
             non_line_sel_mode_sels <- copy_sels.gate_not(&all_empty_sels);
             line_sel_mode_sels     <- line_sel_mode.map(f_!(m.buffer.selections_contents()));
             sels                   <- any(&line_sel_mode_sels,&non_line_sel_mode_sels);
-            eval sels ((s) m.copy(s));
+            clipboard.copy <+ sels.map(|s| encode_copy(s));
 
             cut_sels           <- input.cut.map(f_!(m.buffer.selections_contents()));
             all_empty_sels_cut <- cut_sels.map(|s|s.iter().all(|t|t.is_empty()));
@@ -400,10 +483,11 @@ impl Area {
             non_line_sel_mode_cut_sels <- cut_sels.gate_not(&all_empty_sels_cut);
             line_sel_mode_cut_sels     <- line_sel_mode_cut.map(f_!(m.buffer.selections_contents()));
             sels_cut                   <- any(&line_sel_mode_cut_sels,&non_line_sel_mode_cut_sels);
-            eval sels_cut ((s) m.cut(s));
+            clipboard.copy <+ sels_cut.map(|s| encode_copy(s));
             eval_ sels_cut (m.buffer.frp.delete_left());
 
-            eval_ input.paste (m.paste());
+            clipboard.request_paste <+ input.paste;
+            input.paste_string      <+ clipboard.paste;
             eval input.paste_string((s) m.paste_string(s));
 
 
@@ -784,24 +868,6 @@ impl AreaModel {
         line
     }
 
-    fn copy(&self, selections:&[String]) {
-        let encoded = match selections {
-            []  => "".to_string(),
-            [s] => s.clone(),
-            lst => lst.join(RECORD_SEPARATOR),
-        };
-        clipboard::write_text(encoded);
-    }
-
-    fn cut(&self, selections:&[String]) {
-        self.copy(selections);
-    }
-
-    fn paste(&self) {
-        let paste_string = self.frp_endpoints.input.paste_string.clone_ref();
-        clipboard::read_text(move |t| paste_string.emit(t));
-    }
-
     /// Paste new text in the place of current selections / cursors. In case of pasting multiple
     /// chunks (e.g. after copying multiple selections), the chunks will be pasted into subsequent
     /// selections. In case there are more chunks than selections, end chunks will be dropped. In
@@ -809,7 +875,7 @@ impl AreaModel {
     /// strings. I `self.single_line` is set to true then each chunk will be truncated to its first
     /// line.
     fn paste_string(&self, s: &str) {
-        let mut chunks = self.decode_paste(s);
+        let mut chunks = decode_paste(s);
         if self.single_line.get() {
             for f in &mut chunks {
                 Self::drop_all_but_first_line(f);
@@ -818,10 +884,6 @@ impl AreaModel {
         self.buffer.frp.paste(chunks);
     }
 
-    fn decode_paste(&self, encoded:&str) -> Vec<String> {
-        encoded.split(RECORD_SEPARATOR).map(|s|s.into()).collect()
-    }
-
     fn drop_all_but_first_line(s: &mut String) {
         *s = s.lines().next().unwrap_or("").to_string();
     }
@@ -897,6 +959,8 @@ impl application::View for Area {
           , (Press          , "cmd left-mouse-button"   , "add_cursor_at_mouse_position")
           , (Press          , "cmd left-mouse-button"   , "start_newest_selection_end_follow_mouse")
           , (Release        , "cmd left-mouse-button"   , "stop_newest_selection_end_follow_mouse")
+          , (Press          , "alt left-mouse-button"   , "start_rectangular_selection")
+          , (Release        , "alt left-mouse-button"   , "stop_rectangular_selection")
           , (Press          , "cmd a"                   , "select_all")
           , (Press          , "cmd c"                   , "copy")
           , (Press          , "cmd x"                   , "cut")
@@ -254,6 +254,32 @@ define_themes! { [light:0, dark:1]
             }
         }
 
+        frp_inspector {
+            width        = 420.0 , 420.0;
+            list_height  = 320.0 , 320.0;
+            trace_height = 240.0 , 240.0;
+            padding      = 12.0  , 12.0;
+            background   = Rgba(0.992,0.996,1.0,1.0)    , Rgba(0.182,0.188,0.196,1.0);
+            text         = Rgba(0.439,0.439,0.439,1.0)  , Rgba(0.808,0.808,0.808,1.0);
+            highlight    = Rgba(0.98,0.584,0.122,0.8)   , Rgba(0.98,0.584,0.122,0.8);
+        }
+
+        command_palette {
+            width       = 480.0 , 480.0;
+            list_height = 280.0 , 280.0;
+            doc_height  = 48.0  , 48.0;
+            padding     = 12.0  , 12.0;
+            background  = Rgba(0.992,0.996,1.0,1.0)    , Rgba(0.182,0.188,0.196,1.0);
+            text        = Rgba(0.439,0.439,0.439,1.0)  , Rgba(0.808,0.808,0.808,1.0);
+        }
+
+        shortcut_cheat_sheet {
+            width      = 640.0 , 640.0;
+            padding    = 16.0  , 16.0;
+            background = Rgba(0.992,0.996,1.0,1.0)    , Rgba(0.182,0.188,0.196,1.0);
+            text       = Rgba(0.439,0.439,0.439,1.0)  , Rgba(0.808,0.808,0.808,1.0);
+        }
+
         window_control_buttons {
             radius  = 6.5, 6.5;
             spacing = application::window_control_buttons::radius, application::window_control_buttons::radius;
@@ -331,6 +357,8 @@ define_themes! { [light:0, dark:1]
         types {
             hue_steps     = 512.0 , 512.0;
             hue_shift     = 0.0, 0.0;
+            // Non-zero value enables the color-blind-safe palette of type colors.
+            color_blind_safe = 0.0 , 0.0;
             lightness     = 0.72 , 0.7;
             chroma        = 0.7 , 0.4;
             any           = code::syntax::base , code::syntax::base;
@@ -384,6 +412,11 @@ define_themes! { [light:0, dark:1]
                 added     = Lcha::green(0.8,1.0), Lcha::green(0.8,1.0);
                 edited    = Lcha::yellow(0.9,1.0), Lcha::yellow(0.9,1.0);
             }
+            color_override {
+                background_mix = 0.3 , 0.3;
+                port_mix       = 0.6 , 0.6;
+                edge_mix       = 0.6 , 0.6;
+            }
             error {
                 dataflow     = Rgba(1.0,0.655,0.141,1.0), Rgba(1.0,0.655,0.141,1.0);
                 panic        = Rgba(1.0,0.341,0.125,1.0), Rgba(1.0,0.341,0.125,1.0);
@@ -402,6 +435,29 @@ define_themes! { [light:0, dark:1]
             type_label {
                 offset_y = -23.0, -23.0;
             }
+            removal_preview {
+                removed  = Rgba(1.0,0.341,0.125,1.0), Rgba(1.0,0.341,0.125,1.0);
+                affected = Rgba(1.0,0.655,0.141,1.0), Rgba(1.0,0.655,0.141,1.0);
+            }
+            port {
+                remove_button {
+                    background = Rgba(1.0,0.341,0.125,1.0) , Rgba(1.0,0.341,0.125,1.0);
+                    cross      = Rgba(1.0,1.0,1.0,1.0)     , Rgba(1.0,1.0,1.0,1.0);
+                }
+            }
+            focus_ring = Rgba(0.306,0.647,0.992,1.0), Rgba(0.306,0.647,0.992,1.0);
+            pin = Lcha(0.0,0.0,0.0,0.5) , Lcha(1.0,0.0,0.0,0.5);
+            execution_status {
+                pending = Lcha(0.0,0.0,0.0,0.25), Lcha(1.0,0.0,0.0,0.25);
+                running = Rgba(0.306,0.647,0.992,1.0), Rgba(0.306,0.647,0.992,1.0);
+                cached  = Lcha(0.0,0.0,0.0,0.35), Lcha(1.0,0.0,0.0,0.35);
+                failed  = Rgba(1.0,0.341,0.125,1.0), Rgba(1.0,0.341,0.125,1.0);
+            }
+            badge {
+                background = Lcha(0.0,0.0,0.0,0.1) , Lcha(1.0,0.0,0.0,0.15);
+                active     = Rgba(0.306,0.647,0.992,0.6), Rgba(0.306,0.647,0.992,0.6);
+                text       = Lcha(0.0,0.0,0.0,0.7) , Lcha(1.0,0.0,0.0,0.7);
+            }
         }
         visualization {
             background = graph_editor::node::background , graph_editor::node::background;
@@ -411,6 +467,10 @@ define_themes! { [light:0, dark:1]
                 dataflow.text = Rgba(1.0,0.655,0.141,1.0), Rgba(1.0,0.655,0.141,1.0);
                 panic.text    = Rgba(1.0,0.341,0.125,1.0), Rgba(1.0,0.341,0.125,1.0);
             }
+            watchdog {
+                throttled = Rgba(1.0,0.655,0.141,1.0), Rgba(1.0,0.655,0.141,1.0);
+                suspended = Rgba(1.0,0.341,0.125,1.0), Rgba(1.0,0.341,0.125,1.0);
+            }
             action_bar {
                 // Original RGB values (for reference after fixing color-conversion issues)
                 // rgb(237 240 243)
@@ -426,6 +486,15 @@ define_themes! { [light:0, dark:1]
                 offset = 0.0 , 0.0;
             }
         }
+        vis_panel {
+            close_button {
+                background = Lcha(0.0,0.0,0.0,0.5) , Lcha(1.0,0.0,0.0,0.5);
+                cross      = Rgba(1.0,1.0,1.0,1.0) , Rgba(0.0,0.0,0.0,1.0);
+            }
+            drag_handle {
+                background = Lcha(0.0,0.0,0.0,0.2) , Lcha(1.0,0.0,0.0,0.2);
+            }
+        }
         breadcrumbs {
             full        = Lcha(0.0,0.0,0.0,0.7) , Lcha(1.0,0.0,0.0,0.7);
             transparent = Lcha(0.0,0.0,0.0,0.4) , Lcha(1.0,0.0,0.0,0.4);
@@ -449,11 +518,34 @@ define_themes! { [light:0, dark:1]
                 }
             }
         }
+        execution_order {
+            text     = Lcha(0.0,0.0,0.0,0.5)    , Lcha(1.0,0.0,0.0,0.5);
+            early    = Lcha(0.72,0.5,0.38,1.0)  , Lcha(0.7,0.4,0.38,1.0);
+            late     = Lcha(0.72,0.5,0.07,1.0)  , Lcha(0.7,0.4,0.07,1.0);
+            edge_mix = 0.35 , 0.35;
+        }
+        debug_overlay {
+            background = Rgba(0.992,0.996,1.0,0.9)   , Rgba(0.182,0.188,0.196,0.9);
+            text       = Rgba(0.439,0.439,0.439,1.0) , Rgba(0.808,0.808,0.808,1.0);
+        }
+        alignment_guide {
+            color = Rgba(0.0,0.478,1.0,0.6) , Rgba(0.302,0.639,1.0,0.6);
+        }
         edge {
             split {
                 lightness_factor = 1.2 , 0.2;
                 chroma_factor    = 0.8 , 1.0;
             }
+            removal_preview = Rgba(1.0,0.341,0.125,1.0), Rgba(1.0,0.341,0.125,1.0);
+            highlight {
+                lightness_factor = 0.8 , 1.25;
+            }
+            pulse {
+                lightness_factor = 0.6 , 1.6;
+            }
+            declutter {
+                faded_alpha = 0.15 , 0.15;
+            }
         }
         profiling_button {
             non_toggled     = graph_editor::node::actions::button::non_toggled
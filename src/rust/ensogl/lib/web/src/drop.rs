@@ -1,5 +1,6 @@
-//! The handlers for the files dropped on the web scene. The main object is [`Manager`]:
-//! it notifies about new files, and their metadata and with methods for reading them.
+//! The handlers for the files and text dropped on the web scene. The main object is [`Manager`]:
+//! it notifies about new files, and their metadata and with methods for reading them, and about
+//! plain-text snippets dragged from other applications.
 
 use crate::prelude::*;
 
@@ -84,13 +85,14 @@ type DragOverClosure = Closure<dyn Fn(web_sys::DragEvent) -> bool>;
 /// The Manager of dropped files.
 ///
 /// It adds listeners for drag and drop events to the target passed during construction. It provides
-/// the single frp endpoints emitting a signal when a file is dropped.
+/// the frp endpoints emitting a signal when files or a plain-text snippet are dropped.
 // NOTE[allow_dead] We allow dead fields here, because they keep living closures and network.
 #[derive(Clone,CloneRef,Debug)]
 pub struct Manager {
     #[allow(dead_code)]
     network            : frp::Network,
     files_received     : frp::Source<Vec<File>>,
+    text_received      : frp::Source<String>,
     #[allow(dead_code)]
     drop_callback      : Rc<DropClosure>,
     #[allow(dead_code)]
@@ -105,13 +107,14 @@ impl Manager {
         let network = frp::Network::new("DropFileManager");
         frp::extend! { network
             files_received <- source();
+            text_received  <- source();
         }
 
         let drop:DropClosure = Closure::wrap(Box::new(
-            f!([logger,files_received](event:web_sys::DragEvent) {
-                debug!(logger, "Dropped files.");
+            f!([logger,files_received,text_received](event:web_sys::DragEvent) {
+                debug!(logger, "Dropped data.");
                 event.prevent_default();
-                Self::handle_drop_event(&logger,event,&files_received)
+                Self::handle_drop_event(&logger,event,&files_received,&text_received)
             })
         ));
         // To mark element as a valid drop target, the `dragover` event handler should return
@@ -128,16 +131,32 @@ impl Manager {
         target.add_event_listener_with_callback("dragover",drag_over_js).unwrap();
         let drop_callback      = Rc::new(drop);
         let drag_over_callback = Rc::new(drag_over);
-        Self {network,files_received,drop_callback,drag_over_callback}
+        Self {network,files_received,text_received,drop_callback,drag_over_callback}
     }
 
     /// The frp endpoint emitting signal when a file is dropped.
     pub fn files_received(&self) -> &frp::Source<Vec<File>> { &self.files_received }
 
+    /// The frp endpoint emitting signal when a plain-text snippet (e.g. a text selection dragged
+    /// from another application) is dropped.
+    pub fn text_received(&self) -> &frp::Source<String> { &self.text_received }
+
     fn handle_drop_event
-    (logger:&Logger, event:web_sys::DragEvent, files_received:&frp::Source<Vec<File>>) {
-        let opt_files = event.data_transfer().and_then(|t| t.files());
-        if let Some(js_files) = opt_files {
+    ( logger         : &Logger
+    , event          : web_sys::DragEvent
+    , files_received : &frp::Source<Vec<File>>
+    , text_received  : &frp::Source<String>
+    ) {
+        let data_transfer = event.data_transfer();
+        let opt_files     = data_transfer.as_ref().and_then(|t| t.files());
+        let has_files     = opt_files.as_ref().map_or(false, |files| files.length() > 0);
+        if !has_files {
+            let opt_text = data_transfer.and_then(|t| t.get_data("text/plain").ok());
+            if let Some(text) = opt_text.filter(|text| !text.is_empty()) {
+                debug!(logger, "Dropped text.");
+                text_received.emit(text);
+            }
+        } else if let Some(js_files) = opt_files {
             let js_files_iter = (0..js_files.length()).filter_map(|i| js_files.get(i));
             let files_iter    = js_files_iter.filter_map(|f| match File::from_js_file(&f) {
                 Ok(file) => Some(file),
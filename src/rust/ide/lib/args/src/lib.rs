@@ -37,6 +37,8 @@ ensogl::read_args! {
         wasm_url               : String,
         wasm_glue_url          : String,
         node_labels            : bool,
+        audio_feedback         : bool,
+        deterministic_ids      : bool,
         crash_report_host      : String,
         data_gathering         : bool,
         is_in_cloud            : bool,
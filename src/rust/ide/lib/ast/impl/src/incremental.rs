@@ -0,0 +1,176 @@
+//! Incremental reparsing: updating an existing AST after a text change by parsing only the line
+//! affected by the change. All the nodes outside of the reparsed line are kept intact, together
+//! with their ids, so the views displaying them (like the graph editor) stay stable while the user
+//! types in a single node.
+
+use crate::prelude::*;
+
+use crate::crumbs::Crumbable;
+use crate::crumbs::TraversableAst;
+use crate::Crumbs;
+use crate::HasRepr;
+use crate::IdMap;
+use crate::Shape;
+
+use enso_data::text::Index;
+use enso_data::text::Size;
+use enso_data::text::Span;
+use enso_data::text::TextChange;
+
+
+
+// ==============
+// === Errors ===
+// ==============
+
+#[allow(missing_docs)]
+#[derive(Clone,Debug,Fail)]
+#[fail(display="The change of the span {:?} cannot be reparsed within a single line.",_0)]
+pub struct NoLineToReparse(pub Span);
+
+
+
+// ================
+// === Reparsed ===
+// ================
+
+/// The result of an incremental reparse.
+#[derive(Clone,Debug)]
+pub struct Reparsed {
+    /// The updated AST, equal to the result of parsing the whole changed code.
+    pub ast     : Ast,
+    /// The crumbs of the smallest subtree containing all the nodes affected by the change. The
+    /// nodes outside of this subtree are the same as in the AST before the change.
+    pub changed : Crumbs,
+}
+
+
+
+// ===============
+// === Reparse ===
+// ===============
+
+/// Apply the text `change` to the `ast` by reparsing the innermost module or block line containing
+/// the replaced span.
+///
+/// The `id_map` describes the ids in the code after the change, like the one passed to the parser
+/// when parsing the whole module. Only its entries lying within the reparsed line are used.
+/// The `parse_line` function should parse a single-line program using the given ids, e.g. with the
+/// parser's `parse_line_ast_with_id_map`.
+///
+/// Fails if the change does not fit a single line, or makes the line span multiple lines. The
+/// caller is expected to fall back to parsing the whole code in such case.
+pub fn reparse
+( ast        : &Ast
+, change     : &TextChange
+, id_map     : &IdMap
+, parse_line : impl FnOnce(String,IdMap) -> FallibleResult<Ast>
+) -> FallibleResult<Reparsed> {
+    let replaced      = change.replaced_span();
+    let no_line_error = || NoLineToReparse(replaced);
+    let new_code      = change.applied(&ast.repr());
+    let removed_size  = replaced.size.value;
+    let inserted_size = Size::from_text(change.inserted.as_str()).value;
+    let lines         = lines_containing(ast,&replaced)?;
+    let new_line_span = |span:Span| {
+        let size = span.size.value + inserted_size - removed_size;
+        Span::new(span.index,Size::new(size))
+    };
+    let single_line = |line:&&(Crumbs,Span)| {
+        let text = &new_code[new_line_span(line.1)];
+        !text.contains('\n')
+    };
+    let top_level_line = lines.first().filter(|(crumbs,_)| {
+        crumbs.len() == 1 && matches!(ast.shape(),Shape::Module(_))
+    });
+    let innermost_line = lines.iter().rev().find(single_line);
+    let (crumbs,span)  = innermost_line.or(top_level_line).ok_or_else(no_line_error)?;
+    let span           = new_line_span(*span);
+    let text           = new_code[span].to_string();
+    if text.trim() != text || text.is_empty() {
+        return Err(no_line_error().into())
+    }
+    let line_id_map = line_id_map(id_map,&span);
+    let old_line    = ast.get_traversing(crumbs)?;
+    let new_line    = parse_line(text,line_id_map)?;
+    let changed     = changed_subtree(old_line,&new_line)?;
+    let changed     = crumbs.iter().cloned().chain(changed).collect();
+    let ast         = ast.set_traversing(crumbs,new_line)?;
+    Ok(Reparsed {ast,changed})
+}
+
+/// The module and block lines containing the given span, together with their spans, from the
+/// outermost to the innermost.
+fn lines_containing(ast:&Ast, span:&Span) -> FallibleResult<Vec<(Crumbs,Span)>> {
+    let mut lines    = Vec::new();
+    let mut crumbs   = Crumbs::new();
+    let mut node     = ast;
+    let mut position = Index::new(0);
+    loop {
+        let mut found = None;
+        for crumb in node.iter_subcrumbs() {
+            let child_span  = node.span_of_child_at(&crumb)?;
+            let child_index = Index::new(position.value + child_span.index.value);
+            let child_span  = Span::new(child_index,child_span.size);
+            if child_span.index <= span.index && span.end() <= child_span.end() {
+                found = Some((crumb,child_span));
+                break
+            }
+        }
+        match found {
+            Some((crumb,child_span)) => {
+                let is_line = crumb.is_module() || crumb.is_block();
+                node     = node.get(&crumb)?;
+                position = child_span.index;
+                crumbs.push(crumb);
+                if is_line {
+                    lines.push((crumbs.clone(),child_span))
+                }
+            }
+            None => break Ok(lines)
+        }
+    }
+}
+
+/// The entries of the `id_map` lying within the `line` span, relative to the line beginning.
+fn line_id_map(id_map:&IdMap, line:&Span) -> IdMap {
+    let in_line = |(span,_):&&(Span,crate::Id)| line.contains_span(span);
+    let entries = id_map.vec.iter().filter(in_line).map(|(span,id)| {
+        let index = Index::new(span.index.value - line.index.value);
+        (Span::new(index,span.size),*id)
+    });
+    IdMap::new(entries.collect())
+}
+
+/// The crumbs of the smallest subtree of `old` which differs from `new`, found by descending as
+/// long as only a single child changed.
+fn changed_subtree(old:&Ast, new:&Ast) -> FallibleResult<Crumbs> {
+    let mut crumbs = Crumbs::new();
+    let mut old    = old;
+    let mut new    = new;
+    loop {
+        let old_crumbs = old.iter_subcrumbs().collect_vec();
+        if old_crumbs != new.iter_subcrumbs().collect_vec() {
+            break Ok(crumbs)
+        }
+        let mut changed = Vec::new();
+        for crumb in old_crumbs {
+            if old.get(&crumb)?.repr() != new.get(&crumb)?.repr() {
+                changed.push(crumb)
+            }
+        }
+        match changed.as_slice() {
+            [crumb] => {
+                let new_child      = new.get(crumb)?;
+                let only_the_child = old.set(crumb,new_child.clone())?.repr() == new.repr();
+                if !only_the_child {
+                    break Ok(crumbs)
+                }
+                old = old.get(crumb)?;
+                new = new_child;
+                crumbs.push(crumb.clone());
+            }
+            _ => break Ok(crumbs)
+        }
+    }
+}
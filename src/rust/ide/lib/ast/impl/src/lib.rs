@@ -11,6 +11,8 @@ pub mod crumbs;
 #[warn(missing_docs)]
 pub mod identifier;
 #[warn(missing_docs)]
+pub mod incremental;
+#[warn(missing_docs)]
 pub mod internal;
 #[warn(missing_docs)]
 pub mod known;
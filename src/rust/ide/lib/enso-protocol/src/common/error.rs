@@ -17,6 +17,12 @@ pub struct DeserializationError(pub String);
 #[fail(display = "Received a message that is neither a response nor a notification")]
 pub struct UnexpectedMessage;
 
+/// The connection was lost before the reply to the request was received. The request is not
+/// replayed after reconnecting, as the peer might have already processed it.
+#[derive(Debug,Fail,Clone,Copy)]
+#[fail(display = "Lost connection before receiving a reply.")]
+pub struct LostConnection;
+
 /// The error codes defined in Enso Protocol (see
 /// https://enso.org/docs/developer/enso/language-server/protocol-language-server.html#error)
 pub mod code {
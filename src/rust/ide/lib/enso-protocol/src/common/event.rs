@@ -3,7 +3,11 @@
 /// Event emitted by the RPC handler.
 #[derive(Debug)]
 pub enum Event<N> {
-    /// The handler's transport has been closed.
+    /// The handler's transport has been opened again after being closed, e.g. by reconnecting.
+    /// The session must be initialized again.
+    Opened,
+    /// The handler's transport has been closed. All the requests awaiting the reply fail with
+    /// the [`LostConnection`](crate::common::error::LostConnection) error.
     Closed,
     /// An error has occurred.
     Error(failure::Error),
@@ -4,6 +4,7 @@ use crate::prelude::*;
 
 use futures::channel::oneshot;
 
+use crate::common::error::LostConnection;
 use crate::common::error::NoSuchRequest;
 
 /// Stores active requests, i.e. the requests that were sent to the peer but are still awaiting
@@ -51,11 +52,12 @@ where Id:Copy + Debug + Display + Hash + Eq + Send + Sync + 'static {
     ///
     /// `f` is a function that must transform peer's reply into the request's returned value.
     /// Returns a `Future` that shall yield request result, once it is completed (or cancelled).
+    /// The cancelled requests yield the `LostConnection` error.
     pub fn open_new_request<F,R>(&mut self, id:Id, f:F) -> impl Future<Output=FallibleResult<R>>
     where F: FnOnce(Reply) -> FallibleResult<R> {
         let (sender, receiver) = oneshot::channel::<Reply>();
         let ret = receiver.map(move |result_or_cancel| {
-            let result = result_or_cancel?;
+            let result = result_or_cancel.map_err(|_| LostConnection)?;
             f(result)
         });
         self.insert_request(id, sender);
@@ -0,0 +1,93 @@
+//! Module defines the policy of reconnecting the lost connections to the backend.
+
+use crate::prelude::*;
+
+use std::time::Duration;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The delay before the first reconnecting attempt.
+pub const INITIAL_DELAY:Duration = Duration::from_millis(500);
+
+/// The maximum delay between the reconnecting attempts.
+pub const MAX_DELAY:Duration = Duration::from_secs(30);
+
+/// The factor by which the delay grows after each failed attempt.
+pub const DELAY_FACTOR:u32 = 2;
+
+
+
+// ===============
+// === Backoff ===
+// ===============
+
+/// The exponential backoff of the reconnecting attempts: the delay before each attempt is
+/// `DELAY_FACTOR` times longer than the previous one, up to `MAX_DELAY`, so the backend being
+/// restarted is not flooded with connection requests.
+#[derive(Clone,Copy,Debug)]
+pub struct Backoff {
+    initial_delay : Duration,
+    max_delay     : Duration,
+    attempt       : u32,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self::new(INITIAL_DELAY,MAX_DELAY)
+    }
+}
+
+impl Backoff {
+    /// Create a backoff with the given delay of the first attempt and the maximum delay.
+    pub fn new(initial_delay:Duration, max_delay:Duration) -> Self {
+        let attempt = 0;
+        Self {initial_delay,max_delay,attempt}
+    }
+
+    /// The number of the attempts made since the last `reset`.
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Register a new attempt and return the delay to wait before making it.
+    pub fn next_delay(&mut self) -> Duration {
+        let factor = DELAY_FACTOR.checked_pow(self.attempt).unwrap_or(u32::MAX);
+        let delay  = self.initial_delay.checked_mul(factor).unwrap_or(self.max_delay);
+        self.attempt = self.attempt.saturating_add(1);
+        delay.min(self.max_delay)
+    }
+
+    /// Start counting the attempts from the beginning, e.g. after the connection was established.
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn growing_delays() {
+        let initial     = Duration::from_millis(100);
+        let max         = Duration::from_millis(1000);
+        let mut backoff = Backoff::new(initial,max);
+        let delays      = (0..6).map(|_| backoff.next_delay().as_millis()).collect_vec();
+        assert_eq!(delays,vec![100,200,400,800,1000,1000]);
+        assert_eq!(backoff.attempt(),6);
+        for _ in 0..100 { backoff.next_delay(); }
+        assert_eq!(backoff.next_delay(),max);
+        backoff.reset();
+        assert_eq!(backoff.next_delay(),initial);
+    }
+}
@@ -130,8 +130,12 @@ where Id           : Copy + Debug + Display + Hash + Eq + Send + Sync + 'static,
                         Disposition::Ignore => {}
                     }
                 }
-                TransportEvent::Opened => {}
-                TransportEvent::Closed => { self.emit_event(Event::Closed) }
+                TransportEvent::Opened => self.emit_event(Event::Opened),
+                TransportEvent::Closed => {
+                    // Dropping the ongoing calls makes their futures fail.
+                    self.ongoing_calls.clear();
+                    self.emit_event(Event::Closed)
+                }
             }
         });
     }
@@ -265,6 +269,8 @@ where Id           : Copy + Debug + Display + Hash + Eq + Send + Sync + 'static,
 mod tests {
     use super::*;
 
+    use crate::common::error::LostConnection;
+
     use json_rpc::test_util::transport::mock::MockTransport;
     use utils::test::future::FutureTestExt;
     use utils::test::stream::StreamTestExt;
@@ -288,5 +294,43 @@ mod tests {
         assert!(matches!(event, Event::Closed), "Event was: {:?}", event);
         events.expect_pending();
     }
+
+    #[derive(Debug)]
+    struct MockRequest(i32);
+
+    impl IsRequest for MockRequest {
+        type Id = i32;
+
+        fn send(&self, transport:&mut dyn Transport) -> FallibleResult {
+            transport.send_text(&self.0.to_string())
+        }
+
+        fn id(&self) -> Self::Id {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_failing_ongoing_requests_on_reconnect() {
+        let logger        =  Logger::new("RPC_Handler_Test");
+        let mut transport = MockTransport::new();
+        let processor     = |msg| panic!("Must never be called in this test, but got {:?}!",msg);
+        let handler       = Handler::<i32,(),()>::new(transport.clone_ref(),logger,processor);
+        let mut runner    = handler.runner().boxed_local();
+        let mut events    = handler.event_stream().boxed_local();
+        let mut request   = handler.make_request(&MockRequest(1),Ok).boxed_local();
+        request.expect_pending();
+
+        transport.mock_connection_closed();
+        runner.expect_pending();
+        let error = request.expect_err();
+        assert!(error.downcast_ref::<LostConnection>().is_some(),"Error was: {:?}",error);
+        assert!(matches!(events.expect_next(), Event::Closed));
+
+        transport.mock_connection_reopened();
+        runner.expect_pending();
+        assert!(matches!(events.expect_next(), Event::Opened));
+        events.expect_pending();
+    }
 }
 
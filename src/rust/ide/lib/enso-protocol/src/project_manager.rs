@@ -258,6 +258,10 @@ mod mock_client_tests {
         expect_call!(mock_client.close_project(expected_uuid) => Ok(()));
         result(mock_client.close_project(&uuid)).expect("Couldn't close project.");
 
+        let new_name = "NewHelloWorld".to_string();
+        expect_call!(mock_client.rename_project(expected_uuid,new_name.clone()) => Ok(()));
+        result(mock_client.rename_project(&uuid,&new_name)).expect("Couldn't rename project.");
+
         expect_call!(mock_client.delete_project(expected_uuid) => Ok(()));
         result(mock_client.delete_project(&uuid)).expect("Couldn't delete project.");
     }
@@ -413,6 +417,11 @@ mod remote_client_tests {
             "missingComponentAction" : "Install",
             "version"                : "1.0.0",
         });
+        let new_project_name    = String::from("NewHelloWorld");
+        let project_rename_json = json!({
+            "projectId" : "00000000-0000-0000-0000-000000000000",
+            "name"      : "NewHelloWorld",
+        });
         let number_of_projects      = 2;
         let number_of_projects_json = json!({"numberOfProjects":number_of_projects});
         let num_projects_json       = json!({"numProjects":number_of_projects});
@@ -478,6 +487,13 @@ mod remote_client_tests {
             &unit_json,
             &()
         );
+        test_request(
+            |client| client.rename_project(&project_id,&new_project_name),
+            "project/rename",
+            &project_rename_json,
+            &unit_json,
+            &()
+        );
         test_request(
             |client| client.delete_project(&project_id),
             "project/delete",
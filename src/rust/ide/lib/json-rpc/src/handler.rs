@@ -94,6 +94,9 @@ impl Default for IdGenerator {
 /// Event emitted by the `Handler<N>`.
 #[derive(Debug)]
 pub enum Event<N> {
+    /// Transport has been opened again after being closed, e.g. by reconnecting. The peer does
+    /// not remember the previous connection, so the session must be initialized again.
+    Opened,
     /// Transport has been closed.
     Closed,
     /// Error occurred.
@@ -367,7 +370,7 @@ impl<Notification> Handler<Notification> {
                 self.process_incoming_message(msg),
             TransportEvent::BinaryMessage(data) =>
                 self.error_occurred(HandlingError::UnexpectedBinaryMessage(data)),
-            TransportEvent::Opened => {}
+            TransportEvent::Opened => self.emit_event(Event::Opened),
             TransportEvent::Closed => {
                 // Dropping all ongoing calls will cancel their futures.
                 self.clear_ongoing_requests();
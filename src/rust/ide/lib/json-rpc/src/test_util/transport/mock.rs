@@ -139,6 +139,17 @@ impl MockTransport {
         })
     }
 
+    /// Mocks event generated when the connection is established again after being lost, e.g. by
+    /// the reconnecting transport.
+    pub fn mock_connection_reopened(&mut self) {
+        self.with_mut_data(|data| {
+            if let Some(ref mut transmitter) = data.event_transmitter {
+                data.is_closed = false;
+                channel::emit(transmitter,TransportEvent::Opened);
+            }
+        })
+    }
+
     /// Takes the text message sent by the client and returns its contents.
     ///
     /// If the client has not sent any text messages, panics.
@@ -24,6 +24,8 @@ use crate::prelude::*;
 use ast::Ast;
 use ast::BlockLine;
 use ast::IdMap;
+use ast::incremental::Reparsed;
+use enso_data::text::TextChange;
 use std::panic;
 use utils::fail::FallibleResult;
 
@@ -134,6 +136,14 @@ impl Parser {
             Err(api::NoLinesProduced.into())
         }
     }
+
+    /// Apply the text change to the `ast` by reparsing only the line affected by the change. The
+    /// `id_map` describes the code after the change. See `ast::incremental::reparse` for details.
+    pub fn reparse
+    (&self, ast:&Ast, change:&TextChange, id_map:&IdMap) -> FallibleResult<Reparsed> {
+        let parse_line = |program:String,id_map| self.parse_line_ast_with_id_map(program,id_map);
+        ast::incremental::reparse(ast,change,id_map,parse_line)
+    }
 }
 
 /// Deserialize value from json text.
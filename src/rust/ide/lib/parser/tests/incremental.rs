@@ -0,0 +1,42 @@
+use parser::prelude::*;
+
+use ast::HasIdMap;
+use ast::crumbs::*;
+use enso_data::text::Index;
+use enso_data::text::TextChange;
+use parser::Parser;
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn reparsing_single_line_keeps_other_ids() {
+    let parser = Parser::new_or_panic();
+    let code   = "main =\n    foo = bar 2\n    baz = foo + 1";
+    let module = parser.parse_module(code,default()).unwrap();
+    let change = TextChange::replace(Index::new(21)..Index::new(22),"3".to_string());
+    let code   = change.applied(code);
+    let full   = parser.parse_module(code.as_str(),module.id_map()).unwrap();
+
+    let reparsed = parser.reparse(module.ast(),&change,&full.id_map()).unwrap();
+    assert_eq!(reparsed.ast.repr(),code);
+    assert_eq!(reparsed.ast,*full.ast());
+
+    let expected_changed : ast::Crumbs = vec!
+        [ ModuleCrumb{line_index:0}.into()
+        , InfixCrumb::RightOperand.into()
+        , BlockCrumb::HeadLine.into()
+        , InfixCrumb::RightOperand.into()
+        , PrefixCrumb::Arg.into()
+        ];
+    assert_eq!(reparsed.changed,expected_changed);
+}
+
+#[wasm_bindgen_test]
+fn reparsing_fails_for_multiline_change() {
+    let parser = Parser::new_or_panic();
+    let module = parser.parse_module("foo = bar",default()).unwrap();
+    let change = TextChange::insert(Index::new(9),"\nbaz".to_string());
+    assert!(parser.reparse(module.ast(),&change,&default()).is_err());
+}
@@ -151,7 +151,7 @@ impl<'a,T> Implementation for node::Ref<'a,T> {
                 );
                 root.set_traversing(&self.ast_crumbs,new_ast?)
             })),
-            node::Kind::Token => None,
+            node::Kind::Token | node::Kind::Group => None,
             _ => match &self.ast_crumbs.last() {
                 // Operators should be treated in a special way - setting functions in place in
                 // a operator should replace Infix with Prefix with two applications.
@@ -318,12 +318,16 @@ fn generate_node_for_prefix_chain<T:Payload>
     let known_args       = invocation_info.is_some();
     let mut known_params = base.prefix_params(invocation_info);
     let prefix_arity     = this.args.len().max(known_params.len());
+    let arg_count        = this.args.len();
+    let aggregated_from  = this.id().and_then(|id| context.aggregated_arguments(id,arg_count));
+    let aggregated_from  = aggregated_from.filter(|first| first + 1 < arg_count);
+    let (args,grouped)   = this.args.split_at(aggregated_from.unwrap_or(arg_count));
 
     use ast::crumbs::PrefixCrumb::*;
     // Removing arguments is possible if there at least two of them
     let removable = this.args.len() >= 2;
     let node         = this.func.generate_node(node::Kind::Operation,context);
-    let ret          = this.args.iter().enumerate().fold(node, |node,(i,arg)| {
+    let ret          = args.iter().enumerate().fold(node, |node,(i,arg)| {
         let node     = node?;
         let is_first = i == 0;
         let is_last  = i + 1 == prefix_arity;
@@ -355,10 +359,51 @@ fn generate_node_for_prefix_chain<T:Payload>
             payload  : default(),
         })
     })?;
+    let ret = match grouped.last() {
+        None       => ret,
+        Some(last) => {
+            known_params.by_ref().take(grouped.len()).for_each(drop);
+            let is_last = arg_count == prefix_arity;
+            let kind    = if is_last {kind.clone()} else {node::Kind::Chained};
+            generate_aggregated_arguments(ret,kind,grouped,last.prefix_id,known_args)
+        }
+    };
 
     Ok(generate_expected_arguments(ret,kind,this.args.len(),known_params))
 }
 
+/// Build the outermost level of a prefix application whose trailing arguments are aggregated into
+/// a single `Group` node. The group is a leaf without AST crumbs, so looking up any of the grouped
+/// arguments by AST crumbs ends at the group. See `Context::aggregated_arguments`.
+fn generate_aggregated_arguments<T:Payload>
+( node       : Node<T>
+, kind       : node::Kind
+, grouped    : &[ast::prefix::Argument]
+, ast_id     : Option<ast::Id>
+, known_args : bool
+) -> Node<T> {
+    use ast::crumbs::PrefixCrumb::*;
+    let arg_size     = |arg:&ast::prefix::Argument| arg.sast.off + arg.sast.wrapped.len();
+    let first_offset = grouped.first().map_or(0,|arg| arg.sast.off);
+    let args_size    = grouped.iter().map(arg_size).sum::<usize>();
+    let group_size   = Size::new(args_size - first_offset);
+    let group        = Node::<T>::new().with_kind(node::Kind::Group).with_size(group_size);
+    let mut gen      = ChildGenerator::default();
+    gen.add_node(vec![Func.into(); grouped.len()],node);
+    gen.spacing(first_offset);
+    gen.add_node(ast::Crumbs::new(),group);
+    if !known_args {
+        gen.generate_empty_node(InsertionPointType::Append);
+    }
+    Node {
+        kind,
+        size     : gen.current_offset,
+        children : gen.children,
+        ast_id,
+        payload  : default(),
+    }
+}
+
 
 // === Match ===
 
@@ -1009,6 +1054,43 @@ mod test {
         assert_eq!(tree,expected);
     }
 
+    #[wasm_bindgen_test]
+    fn generating_span_tree_with_aggregated_arguments() {
+        let parser       = Parser::new_or_panic();
+        let ast          = parser.parse_line_ast("foo a b c").unwrap();
+        let mut context  = context::Aggregating::new(context::Empty,2);
+        let expanded     = ast.generate_tree(&context::Empty).unwrap() : SpanTree;
+        let mut tree     = ast.generate_tree(&context).unwrap() : SpanTree;
+        let aggregated   = tree.clone();
+        clear_expression_ids(&mut tree.root);
+
+        let expected = TreeBuilder::new(9)
+            .add_child(0,5,node::Kind::Chained,vec![PrefixCrumb::Func,PrefixCrumb::Func])
+                .add_leaf(0,3,node::Kind::Operation,PrefixCrumb::Func)
+                .add_empty_child(4,BeforeTarget)
+                .add_leaf(4,1,node::Kind::this().removable(),PrefixCrumb::Arg)
+                .add_empty_child(5,Append)
+                .done()
+            .add_leaf(6,3,node::Kind::Group,Crumbs::default())
+            .add_empty_child(9,Append)
+            .build();
+        assert_eq!(expected,tree);
+
+        // The ports of the grouped arguments are mapped to the group, the other ports are kept.
+        let crumbs = |vec:Vec<usize>| crate::Crumbs::new(vec);
+        let to_aggregated = |vec| aggregated.corresponding_crumbs(&expanded,&crumbs(vec));
+        let to_expanded   = |vec| expanded.corresponding_crumbs(&aggregated,&crumbs(vec));
+        assert_eq!(to_aggregated(vec![0,0,2]), Some(crumbs(vec![0,2])));
+        assert_eq!(to_aggregated(vec![0,1])  , Some(crumbs(vec![1])));
+        assert_eq!(to_aggregated(vec![1])    , Some(crumbs(vec![1])));
+        assert_eq!(to_expanded(vec![0,2])    , Some(crumbs(vec![0,0,2])));
+        assert_eq!(to_expanded(vec![1])      , None);
+
+        // Expanded applications are generated as usual.
+        context.expand(ast.id.unwrap());
+        assert_eq!(ast.generate_tree(&context).unwrap() : SpanTree, expanded);
+    }
+
     fn segment_body_crumbs(index:usize, pattern_crumb:&Vec<PatternMatchCrumb>) -> ast::crumbs::MatchCrumb {
         let val = ast::crumbs::SegmentMatchCrumb::Body {val:pattern_crumb.clone()};
         ast::crumbs::MatchCrumb::Segs {val,index}
@@ -33,6 +33,15 @@ pub trait Context {
     /// Trait implementors may used it to filter-out results, however they are not required to do
     /// so. Caller should not assume that the called method has the same name as given identifier.
     fn call_info(&self, id:Id, name:Option<&str>) -> Option<CalledMethodInfo>;
+
+    /// Check if the trailing arguments of the given prefix application should be aggregated into a
+    /// single `Group` node. If so, return the index of the first aggregated argument. At least two
+    /// arguments must be left to aggregate, otherwise the application is generated as usual.
+    ///
+    /// By default, no arguments are aggregated.
+    fn aggregated_arguments(&self, _id:Id, _argument_count:usize) -> Option<usize> {
+        None
+    }
 }
 
 
@@ -50,3 +59,53 @@ impl Context for Empty {
         None
     }
 }
+
+
+
+// ===================
+// === Aggregating ===
+// ===================
+
+/// A context aggregating the trailing arguments of long prefix applications, like calls of
+/// variadic functions, into a single port. The information about the called methods is provided
+/// by the wrapped context.
+///
+/// The application having more than `max_ports` arguments is displayed with the first
+/// `max_ports - 1` arguments as separate ports, followed by the group of the remaining ones.
+/// The applications marked as expanded are generated as usual.
+#[derive(Clone,Debug)]
+#[allow(missing_docs)]
+pub struct Aggregating<C> {
+    pub context   : C,
+    pub max_ports : usize,
+    pub expanded  : HashSet<Id>,
+}
+
+impl<C:Context> Aggregating<C> {
+    /// Constructor.
+    pub fn new(context:C, max_ports:usize) -> Self {
+        let expanded = default();
+        Self {context,max_ports,expanded}
+    }
+
+    /// Display all the arguments of the application with the given id as separate ports.
+    pub fn expand(&mut self, id:Id) {
+        self.expanded.insert(id);
+    }
+
+    /// Aggregate back the arguments of the application with the given id.
+    pub fn collapse(&mut self, id:Id) {
+        self.expanded.remove(&id);
+    }
+}
+
+impl<C:Context> Context for Aggregating<C> {
+    fn call_info(&self, id:Id, name:Option<&str>) -> Option<CalledMethodInfo> {
+        self.context.call_info(id,name)
+    }
+
+    fn aggregated_arguments(&self, id:Id, argument_count:usize) -> Option<usize> {
+        let aggregated = argument_count > self.max_ports && !self.expanded.contains(&id);
+        aggregated.as_some(self.max_ports.saturating_sub(1))
+    }
+}
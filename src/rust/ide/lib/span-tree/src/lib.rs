@@ -23,6 +23,7 @@
 pub mod action;
 pub mod generate;
 pub mod iter;
+pub mod literal;
 pub mod node;
 pub mod builder;
 
@@ -126,6 +127,22 @@ impl<T:Payload> SpanTree<T> {
         let root = self.root.map(f);
         SpanTree {root}
     }
+
+    /// Get the crumbs of the node corresponding to the node located by `crumbs` in the `other`
+    /// tree, generated from the same expression but with different argument groups expanded (see
+    /// `generate::Context::aggregated_arguments`). The nodes of an aggregated group are mapped to
+    /// the group node, so the crumbs of ports stay usable whether the group is expanded or not.
+    ///
+    /// Returns `None` for the insertion points and groups, which have no AST node of their own.
+    pub fn corresponding_crumbs(&self, other:&SpanTree<T>, crumbs:&Crumbs) -> Option<Crumbs> {
+        let node    = other.get_node(crumbs).ok()?;
+        let has_ast = node.crumbs.is_empty() || !(node.is_insertion_point() || node.is_group());
+        let found   = has_ast.and_option_from(|| {
+            self.root_ref().get_descendant_by_ast_crumbs(&node.ast_crumbs)
+        })?;
+        let exact = found.ast_crumbs.is_empty() || found.node.is_group();
+        exact.as_some(found.node.crumbs)
+    }
 }
 
 
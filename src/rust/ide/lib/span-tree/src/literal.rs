@@ -0,0 +1,124 @@
+//! Detection and adjustment of the numeric literals in the span tree nodes' code. It is used by the
+//! ports allowing changing the literal value by dragging it.
+
+use crate::prelude::*;
+
+
+
+// ======================
+// === NumericLiteral ===
+// ======================
+
+/// A decimal numeric literal, like `12`, `-3` or `0.25`. The number of the decimal places is kept,
+/// so the adjusted literal is displayed with the precision the user wrote it with.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct NumericLiteral {
+    /// The value of the literal.
+    pub value    : f64,
+    /// The number of digits after the decimal point.
+    pub decimals : usize,
+}
+
+impl NumericLiteral {
+    /// Parse the literal from its code. Returns `None` if the code is not a decimal numeric
+    /// literal, for example if it is an identifier or a number in a non-decimal base.
+    pub fn parse(code:&str) -> Option<Self> {
+        let digits         = code.strip_prefix('-').unwrap_or(code);
+        let (integer,frac) = match digits.split_once('.') {
+            Some((integer,frac)) => (integer,Some(frac)),
+            None                 => (digits,None),
+        };
+        let is_digits = |s:&str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+        if !is_digits(integer) || !frac.map_or(true,is_digits) {
+            return None
+        }
+        let value    = digits.parse::<f64>().ok()?;
+        let value    = if code.starts_with('-') { -value } else { value };
+        let decimals = frac.map_or(0,|frac| frac.len());
+        Some(Self {value,decimals})
+    }
+
+    /// The literal changed by `steps` steps of size `10^step_exponent`. If the step is finer than
+    /// the literal precision, the precision is increased to fit the step, unless the literal is an
+    /// integer, which is changed by the steps of one instead. Zero steps leave the literal as is.
+    pub fn adjusted(&self, steps:i32, step_exponent:i32) -> Self {
+        if steps == 0 { return *self }
+        let step_exponent = if self.decimals == 0 { step_exponent.max(0) } else { step_exponent };
+        let decimals      = self.decimals.max((-step_exponent).max(0) as usize);
+        let scale         = 10.0_f64.powi(decimals as i32);
+        let value         = self.value + steps as f64 * 10.0_f64.powi(step_exponent);
+        let value         = (value * scale).round() / scale;
+        // Avoid displaying the negative zero as `-0`.
+        let value         = if value == 0.0 { 0.0 } else { value };
+        Self {value,decimals}
+    }
+
+    /// The code of the literal.
+    pub fn code(&self) -> String {
+        format!("{:.*}",self.decimals,self.value)
+    }
+}
+
+impl Display for NumericLiteral {
+    fn fmt(&self, f:&mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,"{}",self.code())
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parsing_numeric_literals() {
+        let literal = |value,decimals| Some(NumericLiteral{value,decimals});
+        assert_eq!(NumericLiteral::parse("12")   , literal(12.0,0));
+        assert_eq!(NumericLiteral::parse("-3")   , literal(-3.0,0));
+        assert_eq!(NumericLiteral::parse("0.25") , literal(0.25,2));
+        assert_eq!(NumericLiteral::parse("-1.50"), literal(-1.5,2));
+        for code in &["","-","foo","1.","1.2.3","16_FF","1e3"," 1","--1"] {
+            assert_eq!(NumericLiteral::parse(code),None,"{}",code);
+        }
+    }
+
+    #[test]
+    fn adjusting_numeric_literals() {
+        let adjusted = |code,steps,exponent| {
+            NumericLiteral::parse(code).unwrap().adjusted(steps,exponent).code()
+        };
+        assert_eq!(adjusted("12",3,0)     , "15");
+        assert_eq!(adjusted("12",-2,1)    , "-8");
+        assert_eq!(adjusted("0.25",1,-2)  , "0.26");
+        assert_eq!(adjusted("0.25",1,-1)  , "0.35");
+        assert_eq!(adjusted("0.25",-3,-3) , "0.247");
+        assert_eq!(adjusted("0.1",-1,-1)  , "0.0");
+        assert_eq!(adjusted("0.1",-2,-1)  , "-0.1");
+    }
+
+    #[test]
+    fn integer_literals_stay_integers() {
+        let adjusted = |code,steps,exponent| {
+            NumericLiteral::parse(code).unwrap().adjusted(steps,exponent).code()
+        };
+        assert_eq!(adjusted("1",-10,-1) , "-9");
+        assert_eq!(adjusted("1",2,-2)   , "3");
+        assert_eq!(adjusted("1",2,1)    , "21");
+    }
+
+    #[test]
+    fn zero_steps_keep_the_literal() {
+        for code in &["12","-3","0.25","-1.50"] {
+            let literal = NumericLiteral::parse(code).unwrap();
+            for exponent in -3..3 {
+                assert_eq!(literal.adjusted(0,exponent),literal);
+                assert_eq!(literal.adjusted(0,exponent).code(),*code);
+            }
+        }
+    }
+}
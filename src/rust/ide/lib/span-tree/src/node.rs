@@ -93,6 +93,7 @@ impl<T:Payload> Node<T> {
     pub fn is_this                       (&self) -> bool {self.kind.is_this()}
     pub fn is_argument                   (&self) -> bool {self.kind.is_argument()}
     pub fn is_token                      (&self) -> bool {self.kind.is_token()}
+    pub fn is_group                      (&self) -> bool {self.kind.is_group()}
     pub fn is_insertion_point            (&self) -> bool {self.kind.is_insertion_point()}
     pub fn is_positional_insertion_point (&self) -> bool {self.kind.is_positional_insertion_point()}
     pub fn is_expected_argument          (&self) -> bool {self.kind.is_expected_argument()}
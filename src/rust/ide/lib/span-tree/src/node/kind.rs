@@ -26,6 +26,9 @@ pub enum Kind {
     Argument(Argument),
     /// A node being a unmodifiable token in macro.
     Token,
+    /// A node aggregating the trailing arguments of a prefix application, displayed as a single
+    /// port. See `generate::Context::aggregated_arguments`.
+    Group,
     /// A node being a placeholder for inserting new child to Prefix or Operator chain. It should
     /// not have children, but can be assigned with a span representing the number of spaces between
     /// AST tokens. For example, given expression `foo   bar`, the span assigned to the
@@ -54,6 +57,7 @@ impl Kind {
     pub fn is_this            (&self) -> bool { matches!(self,Self::This{..}) }
     pub fn is_argument        (&self) -> bool { matches!(self,Self::Argument{..}) }
     pub fn is_token           (&self) -> bool { matches!(self,Self::Token{..}) }
+    pub fn is_group           (&self) -> bool { matches!(self,Self::Group{..}) }
     pub fn is_insertion_point (&self) -> bool { matches!(self,Self::InsertionPoint{..}) }
 
     /// Match the value with `Kind::InsertionPoint{..}` but not
@@ -145,6 +149,7 @@ impl Kind {
             Self::This(_)           => "This",
             Self::Argument(_)       => "Argument",
             Self::Token             => "Token",
+            Self::Group             => "Group",
             Self::InsertionPoint(_) => "InsertionPoint"
         }
     }
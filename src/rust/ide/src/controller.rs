@@ -5,6 +5,7 @@
 //! The API of each controller is "view-facing", in contrast to the models in [`crate::model`] which
 //! are focusing on reflecting the Engine entities (thus can be called "Engine-facing").
 
+pub mod autosave;
 pub mod graph;
 pub mod ide;
 pub mod module;
@@ -14,6 +15,7 @@ pub mod visualization;
 pub mod searcher;
 pub mod upload;
 
+pub use autosave::Handle        as Autosave;
 pub use graph::Handle           as Graph;
 pub use graph::executed::Handle as ExecutedGraph;
 pub use self::ide::Ide;
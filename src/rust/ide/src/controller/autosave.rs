@@ -0,0 +1,299 @@
+//! The Autosave Controller: saving the edited modules of the project without an explicit request
+//! from the user.
+//!
+//! The controller tracks which modules were edited since the last save, both from the graph and
+//! the text editor, and saves them once no further edit happened for the configured interval. The
+//! pending edits are also saved when the window is being closed.
+
+use crate::prelude::*;
+
+use crate::executor::global::spawn;
+use crate::executor::global::spawn_stream_handler;
+use crate::model::module::Path;
+
+use enso_frp as frp;
+use enso_protocol::language_server;
+use ensogl::system::web;
+use ensogl_system_web::event::listener::Slot;
+use parser::Parser;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The default time in milliseconds between the last edit of a module and saving it.
+pub const DEFAULT_INTERVAL_MS : u32 = 2000;
+
+
+
+// =================
+// === JS Events ===
+// =================
+
+/// Description of the window events handled by the autosave.
+pub mod event {
+    use super::*;
+    use ensogl_system_web::event::Type;
+
+    /// Represents the `window.beforeunload` event.
+    #[derive(Clone,Copy,Debug)]
+    pub enum BeforeUnload{}
+    impl Type for BeforeUnload {
+        type Interface = web_sys::Event;
+        type Target = web_sys::Window;
+        const NAME:&'static str = "beforeunload";
+    }
+}
+
+
+
+// ===========
+// === Frp ===
+// ===========
+
+/// The save status of the project, meant to be displayed e.g. in the status bar.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Frp {
+    /// Emitted with `true` when saving begins, and with `false` when all the started saves
+    /// finished, either successfully or not.
+    pub saving      : frp::Stream<bool>,
+    /// Emitted with the time (in milliseconds since the Unix epoch) of each successful save.
+    pub saved_at    : frp::Stream<f64>,
+    /// Emitted with the description of the error when saving any module failed. The module stays
+    /// dirty and will be saved again after the next edit or flush.
+    pub save_failed : frp::Stream<String>,
+}
+
+
+
+// =============
+// === Model ===
+// =============
+
+#[derive(Debug)]
+struct Model {
+    logger        : Logger,
+    json_rpc      : Rc<language_server::Connection>,
+    parser        : Parser,
+    modules       : RefCell<Vec<model::Module>>,
+    dirty         : RefCell<HashSet<Path>>,
+    ongoing_saves : Cell<usize>,
+    edited        : frp::Source,
+    saving        : frp::Source<bool>,
+    saved_at      : frp::Source<f64>,
+    save_failed   : frp::Source<String>,
+}
+
+impl Model {
+    fn mark_dirty(&self, path:&Path) {
+        self.dirty.borrow_mut().insert(path.clone_ref());
+        self.edited.emit(());
+    }
+
+    /// Send the save requests for all the dirty modules. The requests are sent before returning,
+    /// so they reach the Language Server even if the executor does not run anymore, e.g. when the
+    /// window is being closed.
+    fn save_dirty(self:&Rc<Self>) {
+        let dirty   = std::mem::take(&mut *self.dirty.borrow_mut());
+        let modules = self.modules.borrow().iter().filter(|module| {
+            dirty.contains(module.path())
+        }).cloned().collect_vec();
+        if modules.is_empty() {
+            return
+        }
+        let saves = modules.into_iter().map(|module| {
+            let path   = module.path().clone_ref();
+            let result = self.save_module(&module);
+            async move { result.await.map_err(|err| (path,err)) }
+        }).collect_vec();
+        self.save_started();
+        // Polling the saves once sends their requests.
+        let mut saves = futures::future::join_all(saves).boxed_local();
+        let sent      = (&mut saves).now_or_never();
+        let this      = self.clone_ref();
+        spawn(async move {
+            let results = match sent {
+                Some(results) => results,
+                None          => saves.await,
+            };
+            let errors  = results.into_iter().filter_map(|result| result.err()).collect_vec();
+            for (path,err) in &errors {
+                error!(this.logger,"Failed to save module {path}: {err}");
+                this.dirty.borrow_mut().insert(path.clone_ref());
+                this.save_failed.emit(format!("Failed to save module {}: {}",path,err));
+            }
+            if errors.is_empty() {
+                this.saved_at.emit(js_sys::Date::now());
+            }
+            this.save_finished();
+        });
+    }
+
+    fn save_module(&self, module:&model::Module) -> impl Future<Output=FallibleResult> {
+        let model           = module.clone_ref();
+        let language_server = self.json_rpc.clone_ref();
+        let parser          = self.parser.clone_ref();
+        let logger          = self.logger.clone_ref();
+        controller::Module {model,language_server,parser,logger}.save_file()
+    }
+
+    fn save_started(&self) {
+        let ongoing = self.ongoing_saves.get();
+        self.ongoing_saves.set(ongoing + 1);
+        if ongoing == 0 {
+            self.saving.emit(true);
+        }
+    }
+
+    fn save_finished(&self) {
+        let ongoing = self.ongoing_saves.get().saturating_sub(1);
+        self.ongoing_saves.set(ongoing);
+        if ongoing == 0 {
+            self.saving.emit(false);
+        }
+    }
+}
+
+
+
+// ==============
+// === Handle ===
+// ==============
+
+/// The Autosave Controller Handle.
+///
+/// The tracked modules are saved as long as the handle is alive.
+#[derive(Debug)]
+pub struct Handle {
+    model     : Rc<Model>,
+    network   : frp::Network,
+    flush     : frp::Source,
+    on_unload : Option<Slot<event::BeforeUnload>>,
+    /// The save status.
+    pub frp   : Frp,
+}
+
+impl Handle {
+    /// Create an autosave controller saving the modules `interval_ms` milliseconds after their
+    /// last edit.
+    pub fn new(project:&model::Project, interval_ms:u32) -> Self {
+        let logger   = Logger::new("controller::Autosave");
+        let json_rpc = project.json_rpc();
+        let parser   = project.parser();
+        frp::new_network! { network
+            edited      <- source_();
+            flush       <- source_();
+            saving      <- source();
+            saved_at    <- source();
+            save_failed <- source();
+        }
+        let modules       = default();
+        let dirty         = default();
+        let ongoing_saves = default();
+        let model         = Rc::new(Model {logger,json_rpc,parser,modules,dirty,ongoing_saves
+            ,edited:edited.clone_ref(),saving:saving.clone_ref(),saved_at:saved_at.clone_ref()
+            ,save_failed:save_failed.clone_ref()});
+        let debounce = frp::io::timer::timeout(&network,interval_ms);
+        frp::extend! { network
+            debounce.restart <+ edited;
+            debounce.cancel  <+ flush;
+            save             <- any(&debounce.expired,&flush);
+            eval_ save (model.save_dirty());
+        }
+        let on_unload = web::try_window().ok().map(|window| {
+            let mut slot = Slot::new(&window,&model.logger);
+            let weak     = Rc::downgrade(&model);
+            slot.set_callback(move |_| if let Some(model) = weak.upgrade() { model.save_dirty() });
+            slot
+        });
+        let saving      = saving.into();
+        let saved_at    = saved_at.into();
+        let save_failed = save_failed.into();
+        let frp         = Frp {saving,saved_at,save_failed};
+        Handle {model,network,flush,on_unload,frp}
+    }
+
+    /// Start tracking the edits of the module. The edits made before calling this method are not
+    /// saved.
+    pub fn track_module(&self, module:model::Module) {
+        let path = module.path().clone_ref();
+        let weak = Rc::downgrade(&self.model);
+        spawn_stream_handler(weak,module.subscribe(),move |_,model| {
+            model.mark_dirty(&path);
+            futures::future::ready(())
+        });
+        self.model.modules.borrow_mut().push(module);
+    }
+
+    /// Check whether any tracked module was edited since its last save.
+    pub fn is_dirty(&self) -> bool {
+        !self.model.dirty.borrow().is_empty()
+    }
+
+    /// Save all the dirty modules immediately, without waiting for the interval to pass.
+    pub fn flush(&self) {
+        self.flush.emit(());
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::executor::test_utils::TestWithLocalPoolExecutor;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn saving_edited_module_on_flush() {
+        let mut test = TestWithLocalPoolExecutor::set_up();
+        let module   = model::module::test::plain_from_code("main = 2 + 2");
+        let path     = module.path().file_path().clone();
+
+        let client = language_server::MockClient::default();
+        client.expect.save_text_file(move |saved_path,_| {
+            assert_eq!(*saved_path,path);
+            Ok(())
+        });
+        client.require_all_calls();
+        let mut project = model::project::MockAPI::new();
+        let json_rpc    = language_server::Connection::new_mock_rc(client);
+        let parser      = Parser::new_or_panic();
+        model::project::test::expect_json_rpc(&mut project,json_rpc);
+        model::project::test::expect_parser(&mut project,&parser);
+        let project : model::Project = Rc::new(project);
+
+        let autosave = Handle::new(&project,DEFAULT_INTERVAL_MS);
+        let network  = &autosave.network;
+        frp::extend! { network
+            saved_count  <- autosave.frp.saved_at.count().sampler();
+            failed_count <- autosave.frp.save_failed.count().sampler();
+            saving       <- autosave.frp.saving.sampler();
+        }
+        autosave.track_module(module.clone_ref());
+        test.run_until_stalled();
+        assert!(!autosave.is_dirty());
+
+        let code = "main = 2 + 3";
+        let ast  = parser.parse_module(code,default()).unwrap();
+        module.update_ast(ast).unwrap();
+        test.run_until_stalled();
+        assert!(autosave.is_dirty());
+
+        autosave.flush();
+        assert!(!autosave.is_dirty());
+        assert!(saving.value());
+        test.run_until_stalled();
+        assert!(!saving.value());
+        assert_eq!(saved_count.value(),1);
+        assert_eq!(failed_count.value(),0);
+    }
+}
@@ -95,6 +95,10 @@ pub enum Notification {
     NewProjectCreated,
     /// User opened an existing project.
     ProjectOpened,
+    /// User renamed a project. The opened project is renamed also through this API.
+    ProjectRenamed,
+    /// User deleted a project which was not opened.
+    ProjectDeleted,
 }
 
 
@@ -119,6 +123,13 @@ pub trait ManagingProjectAPI {
 
     /// Open the project with given id and name.
     fn open_project(&self, id:Uuid) -> BoxFuture<FallibleResult>;
+
+    /// Rename the project with given id. If it is the currently opened project, its model is
+    /// updated as well.
+    fn rename_project(&self, id:Uuid, name:String) -> BoxFuture<FallibleResult>;
+
+    /// Delete the project with given id. The currently opened project cannot be deleted.
+    fn delete_project(&self, id:Uuid) -> BoxFuture<FallibleResult>;
 }
 
 
@@ -28,6 +28,17 @@ const UNNAMED_PROJECT_NAME:&str = "Unnamed";
 
 
 
+// =============
+// === Error ===
+// =============
+
+#[allow(missing_docs)]
+#[derive(Clone,Copy,Debug,Fail)]
+#[fail(display="Cannot delete the project {}, because it is currently opened.", id)]
+pub struct CannotDeleteOpenedProject { id:Uuid }
+
+
+
 // =============================
 // === The Controller Handle ===
 // =============================
@@ -129,4 +140,29 @@ impl ManagingProjectAPI for Handle {
             Ok(())
         }.boxed_local()
     }
+
+    fn rename_project(&self, id:Uuid, name:String) -> BoxFuture<FallibleResult> {
+        async move {
+            let current_project = self.current_project.get();
+            if current_project.id() == id {
+                current_project.rename_project(name).await?;
+            } else {
+                self.project_manager.rename_project(&id,&name).await?;
+            }
+            executor::global::spawn(self.notifications.publish(Notification::ProjectRenamed));
+            Ok(())
+        }.boxed_local()
+    }
+
+    fn delete_project(&self, id:Uuid) -> BoxFuture<FallibleResult> {
+        async move {
+            if self.current_project.get().id() == id {
+                Err(CannotDeleteOpenedProject{id}.into())
+            } else {
+                self.project_manager.delete_project(&id).await?;
+                executor::global::spawn(self.notifications.publish(Notification::ProjectDeleted));
+                Ok(())
+            }
+        }.boxed_local()
+    }
 }
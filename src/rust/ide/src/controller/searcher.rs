@@ -583,11 +583,22 @@ impl Searcher {
         info!(self.logger, "Picking suggestion: {picked_suggestion:?}");
         let id                = self.data.borrow().input.next_completion_id();
         let picked_completion = FragmentAddedByPickingSuggestion {id,picked_suggestion};
-        let code_to_insert    = self.code_to_insert(&picked_completion).code;
+        let new_parsed_input  = self.input_with_picked(&picked_completion)?;
+        let new_input         = new_parsed_input.repr();
+        self.data.borrow_mut().input = new_parsed_input;
+        self.data.borrow_mut().fragments_added_by_picking.push(picked_completion);
+        self.reload_list();
+        Ok(new_input)
+    }
+
+    /// The searcher's input after picking the given completion. The current input is not changed.
+    fn input_with_picked
+    (&self, picked_completion:&FragmentAddedByPickingSuggestion) -> FallibleResult<ParsedInput> {
+        let code_to_insert    = self.code_to_insert(picked_completion).code;
         debug!(self.logger, "Code to insert: \"{code_to_insert}\"");
         let added_ast         = self.ide.parser().parse_line_ast(&code_to_insert)?;
         let pattern_offset    = self.data.borrow().input.pattern_offset;
-        let new_expression    = match self.data.borrow_mut().input.expression.take() {
+        let new_expression    = match self.data.borrow().input.expression.clone() {
             None => {
                 let ast = ast::prefix::Chain::from_ast_non_strict(&added_ast);
                 ast::Shifted::new(pattern_offset,ast)
@@ -601,20 +612,28 @@ impl Searcher {
                 expression
             }
         };
-        let new_parsed_input = ParsedInput {
+        Ok(ParsedInput {
             expression     : Some(new_expression),
             pattern_offset : 1,
             pattern        : "".to_string()
-        };
-        let new_input = new_parsed_input.repr();
-        self.data.borrow_mut().input = new_parsed_input;
-        self.data.borrow_mut().fragments_added_by_picking.push(picked_completion);
-        self.reload_list();
-        Ok(new_input)
+        })
+    }
+
+    /// The searcher's input which would be set by using the action at given index as a suggestion,
+    /// e.g. to preview the hovered action. The current input is not changed.
+    pub fn preview_suggestion(&self, index:usize) -> FallibleResult<String> {
+        let picked_suggestion = self.suggestion_at(index)?;
+        let id                = self.data.borrow().input.next_completion_id();
+        let picked_completion = FragmentAddedByPickingSuggestion {id,picked_suggestion};
+        Ok(self.input_with_picked(&picked_completion)?.repr())
     }
 
     /// Use action at given index as a suggestion. The exact outcome depends on the action's type.
     pub fn use_as_suggestion(&self, index:usize) -> FallibleResult<String> {
+        self.use_suggestion(self.suggestion_at(index)?)
+    }
+
+    fn suggestion_at(&self, index:usize) -> FallibleResult<action::Suggestion> {
         let error      = || NoSuchAction {index};
         let suggestion = {
             let data = self.data.borrow();
@@ -622,7 +641,7 @@ impl Searcher {
             list.get_cloned(index).ok_or_else(error)?.action
         };
         match suggestion {
-            Action::Suggestion(suggestion) => self.use_suggestion(suggestion),
+            Action::Suggestion(suggestion) => Ok(suggestion),
             _                              => Err(NotASuggestion {index}.into())
         }
     }
@@ -1529,6 +1548,21 @@ pub mod test {
         assert_eq!(notification, Some(Notification::NewActionList));
     }
 
+    #[wasm_bindgen_test]
+    fn previewing_suggestion() {
+        let Fixture{mut test,searcher,..} = Fixture::new_custom(|data,client| {
+            data.expect_completion(client,None,None,&[1,9]);
+            data.expect_completion(client,None,None,&[]);
+        });
+        searcher.reload_list();
+        test.run_until_stalled();
+        let input   = searcher.data.borrow().input.repr();
+        let preview = searcher.preview_suggestion(2).unwrap();
+        assert_eq!(searcher.data.borrow().input.repr(), input);
+        assert!(searcher.data.borrow().fragments_added_by_picking.is_empty());
+        assert_eq!(searcher.use_as_suggestion(2).unwrap(), preview);
+    }
+
     #[wasm_bindgen_test]
     fn parsed_input() {
         let parser = Parser::new_or_panic();
@@ -8,8 +8,8 @@ use crate::prelude::*;
 use crate::constants::VISUALIZATION_DIRECTORY;
 
 use enso_protocol::language_server;
-use ide_view::graph_editor::component::visualization::definition;
-use ide_view::graph_editor::component::visualization;
+use ide_view::graph_editor::api::visualization::definition;
+use ide_view::graph_editor::api::visualization;
 use std::rc::Rc;
 
 
@@ -174,9 +174,9 @@ mod tests {
 
     use enso_protocol::language_server::FileSystemObject;
     use enso_protocol::language_server::Path;
-    use ide_view::graph_editor::builtin;
-    use ide_view::graph_editor::component::visualization;
-    use ide_view::graph_editor::component::visualization::java_script as js_vis;
+    use ide_view::graph_editor::api::visualization;
+    use ide_view::graph_editor::api::visualization::builtin;
+    use ide_view::graph_editor::api::visualization::java_script as js_vis;
     use json_rpc::expect_call;
 
     use wasm_bindgen_test::wasm_bindgen_test_configure;
@@ -228,7 +228,7 @@ mod tests {
 
         let language_server             = language_server::Connection::new_mock_rc(mock_client);
         let mut embedded_visualizations = EmbeddedVisualizations::default();
-        let embedded_visualization      = builtin::visualization::native::BubbleChart::definition();
+        let embedded_visualization      = builtin::BubbleChart::definition();
         embedded_visualizations.insert("[Demo] Bubble Visualization".to_string(), embedded_visualization.clone());
         let vis_controller              = Handle::new(language_server,embedded_visualizations);
 
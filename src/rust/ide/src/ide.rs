@@ -23,7 +23,15 @@ pub use initializer::Initializer;
 
 /// Text that shows up in the statusbar when any of the backend connections is lost.
 pub const BACKEND_DISCONNECTED_MESSAGE:&str =
-    "Connection to the backend has been lost. Please try restarting IDE.";
+    "Connection to the backend has been lost. Reconnecting...";
+
+/// Text that shows up in the statusbar when the lost backend connection is reestablished.
+pub const BACKEND_RECONNECTED_MESSAGE:&str = "Connection to the backend has been restored.";
+
+/// Text that shows up in the statusbar when the session of the reestablished backend connection
+/// could not be initialized again.
+pub const BACKEND_REINITIALIZATION_FAILED_MESSAGE:&str =
+    "Failed to restore the backend session. Reopen the project to avoid losing changes.";
 
 const ALIVE_LOG_INTERVAL_SEC:u64 = 60;
 
@@ -9,7 +9,7 @@ use crate::prelude::*;
 use crate::controller::ide::StatusNotification;
 use crate::model::undo_redo::Aware;
 
-use ide_view::graph_editor::SharedHashMap;
+use ide_view::graph_editor::api::SharedHashMap;
 
 
 // =======================
@@ -137,6 +137,10 @@ impl Integration {
                     controller::ide::Notification::ProjectOpened     => {
                         model.setup_and_display_new_project()
                     }
+                    // The views displaying project names refresh themselves when edited, and the
+                    // deleted project is never the displayed one.
+                    controller::ide::Notification::ProjectRenamed |
+                    controller::ide::Notification::ProjectDeleted => {}
                 }
             }
             futures::future::ready(())
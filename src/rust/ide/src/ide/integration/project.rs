@@ -23,7 +23,6 @@ use crate::ide::integration::visualization::Manager as VisualizationManager;
 use crate::model::execution_context::ComputedValueInfo;
 use crate::model::execution_context::ExpressionId;
 use crate::model::execution_context::LocalCall;
-use crate::model::execution_context::VisualizationUpdateData;
 use crate::model::module::ProjectMetadata;
 use crate::model::suggestion_database;
 use crate::model::traits::*;
@@ -34,16 +33,18 @@ use enso_data::text::TextChange;
 use enso_frp as frp;
 use enso_protocol::language_server::ExpressionUpdatePayload;
 use ensogl::display::traits::*;
+use ensogl::system::web;
+use ensogl::system::web::clipboard;
 use ensogl_gui_components::file_browser::model::AnyFolderContent;
 use ensogl_gui_components::list_view;
 use ensogl_web::drop;
 use futures::future::LocalBoxFuture;
-use ide_view::graph_editor;
-use ide_view::graph_editor::component::node;
-use ide_view::graph_editor::component::visualization;
-use ide_view::graph_editor::EdgeEndpoint;
-use ide_view::graph_editor::GraphEditor;
-use ide_view::graph_editor::SharedHashMap;
+use ide_view::graph_editor::api as graph_editor;
+use ide_view::graph_editor::api::node;
+use ide_view::graph_editor::api::visualization;
+use ide_view::graph_editor::api::EdgeEndpoint;
+use ide_view::graph_editor::api::GraphEditor;
+use ide_view::graph_editor::api::SharedHashMap;
 use ide_view::searcher::entry::AnyModelProvider;
 use ide_view::searcher::entry::GlyphHighlightedLabel;
 use ide_view::searcher::new::Icon;
@@ -85,13 +86,28 @@ struct VisualizationAlreadyAttached(graph_editor::NodeId);
 #[fail(display="The Graph Integration hsd no SearcherController.")]
 struct MissingSearcherController;
 
+#[derive(Clone,Debug,Fail)]
+#[fail(display="The pasted text {:?} is not a single line expression.", _0)]
+struct InvalidPastedExpression(String);
+
 /// Denotes visualizations set in the graph editor.
-#[derive(Clone,Copy,Debug,Display)]
+#[derive(Clone,Copy,Debug,Display,Eq,PartialEq)]
 pub enum WhichVisualization {
     /// Usual visualization, triggered by the user.
     Normal,
     /// Special visualization, attached automatically when there is an error on the node.
     Error,
+    /// Visualization of the pinned panels of the node, attached independently of the node's own
+    /// visualization.
+    Pinned,
+}
+
+/// The refresh of the graph view deferred until the veil of the level transition covers the
+/// scene, so the graph of the new level is swapped unnoticed.
+#[derive(Clone,Copy,Debug,Default)]
+struct DeferredRefresh {
+    /// The node selected once the graph is refreshed, e.g. the node which was just exited.
+    node_to_select : Option<double_representation::node::Id>,
 }
 
 // ====================
@@ -160,6 +176,21 @@ const DEFAULT_NODE_X_POSITION   : f32 = -100.0;
 /// node was added by editing text.
 const DEFAULT_NODE_Y_POSITION   : f32 =  200.0;
 
+/// The label of the status bar process displayed while the project is being saved.
+const AUTOSAVE_PROCESS_LABEL:&str = "Saving project.";
+
+/// The expression of the node inserted by splitting an edge. It is replaced with the source of the
+/// split connection once the node is connected.
+const SPLIT_NODE_PLACEHOLDER:&str = "Nothing";
+
+/// The label of the entry added to the node's context menu, copying the node's expression to the
+/// clipboard. The expression may then be pasted as a new node with the background's menu.
+const COPY_EXPRESSION_ENTRY:&str = "Copy Expression";
+
+/// The prefixes of the documentation links opened in a new browser window. The other links are
+/// the names of the documented entities, searched for in the searcher.
+const EXTERNAL_LINK_PREFIXES:&[&str] = &["http://","https://","mailto:"];
+
 /// Default node position -- acts as a starting points for laying out nodes with no position defined
 /// in the metadata.
 pub fn default_node_position() -> Vector2 {
@@ -196,19 +227,25 @@ struct Model {
     graph                   : controller::ExecutedGraph,
     text                    : controller::Text,
     ide                     : controller::Ide,
+    autosave                : controller::Autosave,
     searcher                : RefCell<Option<controller::Searcher>>,
     project                 : model::Project,
     main_module             : model::Module,
     node_views              : RefCell<BiMap<ast::Id,graph_editor::NodeId>>,
     node_view_by_expression : RefCell<HashMap<ast::Id,graph_editor::NodeId>>,
-    expression_views        : RefCell<HashMap<graph_editor::NodeId,graph_editor::component::node::Expression>>,
+    expression_views        : RefCell<HashMap<graph_editor::NodeId,node::Expression>>,
     expression_types        : SharedHashMap<ExpressionId,Option<graph_editor::Type>>,
     connection_views        : RefCell<BiMap<controller::graph::Connection,graph_editor::EdgeId>>,
     code_view               : CloneRefCell<ensogl_text::Text>,
     visualizations          : Rc<VisualizationManager>,
     error_visualizations    : Rc<VisualizationManager>,
+    pinned_visualizations   : Rc<VisualizationManager>,
     prompt_was_shown        : Cell<bool>,
     displayed_project_list  : CloneRefCell<ProjectsToOpen>,
+    deferred_refresh        : Cell<Option<DeferredRefresh>>,
+    veil_covered            : Cell<bool>,
+    pinned_metadata         : RefCell<HashMap<graph_editor::NodeId,visualization::Metadata>>,
+    vis_selections          : RefCell<HashMap<graph_editor::NodeId,Vec<usize>>>,
 }
 
 
@@ -268,11 +305,21 @@ impl Integration {
         // === Setting Visualization Preprocessor ===
 
         frp::extend! { network
+            eval editor_outs.pinned_visualization_changed ([model]((node_id,metadata)) {
+                model.pinned_visualization_changed(*node_id,metadata.clone());
+            });
+            eval editor_outs.visualization_selection_changed ([model]((node_id,indices)) {
+                model.visualization_selection_changed(*node_id,indices.clone());
+            });
             eval editor_outs.visualization_preprocessor_changed ([model]((node_id,preprocessor)) {
-                if let Err(err) = model.visualization_preprocessor_changed(*node_id,preprocessor.clone_ref()) {
+                let preprocessor = preprocessor.clone_ref();
+                let result       = model.visualization_preprocessor_changed(*node_id,preprocessor);
+                let accepted     = result.is_ok();
+                if let Err(err) = result {
                     error!(model.logger, "Error when handling request for setting new \
                         visualization's preprocessor code: {err}");
                 }
+                model.view.graph().acknowledge_visualization_preprocessor((*node_id,accepted));
             });
         }
 
@@ -287,6 +334,24 @@ impl Integration {
         }
 
 
+        // === Documentation Links ===
+
+        frp::extend! { network
+            eval searcher_frp.documentation_link_clicked ([model](target) {
+                if let Err(err) = model.documentation_link_clicked(target) {
+                    error!(model.logger, "Error when following the documentation link: {err}");
+                }
+            });
+        }
+
+
+        // === Context Menu ===
+
+        let node_menu  = graph_editor::context_menu::TargetKind::Node;
+        let copy_entry = (node_menu,COPY_EXPRESSION_ENTRY.into());
+        model.view.graph().frp.register_context_menu_entry.emit(copy_entry);
+
+
         // === Dropping Files ===
 
         let dropping_enabled = model.view.drop_files_enabled.clone_ref();
@@ -311,6 +376,27 @@ impl Integration {
         }
 
 
+        // === Dropping Text ===
+
+        let text_dropped = model.view.graph().text_dropped.clone_ref();
+        frp::extend! { network
+            text_node_requested <- text_dropped.gate(&dropping_enabled);
+            eval text_node_requested ([model]((expression,position)) {
+                let graph    = model.graph.graph();
+                let position = model::module::Position {vector:*position};
+                let metadata = model::module::NodeMetadata {position:Some(position),..default()};
+                let node     = controller::graph::NewNodeInfo {
+                    metadata          : Some(metadata),
+                    introduce_pattern : true,
+                    ..controller::graph::NewNodeInfo::new_pushed_back(expression.clone())
+                };
+                if let Err(err) = graph.add_node(node) {
+                    error!(model.logger, "Error when creating node from dropped text: {err}");
+                }
+            });
+        }
+
+
         // === Open File or Project Dialog ===
 
         let file_browser = &model.view.open_dialog().file_browser;
@@ -368,12 +454,16 @@ impl Integration {
         let inv                       = &invalidate.trigger;
         let node_editing_in_ui        = Model::node_editing_in_ui(Rc::downgrade(&model));
         let searcher_opened_in_ui     = Model::searcher_opened_in_ui(Rc::downgrade(&model));
+        let context_menu_action_in_ui = Model::context_menu_action_in_ui(Rc::downgrade(&model));
         let code_changed              = Self::ui_action(&model,Model::code_changed_in_ui          ,inv);
         let node_removed              = Self::ui_action(&model,Model::node_removed_in_ui          ,inv);
         let nodes_collapsed           = Self::ui_action(&model,Model::nodes_collapsed_in_ui       ,inv);
+        let edge_split                = Self::ui_action(&model,Model::edge_split_in_ui            ,inv);
+        let node_reordered            = Self::ui_action(&model,Model::node_reordered_in_ui        ,inv);
+        let context_menu_action       = Self::ui_action(&model,context_menu_action_in_ui          ,inv);
         let node_selected             = Self::ui_action(&model,Model::node_selected_in_ui         ,inv);
         let node_deselected           = Self::ui_action(&model,Model::node_deselected_in_ui       ,inv);
-        let node_entered              = Self::ui_action(&model,Model::node_entered_in_ui          ,inv);
+        let call_entered              = Self::ui_action(&model,Model::call_entered_in_ui          ,inv);
         let node_exited               = Self::ui_action(&model,|model,_| { model.node_exited_in_ui(); Ok(()) },inv);
         let connection_created        = Self::ui_action(&model,Model::connection_created_in_ui    ,inv);
         let connection_removed        = Self::ui_action(&model,Model::connection_removed_in_ui    ,inv);
@@ -381,7 +471,9 @@ impl Integration {
         let searcher_opened           = Self::ui_action(&model,searcher_opened_in_ui              ,inv);
         let node_editing              = Self::ui_action(&model,node_editing_in_ui                 ,inv);
         let node_expression_set       = Self::ui_action(&model,Model::node_expression_set_in_ui   ,inv);
+        let node_literal_adjusted     = Self::ui_action(&model,Model::node_literal_adjusted_in_ui ,inv);
         let used_as_suggestion        = Self::ui_action(&model,Model::used_as_suggestion_in_ui    ,inv);
+        let suggestion_hovered        = Self::ui_action(&model,Model::suggestion_hovered_in_ui    ,inv);
         let node_editing_committed    = Self::ui_action(&model,Model::node_editing_committed_in_ui,inv);
         let node_editing_aborted      = Self::ui_action(&model,Model::node_editing_aborted_in_ui  ,inv);
         let visualization_path_changed = Self::ui_action(&model,Model::visualization_path_changed_in_ui  ,inv);
@@ -400,17 +492,33 @@ impl Integration {
                     model.handle_text_notification(*notification);
             ));
 
+            // The graph of the new level is displayed once the level transition covers the scene.
+            let handle_level_transition = FencedAction::fence(&network,
+                f_!(model.on_level_transition_covered())
+            );
+            transition_started <- editor_outs.level_transition_running.on_true();
+            transition_ended   <- editor_outs.level_transition_running.on_false();
+            eval_ transition_started (model.veil_covered.set(false));
+            handle_level_transition.trigger <+ editor_outs.level_transition_covered;
+            handle_level_transition.trigger <+ transition_ended;
+
             // Changes in Graph Editor
             is_handling_notification <- handle_graph_notification.is_running
-                                     || handle_text_notification.is_running;
-            is_hold                  <- is_handling_notification || invalidate.is_running;
+                                     || handle_text_notification.is_running
+                                     || handle_level_transition.is_running;
+            is_applying_changes      <- is_handling_notification || editor_outs.synchronizing
+                                     || editor_outs.batching;
+            is_hold                  <- is_applying_changes || invalidate.is_running;
             on_connection_removed    <- editor_outs.on_edge_endpoint_unset._0();
             _action <- code_editor.changed                  .map2(&is_hold,code_changed);
             _action <- editor_outs.node_removed             .map2(&is_hold,node_removed);
             _action <- editor_outs.nodes_collapsed          .map2(&is_hold,nodes_collapsed);
+            _action <- editor_outs.edge_split               .map2(&is_hold,edge_split);
+            _action <- editor_outs.node_reordered           .map2(&is_hold,node_reordered);
+            _action <- editor_outs.context_menu_action      .map2(&is_hold,context_menu_action);
             _action <- editor_outs.node_selected            .map2(&is_hold,node_selected);
             _action <- editor_outs.node_deselected          .map2(&is_hold,node_deselected);
-            _action <- editor_outs.node_entered             .map2(&is_hold,node_entered);
+            _action <- editor_outs.call_entered             .map2(&is_hold,call_entered);
             _action <- editor_outs.node_exited              .map2(&is_hold,node_exited);
             _action <- editor_outs.on_edge_endpoints_set    .map2(&is_hold,connection_created);
             _action <- on_connection_removed                .map2(&is_hold,connection_removed);
@@ -418,7 +526,9 @@ impl Integration {
             _action <- editor_outs.node_being_edited        .map2(&is_hold,node_editing);
             _action <- project_frp.searcher_opened          .map2(&is_hold,searcher_opened);
             _action <- editor_outs.node_expression_set      .map2(&is_hold,node_expression_set);
+            _action <- editor_outs.node_literal_adjusted    .map2(&is_hold,node_literal_adjusted);
             _action <- searcher_frp.used_as_suggestion      .map2(&is_hold,used_as_suggestion);
+            _action <- searcher_frp.selected_entry          .map2(&is_hold,suggestion_hovered);
             _action <- project_frp.editing_committed        .map2(&is_hold,node_editing_committed);
             _action <- project_frp.editing_aborted          .map2(&is_hold,node_editing_aborted);
             _action <- editor_outs.enabled_visualization_path.map2(&is_hold,visualization_path_changed);
@@ -441,6 +551,24 @@ impl Integration {
             eval_ project_frp.redo              (model.redo_in_ui());
         }
 
+
+        // === Autosave Status ===
+
+        let status_bar  = model.view.status_bar();
+        let autosave    = &model.autosave.frp;
+        let save_label  = ide_view::status_bar::process::Label::from(AUTOSAVE_PROCESS_LABEL);
+        let error_label = |message:&String| {
+            ide_view::status_bar::event::Label::from(message.as_str())
+        };
+        frp::extend! { network
+            save_started  <- autosave.saving.on_true();
+            save_finished <- autosave.saving.on_false();
+            status_bar.add_process <+ save_started.constant(save_label);
+            save_process  <- status_bar.last_process.sample(&save_started);
+            status_bar.finish_process <+ save_process.sample(&save_finished);
+            status_bar.add_event <+ autosave.save_failed.map(error_label);
+        }
+
         frp::extend! { network
             eval_ editor_outs.node_editing_started([]analytics::remote_log_event("graph_editor::node_editing_started"));
             eval_ editor_outs.node_editing_finished([]analytics::remote_log_event("graph_editor::node_editing_finished"));
@@ -477,14 +605,30 @@ impl Integration {
     }
 
     fn setup_handling_project_notifications(&self) {
+        use model::project::BackendConnection::LanguageServerJson;
+        use model::project::Notification;
         let stream     = self.model.project.subscribe();
         let logger     = self.model.logger.clone_ref();
         let status_bar = self.model.view.status_bar().clone_ref();
+        let graph      = self.model.view.graph().clone_ref();
         self.spawn_sync_stream_handler(stream, move |notification,_| {
             info!(logger,"Processing notification {notification:?}");
+            // The graph is edited through the JSON connection, so it cannot be edited without it.
+            // The outage ends also when the session fails to be restored, so the user is not left
+            // with the read-only graph, and is told to reopen the project instead.
+            match notification {
+                Notification::ConnectionLost(LanguageServerJson) =>
+                    graph.frp.set_read_only.emit(true),
+                Notification::ConnectionRestored(LanguageServerJson)
+                | Notification::ReinitializationFailed(LanguageServerJson) =>
+                    graph.frp.set_read_only.emit(false),
+                _ => {}
+            }
             let message = match notification {
-                model::project::Notification::ConnectionLost(_) =>
-                    crate::BACKEND_DISCONNECTED_MESSAGE,
+                Notification::ConnectionLost(_)         => crate::BACKEND_DISCONNECTED_MESSAGE,
+                Notification::ConnectionRestored(_)     => crate::BACKEND_RECONNECTED_MESSAGE,
+                Notification::ReinitializationFailed(_) =>
+                    crate::BACKEND_REINITIALIZATION_FAILED_MESSAGE,
             };
             let message = ide_view::status_bar::event::Label::from(message);
             status_bar.add_event(message);
@@ -573,16 +717,27 @@ impl Model {
         let searcher                = default();
         let prompt_was_shown        = default();
         let displayed_project_list  = default();
+        let deferred_refresh        = default();
+        let veil_covered            = default();
+        let pinned_metadata         = default();
+        let vis_selections          = default();
+        let autosave_interval       = controller::autosave::DEFAULT_INTERVAL_MS;
+        let autosave                = controller::Autosave::new(&project,autosave_interval);
+        autosave.track_module(main_module.clone_ref());
         let (visualizations, visualizations_notifications)             = crate::integration::visualization::Manager::new(logger.sub("visualizations"), graph.clone_ref(),project.clone_ref());
         let (error_visualizations, error_visualizations_notifications) = crate::integration::visualization::Manager::new(logger.sub("error_visualizations"), graph.clone_ref(),project.clone_ref());
+        let (pinned_visualizations, pinned_visualizations_notifications) = crate::integration::visualization::Manager::new(logger.sub("pinned_visualizations"), graph.clone_ref(),project.clone_ref());
         let this                    = Model
-            {logger,view,graph,text,ide,searcher,project,main_module,node_views
+            {logger,view,graph,text,ide,autosave,searcher,project,main_module,node_views
             ,node_view_by_expression,expression_views,expression_types,connection_views,code_view
-            ,visualizations,error_visualizations,prompt_was_shown,displayed_project_list};
+            ,visualizations,error_visualizations,pinned_visualizations,prompt_was_shown
+            ,displayed_project_list,deferred_refresh,veil_covered,pinned_metadata
+            ,vis_selections};
         let this = Rc::new(this);
 
         this.spawn_visualization_handler(visualizations_notifications, WhichVisualization::Normal);
         this.spawn_visualization_handler(error_visualizations_notifications, WhichVisualization::Error);
+        this.spawn_visualization_handler(pinned_visualizations_notifications, WhichVisualization::Pinned);
 
         let graph_frp = this.view.graph().frp.clone_ref();
         graph_frp.remove_all_nodes();
@@ -645,11 +800,16 @@ impl Model {
     fn rename_project(&self, name:impl Str) {
         if self.project.name() != name.as_ref() {
             let project     = self.project.clone_ref();
+            let ide         = self.ide.clone_ref();
             let breadcrumbs = self.view.graph().model.breadcrumbs.clone_ref();
             let logger      = self.logger.clone_ref();
             let name        = name.into();
             spawn(async move {
-                if let Err(e) = project.rename_project(name).await {
+                let result = match ide.manage_projects() {
+                    Ok(manage_projects) => manage_projects.rename_project(project.id(),name).await,
+                    Err(_)              => project.rename_project(name).await,
+                };
+                if let Err(e) = result {
                     info!(logger, "The project couldn't be renamed: {e}");
                     breadcrumbs.cancel_project_name_editing.emit(());
                 }
@@ -724,13 +884,16 @@ impl Model {
         }
     }
 
-    /// Reload whole displayed content to be up to date with module state.
+    /// Reload whole displayed content to be up to date with module state. The view is updated in a
+    /// single batch, so the graph editor refreshes the execution order and edges only once.
     pub fn refresh_graph_view(&self) -> FallibleResult {
         info!(self.logger, "Refreshing the graph view.");
-        let connections_info = self.graph.connections()?;
-        self.refresh_node_views(&connections_info, true)?;
-        self.refresh_connection_views(connections_info.connections)?;
-        Ok(())
+        self.view.graph().batch(|_| {
+            let connections_info = self.graph.connections()?;
+            self.refresh_node_views(&connections_info, true)?;
+            self.refresh_connection_views(connections_info.connections)?;
+            Ok(())
+        })
     }
 
     fn refresh_node_views
@@ -813,14 +976,6 @@ impl Model {
         }
     }
 
-    fn deserialize_visualization_data
-    (data:VisualizationUpdateData) -> FallibleResult<visualization::Data> {
-        let binary  = data.as_ref();
-        let as_text = std::str::from_utf8(binary)?;
-        let as_json : serde_json::Value = serde_json::from_str(as_text)?;
-        Ok(visualization::Data::from(as_json))
-    }
-
     fn refresh_node_view
     (&self, id:graph_editor::NodeId, node:&controller::graph::Node, trees:NodeTrees) {
         self.refresh_node_position(id,node);
@@ -895,7 +1050,7 @@ impl Model {
     /// Update the expression of the node and all related properties e.g., types, ports).
     fn refresh_node_expression
     (&self, id:graph_editor::NodeId, node:&controller::graph::Node, trees:NodeTrees) {
-        let code_and_trees = graph_editor::component::node::Expression {
+        let code_and_trees = node::Expression {
             pattern             : node.info.pattern().map(|t|t.repr()),
             code                : node.info.expression().repr(),
             whole_expression_id : node.info.expression().id ,
@@ -936,7 +1091,8 @@ impl Model {
     /// Look up the computed information for a given expression and pass the information to the
     /// graph editor view.
     ///
-    /// The computed value information includes the expression type and the target method pointer.
+    /// The computed value information includes the expression type, the target method pointer and
+    /// the execution time measured by the engine.
     fn refresh_computed_info(&self, id:ExpressionId, force_type_info_refresh:bool) {
         let info     = self.lookup_computed_info(&id);
         let info     = info.as_ref();
@@ -956,6 +1112,9 @@ impl Model {
                 if let Err(error) = set_error_result {
                     error!(self.logger, "Error when setting error on node: {error}");
                 }
+                if let Some(time) = info.and_then(|info| info.execution_time) {
+                    self.view.graph().frp.input.set_node_profiling_data.emit((node_id,time));
+                }
             }
         }
     }
@@ -987,6 +1146,7 @@ impl Model {
         match which {
             WhichVisualization::Normal => &self.visualizations,
             WhichVisualization::Error  => &self.error_visualizations,
+            WhichVisualization::Pinned => &self.pinned_visualizations,
         }
     }
 
@@ -1000,20 +1160,23 @@ impl Model {
         match notification {
             Notification::ValueUpdate {target,data,..} => {
                 if let Ok(view_id) = self.get_displayed_node_id(target) {
-                    let endpoint = &self.view.graph().frp.input.set_visualization_data;
-                    match Self::deserialize_visualization_data(data) {
-                        Ok(data)   => endpoint.emit((view_id, data)),
-                        Err(error) =>
-                        // TODO [mwu]
-                        //  We should consider having the visualization also accept error input.
-                            error!(self.logger, "Failed to deserialize visualization update: {error}"),
-                    }
+                    // The payload is passed as is. It is decoded by the visualization container,
+                    // according to the data format expected by the displayed visualization.
+                    let data     = visualization::Data::from(data.into_bytes());
+                    let inputs   = &self.view.graph().frp.input;
+                    let endpoint = match which {
+                        WhichVisualization::Pinned => &inputs.set_pinned_visualization_data,
+                        _                          => &inputs.set_visualization_data,
+                    };
+                    endpoint.emit((view_id, data));
                 }
             }
             Notification::FailedToAttach {visualization,error} => {
                 error!(self.logger, "Visualization {visualization.id} failed to attach: {error}.");
+                if which == WhichVisualization::Pinned { return }
                 if let Ok(node_view_id) = self.get_displayed_node_id(visualization.expression_id) {
                     self.view.graph().disable_visualization(node_view_id);
+                    self.reject_visualization_preprocessor(which,node_view_id);
                 }
             }
             Notification::FailedToDetach {visualization,error} => {
@@ -1032,16 +1195,27 @@ impl Model {
             Notification::FailedToModify {desired,error} => {
                 error!(self.logger, "Visualization {desired.id} failed to be modified: {error} \
                 Will hide it in GUI.");
+                if which == WhichVisualization::Pinned { return }
                 // Actually it would likely have more sense if we had just restored the previous
                 // visualization, as its LS state should be preserved. However, we already scrapped
                 // it on the GUI side and we don't even know its path anymore.
                 if let Ok(node_view_id) = self.get_displayed_node_id(desired.expression_id) {
                     self.view.graph().disable_visualization(node_view_id);
+                    self.reject_visualization_preprocessor(which,node_view_id);
                 }
             }
         }
     }
 
+    /// Let the node's visualization know that its preprocessor was rejected, so it stops waiting
+    /// for the new data. Error visualizations have no preprocessor set by the view.
+    fn reject_visualization_preprocessor
+    (&self, which:WhichVisualization, node_id:graph_editor::NodeId) {
+        if which == WhichVisualization::Normal {
+            self.view.graph().acknowledge_visualization_preprocessor((node_id,false));
+        }
+    }
+
     /// Route the metadata description as a desired visualization state to the Manager.
     fn update_visualization
     ( &self
@@ -1062,7 +1236,7 @@ impl Model {
         let error     = self.convert_payload_to_error_view(error,node_id);
         let has_error = error.is_some();
         self.view.graph().set_node_error_status(node_id,error);
-        let metadata = has_error.then(graph_editor::builtin::visualization::native::error::metadata);
+        let metadata = has_error.then(visualization::builtin::error::metadata);
         self.update_visualization(node_id,WhichVisualization::Error,metadata)
     }
 
@@ -1193,7 +1367,7 @@ impl Model {
         // In some future (where we can deal better with such scenarios) this code should be
         // reconsidered.
         let _ = self.refresh_call_stack().ok();
-        self.refresh_graph_view()
+        if self.is_refresh_deferred() { Ok(()) } else { self.refresh_graph_view() }
     }
 
     /// Handle notification received from controller about the graph receiving new type information.
@@ -1211,18 +1385,49 @@ impl Model {
         analytics::remote_log_event("integration::node_entered");
         self.view.graph().frp.deselect_all_nodes.emit(&());
         self.push_crumb(local_call);
-        self.refresh_graph_view()
+        self.refresh_level(None)
     }
 
     /// Handle notification received from controller about node having been exited.
     pub fn on_node_exited(&self, id:double_representation::node::Id) -> FallibleResult {
         analytics::remote_log_event("integration::node_exited");
         self.view.graph().frp.deselect_all_nodes.emit(&());
-        self.refresh_graph_view()?;
         self.pop_crumb();
-        let id = self.get_displayed_node_id(id)?;
-        self.view.graph().frp.select_node.emit(&id);
-        Ok(())
+        self.refresh_level(Some(id))
+    }
+
+    /// Refresh the graph view after changing the graph level. While the level transition is
+    /// covering the scene, the refresh is deferred until the veil is opaque, see
+    /// `on_level_transition_covered`.
+    fn refresh_level(&self, node_to_select:Option<double_representation::node::Id>)
+    -> FallibleResult {
+        let transition_running = self.view.graph().frp.level_transition_running.value();
+        if transition_running && !self.veil_covered.get() {
+            self.deferred_refresh.set(Some(DeferredRefresh {node_to_select}));
+            Ok(())
+        } else {
+            self.refresh_graph_view()?;
+            if let Some(id) = node_to_select {
+                let id = self.get_displayed_node_id(id)?;
+                self.view.graph().frp.select_node.emit(&id);
+            }
+            Ok(())
+        }
+    }
+
+    fn is_refresh_deferred(&self) -> bool {
+        self.deferred_refresh.get().is_some()
+    }
+
+    /// Handle the veil of the level transition covering the scene (or the transition ending),
+    /// performing the deferred refresh of the graph view.
+    fn on_level_transition_covered(&self) {
+        self.veil_covered.set(true);
+        if let Some(refresh) = self.deferred_refresh.take() {
+            if let Err(err) = self.refresh_level(refresh.node_to_select) {
+                error!(self.logger,"Error while refreshing the graph beneath the veil: {err}");
+            }
+        }
     }
 
     /// Handle notification received from controller about values having been computed.
@@ -1362,23 +1567,158 @@ impl Model {
         Ok(())
     }
 
-    fn nodes_collapsed_in_ui
-    (&self, (collapsed,_new_node_view_id):&(Vec<graph_editor::NodeId>,graph_editor::NodeId))
-    -> FallibleResult {
+    fn nodes_collapsed_in_ui(&self, collapse:&graph_editor::NodeCollapse) -> FallibleResult {
         debug!(self.logger, "Collapsing node.");
-        let ids          = self.get_controller_node_ids(collapsed)?;
-        let _new_node_id = self.graph.graph().collapse(ids,COLLAPSED_FUNCTION_NAME)?;
-        // TODO [mwu] https://github.com/enso-org/ide/issues/760
-        //   As part of this issue, storing relation between new node's controller and view ids will
-        //   be necessary.
+        let ids         = self.get_controller_node_ids(&collapse.collapsed)?;
+        let new_node_id = self.graph.graph().collapse(ids.iter().copied(),COLLAPSED_FUNCTION_NAME)?;
+        // The collapsed nodes were already removed from the view, and the replacement node was
+        // already created there.
+        let mut node_views = self.node_views.borrow_mut();
+        for id in ids {
+            node_views.remove_by_left(&id);
+        }
+        node_views.insert(new_node_id,collapse.new_node);
+        Ok(())
+    }
+
+    /// Replace the connections of the edges rewired by swapping the nodes in the view with the
+    /// connections they display now. The controller moves the node lines, so every node stays
+    /// below the nodes it depends on.
+    fn node_reordered_in_ui(&self, reorder:&graph_editor::NodeReorder) -> FallibleResult {
+        debug!(self.logger, "Moving node {reorder.node} {reorder.direction:?} along its chain.");
+        let result = reorder.edges.iter().try_for_each(|edge_id| {
+            self.connection_removed_in_ui(edge_id)
+        }).and_then(|_| reorder.edges.iter().try_for_each(|edge_id| {
+            self.connection_created_in_ui(edge_id)
+        }));
+        if result.is_err() {
+            // Refreshing the view restores the edges of the connections present in the graph.
+            self.refresh_graph_view()?;
+        }
+        result
+    }
+
+    fn context_menu_action_in_ui(weak_self:Weak<Self>)
+    -> impl Fn(&Self,&(graph_editor::context_menu::Target,graph_editor::context_menu::Action))
+    -> FallibleResult {
+        use graph_editor::context_menu::Action;
+        use graph_editor::context_menu::Target;
+        move |this,(target,action)| {
+            match (target,action) {
+                (Target::Background(position),Action::Paste) => {
+                    let weak_self = weak_self.clone();
+                    let position  = *position;
+                    clipboard::read_text(move |text| {
+                        if let Some(this) = weak_self.upgrade() {
+                            if let Err(err) = this.paste_node(position,&text) {
+                                error!(this.logger, "Error while pasting the node: {err}");
+                            }
+                        }
+                    });
+                }
+                (Target::Node(node_id),Action::Custom(label))
+                if label.deref() == COPY_EXPRESSION_ENTRY => {
+                    let id   = this.get_controller_node_id(*node_id)?;
+                    let node = this.graph.graph().node(id)?;
+                    clipboard::write_text(node.info.expression().repr());
+                }
+                _ => {}
+            }
+            Ok(())
+        }
+    }
+
+    /// Open the external link clicked in the documentation in a new window, or search for the
+    /// documented entity the link points to.
+    fn documentation_link_clicked(&self, target:&str) -> FallibleResult {
+        let is_external = EXTERNAL_LINK_PREFIXES.iter().any(|prefix| target.starts_with(prefix));
+        if is_external {
+            let opened = web::window().open_with_url_and_target(target,"_blank");
+            if let Err(err) = opened {
+                error!(self.logger, "Cannot open the documentation link {target}: {err:?}");
+            }
+        } else {
+            let graph_frp   = &self.view.graph().frp;
+            let error       = || GraphEditorInconsistency;
+            let edited_node = graph_frp.output.node_being_edited.value().ok_or_else(error)?;
+            let expression  = node::Expression::new_plain(target);
+            graph_frp.input.set_node_expression.emit(&(edited_node,expression));
+            if let Some(searcher) = self.searcher.borrow().as_ref() {
+                searcher.set_input(target.to_owned())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Add the node with the expression pasted from the clipboard at the given position.
+    fn paste_node(&self, position:Vector2, text:&str) -> FallibleResult {
+        debug!(self.logger, "Pasting the node: {text}.");
+        let expression = text.trim();
+        if expression.is_empty() || expression.contains('\n') {
+            return Err(InvalidPastedExpression(text.to_owned()).into())
+        }
+        let position = model::module::Position {vector:position};
+        let metadata = model::module::NodeMetadata {position:Some(position),..default()};
+        let node     = controller::graph::NewNodeInfo {
+            metadata : Some(metadata),
+            ..controller::graph::NewNodeInfo::new_pushed_back(expression)
+        };
+        self.graph.graph().add_node(node)?;
         Ok(())
     }
 
+    fn edge_split_in_ui
+    (&self, (_,node_id,in_edge,out_edge):&(graph_editor::EdgeId,graph_editor::NodeId
+        ,graph_editor::EdgeId,graph_editor::EdgeId)) -> FallibleResult {
+        debug!(self.logger, "Splitting an edge with the node {node_id}.");
+        let result = self.insert_split_node(*node_id,*in_edge,*out_edge);
+        if result.is_err() {
+            // Refreshing the view removes the node and the edges missing in the graph.
+            self.node_views.borrow_mut().remove_by_right(node_id);
+            self.refresh_graph_view()?;
+        }
+        result
+    }
+
+    /// Add the node created in the view by splitting an edge to the graph, and connect it with
+    /// the endpoints of the split connection.
+    fn insert_split_node
+    (&self, node_id:graph_editor::NodeId, in_edge:graph_editor::EdgeId
+    , out_edge:graph_editor::EdgeId) -> FallibleResult {
+        let editor      = self.view.graph();
+        let edge        = editor.model.edges.get_cloned(&in_edge);
+        let source      = edge.and_then(|edge| edge.source()).ok_or(GraphEditorInconsistency)?;
+        let source_node = self.get_controller_node_id(source.node_id)?;
+        let vector      = editor.model.node_position(node_id);
+        let position    = model::module::Position {vector};
+        let metadata    = model::module::NodeMetadata {position:Some(position),..default()};
+        let node        = controller::graph::NewNodeInfo {
+            metadata      : Some(metadata),
+            location_hint : controller::graph::LocationHint::After(source_node),
+            ..controller::graph::NewNodeInfo::new_pushed_back(SPLIT_NODE_PLACEHOLDER)
+        };
+        let graph    = self.graph.graph();
+        let new_node = graph.add_node(node)?;
+        self.node_views.borrow_mut().insert(new_node,node_id);
+        let connected = self.connection_created_in_ui(&out_edge)
+            .and_then(|_| self.connection_created_in_ui(&in_edge));
+        if connected.is_err() {
+            for edge_id in &[in_edge,out_edge] {
+                if let Ok(connection) = self.get_controller_connection(*edge_id) {
+                    self.connection_views.borrow_mut().remove_by_left(&connection);
+                    let _ = self.graph.disconnect(&connection);
+                }
+            }
+            graph.remove_node(new_node)?;
+        }
+        connected
+    }
+
     fn node_expression_set_in_ui
     (&self, (displayed_id,expression):&(graph_editor::NodeId,String)) -> FallibleResult {
         debug!(self.logger, "Setting node {displayed_id} expression: {expression}.");
         let searcher       = self.searcher.borrow();
-        let code_and_trees = graph_editor::component::node::Expression::new_plain(expression);
+        let code_and_trees = node::Expression::new_plain(expression);
         self.expression_views.borrow_mut().insert(*displayed_id,code_and_trees);
         if let Some(searcher) = searcher.as_ref() {
             searcher.set_input(expression.clone())?;
@@ -1386,6 +1726,15 @@ impl Model {
         Ok(())
     }
 
+    fn node_literal_adjusted_in_ui
+    (&self, (displayed_id,expression):&(graph_editor::NodeId,String)) -> FallibleResult {
+        debug!(self.logger, "Adjusting node {displayed_id} literal: {expression}.");
+        if let Ok(id) = self.get_controller_node_id(*displayed_id) {
+            self.graph.graph().set_expression(id,expression)?
+        }
+        Ok(())
+    }
+
     fn searcher_opened_in_ui(weak_self:Weak<Self>)
     -> impl Fn(&Self,&graph_editor::NodeId) -> FallibleResult {
         move |this,displayed_id| {
@@ -1441,6 +1790,26 @@ impl Model {
         Ok(())
     }
 
+    /// Preview the suggestion hovered (or selected with keyboard) in the searcher in the edited
+    /// node. The actions which are not suggestions, like examples, have no preview.
+    fn suggestion_hovered_in_ui
+    (&self, entry:&Option<ide_view::searcher::entry::Id>) -> FallibleResult {
+        let graph_frp = &self.view.graph().frp;
+        if let Some(edited_node) = graph_frp.output.node_being_edited.value() {
+            let searcher = self.searcher.borrow().clone();
+            let preview  = searcher.zip(*entry).and_then(|(searcher,entry)|
+                searcher.preview_suggestion(entry).ok());
+            match preview {
+                Some(code) => {
+                    let expression = node::Expression::new_plain(code);
+                    graph_frp.input.preview_node_expression.emit(&(edited_node,expression));
+                }
+                None => graph_frp.input.clear_expression_preview.emit(&edited_node),
+            }
+        }
+        Ok(())
+    }
+
     fn node_editing_committed_in_ui
     (&self, (displayed_id,entry_id):&(graph_editor::NodeId,Option<ide_view::searcher::entry::Id>))
     -> FallibleResult {
@@ -1572,13 +1941,11 @@ impl Model {
         Ok(())
     }
 
-    fn node_entered_in_ui(&self, node_id:&graph_editor::NodeId) -> FallibleResult {
-        debug!(self.logger,"Requesting entering the node {node_id}.");
-        let call           = self.get_controller_node_id(*node_id)?;
-        let method_pointer = self.graph.node_method_pointer(call)?;
-        let definition     = (*method_pointer).clone();
-        let local_call     = LocalCall{call,definition};
-        self.expression_entered_in_ui(&Some(local_call))
+    fn call_entered_in_ui(&self, local_call:&graph_editor::LocalCall) -> FallibleResult {
+        debug!(self.logger,"Requesting entering the call {local_call.call}.");
+        let definition = (**local_call.definition).clone();
+        let call       = local_call.call;
+        self.expression_entered_in_ui(&Some(LocalCall{call,definition}))
     }
 
     fn node_exited_in_ui(&self) {
@@ -1639,6 +2006,53 @@ impl Model {
         }
     }
 
+    /// Attach the visualization of the node's pinned panels, restricted to the items selected in
+    /// the node's visualization.
+    fn pinned_visualization_changed
+    (&self, node_id:graph_editor::NodeId, metadata:Option<visualization::Metadata>) {
+        match &metadata {
+            Some(metadata) => self.pinned_metadata.borrow_mut().insert(node_id,metadata.clone()),
+            None           => self.pinned_metadata.borrow_mut().remove(&node_id),
+        };
+        self.attach_pinned_visualization(node_id);
+    }
+
+    /// Remember the items selected in the node's visualization, and display only them in the
+    /// node's pinned panels.
+    fn visualization_selection_changed(&self, node_id:graph_editor::NodeId, indices:Vec<usize>) {
+        if indices.is_empty() {
+            self.vis_selections.borrow_mut().remove(&node_id);
+        } else {
+            self.vis_selections.borrow_mut().insert(node_id,indices);
+        }
+        if self.pinned_metadata.borrow().contains_key(&node_id) {
+            self.attach_pinned_visualization(node_id);
+        }
+    }
+
+    fn attach_pinned_visualization(&self, node_id:graph_editor::NodeId) {
+        let metadata  = self.pinned_metadata.borrow().get(&node_id).cloned();
+        let selection = self.vis_selections.borrow().get(&node_id).cloned();
+        let metadata  = metadata.map(|metadata| match selection {
+            Some(indices) => Self::restrict_to_selection(metadata,&indices),
+            None          => metadata,
+        });
+        let which = WhichVisualization::Pinned;
+        if let Err(err) = self.update_visualization(node_id,which,metadata) {
+            error!(self.logger, "Error when attaching the pinned visualization: {err}");
+        }
+    }
+
+    /// Wrap the preprocessor, so it receives only the items of the visualized vector at the given
+    /// indices.
+    fn restrict_to_selection
+    (mut metadata:visualization::Metadata, indices:&[usize]) -> visualization::Metadata {
+        let code    = metadata.preprocessor.code.to_string();
+        let indices = indices.iter().map(ToString::to_string).join(",");
+        metadata.preprocessor.code = format!("x -> ({}) ([{}].map x.at)",code,indices).into();
+        metadata
+    }
+
     fn visualization_preprocessor_changed
     ( &self
     , node_id      : graph_editor::NodeId
@@ -1839,19 +2253,12 @@ impl list_view::entry::ModelProvider<GlyphHighlightedLabel> for SuggestionsProvi
     fn get(&self, id: usize) -> Option<list_view::entry::GlyphHighlightedLabelModel> {
         let action = self.actions.get_cloned(id)?;
         if let MatchInfo::Matches {subsequence} = action.match_info {
-            let label         = action.action.to_string();
-            let mut char_iter = label.char_indices().enumerate();
-            let highlighted   = subsequence.indices.iter().filter_map(|idx| loop {
-                if let Some(char) = char_iter.next() {
-                    let (char_idx,(byte_id,char)) = char;
-                    if char_idx == *idx {
-                        let start = ensogl_text::Bytes(byte_id as i32);
-                        let end   = ensogl_text::Bytes((byte_id + char.len_utf8()) as i32);
-                        break Some(ensogl_text::Range::new(start,end))
-                    }
-                } else {
-                    break None;
-                }
+            let label       = action.action.to_string();
+            let spans       = subsequence.highlight_spans(&label).into_iter();
+            let highlighted = spans.map(|span| {
+                let start = ensogl_text::Bytes(span.start as i32);
+                let end   = ensogl_text::Bytes(span.end as i32);
+                ensogl_text::Range::new(start,end)
             }).collect();
             Some(list_view::entry::GlyphHighlightedLabelModel {label,highlighted})
         } else {
@@ -11,10 +11,10 @@ use crate::controller::ExecutedGraph;
 
 use futures::channel::mpsc::UnboundedReceiver;
 use futures::future::ready;
-use ide_view::graph_editor::component::visualization;
-use ide_view::graph_editor::SharedHashMap;
-use ide_view::graph_editor::component::visualization::instance::ContextModule;
-use ide_view::graph_editor::component::visualization::Metadata;
+use ide_view::graph_editor::api::visualization;
+use ide_view::graph_editor::api::SharedHashMap;
+use ide_view::graph_editor::api::visualization::instance::ContextModule;
+use ide_view::graph_editor::api::visualization::Metadata;
 
 // ================================
 // === Resolving Context Module ===
@@ -503,8 +503,8 @@ mod tests {
     use utils::test::traits::*;
 
     use futures::future::ready;
-    use ide_view::graph_editor::component::visualization::instance::ContextModule;
-    use ide_view::graph_editor::component::visualization::instance::PreprocessorConfiguration;
+    use ide_view::graph_editor::api::visualization::instance::ContextModule;
+    use ide_view::graph_editor::api::visualization::instance::PreprocessorConfiguration;
     use wasm_bindgen_test::wasm_bindgen_test;
 
     #[derive(Shrinkwrap)]
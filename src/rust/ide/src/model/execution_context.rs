@@ -12,6 +12,7 @@ use enso_protocol::language_server;
 use enso_protocol::language_server::ExpressionUpdate;
 use enso_protocol::language_server::ExpressionUpdatePayload;
 use enso_protocol::language_server::MethodPointer;
+use enso_protocol::language_server::ProfilingInfo;
 use enso_protocol::language_server::SuggestionId;
 use enso_protocol::language_server::VisualisationConfiguration;
 use flo_stream::Subscriber;
@@ -19,6 +20,7 @@ use mockall::automock;
 use serde::Deserialize;
 use serde::Serialize;
 use std::collections::HashMap;
+use std::time::Duration;
 use utils::future::ready_boxed;
 use uuid::Uuid;
 
@@ -48,18 +50,24 @@ pub type ExpressionId = ast::Id;
 pub struct ComputedValueInfo {
     /// The string representing the full qualified typename of the computed value, e.g.
     /// "Standard.Base.Number".
-    pub typename    : Option<ImString>,
-    pub payload     : ExpressionUpdatePayload,
+    pub typename       : Option<ImString>,
+    pub payload        : ExpressionUpdatePayload,
     /// If the expression is a method call (i.e. can be entered), this points to the target method.
-    pub method_call : Option<SuggestionId>,
+    pub method_call    : Option<SuggestionId>,
+    /// The time the engine spent on computing the value, if it was profiled.
+    pub execution_time : Option<Duration>,
 }
 
 impl From<ExpressionUpdate> for ComputedValueInfo {
     fn from(update:ExpressionUpdate) -> Self {
+        let execution_time = update.profiling_info.iter().map(|info| match info {
+            ProfilingInfo::ExecutionTime {nano_time} => Duration::from_nanos(*nano_time),
+        }).next();
         ComputedValueInfo {
             typename    : update.typename.map(ImString::new),
             method_call : update.method_pointer,
-            payload     : update.payload
+            payload     : update.payload,
+            execution_time,
         }
     }
 }
@@ -167,6 +175,11 @@ impl VisualizationUpdateData {
     pub fn new(data:Vec<u8>) -> VisualizationUpdateData {
         VisualizationUpdateData(data)
     }
+
+    /// The binary data of the update.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
 }
 
 impl AsRef<[u8]> for VisualizationUpdateData {
@@ -438,4 +451,14 @@ mod tests {
         let notification = test.expect_completion(subscriber.next()).unwrap();
         assert_eq!(notification, vec![expr2,expr3]);
     }
+
+    #[test]
+    fn reading_execution_time_from_expression_update() {
+        let mut update = value_update_with_type(ExpressionId::new_v4(),"Test.Typename");
+        assert_eq!(ComputedValueInfo::from(update.clone()).execution_time, None);
+
+        update.profiling_info = vec![ProfilingInfo::ExecutionTime {nano_time:1_500_000}];
+        let execution_time = ComputedValueInfo::from(update).execution_time;
+        assert_eq!(execution_time, Some(Duration::from_micros(1500)));
+    }
 }
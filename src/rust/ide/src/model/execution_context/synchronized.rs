@@ -44,7 +44,7 @@ pub enum Notification {
 /// from LS once dropped.
 #[derive(Debug)]
 pub struct ExecutionContext {
-    id              : model::execution_context::Id,
+    id              : Cell<model::execution_context::Id>,
     model           : model::execution_context::Plain,
     language_server : Rc<language_server::Connection>,
     logger          : Logger,
@@ -53,7 +53,7 @@ pub struct ExecutionContext {
 impl ExecutionContext {
     /// The unique identifier of this execution context.
     pub fn id(&self) -> model::execution_context::Id {
-        self.id
+        self.id.get()
     }
 
     /// Create new ExecutionContext. It will be created in LanguageServer and the ExplicitCall
@@ -73,6 +73,7 @@ impl ExecutionContext {
             let logger = Logger::new_sub(&parent,iformat!{"ExecutionContext {id}"});
             let model  = model::execution_context::Plain::new(&logger,root_definition);
             info!(logger, "Created. Id: {id}.");
+            let id   = Cell::new(id);
             let this = Self {id,model,language_server,logger};
             this.push_root_frame().await?;
             info!(this.logger, "Pushed root frame.");
@@ -88,7 +89,7 @@ impl ExecutionContext {
         let call = language_server::ExplicitCall {method_pointer,this_argument_expression,
             positional_arguments_expressions};
         let frame  = language_server::StackItem::ExplicitCall(call);
-        let result = self.language_server.push_to_execution_context(&self.id,&frame);
+        let result = self.language_server.push_to_execution_context(&self.id(),&frame);
         result.map(|res| res.map_err(|err| err.into()))
     }
 
@@ -99,7 +100,7 @@ impl ExecutionContext {
     async fn detach_visualization_inner
     (&self, vis:Visualization) -> FallibleResult<Visualization> {
         let vis_id = vis.id;
-        let exe_id = self.id;
+        let exe_id = self.id();
         let ast_id = vis.expression_id;
         let ls     = self.language_server.clone_ref();
         let logger = self.logger.clone_ref();
@@ -111,13 +112,35 @@ impl ExecutionContext {
         Ok(vis)
     }
 
+    /// Create the context again in the Language Server, which forgets all the execution contexts
+    /// when the connection is lost. The context gets a new id, and its stack frames are pushed
+    /// and its visualizations attached again.
+    pub async fn recreate(&self) -> FallibleResult {
+        info!(self.logger, "Recreating.");
+        let id = self.language_server.client.create_execution_context().await?.context_id;
+        self.id.set(id);
+        self.push_root_frame().await?;
+        let stack_items = self.model.stack_items().collect_vec();
+        for stack_item in stack_items {
+            let call  = language_server::LocalCall {expression_id:stack_item.call};
+            let frame = language_server::StackItem::LocalCall(call);
+            self.language_server.push_to_execution_context(&id,&frame).await?;
+        }
+        for vis in self.model.all_visualizations_info() {
+            let config = vis.config(id);
+            self.language_server.attach_visualisation(&vis.id,&vis.expression_id,&config).await?;
+        }
+        info!(self.logger, "Recreated. Id: {id}.");
+        Ok(())
+    }
+
     /// Handles the update about expressions being computed.
     pub fn handle_notification
     (&self, notification: Notification) -> FallibleResult {
         match notification {
             Notification::Completed => {
                 if !self.model.is_ready.replace(true) {
-                    WARNING!("Context {self.id} Became ready");
+                    WARNING!("Context {self.id()} Became ready");
                 }
             }
             Notification::ExpressionUpdates(updates) => {
@@ -163,7 +186,7 @@ impl model::execution_context::API for ExecutionContext {
             let expression_id = stack_item.call;
             let call          = language_server::LocalCall{expression_id};
             let frame         = language_server::StackItem::LocalCall(call);
-            self.language_server.push_to_execution_context(&self.id,&frame).await?;
+            self.language_server.push_to_execution_context(&self.id(),&frame).await?;
             self.model.push(stack_item);
             Ok(())
         }.boxed_local()
@@ -174,7 +197,7 @@ impl model::execution_context::API for ExecutionContext {
             // We do pop first, because we want to call any ls method if the operation is impossible
             // in the plain model.
             let frame  = self.model.pop()?;
-            let result = self.language_server.pop_from_execution_context(&self.id).await;
+            let result = self.language_server.pop_from_execution_context(&self.id()).await;
             if let Err(err) = result {
                 self.model.push(frame);
                 Err(err.into())
@@ -191,7 +214,7 @@ impl model::execution_context::API for ExecutionContext {
         //  We must register our visualization in the model first, because Language server can send
         //  us visualization updates through the binary socket before confirming that visualization
         //  has been successfully attached.
-        let config = vis.config(self.id);
+        let config = vis.config(self.id());
         let stream = self.model.attach_visualization(vis.clone());
 
         async move {
@@ -217,7 +240,7 @@ impl model::execution_context::API for ExecutionContext {
     (&self, id:VisualizationId, expression:Option<String>, module:Option<module::QualifiedName>)
     -> BoxFuture<FallibleResult> {
         let result     = self.model.modify_visualization(id,expression,module);
-        let new_config = self.model.visualization_config(id,self.id);
+        let new_config = self.model.visualization_config(id,self.id());
         async move {
             result?;
             self.language_server.modify_visualisation(&id,&new_config?).await?;
@@ -234,7 +257,7 @@ impl model::execution_context::API for ExecutionContext {
 
 impl Drop for ExecutionContext {
     fn drop(&mut self) {
-        let id     = self.id;
+        let id     = self.id();
         let ls     = self.language_server.clone_ref();
         let logger = self.logger.clone_ref();
         executor::global::spawn(async move {
@@ -305,25 +328,25 @@ pub mod test {
             CreateExecutionContext {context_id,can_modify,receives_updates}
         }
 
-        /// Sets up mock client expectations for context creation and destruction.
-        fn mock_create_destroy_calls(data:&MockData, ls:&mut language_server::MockClient) {
-            let id     = data.context_id;
-            let result = Self::expected_creation_response(data);
-            expect_call!(ls.create_execution_context()    => Ok(result));
-            expect_call!(ls.destroy_execution_context(id) => Ok(()));
-        }
-
         /// Sets up mock client expectations for context creation, initial frame push
         /// and destruction.
         pub fn mock_create_push_destroy_calls(data:&MockData, ls:&mut language_server::MockClient) {
-            Self::mock_create_destroy_calls(&data,ls);
+            let id = data.context_id;
+            Self::mock_create_push_calls(data,ls);
+            expect_call!(ls.destroy_execution_context(id) => Ok(()));
+        }
+
+        /// Sets up mock client expectations for context creation and initial frame push.
+        fn mock_create_push_calls(data:&MockData, ls:&mut language_server::MockClient) {
             let id         = data.context_id;
+            let result     = Self::expected_creation_response(data);
             let root_frame = language_server::ExplicitCall {
                 method_pointer                   : data.main_method_pointer(),
                 this_argument_expression         : None,
                 positional_arguments_expressions : vec![]
             };
             let stack_item = language_server::StackItem::ExplicitCall(root_frame);
+            expect_call!(ls.create_execution_context() => Ok(result));
             expect_call!(ls.push_to_execution_context(id,stack_item) => Ok(()));
         }
 
@@ -350,7 +373,7 @@ pub mod test {
     #[test]
     fn creating_context() {
         let f = Fixture::new();
-        assert_eq!(f.data.context_id, f.context.id);
+        assert_eq!(f.data.context_id, f.context.id());
         let name_in_data      = f.data.module_qualified_name();
         let name_in_ctx_model = QualifiedName::try_from(&f.context.model.entry_point);
         assert_eq!(name_in_data, name_in_ctx_model.unwrap());
@@ -381,6 +404,41 @@ pub mod test {
         });
     }
 
+    #[test]
+    fn recreating_context() {
+        let expression_id = model::execution_context::ExpressionId::new_v4();
+        let vis = Visualization {
+            id                : model::execution_context::VisualizationId::new_v4(),
+            expression_id,
+            preprocessor_code : "".to_string(),
+            context_module    : MockData::new().module_qualified_name(),
+        };
+        let Fixture{data,mut test,context} = Fixture::new_customized(|ls,data| {
+            let id         = data.context_id;
+            let vis_id     = vis.id;
+            let call       = language_server::LocalCall{expression_id};
+            let stack_item = language_server::StackItem::LocalCall(call);
+            let config     = vis.config(id);
+            expect_call!(ls.push_to_execution_context(id,stack_item) => Ok(()));
+            expect_call!(ls.attach_visualisation(vis_id,expression_id,config) => Ok(()));
+            // The calls made by recreating the context.
+            let stack_item = language_server::StackItem::LocalCall(call);
+            let config     = vis.config(id);
+            Fixture::mock_create_push_calls(data,ls);
+            expect_call!(ls.push_to_execution_context(id,stack_item) => Ok(()));
+            expect_call!(ls.attach_visualisation(vis_id,expression_id,config) => Ok(()));
+        });
+        test.run_task(async move {
+            let item = LocalCall {
+                call       : expression_id,
+                definition : data.main_method_pointer(),
+            };
+            context.push(item).await.unwrap();
+            let _ = context.attach_visualization(vis.clone()).await.unwrap();
+            context.recreate().await.unwrap();
+        });
+    }
+
     #[test]
     fn attaching_visualizations_and_notifying() {
         let vis = Visualization {
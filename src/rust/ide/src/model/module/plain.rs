@@ -149,10 +149,15 @@ impl model::module::API for Module {
 
     fn apply_code_change
     (&self, change:TextChange, parser:&Parser, new_id_map:ast::IdMap) -> FallibleResult {
-        let code              = self.ast().repr();
+        let ast               = self.ast();
+        let code              = ast.repr();
         let replaced_location = TextLocation::convert_range(&code,&change.replaced);
-        let new_code          = change.applied(&code);
-        let new_ast           = parser.parse(new_code,new_id_map)?.try_into()?;
+        // Reparsing only the changed line keeps the rest of the module AST intact, falling back to
+        // parsing the whole code when the change does not fit a single line.
+        let new_ast : ast::known::Module = match parser.reparse(ast.ast(),&change,&new_id_map) {
+            Ok(reparsed) => reparsed.ast.try_into()?,
+            Err(_)       => parser.parse(change.applied(&code),new_id_map)?.try_into()?,
+        };
         let notification      = NotificationKind::CodeChanged {change,replaced_location};
         self.update_content(notification,|content| content.ast = new_ast)
     }
@@ -123,6 +123,8 @@ pub struct Module {
     model           : model::module::Plain,
     language_server : Rc<language_server::Connection>,
     logger          : Logger,
+    /// The Language Server content after the module was reopened, to be picked up by the `runner`.
+    reopened        : RefCell<Option<LanguageServerContent>>,
 }
 
 
@@ -147,12 +149,14 @@ impl Module {
         let end_of_file = TextLocation::at_document_end(&opened.content);
         // TODO[ao] We should not fail here when metadata are malformed, but discard them and set
         //  default instead.
-        let source  = parser.parse_with_metadata(opened.content)?;
-        let digest  = opened.current_version;
-        let summary = ContentSummary {digest,end_of_file};
-        let model   = model::module::Plain::new(&logger,path,source.ast,source.metadata,repository);
-        let this    = Rc::new(Module {model,language_server,logger});
-        let content = this.model.serialized_content()?;
+        let source   = parser.parse_with_metadata(opened.content)?;
+        let digest   = opened.current_version;
+        let summary  = ContentSummary {digest,end_of_file};
+        let metadata = source.metadata;
+        let model    = model::module::Plain::new(&logger,path,source.ast,metadata,repository);
+        let reopened = default();
+        let this     = Rc::new(Module {model,language_server,logger,reopened});
+        let content  = this.model.serialized_content()?;
         let first_invalidation = this.full_invalidation(&summary,content);
         executor::global::spawn(Self::runner(this.clone_ref(),summary,first_invalidation));
         Ok(this)
@@ -165,7 +169,34 @@ impl Module {
         client.expect.close_text_file(|_| Ok(()));
         // We don't expect any other call, because we don't execute `runner()`.
         let language_server = language_server::Connection::new_mock_rc(client);
-        Rc::new(Module{model,language_server,logger})
+        let reopened        = default();
+        Rc::new(Module{model,language_server,logger,reopened})
+    }
+
+    /// Open the module again in Language Server, which closes all the files when the connection
+    /// is lost. The whole content of the module is sent, so no change made while the connection
+    /// was down is lost.
+    pub async fn reopen(&self) -> FallibleResult {
+        let file_path = self.path().file_path().clone();
+        info!(self.logger, "Reopening module {file_path}");
+        let opened      = self.language_server.client.open_text_file(&file_path).await?;
+        let end_of_file = TextLocation::at_document_end(&opened.content);
+        let digest      = opened.current_version;
+        let summary     = ContentSummary {digest,end_of_file};
+        let content     = self.model.serialized_content()?;
+        let result      = self.full_invalidation(&summary,content).await;
+        let ls_summary  = match result {
+            Ok(content) => content.summary,
+            Err(err)    => {
+                error!(self.logger,"Error during sending module content to Language Server: {err}");
+                summary
+            }
+        };
+        // The notifications still waiting for the runner describe the changes which were already
+        // sent, so the next one must invalidate the whole file.
+        let ls_content = LanguageServerContent::Desynchronized(ls_summary);
+        *self.reopened.borrow_mut() = Some(ls_content);
+        Ok(())
     }
 }
 
@@ -252,6 +283,9 @@ impl Module {
             match (notification,this) {
                 (Some(notification),Some(this)) => {
                     debug!(this.logger,"Processing a notification: {notification:?}");
+                    if let Some(reopened) = this.reopened.borrow_mut().take() {
+                        ls_content = reopened;
+                    }
                     let result = this.handle_notification(&ls_content,notification).await;
                     ls_content = this.new_ls_content_info(ls_content.summary().clone(),result)
                 }
@@ -37,6 +37,9 @@ pub trait API:Debug {
     /// Project's qualified name
     fn qualified_name(&self) -> QualifiedName;
 
+    /// Project's id, as assigned by the Project Manager.
+    fn id(&self) -> Uuid;
+
     /// Get Language Server JSON-RPC Connection for this project.
     fn json_rpc(&self) -> Rc<language_server::Connection>;
 
@@ -166,8 +169,15 @@ pub type Synchronized = synchronized::Project;
 /// Notification emitted by the project model.
 #[derive(Clone,Copy,Debug,PartialEq)]
 pub enum Notification {
-    /// One of the backend connections has been lost.
-    ConnectionLost(BackendConnection)
+    /// One of the backend connections has been lost. The requests awaiting the reply fail, and
+    /// the connection is being reestablished.
+    ConnectionLost(BackendConnection),
+    /// One of the backend connections has been reestablished after being lost, and its session
+    /// has been initialized again.
+    ConnectionRestored(BackendConnection),
+    /// One of the backend connections has been reestablished after being lost, but its session
+    /// could not be initialized again, so the state held by the backend may be lost.
+    ReinitializationFailed(BackendConnection),
 }
 
 /// Denotes one of backend connections used by a project.
@@ -89,6 +89,22 @@ impl ExecutionContextsRegistry {
     pub fn insert(&self, context:Rc<execution_context::Synchronized>) {
         self.0.borrow_mut().insert(context.id(),context);
     }
+
+    /// Create again all the registered contexts in the Language Server, which forgets them when
+    /// the connection is lost. The contexts are registered again under their new ids.
+    pub async fn recreate_all(&self) -> FallibleResult {
+        let contexts = self.0.borrow().values().collect_vec();
+        for context in contexts {
+            let old_id = context.id();
+            let result = context.recreate().await;
+            with(self.0.borrow_mut(), |mut map| {
+                map.remove(&old_id);
+                map.insert(context.id(),context.clone_ref());
+            });
+            result?;
+        }
+        Ok(())
+    }
 }
 
 
@@ -197,8 +213,94 @@ impl Display for UnsupportedEngineVersion {
 
 
 
+// === Session Reinitialization ===
+
+fn acquire_suggestion_db_updates_capability
+(language_server:&language_server::Connection) -> impl Future<Output=json_rpc::Result<()>> {
+    let capability = CapabilityRegistration::create_receives_suggestions_database_updates();
+    language_server.acquire_capability(&capability.method,&capability.register_options)
+}
+
+/// Initialize the session of the reopened JSON-RPC connection and restore the state held by the
+/// Language Server in the previous session: the capabilities acquired by the project model, the
+/// opened modules, the execution contexts and their visualizations. The Language Server does not
+/// remember any of them after the previous connection was lost.
+async fn reinitialize_json_session
+( connection         : Rc<language_server::Connection>
+, module_registry    : Rc<ModuleRegistry>
+, execution_contexts : Rc<ExecutionContextsRegistry>
+) -> FallibleResult {
+    connection.init_protocol_connection(&connection.client_id).await?;
+    acquire_suggestion_db_updates_capability(&connection).await?;
+    for module in module_registry.loaded() {
+        module.reopen().await?;
+    }
+    execution_contexts.recreate_all().await
+}
+
+/// The number of attempts to reinitialize the session of the reopened connection before giving up.
+const SESSION_REINITIALIZATION_ATTEMPTS : usize = 3;
+
+/// Notify that the connection was lost, unless it was already reported in the ongoing outage. The
+/// connection is closed again on every failed reconnect attempt, so the outage is reported once.
+fn report_connection_lost
+( logger          : &Logger
+, publisher       : &notification::Publisher<model::project::Notification>
+, which           : model::project::BackendConnection
+, connection_lost : &Cell<bool>
+) {
+    if connection_lost.replace(true) {
+        debug!(logger,"The {which:?} connection is still lost.");
+    } else {
+        error!(logger,"Lost the {which:?} connection with the Language Server!");
+        publisher.notify(model::project::Notification::ConnectionLost(which));
+    }
+}
+
+/// Spawn the reinitialization of the reopened connection session, notifying that the connection
+/// was restored once it succeeds. The failed reinitialization is retried a few times before the
+/// failure is notified; in both cases the outage ends.
+fn spawn_session_reinitialization<Init,InitFuture>
+( logger          : &Logger
+, publisher       : &notification::Publisher<model::project::Notification>
+, which           : model::project::BackendConnection
+, connection_lost : &Rc<Cell<bool>>
+, init            : Init
+) where Init       : Fn() -> InitFuture + 'static,
+        InitFuture : Future<Output=FallibleResult> {
+    let logger          = logger.clone_ref();
+    let publisher       = publisher.clone_ref();
+    let connection_lost = connection_lost.clone_ref();
+    executor::global::spawn(async move {
+        let mut attempt = 1;
+        let result = loop {
+            match init().await {
+                Err(err) if attempt < SESSION_REINITIALIZATION_ATTEMPTS => {
+                    warning!(logger,"Failed to reinitialize the {which:?} connection (attempt \
+                        {attempt}): {err}");
+                    attempt += 1;
+                }
+                result => break result,
+            }
+        };
+        connection_lost.set(false);
+        match result {
+            Ok(()) => publisher.notify(model::project::Notification::ConnectionRestored(which)),
+            Err(err) => {
+                error!(logger,"Failed to reinitialize the {which:?} connection: {err}");
+                publisher.notify(model::project::Notification::ReinitializationFailed(which));
+            }
+        }
+    });
+}
+
+
+
 // === Data ===
 
+/// The registry of the modules opened in the project.
+pub type ModuleRegistry = model::registry::Registry<module::Path,module::Synchronized>;
+
 /// A structure containing the project's properties.
 #[allow(missing_docs)]
 #[derive(Debug,Clone)]
@@ -222,7 +324,7 @@ pub struct Project {
     pub project_manager     : Option<Rc<dyn project_manager::API>>,
     pub language_server_rpc : Rc<language_server::Connection>,
     pub language_server_bin : Rc<binary::Connection>,
-    pub module_registry     : Rc<model::registry::Registry<module::Path,module::Synchronized>>,
+    pub module_registry     : Rc<ModuleRegistry>,
     pub execution_contexts  : Rc<ExecutionContextsRegistry>,
     pub visualization       : controller::Visualization,
     pub suggestion_db       : Rc<SuggestionDatabase>,
@@ -334,6 +436,9 @@ impl Project {
         let logger                  = self.logger.clone_ref();
         let publisher               = self.notifications.clone_ref();
         let weak_execution_contexts = Rc::downgrade(&self.execution_contexts);
+        let weak_connection         = Rc::downgrade(&self.language_server_bin);
+        let connection_lost         = Rc::new(Cell::new(false));
+        let which                   = model::project::BackendConnection::LanguageServerBinary;
         move |event| {
             debug!(logger, "Received an event from the binary protocol: {event:?}");
             use enso_protocol::binary::client::Event;
@@ -353,13 +458,15 @@ impl Project {
                     }
                 }
                 Event::Closed => {
-                    error!(logger,"Lost binary connection with the Language Server!");
-                    let which        = model::project::BackendConnection::LanguageServerBinary;
-                    let notification = model::project::Notification::ConnectionLost(which);
-                    publisher.notify(notification);
-                    // TODO [wmu]
-                    //   The connection should be reestablished, see
-                    //   https://github.com/enso-org/ide/issues/145
+                    report_connection_lost(&logger,&publisher,which,&connection_lost);
+                }
+                Event::Opened => {
+                    info!(logger,"Binary connection with the Language Server has been reopened.");
+                    if let Some(connection) = weak_connection.upgrade() {
+                        let init = move || connection.init(connection.client_id);
+                        let lost = &connection_lost;
+                        spawn_session_reinitialization(&logger,&publisher,which,lost,init);
+                    }
                 }
                 Event::Error(error) => {
                     error!(logger,"Error emitted by the binary data connection: {error}.");
@@ -403,7 +510,12 @@ impl Project {
         let publisher                = self.notifications.clone_ref();
         let weak_suggestion_db       = Rc::downgrade(&self.suggestion_db);
         let weak_content_roots       = Rc::downgrade(&self.content_roots);
+        let weak_connection          = Rc::downgrade(&self.language_server_rpc);
+        let weak_module_registry     = Rc::downgrade(&self.module_registry);
+        let weak_execution_contexts  = Rc::downgrade(&self.execution_contexts);
         let execution_update_handler = self.execution_update_handler();
+        let connection_lost          = Rc::new(Cell::new(false));
+        let which                    = model::project::BackendConnection::LanguageServerJson;
         move |event| {
             debug!(logger, "Received an event from the json-rpc protocol: {event:?}");
             use enso_protocol::language_server::Event;
@@ -442,13 +554,21 @@ impl Project {
                     }
                 }
                 Event::Closed => {
-                    error!(logger,"Lost JSON-RPC connection with the Language Server!");
-                    let which        = model::project::BackendConnection::LanguageServerJson;
-                    let notification = model::project::Notification::ConnectionLost(which);
-                    publisher.notify(notification);
-                    // TODO [wmu]
-                    //  The connection should be reestablished, 
-                    //  see https://github.com/enso-org/ide/issues/145
+                    report_connection_lost(&logger,&publisher,which,&connection_lost);
+                }
+                Event::Opened => {
+                    info!(logger,"JSON-RPC connection with the Language Server has been reopened.");
+                    let connection         = weak_connection.upgrade();
+                    let module_registry    = weak_module_registry.upgrade();
+                    let execution_contexts = weak_execution_contexts.upgrade();
+                    if let (Some(connection),Some(module_registry),Some(execution_contexts)) =
+                        (connection,module_registry,execution_contexts) {
+                        let init = move || reinitialize_json_session
+                            (connection.clone_ref(),module_registry.clone_ref(),
+                             execution_contexts.clone_ref());
+                        let lost = &connection_lost;
+                        spawn_session_reinitialization(&logger,&publisher,which,lost,init);
+                    }
                 }
                 Event::Error(error) => {
                     error!(logger,"Error emitted by the JSON-RPC data connection: {error}.");
@@ -459,8 +579,7 @@ impl Project {
     }
 
     fn acquire_suggestion_db_updates_capability(&self) -> impl Future<Output=json_rpc::Result<()>> {
-        let capability = CapabilityRegistration::create_receives_suggestions_database_updates();
-        self.language_server_rpc.acquire_capability(&capability.method,&capability.register_options)
+        acquire_suggestion_db_updates_capability(&self.language_server_rpc)
     }
 
     fn load_module(&self, path:module::Path)
@@ -486,6 +605,10 @@ impl model::project::API for Project {
         self.properties.borrow().name.clone()
     }
 
+    fn id(&self) -> Uuid {
+        self.properties.borrow().id
+    }
+
     fn json_rpc(&self) -> Rc<language_server::Connection> {
         self.language_server_rpc.clone_ref()
     }
@@ -650,6 +773,10 @@ mod test {
             f.test.run_until_stalled();
             let event = events.expect_next();
             assert_eq!(event,expected_event);
+            // The failed reconnect attempt closes the connection again during the same outage.
+            close_socket(&mut f);
+            f.test.run_until_stalled();
+            events.expect_pending();
         }
 
         run(Notification::ConnectionLost(LanguageServerBinary), |f| {
@@ -661,6 +788,94 @@ mod test {
         });
     }
 
+    #[wasm_bindgen_test]
+    fn notify_failed_reinitialization_after_retrying() {
+        use crate::model::project::Notification;
+        use crate::model::project::BackendConnection::*;
+
+        let mut f = Fixture::new(|_| {}, |ls_binary| {
+            ls_binary.expect_init().times(SESSION_REINITIALIZATION_ATTEMPTS).returning(|_| {
+                futures::future::ready(Err(failure::format_err!("Init failed."))).boxed_local()
+            });
+        });
+        let mut events = f.project.subscribe().boxed_local();
+
+        f.binary_events_sender.send(binary::Event::Closed).boxed_local().expect_ok();
+        f.test.run_until_stalled();
+        assert_eq!(events.expect_next(),Notification::ConnectionLost(LanguageServerBinary));
+
+        f.binary_events_sender.send(binary::Event::Opened).boxed_local().expect_ok();
+        f.test.run_until_stalled();
+        assert_eq!(events.expect_next(),Notification::ReinitializationFailed(LanguageServerBinary));
+
+        // The failure ends the outage, so the next loss is reported again.
+        f.binary_events_sender.send(binary::Event::Closed).boxed_local().expect_ok();
+        f.test.run_until_stalled();
+        assert_eq!(events.expect_next(),Notification::ConnectionLost(LanguageServerBinary));
+    }
+
+    #[wasm_bindgen_test]
+    fn reinitialize_reopened_connections() {
+        use crate::model::project::Notification;
+        use crate::model::project::BackendConnection::*;
+
+        let client_id = Uuid::default();
+        let mut f = Fixture::new(|ls_json| {
+            let content_roots = vec![];
+            let response      = response::InitProtocolConnection {content_roots};
+            expect_call!(ls_json.init_protocol_connection(client_id) => Ok(response));
+            let capability = CapabilityRegistration::create_receives_suggestions_database_updates();
+            let method     = capability.method;
+            let options    = capability.register_options;
+            expect_call!(ls_json.acquire_capability(method,options) => Ok(()));
+        }, |ls_binary| {
+            ls_binary.expect_init().withf(move |id| *id == client_id).times(1)
+                .return_once(|_| futures::future::ready(Ok(())).boxed_local());
+        });
+        let mut events = f.project.subscribe().boxed_local();
+
+        f.binary_events_sender.send(binary::Event::Opened).boxed_local().expect_ok();
+        f.test.run_until_stalled();
+        assert_eq!(events.expect_next(),Notification::ConnectionRestored(LanguageServerBinary));
+
+        f.json_events_sender.send(json_rpc::Event::Opened).boxed_local().expect_ok();
+        f.test.run_until_stalled();
+        assert_eq!(events.expect_next(),Notification::ConnectionRestored(LanguageServerJson));
+    }
+
+    #[wasm_bindgen_test]
+    fn reopen_modules_after_reconnecting() {
+        use crate::model::project::Notification;
+        use crate::model::project::BackendConnection::*;
+
+        let path      = module::Path::from_mock_module_name("TestModule");
+        let file_path = path.file_path().clone();
+        let client_id = Uuid::default();
+        let mut f = Fixture::new(|ls_json| {
+            let content          = "2+2".to_string();
+            let current_version  = Sha3_224::new(content.as_bytes());
+            let can_edit         = CapabilityRegistration::create_can_edit_text_file;
+            let write_capability = Some(can_edit(file_path.clone()));
+            let opened           = response::OpenTextFile {content,current_version,write_capability};
+            mock_calls_for_opening_text_file(ls_json,file_path.clone(),"2+2");
+            let content_roots = vec![];
+            let response      = response::InitProtocolConnection {content_roots};
+            expect_call!(ls_json.init_protocol_connection(client_id) => Ok(response));
+            let capability = CapabilityRegistration::create_receives_suggestions_database_updates();
+            let method     = capability.method;
+            let options    = capability.register_options;
+            expect_call!(ls_json.acquire_capability(method,options) => Ok(()));
+            expect_call!(ls_json.open_text_file(path=file_path) => Ok(opened));
+            ls_json.expect.apply_text_file_edit(|_| Ok(()));
+        }, |_| {});
+        let _module    = f.test.expect_completion(f.project.module(path)).unwrap();
+        let mut events = f.project.subscribe().boxed_local();
+
+        f.json_events_sender.send(json_rpc::Event::Opened).boxed_local().expect_ok();
+        f.test.run_until_stalled();
+        assert_eq!(events.expect_next(),Notification::ConnectionRestored(LanguageServerJson));
+    }
+
     #[wasm_bindgen_test]
     fn obtain_module_controller() {
         let path         = module::Path::from_mock_module_name("TestModule");
@@ -111,6 +111,15 @@ where K : Clone + Eq + Hash {
         }
     }
 
+    /// All the items which are loaded and still alive.
+    pub fn loaded(&self) -> Vec<Rc<V>> {
+        let registry = self.registry.borrow();
+        registry.values().filter_map(|entry| match entry {
+            Entry::Loaded(item) => Some(item),
+            Entry::Loading(_)   => None,
+        }).collect()
+    }
+
     async fn get(&self, key:&K) -> Result<Option<Rc<V>>,LoadingError> {
         loop {
             let entry = self.registry.borrow_mut().get(key);
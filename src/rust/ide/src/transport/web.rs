@@ -2,6 +2,8 @@
 
 use crate::prelude::*;
 
+use enso_protocol::common::reconnect::Backoff;
+use ensogl_system_web as web;
 use ensogl_system_web::js_to_string;
 use ensogl_system_web::event::listener::Slot;
 use failure::Error;
@@ -173,8 +175,13 @@ struct Model {
     /// taken to keep it registered as an event listener *after* `on_close` registration.
     /// By default `Model` takes care of it by itself.
     pub on_close_internal : Slot<event::Close>,
+    /// Special callback on "open" event, resetting the `backoff` once the connection was
+    /// established.
+    pub on_open_internal  : Slot<event::Open>,
     /// When enabled, the WS will try to automatically reconnect whenever connection is lost.
     pub auto_reconnect    : bool,
+    /// The delays between the reconnecting attempts.
+    pub backoff           : Backoff,
 }
 
 impl Model {
@@ -187,7 +194,9 @@ impl Model {
             on_open           : Slot::new(&socket, &logger),
             on_error          : Slot::new(&socket, &logger),
             on_close_internal : Slot::new(&socket, &logger),
+            on_open_internal  : Slot::new(&socket, &logger),
             auto_reconnect    : true,
+            backoff           : default(),
             logger,
             socket,
         }
@@ -210,9 +219,9 @@ impl Model {
         #[allow(clippy::unneeded_field_pattern)]
         let Self{
             // Callback slots to be cleared.
-            on_close, on_error, on_message, on_open, on_close_internal,
+            on_close, on_error, on_message, on_open, on_close_internal, on_open_internal,
             // Explicitly ignored non-slot fields.
-            auto_reconnect:_, logger:_, socket:_
+            auto_reconnect:_, backoff:_, logger:_, socket:_
         } = self;
         // We don't care if removing actually removed anything.
         // If callbacks were not set, then they are clear from the start.
@@ -220,7 +229,8 @@ impl Model {
         on_error.clear_callback();
         on_message.clear_callback();
         on_open.clear_callback();
-        on_close_internal.clear_callback()
+        on_close_internal.clear_callback();
+        on_open_internal.clear_callback()
     }
 
     /// Establish a new WS connection, using the same URL as the previous one.
@@ -240,6 +250,7 @@ impl Model {
         self.on_message.       set_target(&new_ws);
         self.on_open.          set_target(&new_ws);
         self.on_close_internal.set_target(&new_ws);
+        self.on_open_internal. set_target(&new_ws);
         self.socket = new_ws;
 
         Ok(())
@@ -287,15 +298,40 @@ impl WebSocket {
         Ok(wst)
     }
 
-    /// Generate a callback to be invoked when socket needs reconnecting.
+    /// Generate a callback to be invoked when socket needs reconnecting. The reconnecting attempt
+    /// is delayed according to the model's `backoff`. If it fails, the socket gets closed again,
+    /// and the next attempt is scheduled with a longer delay.
     fn reconnect_trigger(&self) -> impl FnMut(web_sys::CloseEvent) {
         let model  = Rc::downgrade(&self.model);
         let logger = self.logger.clone();
         move |_| {
-            if let Some(model) = model.upgrade() {
-                if let Err(e) = model.borrow_mut().reconnect() {
-                    error!(logger,"Failed to reconnect: {js_to_string(&e)}");
+            if let Some(model_rc) = model.upgrade() {
+                if !model_rc.borrow().auto_reconnect {
+                    return
                 }
+                let delay = model_rc.borrow_mut().backoff.next_delay();
+                info!(logger,"Reconnecting in {delay.as_millis()} ms.");
+                let model  = model.clone();
+                let logger = logger.clone();
+                executor::global::spawn(async move {
+                    web::sleep(delay).await;
+                    if let Some(model) = model.upgrade() {
+                        if let Err(e) = model.borrow_mut().reconnect() {
+                            error!(logger,"Failed to reconnect: {js_to_string(&e)}");
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    /// Generate a callback to be invoked when socket has been opened, either initially or after
+    /// reconnecting.
+    fn backoff_reset_trigger(&self) -> impl FnMut(web_sys::Event) {
+        let model = Rc::downgrade(&self.model);
+        move |_| {
+            if let Some(model) = model.upgrade() {
+                model.borrow_mut().backoff.reset();
             }
         }
     }
@@ -322,6 +358,7 @@ impl WebSocket {
             Some(Ok(())) => {
                 self.model.borrow_mut().clear_callbacks();
                 self.model.borrow_mut().on_close_internal.set_callback(self.reconnect_trigger());
+                self.model.borrow_mut().on_open_internal.set_callback(self.backoff_reset_trigger());
                 info!(self.logger, "Connection opened.");
                 Ok(())
             }
@@ -0,0 +1,210 @@
+//! Keyboard accessibility of the graph. The keyboard [`Focus`] is independent of the node
+//! selection. It moves between the nodes in the reading order (see [`reading_order`]) and between
+//! the input ports of the focused node, and is displayed as a ring around the focused node. As the
+//! graph is drawn on a canvas, which is opaque to the screen readers, the description of the
+//! focused element is mirrored in an ARIA live region of the DOM, see [`LiveRegion`].
+
+use crate::prelude::*;
+
+use crate::EdgeEndpoint;
+use crate::NodeId;
+
+use ensogl::system::web;
+use ensogl::system::web::AttributeSetter;
+use ensogl::system::web::NodeInserter;
+use ensogl::system::web::NodeRemover;
+use ensogl::system::web::StyleSetter;
+use std::cmp::Ordering;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The height of a row of the reading order. The nodes whose vertical positions differ by less
+/// than this are considered to lie in the same row, and are ordered from left to right.
+const ROW_HEIGHT : f32 = crate::component::node::HEIGHT;
+
+
+
+// =============
+// === Focus ===
+// =============
+
+/// The graph element having the keyboard focus.
+#[derive(Clone,Debug,Eq,PartialEq)]
+pub enum Focus {
+    /// The whole node.
+    Node(NodeId),
+    /// The input port of the node.
+    Port(EdgeEndpoint),
+}
+
+impl Focus {
+    /// The focused node, or the node the focused port belongs to.
+    pub fn node_id(&self) -> NodeId {
+        match self {
+            Self::Node(node_id)  => *node_id,
+            Self::Port(endpoint) => endpoint.node_id,
+        }
+    }
+
+    /// The crumbs of the focused port, if a port is focused.
+    pub fn port(&self) -> Option<&span_tree::Crumbs> {
+        match self {
+            Self::Node(_)        => None,
+            Self::Port(endpoint) => Some(&endpoint.port),
+        }
+    }
+}
+
+
+
+// ===================
+// === Focus Order ===
+// ===================
+
+/// Sort the nodes in the reading order: the rows from the top to the bottom, and the nodes of
+/// every row from the left to the right. The positions are the positions of the node origins.
+pub fn reading_order(nodes:impl IntoIterator<Item=(NodeId,Vector2)>) -> Vec<NodeId> {
+    let row        = |position:&Vector2| (-position.y / ROW_HEIGHT).round();
+    let mut nodes  = nodes.into_iter().collect_vec();
+    nodes.sort_by(|(lhs_id,lhs),(rhs_id,rhs)| {
+        let by_row = row(lhs).partial_cmp(&row(rhs)).unwrap_or(Ordering::Equal);
+        let by_x   = lhs.x.partial_cmp(&rhs.x).unwrap_or(Ordering::Equal);
+        by_row.then(by_x).then(lhs_id.cmp(rhs_id))
+    });
+    nodes.into_iter().map(|(id,_)| id).collect()
+}
+
+/// The element following the `current` one in the `order`, or preceding it if `forward` is
+/// `false`. The order wraps around. If there is no `current` element, or it is not in the `order`,
+/// the first element is returned, or the last one when moving backwards.
+pub fn cycle<T:Clone+PartialEq>(order:&[T], current:Option<&T>, forward:bool) -> Option<T> {
+    let index = current.and_then(|current| order.iter().position(|t| t == current));
+    let index = match (index,forward) {
+        (Some(index),true)  => (index + 1) % order.len(),
+        (Some(index),false) => (index + order.len() - 1) % order.len(),
+        (None,true)         => 0,
+        (None,false)        => order.len().checked_sub(1)?,
+    };
+    order.get(index).cloned()
+}
+
+
+
+// ===================
+// === Description ===
+// ===================
+
+/// The description of the focused node, announced by the screen readers.
+pub fn node_description(index:usize, count:usize, expression:&str) -> String {
+    format!("Node {} of {}: {}",index + 1,count,expression)
+}
+
+/// The description of the focused input port, announced by the screen readers.
+pub fn port_description
+(name:Option<&str>, tp:Option<&str>, connected:bool, node_expression:&str) -> String {
+    let name      = name.unwrap_or("unnamed");
+    let tp        = tp.map(|tp| format!(" of type {}",tp)).unwrap_or_default();
+    let connected = if connected { "connected" } else { "not connected" };
+    format!("Input {}{}, {}, of node {}",name,tp,connected,node_expression)
+}
+
+
+
+// ==================
+// === LiveRegion ===
+// ==================
+
+/// A visually hidden DOM element with the `status` ARIA role. The screen readers announce every
+/// change of its content. The element is removed from the DOM when the last clone is dropped.
+#[derive(Clone,CloneRef,Debug)]
+pub struct LiveRegion {
+    model : Rc<LiveRegionModel>,
+}
+
+#[derive(Debug)]
+struct LiveRegionModel {
+    logger : Logger,
+    dom    : web::HtmlDivElement,
+}
+
+impl LiveRegion {
+    /// Constructor. The region is appended to the given DOM element.
+    pub fn new(logger:&Logger, parent:&web::HtmlElement) -> Self {
+        let logger = Logger::new_sub(logger,"LiveRegion");
+        let dom    = web::create_div();
+        dom.set_attribute_or_warn("role"       ,"status",&logger);
+        dom.set_attribute_or_warn("aria-live"  ,"polite",&logger);
+        dom.set_attribute_or_warn("aria-atomic","true"  ,&logger);
+        // The standard "visually hidden" styles, keeping the element in the accessibility tree.
+        dom.set_style_or_warn("position"   ,"absolute"     ,&logger);
+        dom.set_style_or_warn("width"      ,"1px"          ,&logger);
+        dom.set_style_or_warn("height"     ,"1px"          ,&logger);
+        dom.set_style_or_warn("margin"     ,"-1px"         ,&logger);
+        dom.set_style_or_warn("padding"    ,"0"            ,&logger);
+        dom.set_style_or_warn("border"     ,"0"            ,&logger);
+        dom.set_style_or_warn("overflow"   ,"hidden"       ,&logger);
+        dom.set_style_or_warn("clip"       ,"rect(0,0,0,0)",&logger);
+        dom.set_style_or_warn("white-space","nowrap"       ,&logger);
+        parent.append_or_warn(&dom,&logger);
+        let model = Rc::new(LiveRegionModel {logger,dom});
+        Self {model}
+    }
+
+    /// Replace the announced message. The empty message clears the region without announcing
+    /// anything.
+    pub fn announce(&self, message:&str) {
+        debug!(self.model.logger, "Announcing: {message}");
+        self.model.dom.set_inner_text(message);
+    }
+}
+
+impl Drop for LiveRegionModel {
+    fn drop(&mut self) {
+        self.dom.remove_from_parent_or_warn(&self.logger);
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ensogl::display::object::Id;
+
+    fn node_id(id:usize) -> NodeId {
+        NodeId(Id::from(id))
+    }
+
+    #[test]
+    fn reading_order_goes_by_rows() {
+        let nodes = vec!
+            [ (node_id(1),Vector2(200.0,  0.0))
+            , (node_id(2),Vector2(  0.0,100.0))
+            , (node_id(3),Vector2(  0.0,  5.0))
+            , (node_id(4),Vector2(100.0,100.0))
+            ];
+        let order    = reading_order(nodes);
+        let expected = vec![2,4,3,1].into_iter().map(node_id).collect_vec();
+        assert_eq!(order,expected);
+    }
+
+    #[test]
+    fn cycling_wraps_around() {
+        let order = vec![1,2,3];
+        assert_eq!(cycle(&order,None,true),Some(1));
+        assert_eq!(cycle(&order,None,false),Some(3));
+        assert_eq!(cycle(&order,Some(&3),true),Some(1));
+        assert_eq!(cycle(&order,Some(&1),false),Some(3));
+        assert_eq!(cycle(&order,Some(&7),true),Some(1));
+        assert_eq!(cycle::<usize>(&[],None,false),None);
+    }
+}
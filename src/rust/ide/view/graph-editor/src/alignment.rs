@@ -0,0 +1,292 @@
+//! Alignment guides displayed while dragging nodes. When the left edge, center, or right edge of
+//! the dragged node gets close to the corresponding anchor of another node (and similarly for the
+//! bottom edge, center, and top edge), a thin line is displayed along the aligned anchors. The
+//! guides are purely visual, the dragged node is snapped only by the grid magnet of `Nodes`.
+
+use crate::prelude::*;
+
+use crate::selection::BoundingBox;
+
+use ensogl::display;
+use ensogl::display::Scene;
+use ensogl::display::shape::*;
+use ensogl_theme::graph_editor::alignment_guide as theme;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The maximum distance between the anchors considered aligned, in scene units.
+pub const THRESHOLD : f32 = 4.0;
+
+/// The anchors closer than this distance are displayed by a single guide, in scene units.
+const MERGE_DISTANCE : f32 = 0.5;
+
+/// The width of the guide line, in pixels.
+const LINE_WIDTH : f32 = 1.0;
+
+/// The length by which the guide extends beyond the aligned nodes, in scene units.
+const OVERHANG : f32 = 12.0;
+
+
+
+// ============
+// === Axis ===
+// ============
+
+/// The axis of the anchor. The guide of the `X` axis anchor is a vertical line, and the guide of
+/// the `Y` axis anchor is a horizontal one.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+#[allow(missing_docs)]
+pub enum Axis {X,Y}
+
+impl Axis {
+    /// The anchors of the bounding box along the axis: the lower edge, the center, and the upper
+    /// edge.
+    fn anchors(self, bbox:&BoundingBox) -> [f32;3] {
+        match self {
+            Axis::X => [bbox.left(),(bbox.left() + bbox.right()) / 2.0,bbox.right()],
+            Axis::Y => [bbox.bottom(),(bbox.bottom() + bbox.top()) / 2.0,bbox.top()],
+        }
+    }
+
+    /// The extent of the bounding box along the perpendicular axis.
+    fn span(self, bbox:&BoundingBox) -> (f32,f32) {
+        match self {
+            Axis::X => (bbox.bottom(),bbox.top()),
+            Axis::Y => (bbox.left(),bbox.right()),
+        }
+    }
+}
+
+
+
+// =============
+// === Guide ===
+// =============
+
+/// A single alignment guide.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct Guide {
+    /// The axis of the aligned anchors.
+    pub axis     : Axis,
+    /// The position of the aligned anchors along the axis.
+    pub position : f32,
+    /// The start of the guide along the perpendicular axis.
+    pub start    : f32,
+    /// The end of the guide along the perpendicular axis.
+    pub end      : f32,
+}
+
+impl Guide {
+    /// The endpoints of the guide line in the scene, including the overhang.
+    fn line(&self) -> (Vector2,Vector2) {
+        let start = self.start - OVERHANG;
+        let end   = self.end + OVERHANG;
+        match self.axis {
+            Axis::X => (Vector2(self.position,start),Vector2(self.position,end)),
+            Axis::Y => (Vector2(start,self.position),Vector2(end,self.position)),
+        }
+    }
+}
+
+
+
+// =============
+// === Index ===
+// =============
+
+/// The anchors of the nodes which are not dragged, sorted along each axis.
+#[derive(Clone,Debug,Default)]
+pub struct Index {
+    x_anchors : Vec<(f32,BoundingBox)>,
+    y_anchors : Vec<(f32,BoundingBox)>,
+}
+
+impl Index {
+    /// Constructor. The `boxes` are the bounding boxes of the nodes which are not dragged.
+    pub fn new(boxes:impl IntoIterator<Item=BoundingBox>) -> Self {
+        let mut x_anchors = Vec::new();
+        let mut y_anchors = Vec::new();
+        for bbox in boxes {
+            x_anchors.extend(Axis::X.anchors(&bbox).iter().map(|&anchor| (anchor,bbox)));
+            y_anchors.extend(Axis::Y.anchors(&bbox).iter().map(|&anchor| (anchor,bbox)));
+        }
+        x_anchors.sort_unstable_by(|a,b| a.0.total_cmp(&b.0));
+        y_anchors.sort_unstable_by(|a,b| a.0.total_cmp(&b.0));
+        Self {x_anchors,y_anchors}
+    }
+
+    fn anchors(&self, axis:Axis) -> &[(f32,BoundingBox)] {
+        match axis {
+            Axis::X => &self.x_anchors,
+            Axis::Y => &self.y_anchors,
+        }
+    }
+
+    /// The anchors lying in the `[min,max]` range along the axis.
+    fn anchors_in(&self, axis:Axis, min:f32, max:f32) -> &[(f32,BoundingBox)] {
+        let anchors = self.anchors(axis);
+        let start   = anchors.partition_point(|(anchor,_)| *anchor < min);
+        let end     = anchors.partition_point(|(anchor,_)| *anchor <= max);
+        &anchors[start..end]
+    }
+
+    /// The guides for the node with the given bounding box. For every anchor of the node, the
+    /// closest anchor of other nodes within `threshold` is found, and the guide spans the node and
+    /// all the other nodes having an anchor at that position.
+    pub fn guides(&self, bbox:&BoundingBox, threshold:f32) -> Vec<Guide> {
+        let mut guides = Vec::<Guide>::new();
+        for &axis in &[Axis::X,Axis::Y] {
+            for &anchor in &axis.anchors(bbox) {
+                let candidates = self.anchors_in(axis,anchor - threshold,anchor + threshold);
+                let closest    = candidates.iter().map(|(position,_)| *position).min_by(|a,b| {
+                    (a - anchor).abs().total_cmp(&(b - anchor).abs())
+                });
+                if let Some(position) = closest {
+                    let duplicate = guides.iter().any(|guide| {
+                        guide.axis == axis && (guide.position - position).abs() < MERGE_DISTANCE
+                    });
+                    if !duplicate {
+                        let aligned      = self.anchors_in(axis,position - MERGE_DISTANCE,
+                            position + MERGE_DISTANCE);
+                        let (start,end)  = axis.span(bbox);
+                        let (start,end)  = aligned.iter().fold((start,end),|(start,end),(_,bb)| {
+                            let (bb_start,bb_end) = axis.span(bb);
+                            (start.min(bb_start),end.max(bb_end))
+                        });
+                        guides.push(Guide {axis,position,start,end});
+                    }
+                }
+            }
+        }
+        guides
+    }
+}
+
+
+
+// ==============
+// === Shapes ===
+// ==============
+
+mod line {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (style:Style) {
+            let width  = Var::<Pixels>::from("input_size.x");
+            let height = Var::<Pixels>::from("input_size.y");
+            let shape  = Rect((&width,&height));
+            shape.fill(style.get_color(theme::color)).into()
+        }
+    }
+}
+
+
+
+// ==============
+// === Guides ===
+// ==============
+
+/// The view of the alignment guides, together with the index of the anchors of the nodes which are
+/// not dragged.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Guides {
+    logger         : Logger,
+    display_object : display::object::Instance,
+    scene          : Scene,
+    index          : Rc<RefCell<Index>>,
+    lines          : Rc<RefCell<Vec<line::View>>>,
+}
+
+impl Guides {
+    /// Constructor.
+    pub fn new(logger:&Logger, scene:&Scene) -> Self {
+        let logger         = Logger::sub(logger,"AlignmentGuides");
+        let display_object = display::object::Instance::new(&logger);
+        let scene          = scene.clone_ref();
+        let index          = default();
+        let lines          = default();
+        Self {logger,display_object,scene,index,lines}
+    }
+
+    /// Replace the index of the anchors, e.g. when the drag starts.
+    pub fn set_index(&self, index:Index) {
+        *self.index.borrow_mut() = index;
+    }
+
+    /// Display the guides for the node with the given bounding box.
+    pub fn update(&self, bbox:&BoundingBox) {
+        let guides = self.index.borrow().guides(bbox,THRESHOLD);
+        self.set_guides(&guides);
+    }
+
+    /// Hide all the guides.
+    pub fn clear(&self) {
+        self.set_guides(&[]);
+    }
+
+    /// Display the given guides, reusing the already created shapes.
+    fn set_guides(&self, guides:&[Guide]) {
+        let mut lines = self.lines.borrow_mut();
+        while lines.len() < guides.len() {
+            lines.push(line::View::new(&self.logger));
+        }
+        for line in lines.iter().skip(guides.len()) {
+            line.unset_parent();
+        }
+        let width = LINE_WIDTH / self.scene.camera().zoom();
+        for (line,guide) in lines.iter().zip(guides) {
+            let (start,end) = guide.line();
+            let size        = match guide.axis {
+                Axis::X => Vector2(width,end.y - start.y),
+                Axis::Y => Vector2(end.x - start.x,width),
+            };
+            self.display_object.add_child(line);
+            line.size.set(size);
+            line.set_position_xy((start + end) / 2.0);
+        }
+    }
+}
+
+impl display::Object for Guides {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.display_object
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node_box(left:f32, bottom:f32) -> BoundingBox {
+        BoundingBox::from_position_and_size(Vector2(left,bottom),Vector2(100.0,30.0))
+    }
+
+    #[test]
+    fn finding_aligned_anchors() {
+        let index  = Index::new(vec![node_box(0.0,0.0),node_box(0.0,200.0),node_box(500.0,500.0)]);
+        let guides = index.guides(&node_box(2.0,100.0),THRESHOLD);
+        assert_eq!(guides.len(),3);
+        assert!(guides.iter().all(|guide| guide.axis == Axis::X));
+        assert_eq!(guides[0],Guide {axis:Axis::X, position:0.0, start:0.0, end:230.0});
+        assert_eq!(guides[1].position,50.0);
+        assert_eq!(guides[2].position,100.0);
+
+        let guides = index.guides(&node_box(300.0,503.0),THRESHOLD);
+        assert_eq!(guides.len(),3);
+        assert!(guides.iter().all(|guide| guide.axis == Axis::Y));
+        assert_eq!(guides[0],Guide {axis:Axis::Y, position:500.0, start:300.0, end:600.0});
+
+        assert!(index.guides(&node_box(250.0,300.0),THRESHOLD).is_empty());
+    }
+}
@@ -0,0 +1,78 @@
+//! The stable public surface of the graph editor view.
+//!
+//! Crates built on top of the graph editor (most notably the IDE integration layer) should import
+//! the graph editor types from this module only, instead of reaching into the deep module paths
+//! like `component::node::expression`. The internal module layout is free to change between
+//! releases, while the items re-exported here are kept at these paths: an item may be added here
+//! at any time, but removing or renaming one is considered a breaking change and should be done
+//! only together with migrating all the consumers.
+
+pub use crate::GraphEditor;
+pub use crate::GraphEditorModel;
+pub use crate::GraphEditorModelWithNetwork;
+pub use crate::Frp;
+pub use crate::FrpEndpoints;
+pub use crate::FrpInputs;
+
+pub use crate::Edge;
+pub use crate::EdgeEndpoint;
+pub use crate::EdgeId;
+pub use crate::LocalCall;
+pub use crate::MethodPointer;
+pub use crate::NodeId;
+pub use crate::NodeProfilingStatus;
+pub use crate::Type;
+
+pub use crate::CollapseBoundaryEdge;
+pub use crate::GraphLayoutDescription;
+pub use crate::NodeCollapse;
+pub use crate::NodeLayout;
+pub use crate::NodeReorder;
+pub use crate::RemovalPreview;
+pub use crate::ReorderDirection;
+
+pub use crate::SharedHashMap;
+pub use crate::SharedHashSet;
+pub use crate::SharedVec;
+
+pub use crate::interaction::Mode as InteractionMode;
+
+
+
+// ============
+// === Node ===
+// ============
+
+/// The stable surface of the node component.
+pub mod node {
+    pub use crate::component::node::Comment;
+    pub use crate::component::node::Expression;
+    pub use crate::component::node::HEIGHT;
+    pub use crate::component::node::error;
+    pub use crate::component::node::vcs;
+}
+
+
+
+// ====================
+// === Context Menu ===
+// ====================
+
+/// The stable surface of the graph editor's context menu.
+pub mod context_menu {
+    pub use crate::context_menu::Action;
+    pub use crate::context_menu::Target;
+    pub use crate::context_menu::TargetKind;
+}
+
+
+
+// =====================
+// === Visualization ===
+// =====================
+
+/// The stable surface of the visualization framework, including the builtin visualizations.
+pub mod visualization {
+    pub use crate::component::visualization::*;
+    pub use crate::builtin::visualization::native as builtin;
+}
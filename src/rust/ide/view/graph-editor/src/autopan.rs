@@ -0,0 +1,62 @@
+//! Auto-panning of the camera during drags. When the cursor dragging a node or an edge gets close
+//! to the screen border, the camera pans towards that border, so the user can reach targets lying
+//! outside of the screen. The pan speed is proportional to the overshoot, that is, to how deep the
+//! cursor is in the border area.
+
+use crate::prelude::*;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The width of the area along the screen border where the auto-panning is active, in pixels.
+pub const BORDER : f32 = 40.0;
+
+/// The pan speed, in pixels per frame for each pixel of the overshoot.
+pub const SPEED : f32 = 0.25;
+
+/// The maximum pan speed, in pixels per frame.
+pub const MAX_SPEED : f32 = 15.0;
+
+
+
+// ===============
+// === Autopan ===
+// ===============
+
+/// The camera movement, in pixels, during a single frame of the auto-panning. The `cursor` is the
+/// cursor position in pixels, relative to the screen center, and `screen` is the screen size.
+/// Returns zero if the cursor is not in the border area.
+pub fn pan_per_frame(cursor:Vector2, screen:Vector2) -> Vector2 {
+    let axis = |position:f32, size:f32| {
+        let limit     = (size / 2.0 - BORDER).max(0.0);
+        let overshoot = if position > limit { position - limit }
+                        else if position < -limit { position + limit }
+                        else { 0.0 };
+        (overshoot * SPEED).max(-MAX_SPEED).min(MAX_SPEED)
+    };
+    Vector2(axis(cursor.x,screen.x),axis(cursor.y,screen.y))
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panning_proportionally_to_overshoot() {
+        let screen = Vector2(800.0,600.0);
+        assert_eq!(pan_per_frame(Vector2(0.0,0.0),screen),Vector2(0.0,0.0));
+        assert_eq!(pan_per_frame(Vector2(360.0,-260.0),screen),Vector2(0.0,0.0));
+        assert_eq!(pan_per_frame(Vector2(370.0,0.0),screen),Vector2(2.5,0.0));
+        assert_eq!(pan_per_frame(Vector2(0.0,-280.0),screen),Vector2(0.0,-5.0));
+        assert_eq!(pan_per_frame(Vector2(-1000.0,0.0),screen),Vector2(-MAX_SPEED,0.0));
+    }
+}
@@ -0,0 +1,208 @@
+//! Batching of the graph editor inputs. Constructing a graph by a script, for example when loading a
+//! project, issues many inputs in a row, and every added node or edge triggers refreshing the
+//! execution order, the edge colors, and the edge de-clutter. Inside a batch these refreshes are
+//! deferred until the outermost batch ends, and a single `batch_finished` output summarizes the
+//! changes.
+//!
+//! The per-input outputs, like `node_added` or `node_position_set_batched`, are still emitted
+//! during a batch, as the graph editor itself depends on them. The `batching` output is true
+//! meanwhile, so the consumers may ignore them and handle the consolidated `batch_finished` output
+//! instead.
+
+use crate::prelude::*;
+
+use crate::EdgeEndpoint;
+use crate::EdgeId;
+use crate::GraphEditor;
+use crate::NodeId;
+use crate::component::node;
+
+
+
+// ===============
+// === Summary ===
+// ===============
+
+/// The changes made during a batch, emitted once the outermost batch ends.
+#[derive(Clone,Debug,Default,PartialEq)]
+pub struct Summary {
+    /// The nodes added during the batch, in the order of addition.
+    pub added_nodes : Vec<NodeId>,
+    /// The edges added during the batch, in the order of addition.
+    pub added_edges : Vec<EdgeId>,
+    /// The final positions of the nodes moved during the batch, one per node.
+    pub moved_nodes : Vec<(NodeId,Vector2)>,
+    /// The final expressions of the nodes changed during the batch, one per node.
+    pub expressions : Vec<(NodeId,String)>,
+}
+
+/// Set the value for the node, replacing the one recorded before.
+fn set_for_node<T>(values:&mut Vec<(NodeId,T)>, node_id:NodeId, value:T) {
+    match values.iter_mut().find(|(id,_)| *id == node_id) {
+        Some((_,old)) => *old = value,
+        None          => values.push((node_id,value)),
+    }
+}
+
+
+
+// =============
+// === State ===
+// =============
+
+/// The state of the batches currently in progress. Batches may be nested, and only the end of the
+/// outermost one flushes the deferred refreshes.
+#[derive(Clone,CloneRef,Debug,Default)]
+pub struct State {
+    depth   : Rc<Cell<usize>>,
+    summary : Rc<RefCell<Summary>>,
+}
+
+impl State {
+    /// Check whether any batch is in progress.
+    pub fn is_active(&self) -> bool {
+        self.depth.get() > 0
+    }
+
+    /// Begin a new, possibly nested, batch.
+    pub fn begin(&self) {
+        self.depth.set(self.depth.get() + 1);
+    }
+
+    /// End the innermost batch. Returns the summary of the changes if it was the outermost one.
+    /// Unbalanced calls are ignored.
+    pub fn end(&self) -> Option<Summary> {
+        let depth = self.depth.get();
+        if depth == 0 { return None }
+        self.depth.set(depth - 1);
+        (depth == 1).as_some_from(|| self.summary.take())
+    }
+
+    /// Record the added node if any batch is in progress.
+    pub fn record_node(&self, node_id:NodeId) {
+        if self.is_active() { self.summary.borrow_mut().added_nodes.push(node_id) }
+    }
+
+    /// Record the added edge if any batch is in progress.
+    pub fn record_edge(&self, edge_id:EdgeId) {
+        if self.is_active() { self.summary.borrow_mut().added_edges.push(edge_id) }
+    }
+
+    /// Record the new position of the node if any batch is in progress.
+    pub fn record_position(&self, node_id:NodeId, position:Vector2) {
+        if self.is_active() {
+            set_for_node(&mut self.summary.borrow_mut().moved_nodes,node_id,position)
+        }
+    }
+
+    /// Record the new expression of the node if any batch is in progress.
+    pub fn record_expression(&self, node_id:NodeId, expression:String) {
+        if self.is_active() {
+            set_for_node(&mut self.summary.borrow_mut().expressions,node_id,expression)
+        }
+    }
+}
+
+
+
+// =============
+// === Batch ===
+// =============
+
+/// A handle for issuing the inputs of a batch, see `GraphEditor::batch`. The methods are thin
+/// wrappers over the graph editor inputs, returning the identifiers of the created objects.
+#[derive(Debug)]
+pub struct Batch<'a> {
+    editor : &'a GraphEditor,
+}
+
+impl<'a> Batch<'a> {
+    /// Add a new node and return its identifier.
+    pub fn add_node(&self) -> NodeId {
+        self.editor.add_node()
+    }
+
+    /// Set the expression of the node.
+    pub fn set_node_expression(&self, node_id:NodeId, expression:impl Into<node::Expression>) {
+        self.editor.frp.set_node_expression.emit((node_id,expression.into()));
+    }
+
+    /// Set the position of the node.
+    pub fn set_node_position(&self, node_id:NodeId, position:Vector2) {
+        self.editor.frp.set_node_position.emit((node_id,position));
+    }
+
+    /// Connect the nodes with a new edge and return its identifier. Returns `None` if no edge was
+    /// created.
+    pub fn connect(&self, source:EdgeEndpoint, target:EdgeEndpoint) -> Option<EdgeId> {
+        let model    = &self.editor.model;
+        let existing = model.edges_connecting(&source,&target);
+        self.editor.frp.connect_nodes.emit((source.clone(),target.clone()));
+        let edges = model.edges_connecting(&source,&target);
+        edges.into_iter().find(|edge_id| !existing.contains(edge_id))
+    }
+
+    /// The graph editor, for the inputs not covered by the methods above.
+    pub fn editor(&self) -> &GraphEditor {
+        self.editor
+    }
+}
+
+impl GraphEditor {
+    /// Run `f` issuing multiple inputs as a single batch. The refreshes of the execution order, the
+    /// edge colors, and the edge de-clutter are deferred until the batch ends, and the
+    /// `batch_finished` output summarizes the added nodes and edges, the moved nodes, and the
+    /// changed expressions. Batches may be nested.
+    pub fn batch<T>(&self, f:impl FnOnce(&Batch) -> T) -> T {
+        self.frp.begin_batch.emit(());
+        let result = f(&Batch {editor:self});
+        self.frp.end_batch.emit(());
+        result
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_batches() {
+        let state = State::default();
+        state.record_node(NodeId::default());
+        assert!(!state.is_active());
+        state.begin();
+        state.begin();
+        state.record_node(NodeId::default());
+        assert_eq!(state.end(),None);
+        state.record_edge(EdgeId::default());
+        assert!(state.is_active());
+        let summary = state.end().unwrap();
+        assert_eq!(summary.added_nodes,vec![NodeId::default()]);
+        assert_eq!(summary.added_edges,vec![EdgeId::default()]);
+        assert!(!state.is_active());
+        assert_eq!(state.end(),None);
+    }
+
+    #[test]
+    fn consolidating_node_changes() {
+        let state = State::default();
+        let node  = NodeId::default();
+        state.record_position(node,Vector2(1.0,2.0));
+        state.begin();
+        state.record_position(node,Vector2(3.0,4.0));
+        state.record_expression(node,"foo".into());
+        state.record_position(node,Vector2(5.0,6.0));
+        state.record_expression(node,"bar".into());
+        let summary = state.end().unwrap();
+        assert_eq!(summary.moved_nodes,vec![(node,Vector2(5.0,6.0))]);
+        assert_eq!(summary.expressions,vec![(node,"bar".to_string())]);
+        state.begin();
+        assert_eq!(state.end(),Some(Summary::default()));
+    }
+}
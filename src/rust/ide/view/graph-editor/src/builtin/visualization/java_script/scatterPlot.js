@@ -24,6 +24,8 @@ const BUTTONS_HEIGHT = 25
  * To zoom use scroll wheel.
  * To select click and swipe with LMB.
  * To deselect click outside of selection with LMB.
+ * To select points with a lasso click and swipe with LMB while holding shift. The indices of the
+ * points inside the lasso are reported to IDE. To clear the lasso selection shift-click with LMB.
  * To pan click and swipe with RMB.
  * To zoom out click "Fit all" or use key combination "ctrl/cmd+a".
  * To zoom into selection click appropriate button or use key combination "ctrl/cmd+z".
@@ -56,6 +58,7 @@ class ScatterPlot extends Visualization {
             y: { scale: LINEAR_SCALE },
         }
         this.points = { labels: VISIBLE_POINTS }
+        this.selectedIndices = new Set()
     }
 
     /**
@@ -87,6 +90,8 @@ class ScatterPlot extends Visualization {
         this.focus = parsedData.focus
         this.points = parsedData.points ?? { labels: 'visible' }
         this.dataPoints = this.extractValues(parsedData)
+        // The index in the received data is kept, as it identifies the point in the selection.
+        this.dataPoints = this.dataPoints.map((pt, index) => ({ ...pt, index }))
         this.dataPoints = this.dataPoints.filter(pt => isValidNumber(pt.x) && isValidNumber(pt.y))
         this.updateBox()
     }
@@ -171,6 +176,7 @@ class ScatterPlot extends Visualization {
         this.createButtonFitAll(scatter, this.points, extremesAndDeltas, zoom, this.boxWidth)
         let selectedZoomBtn = this.createButtonScaleToPoints()
         this.addBrushing(this.boxWidth, this.boxHeight, scatter, selectedZoomBtn, this.points, zoom)
+        this.addLasso(svg, scatter, zoom)
     }
 
     /**
@@ -357,6 +363,7 @@ class ScatterPlot extends Visualization {
                 [0, 0],
                 [boxWidth, boxHeight],
             ])
+            .filter(() => !d3.event.button && !d3.event.shiftKey)
             .on('start ' + brushClass, updateChart)
 
         // The brush element must be child of zoom element - this is only way we found to have both zoom and brush
@@ -415,6 +422,64 @@ class ScatterPlot extends Visualization {
         endEvents.forEach(e => document.addEventListener(e, endBrushing, false))
     }
 
+    /**
+     * Adds lasso selection to the plot.
+     *
+     * Lasso is a free-form polygon drawn by dragging with LMB while holding shift. The points
+     * inside it get highlighted, and their indices are reported to IDE, so the computations
+     * depending on the visualization can be restricted to the selected points.
+     */
+    addLasso(svg, scatter, zoom) {
+        const leftButton = 0
+        const self = this
+        // The lasso is not a child of `scatter`, as all paths there are moved as points on zoom.
+        const lassoPath = svg.append('path').attr('class', 'lasso').style('pointer-events', 'none')
+
+        /**
+         * Selects the points inside the polygon and reports their indices.
+         */
+        function select(polygon) {
+            const inside = d => {
+                const x = zoom.transformedScale.xScale(d.x)
+                const y = zoom.transformedScale.yScale(d.y)
+                return d3.polygonContains(polygon, [x, y])
+            }
+            const indices = self.dataPoints.filter(inside).map(d => d.index)
+            self.selectedIndices = new Set(indices)
+            self.highlightSelection(scatter)
+            self.emitSelectionChange(indices)
+        }
+
+        // The lasso listener is added to the zoom element, as the brush element, which receives
+        // the events, is its child.
+        zoom.zoomElem.on('mousedown.lasso', function () {
+            if (d3.event.button !== leftButton || !d3.event.shiftKey) {
+                return
+            }
+            d3.event.stopPropagation()
+            const polygon = [d3.mouse(this)]
+            d3.select(window)
+                .on('mousemove.lasso', () => {
+                    polygon.push(d3.mouse(this))
+                    lassoPath.attr('d', 'M' + polygon.join('L') + 'Z')
+                })
+                .on('mouseup.lasso', () => {
+                    d3.select(window).on('mousemove.lasso', null).on('mouseup.lasso', null)
+                    lassoPath.attr('d', null)
+                    select(polygon)
+                })
+        })
+
+        this.highlightSelection(scatter)
+    }
+
+    /**
+     * Marks the points selected by the lasso.
+     */
+    highlightSelection(scatter) {
+        scatter.selectAll('path').classed('lasso-selected', d => this.selectedIndices.has(d.index))
+    }
+
     /**
      * Helper function for zooming in after the scale has been updated.
      */
@@ -721,8 +786,12 @@ class ScatterPlot extends Visualization {
         let buttonLightColor = `#333`
         let darkBtnHoverColor = `rgba(255,255,255,0.5)`
         let darkSelectionFill = `#efefef`
+        let lassoLightFill = `rgba(0,0,0,0.1)`
+        let lassoDarkFill = `rgba(255,255,255,0.1)`
 
         addStyleToElem('.selection', 'rx: 4px;stroke: transparent;')
+        addStyleToElem('.lasso', `fill: ${lassoLightFill};stroke: ${buttonLightColor};`)
+        addStyleToElem('.lasso-selected', `stroke: ${buttonLightColor};stroke-width: 1.5px;`)
         addStyleToElem(
             'button',
             `
@@ -763,6 +832,8 @@ class ScatterPlot extends Visualization {
         `
         )
         addStyleToElem('.dark-theme .selection', `fill: ${darkSelectionFill}`)
+        addStyleToElem('.dark-theme .lasso', `fill: ${lassoDarkFill};stroke: ${darkStrokeColor};`)
+        addStyleToElem('.dark-theme .lasso-selected', `stroke: ${darkStrokeColor};`)
         addStyleToElem('.dark-theme line', `stroke: ${darkStrokeColor};`)
         addStyleToElem('.dark-theme .domain', `stroke: ${darkStrokeColor};`)
         addStyleToElem('.dark-theme text', `fill: ${darkStrokeColor};`)
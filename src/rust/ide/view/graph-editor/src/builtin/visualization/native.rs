@@ -3,8 +3,14 @@
 pub mod bubble_chart;
 #[warn(missing_docs)]
 pub mod error;
+#[warn(missing_docs)]
+pub mod heatmap;
 pub mod raw_text;
+#[warn(missing_docs)]
+pub mod table;
 
 pub use bubble_chart::BubbleChart;
 pub use error::Error;
+pub use heatmap::Heatmap;
 pub use raw_text::RawText;
+pub use table::Table;
@@ -203,7 +203,7 @@ impl Model {
                 self.set_data(&input);
                 Ok(())
             }
-            Data::Binary => Err(DataError::BinaryNotSupported)
+            Data::Binary {..} => Err(DataError::BinaryNotSupported)
         }
     }
 
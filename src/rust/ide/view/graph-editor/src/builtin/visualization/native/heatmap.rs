@@ -0,0 +1,587 @@
+//! Heatmap visualization rendering 2D numeric matrices as a color-mapped grid of cells.
+//!
+//! The matrix is drawn on a 2D canvas. Hovering a cell shows its indices and value in the readout
+//! line below the grid, and clicking the readout line switches to the next color map. Large
+//! matrices are downsampled by the preprocessor on the Engine side, so that no more than one cell
+//! per `MIN_CELL_SIZE` pixels is ever transmitted to the IDE.
+
+use crate::prelude::*;
+
+use crate::component::visualization::*;
+use crate::component::visualization;
+
+use enso_frp as frp;
+use ensogl::data::color;
+use ensogl::display::DomSymbol;
+use ensogl::display::scene::Scene;
+use ensogl::display::shape::primitive::StyleWatch;
+use ensogl::display;
+use ensogl::system::web;
+use ensogl::system::web::AttributeSetter;
+use ensogl::system::web::NodeInserter;
+use ensogl::system::web::StyleSetter;
+use ensogl_theme;
+use serde::Deserialize;
+use serde::Serialize;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use wasm_bindgen::closure::Closure;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The smallest size of a single cell on the screen, in pixels. Determines the resolution to which
+/// the preprocessor downsamples the matrix.
+const MIN_CELL_SIZE  : f32 = 2.0;
+/// Height of the readout line displayed below the grid.
+const READOUT_HEIGHT : f32 = 20.0;
+const PADDING        : f32 = 10.0;
+
+/// The context module for the preprocessor code.
+pub const PREPROCESSOR_MODULE:&str = "Standard.Base.Main";
+
+type MouseListener = Closure<dyn FnMut(web::MouseEvent)>;
+
+
+
+// ====================
+// === Preprocessor ===
+// ====================
+
+/// The maximum number of rows and columns of the matrix which can be displayed at the given
+/// visualization size.
+#[derive(Clone,Copy,Debug,Default,Eq,Hash,PartialEq)]
+pub struct Resolution {
+    #[allow(missing_docs)]
+    pub rows    : usize,
+    #[allow(missing_docs)]
+    pub columns : usize,
+}
+
+impl Resolution {
+    /// The resolution of the grid fitting in the visualization of the given size.
+    pub fn for_size(size:Vector2) -> Self {
+        let grid    = grid_size(size);
+        let rows    = ((grid.y / MIN_CELL_SIZE).floor() as usize).max(1);
+        let columns = ((grid.x / MIN_CELL_SIZE).floor() as usize).max(1);
+        Self {rows,columns}
+    }
+}
+
+/// The preprocessor code picking every n-th row and column of the matrix, so the result does not
+/// exceed the given resolution. The strides are sent along with the data, so the readout can refer
+/// to the indices of the original matrix.
+pub fn preprocessor_code(resolution:Resolution) -> String {
+    format!(r#"
+x ->
+    max_rows = {}
+    max_cols = {}
+    rows     = x.length
+    cols     = if rows == 0 then 0 else (x.at 0).length
+    row_step = if rows <= max_rows then 1 else (rows + max_rows - 1).div max_rows
+    col_step = if cols <= max_cols then 1 else (cols + max_cols - 1).div max_cols
+    sampled_row r =
+        row = x.at r*row_step
+        Vector.new ((cols + col_step - 1).div col_step) c-> row.at c*col_step
+    data     = Vector.new ((rows + row_step - 1).div row_step) sampled_row
+    Json.from_pairs [["data",data],["row_step",row_step],["col_step",col_step]] . to_text
+"#,resolution.rows,resolution.columns)
+}
+
+/// Get preprocessor configuration for the heatmap displayed at the given resolution.
+pub fn preprocessor(resolution:Resolution) -> instance::PreprocessorConfiguration {
+    instance::PreprocessorConfiguration::new(preprocessor_code(resolution),PREPROCESSOR_MODULE)
+}
+
+fn grid_size(size:Vector2) -> Vector2 {
+    let width  = (size.x - 2.0 * PADDING).max(0.0);
+    let height = (size.y - 2.0 * PADDING - READOUT_HEIGHT).max(0.0);
+    Vector2(width,height)
+}
+
+
+
+// =============
+// === Input ===
+// =============
+
+/// The input for the Heatmap Visualization. It is either a plain matrix given as an array of rows,
+/// or a matrix downsampled by the preprocessor together with the strides used. Missing values
+/// (e.g. `NaN`s, serialized as `null`) are left blank.
+#[derive(Clone,Debug,Deserialize,Serialize)]
+#[serde(untagged)]
+#[allow(missing_docs)]
+pub enum Input {
+    Plain(Vec<Vec<Option<f32>>>),
+    Sampled {
+        data     : Vec<Vec<Option<f32>>>,
+        row_step : usize,
+        col_step : usize,
+    },
+}
+
+/// The matrix being displayed.
+#[derive(Clone,Debug,Default,PartialEq)]
+pub struct Matrix {
+    /// The rows of the (possibly downsampled) matrix.
+    pub rows     : Vec<Vec<Option<f32>>>,
+    /// The number of original rows represented by a single row of `rows`.
+    pub row_step : usize,
+    /// The number of original columns represented by a single column of `rows`.
+    pub col_step : usize,
+}
+
+impl From<Input> for Matrix {
+    fn from(input:Input) -> Self {
+        match input {
+            Input::Plain(rows) => Self {rows,row_step:1,col_step:1},
+            Input::Sampled {data,row_step,col_step} => {
+                let row_step = row_step.max(1);
+                let col_step = col_step.max(1);
+                Self {rows:data,row_step,col_step}
+            }
+        }
+    }
+}
+
+impl Matrix {
+    /// The number of the displayed rows.
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The number of the displayed columns. Rows of different lengths are padded with blanks.
+    pub fn column_count(&self) -> usize {
+        self.rows.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+
+    /// The value of the given cell, if present.
+    pub fn get(&self, row:usize, column:usize) -> Option<f32> {
+        self.rows.get(row)?.get(column).copied().flatten().filter(|value| value.is_finite())
+    }
+
+    /// The minimum and maximum of all the values in the matrix.
+    pub fn range(&self) -> Option<(f32,f32)> {
+        let values = self.rows.iter().flatten().filter_map(|v| *v).filter(|v| v.is_finite());
+        values.fold(None,|range,value| match range {
+            None           => Some((value,value)),
+            Some((lo,hi))  => Some((lo.min(value),hi.max(value))),
+        })
+    }
+}
+
+
+
+// ================
+// === ColorMap ===
+// ================
+
+/// The color maps the user can switch between.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+#[allow(missing_docs)]
+pub enum ColorMap { Viridis, Grayscale, Diverging }
+
+impl Default for ColorMap {
+    fn default() -> Self {
+        Self::Viridis
+    }
+}
+
+impl ColorMap {
+    /// The name displayed in the readout line.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Viridis   => "viridis",
+            Self::Grayscale => "grayscale",
+            Self::Diverging => "diverging",
+        }
+    }
+
+    /// The color map following this one, used when the user cycles through them.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Viridis   => Self::Grayscale,
+            Self::Grayscale => Self::Diverging,
+            Self::Diverging => Self::Viridis,
+        }
+    }
+
+    fn stops(self) -> &'static [(f32,f32,f32)] {
+        match self {
+            Self::Viridis   => &[(0.267,0.005,0.329),(0.229,0.322,0.545),(0.128,0.567,0.551)
+                                ,(0.369,0.789,0.383),(0.993,0.906,0.144)],
+            Self::Grayscale => &[(0.0,0.0,0.0),(1.0,1.0,1.0)],
+            Self::Diverging => &[(0.230,0.299,0.754),(0.865,0.865,0.865),(0.706,0.016,0.150)],
+        }
+    }
+
+    /// The color of a value normalized to the `[0,1]` range.
+    pub fn color(self, value:f32) -> color::Rgb {
+        let stops    = self.stops();
+        let segments = stops.len() - 1;
+        let position = value.max(0.0).min(1.0) * segments as f32;
+        let index    = (position.floor() as usize).min(segments - 1);
+        let coef     = position - index as f32;
+        let (r1,g1,b1) = stops[index];
+        let (r2,g2,b2) = stops[index + 1];
+        color::mix(color::Rgb::new(r1,g1,b1),color::Rgb::new(r2,g2,b2),coef)
+    }
+}
+
+
+
+// ===============
+// === Heatmap ===
+// ===============
+
+/// Heatmap visualization of 2D numeric matrices. See the module docs to learn more.
+#[derive(Clone,CloneRef,Debug)]
+#[allow(missing_docs)]
+pub struct Heatmap {
+    pub frp : visualization::instance::Frp,
+    model   : Model,
+    network : frp::Network,
+}
+
+impl Deref for Heatmap {
+    type Target = visualization::instance::FrpInputs;
+
+    fn deref(&self) -> &Self::Target { &self.frp.inputs }
+}
+
+impl Heatmap {
+    /// The visualization path.
+    pub fn path() -> Path { Path::builtin("Heatmap (Native)") }
+
+    /// Definition of this visualization.
+    pub fn definition() -> Definition {
+        let path = Self::path();
+        Definition::new(
+            Signature::new_for_any_type(path,Format::Json),
+            |scene| { Ok(Self::new(scene).into()) }
+        )
+    }
+
+    /// Constructor.
+    pub fn new(scene:&Scene) -> Self {
+        let network = frp::Network::new("native_visualization_heatmap");
+        let frp     = visualization::instance::Frp::new(&network);
+        let model   = Model::new(scene.clone_ref());
+        Self {frp,model,network} . init(scene)
+    }
+
+    fn init(self, scene:&Scene) -> Self {
+        let network = &self.network;
+        let model   = self.model.clone_ref();
+        let frp     = self.frp.clone_ref();
+        frp::extend! { network
+            mouse_move  <- source::<Option<Vector2>>();
+            mouse_click <- source::<()>();
+
+            eval frp.set_size  ((size) model.set_size(*size));
+            eval frp.send_data ([frp,model](data) {
+                if let Err(e) = model.receive_data(data) {
+                    frp.data_receive_error.emit(Some(e));
+                }
+            });
+            eval frp.set_layer ((layer) model.set_layer(*layer));
+
+            resolution <- frp.set_size.map(|size| Resolution::for_size(*size)).on_change();
+            frp.preprocessor_change <+ resolution.map(|resolution| preprocessor(*resolution));
+
+            eval mouse_move   ((position) model.set_hover_position(*position));
+            eval_ mouse_click (model.set_color_map(model.color_map.get().next()));
+        }
+        frp.pass_events_to_dom_if_active(scene,network);
+        model.attach_listeners(&mouse_move,&mouse_click);
+        frp.preprocessor_change.emit(preprocessor(Resolution::for_size(model.size.get())));
+        self
+    }
+
+    /// Sets the displayed matrix directly (not from the serialized JSON).
+    pub fn set_data(&self, input:Input) {
+        self.model.set_matrix(input.into());
+    }
+
+    /// Switch the color map used to display the values.
+    pub fn set_color_map(&self, color_map:ColorMap) {
+        self.model.set_color_map(color_map);
+    }
+}
+
+
+
+// =============
+// === Model ===
+// =============
+
+#[derive(Clone,CloneRef,Debug)]
+#[allow(missing_docs)]
+pub struct Model {
+    logger    : Logger,
+    dom       : DomSymbol,
+    canvas    : web::HtmlCanvasElement,
+    context   : web::CanvasRenderingContext2d,
+    readout   : web::HtmlDivElement,
+    size      : Rc<Cell<Vector2>>,
+    matrix    : Rc<RefCell<Matrix>>,
+    color_map : Rc<Cell<ColorMap>>,
+    hovered   : Rc<Cell<Option<(usize,usize)>>>,
+    listeners : Rc<RefCell<Vec<MouseListener>>>,
+    scene     : Scene,
+}
+
+impl Model {
+    /// Constructor.
+    fn new(scene:Scene) -> Self {
+        let logger    = Logger::new("Heatmap");
+        let div       = web::create_div();
+        let dom       = DomSymbol::new(&div);
+        let canvas    = web::create_canvas();
+        let readout   = web::create_div();
+        let context   = canvas.get_context("2d").unwrap().unwrap();
+        let context   = context.dyn_into::<web::CanvasRenderingContext2d>().unwrap();
+        let size      = Rc::new(Cell::new(Vector2(200.0,200.0)));
+        let matrix    = default();
+        let color_map = default();
+        let hovered   = default();
+        let listeners = default();
+
+        // FIXME : StyleWatch is unsuitable here, as it was designed as an internal tool for shape system (#795)
+        let styles       = StyleWatch::new(&scene.style_sheet);
+        let text_color   = styles.get_color(ensogl_theme::graph_editor::visualization::text);
+        let text_color   = text_color.to_javascript_string();
+        let padding      = format!("{}px",PADDING);
+        let readout_size = format!("{}px",READOUT_HEIGHT);
+
+        dom.dom().set_attribute_or_warn("class","visualization",&logger);
+        dom.dom().set_style_or_warn("box-sizing"    ,"border-box"         ,&logger);
+        dom.dom().set_style_or_warn("padding"       ,&padding             ,&logger);
+        dom.dom().set_style_or_warn("font-family"   ,"DejaVuSansMonoBook" ,&logger);
+        dom.dom().set_style_or_warn("font-size"     ,"12px"               ,&logger);
+        dom.dom().set_style_or_warn("color"         ,text_color           ,&logger);
+        dom.dom().set_style_or_warn("pointer-events","auto"               ,&logger);
+        canvas.set_style_or_warn("display","block",&logger);
+        readout.set_style_or_warn("height"     ,&readout_size,&logger);
+        readout.set_style_or_warn("line-height",&readout_size,&logger);
+        readout.set_style_or_warn("white-space","pre"        ,&logger);
+        readout.set_style_or_warn("cursor"     ,"pointer"    ,&logger);
+        dom.dom().append_or_warn(&canvas,&logger);
+        dom.dom().append_or_warn(&readout,&logger);
+
+        scene.dom.layers.back.manage(&dom);
+        let model = Model {logger,dom,canvas,context,readout,size,matrix,color_map,hovered
+                          ,listeners,scene};
+        model.init()
+    }
+
+    fn init(self) -> Self {
+        self.reload_style();
+        self
+    }
+
+    /// Register the DOM event listeners forwarding the mouse events to the given FRP sources.
+    fn attach_listeners
+    (&self, mouse_move:&frp::Source<Option<Vector2>>, mouse_click:&frp::Source) {
+        let on_move  = f!([mouse_move](event:web::MouseEvent) {
+            mouse_move.emit(Some(Vector2(event.offset_x() as f32,event.offset_y() as f32)))
+        });
+        let on_leave = f!([mouse_move](_event:web::MouseEvent) mouse_move.emit(None));
+        let on_click = f!([mouse_click](_event:web::MouseEvent) mouse_click.emit(()));
+        let listeners = vec!
+            [ self.add_listener(&self.canvas,"mousemove" ,on_move)
+            , self.add_listener(&self.canvas,"mouseleave",on_leave)
+            , self.add_listener(&self.readout,"click"    ,on_click)
+            ];
+        *self.listeners.borrow_mut() = listeners.into_iter().flatten().collect();
+    }
+
+    fn add_listener
+    (&self, target:&web::EventTarget, event:&str, f:impl FnMut(web::MouseEvent)+'static)
+    -> Option<MouseListener> {
+        let closure:MouseListener = Closure::wrap(Box::new(f));
+        let callback = closure.as_ref().unchecked_ref();
+        match target.add_event_listener_with_callback(event,callback) {
+            Ok(_)  => Some(closure),
+            Err(e) => {
+                error!(&self.logger,"Unable to add the {event} listener: {e:?}");
+                None
+            }
+        }
+    }
+
+    fn set_size(&self, size:Vector2) {
+        self.size.set(size);
+        self.reload_style();
+    }
+
+    fn receive_data(&self, data:&Data) -> Result<(),DataError> {
+        match data {
+            Data::Json {content} => {
+                let input_result = serde_json::from_value(content.deref().clone());
+                let input:Input  = input_result.map_err(|_| DataError::InvalidDataType)?;
+                self.set_matrix(input.into());
+                Ok(())
+            }
+            Data::Binary {..} => Err(DataError::BinaryNotSupported)
+        }
+    }
+
+    fn set_matrix(&self, matrix:Matrix) {
+        *self.matrix.borrow_mut() = matrix;
+        self.hovered.set(None);
+        self.redraw();
+    }
+
+    fn set_color_map(&self, color_map:ColorMap) {
+        self.color_map.set(color_map);
+        self.redraw();
+    }
+
+    /// Update the hovered cell, given the mouse position relative to the canvas.
+    fn set_hover_position(&self, position:Option<Vector2>) {
+        let hovered = position.and_then(|position| self.cell_at(position));
+        if hovered != self.hovered.get() {
+            self.hovered.set(hovered);
+            self.update_readout();
+        }
+    }
+
+    fn cell_at(&self, position:Vector2) -> Option<(usize,usize)> {
+        let matrix = self.matrix.borrow();
+        let grid   = grid_size(self.size.get());
+        let rows   = matrix.row_count();
+        let cols   = matrix.column_count();
+        let inside = position.x >= 0.0 && position.y >= 0.0
+            && position.x < grid.x && position.y < grid.y;
+        (inside && rows > 0 && cols > 0).as_some_from(|| {
+            let row    = (position.y / grid.y * rows as f32) as usize;
+            let column = (position.x / grid.x * cols as f32) as usize;
+            (row.min(rows - 1),column.min(cols - 1))
+        })
+    }
+
+    fn redraw(&self) {
+        let matrix    = self.matrix.borrow();
+        let grid      = grid_size(self.size.get());
+        let rows      = matrix.row_count();
+        let cols      = matrix.column_count();
+        let color_map = self.color_map.get();
+        self.context.clear_rect(0.0,0.0,grid.x as f64,grid.y as f64);
+        if let Some((min,max)) = matrix.range() {
+            let cell_width  = grid.x as f64 / cols as f64;
+            let cell_height = grid.y as f64 / rows as f64;
+            let span        = if max > min { max - min } else { 1.0 };
+            for (row_index,row) in matrix.rows.iter().enumerate() {
+                for column_index in 0..row.len() {
+                    if let Some(value) = matrix.get(row_index,column_index) {
+                        let color = color_map.color((value - min) / span);
+                        let style = JsValue::from_str(&color.to_javascript_string());
+                        let x     = column_index as f64 * cell_width;
+                        let y     = row_index as f64 * cell_height;
+                        self.context.set_fill_style(&style);
+                        // Cells are slightly enlarged to avoid gaps caused by antialiasing.
+                        self.context.fill_rect(x,y,cell_width + 0.5,cell_height + 0.5);
+                    }
+                }
+            }
+        }
+        self.update_readout();
+    }
+
+    fn update_readout(&self) {
+        let matrix = self.matrix.borrow();
+        let text   = match self.hovered.get() {
+            Some((row,column)) => {
+                let value    = matrix.get(row,column);
+                let value    = value.map_or_else(|| "-".to_string(),|v| format!("{:.4}",v));
+                let row      = row * matrix.row_step;
+                let column   = column * matrix.col_step;
+                format!("[{}, {}] = {}",row,column,value)
+            }
+            None => {
+                let name  = self.color_map.get().name();
+                let range = matrix.range();
+                let range = range.map(|(min,max)| format!("{:.4} .. {:.4}",min,max));
+                format!("{}  {}",name,range.unwrap_or_default())
+            }
+        };
+        self.readout.set_inner_text(&text);
+    }
+
+    fn reload_style(&self) {
+        let size = self.size.get();
+        let grid = grid_size(size);
+        self.dom.set_size(size);
+        self.canvas.set_width(grid.x as u32);
+        self.canvas.set_height(grid.y as u32);
+        self.redraw();
+    }
+
+    fn set_layer(&self, layer:Layer) {
+        layer.apply_for_html_component(&self.scene,&self.dom)
+    }
+}
+
+impl From<Heatmap> for Instance {
+    fn from(t:Heatmap) -> Self {
+        Self::new(&t,&t.frp,&t.network,Some(t.model.dom.clone_ref()))
+    }
+}
+
+impl display::Object for Heatmap {
+    fn display_object(&self) -> &display::object::Instance {
+        self.model.dom.display_object()
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_input() {
+        let plain:Input = serde_json::from_str("[[1,2],[3,null]]").unwrap();
+        let plain       = Matrix::from(plain);
+        assert_eq!(plain.row_step,1);
+        assert_eq!(plain.get(1,0),Some(3.0));
+        assert_eq!(plain.get(1,1),None);
+        assert_eq!(plain.range(),Some((1.0,3.0)));
+
+        let json    = r#"{"data":[[1],[5]],"row_step":4,"col_step":2}"#;
+        let sampled = Matrix::from(serde_json::from_str::<Input>(json).unwrap());
+        assert_eq!((sampled.row_step,sampled.col_step),(4,2));
+        assert_eq!((sampled.row_count(),sampled.column_count()),(2,1));
+    }
+
+    #[test]
+    fn color_map_boundaries() {
+        for color_map in &[ColorMap::Viridis,ColorMap::Grayscale,ColorMap::Diverging] {
+            let stops        = color_map.stops();
+            let (r,g,b)      = stops[0];
+            let first        = color_map.color(-1.0);
+            assert!((first.red - r).abs() < 0.01 && (first.green - g).abs() < 0.01);
+            assert!((first.blue - b).abs() < 0.01);
+            let (r,g,b)      = stops[stops.len() - 1];
+            let last         = color_map.color(2.0);
+            assert!((last.red - r).abs() < 0.01 && (last.green - g).abs() < 0.01);
+            assert!((last.blue - b).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn resolution_fits_grid() {
+        let size       = Vector2(2.0 * PADDING + 100.0,2.0 * PADDING + READOUT_HEIGHT + 50.0);
+        let resolution = Resolution::for_size(size);
+        assert_eq!(resolution,Resolution {rows:25,columns:50});
+    }
+}
@@ -0,0 +1,726 @@
+//! Table visualization rendering large tabular data with virtual scrolling.
+//!
+//! Only the cells intersecting the visible area (plus a few overscan rows) are instantiated as DOM
+//! elements, so tables with many thousands of rows stay responsive. Clicking a column header cycles
+//! between the ascending, descending, and original order of rows. Dragging the handle at the right
+//! edge of a column header adjusts the column width.
+
+use crate::prelude::*;
+
+use crate::component::visualization::*;
+use crate::component::visualization;
+
+use enso_frp as frp;
+use ensogl::display::DomSymbol;
+use ensogl::display::scene::Scene;
+use ensogl::display::shape::primitive::StyleWatch;
+use ensogl::display;
+use ensogl::system::web;
+use ensogl::system::web::AttributeSetter;
+use ensogl::system::web::NodeInserter;
+use ensogl::system::web::StyleSetter;
+use ensogl_theme;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::ops::Range;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::closure::Closure;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// Height of a single row, including the header row.
+pub const ROW_HEIGHT : f32 = 20.0;
+/// Width of the columns which were not resized by the user.
+pub const DEFAULT_COLUMN_WIDTH : f32 = 100.0;
+/// The user cannot make a column narrower than this.
+pub const MIN_COLUMN_WIDTH : f32 = 30.0;
+/// The number of rows instantiated above and below the visible area, so fast scrolling does not
+/// reveal blank space before the next render.
+const OVERSCAN_ROWS        : usize = 4;
+const RESIZE_HANDLE_WIDTH  : f32   = 6.0;
+const PADDING              : f32   = 10.0;
+const GRID_COLOR           : &str  = "rgba(127,127,127,0.25)";
+const COLUMN_ATTRIBUTE     : &str  = "data-column";
+const RESIZE_ATTRIBUTE     : &str  = "data-resize";
+const HANDLE_STYLE         : &str  = "position:absolute;height:100%;cursor:col-resize;";
+
+type Listener = Closure<dyn FnMut(web_sys::Event)>;
+
+
+
+// =============
+// === Input ===
+// =============
+
+/// The input of the Table Visualization. It is either a table with explicit column headers, or an
+/// array of records (objects), rows (arrays), or single values.
+#[derive(Clone,Debug,Deserialize,Serialize)]
+#[serde(untagged)]
+#[allow(missing_docs)]
+pub enum Input {
+    Table {
+        headers : Vec<String>,
+        rows    : Vec<Vec<Value>>,
+    },
+    Values(Vec<Value>),
+}
+
+
+
+// ============
+// === Grid ===
+// ============
+
+/// The displayed grid of cells.
+#[derive(Clone,Debug,Default,PartialEq)]
+pub struct Grid {
+    /// The column headers.
+    pub headers : Vec<String>,
+    /// The rows of cells. Rows shorter than `headers` are padded with blanks.
+    pub rows    : Vec<Vec<Value>>,
+}
+
+impl From<Input> for Grid {
+    fn from(input:Input) -> Self {
+        match input {
+            Input::Table {headers,rows} => Self {headers,rows},
+            Input::Values(values) => {
+                if values.iter().all(Value::is_object) {
+                    Self::from_records(values)
+                } else if values.iter().all(Value::is_array) {
+                    let rows    = values.into_iter().map(|row| match row {
+                        Value::Array(cells) => cells,
+                        _                   => default(),
+                    }).collect_vec();
+                    let columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+                    let headers = (0..columns).map(|index| index.to_string()).collect();
+                    Self {headers,rows}
+                } else {
+                    let headers = vec!["Value".to_string()];
+                    let rows    = values.into_iter().map(|value| vec![value]).collect();
+                    Self {headers,rows}
+                }
+            }
+        }
+    }
+}
+
+impl Grid {
+    /// Grid having a column for every key present in any of the records, in the order of the
+    /// first appearance.
+    fn from_records(records:Vec<Value>) -> Self {
+        let mut headers = Vec::<String>::new();
+        for record in &records {
+            if let Value::Object(fields) = record {
+                for key in fields.keys() {
+                    if !headers.contains(key) { headers.push(key.clone()) }
+                }
+            }
+        }
+        let rows = records.iter().map(|record| {
+            headers.iter().map(|key| record.get(key).cloned().unwrap_or(Value::Null)).collect()
+        }).collect();
+        Self {headers,rows}
+    }
+
+    /// The number of rows.
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The number of columns.
+    pub fn column_count(&self) -> usize {
+        let widest_row = self.rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        self.headers.len().max(widest_row)
+    }
+
+    /// The header of the column. Columns without explicit headers are named by their index.
+    pub fn header(&self, column:usize) -> String {
+        self.headers.get(column).cloned().unwrap_or_else(|| column.to_string())
+    }
+
+    /// The value of the cell, if present.
+    pub fn get(&self, row:usize, column:usize) -> Option<&Value> {
+        self.rows.get(row)?.get(column).filter(|value| !value.is_null())
+    }
+
+    /// The text displayed in the cell.
+    pub fn cell_text(&self, row:usize, column:usize) -> String {
+        match self.get(row,column) {
+            None                      => default(),
+            Some(Value::String(text)) => text.clone(),
+            Some(value)               => value.to_string(),
+        }
+    }
+
+    /// The indices of rows in the order of displaying. Numbers are compared numerically, and other
+    /// values by their text. Blank cells are always placed last.
+    pub fn row_order(&self, sort:Option<Sort>) -> Vec<usize> {
+        let mut order = (0..self.row_count()).collect_vec();
+        if let Some(Sort {column,direction}) = sort {
+            order.sort_by(|&a,&b| {
+                match (self.get(a,column),self.get(b,column)) {
+                    (None,None)       => Ordering::Equal,
+                    (None,Some(_))    => Ordering::Greater,
+                    (Some(_),None)    => Ordering::Less,
+                    (Some(x),Some(y)) => {
+                        let ordering = compare_values(x,y);
+                        match direction {
+                            Direction::Ascending  => ordering,
+                            Direction::Descending => ordering.reverse(),
+                        }
+                    }
+                }
+            });
+        }
+        order
+    }
+}
+
+fn compare_values(a:&Value, b:&Value) -> Ordering {
+    match (a.as_f64(),b.as_f64()) {
+        (Some(a),Some(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => {
+            let text = |value:&Value| match value {
+                Value::String(text) => text.clone(),
+                value               => value.to_string(),
+            };
+            text(a).cmp(&text(b))
+        }
+    }
+}
+
+
+
+// ============
+// === Sort ===
+// ============
+
+/// The direction of sorting.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+#[allow(missing_docs)]
+pub enum Direction { Ascending, Descending }
+
+/// The column the rows are sorted by.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+#[allow(missing_docs)]
+pub struct Sort {
+    pub column    : usize,
+    pub direction : Direction,
+}
+
+impl Sort {
+    /// The sorting after clicking the header of the column. Clicking the same column cycles through
+    /// the ascending, descending, and original order.
+    pub fn next(current:Option<Sort>, column:usize) -> Option<Sort> {
+        let ascending = Some(Sort {column,direction:Direction::Ascending});
+        match current {
+            Some(sort) if sort.column == column => match sort.direction {
+                Direction::Ascending  => Some(Sort {column,direction:Direction::Descending}),
+                Direction::Descending => None,
+            },
+            _ => ascending,
+        }
+    }
+
+    fn indicator(self) -> &'static str {
+        match self.direction {
+            Direction::Ascending  => " ▲",
+            Direction::Descending => " ▼",
+        }
+    }
+}
+
+
+
+// ================
+// === Viewport ===
+// ================
+
+/// The offsets of the column edges, starting with `0.0` and ending with the total width.
+pub fn column_offsets(widths:&[f32]) -> Vec<f32> {
+    let mut offsets = Vec::with_capacity(widths.len() + 1);
+    let mut offset  = 0.0;
+    offsets.push(offset);
+    for width in widths {
+        offset += width;
+        offsets.push(offset);
+    }
+    offsets
+}
+
+/// The rows and columns which have to be instantiated to fill the visible area.
+#[derive(Clone,Debug,Default,Eq,PartialEq)]
+pub struct Viewport {
+    /// The visible rows, including the overscan.
+    pub rows    : Range<usize>,
+    /// The visible columns.
+    pub columns : Range<usize>,
+}
+
+impl Viewport {
+    /// Constructor. The `scroll` is the scroll offset of the table body, and `offsets` are the
+    /// column edges computed by `column_offsets`.
+    pub fn new(scroll:Vector2, size:Vector2, row_count:usize, offsets:&[f32]) -> Self {
+        let first_row = (scroll.y / ROW_HEIGHT).floor().max(0.0) as usize;
+        let last_row  = ((scroll.y + size.y) / ROW_HEIGHT).ceil().max(0.0) as usize;
+        let first_row = first_row.saturating_sub(OVERSCAN_ROWS).min(row_count);
+        let last_row  = (last_row + OVERSCAN_ROWS).min(row_count);
+        let edges     = offsets.iter().skip(1);
+        let first_col = edges.clone().take_while(|&&right| right <= scroll.x).count();
+        let last_col  = offsets.iter().take_while(|&&left| left < scroll.x + size.x).count();
+        let last_col  = last_col.min(offsets.len().saturating_sub(1)).max(first_col);
+        Self {rows:first_row..last_row, columns:first_col..last_col}
+    }
+}
+
+fn body_size(size:Vector2) -> Vector2 {
+    let width  = (size.x - 2.0 * PADDING).max(0.0);
+    let height = (size.y - 2.0 * PADDING - ROW_HEIGHT).max(0.0);
+    Vector2(width,height)
+}
+
+
+
+// =============
+// === Table ===
+// =============
+
+/// Table visualization with virtual scrolling. See the module docs to learn more.
+#[derive(Clone,CloneRef,Debug)]
+#[allow(missing_docs)]
+pub struct Table {
+    pub frp : visualization::instance::Frp,
+    model   : Model,
+    network : frp::Network,
+}
+
+impl Deref for Table {
+    type Target = visualization::instance::FrpInputs;
+
+    fn deref(&self) -> &Self::Target { &self.frp.inputs }
+}
+
+impl Table {
+    /// The visualization path.
+    pub fn path() -> Path { Path::builtin("Table (Native)") }
+
+    /// Definition of this visualization.
+    pub fn definition() -> Definition {
+        let path = Self::path();
+        Definition::new(
+            Signature::new_for_any_type(path,Format::Json),
+            |scene| { Ok(Self::new(scene).into()) }
+        )
+    }
+
+    /// Constructor.
+    pub fn new(scene:&Scene) -> Self {
+        let network = frp::Network::new("native_visualization_table");
+        let frp     = visualization::instance::Frp::new(&network);
+        let model   = Model::new(scene.clone_ref());
+        Self {frp,model,network} . init(scene)
+    }
+
+    fn init(self, scene:&Scene) -> Self {
+        let network = &self.network;
+        let model   = self.model.clone_ref();
+        let frp     = self.frp.clone_ref();
+        frp::extend! { network
+            scrolled         <- source::<Vector2>();
+            header_pressed   <- source::<usize>();
+            resize_started   <- source::<(usize,f32)>();
+            pointer_moved    <- source::<f32>();
+            pointer_released <- source::<()>();
+
+            eval frp.set_size  ((size) model.set_size(*size));
+            eval frp.send_data ([frp,model](data) {
+                if let Err(e) = model.receive_data(data) {
+                    frp.data_receive_error.emit(Some(e));
+                }
+            });
+            eval frp.set_layer ((layer) model.set_layer(*layer));
+
+            eval scrolled       ((scroll) model.set_scroll(*scroll));
+            eval header_pressed ((column) model.sort_by(*column));
+            eval resize_started (((column,x)) model.start_resize(*column,*x));
+            eval pointer_moved  ((x) model.update_resize(*x));
+            eval_ pointer_released (model.finish_resize());
+        }
+        frp.pass_events_to_dom_if_active(scene,network);
+        model.attach_listeners(&scrolled,&header_pressed,&resize_started,&pointer_moved
+            ,&pointer_released);
+        self
+    }
+
+    /// Sets the displayed table directly (not from the serialized JSON).
+    pub fn set_data(&self, input:Input) {
+        self.model.set_grid(input.into());
+    }
+}
+
+
+
+// =============
+// === Model ===
+// =============
+
+/// The column being resized: its index, the pointer position, and the column width when the drag
+/// started.
+#[derive(Clone,Copy,Debug)]
+struct Resize {
+    column  : usize,
+    start_x : f32,
+    width   : f32,
+}
+
+#[derive(Clone,CloneRef,Debug)]
+#[allow(missing_docs)]
+pub struct Model {
+    logger    : Logger,
+    dom       : DomSymbol,
+    header    : web::HtmlDivElement,
+    body      : web::HtmlDivElement,
+    spacer    : web::HtmlDivElement,
+    size      : Rc<Cell<Vector2>>,
+    scroll    : Rc<Cell<Vector2>>,
+    grid      : Rc<RefCell<Grid>>,
+    widths    : Rc<RefCell<Vec<f32>>>,
+    sort      : Rc<Cell<Option<Sort>>>,
+    order     : Rc<RefCell<Vec<usize>>>,
+    resize    : Rc<Cell<Option<Resize>>>,
+    listeners : Rc<RefCell<Vec<Listener>>>,
+    scene     : Scene,
+}
+
+impl Model {
+    /// Constructor.
+    fn new(scene:Scene) -> Self {
+        let logger    = Logger::new("Table");
+        let div       = web::create_div();
+        let dom       = DomSymbol::new(&div);
+        let header    = web::create_div();
+        let body      = web::create_div();
+        let spacer    = web::create_div();
+        let size      = Rc::new(Cell::new(Vector2(200.0,200.0)));
+        let scroll    = default();
+        let grid      = default();
+        let widths    = default();
+        let sort      = default();
+        let order     = default();
+        let resize    = default();
+        let listeners = default();
+
+        // FIXME : StyleWatch is unsuitable here, as it was designed as an internal tool for shape system (#795)
+        let styles     = StyleWatch::new(&scene.style_sheet);
+        let text_color = styles.get_color(ensogl_theme::graph_editor::visualization::text);
+        let text_color = text_color.to_javascript_string();
+        let padding    = format!("{}px",PADDING);
+        let row_height = format!("{}px",ROW_HEIGHT);
+
+        dom.dom().set_attribute_or_warn("class","visualization",&logger);
+        dom.dom().set_style_or_warn("box-sizing"    ,"border-box"         ,&logger);
+        dom.dom().set_style_or_warn("padding"       ,&padding             ,&logger);
+        dom.dom().set_style_or_warn("font-family"   ,"DejaVuSansMonoBook" ,&logger);
+        dom.dom().set_style_or_warn("font-size"     ,"12px"               ,&logger);
+        dom.dom().set_style_or_warn("color"         ,text_color           ,&logger);
+        dom.dom().set_style_or_warn("pointer-events","auto"               ,&logger);
+        header.set_style_or_warn("position"   ,"relative"  ,&logger);
+        header.set_style_or_warn("overflow"   ,"hidden"    ,&logger);
+        header.set_style_or_warn("height"     ,&row_height ,&logger);
+        header.set_style_or_warn("font-weight","bold"      ,&logger);
+        header.set_style_or_warn("cursor"     ,"pointer"   ,&logger);
+        body.set_attribute_or_warn("class","scrollable",&logger);
+        body.set_style_or_warn("position","relative",&logger);
+        body.set_style_or_warn("overflow","auto"    ,&logger);
+        spacer.set_style_or_warn("position","relative",&logger);
+        dom.dom().append_or_warn(&header,&logger);
+        dom.dom().append_or_warn(&body,&logger);
+        body.append_or_warn(&spacer,&logger);
+
+        scene.dom.layers.back.manage(&dom);
+        let model = Model {logger,dom,header,body,spacer,size,scroll,grid,widths,sort,order
+                          ,resize,listeners,scene};
+        model.init()
+    }
+
+    fn init(self) -> Self {
+        self.reload_style();
+        self
+    }
+
+    /// Register the DOM event listeners forwarding the events to the given FRP sources.
+    fn attach_listeners
+    ( &self
+    , scrolled         : &frp::Source<Vector2>
+    , header_pressed   : &frp::Source<usize>
+    , resize_started   : &frp::Source<(usize,f32)>
+    , pointer_moved    : &frp::Source<f32>
+    , pointer_released : &frp::Source
+    ) {
+        let body      = self.body.clone();
+        let on_scroll = f!([scrolled](_event:web_sys::Event) {
+            scrolled.emit(Vector2(body.scroll_left() as f32,body.scroll_top() as f32))
+        });
+        let on_click  = f!([header_pressed](event:web_sys::Event) {
+            if let Some(column) = target_attribute(&event,COLUMN_ATTRIBUTE) {
+                header_pressed.emit(column)
+            }
+        });
+        let on_down   = f!([resize_started](event:web_sys::Event) {
+            if let Some(column) = target_attribute(&event,RESIZE_ATTRIBUTE) {
+                event.prevent_default();
+                event.stop_propagation();
+                resize_started.emit((column,client_x(&event)))
+            }
+        });
+        let on_move   = f!([pointer_moved](event:web_sys::Event) {
+            pointer_moved.emit(client_x(&event))
+        });
+        let on_up     = f!([pointer_released](_event:web_sys::Event) pointer_released.emit(()));
+        let on_leave  = f!([pointer_released](_event:web_sys::Event) pointer_released.emit(()));
+        let root      = self.dom.dom();
+        let listeners = vec!
+            [ self.add_listener(&self.body  ,"scroll"    ,on_scroll)
+            , self.add_listener(&self.header,"click"     ,on_click)
+            , self.add_listener(&self.header,"mousedown" ,on_down)
+            , self.add_listener(root        ,"mousemove" ,on_move)
+            , self.add_listener(root        ,"mouseup"   ,on_up)
+            , self.add_listener(root        ,"mouseleave",on_leave)
+            ];
+        *self.listeners.borrow_mut() = listeners.into_iter().flatten().collect();
+    }
+
+    fn add_listener
+    (&self, target:&web::EventTarget, event:&str, f:impl FnMut(web_sys::Event)+'static)
+    -> Option<Listener> {
+        let closure:Listener = Closure::wrap(Box::new(f));
+        let callback = closure.as_ref().unchecked_ref();
+        match target.add_event_listener_with_callback(event,callback) {
+            Ok(_)  => Some(closure),
+            Err(e) => {
+                error!(&self.logger,"Unable to add the {event} listener: {e:?}");
+                None
+            }
+        }
+    }
+
+    fn set_size(&self, size:Vector2) {
+        self.size.set(size);
+        self.reload_style();
+    }
+
+    fn receive_data(&self, data:&Data) -> Result<(),DataError> {
+        match data {
+            Data::Json {content} => {
+                let input_result = serde_json::from_value(content.deref().clone());
+                let input:Input  = input_result.map_err(|_| DataError::InvalidDataType)?;
+                self.set_grid(input.into());
+                Ok(())
+            }
+            Data::Binary {..} => Err(DataError::BinaryNotSupported)
+        }
+    }
+
+    /// Display the new table. The column widths adjusted by the user and the sorting are kept as
+    /// long as the columns exist.
+    fn set_grid(&self, grid:Grid) {
+        let columns = grid.column_count();
+        self.widths.borrow_mut().resize(columns,DEFAULT_COLUMN_WIDTH);
+        if self.sort.get().map_or(false,|sort| sort.column >= columns) {
+            self.sort.set(None);
+        }
+        *self.order.borrow_mut() = grid.row_order(self.sort.get());
+        *self.grid.borrow_mut()  = grid;
+        self.render();
+    }
+
+    fn set_scroll(&self, scroll:Vector2) {
+        self.scroll.set(scroll);
+        self.render();
+    }
+
+    fn sort_by(&self, column:usize) {
+        let sort = Sort::next(self.sort.get(),column);
+        self.sort.set(sort);
+        *self.order.borrow_mut() = self.grid.borrow().row_order(sort);
+        self.render();
+    }
+
+    fn start_resize(&self, column:usize, start_x:f32) {
+        let width = self.widths.borrow().get(column).copied();
+        self.resize.set(width.map(|width| Resize {column,start_x,width}));
+    }
+
+    fn update_resize(&self, x:f32) {
+        if let Some(resize) = self.resize.get() {
+            let width = (resize.width + x - resize.start_x).max(MIN_COLUMN_WIDTH);
+            if let Some(column_width) = self.widths.borrow_mut().get_mut(resize.column) {
+                *column_width = width;
+            }
+            self.render();
+        }
+    }
+
+    fn finish_resize(&self) {
+        self.resize.set(None);
+    }
+
+    /// Instantiate the cells of the visible area, dropping all the previously displayed ones.
+    fn render(&self) {
+        let grid     = self.grid.borrow();
+        let widths   = self.widths.borrow();
+        let order    = self.order.borrow();
+        let offsets  = column_offsets(&widths);
+        let scroll   = self.scroll.get();
+        let viewport = Viewport::new(scroll,body_size(self.size.get()),order.len(),&offsets);
+        let width    = offsets.last().copied().unwrap_or_default();
+        let height   = order.len() as f32 * ROW_HEIGHT;
+        self.spacer.set_style_or_warn("width" ,format!("{}px",width) ,&self.logger);
+        self.spacer.set_style_or_warn("height",format!("{}px",height),&self.logger);
+        let style    = cell_style();
+        self.header.set_inner_html("");
+        self.spacer.set_inner_html("");
+        for column in viewport.columns.clone() {
+            let left      = offsets[column];
+            let sort      = self.sort.get().filter(|sort| sort.column == column);
+            let indicator = sort.map(|sort| sort.indicator()).unwrap_or_default();
+            let title     = format!("{}{}",grid.header(column),indicator);
+            let position  = Vector2(left - scroll.x,0.0);
+            let cell      = self.cell(&self.header,&title,&style,position,widths[column]);
+            cell.set_attribute_or_warn(COLUMN_ATTRIBUTE,column.to_string(),&self.logger);
+            let handle_x  = offsets[column + 1] - scroll.x - RESIZE_HANDLE_WIDTH / 2.0;
+            let position  = Vector2(handle_x,0.0);
+            let handle    = self.cell(&self.header,"",HANDLE_STYLE,position,RESIZE_HANDLE_WIDTH);
+            handle.set_attribute_or_warn(RESIZE_ATTRIBUTE,column.to_string(),&self.logger);
+            for row in viewport.rows.clone() {
+                let text     = grid.cell_text(order[row],column);
+                let position = Vector2(left,row as f32 * ROW_HEIGHT);
+                self.cell(&self.spacer,&text,&style,position,widths[column]);
+            }
+        }
+    }
+
+    /// Create an absolutely positioned cell. The whole style is set at once, as there may be
+    /// hundreds of cells created on every render.
+    fn cell
+    (&self, parent:&web::HtmlDivElement, text:&str, style:&str, position:Vector2, width:f32)
+    -> web::HtmlDivElement {
+        let cell  = web::create_div();
+        let style = format!("{}left:{}px;top:{}px;width:{}px;",style,position.x,position.y,width);
+        cell.set_inner_text(text);
+        cell.set_attribute_or_warn("style",style,&self.logger);
+        parent.append_or_warn(&cell,&self.logger);
+        cell
+    }
+
+    fn reload_style(&self) {
+        let size = self.size.get();
+        let body = body_size(size);
+        self.dom.set_size(size);
+        self.header.set_style_or_warn("width" ,format!("{}px",body.x),&self.logger);
+        self.body.set_style_or_warn("width"   ,format!("{}px",body.x),&self.logger);
+        self.body.set_style_or_warn("height"  ,format!("{}px",body.y),&self.logger);
+        self.render();
+    }
+
+    fn set_layer(&self, layer:Layer) {
+        layer.apply_for_html_component(&self.scene,&self.dom)
+    }
+}
+
+/// The style shared by all the cells, except their position and width.
+fn cell_style() -> String {
+    let size = format!("height:{0}px;line-height:{0}px;",ROW_HEIGHT);
+    let grid = format!("border-right:1px solid {0};border-bottom:1px solid {0};",GRID_COLOR);
+    let text = "overflow:hidden;white-space:pre;text-overflow:ellipsis;";
+    format!("position:absolute;box-sizing:border-box;padding:0 4px;{}{}{}",size,grid,text)
+}
+
+/// The column index stored in the given attribute of the event target.
+fn target_attribute(event:&web_sys::Event, attribute:&str) -> Option<usize> {
+    let target = event.target()?.dyn_into::<web::Element>().ok()?;
+    target.get_attribute(attribute)?.parse().ok()
+}
+
+fn client_x(event:&web_sys::Event) -> f32 {
+    event.dyn_ref::<web::MouseEvent>().map_or(0.0,|event| event.client_x() as f32)
+}
+
+impl From<Table> for Instance {
+    fn from(t:Table) -> Self {
+        Self::new(&t,&t.frp,&t.network,Some(t.model.dom.clone_ref()))
+    }
+}
+
+impl display::Object for Table {
+    fn display_object(&self) -> &display::object::Instance {
+        self.model.dom.display_object()
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_input() {
+        let json    = r#"[{"a":1,"b":"x"},{"b":"y","c":null}]"#;
+        let records = Grid::from(serde_json::from_str::<Input>(json).unwrap());
+        assert_eq!(records.headers,vec!["a","b","c"]);
+        assert_eq!(records.cell_text(0,1),"x");
+        assert_eq!(records.cell_text(1,0),"");
+
+        let rows = Grid::from(serde_json::from_str::<Input>("[[1,2],[3]]").unwrap());
+        assert_eq!((rows.row_count(),rows.column_count()),(2,2));
+        assert_eq!(rows.header(1),"1");
+
+        let values = Grid::from(serde_json::from_str::<Input>("[1,\"a\"]").unwrap());
+        assert_eq!(values.headers,vec!["Value"]);
+        assert_eq!(values.cell_text(1,0),"a");
+
+        let json  = r#"{"headers":["n"],"rows":[[5]]}"#;
+        let grid  = Grid::from(serde_json::from_str::<Input>(json).unwrap());
+        assert_eq!(grid.cell_text(0,0),"5");
+    }
+
+    #[test]
+    fn sorting_rows() {
+        let json  = "[[10,\"b\"],[null,\"a\"],[2,\"c\"]]";
+        let grid  = Grid::from(serde_json::from_str::<Input>(json).unwrap());
+        let sort  = Sort::next(None,0);
+        assert_eq!(grid.row_order(sort),vec![2,0,1]);
+        let sort  = Sort::next(sort,0);
+        assert_eq!(grid.row_order(sort),vec![0,2,1]);
+        assert_eq!(Sort::next(sort,0),None);
+        assert_eq!(grid.row_order(Sort::next(sort,1)),vec![1,0,2]);
+        assert_eq!(grid.row_order(None),vec![0,1,2]);
+    }
+
+    #[test]
+    fn visible_cells() {
+        let offsets  = column_offsets(&[100.0,50.0,100.0,100.0]);
+        assert_eq!(offsets,vec![0.0,100.0,150.0,250.0,350.0]);
+        let size     = Vector2(120.0,10.0 * ROW_HEIGHT);
+        let viewport = Viewport::new(Vector2(110.0,100.0 * ROW_HEIGHT),size,1000,&offsets);
+        assert_eq!(viewport.columns,1..3);
+        assert_eq!(viewport.rows,100 - OVERSCAN_ROWS..110 + OVERSCAN_ROWS);
+
+        let viewport = Viewport::new(Vector2(0.0,0.0),size,3,&offsets);
+        assert_eq!(viewport.columns,0..2);
+        assert_eq!(viewport.rows,0..3);
+        assert_eq!(Viewport::new(default(),size,0,&[0.0]),default());
+    }
+}
@@ -3,10 +3,13 @@
 pub mod breadcrumbs;
 pub mod edge;
 pub mod node;
+pub mod port_tooltip;
 pub mod tooltip;
 pub mod type_coloring;
 pub mod visualization;
 #[warn(missing_docs)]
+pub mod vis_panel;
+#[warn(missing_docs)]
 pub mod profiling;
 
 pub use breadcrumbs::Breadcrumbs;
@@ -4,6 +4,7 @@
 use crate::prelude::*;
 
 pub mod breadcrumb;
+pub mod overflow;
 pub mod project_name;
 
 pub use breadcrumb::Breadcrumb;
@@ -15,6 +16,8 @@ use crate::component::breadcrumbs::project_name::LINE_HEIGHT;
 use enso_frp as frp;
 use enso_protocol::language_server::MethodPointer;
 use ensogl::application::Application;
+use ensogl::control::callback;
+use ensogl::control::io::mouse;
 use ensogl::display::camera::Camera2d;
 use ensogl::display::object::ObjectOps;
 use ensogl::display::Scene;
@@ -47,6 +50,8 @@ pub const HEIGHT         : f32 = VERTICAL_MARGIN
 
 // This should be as large as the shadow around the background.
 const MAGIC_SHADOW_MARGIN : f32 = 40.0;
+/// The space left between the breadcrumbs and the right edge of the screen.
+const RIGHT_SCREEN_MARGIN : f32 = 100.0;
 
 
 
@@ -147,8 +152,17 @@ ensogl::define_endpoints! {
         pointer_style      (cursor::Style),
         /// Indicates whether the cursor hovers over the project name.
         project_name_hovered (bool),
+        /// Indicates whether the cursor hovers over the breadcrumbs bar, which then consumes the
+        /// mouse wheel to scroll the overflowing breadcrumbs.
+        hovered            (bool),
         /// Indicates whether the project name was clicked.
         project_mouse_down (),
+        /// Signalizes when the stack of the selected breadcrumbs changed. Contains the calls of
+        /// all the breadcrumbs up to the selected one.
+        stack_changed      (Vec<LocalCall>),
+        /// Indicates whether the breadcrumbs do not fit on the screen, so some of them are
+        /// collapsed or scrolled out of the view.
+        breadcrumbs_overflowed (bool),
     }
 }
 
@@ -178,6 +192,15 @@ pub struct BreadcrumbsModel {
     /// Describes an empty space on the left of all the content. This space will be covered by the
     /// background and is intended to make room for windows control buttons.
     gap_width             : Rc<Cell<f32>>,
+    /// The entry replacing the breadcrumbs collapsed when they do not fit on the screen.
+    ellipsis              : overflow::ellipsis::View,
+    /// Whether the collapsed breadcrumbs were expanded by clicking the ellipsis entry.
+    expanded              : Rc<Cell<bool>>,
+    /// The horizontal scroll offset of the breadcrumbs, see `overflow::clamp_scroll`.
+    scroll_offset         : Rc<Cell<f32>>,
+    /// The width of the whole content covered by the background.
+    width                 : Rc<Cell<f32>>,
+    overflowed            : Rc<Cell<bool>>,
 }
 
 impl BreadcrumbsModel {
@@ -198,11 +221,22 @@ impl BreadcrumbsModel {
         let camera                = scene.camera().clone_ref();
         let background            = background::View::new(&logger);
         let gap_width             = default();
+        let ellipsis              = overflow::ellipsis::View::new(&logger);
+        let expanded              = default();
+        let scroll_offset         = default();
+        let width                 = default();
+        let overflowed            = default();
 
         scene.layers.panel.add_exclusive(&background);
+        scene.layers.panel.add_exclusive(&ellipsis);
+        let ellipsis_height = LINE_HEIGHT + breadcrumb::VERTICAL_MARGIN * 2.0;
+        ellipsis.size.set(Vector2(overflow::ELLIPSIS_WIDTH,ellipsis_height));
+        ellipsis.set_position_x(overflow::ELLIPSIS_WIDTH / 2.0);
+        ellipsis.set_position_y(-ellipsis_height/2.0 - VERTICAL_MARGIN - breadcrumb::PADDING);
 
         Self{logger,display_object,background,project_name,root,breadcrumbs_container,app
-            ,breadcrumbs,frp_inputs,current_index,camera,gap_width}.init(&scene)
+            ,breadcrumbs,frp_inputs,current_index,camera,gap_width,ellipsis,expanded,scroll_offset
+            ,width,overflowed}.init(&scene)
     }
 
     fn init(self, scene:&Scene) -> Self {
@@ -215,6 +249,7 @@ impl BreadcrumbsModel {
             scene => {
                 background -> breadcrumb::background;
                 background -> project_name::background;
+                background -> overflow::ellipsis;
             }
         }
 
@@ -230,10 +265,14 @@ impl BreadcrumbsModel {
         // We add half a pixel to the y offset as a quick fix for misaligned text.
         let y_position = screen.height/2.0 - 0.5;
         self.root.set_position(Vector3(x_position.round(), y_position.round(), 0.0));
+        self.update_layout();
     }
 
-    fn breadcrumbs_container_width(&self) -> f32 {
-        self.breadcrumbs.borrow().iter().map(|breadcrumb| breadcrumb.width()).sum()
+    /// The width available for the whole content. The breadcrumbs panel is moved right by the
+    /// graph editor to make room for the macOS traffic lights, so that offset is subtracted too.
+    fn available_width(&self) -> f32 {
+        let screen = self.camera.screen();
+        screen.width - self.display_object.position().x - RIGHT_SCREEN_MARGIN
     }
 
     fn set_gap_width(&self, gap_width:f32) {
@@ -244,13 +283,54 @@ impl BreadcrumbsModel {
     fn update_layout(&self) {
         let gap_width          = self.gap_width.get();
         let project_name_width = self.project_name.width.value().round();
-
+        let content_x          = gap_width + project_name_width;
+        let available          = (self.available_width() - content_x).max(0.0);
         self.project_name.set_position_x(gap_width);
-        self.breadcrumbs_container.set_position_x(gap_width + project_name_width);
 
-        let width              = gap_width
-                               + project_name_width
-                               + self.breadcrumbs_container_width();
+        let breadcrumbs = self.breadcrumbs.borrow();
+        let widths      = breadcrumbs.iter().map(|breadcrumb| breadcrumb.width()).collect_vec();
+        let collapsed   = if self.expanded.get() { None } else {
+            overflow::collapsed_range(&widths,available,overflow::ELLIPSIS_WIDTH)
+        };
+        let mut positions = Vec::with_capacity(widths.len());
+        let mut x         = 0.0;
+        for (index,width) in widths.iter().enumerate() {
+            if collapsed.contains_if(|range| range.start == index) {
+                self.ellipsis.set_position_x((x + overflow::ELLIPSIS_WIDTH / 2.0).round());
+                x += overflow::ELLIPSIS_WIDTH;
+            }
+            let is_collapsed = collapsed.contains_if(|range| range.contains(&index));
+            positions.push((!is_collapsed).as_some(x));
+            if !is_collapsed { x += width; }
+        }
+        let content_width = x;
+        let scroll_offset = self.scroll_offset.get();
+        let scroll_offset = overflow::clamp_scroll(scroll_offset,content_width,available);
+        self.scroll_offset.set(scroll_offset);
+        self.overflowed.set(collapsed.is_some() || content_width > available);
+        self.breadcrumbs_container.set_position_x(content_x + scroll_offset);
+
+        // Only the breadcrumbs fully fitting in the available space are displayed.
+        let in_view = |x:f32, width:f32| {
+            x + scroll_offset >= 0.0 && x + scroll_offset + width <= available
+        };
+        for ((breadcrumb,position),width) in breadcrumbs.iter().zip(positions).zip(&widths) {
+            match position.filter(|x| in_view(*x,*width)) {
+                Some(x) => {
+                    breadcrumb.set_position_x(x.round());
+                    self.breadcrumbs_container.add_child(breadcrumb);
+                }
+                None => breadcrumb.unset_parent(),
+            }
+        }
+        let ellipsis_x = self.ellipsis.position().x - overflow::ELLIPSIS_WIDTH / 2.0;
+        if collapsed.is_some() && in_view(ellipsis_x,overflow::ELLIPSIS_WIDTH) {
+            self.breadcrumbs_container.add_child(&self.ellipsis);
+        } else {
+            self.ellipsis.unset_parent();
+        }
+
+        let width              = content_x + content_width.min(available);
         let background_width   = width + 2.0 * BACKGROUND_PADDING;
         let background_height  =
             crate::MACOS_TRAFFIC_LIGHTS_CONTENT_HEIGHT + BACKGROUND_PADDING * 2.0;
@@ -259,6 +339,43 @@ impl BreadcrumbsModel {
         self.background.size.set(Vector2(width_with_shadow,height_with_shadow));
         self.background.set_position_x(width/2.0);
         self.background.set_position_y(-HEIGHT/2.0);
+        self.width.set(width);
+    }
+
+    /// Expand the breadcrumbs collapsed into the ellipsis entry.
+    fn expand(&self) {
+        self.expanded.set(true);
+        self.update_layout();
+    }
+
+    /// Collapse the breadcrumbs again if they do not fit, and scroll to the end of the stack.
+    fn reset_overflow(&self) {
+        self.expanded.set(false);
+        self.scroll_offset.set(f32::NEG_INFINITY);
+        self.update_layout();
+    }
+
+    /// Scroll the breadcrumbs horizontally by the given amount of pixels.
+    fn scroll_by(&self, delta:f32) {
+        self.scroll_offset.set(self.scroll_offset.get() - delta);
+        self.update_layout();
+    }
+
+    /// Check whether the given point in the screen space lies over the breadcrumbs panel.
+    fn is_hovered(&self, position:Vector2) -> bool {
+        let local  = position - self.root.global_position().xy();
+        let height = HEIGHT + BACKGROUND_PADDING;
+        (0.0..=self.width.get()).contains(&local.x) && (-height..=0.0).contains(&local.y)
+    }
+
+    /// The calls of all the breadcrumbs up to the selected one.
+    fn stack(&self) -> Vec<LocalCall> {
+        let breadcrumbs = self.breadcrumbs.borrow();
+        breadcrumbs.iter().take(self.current_index.get()).map(|breadcrumb| {
+            let definition = breadcrumb.info.method_pointer.clone();
+            let call       = breadcrumb.info.expression_id;
+            LocalCall{call,definition}
+        }).collect()
     }
 
     fn get_breadcrumb(&self, index:usize) -> Option<Breadcrumb> {
@@ -328,7 +445,6 @@ impl BreadcrumbsModel {
                 }
 
                 debug!(self.logger, "Pushing {breadcrumb.info.method_pointer.name} breadcrumb.");
-                breadcrumb.set_position_x(self.breadcrumbs_container_width().round());
                 self.breadcrumbs_container.add_child(&breadcrumb);
                 self.breadcrumbs.borrow_mut().push(breadcrumb);
             }
@@ -424,8 +540,9 @@ impl display::Object for BreadcrumbsModel {
 #[derive(Debug,Clone,CloneRef)]
 #[allow(missing_docs)]
 pub struct Breadcrumbs {
-    model : Rc<BreadcrumbsModel>,
-    frp   : Frp
+    model          : Rc<BreadcrumbsModel>,
+    frp            : Frp,
+    scroll_handler : Rc<callback::Handle>,
 }
 
 impl Breadcrumbs {
@@ -460,6 +577,8 @@ impl Breadcrumbs {
                 (f_!(model.debug_pop_breadcrumb()));
 
             indices <- any4(&push_indices,&pop_indices,&debug_push_indices,&debug_pop_indices);
+            stack   <- indices.map(f_!(model.stack())).on_change();
+            frp.source.stack_changed <+ stack;
             old_breadcrumb <- indices.map(f!([model] (indices) {
                 (Some(*indices),model.get_breadcrumb(indices.0))
             }));
@@ -527,13 +646,38 @@ impl Breadcrumbs {
             eval_ model.project_name.frp.output.width (model.update_layout());
 
 
+            // === Overflow ===
+
+            eval_ indices (model.reset_overflow());
+            eval_ model.ellipsis.events.mouse_down (model.expand());
+            hovered <- scene.mouse.frp.position.map(f!((position) model.is_hovered(*position)));
+            hovered <- hovered.sampler();
+            frp.source.hovered <+ hovered.on_change();
+            relayout <- any_(indices,frp.input.gap_width,scene.frp.camera_changed,
+                model.project_name.frp.output.width,model.ellipsis.events.mouse_down);
+            frp.source.breadcrumbs_overflowed <+ relayout.map(f_!(model.overflowed.get()));
+
+
             // === Pointer style ===
 
             frp.source.pointer_style <+ model.project_name.frp.output.pointer_style;
 
         }
 
-        Self{model,frp}
+        let mouse_manager  = &scene.mouse.mouse_manager;
+        let scroll_handler = f!([model](event:&mouse::OnWheel)
+            if hovered.value() {
+                // The scroll is consumed by the breadcrumbs, so it does not reach the page.
+                event.prevent_default();
+                event.stop_propagation();
+                let delta_x = event.delta_x() as f32;
+                let delta_y = event.delta_y() as f32;
+                model.scroll_by(if delta_x.abs() > delta_y.abs() { delta_x } else { delta_y });
+            }
+        );
+        let scroll_handler = Rc::new(mouse_manager.on_wheel.add(scroll_handler));
+
+        Self{model,frp,scroll_handler}
     }
 }
 
@@ -0,0 +1,115 @@
+//! Handling of the breadcrumbs which do not fit on the screen. The breadcrumbs from the middle of
+//! the stack are collapsed into a single "…" entry, keeping the first and the last ones visible.
+//! Clicking the entry expands the collapsed breadcrumbs, and the content which still does not fit
+//! can be scrolled horizontally with the mouse wheel.
+
+use crate::prelude::*;
+
+use ensogl::data::color;
+use ensogl::display::shape::*;
+use ensogl_theme as theme;
+use std::ops::Range;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The width of the entry replacing the collapsed breadcrumbs.
+pub const ELLIPSIS_WIDTH : f32 = 28.0;
+const DOT_RADIUS         : f32 = 1.5;
+const DOT_SPACING        : f32 = 5.0;
+
+
+
+// ================
+// === Ellipsis ===
+// ================
+
+/// The "…" entry replacing the collapsed breadcrumbs.
+pub mod ellipsis {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (style:Style) {
+            let width     = Var::<Pixels>::from("input_size.x");
+            let height    = Var::<Pixels>::from("input_size.y");
+            let hit_area  = Rect((&width,&height)).fill(color::Rgba::new(0.0,0.0,0.0,0.000_001));
+            let dot       = Circle(DOT_RADIUS.px());
+            let left      = dot.translate_x((-DOT_SPACING).px());
+            let right     = dot.translate_x(DOT_SPACING.px());
+            let dots      = left + &dot + right;
+            let dot_color = style.get_color(theme::graph_editor::breadcrumbs::transparent);
+            let dots      = dots.fill(dot_color);
+            (hit_area + dots).into()
+        }
+    }
+}
+
+
+
+// ================
+// === Collapse ===
+// ================
+
+/// The range of breadcrumbs which should be collapsed into the ellipsis entry, so all the
+/// breadcrumbs of the given `widths` fit in the `available` width. `None` if they fit without
+/// collapsing. The first breadcrumb is collapsed only when there is no other way, and the last one
+/// is never collapsed.
+pub fn collapsed_range
+(widths:&[f32], available:f32, ellipsis_width:f32) -> Option<Range<usize>> {
+    let total:f32 = widths.iter().sum();
+    if total <= available || widths.len() < 2 {
+        return None;
+    }
+    let last      = widths.len() - 1;
+    let fits      = |range:&Range<usize>| {
+        let hidden:f32 = widths[range.clone()].iter().sum();
+        total - hidden + ellipsis_width <= available
+    };
+    let mut range = 1.min(last)..1.min(last);
+    while range.end < last && !fits(&range) {
+        range.end += 1;
+    }
+    if !fits(&range) {
+        range.start = 0;
+    }
+    (!range.is_empty()).as_some(range)
+}
+
+/// Clamp the horizontal scroll offset of the content, so it does not scroll past its edges. The
+/// offset is zero when the content is scrolled to the very left, and negative otherwise.
+pub fn clamp_scroll(offset:f32, content_width:f32, available:f32) -> f32 {
+    let min_offset = (available - content_width).min(0.0);
+    offset.max(min_offset).min(0.0)
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collapsing_middle_breadcrumbs() {
+        let widths = [10.0,10.0,10.0,10.0];
+        assert_eq!(collapsed_range(&widths,40.0,5.0),None);
+        assert_eq!(collapsed_range(&widths,35.0,5.0),Some(1..2));
+        assert_eq!(collapsed_range(&widths,25.0,5.0),Some(1..3));
+        assert_eq!(collapsed_range(&widths,15.0,5.0),Some(0..3));
+        assert_eq!(collapsed_range(&[50.0],10.0,5.0),None);
+    }
+
+    #[test]
+    fn clamping_scroll() {
+        assert_eq!(clamp_scroll(10.0,100.0,50.0),0.0);
+        assert_eq!(clamp_scroll(-20.0,100.0,50.0),-20.0);
+        assert_eq!(clamp_scroll(-80.0,100.0,50.0),-50.0);
+        assert_eq!(clamp_scroll(-80.0,30.0,50.0),0.0);
+    }
+}
@@ -31,6 +31,18 @@ const ARROW_SIZE_Y       : f32 = 20.0;
 
 const HOVER_EXTENSION    : f32 = 10.0;
 
+const DASH_PERIOD        : f32 = 10.0;
+const DASH_GAP           : f32 = 4.0;
+
+const PULSE_WIDTH        : f32 = 16.0;
+const PULSE_DURATION_MS  : f32 = 600.0;
+
+const HANDLE_SIZE        : f32 = 12.0;
+const HANDLE_BORDER      : f32 = 2.0;
+
+/// Number of the straight segments approximating a corner in the edge outline.
+const CORNER_OUTLINE_SEGMENTS : usize = 8;
+
 const MOUSE_OFFSET       : f32 = 2.0;
 
 // It was node::SHADOW_SIZE; Should be moved to theme manager and linked to node::shadow.
@@ -77,6 +89,26 @@ trait EdgeShape : display::Object {
     fn set_color(&self, color:color::Rgba);
     fn set_color_focus(&self, color:color::Rgba);
 
+    /// Draw the shape dashed. Only the lines support it, the other shapes are always solid.
+    fn set_dashed(&self, _dashed:bool) {}
+
+
+    // === Data Pulse ===
+
+    /// Set the data pulse, a ring of the given radius around the given center, highlighting the
+    /// parts of the shape it crosses. The center must be in the shape local coordinate system.
+    /// The arrow does not display the pulse.
+    fn set_pulse_local(&self, _center:Vector2<f32>, _radius:f32) {}
+
+    /// Set the color of the data pulse.
+    fn set_pulse_color(&self, _color:color::Rgba) {}
+
+    /// Set the data pulse around the given center, in the global coordinate system.
+    fn set_pulse(&self, center:Vector2<f32>, radius:f32) {
+        let center = self.global_to_local_position(center);
+        self.set_pulse_local(center,radius);
+    }
+
 
     // === Hover ===
 
@@ -137,6 +169,18 @@ trait EdgeShape : display::Object {
         self.normal_local(local)
     }
 
+    /// Return the polyline running along the middle of the visible shape, in the shape local
+    /// coordinate system. Empty if the shape is hidden or not a part of the edge line.
+    fn outline_local(&self) -> Vec<Vector2<f32>> {
+        default()
+    }
+
+    /// Return the polyline running along the middle of the visible shape, in the global
+    /// coordinate system.
+    fn outline(&self) -> Vec<Vector2<f32>> {
+        self.outline_local().into_iter().map(|p| self.local_to_global_position(p)).collect()
+    }
+
 
     // === Metrics ===
 
@@ -296,6 +340,33 @@ pub mod joint {
     }
 }
 
+/// Handle displayed at each end of the hovered edge. Pressing it detaches that end of the edge.
+pub mod handle {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (color_rgba:Vector4<f32>) {
+            let radius       = Var::<Pixels>::from("input_size.x") / 2.0;
+            let ring         = Circle(&radius) - Circle(&radius - HANDLE_BORDER.px());
+            let ring_color   = Var::<color::Rgba>::from(color_rgba);
+            let ring_colored = ring.fill(ring_color);
+            let hit_area     = Circle(&radius).fill(HOVER_COLOR);
+            (hit_area + ring_colored).into()
+        }
+    }
+}
+
+/// The arc along the middle of the corner shape with the given parameters. The angles are measured
+/// clockwise from the upward direction, like in the corner `snap_local`.
+fn corner_outline(size:Vector2<f32>, radius:f32, start_angle:f32, angle:f32) -> Vec<Vector2<f32>> {
+    if size == Vector2::zero() { return default() }
+    let point_at = |step:usize| {
+        let angle = start_angle + angle * step as f32 / CORNER_OUTLINE_SEGMENTS as f32;
+        Vector2(angle.sin(),angle.cos()) * radius
+    };
+    (0..=CORNER_OUTLINE_SEGMENTS).map(point_at).collect()
+}
+
 fn corner_base_shape
 (radius:&Var<f32>, width:&Var<Pixels>, angle:&Var<f32>, start_angle:&Var<f32>) -> AnyShape {
     let radius         = 1.px() * radius;
@@ -328,11 +399,15 @@ macro_rules! define_corner_start { () => {
             , focus_split_angle  : f32
             , color_rgba:Vector4<f32>
             , focus_color_rgba:Vector4<f32>
+            , pulse_center:Vector2<f32>
+            , pulse_radius:f32
+            , pulse_color_rgba:Vector4<f32>
             ) {
                 let width       = &LINE_WIDTH.px();
                 let shape       = corner_base_shape(&radius,width,&angle,&start_angle);
                 let color       = Var::<color::Rgba>::from(color_rgba);
                 let focus_color = Var::<color::Rgba>::from(focus_color_rgba);
+                let pulse_color = Var::<color::Rgba>::from(pulse_color_rgba);
 
 
                 let shadow_size = 10.px();
@@ -348,6 +423,12 @@ macro_rules! define_corner_start { () => {
 
                 let shape    = shape.difference(node_shape);
 
+                let pulse_radius = 1.px() * pulse_radius;
+                let pulse_outer  = Circle(pulse_radius.clone());
+                let pulse_inner  = Circle(pulse_radius - PULSE_WIDTH.px());
+                let pulse_ring   = (pulse_outer - pulse_inner).translate(pulse_center.px());
+                let pulse        = (&shape * &pulse_ring).fill(&pulse_color);
+
                 let split_shape = FocusedEdge::new(
                     shape,&focus_split_center.px(),&focus_split_angle.into());
                 let shape       = split_shape.fill(&color, &focus_color);
@@ -355,7 +436,7 @@ macro_rules! define_corner_start { () => {
                 let hover_width = width + HOVER_EXTENSION.px() * 2.0;
                 let hover_area  = corner_base_shape(&radius,&hover_width,&angle,&start_angle);
                 let hover_area  = hover_area.fill(HOVER_COLOR);
-                (hover_area + shape).into()
+                (hover_area + shape + pulse).into()
             }
         }
 
@@ -381,6 +462,15 @@ macro_rules! define_corner_start { () => {
                 self.focus_color_rgba.set(color_vec);
             }
 
+            fn set_pulse_local(&self, center:Vector2<f32>, radius:f32) {
+                self.pulse_center.set(center);
+                self.pulse_radius.set(radius);
+            }
+
+            fn set_pulse_color(&self, color:color::Rgba) {
+                self.pulse_color_rgba.set(Vector4(color.red,color.green,color.blue,color.alpha));
+            }
+
             fn normal_local(&self, point:Vector2<f32>) -> Rotation2<f32> {
                 point_rotation(point)
             }
@@ -402,6 +492,11 @@ macro_rules! define_corner_start { () => {
                 let correct_quadrant = lower_bound < vector_angle && upper_bound > vector_angle;
                 correct_quadrant.as_some(Vector2(closest_point.x, closest_point.y))
             }
+
+            fn outline_local(&self) -> Vec<Vector2<f32>> {
+                corner_outline(self.size.get(),self.radius.get(),self.start_angle.get(),
+                    self.angle.get())
+            }
         }
     }
 }}
@@ -422,11 +517,15 @@ macro_rules! define_corner_end { () => {
             , focus_split_angle:f32
             , color_rgba:Vector4<f32>
             , focus_color_rgba:Vector4<f32>
+            , pulse_center:Vector2<f32>
+            , pulse_radius:f32
+            , pulse_color_rgba:Vector4<f32>
             ) {
                 let width       = &LINE_WIDTH.px();
                 let shape       = corner_base_shape(&radius,width,&angle,&start_angle);
                 let color       = Var::<color::Rgba>::from(color_rgba);
                 let focus_color = Var::<color::Rgba>::from(focus_color_rgba);
+                let pulse_color = Var::<color::Rgba>::from(pulse_color_rgba);
 
                 let shadow_size = 10.px() + 1.px();
                 let node_radius = &shadow_size + 1.px() * dim.y();
@@ -439,6 +538,12 @@ macro_rules! define_corner_end { () => {
 
                 let shape = shape.intersection(node_shape);
 
+                let pulse_radius = 1.px() * pulse_radius;
+                let pulse_outer  = Circle(pulse_radius.clone());
+                let pulse_inner  = Circle(pulse_radius - PULSE_WIDTH.px());
+                let pulse_ring   = (pulse_outer - pulse_inner).translate(pulse_center.px());
+                let pulse        = (&shape * &pulse_ring).fill(&pulse_color);
+
                 let split_shape = FocusedEdge::new(
                 shape,&focus_split_center.px(),&focus_split_angle.into());
                 let shape       = split_shape.fill(&color,&focus_color);
@@ -446,7 +551,7 @@ macro_rules! define_corner_end { () => {
                 let hover_width = width + HOVER_EXTENSION.px() * 2.0;
                 let hover_area  = corner_base_shape(&radius,&hover_width,&angle,&start_angle);
                 let hover_area  = hover_area.fill(HOVER_COLOR);
-                (hover_area + shape).into()
+                (hover_area + shape + pulse).into()
             }
         }
 
@@ -471,6 +576,15 @@ macro_rules! define_corner_end { () => {
                 self.focus_color_rgba.set(Vector4(color.red,color.green,color.blue,color.alpha));
             }
 
+            fn set_pulse_local(&self, center:Vector2<f32>, radius:f32) {
+                self.pulse_center.set(center);
+                self.pulse_radius.set(radius);
+            }
+
+            fn set_pulse_color(&self, color:color::Rgba) {
+                self.pulse_color_rgba.set(Vector4(color.red,color.green,color.blue,color.alpha));
+            }
+
             fn normal_local(&self, point:Vector2<f32>) -> Rotation2<f32> {
                 point_rotation(point)
             }
@@ -496,6 +610,11 @@ macro_rules! define_corner_end { () => {
                     None
                 }
             }
+
+            fn outline_local(&self) -> Vec<Vector2<f32>> {
+                corner_outline(self.size.get(),self.radius.get(),self.start_angle.get(),
+                    self.angle.get())
+            }
         }
     }
 }}
@@ -507,17 +626,30 @@ macro_rules! define_line { () => {
         ensogl::define_shape_system! {
             below = [joint];
             (focus_split_center:Vector2<f32>, focus_split_angle:f32, color_rgba:Vector4<f32>,
-             focus_color_rgba:Vector4<f32>) {
+             focus_color_rgba:Vector4<f32>, dash_cut:f32, pulse_center:Vector2<f32>,
+             pulse_radius:f32, pulse_color_rgba:Vector4<f32>) {
                 let width       = LINE_WIDTH.px();
                 let height      = Var::<Pixels>::from("input_size.y");
                 let shape       = Rect((width.clone(),height));
+                // The dashes overlap each other, unless they are shortened by `dash_cut`.
+                let dash_size   = (DASH_PERIOD * 2.0).px();
+                let dash        = Rect((dash_size.clone(),dash_size - 1.px() * dash_cut));
+                let dashes      = dash.repeat((DASH_PERIOD.px(),DASH_PERIOD.px()));
+                let shape       = shape * dashes;
                 let color       = Var::<color::Rgba>::from(color_rgba);
                 let focus_color = Var::<color::Rgba>::from(focus_color_rgba);
+                let pulse_color = Var::<color::Rgba>::from(pulse_color_rgba);
+
+                let pulse_radius = 1.px() * pulse_radius;
+                let pulse_outer  = Circle(pulse_radius.clone());
+                let pulse_inner  = Circle(pulse_radius - PULSE_WIDTH.px());
+                let pulse_ring   = (pulse_outer - pulse_inner).translate(pulse_center.px());
+                let pulse        = (&shape * &pulse_ring).fill(&pulse_color);
 
                 let split_shape = FocusedEdge::new(
                     shape,&focus_split_center.px(),&focus_split_angle.into());
                 let shape       = split_shape.fill(&color,&focus_color);
-                hover_area(shape,HOVER_EXTENSION.px()).into()
+                hover_area((shape + pulse).into(),HOVER_EXTENSION.px()).into()
             }
         }
 
@@ -542,6 +674,19 @@ macro_rules! define_line { () => {
                 self.focus_color_rgba.set(Vector4(color.red,color.green,color.blue,color.alpha));
             }
 
+            fn set_dashed(&self, dashed:bool) {
+                self.dash_cut.set(if dashed { DASH_PERIOD + DASH_GAP } else { 0.0 });
+            }
+
+            fn set_pulse_local(&self, center:Vector2<f32>, radius:f32) {
+                self.pulse_center.set(center);
+                self.pulse_radius.set(radius);
+            }
+
+            fn set_pulse_color(&self, color:color::Rgba) {
+                self.pulse_color_rgba.set(Vector4(color.red,color.green,color.blue,color.alpha));
+            }
+
             fn normal_local(&self, _:Vector2<f32>) -> Rotation2<f32> {
                 Rotation2::new(0.0)
             }
@@ -553,6 +698,13 @@ macro_rules! define_line { () => {
                 let y      = point.y.clamp(-height/2.0, height/2.0);
                 Some(Vector2(0.0, y))
             }
+
+            fn outline_local(&self) -> Vec<Vector2<f32>> {
+                let size = self.size.get();
+                if size == Vector2::zero() { default() } else {
+                    vec![Vector2(0.0,-size.y/2.0),Vector2(0.0,size.y/2.0)]
+                }
+            }
         }
     }
 }}
@@ -1031,9 +1183,21 @@ pub struct Frp {
     pub redraw          : frp::Source,
     pub set_disabled    : frp::Source<bool>,
     pub set_color       : frp::Source<color::Lcha>,
+    /// Highlight the edge, e.g. when a port it is connected to is hovered.
+    pub set_highlighted : frp::Source<bool>,
+    /// Draw the edge cheaper: without the color animations, the hover highlight, and the arrow.
+    /// Used on slow GPUs.
+    pub set_lite_mode   : frp::Source<bool>,
+    /// Draw the edge dashed, e.g. when the type of the connected ports is unknown.
+    pub set_dashed      : frp::Source<bool>,
+    /// Play the data pulse: a highlight traveling along the edge from its source to its target,
+    /// visualizing the flow of a new value. Ignored in the lite mode.
+    pub pulse           : frp::Source,
 
     pub hover_position  : frp::Source<Option<Vector2<f32>>>,
-    pub shape_events    : ShapeViewEventsProxy
+    pub shape_events    : ShapeViewEventsProxy,
+    /// Emitted when the handle at the end of the edge was pressed, with the end to be detached.
+    pub handle_pressed  : frp::Source<PortType>,
 }
 
 impl Frp {
@@ -1049,10 +1213,16 @@ impl Frp {
             def hover_position  = source();
             def set_disabled    = source();
             def set_color       = source();
+            def set_highlighted = source();
+            def set_lite_mode   = source();
+            def set_dashed      = source();
+            def pulse           = source();
+            def handle_pressed  = source();
         }
         let shape_events = ShapeViewEventsProxy::new(network);
         Self {source_width,source_height,target_position,target_attached,source_attached,redraw
-             ,set_disabled,set_color,hover_position,shape_events}
+             ,set_disabled,set_color,set_highlighted,set_lite_mode,set_dashed,pulse
+             ,hover_position,shape_events,handle_pressed}
     }
 }
 
@@ -1123,6 +1293,7 @@ impl Edge {
         let network = frp::Network::new("node_edge");
         let data    = Rc::new(EdgeModelData::new(app.display.scene(),&network));
         let model   = Rc::new(EdgeModel {data});
+        ensogl::debug::frp_owner::register(&network,&model.display_object);
         Self {model,network}.init(app)
     }
 
@@ -1137,12 +1308,16 @@ impl Edge {
         let source_height   = &self.source_height;
         let hover_position  = &self.hover_position;
         let hover_target    = &self.hover_target;
+        let lite_mode       = &self.lite_mode;
 
         let model            = &self.model;
         let shape_events     = &self.frp.shape_events;
+        let source_handle    = &self.model.source_handle;
+        let target_handle    = &self.model.target_handle;
         let edge_color       = color::Animation::new(network);
         let edge_focus_color = color::Animation::new(network);
-        let _style           = StyleWatch::new(&app.display.scene().style_sheet);
+        let scene            = app.display.scene();
+        let _style           = StyleWatch::new(&scene.style_sheet);
 
         model.data.front.register_proxy_frp(network, &input.shape_events);
         model.data.back.register_proxy_frp(network, &input.shape_events);
@@ -1156,6 +1331,7 @@ impl Edge {
             eval input.source_width    ((t) source_width.set(*t));
             eval input.source_height   ((t) source_height.set(*t));
             eval input.hover_position  ((t) hover_position.set(*t));
+            eval input.set_lite_mode   ((t) lite_mode.set(*t));
 
             eval  shape_events.on_mouse_over ((id) hover_target.set(Some(*id)));
             eval_ shape_events.on_mouse_out       (hover_target.set(None));
@@ -1165,16 +1341,49 @@ impl Edge {
             // === Colors ===
 
             is_hovered      <- input.hover_position.map(|t| t.is_some());
-            new_color       <- all_with(&input.set_color,&input.set_disabled,
+            base_color      <- all_with(&input.set_color,&input.set_disabled,
                 f!((c,t)model.base_color(*c,*t)));
+            new_color       <- all_with(&base_color,&input.set_highlighted,
+                f!((c,t)model.highlight_color(*c,*t)));
             new_focus_color <- new_color.map(f!((color) model.focus_color(*color)));
             focus_color     <- switch(&is_hovered,&new_color,&new_focus_color);
 
             edge_color.target       <+ new_color;
             edge_focus_color.target <+ focus_color;
+            edge_color.skip         <+ new_color.gate(&input.set_lite_mode).constant(());
+            edge_focus_color.skip   <+ focus_color.gate(&input.set_lite_mode).constant(());
 
             eval edge_color.value       ((color) model.set_color(color.into()));
             eval edge_focus_color.value ((color) model.set_focus_color(color.into()));
+            eval input.set_dashed       ((dashed) model.set_dashed(*dashed));
+
+
+            // === Data Pulse ===
+
+            pulse_color    <- new_color.map(f!((color) model.pulse_color(*color)));
+            eval pulse_color ((color) model.set_pulse_color(*color));
+
+            pulse_running  <- any_mut::<bool>();
+            pulse          <- input.pulse.gate_not(&input.set_lite_mode);
+            pulse_start    <- scene.frp.frame_time.sample(&pulse);
+            pulse_running  <+ pulse_start.constant(true);
+            pulse_time     <- scene.frp.frame_time.gate(&pulse_running);
+            pulse_progress <- pulse_time.map2(&pulse_start,
+                |time,start| ((time - start) / PULSE_DURATION_MS).min(1.0));
+            pulse_running  <+ pulse_progress.map(|progress| *progress < 1.0);
+            eval pulse_progress ((progress) model.set_pulse_progress(*progress));
+
+
+            // === Handles ===
+
+            handle_over  <- any_(source_handle.events.mouse_over,target_handle.events.mouse_over);
+            handle_out   <- any_(source_handle.events.mouse_out,target_handle.events.mouse_out);
+            handle_hover <- bool(&handle_out,&handle_over);
+            show_handles <- all_with3(&is_hovered,&handle_hover,&input.target_attached,
+                |edge,handle,attached| (*edge || *handle) && *attached);
+            eval show_handles ((visible) model.set_handles_visibility(*visible));
+            input.handle_pressed <+ source_handle.events.mouse_down.constant(PortType::OutputPort);
+            input.handle_pressed <+ target_handle.events.mouse_down.constant(PortType::InputPort);
         }
         self
     }
@@ -1200,6 +1409,12 @@ pub enum PortType {
     OutputPort
 }
 
+impl Default for PortType {
+    fn default() -> Self {
+        Self::OutputPort
+    }
+}
+
 /// Edge definition.
 #[derive(AsRef,Clone,CloneRef,Debug,Deref)]
 pub struct EdgeModel {
@@ -1216,6 +1431,8 @@ pub struct EdgeModelData {
     pub front           : Front,
     pub back            : Back,
     pub joint           : joint::View,
+    pub source_handle   : handle::View,
+    pub target_handle   : handle::View,
     pub source_width    : Rc<Cell<f32>>,
     pub source_height   : Rc<Cell<f32>>,
     pub target_position : Rc<Cell<Vector2>>,
@@ -1225,6 +1442,7 @@ pub struct EdgeModelData {
     layout_state        : Rc<Cell<LayoutState>>,
     hover_position      : Rc<Cell<Option<Vector2<f32>>>>,
     hover_target        : Rc<Cell<Option<display::object::Id>>>,
+    lite_mode           : Rc<Cell<bool>>,
     scene               : Scene,
 }
 
@@ -1236,6 +1454,8 @@ impl EdgeModelData {
         let front          = Front::new(Logger::new_sub(&logger,"front"));
         let back           = Back::new (Logger::new_sub(&logger,"back"));
         let joint          = joint::View::new(Logger::new_sub(&logger,"joint"));
+        let source_handle  = handle::View::new(Logger::new_sub(&logger,"source_handle"));
+        let target_handle  = handle::View::new(Logger::new_sub(&logger,"target_handle"));
 
         let shape_system = scene.layers.main.shape_system_registry.shape_system
             (scene,PhantomData::<joint::DynamicShape>);
@@ -1244,6 +1464,8 @@ impl EdgeModelData {
         display_object.add_child(&front);
         display_object.add_child(&back);
         display_object.add_child(&joint);
+        display_object.add_child(&source_handle);
+        display_object.add_child(&target_handle);
 
         front . side_line  . mod_rotation(|r| r.z = RIGHT_ANGLE);
         back  . side_line  . mod_rotation(|r| r.z = RIGHT_ANGLE);
@@ -1259,10 +1481,12 @@ impl EdgeModelData {
         let hover_position  = default();
         let layout_state    = Rc::new(Cell::new(LayoutState::UpLeft));
         let hover_target    = default();
+        let lite_mode       = default();
 
         let scene = scene.into();
-        Self {display_object,logger,frp,front,back,joint,source_width,source_height,target_position
-             ,target_attached,source_attached,layout_state,hover_position,hover_target,scene}
+        Self {display_object,logger,frp,front,back,joint,source_handle,target_handle,source_width
+             ,source_height,target_position,target_attached,source_attached,layout_state
+             ,hover_position,hover_target,lite_mode,scene}
     }
 
     /// Set the color of the edge.
@@ -1272,6 +1496,35 @@ impl EdgeModelData {
         let color_rgba        = color::Rgba::from(color);
         self.shapes().iter().for_each(|shape| shape.set_color(color_rgba));
         self.joint.color_rgba.set(color_rgba.into());
+        self.source_handle.color_rgba.set(color_rgba.into());
+        self.target_handle.color_rgba.set(color_rgba.into());
+    }
+
+    /// Draw the edge dashed or solid.
+    fn set_dashed(&self, dashed:bool) {
+        self.shapes().iter().for_each(|shape| shape.set_dashed(dashed));
+    }
+
+    fn set_pulse_color(&self, color:color::Lcha) {
+        let color:color::Lcha = color.opaque.into();
+        self.shapes().iter().for_each(|shape| shape.set_pulse_color(color.into()));
+    }
+
+    /// Move the data pulse. The pulse is a ring around the edge source, growing until it passes
+    /// the edge target when the `progress` reaches 1.0. The ring is removed afterwards.
+    fn set_pulse_progress(&self, progress:f32) {
+        let source = self.position().xy() - Vector2(0.0,self.source_height.get() / 2.0);
+        let target = self.target_position.get().xy();
+        let length = (target - source).norm() + PULSE_WIDTH;
+        let radius = if progress < 1.0 { progress * length } else { 0.0 };
+        self.shapes().iter().for_each(|shape| shape.set_pulse(source,radius));
+    }
+
+    /// Show or hide the handles at the ends of the edge.
+    fn set_handles_visibility(&self, visible:bool) {
+        let size = if visible { Vector2(HANDLE_SIZE,HANDLE_SIZE) } else { Vector2::zero() };
+        self.source_handle.size.set(size);
+        self.target_handle.size.set(size);
     }
 
     fn set_focus_color(&self, color:color::Lcha) {
@@ -1287,6 +1540,26 @@ impl EdgeModelData {
         }
     }
 
+    fn highlight_color(&self, color:color::Lcha, is_highlighted:bool) -> color::Lcha {
+        if !is_highlighted {color} else {
+            let styles           = StyleWatch::new(&self.scene.style_sheet);
+            let lightness_factor = theme::graph_editor::edge::highlight::lightness_factor;
+            let lightness_factor = styles.get_number_or(lightness_factor,1.0);
+            let lch              = color.opaque;
+            let lightness        = (lch.lightness * lightness_factor).min(1.0);
+            color::Lcha::new(lightness,lch.chroma,lch.hue,color.alpha)
+        }
+    }
+
+    fn pulse_color(&self, color:color::Lcha) -> color::Lcha {
+        let styles           = StyleWatch::new(&self.scene.style_sheet);
+        let lightness_factor = theme::graph_editor::edge::pulse::lightness_factor;
+        let lightness_factor = styles.get_number_or(lightness_factor,1.0);
+        let lch              = color.opaque;
+        let lightness        = (lch.lightness * lightness_factor).min(1.0);
+        color::Lcha::new(lightness,lch.chroma,lch.hue,color.alpha)
+    }
+
     fn focus_color(&self, color:color::Lcha) -> color::Lcha {
         // We must never use alpha in edges, as it will show artifacts with overlapping sub-parts.
         let color:color::Lcha = color.opaque.into();
@@ -1311,11 +1584,13 @@ impl EdgeModelData {
         let source_node_half_height = self.source_height.get() / 2.0;
         let source_node_circle      = Vector2(node_half_width- source_node_half_height, 0.0);
         let source_node_radius      = source_node_half_height;
+        let lite_mode               = self.lite_mode.get();
+        let hover_position          = self.hover_position.get().filter(|_| !lite_mode);
 
 
         // === Update Highlights ===
 
-        match (fully_attached, self.hover_position.get(), self.hover_target.get()) {
+        match (fully_attached, hover_position, self.hover_target.get()) {
             (true, Some(hover_position), Some(hover_target)) => {
                 let focus_part = self.port_to_detach_for_position(hover_position);
                 let focus_split_result = self.try_enable_focus_split
@@ -1334,6 +1609,13 @@ impl EdgeModelData {
         }
 
 
+        // === Handles ===
+
+        let target_handle_position = self.target_position.get() - self.position().xy();
+        self.source_handle.set_position_xy(Vector2(0.0,-source_node_half_height));
+        self.target_handle.set_position_xy(target_handle_position);
+
+
         // === Target ===
         //
         // Target is the end position of the connection in local node space (the origin is placed in
@@ -1667,7 +1949,7 @@ impl EdgeModelData {
                 fg.main_line.layout_v(main_line_start, main_line_len);
             }
 
-            if main_line_len > ARROW_SIZE_Y {
+            if main_line_len > ARROW_SIZE_Y && !lite_mode {
                 let arrow_y    = (corner1.y - corner1_radius + corner2_y + corner2_radius)/2.0;
                 let arrow_pos  = Vector2(main_line_start.x, arrow_y);
                 let arrow_size = Vector2(ARROW_SIZE_X,ARROW_SIZE_Y);
@@ -1722,6 +2004,20 @@ impl EdgeModelData {
 }
 
 
+// === Geometry ===
+
+impl EdgeModelData {
+    /// The straight segments approximating the visible edge line, in the global coordinate
+    /// system. The corners are approximated by `CORNER_OUTLINE_SEGMENTS` segments each.
+    pub fn segments(&self) -> Vec<(Vector2<f32>,Vector2<f32>)> {
+        self.shapes().iter().flat_map(|shape| {
+            let outline = shape.outline();
+            outline.iter().copied().zip(outline.iter().copied().skip(1)).collect_vec()
+        }).collect()
+    }
+}
+
+
 // === Edge Splitting ===
 
 impl EdgeModelData {
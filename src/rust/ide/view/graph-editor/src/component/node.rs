@@ -5,6 +5,8 @@
 
 #[deny(missing_docs)]
 pub mod action_bar;
+#[deny(missing_docs)]
+pub mod badge;
 pub mod expression;
 pub mod input;
 pub mod output;
@@ -12,17 +14,27 @@ pub mod output;
 pub mod error;
 #[deny(missing_docs)]
 pub mod vcs;
+#[deny(missing_docs)]
+pub mod execution_order;
+#[deny(missing_docs)]
+pub mod execution_status;
 #[warn(missing_docs)]
 pub mod profiling;
 
+pub use badge::BadgeDefinition;
 pub use error::Error;
+pub use execution_status::ExecutionStatus;
 pub use expression::Expression;
 
 use crate::prelude::*;
 
+use crate::component::node::execution_order::ExecutionOrderLabel;
 use crate::component::node::profiling::ProfilingLabel;
+use crate::component::port_tooltip;
+use crate::component::type_coloring;
 use crate::view;
 use crate::component::visualization;
+use crate::component::visualization::container::watchdog;
 use crate::tooltip;
 use crate::Type;
 
@@ -38,6 +50,7 @@ use ensogl::display::traits::*;
 use ensogl::display;
 use ensogl_gui_components::shadow;
 use ensogl_text::Text;
+use ensogl_text as text;
 use ensogl_theme;
 use std::f32::EPSILON;
 
@@ -63,11 +76,20 @@ pub const COMMENT_MARGIN    : f32 = 10.0;
 const INFINITE                 : f32       = 99999.0;
 const ERROR_VISUALIZATION_SIZE : (f32,f32) = visualization::container::DEFAULT_SIZE;
 
+/// The default magnification of the hovered port, see the `set_port_hover_scale` input.
+pub const DEFAULT_PORT_HOVER_SCALE : f32 = 1.5;
+
 const VISUALIZATION_OFFSET_Y : f32  = -120.0;
+const WATCHDOG_BADGE_SIZE    : f32  = 16.0;
+const PIN_ICON_SIZE          : f32  = 14.0;
+/// Vertical distance between the badges and the top edge of the node.
+const BADGES_OFFSET_Y        : f32  = 4.0;
 
 const ENABLE_VIS_PREVIEW     : bool = false;
 const VIS_PREVIEW_ONSET_MS   : f32  = 4000.0;
 const ERROR_PREVIEW_ONSET_MS : f32  = 0000.0;
+/// The identifier of the badge displaying the execution time in the profiling heatmap.
+pub const PROFILING_BADGE_ID : &str = "profiling";
 /// A type of unresolved methods. We filter them out, because we don't want to treat them as types
 /// for ports and edges coloring (due to bad UX otherwise).
 const UNRESOLVED_SYMBOL_TYPE : &str = "Builtins.Main.Unresolved_Symbol";
@@ -109,12 +131,13 @@ pub mod background {
     }
 }
 
-/// Node backdrop. Contains shadow and selection.
+/// Node backdrop. Contains shadow and selection. The shadow is hidden in the lite mode, when the
+/// `lite_mode` parameter is set to 1.0.
 pub mod backdrop {
     use super::*;
 
     ensogl::define_shape_system! {
-        (style:Style, selection:f32) {
+        (style:Style, selection:f32, lite_mode:f32) {
 
             let width  = Var::<Pixels>::from("input_size.x");
             let height = Var::<Pixels>::from("input_size.y");
@@ -125,7 +148,9 @@ pub mod backdrop {
 
             let shadow_radius = &height / 2.0;
             let shadow_base   = Rect((&width,&height)).corners_radius(shadow_radius);
-            let shadow        = shadow::from_shape(shadow_base.into(),style);
+            let shadow_alpha  = Var::<f32>::from(1.0) - &lite_mode;
+            let shadow_base   = shadow_base.into();
+            let shadow        = shadow::from_shape_with_alpha(shadow_base,&shadow_alpha,style);
 
 
             // === Selection ===
@@ -228,6 +253,118 @@ pub mod error_shape {
 
 
 
+// ======================
+// === Watchdog Badge ===
+// ======================
+
+/// Badge displayed in the top right corner of the node when its visualization was throttled or
+/// suspended by the visualization container watchdog.
+pub mod watchdog_badge {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (style:Style,color_rgba:Vector4<f32>) {
+            let radius = (WATCHDOG_BADGE_SIZE / 2.0 - 1.0).px();
+            let circle = Circle(radius);
+            let bar    = Rect((2.px(),6.px())).corners_radius(1.px()).translate_y(1.5.px());
+            let dot    = Circle(1.px()).translate_y((-3.5).px());
+            let badge  = circle - bar - dot;
+            let badge  = badge.fill(color_rgba);
+            badge.into()
+        }
+    }
+}
+
+
+
+// ================
+// === Pin Icon ===
+// ================
+
+/// Icon displayed in the top left corner of the pinned node, which cannot be dragged.
+pub mod pin_icon {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (style:Style) {
+            let color  = style.get_color(ensogl_theme::graph_editor::node::pin);
+            let head   = Circle(3.px()).translate_y(2.5.px());
+            let needle = Rect((1.5.px(),6.px())).translate_y((-2.5).px());
+            let icon   = head + needle;
+            let icon   = icon.rotate((-45.0_f32).to_radians().radians());
+            let icon   = icon.fill(color);
+            icon.into()
+        }
+    }
+}
+
+
+
+// ===============================
+// === Removal Preview Outline ===
+// ===============================
+
+/// The way the node is affected by a pending removal of nodes.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum RemovalRole {
+    /// The node itself will be removed.
+    Removed,
+    /// The node will lose some of its inputs.
+    Affected,
+}
+
+/// Outline displayed around the node when previewing the removal of nodes.
+pub mod removal_preview_shape {
+    use super::*;
+
+    const OUTLINE_WIDTH : f32 = 4.0;
+
+    ensogl::define_shape_system! {
+        (style:Style,color_rgba:Vector4<f32>) {
+            let width   = Var::<Pixels>::from("input_size.x");
+            let height  = Var::<Pixels>::from("input_size.y");
+            let width   = width  - PADDING.px() * 2.0;
+            let height  = height - PADDING.px() * 2.0;
+            let radius  = RADIUS.px();
+            let base    = Rect((&width,&height)).corners_radius(&radius);
+            let outline = base.grow(OUTLINE_WIDTH.px()) - base;
+            outline.fill(color_rgba).into()
+        }
+    }
+}
+
+
+
+// ==================
+// === Focus Ring ===
+// ==================
+
+/// Ring displayed around the node having the keyboard focus. See the `accessibility` module of the
+/// graph editor.
+pub mod focus_ring_shape {
+    use super::*;
+
+    const RING_WIDTH  : f32 = 2.0;
+    const RING_OFFSET : f32 = 4.0;
+
+    ensogl::define_shape_system! {
+        (style:Style) {
+            let width  = Var::<Pixels>::from("input_size.x");
+            let height = Var::<Pixels>::from("input_size.y");
+            let width  = width  - PADDING.px() * 2.0;
+            let height = height - PADDING.px() * 2.0;
+            let radius = RADIUS.px();
+            let base   = Rect((&width,&height)).corners_radius(&radius);
+            let inner  = base.grow(RING_OFFSET.px());
+            let outer  = base.grow((RING_OFFSET + RING_WIDTH).px());
+            let ring   = outer - inner;
+            ring.fill(style.get_color(theme::graph_editor::node::focus_ring)).into()
+        }
+    }
+}
+
+
+
 // ==============
 // === Crumbs ===
 // ==============
@@ -275,6 +412,10 @@ ensogl::define_endpoints! {
         set_disabled          (bool),
         set_input_connected   (span_tree::Crumbs,Option<Type>,bool),
         set_expression        (Expression),
+        /// Temporarily display the given expression instead of the set one, e.g. the suggestion
+        /// hovered in the searcher. The preview is displayed in the disabled color and is not
+        /// reported by the `expression` output. `None` restores the set expression.
+        set_expression_preview (Option<Expression>),
         set_comment           (Comment),
         set_error             (Option<Error>),
         /// Set the expression USAGE type. This is not the definition type, which can be set with
@@ -289,17 +430,87 @@ ensogl::define_endpoints! {
         set_profiling_min_global_duration (f32),
         set_profiling_max_global_duration (f32),
         set_profiling_status              (profiling::Status),
+        /// Tint the node by its relative execution time and display the time in a badge, without
+        /// switching to the profiling view mode.
+        set_profiling_heatmap             (bool),
+        /// Set the index of the node in the execution order of the graph, displayed in the
+        /// execution order overlay. `None` hides the index.
+        set_execution_order               (Option<usize>),
+        /// Set the execution status of the node, as reported by the engine. The status is
+        /// displayed as a small indicator in the bottom right corner of the node.
+        set_execution_status              (ExecutionStatus),
+        /// Set the documentation of the argument of the input port, displayed in the port
+        /// tooltip. An empty string removes the documentation.
+        set_port_documentation            (span_tree::Crumbs,String),
+        /// Highlight the input port as if it was hovered, e.g. when an edge connected to it is
+        /// hovered.
+        highlight_input_port              (Switch<span_tree::Crumbs>),
+        /// Highlight the output port as if it was hovered, e.g. when an edge connected to it is
+        /// hovered.
+        highlight_output_port             (Switch<span_tree::Crumbs>),
+        /// Mask the code of the input port, for example because it contains credentials.
+        set_port_masked                   (span_tree::Crumbs,bool),
+        /// Reveal the code of the masked ports until the edit mode ends. The node cannot be
+        /// edited while it has unrevealed masked ports.
+        reveal_masked_ports               (),
+        /// Recompute the colors of all ports, e.g. after the type coloring was changed.
+        refresh_type_colors               (),
+        /// Set the color of the node chosen by the user, e.g. to mark a group of nodes. The color
+        /// is blended with the background and the type colors of the output ports. `None` removes
+        /// the override.
+        set_color_override                (Option<color::Lcha>),
         /// Indicate whether on hover the quick action icons should appear.
-        show_quick_action_bar_on_hover    (bool)
+        show_quick_action_bar_on_hover    (bool),
+        /// Highlight the node as affected by a pending removal of nodes. `None` removes the
+        /// highlight.
+        set_removal_preview               (Option<RemovalRole>),
+        /// Display the ring indicating that the node has the keyboard focus.
+        set_focused                       (bool),
+        set_frozen                        (bool),
+        set_skipped                       (bool),
+        /// Configure which actions are available in the node's action bar.
+        set_actions                       (action_bar::Actions),
+        /// Indicate that the recomputation of the node was requested. The pending state is
+        /// cleared as soon as the node receives a new type, error, or finished profiling or
+        /// execution status.
+        set_recompute_pending             (bool),
+        /// Display the pin icon, indicating that the node cannot be dragged.
+        set_pinned                        (bool),
+        /// Render the node cheaper, without the shadow. Used on slow GPUs.
+        set_lite_mode                     (bool),
+        /// Display only the node background, without the expression and comment. Used when the
+        /// node is too small on the screen to be readable.
+        set_simplified                    (bool),
+        /// Hide the expensive parts of the node, like the ports, labels, and visualization,
+        /// leaving only its background. The parts are only detached from the scene, so they are
+        /// not drawn, but they keep their state. Used for the nodes lying far outside of the
+        /// screen, see the `culling` module of the graph editor.
+        set_details_hidden                (bool),
+        /// Set the magnification of the hovered port. The hovered port and its hover area grow,
+        /// and while an edge is dragged, the hover areas of all input ports grow, making the ports
+        /// easier to hit. The scale of `1.0` disables the magnification.
+        set_port_hover_scale              (f32),
+        /// Attach a badge to the node, replacing the badge with the same identifier, if any. The
+        /// badges are displayed in a row above the top right corner of the node.
+        add_badge                         (BadgeDefinition),
+        /// Remove the badge with the given identifier.
+        remove_badge                      (ImString),
     }
     Output {
         /// Press event. Emitted when user clicks on non-active part of the node, like its
         /// background. In edit mode, the whole node area is considered non-active.
         background_press         (),
         expression               (Text),
+        /// Locations of all cursors in the expression being edited.
+        expression_cursors       (Vec<text::Location>),
         comment                  (Comment),
         skip                     (bool),
         freeze                   (bool),
+        /// Emitted when the user pressed the recompute action of the node.
+        recompute                (),
+        execution_status         (ExecutionStatus),
+        /// Emitted when the masked ports were revealed. Meant for auditing.
+        masked_ports_revealed    (),
         hover                    (bool),
         error                    (Option<Error>),
         /// Whether visualization was permanently enabled (e.g. by pressing the button).
@@ -309,7 +520,18 @@ ensogl::define_endpoints! {
         visualization_path       (Option<visualization::Path>),
         expression_label_visible (bool),
         tooltip                  (tooltip::Style),
-        bounding_box             (BoundingBox)
+        /// Content of the tooltip of the hovered input port and the port position relative to the
+        /// node. `None` if no input port is hovered.
+        port_tooltip             (Option<(port_tooltip::Content,Vector2)>),
+        bounding_box             (BoundingBox),
+        /// Emitted when the visualization watchdog throttled or suspended the visualization.
+        visualization_misbehaving (watchdog::Report),
+        /// Emitted when the badge with the given identifier was pressed.
+        badge_pressed            (ImString),
+        /// Emitted when the active state of the toggleable badge with the given identifier was
+        /// switched by a press.
+        badge_toggled            (ImString,bool),
+        color_override           (Option<color::Lcha>),
     }
 }
 
@@ -396,7 +618,14 @@ pub struct NodeModel {
     pub background          : background::View,
     pub drag_area           : drag_area::View,
     pub error_indicator     : error_shape::View,
+    pub watchdog_badge      : watchdog_badge::View,
+    pub pin_icon            : pin_icon::View,
+    pub removal_preview     : removal_preview_shape::View,
+    pub focus_ring          : focus_ring_shape::View,
     pub profiling_label     : ProfilingLabel,
+    pub execution_order     : ExecutionOrderLabel,
+    pub execution_status    : execution_status::StatusIndicator,
+    pub badges              : badge::Badges,
     pub input               : input::Area,
     pub output              : output::Area,
     pub visualization       : visualization::Container,
@@ -405,6 +634,7 @@ pub struct NodeModel {
     pub vcs_indicator       : vcs::StatusIndicator,
     pub style               : StyleWatchFrp,
     pub comment             : ensogl_text::Area,
+    pub port_documentation  : Rc<RefCell<HashMap<span_tree::Crumbs,String>>>,
 }
 
 impl NodeModel {
@@ -421,11 +651,18 @@ impl NodeModel {
                 edge::back::corner        -> error_shape;
                 edge::back::line          -> error_shape;
                 error_shape               -> backdrop;
+                removal_preview_shape     -> backdrop;
+                focus_ring_shape          -> backdrop;
                 backdrop                  -> output::port::single_port;
                 backdrop                  -> output::port::multi_port;
                 output::port::single_port -> background;
                 output::port::multi_port  -> background;
                 background                -> drag_area;
+                background                -> watchdog_badge;
+                background                -> pin_icon;
+                background                -> execution_status::ring_shape;
+                background                -> execution_status::dot_shape;
+                background                -> badge::shape;
                 drag_area                 -> edge::front::corner;
                 drag_area                 -> edge::front::line;
                 edge::front::corner       -> input::port::hover;
@@ -442,7 +679,17 @@ impl NodeModel {
         let error_indicator_logger  = Logger::new_sub(&logger,"error_indicator");
 
         let error_indicator = error_shape::View::new(&error_indicator_logger);
+        let watchdog_badge  = watchdog_badge::View::new(&error_indicator_logger);
+        watchdog_badge.size.set(Vector2(WATCHDOG_BADGE_SIZE,WATCHDOG_BADGE_SIZE));
+        let pin_icon        = pin_icon::View::new(&main_logger);
+        pin_icon.size.set(Vector2(PIN_ICON_SIZE,PIN_ICON_SIZE));
+        pin_icon.set_position_y(HEIGHT/2.0);
+        let removal_preview = removal_preview_shape::View::new(&main_logger);
+        let focus_ring      = focus_ring_shape::View::new(&main_logger);
         let profiling_label = ProfilingLabel::new(app);
+        let execution_order = ExecutionOrderLabel::new(app);
+        let execution_status = execution_status::StatusIndicator::new(app);
+        let badges          = badge::Badges::new(app);
         let backdrop        = backdrop::View::new(&main_logger);
         let background      = background::View::new(&main_logger);
         let drag_area       = drag_area::View::new(&drag_logger);
@@ -450,6 +697,9 @@ impl NodeModel {
         let display_object  = display::object::Instance::new(&logger);
 
         display_object.add_child(&profiling_label);
+        display_object.add_child(&execution_order);
+        display_object.add_child(&execution_status);
+        display_object.add_child(&badges);
         display_object.add_child(&drag_area);
         display_object.add_child(&backdrop);
         display_object.add_child(&background);
@@ -482,10 +732,32 @@ impl NodeModel {
         let comment = ensogl_text::Area::new(app);
         display_object.add_child(&comment);
 
+        let port_documentation = default();
+
         let app = app.clone_ref();
         Self {app,display_object,logger,backdrop,background,drag_area,error_indicator
-             ,profiling_label,input,output,visualization,error_visualization,action_bar
-             ,vcs_indicator,style,comment}.init()
+             ,watchdog_badge,pin_icon,removal_preview,focus_ring,profiling_label,execution_order
+             ,execution_status,badges,input,output,visualization,error_visualization,action_bar
+             ,vcs_indicator,style,comment,port_documentation}.init()
+    }
+
+    fn set_port_documentation(&self, crumbs:&span_tree::Crumbs, documentation:&str) {
+        let mut port_documentation = self.port_documentation.borrow_mut();
+        if documentation.is_empty() {
+            port_documentation.remove(crumbs);
+        } else {
+            port_documentation.insert(crumbs.clone(),documentation.to_owned());
+        }
+    }
+
+    /// Content of the input port tooltip and the port position relative to the node.
+    fn port_tooltip(&self, crumbs:&span_tree::Crumbs) -> (port_tooltip::Content,Vector2) {
+        let name          = self.input.port_name(crumbs);
+        let tp            = self.input.port_type(crumbs);
+        let documentation = self.port_documentation.borrow().get(crumbs).cloned();
+        let content       = port_tooltip::Content {name,tp,documentation};
+        let offset        = self.input.port_offset(crumbs).unwrap_or_default();
+        (content,offset + Vector2(0.0,HEIGHT/2.0))
     }
 
     pub fn get_crumbs_by_id(&self, id:ast::Id) -> Option<Crumbs> {
@@ -527,12 +799,20 @@ impl NodeModel {
         self.background.size.set(padded_size);
         self.drag_area.size.set(padded_size);
         self.error_indicator.size.set(padded_size);
+        self.removal_preview.size.set(padded_size);
+        self.focus_ring.size.set(padded_size);
         self.vcs_indicator.set_size(padded_size);
         self.backdrop.mod_position(|t| t.x = width/2.0);
         self.background.mod_position(|t| t.x = width/2.0);
         self.drag_area.mod_position(|t| t.x = width/2.0);
         self.error_indicator.set_position_x(width/2.0);
+        self.removal_preview.set_position_x(width/2.0);
+        self.focus_ring.set_position_x(width/2.0);
         self.vcs_indicator.set_position_x(width/2.0);
+        self.watchdog_badge.set_position_xy(Vector2(width,HEIGHT/2.0));
+        self.execution_status.set_position_xy(Vector2(width,-HEIGHT/2.0));
+        let badges_y = HEIGHT/2.0 + BADGES_OFFSET_Y + badge::HEIGHT/2.0;
+        self.badges.set_position_xy(Vector2(width - WATCHDOG_BADGE_SIZE,badges_y));
 
         let action_bar_width = ACTION_BAR_WIDTH;
         self.action_bar.mod_position(|t| {
@@ -571,6 +851,75 @@ impl NodeModel {
             self.display_object.add_child(&self.error_indicator);
         }
     }
+
+    fn set_removal_preview(&self, role:Option<RemovalRole>, style:&StyleWatch) {
+        use ensogl_theme::graph_editor::node::removal_preview as removal_theme;
+        if let Some(role) = role {
+            let path = match role {
+                RemovalRole::Removed  => removal_theme::removed,
+                RemovalRole::Affected => removal_theme::affected,
+            };
+            let color = color::Rgba::from(style.get_color(path));
+            self.removal_preview.color_rgba.set(color.into());
+            self.display_object.add_child(&self.removal_preview);
+        } else {
+            self.removal_preview.unset_parent();
+        }
+    }
+
+    fn set_simplified(&self, simplified:bool) {
+        if simplified {
+            self.input.unset_parent();
+            self.comment.unset_parent();
+        } else {
+            self.display_object.add_child(&self.input);
+            self.display_object.add_child(&self.comment);
+        }
+    }
+
+    fn set_details_hidden(&self, hidden:bool) {
+        let parts : [&dyn display::Object;8] =
+            [ &self.output,&self.visualization,&self.action_bar,&self.profiling_label
+            , &self.execution_order,&self.execution_status,&self.badges,&self.vcs_indicator ];
+        for part in parts.iter() {
+            if hidden {
+                part.display_object().unset_parent();
+            } else {
+                self.display_object.add_child(part.display_object());
+            }
+        }
+    }
+
+    fn set_focused(&self, focused:bool) {
+        if focused {
+            self.display_object.add_child(&self.focus_ring);
+        } else {
+            self.focus_ring.unset_parent();
+        }
+    }
+
+    fn set_pinned(&self, pinned:bool) {
+        if pinned {
+            self.display_object.add_child(&self.pin_icon);
+        } else {
+            self.pin_icon.unset_parent();
+        }
+    }
+
+    fn set_watchdog_report(&self, report:Option<&watchdog::Report>, style:&StyleWatch) {
+        use ensogl_theme::graph_editor::visualization::watchdog as watchdog_theme;
+        if let Some(report) = report {
+            let path = match report.action {
+                watchdog::Action::Throttled => watchdog_theme::throttled,
+                watchdog::Action::Suspended => watchdog_theme::suspended,
+            };
+            let color = color::Rgba::from(style.get_color(path));
+            self.watchdog_badge.color_rgba.set(color.into());
+            self.display_object.add_child(&self.watchdog_badge);
+        } else {
+            self.watchdog_badge.unset_parent();
+        }
+    }
 }
 
 impl Node {
@@ -630,8 +979,23 @@ impl Node {
                 move |(_,tp)| *tp != unresolved_symbol_type
             );
             eval filtered_usage_type (((a,b)) model.set_expression_usage_type(a,b));
-            eval frp.set_expression  ((a)     model.set_expression(a));
-            out.source.expression                  <+ model.input.frp.expression;
+            is_previewing        <- any(...);
+            displayed_expression <- frp.set_expression.gate_not(&is_previewing);
+            eval displayed_expression ((a) model.set_expression(a));
+            preview_expression   <- frp.set_expression_preview.filter_map(|e| e.clone());
+            preview_cleared      <- frp.set_expression_preview.filter(|e| e.is_none());
+            preview_cleared      <- preview_cleared.gate(&is_previewing);
+            restored_expression  <- frp.set_expression.sample(&preview_cleared);
+            // The preview flag is raised before the preview is displayed and lowered after the
+            // set expression is restored, so neither of them is reported as the node expression.
+            is_previewing        <+ preview_expression.constant(true);
+            eval preview_expression  ((a) model.set_expression(a));
+            eval restored_expression ((a) model.set_expression(a));
+            is_previewing        <+ preview_cleared.constant(false);
+            model.input.set_preview <+ is_previewing;
+            expression <- model.input.frp.expression.gate_not(&is_previewing);
+            out.source.expression                  <+ expression;
+            out.source.expression_cursors          <+ model.input.frp.cursor_locations;
             model.input.set_connected              <+ frp.set_input_connected;
             model.input.set_disabled               <+ frp.set_disabled;
             model.output.set_expression_visibility <+ frp.set_output_expression_visibility;
@@ -683,6 +1047,22 @@ impl Node {
             show_action_bar   <- out.hover  && frp.show_quick_action_bar_on_hover;
             eval show_action_bar ((t) action_bar.set_visibility(t));
             eval frp.show_quick_action_bar_on_hover((value) action_bar.show_on_hover(value));
+            action_bar.set_action_freeze_state <+ frp.set_frozen;
+            action_bar.set_action_skip_state   <+ frp.set_skipped;
+            action_bar.set_actions             <+ frp.set_actions;
+            out.source.recompute               <+ action_bar.action_recompute;
+
+
+            // === Recompute ===
+
+            let usage_type_set = frp.set_expression_usage_type.clone_ref();
+            profiling_finished <- frp.set_profiling_status.filter(|status| status.is_finished());
+            execution_finished <- frp.set_execution_status.filter(|status| status.is_finished());
+            recompute_done     <- any_(frp.set_error,usage_type_set,profiling_finished,
+                execution_finished);
+            recompute_done     <- recompute_done.constant(false);
+            recompute_pending  <- any(frp.set_recompute_pending,recompute_done);
+            action_bar.set_recompute_pending <+ recompute_pending;
 
 
             // === View Mode ===
@@ -693,6 +1073,25 @@ impl Node {
             model.vcs_indicator.set_visibility  <+ frp.set_view_mode.map(|&mode| {
                 !matches!(mode,view::Mode::Profiling {..})
             });
+
+
+            // === Port Hover Magnification ===
+
+            model.input.set_port_hover_scale  <+ frp.set_port_hover_scale;
+            model.output.set_port_hover_scale <+ frp.set_port_hover_scale;
+
+
+            // === Lite Mode ===
+
+            eval frp.set_lite_mode ((lite) model.backdrop.lite_mode.set(if *lite {1.0} else {0.0}));
+            simplified <- frp.set_simplified || frp.set_details_hidden;
+            eval simplified ((simplified) model.set_simplified(*simplified));
+            eval frp.set_details_hidden ((hidden) model.set_details_hidden(*hidden));
+
+
+            // === Pinning ===
+
+            eval frp.set_pinned ((pinned) model.set_pinned(*pinned));
         }
 
 
@@ -777,6 +1176,26 @@ impl Node {
 
             eval error_color_anim.value ((value) model.set_error_color(value));
 
+
+            // === Visualization Watchdog ===
+
+            let misbehaving = model.visualization.frp.misbehaving.clone_ref();
+            frp.source.visualization_misbehaving <+ misbehaving;
+            vis_changed <- model.visualization.frp.visualisation.constant(());
+            eval misbehaving ([model,style](report) model.set_watchdog_report(Some(report),&style));
+            eval_ vis_changed ([model,style] model.set_watchdog_report(None,&style));
+
+
+            // === Removal Preview ===
+
+            eval frp.set_removal_preview ([model,style](role)
+                model.set_removal_preview(*role,&style)
+            );
+
+
+            // === Focus ===
+
+            eval frp.set_focused ((focused) model.set_focused(*focused));
         }
 
         // === Profiling Indicator ===
@@ -788,6 +1207,71 @@ impl Node {
                 <+ frp.set_profiling_max_global_duration;
             model.profiling_label.set_status <+ frp.set_profiling_status;
             model.input.set_profiling_status <+ frp.set_profiling_status;
+
+            heatmap_status <- all_with(&frp.set_profiling_heatmap,&frp.set_profiling_status,
+                |&heatmap,&status| (heatmap && status.is_finished()).as_some(status));
+            heatmap_badge  <- heatmap_status.filter_map(|status| {
+                status.map(|status| BadgeDefinition::new(PROFILING_BADGE_ID,status.to_string()))
+            });
+            heatmap_hidden <- heatmap_status.filter(|status| status.is_none());
+            frp.add_badge    <+ heatmap_badge;
+            frp.remove_badge <+ heatmap_hidden.constant(ImString::new(PROFILING_BADGE_ID));
+        }
+
+        // === Execution Order ===
+
+        frp::extend! { network
+            model.execution_order.set_order <+ frp.set_execution_order;
+
+
+            // === Badges ===
+
+            eval frp.add_badge ((definition) model.badges.add(definition));
+            eval frp.remove_badge ((id) model.badges.remove(id));
+            frp.source.badge_pressed <+ model.badges.pressed;
+            frp.source.badge_toggled <+ model.badges.toggled;
+        }
+
+        // === Execution Status ===
+
+        frp::extend! { network
+            model.execution_status.set_status <+ frp.set_execution_status;
+            out.source.execution_status       <+ frp.set_execution_status;
+        }
+
+        // === Port Tooltip ===
+
+        frp::extend! { network
+            eval frp.set_port_documentation (((crumbs,doc))
+                model.set_port_documentation(crumbs,doc));
+            port_tooltip <- model.input.frp.on_port_hover.map(f!((hover)
+                hover.on().map(|crumbs| model.port_tooltip(crumbs))
+            ));
+            frp.source.port_tooltip <+ port_tooltip;
+        }
+
+        // === Port Highlight ===
+
+        frp::extend! { network
+            model.input.set_port_highlight  <+ frp.highlight_input_port;
+            model.output.set_port_highlight <+ frp.highlight_output_port;
+        }
+
+        // === Masked Ports ===
+
+        frp::extend! { network
+            model.input.set_port_masked      <+ frp.set_port_masked;
+            model.input.reveal_masked_ports  <+ frp.reveal_masked_ports;
+            out.source.masked_ports_revealed <+ model.input.frp.masked_ports_revealed;
+        }
+
+        // === Type Colors ===
+
+        frp::extend! { network
+            model.input.refresh_type_colors  <+ frp.refresh_type_colors;
+            model.output.refresh_type_colors <+ frp.refresh_type_colors;
+            model.output.set_color_override  <+ frp.set_color_override;
+            frp.source.color_override        <+ frp.set_color_override;
         }
 
         let bg_color_anim = color::Animation::new(network);
@@ -797,6 +1281,12 @@ impl Node {
             // === Color Handling ===
 
             let bgg = style_frp.get_color(ensogl_theme::graph_editor::node::background);
+            let override_mix_path = theme::graph_editor::node::color_override::background_mix;
+            let override_mix      = style_frp.get_number(override_mix_path);
+            normal_bg_color <- all_with3(&bgg,&frp.set_color_override,&override_mix,
+                |&bgg,&color_override,&mix| {
+                    type_coloring::blend_override(bgg.into(),color_override,mix)
+                });
             let profiling_theme = profiling::Theme::from_styles(style_frp,network);
 
             profiling_color <- all_with5
@@ -810,11 +1300,12 @@ impl Node {
                     }
                 });
 
-            bg_color_anim.target <+ all_with3(&bgg,&frp.set_view_mode,&profiling_color,
-                |bgg,&mode,&profiling_color| {
+            bg_color_anim.target <+ all_with4(&normal_bg_color,&frp.set_view_mode,&profiling_color,
+                &frp.set_profiling_heatmap,|&normal_bg_color,&mode,&profiling_color,&heatmap| {
                     match mode {
-                        view::Mode::Normal    => color::Lcha::from(*bgg),
-                        view::Mode::Profiling => profiling_color,
+                        view::Mode::Normal if heatmap => profiling_color,
+                        view::Mode::Normal            => normal_bg_color,
+                        view::Mode::Profiling         => profiling_color,
                     }
                 });
 
@@ -860,6 +1351,8 @@ impl Node {
         frp.set_error.emit(None);
         frp.set_disabled.emit(false);
         frp.show_quick_action_bar_on_hover.emit(true);
+        frp.set_port_hover_scale.emit(DEFAULT_PORT_HOVER_SCALE);
+        ensogl::debug::frp_owner::register(&frp.network,&model.display_object);
 
         Self {model,frp}
     }
@@ -8,6 +8,7 @@ use enso_frp as frp;
 use ensogl::application::Application;
 use ensogl::display::shape::*;
 use ensogl::display;
+use ensogl::gui::component::ShapeViewEvents;
 use ensogl_gui_components::toggle_button;
 use ensogl_gui_components::toggle_button::ToggleButton;
 use ensogl_gui_components::toggle_button::ColorableShape;
@@ -24,6 +25,8 @@ const BUTTON_OFFSET   : f32 = 0.5;
 /// Grow the hover area in x direction by this amount. Used to close the gap between action
 /// icons and node.
 const HOVER_EXTENSION_X: f32 = 15.0;
+/// Rotation speed of the recompute icon while the recomputation is pending, in turns per second.
+const RECOMPUTE_SPIN_SPEED : f32 = 1.0;
 
 
 // ===============
@@ -48,6 +51,39 @@ mod hover_area {
 
 
 
+// ===============
+// === Actions ===
+// ===============
+
+/// The set of actions available in the action bar. Actions which are not available are not
+/// displayed at all.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+#[allow(missing_docs)]
+pub struct Actions {
+    pub visibility : bool,
+    pub freeze     : bool,
+    pub skip       : bool,
+    pub recompute  : bool,
+}
+
+impl Actions {
+    /// Number of available actions.
+    pub fn count(self) -> usize {
+        [self.visibility,self.freeze,self.skip,self.recompute].iter().filter(|t| **t).count()
+    }
+}
+
+impl Default for Actions {
+    /// Note: Freeze and skip are disabled by default because of
+    /// https://github.com/enso-org/ide/issues/1397. They should be enabled by default when
+    /// https://github.com/enso-org/ide/issues/862 has been implemented.
+    fn default() -> Self {
+        Self {visibility:true,freeze:false,skip:false,recompute:true}
+    }
+}
+
+
+
 // ===========
 // === Frp ===
 // ===========
@@ -57,7 +93,14 @@ ensogl::define_endpoints! {
         set_size                    (Vector2),
         set_visibility              (bool),
         set_action_visibility_state (bool),
+        set_action_freeze_state     (bool),
+        set_action_skip_state       (bool),
+        /// Configure which actions are displayed in the action bar.
+        set_actions                 (Actions),
         show_on_hover               (bool),
+        /// Indicate that the recomputation of the node was requested and its result has not
+        /// arrived yet. The recompute icon spins and stays visible while pending.
+        set_recompute_pending       (bool),
     }
 
     Output {
@@ -66,6 +109,7 @@ ensogl::define_endpoints! {
         action_visibility (bool),
         action_freeze     (bool),
         action_skip       (bool),
+        action_recompute  (),
     }
 }
 
@@ -81,6 +125,7 @@ struct Icons {
     freeze         : ToggleButton<icon::freeze::DynamicShape>,
     visibility     : ToggleButton<icon::visibility::DynamicShape>,
     skip           : ToggleButton<icon::skip::DynamicShape>,
+    recompute      : ToggleButton<icon::recompute::DynamicShape>,
 }
 
 impl Icons {
@@ -90,19 +135,25 @@ impl Icons {
         let freeze         = ToggleButton::new(&logger);
         let visibility     = ToggleButton::new(&logger);
         let skip           = ToggleButton::new(&logger);
-        display_object.add_child(&visibility);
-        // Note: Disabled for https://github.com/enso-org/ide/issues/1397
-        // Should be re-enabled when https://github.com/enso-org/ide/issues/862 as been implemented.
-        //
-        // We implemented the additonal action icons, but do not currenlty use them. If they
-        // are used again, uncomment the below code to make the already implemented icons
-        // functional again.
-        //
-        // display_object.add_child(&freeze);
-        // display_object.add_child(&skip);
-        Self {display_object,freeze,visibility,skip}
+        let recompute      = ToggleButton::new(&logger);
+        Self {display_object,freeze,visibility,skip,recompute}
+    }
+
+    /// Show only the icons of the available actions.
+    fn set_actions(&self, actions:Actions) {
+        let buttons = [
+            (self.visibility.display_object() , actions.visibility),
+            (self.freeze.display_object()     , actions.freeze),
+            (self.skip.display_object()       , actions.skip),
+            (self.recompute.display_object()  , actions.recompute),
+        ];
+        for (button,available) in &buttons {
+            if *available { self.display_object.add_child(*button) } else { button.unset_parent() }
+        }
     }
 
+    /// Show or hide the icons. The recompute icon is handled separately, as it stays visible
+    /// while the recomputation is pending.
     fn set_visibility(&self, visible:bool) {
         self.freeze.frp.set_visibility(visible);
         self.skip.frp.set_visibility(visible);
@@ -128,6 +179,7 @@ struct Model {
     hover_area     : hover_area::View,
     icons          : Icons,
     size           : Rc<Cell<Vector2>>,
+    actions        : Rc<Cell<Actions>>,
     shapes         : compound::events::MouseEvents,
     styles         : StyleWatch,
 }
@@ -141,27 +193,31 @@ impl Model {
         let icons          = Icons::new(&logger);
         let shapes         = compound::events::MouseEvents::default();
         let size           = default();
+        let actions        = default();
         let styles         = StyleWatch::new(&scene.style_sheet);
 
         shapes.add_sub_shape(&hover_area);
         shapes.add_sub_shape(&icons.freeze.view());
         shapes.add_sub_shape(&icons.visibility.view());
         shapes.add_sub_shape(&icons.skip.view());
+        shapes.add_sub_shape(&icons.recompute.view());
 
         ensogl::shapes_order_dependencies! {
             scene => {
                 hover_area -> icon::freeze;
                 hover_area -> icon::visibility;
                 hover_area -> icon::skip;
+                hover_area -> icon::recompute;
             }
         }
 
-        Self{display_object,hover_area,icons,size,shapes,styles}.init()
+        Self{display_object,hover_area,icons,size,actions,shapes,styles}.init()
     }
 
     fn init(self) -> Self {
         self.add_child(&self.hover_area);
         self.add_child(&self.icons);
+        self.icons.set_actions(self.actions.get());
         self
     }
 
@@ -198,23 +254,46 @@ impl Model {
     fn set_size(&self, size:Vector2) {
         self.size.set(size);
         self.icons.set_position_x(-size.x/2.0);
-
-        // Note: Disabled for https://github.com/enso-org/ide/issues/1397
-        // Should be re-enabled when https://github.com/enso-org/ide/issues/862 as been implemented.
-        //
-        // We implemented the additonal action icons, but do not currenlty use them. If they
-        // are used again, uncomment the below code to make the already implemented icons
-        // functional again.
-        self.place_button_in_slot(&self.icons.visibility , 0);
-        // self.place_button_in_slot(&self.icons.skip       , 1);
-        // self.place_button_in_slot(&self.icons.freeze     , 2);
-
-        // Note: needs increasing to 3 when re-enabling the above buttons.
-        self.layout_hover_area_to_cover_buttons(1);
+        self.layout_buttons();
 
         // The appears smaller than the other ones, so this is an aesthetic adjustment.
         self.icons.visibility.set_scale_xy(Vector2::new(1.2,1.2));
     }
+
+    fn set_actions(&self, actions:Actions) {
+        self.actions.set(actions);
+        self.icons.set_actions(actions);
+        self.layout_buttons();
+    }
+
+    /// Place the available buttons in consecutive slots, keeping the visibility, skip, freeze,
+    /// recompute order.
+    fn layout_buttons(&self) {
+        let actions  = self.actions.get();
+        let mut slot = 0;
+        if actions.visibility {
+            self.place_button_in_slot(&self.icons.visibility,slot);
+            slot += 1;
+        }
+        if actions.skip {
+            self.place_button_in_slot(&self.icons.skip,slot);
+            slot += 1;
+        }
+        if actions.freeze {
+            self.place_button_in_slot(&self.icons.freeze,slot);
+            slot += 1;
+        }
+        if actions.recompute {
+            self.place_button_in_slot(&self.icons.recompute,slot);
+        }
+        self.layout_hover_area_to_cover_buttons(actions.count());
+    }
+
+    /// Rotate the recompute icon according to the given frame time, in milliseconds.
+    fn spin_recompute_icon(&self, time:f32) {
+        let turns = time / 1000.0 * RECOMPUTE_SPIN_SPEED;
+        self.icons.recompute.set_rotation_z(-turns * 2.0 * std::f32::consts::PI);
+    }
 }
 
 impl display::Object for Model {
@@ -258,10 +337,16 @@ impl ActionBar {
     pub fn new(logger:impl AnyLogger, app:&Application) -> Self {
         let model = Rc::new(Model::new(logger,app));
         let frp   = Frp::new();
-        ActionBar{frp,model}.init_frp()
+        ActionBar{frp,model}.init_frp(app)
     }
 
-    fn init_frp(self) -> Self {
+    /// The mouse events of the recompute icon. Allows emulating the user pressing the icon.
+    pub fn recompute_icon_events(&self) -> ShapeViewEvents {
+        self.model.icons.recompute.view().events.clone_ref()
+    }
+
+    fn init_frp(self, app:&Application) -> Self {
+        let scene   = app.display.scene();
         let network = &self.frp.network;
         let frp     = &self.frp;
         let model   = &self.model;
@@ -273,6 +358,9 @@ impl ActionBar {
             eval frp.set_size                    ((size)  model.set_size(*size));
             eval frp.set_visibility              ((t)     model.icons.set_visibility(*t));
             eval frp.set_action_visibility_state ((state) model.icons.visibility.set_state(state));
+            eval frp.set_action_freeze_state     ((state) model.icons.freeze.set_state(state));
+            eval frp.set_action_skip_state       ((state) model.icons.skip.set_state(state));
+            eval frp.set_actions                 ((actions) model.set_actions(*actions));
 
 
             // === Mouse Interactions ===
@@ -289,6 +377,27 @@ impl ActionBar {
             frp.source.action_skip       <+ model.icons.skip.state;
             frp.source.action_freeze     <+ model.icons.freeze.state;
             frp.source.action_visibility <+ model.icons.visibility.state;
+
+
+            // === Recompute ===
+
+            // The recompute icon is not a toggle, its state only reflects the pending
+            // recomputation. The press toggling the icon is reverted before the request is
+            // emitted, so the state changes made by `set_recompute_pending` never emit requests.
+            recompute_pending_init <- source::<bool>();
+            recompute_pending  <- any(&frp.set_recompute_pending,&recompute_pending_init);
+            recompute_press    <- model.icons.recompute.is_pressed.on_true();
+            recompute_restored <- recompute_press.map2(&recompute_pending,|_,pending| *pending);
+            eval recompute_restored ((pending) model.icons.recompute.set_state(pending));
+            frp.source.action_recompute <+ recompute_press.gate_not(&recompute_pending);
+            eval recompute_pending ((pending) {
+                model.icons.recompute.set_state(pending);
+                if !pending { model.icons.recompute.set_rotation_z(0.0) }
+            });
+            recompute_visible <- visibility || recompute_pending;
+            eval recompute_visible ((t) model.icons.recompute.frp.set_visibility(*t));
+            spin_time <- scene.frp.frame_time.gate(&recompute_pending);
+            eval spin_time ((time) model.spin_recompute_icon(*time));
         }
 
         let color_scheme = toggle_button::ColorScheme {
@@ -301,9 +410,11 @@ impl ActionBar {
         model.icons.freeze.frp.set_color_scheme(&color_scheme);
         model.icons.skip.frp.set_color_scheme(&color_scheme);
         model.icons.visibility.frp.set_color_scheme(&color_scheme);
+        model.icons.recompute.frp.set_color_scheme(&color_scheme);
 
         frp.show_on_hover.emit(true);
         visibility_init.emit(false);
+        recompute_pending_init.emit(false);
 
         self
     }
@@ -157,3 +157,34 @@ pub mod skip {
         }
     }
 }
+
+/// Icon for the recompute button. Looks like a circular arrow.
+pub mod recompute {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (color_rgba:Vector4<f32>) {
+            let fill_color  = Var::<color::Rgba>::from(color_rgba);
+            let width       = Var::<Pixels>::from("input_size.x");
+            let height      = Var::<Pixels>::from("input_size.y");
+            let unit        = &width/16.0;
+            let right_angle = 90.0_f32.to_radians().radians();
+            let ring        = make_ring(&unit*6.0,&unit*4.0);
+            let gap         = Rect((&unit*7.0,&unit*7.0)).translate((&unit*3.5,&unit*3.5));
+            let arc         = ring - gap;
+            let arrow_head  = Triangle(&unit*6.0,&unit*4.0).rotate(-right_angle);
+            let arrow_head  = arrow_head.translate((&unit*1.0,&unit*5.0));
+            let icon        = arc + arrow_head;
+            let hover_area  = Rect((width,height)).fill(HOVER_COLOR);
+            let icon        = icon.fill(fill_color);
+
+            (icon+hover_area).into()
+        }
+    }
+
+    impl ColorableShape for DynamicShape {
+        fn set_color(&self, color:color::Rgba) {
+            self.color_rgba.set(Vector4::new(color.red,color.green,color.blue,color.alpha));
+        }
+    }
+}
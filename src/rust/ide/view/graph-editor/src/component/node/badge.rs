@@ -0,0 +1,285 @@
+//! Provides [`Badges`], a row of small auxiliary widgets attached to a node by the application,
+//! like a profiling time badge or a breakpoint toggle. Every badge displays a short label, and
+//! reports presses back to the application. A toggleable badge additionally switches its active
+//! state on every press.
+
+use crate::prelude::*;
+
+use enso_frp as frp;
+use ensogl::application::Application;
+use ensogl::data::color;
+use ensogl::display::shape::*;
+use ensogl::display;
+use ensogl::gui::text;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The height of a badge.
+pub const HEIGHT : f32 = 14.0;
+
+const PADDING_X : f32 = 5.0;
+const GAP       : f32 = 4.0;
+const TEXT_SIZE : f32 = 10.0;
+
+
+
+// =======================
+// === BadgeDefinition ===
+// =======================
+
+/// The definition of a badge, provided by the application.
+#[derive(Clone,Debug,Default,PartialEq)]
+pub struct BadgeDefinition {
+    /// The identifier of the badge, unique within the node. Adding a badge with the identifier of
+    /// an existing one replaces it.
+    pub id     : ImString,
+    /// The short text displayed in the badge, for example a duration.
+    pub label  : ImString,
+    /// Whether pressing the badge switches its active state, like a breakpoint toggle does.
+    pub toggle : bool,
+    /// The initial active state of a toggleable badge. Active badges are highlighted.
+    pub active : bool,
+}
+
+impl BadgeDefinition {
+    /// A badge displaying the label, reporting the presses only.
+    pub fn new(id:impl Into<ImString>, label:impl Into<ImString>) -> Self {
+        let id     = id.into();
+        let label  = label.into();
+        let toggle = false;
+        let active = false;
+        Self {id,label,toggle,active}
+    }
+
+    /// A toggleable badge with the given initial state.
+    pub fn new_toggle(id:impl Into<ImString>, label:impl Into<ImString>, active:bool) -> Self {
+        let toggle = true;
+        Self {toggle,active,..Self::new(id,label)}
+    }
+}
+
+
+
+// =============
+// === Shape ===
+// =============
+
+/// The background of a badge.
+pub mod shape {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (style:Style,color_rgba:Vector4<f32>) {
+            let width  : Var<Pixels> = "input_size.x".into();
+            let height : Var<Pixels> = "input_size.y".into();
+            let shape  = Rect((&width,&height)).corners_radius(&height / 2.0);
+            shape.fill(color_rgba).into()
+        }
+    }
+}
+
+
+
+// =============
+// === Badge ===
+// =============
+
+ensogl::define_endpoints! {
+    Input {
+        set_definition (BadgeDefinition),
+    }
+    Output {
+        pressed (),
+        /// Emitted when the active state of a toggleable badge was switched by a press.
+        toggled (bool),
+        active  (bool),
+        width   (f32),
+    }
+}
+
+/// A single badge. It is placed centered on its origin.
+#[derive(Clone,CloneRef,Debug)]
+struct Badge {
+    root       : display::object::Instance,
+    background : shape::View,
+    label      : text::Area,
+    frp        : Frp,
+}
+
+impl Badge {
+    fn new(app:&Application) -> Self {
+        let scene      = app.display.scene();
+        let logger     = Logger::new("NodeBadge");
+        let root       = display::object::Instance::new(&logger);
+        let background = shape::View::new(&logger);
+        let label      = text::Area::new(app);
+        root.add_child(&background);
+        root.add_child(&label);
+        label.set_default_text_size(text::Size(TEXT_SIZE));
+        label.remove_from_scene_layer(&scene.layers.main);
+        label.add_to_scene_layer(&scene.layers.label);
+
+        let frp     = Frp::new();
+        let network = &frp.network;
+        let styles  = StyleWatchFrp::new(&scene.style_sheet);
+        use ensogl_theme::graph_editor::node::badge as theme;
+
+        frp::extend! { network
+
+            // === Content ===
+
+            label.set_content <+ frp.set_definition.map(|def| def.label.to_string());
+            frp.source.width  <+ label.width.map(|width| (width + 2.0 * PADDING_X).max(HEIGHT));
+            eval frp.width ([background,label](width) {
+                background.size.set(Vector2(*width,HEIGHT));
+                label.set_position_xy(Vector2(PADDING_X - width / 2.0, TEXT_SIZE / 2.0));
+            });
+
+
+            // === Press ===
+
+            toggleable         <- frp.set_definition.map(|definition| definition.toggle);
+            frp.source.pressed <+ background.events.mouse_down;
+            toggle_pressed     <- frp.pressed.gate(&toggleable);
+            toggled            <- toggle_pressed.map2(&frp.active,|_,active| !active);
+            frp.source.toggled <+ toggled;
+            frp.source.active  <+ frp.set_definition.map(|definition| definition.active);
+            frp.source.active  <+ toggled;
+
+
+            // === Color ===
+
+            init <- source::<()>();
+            let background_color = styles.get_color(theme::background);
+            let active_color     = styles.get_color(theme::active);
+            let text_color       = styles.get_color(theme::text);
+            color <- all_with4(&frp.active,&background_color,&active_color,&init,
+                |&active,&inactive,&active_color,_| if active { active_color } else { inactive });
+            eval color ((color) background.color_rgba.set((*color).into()));
+            label_color             <- all_with(&text_color,&init,|&color,_| color);
+            label.set_default_color <+ label_color;
+            label.set_color_all     <+ label_color;
+        }
+
+        init.emit(());
+        Self {root,background,label,frp}
+    }
+}
+
+impl display::Object for Badge {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.root
+    }
+}
+
+
+
+// ==============
+// === Badges ===
+// ==============
+
+ensogl::define_endpoints! {
+    Input {}
+    Output {
+        /// Emitted when the badge with the given identifier was pressed.
+        pressed (ImString),
+        /// Emitted when the active state of the toggleable badge with the given identifier was
+        /// switched.
+        toggled (ImString,bool),
+    }
+}
+
+/// A `display::Object` providing a row of badges. The badges are aligned to the right, with the
+/// right edge of the last badge placed on the origin, and centered vertically on it.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Badges {
+    app      : Application,
+    root     : display::object::Instance,
+    badges   : Rc<RefCell<Vec<(ImString,Badge)>>>,
+    relayout : frp::Any,
+    frp      : Frp,
+}
+
+impl Deref for Badges {
+    type Target = Frp;
+
+    fn deref(&self) -> &Self::Target {
+        &self.frp
+    }
+}
+
+impl Badges {
+    /// Constructor.
+    pub fn new(app:&Application) -> Self {
+        let app     = app.clone_ref();
+        let root    = display::object::Instance::new(Logger::new("NodeBadges"));
+        let badges  = default();
+        let frp     = Frp::new();
+        let network = &frp.network;
+
+        frp::extend! { network
+            relayout <- any_mut::<()>();
+            eval_ relayout ([badges] Self::layout(&badges));
+        }
+
+        Self {app,root,badges,relayout,frp}
+    }
+
+    /// Add the badge, replacing the one with the same identifier, if any.
+    pub fn add(&self, definition:&BadgeDefinition) {
+        let id         = definition.id.clone();
+        let toggled_id = id.clone();
+        let badge      = Badge::new(&self.app);
+        let frp        = &self.frp;
+        let network    = &badge.frp.network;
+        frp::extend! { network
+            frp.source.pressed <+ badge.frp.pressed.constant(id.clone());
+            frp.source.toggled <+ badge.frp.toggled.map(move |t| (toggled_id.clone(),*t));
+            self.relayout      <+ badge.frp.width.constant(());
+        }
+        badge.frp.set_definition.emit(definition);
+        self.root.add_child(&badge);
+
+        let replaced = {
+            let mut badges = self.badges.borrow_mut();
+            match badges.iter_mut().find(|(badge_id,_)| badge_id == &id) {
+                Some(entry) => Some(mem::replace(&mut entry.1,badge)),
+                None        => { badges.push((id,badge)); None }
+            }
+        };
+        if let Some(replaced) = replaced {
+            replaced.unset_parent();
+        }
+        self.relayout.emit(());
+    }
+
+    /// Remove the badge with the given identifier.
+    pub fn remove(&self, id:&ImString) {
+        let mut badges = self.badges.borrow_mut();
+        if let Some(index) = badges.iter().position(|(badge_id,_)| badge_id == id) {
+            let (_,badge) = badges.remove(index);
+            badge.unset_parent();
+        }
+        drop(badges);
+        self.relayout.emit(());
+    }
+
+    fn layout(badges:&RefCell<Vec<(ImString,Badge)>>) {
+        let mut right = 0.0;
+        for (_,badge) in badges.borrow().iter().rev() {
+            let width = badge.frp.width.value();
+            badge.set_position_x(right - width / 2.0);
+            right -= width + GAP;
+        }
+    }
+}
+
+impl display::Object for Badges {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.root
+    }
+}
@@ -0,0 +1,112 @@
+//! Provides [`ExecutionOrderLabel`] displaying the position of a node in the execution order of
+//! the graph.
+
+use crate::prelude::*;
+
+use enso_frp as frp;
+use ensogl::application::Application;
+use ensogl::data::color;
+use ensogl::display::shape::*;
+use ensogl::display;
+use ensogl::gui::text;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// Vertical distance between the label and the top edge of the node.
+const LABEL_OFFSET_Y : f32 = 4.0;
+
+
+
+// ===========
+// === Frp ===
+// ===========
+
+ensogl::define_endpoints! {
+    Input {
+        /// Set the index of the node in the execution order. `None` hides the label.
+        set_order (Option<usize>),
+    }
+}
+
+
+
+// ===========================
+// === ExecutionOrderLabel ===
+// ===========================
+
+/// A `display::Object` providing a label displaying the (one-based) position of a node in the
+/// execution order of the graph. The label is placed above the node's top left corner, so its
+/// origin, as a `display::Object`, should be placed on the node's origin. The text is put on the
+/// `label` scene layer, so it is cheap to show it for all the nodes at once.
+#[derive(Clone,CloneRef,Debug)]
+pub struct ExecutionOrderLabel {
+    root   : display::object::Instance,
+    label  : text::Area,
+    frp    : Frp,
+    styles : StyleWatchFrp,
+}
+
+impl Deref for ExecutionOrderLabel {
+    type Target = Frp;
+
+    fn deref(&self) -> &Self::Target {
+        &self.frp
+    }
+}
+
+impl ExecutionOrderLabel {
+    /// Constructs an `ExecutionOrderLabel` for the given application.
+    pub fn new(app:&Application) -> Self {
+        let scene = app.display.scene();
+        let root  = display::object::Instance::new(Logger::new("ExecutionOrderLabel"));
+
+        let label = text::Area::new(app);
+        root.add_child(&label);
+        let text_size = crate::component::node::input::area::TEXT_SIZE;
+        label.set_position_y(crate::component::node::HEIGHT/2.0 + LABEL_OFFSET_Y + text_size);
+        label.remove_from_scene_layer(&scene.layers.main);
+        label.add_to_scene_layer(&scene.layers.label);
+
+        let frp     = Frp::new();
+        let network = &frp.network;
+        let color   = color::Animation::new(network);
+        let styles  = StyleWatchFrp::new(&scene.style_sheet);
+
+        frp::extend! { network
+
+            // === Visibility ===
+
+            color.target_alpha <+ frp.set_order.map(|order| {
+                if order.is_some() { 1.0 } else { 0.0 }
+            });
+
+
+            // === Color ===
+
+            init <- source::<()>();
+            let text_color = styles.get_color(ensogl_theme::graph_editor::execution_order::text);
+            color.target_color <+ all_with(&text_color,&init,|&c,_| c.into());
+            label.set_default_color <+ color.value.map(|c| c.into());
+            label.set_color_all     <+ color.value.map(|c| c.into());
+
+
+            // === Content ===
+
+            order             <- frp.set_order.filter_map(|order| *order);
+            label.set_content <+ order.map(|index| format!("{}",index + 1));
+        }
+
+        init.emit(());
+        ExecutionOrderLabel {root,label,frp,styles}
+    }
+}
+
+impl display::Object for ExecutionOrderLabel {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.root
+    }
+}
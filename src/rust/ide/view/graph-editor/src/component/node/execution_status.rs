@@ -0,0 +1,222 @@
+//! Provides [`StatusIndicator`] displaying the execution status of a node, as reported by the
+//! engine: a dimmed ring while the node waits for the execution, a spinning arc while it is being
+//! computed, and a dot when its value was taken from the cache or its computation failed.
+
+use crate::prelude::*;
+
+use enso_frp as frp;
+use ensogl::application::Application;
+use ensogl::data::color;
+use ensogl::display::shape::*;
+use ensogl::display;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The size of the indicator.
+pub const SIZE : f32 = 10.0;
+
+const RING_WIDTH  : f32 = 1.5;
+const DOT_RADIUS  : f32 = 2.5;
+/// The part of the full ring displayed while the node is running.
+const RUNNING_ARC : f32 = 0.75;
+/// Rotation speed of the arc while the node is running, in turns per second.
+const SPIN_SPEED  : f32 = 1.0;
+
+
+
+// =======================
+// === ExecutionStatus ===
+// =======================
+
+/// The execution status of a node.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum ExecutionStatus {
+    /// The node waits for the execution.
+    Pending,
+    /// The node is being computed.
+    Running,
+    /// The node was computed. No indicator is displayed.
+    Completed,
+    /// The value of the node was taken from the cache.
+    Cached,
+    /// The computation of the node failed.
+    Failed,
+}
+
+impl Default for ExecutionStatus {
+    fn default() -> Self {
+        Self::Completed
+    }
+}
+
+impl ExecutionStatus {
+    /// Check whether the node execution is finished, successfully or not.
+    pub fn is_finished(self) -> bool {
+        matches!(self,Self::Completed|Self::Cached|Self::Failed)
+    }
+
+    fn color(self, style:&StyleWatch) -> color::Rgba {
+        use ensogl_theme::graph_editor::node::execution_status as theme;
+        let path = match self {
+            Self::Pending   => theme::pending,
+            Self::Running   => theme::running,
+            Self::Completed => return color::Rgba::transparent(),
+            Self::Cached    => theme::cached,
+            Self::Failed    => theme::failed,
+        };
+        style.get_color(path).into()
+    }
+}
+
+
+
+// ==============
+// === Shapes ===
+// ==============
+
+/// The ring, or a part of it, displayed while the node is pending or running.
+pub mod ring_shape {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (style:Style,color_rgba:Vector4<f32>,arc:f32) {
+            let radius = (SIZE / 2.0 - 1.0).px();
+            let ring   = Circle(&radius) - Circle(&radius - RING_WIDTH.px());
+            let angle  = arc * 2.0 * std::f32::consts::PI;
+            let ring   = ring * Plane().cut_angle(angle);
+            let ring   = ring.fill(color_rgba);
+            ring.into()
+        }
+    }
+}
+
+/// The dot displayed when the value of the node was cached or its computation failed.
+pub mod dot_shape {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (style:Style,color_rgba:Vector4<f32>) {
+            let dot = Circle(DOT_RADIUS.px());
+            let dot = dot.fill(color_rgba);
+            dot.into()
+        }
+    }
+}
+
+
+
+// ===========
+// === Frp ===
+// ===========
+
+ensogl::define_endpoints! {
+    Input {
+        set_status (ExecutionStatus),
+    }
+}
+
+
+
+// =============
+// === Model ===
+// =============
+
+#[derive(Clone,CloneRef,Debug)]
+struct Model {
+    root : display::object::Instance,
+    ring : ring_shape::View,
+    dot  : dot_shape::View,
+}
+
+impl Model {
+    fn new() -> Self {
+        let logger = Logger::new("ExecutionStatusIndicator");
+        let root   = display::object::Instance::new(&logger);
+        let ring   = ring_shape::View::new(&logger);
+        let dot    = dot_shape::View::new(&logger);
+        ring.size.set(Vector2(SIZE,SIZE));
+        dot.size.set(Vector2(SIZE,SIZE));
+        Self {root,ring,dot}
+    }
+
+    fn set_status(&self, status:ExecutionStatus, style:&StyleWatch) {
+        let color = status.color(style);
+        self.ring.color_rgba.set(color.into());
+        self.dot.color_rgba.set(color.into());
+        self.ring.set_rotation_z(0.0);
+        self.ring.unset_parent();
+        self.dot.unset_parent();
+        match status {
+            ExecutionStatus::Pending => {
+                self.ring.arc.set(1.0);
+                self.root.add_child(&self.ring);
+            }
+            ExecutionStatus::Running => {
+                self.ring.arc.set(RUNNING_ARC);
+                self.root.add_child(&self.ring);
+            }
+            ExecutionStatus::Cached | ExecutionStatus::Failed => self.root.add_child(&self.dot),
+            ExecutionStatus::Completed => {}
+        }
+    }
+
+    /// Rotate the arc according to the given frame time, in milliseconds.
+    fn spin(&self, time:f32) {
+        let turns = time / 1000.0 * SPIN_SPEED;
+        self.ring.set_rotation_z(-turns * 2.0 * std::f32::consts::PI);
+    }
+}
+
+
+
+// =======================
+// === StatusIndicator ===
+// =======================
+
+/// A `display::Object` providing the indicator of the node execution status. It is placed
+/// centered on its origin.
+#[derive(Clone,CloneRef,Debug)]
+pub struct StatusIndicator {
+    model : Model,
+    frp   : Frp,
+}
+
+impl Deref for StatusIndicator {
+    type Target = Frp;
+
+    fn deref(&self) -> &Self::Target {
+        &self.frp
+    }
+}
+
+impl StatusIndicator {
+    /// Constructor.
+    pub fn new(app:&Application) -> Self {
+        let scene   = app.display.scene();
+        let model   = Model::new();
+        let frp     = Frp::new();
+        let network = &frp.network;
+        // FIXME : StyleWatch is unsuitable here, as it was designed as an internal tool for shape
+        // system (#795)
+        let style   = StyleWatch::new(&scene.style_sheet);
+
+        frp::extend! { network
+            eval frp.set_status ([model,style](status) model.set_status(*status,&style));
+            running   <- frp.set_status.map(|status| *status == ExecutionStatus::Running);
+            spin_time <- scene.frp.frame_time.gate(&running);
+            eval spin_time ((time) model.spin(*time));
+        }
+
+        Self {model,frp}
+    }
+}
+
+impl display::Object for StatusIndicator {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.model.root
+    }
+}
@@ -16,6 +16,7 @@ use ensogl::gui::cursor;
 use ensogl_text as text;
 use ensogl_text::buffer::data::unit::traits::*;
 use ensogl_theme as theme;
+use span_tree::literal::NumericLiteral;
 use text::Text;
 
 use crate::Type;
@@ -51,6 +52,18 @@ const PORT_PADDING_X  : f32  = 4.0;
 /// Text size used for input area text.
 pub const TEXT_SIZE : f32 = 12.0;
 
+/// Character displayed instead of every byte of the masked ports' code. A single-byte character
+/// is used, so the byte offsets of the other ports, used to color the code, are kept intact.
+const MASK_CHAR : char = '*';
+
+/// The horizontal mouse distance, in screen pixels, changing the dragged numeric literal by one
+/// step.
+const LITERAL_DRAG_STEP_PX : f32 = 8.0;
+
+/// The minimal time, in milliseconds, between the `literal_adjusted` events emitted during the
+/// literal drag, so the dataflow is not flooded with recomputations.
+const LITERAL_ADJUST_INTERVAL_MS : f32 = 100.0;
+
 
 
 // ================
@@ -174,6 +187,10 @@ ensogl::define_endpoints! {
         /// Enable or disable node editing.
         set_edit_mode (bool),
 
+        /// Select the byte range of the expression code, e.g. the placeholder to be filled by the
+        /// user. Has effect in the edit mode only.
+        select_code (text::Range<text::Bytes>),
+
         /// Set or unset hover over the node. Port area is unable to determine hover by itself, as
         /// the hover may sometimes happen on the node background and the area still needs to be
         /// notified about it, for example in order to display the right cursor style in edit ready
@@ -183,6 +200,10 @@ ensogl::define_endpoints! {
         /// Disable the node (aka "skip mode").
         set_disabled (bool),
 
+        /// Indicate that the displayed expression is only a preview, e.g. of the suggestion
+        /// hovered in the searcher. The preview is displayed in the disabled color.
+        set_preview (bool),
+
         /// Set the connection status of the port indicated by the breadcrumbs. The optional type
         /// is the type of the edge that was connected or disconnected if the edge was typed.
         set_connected (Crumbs,Option<Type>,bool),
@@ -199,20 +220,118 @@ ensogl::define_endpoints! {
 
         set_view_mode        (view::Mode),
         set_profiling_status (profiling::Status),
+
+        /// Highlight the port as if it was hovered, e.g. when hovering an edge connected to it.
+        set_port_highlight (Switch<Crumbs>),
+
+        /// Set the magnification of the hovered port. The hovered port is displayed bigger, and its
+        /// hover area grows, making it easier to hit. While the ports are active, for example when
+        /// an edge is dragged, the hover areas of all ports grow, so the dragged edge can be
+        /// connected from further away.
+        set_port_hover_scale (f32),
+
+        /// Recompute the colors of all ports, e.g. after the type coloring was changed.
+        refresh_type_colors (),
+
+        /// Mask the code of the port, for example because it contains credentials. Masked ports are
+        /// displayed as a row of `MASK_CHAR`. The mask is kept when a new expression is set.
+        set_port_masked (Crumbs,bool),
+
+        /// Reveal the code of the masked ports until the edit mode ends. Entering the edit mode is
+        /// not possible while there are unrevealed masked ports, and copying the code is disabled
+        /// while they are revealed.
+        reveal_masked_ports (),
+
+        /// Enable or disable the argument editing mode. While the mode is enabled and the node is
+        /// hovered, the ports are visible, pressing an expected argument port emits
+        /// `on_port_argument_add` instead of `on_port_press`, and the removable ports display a
+        /// remove button emitting `on_port_argument_remove`. Pressing a numeric literal port
+        /// starts dragging the literal, see `literal_adjusted`.
+        set_argument_editing (bool),
     }
 
     Output {
         pointer_style       (cursor::Style),
         width               (f32),
         expression          (Text),
+        /// Locations of all cursors in the expression. Emitted only in the edit mode.
+        cursor_locations    (Vec<text::Location>),
         editing             (bool),
         ports_visible       (bool),
+        /// Whether the argument editing mode is enabled and the node is hovered.
+        argument_editing    (bool),
         body_hover          (bool),
         on_port_press       (Crumbs),
+        /// The expected argument port was pressed in the argument editing mode.
+        on_port_argument_add    (Crumbs),
+        /// The remove button of the removable port was pressed in the argument editing mode.
+        on_port_argument_remove (Crumbs),
         on_port_hover       (Switch<Crumbs>),
         on_port_type_change (Crumbs,Option<Type>),
         on_background_press (),
         view_mode           (view::Mode),
+        /// Emitted when the masked ports were revealed. Meant for auditing.
+        masked_ports_revealed (),
+        /// Whether the code is displayed in the disabled color, because the node is disabled or
+        /// the displayed expression is a preview.
+        disabled_color_used   (bool),
+        /// Whether a numeric literal port is being dragged.
+        literal_dragged       (bool),
+        /// The expression code with the dragged numeric literal adjusted. Dragging the literal
+        /// horizontally changes it by one step per `LITERAL_DRAG_STEP_PX`. The step is the last
+        /// digit of the literal, ten times bigger while shift is pressed, and ten times smaller
+        /// while control is pressed, but never smaller than one for the integer literals. The
+        /// pointer is locked during the drag, so the drag is not limited by the screen edges.
+        /// Emitted at most every `LITERAL_ADJUST_INTERVAL_MS` during the drag, and once more when
+        /// the drag ends.
+        literal_adjusted      (String),
+    }
+}
+
+
+
+// ===================
+// === LiteralDrag ===
+// ===================
+
+/// The state of the numeric literal drag. The adjusted code is always computed from the code
+/// captured when the drag started, as the expression is replaced with every adjustment.
+#[derive(Clone,Debug)]
+struct LiteralDrag {
+    code           : String,
+    range          : std::ops::Range<usize>,
+    literal        : NumericLiteral,
+    /// The horizontal distance the pointer moved since the drag started.
+    offset         : f32,
+    adjusted_code  : String,
+    pending_code   : Option<String>,
+    last_emit_time : Option<f32>,
+}
+
+impl LiteralDrag {
+    fn move_by(&mut self, distance:f32) {
+        self.offset += distance;
+    }
+
+    fn adjust(&mut self, fine:bool, coarse:bool) {
+        let steps    = (self.offset / LITERAL_DRAG_STEP_PX) as i32;
+        let exponent = -(self.literal.decimals as i32) + coarse as i32 - fine as i32;
+        let literal  = self.literal.adjusted(steps,exponent).code();
+        let mut code = self.code.clone();
+        code.replace_range(self.range.clone(),&literal);
+        if code != self.adjusted_code {
+            self.adjusted_code = code.clone();
+            self.pending_code  = Some(code);
+        }
+    }
+
+    fn throttled_code(&mut self, time:f32) -> Option<String> {
+        let interval = LITERAL_ADJUST_INTERVAL_MS;
+        let elapsed  = self.last_emit_time.map_or(true,|last| time - last >= interval);
+        if elapsed && self.pending_code.is_some() {
+            self.last_emit_time = Some(time);
+            self.pending_code.take()
+        } else { None }
     }
 }
 
@@ -229,6 +348,9 @@ pub struct Model {
     id_crumbs_map  : RefCell<HashMap<ast::Id,Crumbs>>,
     styles         : StyleWatch,
     styles_frp     : StyleWatchFrp,
+    masked         : RefCell<HashSet<Crumbs>>,
+    revealed       : Cell<bool>,
+    literal_drag   : RefCell<Option<LiteralDrag>>,
 }
 
 impl Model {
@@ -244,11 +366,14 @@ impl Model {
         let expression     = default();
         let styles         = StyleWatch::new(&app.display.scene().style_sheet);
         let styles_frp     = StyleWatchFrp::new(&app.display.scene().style_sheet);
+        let masked         = default();
+        let revealed       = default();
+        let literal_drag   = default();
         display_object.add_child(&label);
         display_object.add_child(&ports);
         ports.add_child(&header);
         Self {logger,app,display_object,ports,header,label,expression,id_crumbs_map,styles
-             ,styles_frp}.init()
+             ,styles_frp,masked,revealed,literal_drag}.init()
     }
 
     fn init(self) -> Self {
@@ -289,12 +414,144 @@ impl Model {
         self.with_port_mut(&target.value,|t|t.set_hover(target.is_on()))
     }
 
+    /// Highlight the port. The highlight is independent of the hover, so hovering the port in and
+    /// out does not clear it.
+    fn set_port_highlight(&self, target:&Switch<Crumbs>) {
+        self.with_port_mut(&target.value,|t|t.set_highlighted(target.is_on()))
+    }
+
     /// Update expression type for the particular `ast::Id`.
     fn set_expression_usage_type(&self, crumbs:&Crumbs, tp:&Option<Type>) {
         if let Ok(port) = self.expression.borrow().span_tree.root_ref().get_descendant(crumbs) {
             port.set_usage_type(tp)
         }
     }
+
+    /// The code displayed when the expression is not edited, that is the `viz_code` with the code
+    /// of the unrevealed masked ports replaced with `MASK_CHAR`.
+    fn displayed_code(&self, expression:&Expression) -> String {
+        let masked = self.masked.borrow();
+        if self.revealed.get() || masked.is_empty() {
+            return expression.viz_code.clone()
+        }
+        let mut ranges = Vec::new();
+        expression.root_ref().dfs_with_layer_data((),|node,_| {
+            if masked.contains(&node.crumbs) {
+                let index = node.payload.index;
+                ranges.push(index..index + node.payload.length);
+            }
+        });
+        let mut code = String::with_capacity(expression.viz_code.len());
+        for (index,char) in expression.viz_code.char_indices() {
+            if ranges.iter().any(|range| range.contains(&index)) {
+                code.extend(std::iter::repeat(MASK_CHAR).take(char.len_utf8()));
+            } else {
+                code.push(char);
+            }
+        }
+        code
+    }
+
+    fn refresh_displayed_code(&self) {
+        let code = self.displayed_code(&self.expression.borrow());
+        self.label.set_content(code);
+    }
+
+    /// Check whether editing the expression would reveal the code of masked ports.
+    fn has_unrevealed_masked_ports(&self) -> bool {
+        !self.revealed.get() && !self.masked.borrow().is_empty()
+    }
+
+    fn set_port_masked(&self, crumbs:&Crumbs, masked:bool) {
+        let changed = if masked {
+            self.masked.borrow_mut().insert(crumbs.clone())
+        } else {
+            self.masked.borrow_mut().remove(crumbs)
+        };
+        if changed && !self.revealed.get() {
+            self.refresh_displayed_code();
+        }
+    }
+
+    /// Reveal the masked ports. Returns `false` if there are no masked ports.
+    fn reveal_masked_ports(&self) -> bool {
+        let any_masked = !self.masked.borrow().is_empty();
+        if any_masked && !self.revealed.replace(true) {
+            self.label.disable_command("copy");
+            self.label.disable_command("cut");
+            self.refresh_displayed_code();
+        }
+        any_masked
+    }
+
+    fn hide_masked_ports(&self) {
+        if self.revealed.replace(false) {
+            self.label.enable_command("copy");
+            self.label.enable_command("cut");
+            self.refresh_displayed_code();
+        }
+    }
+
+    /// The byte range and the value of the numeric literal being the code of the port.
+    fn numeric_literal(&self, crumbs:&Crumbs) -> Option<(std::ops::Range<usize>,NumericLiteral)> {
+        let expression = self.expression.borrow();
+        let node       = expression.span_tree.root_ref().get_descendant(crumbs).ok()?;
+        if !node.children.is_empty() {
+            return None
+        }
+        let span    = node.span();
+        let range   = span.index.value..span.index.value + span.size.value;
+        let literal = NumericLiteral::parse(expression.code.get(range.clone())?)?;
+        Some((range,literal))
+    }
+
+    /// Start dragging the numeric literal port. Returns `false` if the port is not a numeric
+    /// literal.
+    fn start_literal_drag(&self, crumbs:&Crumbs) -> bool {
+        let drag = self.numeric_literal(crumbs).map(|(range,literal)| {
+            let code           = self.expression.borrow().code.clone();
+            let offset         = 0.0;
+            let adjusted_code  = code.clone();
+            let pending_code   = default();
+            let last_emit_time = default();
+            LiteralDrag {code,range,literal,offset,adjusted_code,pending_code,last_emit_time}
+        });
+        let started = drag.is_some();
+        *self.literal_drag.borrow_mut() = drag;
+        started
+    }
+
+    fn move_literal_drag(&self, distance:f32) {
+        if let Some(drag) = self.literal_drag.borrow_mut().as_mut() {
+            drag.move_by(distance)
+        }
+    }
+
+    fn drag_literal(&self, fine:bool, coarse:bool) {
+        if let Some(drag) = self.literal_drag.borrow_mut().as_mut() {
+            drag.adjust(fine,coarse)
+        }
+    }
+
+    fn throttled_literal_code(&self, time:f32) -> Option<String> {
+        self.literal_drag.borrow_mut().as_mut().and_then(|drag| drag.throttled_code(time))
+    }
+
+    /// Finish the literal drag, returning the adjusted code not emitted yet, if any.
+    fn end_literal_drag(&self) -> Option<String> {
+        self.literal_drag.borrow_mut().take().and_then(|drag| drag.pending_code)
+    }
+
+    /// Re-emit the types of all ports, so their colors get recomputed.
+    fn refresh_type_colors(&self) {
+        let mut signals_to_emit = Vec::new();
+        self.expression.borrow().root_ref().dfs_with_layer_data((),|node,_| {
+            signals_to_emit.push((node.frp.source.tp.clone_ref(),node.frp.tp.value()));
+        });
+        for (endpoint,tp) in signals_to_emit {
+            endpoint.emit(tp);
+        }
+    }
 }
 
 fn select_color(styles:&StyleWatch, tp:Option<&Type>) -> color::Lcha {
@@ -332,6 +589,9 @@ impl Area {
         let frp             = Frp::new();
         let network         = &frp.network;
         let selection_color = Animation::new(network);
+        let scene           = model.scene();
+        let mouse           = &scene.mouse.frp;
+        let keyboard        = &scene.keyboard.frp;
 
         frp::extend! { network
 
@@ -346,15 +606,48 @@ impl Area {
             // === Cursor setup ===
 
             eval frp.input.set_edit_mode ([model](edit_mode) {
+                let edit_mode = *edit_mode && !model.has_unrevealed_masked_ports();
                 model.label.set_focus(edit_mode);
-                if *edit_mode {
+                if edit_mode {
                     // Reset the code to hide non-connected port names.
                     model.label.set_content(model.expression.borrow().code.clone());
                     model.label.set_cursor_at_mouse_position();
                 } else {
                     model.label.remove_all_cursors();
+                    model.hide_masked_ports();
                 }
             });
+            code_to_select <- frp.input.select_code.gate(&frp.input.set_edit_mode);
+            eval code_to_select ((range) model.label.select_bytes(range));
+
+
+            // === Masked Ports ===
+
+            eval frp.set_port_masked (((crumbs,masked)) model.set_port_masked(crumbs,*masked));
+            revealed <- frp.reveal_masked_ports.map(f_!(model.reveal_masked_ports()));
+            frp.output.source.masked_ports_revealed <+ revealed.on_true();
+
+
+            // === Literal Drag ===
+
+            let literal_dragged = frp.output.literal_dragged.clone_ref();
+            let shift           = keyboard.is_shift_down.clone_ref();
+            let control         = keyboard.is_control_down.clone_ref();
+            // The pointer is locked, so only the movement is reported during the drag.
+            mouse.lock_pointer <+ literal_dragged.on_true();
+            drag_movement  <- mouse.movement.gate(&literal_dragged);
+            eval drag_movement ((movement) model.move_literal_drag(movement.x));
+            drag_move      <- any3_(&drag_movement,&shift,&control).gate(&literal_dragged);
+            eval_ drag_move ([model,shift,control]
+                model.drag_literal(control.value(),shift.value()));
+            throttled_code <- scene.frp.frame_time.gate(&literal_dragged).filter_map(
+                f!((time) model.throttled_literal_code(*time))
+            );
+            drag_end       <- mouse.up_primary.gate(&literal_dragged);
+            last_code      <- drag_end.filter_map(f_!(model.end_literal_drag()));
+            frp.output.source.literal_adjusted <+ any(&throttled_code,&last_code);
+            frp.output.source.literal_dragged  <+ drag_end.constant(false);
+            mouse.unlock_pointer <+ drag_end;
 
 
             // === Show / Hide Phantom Ports ===
@@ -367,7 +660,11 @@ impl Area {
                      (*edit_mode || *edit_ready_mode) && !set_ports_active
                 );
 
-            port_vis <- all_with(&frp.input.set_ports_active,&edit_mode,|(a,_),b|*a&&(!b));
+            argument_editing <- all_with(&frp.input.set_argument_editing,&frp.output.body_hover,
+                |editing,hover| *editing && *hover);
+            frp.output.source.argument_editing <+ argument_editing;
+            port_vis <- all_with3(&frp.input.set_ports_active,&argument_editing,&edit_mode,
+                |(active,_),editing_args,edit_mode| (*active || *editing_args) && !edit_mode);
             frp.output.source.ports_visible <+ port_vis;
             frp.output.source.editing       <+ edit_mode;
 
@@ -381,6 +678,8 @@ impl Area {
             // === Port Hover ===
 
             eval frp.on_port_hover ((t) model.set_port_hover(t));
+            eval frp.set_port_highlight ((t) model.set_port_highlight(t));
+            eval_ frp.refresh_type_colors (model.refresh_type_colors());
 
             eval frp.set_connected ([model]((crumbs,edge_tp,is_connected)) {
                 model.with_port_mut(crumbs,|n|n.set_connected(is_connected,edge_tp));
@@ -393,6 +692,10 @@ impl Area {
             width <- model.label.width.map(|t| t + 2.0 * TEXT_OFFSET);
             frp.output.source.width      <+ width;
             frp.output.source.expression <+ model.label.content;
+            disabled_color_used <- frp.input.set_disabled || frp.input.set_preview;
+            frp.output.source.disabled_color_used <+ disabled_color_used;
+            cursor_locations <- model.label.cursor_locations.gate(&frp.input.set_edit_mode);
+            frp.output.source.cursor_locations <+ cursor_locations;
 
 
             // === Expression Type ===
@@ -445,9 +748,27 @@ impl Area {
         expression.span_tree.root_ref().get_descendant(crumbs).ok().and_then(|t|t.tp.value())
     }
 
+    /// The name of the argument the port corresponds to, if known.
+    pub fn port_name(&self, crumbs:&Crumbs) -> Option<String> {
+        let expression = self.model.expression.borrow();
+        expression.span_tree.root_ref().get_descendant(crumbs).ok().and_then(|t|t.name().cloned())
+    }
+
     pub fn get_crumbs_by_id(&self, id:ast::Id) -> Option<Crumbs> {
         self.model.id_crumbs_map.borrow().get(&id).cloned()
     }
+
+    /// The crumbs of all the ports of the expression, in the order of their appearance in the
+    /// code. The skipped span tree nodes, like the parentheses, are not included.
+    pub fn port_crumbs(&self) -> Vec<Crumbs> {
+        let mut crumbs = Vec::new();
+        self.model.expression.borrow().root_ref().dfs(|node| {
+            if node.payload.shape.is_some() {
+                crumbs.push(node.crumbs.clone_ref());
+            }
+        });
+        crumbs
+    }
 }
 
 
@@ -509,7 +830,7 @@ impl PortLayerBuilder {
 
 impl Area {
     fn set_label_on_new_expression(&self, expression:&Expression) {
-        self.model.label.set_content(expression.viz_code.clone());
+        self.model.label.set_content(self.model.displayed_code(expression));
     }
 
     fn build_port_shapes_on_new_expression(&self, expression:&mut Expression) {
@@ -561,7 +882,11 @@ impl Area {
                 let size         = Vector2(width,height);
                 let logger       = &self.model.logger;
                 let scene        = self.model.scene();
-                let port_shape   = port.payload_mut().init_shape(logger,scene,size,node::HEIGHT);
+                let model        = self.model.clone_ref();
+                let removable    = port.kind.removable();
+                let is_expected  = port.is_expected_argument();
+                let port_shape   = port.payload_mut().init_shape
+                    (logger,scene,size,node::HEIGHT,removable);
 
                 port_shape.mod_position(|t| t.x = unit * index as f32);
                 if DEBUG { port_shape.mod_position(|t| t.y = DEBUG_PORT_OFFSET) }
@@ -581,6 +906,8 @@ impl Area {
                 let crumbs             = port.crumbs.clone_ref();
                 let port_network       = &port.network;
                 let frp                = &self.frp.output;
+                let hit_area_scale     = Animation::<f32>::new_with_init(port_network,1.0);
+                let visual_scale       = Animation::<f32>::new_with_init(port_network,1.0);
 
                 frp::extend! { port_network
 
@@ -618,7 +945,15 @@ impl Area {
 
                     // === Press ===
 
-                    eval_ mouse_down ([crumbs,frp] frp.source.on_port_press.emit(&crumbs));
+                    press     <- mouse_down.gate_not(&frp.argument_editing);
+                    arg_press <- mouse_down.gate(&frp.argument_editing);
+                    eval_ press ([crumbs,frp] frp.source.on_port_press.emit(&crumbs));
+                    add_press <- arg_press.filter(move |_| is_expected);
+                    eval_ add_press ([crumbs,frp] frp.source.on_port_argument_add.emit(&crumbs));
+                    literal_press <- arg_press.filter(move |_| !is_expected);
+                    drag_started  <- literal_press.map(f_!([model,crumbs]
+                        model.start_literal_drag(&crumbs)));
+                    frp.source.literal_dragged <+ drag_started.on_true();
 
 
                     // === Hover ===
@@ -628,6 +963,19 @@ impl Area {
                     frp.source.on_port_hover <+ hover;
 
 
+                    // === Hover Magnification ===
+
+                    ports_active   <- frp.set_ports_active.map(|(active,_)| *active);
+                    hit_area_grown <- hovered || ports_active;
+                    hit_area_scale.target <+ all_with(&hit_area_grown,&frp.set_port_hover_scale,
+                        |&grown,&scale| if grown { scale } else { 1.0 });
+                    visual_scale.target <+ all_with(&hovered,&frp.set_port_hover_scale,
+                        |&hovered,&scale| if hovered { scale } else { 1.0 });
+                    magnification <- all(hit_area_scale.value,visual_scale.value);
+                    eval magnification ([port_shape]((hit_area,visual))
+                        port_shape.set_magnification(*hit_area,*visual));
+
+
                     // === Pointer Style ===
 
                     let port_shape_hover = port_shape.hover.clone_ref();
@@ -644,9 +992,11 @@ impl Area {
                         |tp_color,any_type_sel_color| tp_color.unwrap_or(*any_type_sel_color));
                     in_profiling_mode  <- frp.view_mode.map(|m| matches!(m,view::Mode::Profiling));
                     pointer_color_over <- in_profiling_mode.switch(&tp_color,&any_type_sel_color);
-                    pointer_style_over <- pointer_color_over.map(move |color|
-                        cursor::Style::new_highlight(&port_shape_hover,padded_size,Some(color))
-                    );
+                    pointer_style_over <- all_with(&pointer_color_over,&visual_scale.value,
+                        move |color,&scale| {
+                            let size = padded_size * scale;
+                            cursor::Style::new_highlight(&port_shape_hover,size,Some(color))
+                        });
                     pointer_style_over <- pointer_style_over.sample(&mouse_over);
 
                     pointer_style_hover <- any(pointer_style_over,pointer_style_out);
@@ -654,6 +1004,20 @@ impl Area {
                     pointer_style       <- pointer_styles.fold();
                     self.frp.output.source.pointer_style <+ pointer_style;
                 }
+
+                if let Some(button) = &port_shape.remove_button {
+                    frp::extend! { port_network
+
+                        // === Remove Button ===
+
+                        eval frp.argument_editing ([port_shape](editing)
+                            port_shape.set_remove_button_visibility(*editing));
+                        self.frp.output.source.body_hover <+
+                            bool(&button.events.mouse_out,&button.events.mouse_over);
+                        eval_ button.events.mouse_down ([crumbs,frp]
+                            frp.source.on_port_argument_remove.emit(&crumbs));
+                    }
+                }
                 init_color.emit(());
                 frp.source.view_mode.emit(frp.view_mode.value());
                 port_shape.display_object().clone_ref()
@@ -663,6 +1027,7 @@ impl Area {
                 frp::extend! { port_network
                     node.frp.set_active           <+ parent_frp.set_active;
                     node.frp.set_hover            <+ parent_frp.set_hover;
+                    node.frp.set_highlighted      <+ parent_frp.set_highlighted;
                     node.frp.set_parent_connected <+ parent_frp.set_parent_connected;
                 }
             }
@@ -732,7 +1097,8 @@ impl Area {
                     in_profiling_mode <- self.view_mode.map(|m| m.is_profiling());
                     finished          <- self.set_profiling_status.map(|s| s.is_finished());
                     profiled          <- in_profiling_mode && finished;
-                    selected          <- frp.set_hover || frp.set_parent_connected;
+                    hovered           <- frp.set_hover || frp.set_highlighted;
+                    selected          <- hovered || frp.set_parent_connected;
 
                     init_colors         <- source::<()>();
                     std_base_color      <- all(std_base_color,init_colors)._0();
@@ -748,7 +1114,8 @@ impl Area {
                     editing_color  <- profiled.switch(&std_editing_color,&profiled_editing_color);
                     // Fixme: `label_color` should be animated, when when we can set text colors
                     //        more efficiently. (See https://github.com/enso-org/ide/issues/1031)
-                    label_color    <- all_with8(&self.set_edit_mode,&selected,&self.frp.set_disabled
+                    let disabled   = &self.frp.disabled_color_used;
+                    label_color    <- all_with8(&self.set_edit_mode,&selected,disabled
                         ,&editing_color,&selected_color,&disabled_color,&expected_color,&base_color
                         ,move |&editing,&selected,&disabled,&editing_color,&selected_color
                         ,&disabled_color,&expected_color,&base_color| {
@@ -798,6 +1165,9 @@ impl Area {
                     is_connected        <- frp.set_connected.map(|(is_connected,_)| *is_connected);
                     transparent         <- init_color.constant(color::Lcha::transparent());
                     viz_color_target    <- is_connected.switch(&transparent,&connected_viz_color);
+                    unknown_type        <- all_with(&frp.tp,&frp.set_connected,
+                        |port_tp,(_,edge_tp)| port_tp.is_none() && edge_tp.is_none());
+                    eval unknown_type ((t) port_shape.viz.set_hatched(*t));
 
                     // We need to make sure that the network contains correct values before we
                     // connect the `viz_color` animation. The reason is that the animation will
@@ -8,6 +8,8 @@ use ensogl::display;
 use crate::Type;
 use crate::node::input::area;
 
+use ensogl_theme::graph_editor::node::port::remove_button as theme;
+
 
 
 // =================
@@ -18,6 +20,12 @@ use crate::node::input::area;
 /// boundary on both sides.
 pub const PADDING_X : f32  = 4.0;
 
+/// The size of the remove button displayed above the removable ports in the argument editing mode.
+pub const REMOVE_BUTTON_SIZE : f32 = 10.0;
+
+const HATCH_PERIOD : f32 = 6.0;
+const HATCH_GAP    : f32 = 3.0;
+
 
 
 // ===================
@@ -55,13 +63,53 @@ pub mod viz {
     use super::*;
     ensogl::define_shape_system! {
         above = [hover];
-        (style:Style, color:Vector4) {
-            let width  : Var<Pixels> = "input_size.x".into();
-            let height : Var<Pixels> = "input_size.y".into();
-            let shape  = Rect((&width,&height)).corners_radius(&height / 2.0);
+        (style:Style, color:Vector4, hatch_cut:f32) {
+            let width       : Var<Pixels> = "input_size.x".into();
+            let height      : Var<Pixels> = "input_size.y".into();
+            let shape       = Rect((&width,&height)).corners_radius(&height / 2.0);
+            // The stripes overlap each other, unless they are narrowed by `hatch_cut`.
+            let stripe_size = (HATCH_PERIOD * 2.0).px();
+            let stripe      = Rect((stripe_size.clone(),stripe_size - 1.px() * hatch_cut));
+            let stripes     = stripe.repeat((HATCH_PERIOD.px(),HATCH_PERIOD.px()));
+            let stripes     = stripes.rotate(45.0_f32.to_radians().radians());
+            let shape       = shape * stripes;
             shape.fill("srgba(input_color)").into()
         }
     }
+
+    impl View {
+        /// Draw the shape hatched, marking the port of unknown type, or solid otherwise.
+        pub fn set_hatched(&self, hatched:bool) {
+            self.hatch_cut.set(if hatched { HATCH_PERIOD + HATCH_GAP } else { 0.0 });
+        }
+    }
+}
+
+
+
+// ===========================
+// === Remove Button Shape ===
+// ===========================
+
+/// The `✕` button displayed above the removable ports in the argument editing mode.
+pub mod remove_button {
+    use super::*;
+    ensogl::define_shape_system! {
+        above = [hover,viz];
+        (style:Style) {
+            let width       : Var<Pixels> = "input_size.x".into();
+            let height      : Var<Pixels> = "input_size.y".into();
+            let background  = Circle(&width / 2.0);
+            let background  = background.fill(style.get_color(theme::background));
+            let right_angle = 90.0_f32.to_radians().radians();
+            let arm         = Rect((&width * 0.5,1.5.px()));
+            let arm         = arm.rotate(right_angle / 2.0);
+            let cross       = &arm + arm.rotate(right_angle);
+            let cross       = cross.fill(style.get_color(theme::cross));
+            let hit_area    = Rect((&width,&height)).fill(HOVER_COLOR);
+            (hit_area + background + cross).into()
+        }
+    }
 }
 
 
@@ -82,18 +130,32 @@ pub mod viz {
 /// │   │╰──────╯│▲ (appears after mouse_hover)
 /// ╰───┴────────┴──┄
 /// ```
+///
+/// Removable ports have also the `remove_button` placed above the right end of the port. It is not
+/// a child of the `root` until the button is shown.
 #[derive(Clone,CloneRef,Debug)]
 pub struct Shape {
-    pub root  : display::object::Instance,
-    pub hover : hover::View,
-    pub viz   : viz::View,
+    pub root          : display::object::Instance,
+    pub hover         : hover::View,
+    pub viz           : viz::View,
+    pub remove_button : Option<remove_button::View>,
 }
 
 impl Shape {
-    pub fn new(logger:&Logger, scene:&Scene, size:Vector2, hover_height:f32) -> Self {
-        let root  = display::object::Instance::new(logger);
-        let hover = hover::View::new(logger);
-        let viz   = viz::View::new(logger);
+    pub fn new
+    (logger:&Logger, scene:&Scene, size:Vector2, hover_height:f32, removable:bool) -> Self {
+        let root          = display::object::Instance::new(logger);
+        let hover         = hover::View::new(logger);
+        let viz           = viz::View::new(logger);
+        let remove_button = removable.as_some_from(|| {
+            let button = remove_button::View::new(logger);
+            button.size.set(Vector2::new(REMOVE_BUTTON_SIZE,REMOVE_BUTTON_SIZE));
+            button.mod_position(|t| {
+                t.x = size.x + PADDING_X;
+                t.y = size.y / 2.0;
+            });
+            button
+        });
 
         let width_padded = size.x + 2.0 * PADDING_X;
         hover.size.set(Vector2::new(width_padded,hover_height));
@@ -108,7 +170,22 @@ impl Shape {
             (scene,PhantomData::<viz::DynamicShape>);
         viz_shape_system.shape_system.set_pointer_events(false);
 
-        Self {root,hover,viz}
+        Self {root,hover,viz,remove_button}
+    }
+
+    /// Show or hide the remove button. Does nothing if the port is not removable.
+    pub fn set_remove_button_visibility(&self, visible:bool) {
+        if let Some(button) = &self.remove_button {
+            if visible { self.root.add_child(button) } else { button.unset_parent() }
+        }
+    }
+
+    /// Magnify the port around its center. The `hit_area` scale is applied to the height of the
+    /// hover shape only, so the hover shapes of the neighbouring ports never overlap. The `visual`
+    /// scale is applied to the `viz` shape in both dimensions.
+    pub fn set_magnification(&self, hit_area:f32, visual:f32) {
+        self.hover.set_scale_xy(Vector2(1.0,hit_area));
+        self.viz.set_scale_xy(Vector2(visual,visual));
     }
 }
 
@@ -130,6 +207,7 @@ ensogl::define_endpoints! {
         set_disabled         (bool),
         set_active           (bool),
         set_hover            (bool),
+        set_highlighted      (bool),
         set_connected        (bool,Option<Type>),
         set_parent_connected (bool),
         set_definition_type  (Option<Type>),
@@ -170,11 +248,19 @@ impl Model {
     /// as some are skipped. For example, given the expression `(((foo)))`, the inner parentheses
     /// will be skipped, as there is no point in making them ports. The skip algorithm is
     /// implemented as part of the port are initialization.
+    ///
+    /// The remove button is created for `removable` ports only.
     pub fn init_shape
-    (&mut self, logger:impl AnyLogger, scene:&Scene, size:Vector2, hover_height:f32) -> Shape {
+    ( &mut self
+    , logger       : impl AnyLogger
+    , scene        : &Scene
+    , size         : Vector2
+    , hover_height : f32
+    , removable    : bool
+    ) -> Shape {
         let logger_name = format!("port({},{})",self.index,self.length);
         let logger      = Logger::new_sub(logger,logger_name);
-        let shape       = Shape::new(&logger,scene,size,hover_height);
+        let shape       = Shape::new(&logger,scene,size,hover_height,removable);
         self.shape      = Some(shape);
         self.shape.as_ref().unwrap().clone_ref()
     }
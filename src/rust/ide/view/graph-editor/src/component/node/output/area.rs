@@ -131,6 +131,18 @@ ensogl::define_endpoints! {
         /// `set_expression` instead. In case the usage type is set to None, ports still may be
         /// colored if the definition type was present.
         set_expression_usage_type (Crumbs,Option<Type>),
+
+        /// Highlight the port as if it was hovered, e.g. when hovering an edge connected to it.
+        set_port_highlight (Switch<Crumbs>),
+
+        /// Set the magnification of the hovered port. Both the port and its hover area grow.
+        set_port_hover_scale (f32),
+
+        /// Recompute the colors of all ports, e.g. after the type coloring was changed.
+        refresh_type_colors (),
+
+        /// Blend the colors of all ports with the color override of the node.
+        set_color_override (Option<color::Lcha>),
     }
 
     Output {
@@ -143,6 +155,7 @@ ensogl::define_endpoints! {
         expression_label_visibility (bool),
         tooltip                     (tooltip::Style),
         view_mode                   (view::Mode),
+        color_override              (Option<color::Lcha>),
     }
 }
 
@@ -217,6 +230,28 @@ impl Model {
         }
     }
 
+    fn set_port_highlight(&self, target:&Switch<Crumbs>) {
+        let expression = self.expression.borrow();
+        if let Ok(port) = expression.span_tree.root_ref().get_descendant(&target.value) {
+            if let Some(frp) = &port.frp {
+                frp.set_highlighted(target.is_on())
+            }
+        }
+    }
+
+    /// Re-emit the types of all ports, so their colors get recomputed.
+    fn refresh_type_colors(&self) {
+        let mut signals_to_emit = Vec::new();
+        self.traverse_borrowed_expression(|_,node,_| {
+            if let Some(port_frp) = &node.payload.frp {
+                signals_to_emit.push((port_frp.source.tp.clone_ref(),port_frp.tp.value()));
+            }
+        });
+        for (endpoint,tp) in signals_to_emit {
+            endpoint.emit(tp);
+        }
+    }
+
     /// Traverse all span tree nodes that are considered ports. In case of empty span tree, include
     /// its root as the port as well.
     fn traverse_borrowed_expression_mut
@@ -321,10 +356,14 @@ impl Model {
                     port_frp.set_type_label_visibility  <+ self.frp.type_label_visibility;
                     self.frp.source.tooltip             <+ port_frp.tooltip;
                     port_frp.set_view_mode              <+ self.frp.view_mode;
+                    port_frp.set_hover_scale            <+ self.frp.set_port_hover_scale;
+                    port_frp.set_color_override         <+ self.frp.color_override;
                 }
 
                 port_frp.set_type_label_visibility.emit(self.frp.type_label_visibility.value());
+                port_frp.set_hover_scale.emit(self.frp.set_port_hover_scale.value());
                 port_frp.set_view_mode.emit(self.frp.view_mode.value());
+                port_frp.set_color_override.emit(self.frp.color_override.value());
                 self.ports.add_child(&port_shape);
                 port_index += 1;
             }
@@ -425,6 +464,8 @@ impl Area {
 
             eval frp.set_expression            ((a)     model.set_expression(a));
             eval frp.set_expression_usage_type (((a,b)) model.set_expression_usage_type(a,b));
+            eval frp.set_port_highlight        ((t)     model.set_port_highlight(t));
+            eval_ frp.refresh_type_colors      (model.refresh_type_colors());
 
 
             // === Label Color ===
@@ -448,6 +489,11 @@ impl Area {
             // === View Mode ===
 
             frp.source.view_mode <+ frp.set_view_mode;
+
+
+            // === Color Override ===
+
+            frp.source.color_override <+ frp.set_color_override;
         }
 
         label_color.target_alpha(0.0);
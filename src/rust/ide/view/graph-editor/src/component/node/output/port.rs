@@ -67,7 +67,8 @@ const SHOW_TYPE_AS_LABEL   : bool = true;
 /// ```
 ///
 /// The corners are rounded with the `radius = inner_radius + port_area_size`. The shape also
-/// contains an underlying hover area with a padding defined as `HOVER_AREA_PADDING`.
+/// contains an underlying hover area with the given padding, which is `HOVER_AREA_PADDING` unless
+/// the port is magnified.
 struct AllPortsShape {
     /// The radius of the node, not the outer port radius.
     inner_radius : Var<Pixels>,
@@ -82,12 +83,13 @@ impl AllPortsShape {
     ( canvas_width    : &Var<Pixels>
     , canvas_height   : &Var<Pixels>
     , size_multiplier : &Var<f32>
+    , hover_padding   : &Var<Pixels>
     ) -> Self {
 
         // === Generic Info ===
 
-        let inner_width  = canvas_width - HOVER_AREA_PADDING.px() * 2.0;
-        let inner_height = canvas_height - HOVER_AREA_PADDING.px() * 2.0;
+        let inner_width  = canvas_width - hover_padding * 2.0;
+        let inner_height = canvas_height - hover_padding * 2.0;
         let inner_radius = node::RADIUS.px();
         let top_mask     = BottomHalfPlane();
 
@@ -112,7 +114,7 @@ impl AllPortsShape {
 
         // === Hover Area ===
 
-        let hover_radius = &inner_radius + &HOVER_AREA_PADDING.px();
+        let hover_radius = &inner_radius + hover_padding;
         let hover        = Rect((canvas_width,canvas_height)).corners_radius(&hover_radius);
         let hover        = (hover - &top_mask).into();
 
@@ -171,10 +173,17 @@ pub mod single_port {
     use ensogl::display::shape::*;
 
     ensogl::define_shape_system! {
-        (style:Style, size_multiplier:f32, opacity:f32, color_rgb:Vector3<f32>) {
+        ( style           : Style
+        , size_multiplier : f32
+        , hover_padding   : f32
+        , opacity         : f32
+        , color_rgb       : Vector3<f32>
+        ) {
             let overall_width  = Var::<Pixels>::from("input_size.x");
             let overall_height = Var::<Pixels>::from("input_size.y");
-            let ports          = AllPortsShape::new(&overall_width,&overall_height,&size_multiplier);
+            let hover_padding  = Var::<Pixels>::from(hover_padding);
+            let ports          = AllPortsShape::new
+                (&overall_width,&overall_height,&size_multiplier,&hover_padding);
             let color          = Var::<color::Rgba>::from("srgba(input_color_rgb,input_opacity)");
             let shape          = ports.shape.fill(color);
             let hover          = ports.hover.fill(HOVER_COLOR);
@@ -289,6 +298,7 @@ pub mod multi_port {
     ensogl::define_shape_system! {
         ( style           : Style
         , size_multiplier : f32
+        , hover_padding   : f32
         , index           : f32
         , opacity         : f32
         , port_count      : f32
@@ -298,7 +308,9 @@ pub mod multi_port {
         ) {
             let overall_width  = Var::<Pixels>::from("input_size.x");
             let overall_height = Var::<Pixels>::from("input_size.y");
-            let ports          = AllPortsShape::new(&overall_width,&overall_height,&size_multiplier);
+            let hover_padding  = Var::<Pixels>::from(hover_padding);
+            let ports          = AllPortsShape::new
+                (&overall_width,&overall_height,&size_multiplier,&hover_padding);
 
             let inner_radius = Var::<f32>::from(ports.inner_radius);
             let inner_width  = Var::<f32>::from(ports.inner_width);
@@ -377,6 +389,7 @@ impl PortShapeView {
     fn_both! {
         set_size            (this,t:Vector2)     {this.size.set(t)}
         set_size_multiplier (this,t:f32)         {this.size_multiplier.set(t)}
+        set_hover_padding   (this,t:f32)         {this.hover_padding.set(t)}
         set_color           (this,t:color::Rgba) {this.color_rgb.set(t.opaque.into())}
         set_opacity         (this,t:f32)         {this.opacity.set(t)}
     }
@@ -419,6 +432,13 @@ ensogl::define_endpoints! {
         set_type_label_visibility (bool),
         set_size                  (Vector2),
         set_view_mode             (view::Mode),
+        /// Blend the color of the port with the color override of the node.
+        set_color_override        (Option<color::Lcha>),
+        /// Display the port as if it was hovered.
+        set_highlighted           (bool),
+        /// The magnification of the port while it is hovered. Both the port and its hover area
+        /// grow.
+        set_hover_scale           (f32),
     }
 
     Output {
@@ -498,6 +518,7 @@ impl Model {
         let port_count         = self.port_count;
         let port_index         = self.port_index;
         let full_type_timer    = DelayedAnimation::new(network);
+        let magnification      = Animation::<f32>::new_with_init(network,1.0);
         full_type_timer.set_delay(FULL_TYPE_ONSET_DELAY_MS);
         full_type_timer.set_duration(0.0);
 
@@ -513,14 +534,28 @@ impl Model {
 
             opacity.target <+ events.mouse_over.constant(PORT_OPACITY_HOVERED);
             opacity.target <+ events.mouse_out.constant(PORT_OPACITY_NOT_HOVERED);
+            opacity.target <+ frp.set_highlighted.map(|&highlighted| {
+                if highlighted { PORT_OPACITY_HOVERED } else { PORT_OPACITY_NOT_HOVERED }
+            });
             eval opacity.value ((t) shape.set_opacity(*t));
 
 
+            // === Hover Magnification ===
+
+            magnification.target <+ all_with(&frp.on_hover,&frp.set_hover_scale,
+                |&hovered,&scale| if hovered { scale } else { 1.0 });
+            init_magnification <- source::<()>();
+            hover_padding      <- all_with(&magnification.value,&init_magnification,
+                |scale,_| HOVER_AREA_PADDING * scale);
+            eval hover_padding ((t) shape.set_hover_padding(*t));
+
+
             // === Size ===
 
             frp.source.size <+ frp.set_size;
-            eval frp.size ((&s)
-                shape.set_size(s + Vector2(HOVER_AREA_PADDING,HOVER_AREA_PADDING) * 2.0));
+            canvas_size     <- all_with(&frp.size,&hover_padding,
+                |&size,&padding| size + Vector2(padding,padding) * 2.0);
+            eval canvas_size ((&s) shape.set_size(s));
             set_type_label_x <- all_with(&frp.size,&type_label.width,
                 f!([port_count,port_index](port_size,type_label_width) {
                     let shape_length   = shape_border_length(node::RADIUS, port_size.x);
@@ -532,7 +567,9 @@ impl Model {
                     label_center_x - type_label_width / 2.0
                 }));
             eval set_type_label_x ((&t) type_label.set_position_x(t));
-            eval frp.set_size_multiplier ((t) shape.set_size_multiplier(*t));
+            size_multiplier <- all_with(&frp.set_size_multiplier,&magnification.value,
+                |multiplier,scale| multiplier * scale);
+            eval size_multiplier ((t) shape.set_size_multiplier(*t));
 
 
             // === Type ===
@@ -541,8 +578,14 @@ impl Model {
                 |usage_tp,def_tp| usage_tp.clone().or_else(|| def_tp.clone())
             );
 
-            normal_color        <- frp.tp.map(f!([styles](t)
+            type_color          <- frp.tp.map(f!([styles](t)
                 type_coloring::compute_for_selection(t.as_ref(),&styles)));
+            let override_mix_path = ensogl_theme::graph_editor::node::color_override::port_mix;
+            let override_mix      = styles_frp.get_number(override_mix_path);
+            normal_color        <- all_with3(&type_color,&frp.set_color_override,&override_mix,
+                |&color,&color_override,&mix| {
+                    type_coloring::blend_override(color,color_override,mix)
+                });
             init_color          <- source::<()>();
             let profiling_color  = styles_frp.get_color(ensogl_theme::code::types::any::selection);
             profiling_color     <- all_with(&profiling_color,&init_color,|c,_|color::Lcha::from(c));
@@ -561,6 +604,7 @@ impl Model {
             });
         }
         init_color.emit(());
+        init_magnification.emit(());
 
         if SHOW_TYPE_AS_LABEL {
             frp::extend! { network
@@ -0,0 +1,118 @@
+//! The `PortTooltip` shows the argument name, type and documentation of the hovered input port.
+//! Unlike the `Tooltip` pegged to the cursor, it is anchored at the port position. A single
+//! instance is shared by all nodes of the graph editor.
+
+use crate::prelude::*;
+
+use crate::component::tooltip;
+use crate::component::tooltip::Placement;
+use crate::component::tooltip::Tooltip;
+use crate::Type;
+
+use enso_frp as frp;
+use ensogl::application::Application;
+use ensogl::display;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// Placement of the tooltip relative to the port.
+const PLACEMENT : Placement = Placement::Top;
+
+
+
+// ===============
+// === Content ===
+// ===============
+
+/// Information about an input port displayed in its tooltip.
+#[derive(Clone,Debug,Default,Eq,PartialEq)]
+#[allow(missing_docs)]
+pub struct Content {
+    pub name          : Option<String>,
+    pub tp            : Option<Type>,
+    pub documentation : Option<String>,
+}
+
+impl Content {
+    /// The text displayed in the tooltip: the argument name and type in the first line, followed
+    /// by the documentation. Returns `None` if there is nothing to display.
+    pub fn label(&self) -> Option<String> {
+        let signature = match (&self.name,&self.tp) {
+            (Some(name),Some(tp)) => Some(format!("{} : {}",name,tp)),
+            (Some(name),None)     => Some(name.clone()),
+            (None,Some(tp))       => Some(tp.to_string()),
+            (None,None)           => None,
+        };
+        let documentation = self.documentation.clone().filter(|doc| !doc.is_empty());
+        match (signature,documentation) {
+            (Some(signature),Some(doc)) => Some(format!("{}\n{}",signature,doc)),
+            (signature,doc)             => signature.or(doc),
+        }
+    }
+}
+
+
+
+// ===========
+// === Frp ===
+// ===========
+
+ensogl::define_endpoints! {
+    Input {
+        /// Show the tooltip with the given content, anchored at the given position in the scene.
+        show (Content,Vector2),
+        hide (),
+    }
+}
+
+
+
+// ===================
+// === PortTooltip ===
+// ===================
+
+/// Tooltip anchored above an input port. See the module docs to learn more.
+#[derive(Clone,CloneRef,Debug)]
+pub struct PortTooltip {
+    tooltip : Tooltip,
+    frp     : Frp,
+}
+
+impl Deref for PortTooltip {
+    type Target = Frp;
+    fn deref(&self) -> &Self::Target {
+        &self.frp
+    }
+}
+
+impl PortTooltip {
+    /// Constructor.
+    pub fn new(app:&Application) -> Self {
+        let tooltip = Tooltip::new(app);
+        let frp     = Frp::new();
+        let network = &frp.network;
+
+        frp::extend! { network
+            tooltip.frp.set_location <+ frp.show._1();
+            label                    <- frp.show.map(|(content,_)| content.label());
+            style                    <- label.map(|label| match label {
+                Some(label) => tooltip::Style::set_label(label.clone()).with_placement(PLACEMENT),
+                None        => tooltip::Style::unset_label(),
+            });
+            tooltip.frp.set_style    <+ style;
+            tooltip.frp.set_style    <+ frp.hide.constant(tooltip::Style::unset_label());
+        }
+
+        Self {tooltip,frp}
+    }
+}
+
+impl display::Object for PortTooltip {
+    fn display_object(&self) -> &display::object::Instance {
+        self.tooltip.display_object()
+    }
+}
@@ -7,6 +7,7 @@ use crate::Type;
 
 use ensogl::data::color;
 use ensogl::display::shape::StyleWatch;
+use ensogl::display::style;
 use ensogl::display::style::data::DataMatch;
 use ensogl_theme as theme;
 use std::collections::hash_map::DefaultHasher;
@@ -14,6 +15,25 @@ use std::hash::Hasher;
 
 
 
+// =================
+// === Constants ===
+// =================
+
+/// The palette used in the color-blind-safe mode. These are the colors of the Okabe-Ito palette,
+/// distinguishable under all common forms of color vision deficiency. Black is omitted, as it
+/// would not be visible on the dark theme.
+const COLOR_BLIND_SAFE_PALETTE : [(f32,f32,f32);7] =
+    [ (0.902,0.624,0.000)
+    , (0.337,0.706,0.914)
+    , (0.000,0.620,0.451)
+    , (0.941,0.894,0.259)
+    , (0.000,0.447,0.698)
+    , (0.835,0.369,0.000)
+    , (0.800,0.475,0.655)
+    ];
+
+
+
 // ================================
 // === Type to Color Conversion ===
 // ================================
@@ -32,6 +52,12 @@ use std::hash::Hasher;
 /// can define color overrides, like `code::types::String::hue = 0.3` to override the selected
 /// color hue.
 ///
+/// ## Custom Colors and Color-Blind-Safe Mode
+/// The colors registered with `set_custom_color` take precedence over all other rules. Otherwise,
+/// if the `code::types::color_blind_safe` style is set (see `set_color_blind_safe`), the color is
+/// picked from a small palette of colors distinguishable under color vision deficiencies, ignoring
+/// the theme overrides.
+///
 /// ## Future Development
 /// There are few important ideas regarding future development of this mechanism. First of all,
 /// user defining a new type should be able to mark it as a "container" type and point which of
@@ -42,6 +68,12 @@ use std::hash::Hasher;
 /// parametrization, other mechanisms should be used. For example, `Point Float` and `Point Number`
 /// should have similar colors, completely distinct from their parameter types.
 pub fn compute(tp:&Type, styles:&StyleWatch) -> color::Lcha {
+    if let Some(color) = styles.get(custom_color_path(tp)).color() {
+        return color.into()
+    }
+    if styles.get_number_or(theme::code::types::color_blind_safe,0.0) != 0.0 {
+        return color_blind_safe(tp)
+    }
     let types_path = theme::code::types::overriden::HERE.path();
     let type_path  = types_path.into_subs(tp.as_str().split('.'));
     let hue = styles.get(type_path.sub("hue")).number_or_else(|| auto_hue(tp,styles));
@@ -64,6 +96,24 @@ pub fn compute_for_selection(tp:Option<&Type>, styles:&StyleWatch) -> color::Lch
     opt_color.unwrap_or_else(|| styles.get_color(theme::code::types::any::selection).into())
 }
 
+/// Blend the color derived from the type with the color override of the node, see the
+/// `set_node_color_override` input of the graph editor. The `mix` coefficient is the weight of the
+/// override: `0.0` keeps the type color and `1.0` replaces it with the override.
+pub fn blend_override
+(color:color::Lcha, color_override:Option<color::Lcha>, mix:f32) -> color::Lcha {
+    match color_override {
+        Some(color_override) => color::mix(color,color_override.opaque.with_alpha(color.alpha),mix),
+        None                 => color,
+    }
+}
+
+/// Pick the color of the type from the color-blind-safe palette.
+fn color_blind_safe(tp:&Type) -> color::Lcha {
+    let index   = (hash(tp) % COLOR_BLIND_SAFE_PALETTE.len() as u64) as usize;
+    let (r,g,b) = COLOR_BLIND_SAFE_PALETTE[index];
+    color::Rgba::new(r,g,b,1.0).into()
+}
+
 /// Computes LCH hue value based on incoming type information.
 fn auto_hue(tp:&Type, styles:&StyleWatch) -> f32 {
     // Defines how many hue values we can have based on our incoming type name.
@@ -78,3 +128,51 @@ fn hash(s:&str) -> u64 {
     s.hash(&mut hasher);
     hasher.finish()
 }
+
+
+
+// =====================
+// === Configuration ===
+// =====================
+
+/// The style sheet path of the custom color of the type.
+fn custom_color_path(tp:&Type) -> style::Path {
+    let custom_path = theme::code::types::HERE.path().sub("custom");
+    custom_path.into_subs(tp.as_str().split('.')).sub("color")
+}
+
+/// Register a custom color of the type, overriding the color computed by `compute`. Passing `None`
+/// removes the custom color. The already displayed ports and edges are not re-colored
+/// automatically.
+pub fn set_custom_color(style_sheet:&style::Sheet, tp:&Type, color:Option<color::Lcha>) {
+    let value = color.map(|color| color::Rgba::from(color).into());
+    style_sheet.change(custom_color_path(tp),value);
+}
+
+/// Enable or disable the color-blind-safe palette of type colors. The already displayed ports and
+/// edges are not re-colored automatically.
+pub fn set_color_blind_safe(style_sheet:&style::Sheet, enabled:bool) {
+    let value = if enabled { 1.0 } else { 0.0 };
+    style_sheet.set(theme::code::types::color_blind_safe,value);
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blending_color_override() {
+        let color          = color::Lcha::new(0.5,0.2,0.1,0.8);
+        let color_override = color::Lcha::new(0.7,0.4,0.6,1.0);
+        assert_eq!(blend_override(color,None,0.5),color);
+        let blended = blend_override(color,Some(color_override),0.5);
+        assert_ne!(blended,color);
+        assert!((blended.alpha - color.alpha).abs() < 0.001);
+    }
+}
@@ -0,0 +1,320 @@
+//! Pinned visualization panels. A panel displays the visualization of a node, but is positioned on
+//! the canvas independently of it, so it stays in place when the node is moved around. The panel
+//! is dragged by the handle at its top edge and closed with its own button.
+//!
+//! The panels receive their data independently of the visualization of their node, so they keep
+//! working when the node's visualization is hidden. All the panels of a node share a single
+//! attachment, using the preprocessor of the most recently changed one.
+
+use crate::prelude::*;
+
+use crate::NodeId;
+use crate::component::visualization;
+use crate::component::visualization::instance::PreprocessorConfiguration;
+
+use enso_frp as frp;
+use ensogl::application::Application;
+use ensogl::display;
+use ensogl::display::Scene;
+use ensogl::display::shape::*;
+use ensogl_theme::graph_editor::vis_panel as theme;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The size of the close button, displayed at the top right corner of the panel.
+const CLOSE_BUTTON_SIZE : f32 = 14.0;
+
+/// The height of the handle dragging the panel, displayed along its top edge.
+const DRAG_HANDLE_HEIGHT : f32 = 10.0;
+
+/// The offset of a newly pinned panel from the center of its node's visualization.
+pub const PIN_OFFSET : (f32,f32) = (240.0,0.0);
+
+
+
+// ====================
+// === Close Button ===
+// ====================
+
+/// The button closing the panel.
+pub mod close_button {
+    use super::*;
+    ensogl::define_shape_system! {
+        (style:Style) {
+            let width       : Var<Pixels> = "input_size.x".into();
+            let background  = Circle(&width / 2.0);
+            let background  = background.fill(style.get_color(theme::close_button::background));
+            let right_angle = 90.0_f32.to_radians().radians();
+            let arm         = Rect((&width * 0.5,1.5.px()));
+            let arm         = arm.rotate(right_angle / 2.0);
+            let cross       = &arm + arm.rotate(right_angle);
+            let cross       = cross.fill(style.get_color(theme::close_button::cross));
+            (background + cross).into()
+        }
+    }
+}
+
+
+
+// ===================
+// === Drag Handle ===
+// ===================
+
+/// The handle dragging the panel.
+pub mod drag_handle {
+    use super::*;
+    ensogl::define_shape_system! {
+        (style:Style) {
+            let width  : Var<Pixels> = "input_size.x".into();
+            let height : Var<Pixels> = "input_size.y".into();
+            let handle = Rect((&width,&height)).corners_radius(&height / 2.0);
+            handle.fill(style.get_color(theme::drag_handle::background)).into()
+        }
+    }
+}
+
+
+
+// ===============
+// === PanelId ===
+// ===============
+
+/// The identifier of a pinned visualization panel, unique within the `Panels` collection.
+#[derive(Clone,CloneRef,Copy,Debug,Default,Eq,Hash,PartialEq)]
+pub struct PanelId(usize);
+
+
+
+// ==============
+// === Events ===
+// ==============
+
+/// The events of all the panels, reported by the `Panels` collection.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Events {
+    /// Emitted with the id of the panel whose close button was clicked. The panel is not removed
+    /// until `Panels::remove` is called.
+    pub close_requested      : frp::Source<PanelId>,
+    /// Emitted with the id of the panel whose drag handle was pressed.
+    pub drag_started         : frp::Source<PanelId>,
+    /// Emitted when the visualization of the panel changes the preprocessor of its data.
+    pub preprocessor_changed : frp::Source<(NodeId,PreprocessorConfiguration)>,
+}
+
+impl Events {
+    fn new(network:&frp::Network) -> Self {
+        frp::extend! { network
+            close_requested      <- source();
+            drag_started         <- source();
+            preprocessor_changed <- source();
+        }
+        Self {close_requested,drag_started,preprocessor_changed}
+    }
+}
+
+
+
+// =============
+// === Panel ===
+// =============
+
+/// A single pinned visualization panel.
+#[derive(Debug)]
+pub struct Panel {
+    display_object : display::object::Instance,
+    node_id        : NodeId,
+    /// The container displaying the visualization.
+    pub visualization : visualization::Container,
+    close_button   : close_button::View,
+    drag_handle    : drag_handle::View,
+    network        : frp::Network,
+}
+
+impl Panel {
+    /// Constructor. The panel displays the given visualization definition and reports its
+    /// interactions with the given `events`.
+    pub fn new
+    ( app        : &Application
+    , registry   : visualization::Registry
+    , id         : PanelId
+    , node_id    : NodeId
+    , definition : Option<visualization::Definition>
+    , events     : &Events
+    ) -> Self {
+        let logger         = Logger::new("VisPanel");
+        let display_object = display::object::Instance::new(&logger);
+        let visualization  = visualization::Container::new(&logger,app,registry);
+        let close_button   = close_button::View::new(&logger);
+        let drag_handle    = drag_handle::View::new(&logger);
+        close_button.size.set(Vector2(CLOSE_BUTTON_SIZE,CLOSE_BUTTON_SIZE));
+        display_object.add_child(&visualization);
+        display_object.add_child(&drag_handle);
+        display_object.add_child(&close_button);
+
+        let network = frp::Network::new("vis_panel");
+        frp::extend! { network
+            eval visualization.frp.size ([close_button,drag_handle](size) {
+                close_button.set_position_xy(size / 2.0);
+                drag_handle.size.set(Vector2(size.x,DRAG_HANDLE_HEIGHT));
+                drag_handle.set_position_y((size.y + DRAG_HANDLE_HEIGHT) / 2.0);
+            });
+            events.close_requested <+ close_button.events.mouse_down.constant(id);
+            events.drag_started    <+ drag_handle.events.mouse_down.constant(id);
+            events.preprocessor_changed <+ visualization.frp.preprocessor.map(move |preprocessor|
+                (node_id,preprocessor.clone()));
+        }
+        let (width,height) = visualization::container::DEFAULT_SIZE;
+        visualization.frp.set_size.emit(Vector2(width,height));
+        visualization.frp.set_visualization.emit(definition);
+        visualization.frp.set_visibility.emit(true);
+        Self {display_object,node_id,visualization,close_button,drag_handle,network}
+    }
+
+    /// The preprocessor of the data of the displayed visualization.
+    pub fn preprocessor(&self) -> PreprocessorConfiguration {
+        self.visualization.frp.preprocessor.value()
+    }
+
+    /// The node whose visualization is displayed.
+    pub fn node_id(&self) -> NodeId {
+        self.node_id
+    }
+}
+
+impl display::Object for Panel {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.display_object
+    }
+}
+
+
+
+// ==============
+// === Panels ===
+// ==============
+
+/// The collection of the pinned visualization panels of the graph editor.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Panels {
+    logger         : Logger,
+    display_object : display::object::Instance,
+    app            : Application,
+    registry       : visualization::Registry,
+    panels         : Rc<RefCell<HashMap<PanelId,Panel>>>,
+    next_id        : Rc<Cell<usize>>,
+    network        : frp::Network,
+    /// The events of all the panels.
+    pub events     : Events,
+    /// Emitted when the panel was dragged by the user to the new position.
+    pub moved      : frp::Stream<(PanelId,Vector2)>,
+}
+
+impl Panels {
+    /// Constructor.
+    pub fn new(logger:&Logger, app:&Application, registry:visualization::Registry) -> Self {
+        let logger         = Logger::sub(logger,"VisPanels");
+        let display_object = display::object::Instance::new(&logger);
+        let app            = app.clone_ref();
+        let panels         = default();
+        let next_id        = default();
+        let network        = frp::Network::new("vis_panels");
+        let events         = Events::new(&network);
+        let moved          = Self::init_dragging(&network,app.display.scene(),&events,&panels);
+        Self {logger,display_object,app,registry,panels,next_id,network,events,moved}
+    }
+
+    /// Move the dragged panel with the mouse. Returns the stream of the new panel positions.
+    fn init_dragging
+    ( network : &frp::Network
+    , scene   : &Scene
+    , events  : &Events
+    , panels  : &Rc<RefCell<HashMap<PanelId,Panel>>>
+    ) -> frp::Stream<(PanelId,Vector2)> {
+        let mouse  = &scene.mouse.frp;
+        let camera = scene.camera();
+        frp::extend! { network
+            dragged <- any_mut::<Option<PanelId>>();
+            dragged <+ events.drag_started.map(|id| Some(*id));
+            dragged <+ mouse.up_primary.constant(None);
+            mouse_pos_on_start <- mouse.position.sample(&events.drag_started);
+            panel_pos_on_start <- events.drag_started.map(f!([panels](id)
+                panels.borrow().get(id).map(|panel| panel.position().xy()).unwrap_or_default()));
+            dragged_id <- mouse.position.map2(&dragged,|_,id| *id).unwrap();
+            moved      <- dragged_id.map4(&mouse.position,&mouse_pos_on_start,&panel_pos_on_start,
+                move |id,pos,start,panel_start| (*id,panel_start + (pos - start) / camera.zoom()));
+            eval moved ([panels]((id,position)) {
+                if let Some(panel) = panels.borrow().get(id) { panel.set_position_xy(*position) }
+            });
+        }
+        moved
+    }
+
+    /// Add a new panel displaying the visualization of the node at the given position. Returns the
+    /// id of the new panel.
+    pub fn add
+    (&self, node_id:NodeId, definition:Option<visualization::Definition>, position:Vector2)
+    -> PanelId {
+        let id       = PanelId(self.next_id.get());
+        let registry = self.registry.clone_ref();
+        let panel    = Panel::new(&self.app,registry,id,node_id,definition,&self.events);
+        self.next_id.set(id.0 + 1);
+        panel.set_position_xy(position);
+        self.display_object.add_child(&panel);
+        self.panels.borrow_mut().insert(id,panel);
+        id
+    }
+
+    /// The preprocessor of the data of the panel.
+    pub fn preprocessor(&self, id:PanelId) -> Option<PreprocessorConfiguration> {
+        self.panels.borrow().get(&id).map(|panel| panel.preprocessor())
+    }
+
+    /// Remove the panel. Returns the node it displayed the visualization of, or `None` if there
+    /// was no such panel.
+    pub fn remove(&self, id:PanelId) -> Option<NodeId> {
+        let panel = self.panels.borrow_mut().remove(&id)?;
+        panel.unset_parent();
+        Some(panel.node_id())
+    }
+
+    /// Remove all the panels of the node. Returns the ids of the removed panels.
+    pub fn remove_node_panels(&self, node_id:NodeId) -> Vec<PanelId> {
+        let ids = self.node_panels(node_id);
+        for id in &ids { self.remove(*id); }
+        ids
+    }
+
+    /// The ids of the panels displaying the visualization of the node.
+    pub fn node_panels(&self, node_id:NodeId) -> Vec<PanelId> {
+        let panels = self.panels.borrow();
+        panels.iter().filter(|(_,panel)| panel.node_id == node_id).map(|(id,_)| *id).collect()
+    }
+
+    /// Move the panel to the given position.
+    pub fn set_position(&self, id:PanelId, position:Vector2) {
+        if let Some(panel) = self.panels.borrow().get(&id) {
+            panel.set_position_xy(position);
+        } else {
+            warning!(self.logger,"Cannot move the nonexistent visualization panel {id:?}.");
+        }
+    }
+
+    /// Pass the data of the node's panels attachment to all its panels.
+    pub fn set_data(&self, node_id:NodeId, data:&visualization::Data) {
+        let panels = self.panels.borrow();
+        let panels = panels.values().filter(|panel| panel.node_id == node_id);
+        for panel in panels {
+            panel.visualization.frp.set_data.emit(data);
+        }
+    }
+}
+
+impl display::Object for Panels {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.display_object
+    }
+}
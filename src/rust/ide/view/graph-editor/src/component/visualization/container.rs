@@ -11,6 +11,7 @@
 pub mod action_bar;
 pub mod visualization_chooser;
 pub mod fullscreen;
+pub mod watchdog;
 
 use crate::prelude::*;
 
@@ -19,6 +20,7 @@ use crate::visualization;
 use crate::component::visualization::instance::PreprocessorConfiguration;
 
 use action_bar::ActionBar;
+use watchdog::Watchdog;
 use enso_frp as frp;
 use ensogl::Animation;
 use ensogl::application::Application;
@@ -131,8 +133,16 @@ ensogl::define_endpoints! {
         set_size            (Vector2),
         enable_fullscreen   (),
         disable_fullscreen  (),
+        /// Set the part of the screen covered in the fullscreen mode.
+        set_fullscreen_pane (fullscreen::Pane),
+        /// Pass the zoom factor to the visualization. See `visualization::FrpInputs::set_zoom`.
+        set_zoom            (f32),
         set_vis_input_type  (Option<enso::Type>),
         set_layer           (visualization::Layer),
+        set_watchdog_limits (watchdog::Limits),
+        /// Pass the acknowledgement of the last preprocessor change to the visualization. See
+        /// `visualization::FrpInputs::preprocessor_acknowledged`.
+        acknowledge_preprocessor (bool),
     }
 
     Output {
@@ -141,7 +151,15 @@ ensogl::define_endpoints! {
         size           (Vector2),
         is_selected    (bool),
         visible        (bool),
-        vis_input_type (Option<enso::Type>)
+        vis_input_type (Option<enso::Type>),
+        /// Emitted when the watchdog throttles or suspends the current visualization instance.
+        misbehaving    (watchdog::Report),
+        /// The zoom factor set by the user in the visualization. See
+        /// `visualization::Frp::zoom_change`.
+        zoom           (f32),
+        /// The indices of the data items selected by the user in the visualization. See
+        /// `visualization::Frp::selection_change`.
+        selection      (Vec<usize>),
     }
 }
 
@@ -222,6 +240,22 @@ impl display::Object for View {
 
 
 
+// ======================
+// === AttachedLayout ===
+// ======================
+
+/// The layout of the container attached to its node, remembered while the visualization is
+/// displayed in fullscreen.
+#[derive(Clone,Copy,Debug,Default,PartialEq)]
+pub struct AttachedLayout {
+    /// The size of the container.
+    pub size        : Vector2,
+    /// The offset by which the user dragged the container from its place below the node.
+    pub drag_offset : Vector2,
+}
+
+
+
 // ======================
 // === ContainerModel ===
 // ======================
@@ -236,6 +270,9 @@ pub struct ContainerModel {
     /// container position is changed by dragging.
     drag_root          : display::object::Instance,
     visualization      : RefCell<Option<visualization::Instance>>,
+    /// The data format expected by the current `visualization`. The received data is converted
+    /// to this format before being passed to the visualization.
+    data_format        : Cell<visualization::data::Format>,
     /// A network containing connection between currently set `visualization` FRP endpoints and
     /// container FRP. We keep a separate network for that, so we can manage life of such
     /// connections reliably.
@@ -247,6 +284,7 @@ pub struct ContainerModel {
     registry           : visualization::Registry,
     size               : Rc<Cell<Vector2>>,
     action_bar         : ActionBar,
+    watchdog           : Watchdog,
 }
 
 impl ContainerModel {
@@ -259,6 +297,7 @@ impl ContainerModel {
         let display_object     = display::object::Instance::new(&logger);
         let drag_root          = display::object::Instance::new(&logger);
         let visualization      = default();
+        let data_format        = default();
         let vis_frp_connection = default();
         let view               = View::new(&logger,scene.clone_ref());
         let fullscreen_view    = fullscreen::Panel::new(&logger,scene);
@@ -266,10 +305,11 @@ impl ContainerModel {
         let is_fullscreen      = default();
         let size               = default();
         let action_bar         = ActionBar::new(app,registry.clone_ref());
+        let watchdog           = default();
         view.add_child(&action_bar);
 
-        Self {logger,display_object,drag_root,visualization,vis_frp_connection,scene,view
-            ,fullscreen_view,is_fullscreen,registry,size,action_bar}.init()
+        Self {logger,display_object,drag_root,visualization,data_format,vis_frp_connection,scene
+            ,view,fullscreen_view,is_fullscreen,registry,size,action_bar,watchdog}.init()
     }
 
     fn init(self) -> Self {
@@ -330,7 +370,14 @@ impl ContainerModel {
     }
 
     fn set_visualization
-    (&self, visualization:visualization::Instance, preprocessor:&frp::Any<PreprocessorConfiguration>) {
+    ( &self
+    , visualization : visualization::Instance
+    , data_format   : visualization::data::Format
+    , preprocessor  : &frp::Any<PreprocessorConfiguration>
+    , zoom          : &frp::Any<f32>
+    , selection     : &frp::Any<Vec<usize>>
+    ) {
+        self.data_format.set(data_format);
         let size = self.size.get();
         visualization.set_size.emit(size);
         frp::new_network! { vis_frp_connection
@@ -340,6 +387,10 @@ impl ContainerModel {
             // create any node in this network, so in fact it won't manage the connection.
             vis_preprocessor_change <- visualization.on_preprocessor_change.map(|x| x.clone());
             preprocessor            <+ vis_preprocessor_change;
+            vis_zoom_change         <- visualization.zoom_change.map(|x| *x);
+            zoom                    <+ vis_zoom_change;
+            vis_selection_change    <- visualization.selection_change.map(|x| x.clone());
+            selection               <+ vis_selection_change;
         }
         preprocessor.emit(visualization.on_preprocessor_change.value());
         if self.is_fullscreen.get() {
@@ -349,10 +400,35 @@ impl ContainerModel {
         }
         self.visualization.replace(Some(visualization));
         self.vis_frp_connection.replace(Some(vis_frp_connection));
+        self.watchdog.reset();
     }
 
-    fn set_visualization_data(&self, data:&visualization::Data) {
-        self.visualization.borrow().for_each_ref(|vis| vis.send_data.emit(data))
+    /// Pass the data to the visualization, unless the watchdog decided to hold it back. Returns the
+    /// watchdog report if the update made the instance be throttled or suspended.
+    fn set_visualization_data(&self, data:&visualization::Data) -> Option<watchdog::Report> {
+        let vis = self.visualization.borrow();
+        let vis = vis.as_ref()?;
+        let start_time = web::performance().now();
+        if !self.watchdog.accepts_update(start_time) {
+            return None
+        }
+        match data.convert(self.data_format.get()) {
+            Ok(data)   => vis.send_data.emit(data),
+            Err(error) => {
+                warning!(self.logger,"Cannot convert the visualization data: {error:?}.");
+                return None
+            }
+        }
+        let end_time       = web::performance().now();
+        let update_time    = end_time - start_time;
+        let dom_node_count = vis.root_dom().as_ref().map_or(0, |dom| {
+            dom.dom().get_elements_by_tag_name("*").length() as usize
+        });
+        let report = self.watchdog.register_update(end_time,update_time,dom_node_count);
+        if let Some(report) = &report {
+            warning!(self.logger,"Visualization misbehaves: {report:?}.");
+        }
+        report
     }
 
     fn update_shape_sizes(&self) {
@@ -484,7 +560,19 @@ impl Container {
         frp::extend! { network
             eval  frp.set_visibility    ((v) model.set_visibility(*v));
             eval_ frp.toggle_visibility (model.toggle_visibility());
-            eval  frp.set_data          ((t) model.set_visualization_data(t));
+            misbehaving <= frp.set_data.map(f!((t) model.set_visualization_data(t)));
+            frp.source.misbehaving <+ misbehaving;
+            eval frp.set_watchdog_limits ((limits) model.watchdog.set_limits(*limits));
+            eval frp.acknowledge_preprocessor ([model](accepted) {
+                if let Some(vis) = model.visualization.borrow().as_ref() {
+                    vis.preprocessor_acknowledged.emit(accepted)
+                }
+            });
+            eval frp.set_zoom ([model](zoom) {
+                if let Some(vis) = model.visualization.borrow().as_ref() {
+                    vis.set_zoom.emit(zoom)
+                }
+            });
             frp.source.size    <+ frp.set_size;
             frp.source.visible <+ frp.set_visibility;
             frp.source.visible <+ frp.toggle_visibility.map(f!((()) model.is_active()));
@@ -511,13 +599,16 @@ impl Container {
         frp::extend! { network
             new_vis_definition <- any(frp.set_visualization,vis_after_cycling);
             let preprocessor   =  &frp.source.preprocessor;
+            let zoom           =  &frp.source.zoom;
+            let vis_selection  =  &frp.source.selection;
             frp.source.visualisation <+ new_vis_definition.map(f!(
-                [model,action_bar,scene,logger,preprocessor](vis_definition) {
+                [model,action_bar,scene,logger,preprocessor,zoom,vis_selection](vis_definition) {
 
                 if let Some(definition) = vis_definition {
                     match definition.new_instance(&scene) {
                         Ok(vis)  => {
-                            model.set_visualization(vis,&preprocessor);
+                            let format = *definition.signature.input_format;
+                            model.set_visualization(vis,format,&preprocessor,&zoom,&vis_selection);
                             let path = Some(definition.signature.path.clone());
                             action_bar.set_selected_visualization.emit(path);
                         },
@@ -576,11 +667,12 @@ impl Container {
             fullscreen_weight          <- any(fullscreen_enabled_weight,fullscreen_disabled_weight);
             frp.source.size            <+ frp.set_size;
 
-            _eval <- fullscreen_weight.all_with3(&frp.size,scene_shape,
-                f!([model] (weight,viz_size,scene_size) {
+            _eval <- fullscreen_weight.all_with4(&frp.size,scene_shape,&frp.set_fullscreen_pane,
+                f!([model] (weight,viz_size,scene_size,pane) {
                     let weight_inv           = 1.0 - weight;
                     let scene_size : Vector2 = scene_size.into();
-                    let current_size         = viz_size * weight_inv + scene_size * *weight;
+                    let pane_size            = pane.size(scene_size);
+                    let current_size         = viz_size * weight_inv + pane_size * *weight;
                     model.set_corner_roundness(weight_inv);
                     model.set_size(current_size);
 
@@ -590,7 +682,8 @@ impl Container {
                     let pos = Vector4::new(pos.x,pos.y,pos.z,1.0);
                     let pos = m2 * (m1 * pos);
                     let pp = Vector3(pos.x,pos.y,pos.z);
-                    let current_pos = pp * weight_inv;
+                    let offset      = pane.offset(scene_size) * *weight;
+                    let current_pos = pp * weight_inv + Vector3(offset.x,offset.y,0.0);
                     model.fullscreen_view.set_position(current_pos);
             }));
         }
@@ -602,10 +695,15 @@ impl Container {
             selected_definition  <- action_bar.visualisation_selection.map(f!([registry](path)
                 path.as_ref().map(|path| registry.definition_from_path(path) ).flatten()
             ));
-            eval selected_definition([scene,model,logger,preprocessor](definition)  {
-                let vis = definition.as_ref().map(|d| d.new_instance(&scene));
+            eval selected_definition(
+                [scene,model,logger,preprocessor,zoom,vis_selection](definition) {
+                let vis    = definition.as_ref().map(|d| d.new_instance(&scene));
+                let format = definition.as_ref().map(|d| *d.signature.input_format);
                 match vis {
-                    Some(Ok(vis))  => model.set_visualization(vis,&preprocessor),
+                    Some(Ok(vis))  => {
+                        let format = format.unwrap_or_default();
+                        model.set_visualization(vis,format,&preprocessor,&zoom,&vis_selection)
+                    }
                     Some(Err(err)) => {
                         warning!(logger,"Failed to instantiate visualisation: {err:?}");
                     },
@@ -654,6 +752,20 @@ impl Container {
     pub fn fullscreen_visualization(&self) -> &fullscreen::Panel {
         &self.model.fullscreen_view
     }
+
+    /// The current layout of the container attached to its node.
+    pub fn attached_layout(&self) -> AttachedLayout {
+        let size        = self.frp.size.value();
+        let drag_offset = self.model.drag_root.position().xy();
+        AttachedLayout {size,drag_offset}
+    }
+
+    /// Restore the layout of the container attached to its node, as returned by
+    /// `attached_layout`.
+    pub fn restore_attached_layout(&self, layout:AttachedLayout) {
+        self.model.drag_root.set_position_xy(layout.drag_offset);
+        self.frp.set_size.emit(layout.size);
+    }
 }
 
 impl display::Object for Container {
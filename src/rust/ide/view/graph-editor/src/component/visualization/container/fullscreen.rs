@@ -44,6 +44,47 @@ pub mod background {
 
 
 
+// ============
+// === Pane ===
+// ============
+
+/// The horizontal gap between the panes of the split screen, in pixels.
+const PANE_GAP : f32 = 2.0;
+
+/// The part of the screen covered by the fullscreen visualization. The screen can be split into
+/// two halves, to compare two visualizations side by side.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+#[allow(missing_docs)]
+pub enum Pane {Whole,Left,Right}
+
+impl Default for Pane {
+    fn default() -> Self {
+        Self::Whole
+    }
+}
+
+impl Pane {
+    /// The size of the pane on the screen of the given size.
+    pub fn size(self, screen:Vector2) -> Vector2 {
+        match self {
+            Self::Whole => screen,
+            _           => Vector2((screen.x - PANE_GAP) / 2.0,screen.y),
+        }
+    }
+
+    /// The position of the pane center, relative to the center of the screen of the given size.
+    pub fn offset(self, screen:Vector2) -> Vector2 {
+        let half_offset = (screen.x + PANE_GAP) / 4.0;
+        match self {
+            Self::Whole => Vector2(0.0,0.0),
+            Self::Left  => Vector2(-half_offset,0.0),
+            Self::Right => Vector2(half_offset,0.0),
+        }
+    }
+}
+
+
+
 // ======================
 // === FullscreenView ===
 // ======================
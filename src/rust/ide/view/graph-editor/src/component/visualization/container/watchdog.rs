@@ -0,0 +1,266 @@
+//! Watchdog guarding the visualization container against misbehaving visualization instances.
+//!
+//! Third-party (e.g. JavaScript) visualizations run on the main thread and own their DOM subtree.
+//! A badly written one can block the IDE on every data update or leak DOM nodes. The watchdog
+//! measures the time of every update and the size of the instance's DOM subtree, and decides
+//! whether the instance should be throttled (receive data less often) or suspended (receive no
+//! data at all until a new visualization is set).
+
+use crate::prelude::*;
+
+
+
+// ==============
+// === Limits ===
+// ==============
+
+/// Thresholds used by the `Watchdog` to decide whether a visualization instance misbehaves.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct Limits {
+    /// Maximum time a single data update may take.
+    pub max_update_time_ms   : f64,
+    /// Maximum number of DOM nodes an instance may create.
+    pub max_dom_nodes        : usize,
+    /// Number of consecutive violations after which the instance gets throttled.
+    pub strikes_to_throttle  : usize,
+    /// Number of consecutive violations after which the instance gets suspended.
+    pub strikes_to_suspend   : usize,
+    /// Minimal time between two updates passed to a throttled instance.
+    pub throttle_interval_ms : f64,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_update_time_ms   : 50.0,
+            max_dom_nodes        : 10_000,
+            strikes_to_throttle  : 3,
+            strikes_to_suspend   : 10,
+            throttle_interval_ms : 1000.0,
+        }
+    }
+}
+
+
+
+// ==============
+// === Report ===
+// ==============
+
+/// The reason of raising a `Report`.
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+#[allow(missing_docs)]
+pub enum Violation {
+    SlowUpdate,
+    TooManyDomNodes,
+}
+
+/// The measures taken by the `Watchdog` against the instance.
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum Action {
+    /// The instance receives data at most once per `Limits::throttle_interval_ms`.
+    Throttled,
+    /// The instance receives no data until a new visualization is set.
+    Suspended,
+}
+
+/// Information about a misbehaving visualization instance.
+#[derive(Clone,Copy,Debug,PartialEq)]
+#[allow(missing_docs)]
+pub struct Report {
+    pub violation      : Violation,
+    pub action         : Action,
+    pub update_time_ms : f64,
+    pub dom_node_count : usize,
+}
+
+
+
+// =============
+// === State ===
+// =============
+
+/// State of the watched instance. The states are ordered from the best to the worst one.
+#[derive(Clone,Copy,Debug,PartialEq,Eq,PartialOrd,Ord)]
+#[allow(missing_docs)]
+pub enum State { Healthy, Throttled, Suspended }
+
+impl Default for State {
+    fn default() -> Self {
+        Self::Healthy
+    }
+}
+
+
+
+// ================
+// === Watchdog ===
+// ================
+
+/// Tracks the behaviour of a single visualization instance. It should be `reset` whenever the
+/// watched instance changes.
+#[derive(Debug,Default)]
+pub struct Watchdog {
+    limits         : Cell<Limits>,
+    state          : Cell<State>,
+    strikes        : Cell<usize>,
+    last_update_ms : Cell<Option<f64>>,
+}
+
+impl Watchdog {
+    /// Constructor.
+    pub fn new(limits:Limits) -> Self {
+        let limits = Cell::new(limits);
+        Self {limits,..default()}
+    }
+
+    /// Set new limits. The current state of the instance is kept.
+    pub fn set_limits(&self, limits:Limits) {
+        self.limits.set(limits)
+    }
+
+    /// Current state of the watched instance.
+    pub fn state(&self) -> State {
+        self.state.get()
+    }
+
+    /// Forget all the gathered information. To be called when a new instance is being watched.
+    pub fn reset(&self) {
+        self.state.set(State::Healthy);
+        self.strikes.set(0);
+        self.last_update_ms.set(None);
+    }
+
+    /// Check whether an update at the time `now_ms` should be passed to the instance.
+    pub fn accepts_update(&self, now_ms:f64) -> bool {
+        match self.state.get() {
+            State::Healthy   => true,
+            State::Suspended => false,
+            State::Throttled => {
+                let interval = self.limits.get().throttle_interval_ms;
+                self.last_update_ms.get().map_or(true, |last| now_ms - last >= interval)
+            }
+        }
+    }
+
+    /// Register a finished update of the instance. Returns a `Report` if the instance state got
+    /// worse because of this update. The state never gets better until the watchdog is `reset`.
+    pub fn register_update
+    (&self, now_ms:f64, update_time_ms:f64, dom_node_count:usize) -> Option<Report> {
+        let limits = self.limits.get();
+        self.last_update_ms.set(Some(now_ms));
+        let violation = if dom_node_count > limits.max_dom_nodes {
+            Some(Violation::TooManyDomNodes)
+        } else if update_time_ms > limits.max_update_time_ms {
+            Some(Violation::SlowUpdate)
+        } else {
+            None
+        };
+        match violation {
+            None => {
+                self.strikes.set(0);
+                None
+            }
+            Some(violation) => {
+                let strikes = self.strikes.get() + 1;
+                self.strikes.set(strikes);
+                let strikes_state = if strikes >= limits.strikes_to_suspend {
+                    State::Suspended
+                } else if strikes >= limits.strikes_to_throttle {
+                    State::Throttled
+                } else {
+                    State::Healthy
+                };
+                let new_state = self.state.get().max(strikes_state);
+                let action = match new_state {
+                    State::Healthy   => None,
+                    State::Throttled => Some(Action::Throttled),
+                    State::Suspended => Some(Action::Suspended),
+                };
+                let changed = new_state != self.state.get();
+                self.state.set(new_state);
+                action.filter(|_| changed).map(|action|
+                    Report {violation,action,update_time_ms,dom_node_count}
+                )
+            }
+        }
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits() -> Limits {
+        Limits {
+            max_update_time_ms   : 10.0,
+            max_dom_nodes        : 100,
+            strikes_to_throttle  : 2,
+            strikes_to_suspend   : 4,
+            throttle_interval_ms : 100.0,
+        }
+    }
+
+    #[test]
+    fn healthy_instance_is_not_reported() {
+        let watchdog = Watchdog::new(limits());
+        for i in 0..10 {
+            assert_eq!(watchdog.register_update(i as f64,1.0,10),None);
+        }
+        assert_eq!(watchdog.state(),State::Healthy);
+    }
+
+    #[test]
+    fn slow_instance_gets_throttled_and_suspended() {
+        let watchdog = Watchdog::new(limits());
+        assert_eq!(watchdog.register_update(0.0,20.0,10),None);
+        let report = watchdog.register_update(1.0,20.0,10).unwrap();
+        assert_eq!(report.violation,Violation::SlowUpdate);
+        assert_eq!(report.action,Action::Throttled);
+        assert!(!watchdog.accepts_update(50.0));
+        assert!(watchdog.accepts_update(101.0));
+        assert_eq!(watchdog.register_update(101.0,20.0,10),None);
+        let report = watchdog.register_update(201.0,20.0,10).unwrap();
+        assert_eq!(report.action,Action::Suspended);
+        assert!(!watchdog.accepts_update(1000.0));
+        watchdog.reset();
+        assert!(watchdog.accepts_update(1000.0));
+    }
+
+    #[test]
+    fn good_update_resets_strikes() {
+        let watchdog = Watchdog::new(limits());
+        assert_eq!(watchdog.register_update(0.0,1.0,200),None);
+        assert_eq!(watchdog.register_update(1.0,1.0,10),None);
+        assert_eq!(watchdog.register_update(2.0,1.0,200),None);
+        assert_eq!(watchdog.state(),State::Healthy);
+    }
+
+    #[test]
+    fn state_is_never_lowered_by_new_strikes() {
+        let watchdog = Watchdog::new(limits());
+        for i in 0..4 {
+            watchdog.register_update(i as f64 * 100.0,20.0,10);
+        }
+        assert_eq!(watchdog.state(),State::Suspended);
+        watchdog.register_update(500.0,1.0,10);
+        assert_eq!(watchdog.register_update(600.0,20.0,10),None);
+        assert_eq!(watchdog.state(),State::Suspended);
+        assert_eq!(watchdog.register_update(700.0,20.0,10),None);
+        assert_eq!(watchdog.state(),State::Suspended);
+
+        watchdog.reset();
+        watchdog.register_update(0.0,20.0,10);
+        watchdog.register_update(100.0,20.0,10);
+        assert_eq!(watchdog.state(),State::Throttled);
+        watchdog.register_update(200.0,1.0,10);
+        assert_eq!(watchdog.register_update(300.0,20.0,10),None);
+        assert_eq!(watchdog.state(),State::Throttled);
+    }
+}
@@ -30,6 +30,34 @@ impl From<serde_json::Value> for Json {
     }
 }
 
+
+
+// ==============
+// === Binary ===
+// ==============
+
+/// Binary data with a fast clone operation. Used for transmitting the visualization data received
+/// through the binary protocol via FRP networks, without copying it.
+#[derive(Clone,CloneRef,Debug,Default)]
+pub struct Binary {
+    rc : Rc<Vec<u8>>
+}
+
+impl Deref for Binary {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        &self.rc
+    }
+}
+
+impl From<Vec<u8>> for Binary {
+    fn from(t:Vec<u8>) -> Self {
+        let rc = Rc::new(t);
+        Self {rc}
+    }
+}
+
+
 // ===================
 // === Data Format ===
 // ====================
@@ -77,8 +105,8 @@ impl Default for Format {
 #[derive(Clone,Debug)]
 #[allow(missing_docs)]
 pub enum Data {
-    Json { content : Json },
-    Binary, // TODO replace with actual binary data stream.
+    Json   { content : Json   },
+    Binary { content : Binary },
 }
 
 impl Default for Data {
@@ -95,6 +123,34 @@ impl From<serde_json::Value> for Data {
     }
 }
 
+impl From<Vec<u8>> for Data {
+    fn from(t:Vec<u8>) -> Self {
+        let content = t.into();
+        Self::Binary {content}
+    }
+}
+
+impl Data {
+    /// Convert the data to the format expected by a visualization. The binary data is decoded as
+    /// UTF-8 JSON text if the JSON is expected, and the JSON data is serialized if the binary data
+    /// is expected. The data already in the expected format is passed as is, so the payloads of
+    /// the binary visualizations received through the binary protocol are never parsed.
+    pub fn convert(&self, format:Format) -> Result<Self,DataError> {
+        match (self,format) {
+            (Self::Json {..},Format::Json) | (Self::Binary {..},Format::Binary) => Ok(self.clone()),
+            (Self::Binary {content},Format::Json) => {
+                let text = std::str::from_utf8(content).map_err(|_| DataError::InvalidJsonText)?;
+                let json = serde_json::from_str::<serde_json::Value>(text);
+                json.map(Self::from).map_err(|_| DataError::InvalidJsonText)
+            }
+            (Self::Json {content},Format::Binary) => {
+                let bytes = serde_json::to_vec(content.deref());
+                bytes.map(Self::from).map_err(|_| DataError::InvalidDataType)
+            }
+        }
+    }
+}
+
 
 
 // ==============
@@ -105,7 +161,7 @@ impl From<serde_json::Value> for Data {
 /// violates some other assumption of the visualization.
 #[derive(Copy,Clone,Debug)]
 pub enum DataError {
-    /// Visualization received a binary data package, which is currently not supported.
+    /// Visualization received a binary data package, while it supports only JSON.
     BinaryNotSupported,
     /// Indicates that that the provided data type does not match the expected data format.
     InvalidDataType,
@@ -170,3 +226,35 @@ and contains a value, or `None`, and does not.
         input.to_string()
     }
 }
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes(data:&Data) -> Option<Vec<u8>> {
+        match data {
+            Data::Binary {content} => Some(content.to_vec()),
+            Data::Json   {..}      => None,
+        }
+    }
+
+    #[test]
+    fn converting_data_formats() {
+        let binary = Data::from(b"[1,2]".to_vec());
+        assert_eq!(bytes(&binary.convert(Format::Binary).unwrap()),Some(b"[1,2]".to_vec()));
+        match binary.convert(Format::Json).unwrap() {
+            Data::Json {content} => assert_eq!(*content,serde_json::json!([1,2])),
+            Data::Binary {..}    => panic!("The data should be converted to JSON."),
+        }
+        let json = Data::from(serde_json::json!({"a":1}));
+        assert_eq!(bytes(&json.convert(Format::Binary).unwrap()),Some(br#"{"a":1}"#.to_vec()));
+        let invalid = Data::from(vec![0xff,0xfe]);
+        assert!(matches!(invalid.convert(Format::Json),Err(DataError::InvalidJsonText)));
+    }
+}
@@ -7,6 +7,7 @@ use crate::component::visualization::instance::PreprocessorConfiguration;
 use crate::component::type_coloring;
 use crate::Type;
 
+use enso_frp as frp;
 use ensogl::display::DomSymbol;
 use ensogl::display::shape::StyleWatch;
 use ensogl::data::color;
@@ -134,6 +135,10 @@ pub struct JsConsArgs {
     theme : JsTheme,
     #[wasm_bindgen(skip)]
     pub set_preprocessor : Box<dyn PreprocessorCallback>,
+    #[wasm_bindgen(skip)]
+    pub zoom_change      : frp::Source<f32>,
+    #[wasm_bindgen(skip)]
+    pub selection_change : frp::Source<Vec<usize>>,
 }
 
 impl Debug for JsConsArgs {
@@ -145,11 +150,16 @@ impl Debug for JsConsArgs {
 impl JsConsArgs {
     /// Constructor.
     pub fn new<F:'static+PreprocessorCallback>
-    (root:DomSymbol, styles:StyleWatch, closure:F) -> Self {
+    ( root             : DomSymbol
+    , styles           : StyleWatch
+    , closure          : F
+    , zoom_change      : frp::Source<f32>
+    , selection_change : frp::Source<Vec<usize>>
+    ) -> Self {
         let set_preprocessor = Box::new(closure);
         let theme = JsTheme {styles};
         let root = root.dom().clone();
-        JsConsArgs {root,theme,set_preprocessor}
+        JsConsArgs {root,theme,set_preprocessor,zoom_change,selection_change}
     }
 }
 
@@ -171,4 +181,14 @@ impl JsConsArgs {
         let preprocessor_config = PreprocessorConfiguration::from_options(code,module);
         (*closure)(preprocessor_config);
     }
+
+    /// Helper method to emit a zoom change event from the visualisation.
+    pub fn emit_zoom_change(&self, zoom:f32) {
+        self.zoom_change.emit(zoom);
+    }
+
+    /// Helper method to emit a selection change event from the visualisation.
+    pub fn emit_selection_change(&self, indices:Vec<u32>) {
+        self.selection_change.emit(indices.into_iter().map(|index| index as usize).collect_vec());
+    }
 }
@@ -41,6 +41,7 @@ pub mod field {
 pub mod method {
     pub const ON_DATA_RECEIVED : &str = "onDataReceived";
     pub const SET_SIZE         : &str = "setSize";
+    pub const SET_ZOOM         : &str = "setZoom";
 }
 
 
@@ -92,6 +92,7 @@ pub struct InstanceModel {
     pub logger              : Logger,
         on_data_received    : Rc<Option<js_sys::Function>>,
         set_size            : Rc<Option<js_sys::Function>>,
+        set_zoom            : Rc<Option<js_sys::Function>>,
         #[derivative(Debug="ignore")]
         object              : Rc<java_script::binding::Visualization>,
         #[derivative(Debug="ignore")]
@@ -154,22 +155,34 @@ impl InstanceModel {
         Ok(object)
     }
 
-    /// Tries to create a InstanceModel from the given visualisation class.
-    pub fn from_class(class:&JsValue,scene:&Scene) -> result::Result<Self, Error> {
+    /// Tries to create a InstanceModel from the given visualisation class. The `zoom_change` and
+    /// `selection_change` are emitted when the visualization reports it was zoomed by the user, or
+    /// the user selected some of the displayed data items, respectively.
+    pub fn from_class
+    ( class            : &JsValue
+    , scene            : &Scene
+    , zoom_change      : &frp::Source<f32>
+    , selection_change : &frp::Source<Vec<usize>>
+    ) -> result::Result<Self, Error> {
         let logger                        = Logger::new("Instance");
         let root_node                     = Self::create_root(scene,&logger)?;
         let (preprocessor_change,closure) = Self::preprocessor_change_callback();
         let styles                        = StyleWatch::new(&scene.style_sheet);
-        let init_data                     = JsConsArgs::new(root_node.clone_ref(), styles, closure);
+        let zoom_change                   = zoom_change.clone_ref();
+        let selection_change              = selection_change.clone_ref();
+        let init_data                     = JsConsArgs::new(root_node.clone_ref(), styles, closure,
+                                                            zoom_change, selection_change);
         let object                        = Self::instantiate_class_with_args(class,init_data)?;
         let on_data_received              = get_method(object.as_ref(),method::ON_DATA_RECEIVED).ok();
         let on_data_received              = Rc::new(on_data_received);
         let set_size                      = get_method(object.as_ref(),method::SET_SIZE).ok();
         let set_size                      = Rc::new(set_size);
+        let set_zoom                      = get_method(object.as_ref(),method::SET_ZOOM).ok();
+        let set_zoom                      = Rc::new(set_zoom);
         let object                        = Rc::new(object);
         let scene                         = scene.clone_ref();
-        Ok(InstanceModel{root_node,logger,on_data_received,set_size,object,preprocessor_change,
-                         scene})
+        Ok(InstanceModel{root_node,logger,on_data_received,set_size,set_zoom,object
+                        ,preprocessor_change,scene})
     }
 
     /// Hooks the root node into the given scene.
@@ -185,15 +198,21 @@ impl InstanceModel {
         self.root_node.set_size(size);
     }
 
+    fn set_zoom(&self, zoom:f32) {
+        let _ = self.try_call1(&self.set_zoom,&zoom.into());
+    }
+
     fn receive_data(&self, data:&Data) -> result::Result<(),DataError> {
-         let data_json = match data {
-            Data::Json {content} => content,
-            _                    => return Err(DataError::BinaryNotSupported),
-         };
-         let data_json:&serde_json::Value = data_json.deref();
-         let data_js   = match JsValue::from_serde(data_json) {
-             Ok(value) => value,
-             Err(_)    => return Err(DataError::InvalidDataType),
+         let data_js = match data {
+            Data::Json {content} => {
+                let data_json:&serde_json::Value = content.deref();
+                match JsValue::from_serde(data_json) {
+                    Ok(value) => value,
+                    Err(_)    => return Err(DataError::InvalidDataType),
+                }
+            }
+            // The visualizations declaring the binary input format receive an `Uint8Array`.
+            Data::Binary {content} => js_sys::Uint8Array::from(content.deref()).into(),
          };
          self.try_call1(&self.on_data_received, &data_js)
              .map_err(|_| DataError::InternalComputationError)?;
@@ -243,7 +262,8 @@ impl Instance {
     pub fn new(class:&JsValue, scene:&Scene) -> result::Result<Instance, Error>  {
         let network = frp::Network::new("js_visualization_instance");
         let frp     = visualization::instance::Frp::new(&network);
-        let model   = InstanceModel::from_class(class,scene)?;
+        let model   = InstanceModel::from_class
+            (class,scene,&frp.zoom_change,&frp.selection_change)?;
         model.set_dom_layer(&scene.dom.layers.back);
         Ok(Instance{model,frp,network}.init_frp(scene).init_preprocessor_change_callback())
     }
@@ -260,6 +280,7 @@ impl Instance {
                 }
             });
             eval frp.set_layer ((layer) model.set_layer(*layer));
+            eval frp.set_zoom  ((zoom) model.set_zoom(*zoom));
         }
         frp.pass_events_to_dom_if_active(scene,network);
         self
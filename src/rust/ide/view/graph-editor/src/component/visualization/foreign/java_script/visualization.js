@@ -127,6 +127,33 @@ export class Visualization {
             this.__emitPreprocessorChange__()
         }
     }
+
+    /**
+     * Notify IDE that the user zoomed the visualization.
+     *
+     * IDE uses it to synchronize the zoom of two visualizations of the same type compared side
+     * by side. The visualizations supporting it should also implement the `setZoom(zoom)` method,
+     * which is called with the zoom of the other visualization. It must not call this method, so
+     * the visualizations do not update each other endlessly.
+     *
+     * @param {number} zoom the new zoom factor of the visualization.
+     */
+    emitZoomChange(zoom) {
+        this.__api__.emit_zoom_change(zoom)
+    }
+
+    /**
+     * Notify IDE that the user selected some of the displayed data items, e.g. the points of
+     * a plot.
+     *
+     * IDE reports the selection of the node's visualization, so the computations depending on
+     * the visualized data may be restricted to the selected items.
+     *
+     * @param {number[]} indices the indices of the selected items in the received data.
+     */
+    emitSelectionChange(indices) {
+        this.__api__.emit_selection_change(Uint32Array.from(indices))
+    }
 }
 
 export function __Visualization__() {
@@ -86,6 +86,12 @@ impl PreprocessorConfiguration {
             code   : code.into(),
         }
     }
+
+    /// Check whether the configuration may be sent to the Engine. The preprocessor code must not be
+    /// blank.
+    pub fn is_valid(&self) -> bool {
+        !self.code.trim().is_empty()
+    }
 }
 
 impl Default for PreprocessorConfiguration {
@@ -114,11 +120,17 @@ impl Default for PreprocessorConfiguration {
 #[derive(Clone,CloneRef,Debug)]
 #[allow(missing_docs)]
 pub struct FrpInputs {
-    pub set_size   : frp::Source<Vector2>,
-    pub send_data  : frp::Source<Data>,
-    pub activate   : frp::Source,
-    pub deactivate : frp::Source,
-    pub set_layer  : frp::Source<Layer>
+    pub set_size                  : frp::Source<Vector2>,
+    pub send_data                 : frp::Source<Data>,
+    pub activate                  : frp::Source,
+    pub deactivate                : frp::Source,
+    pub set_layer                 : frp::Source<Layer>,
+    /// Acknowledgement of the last preprocessor change. `true` means the new preprocessor was
+    /// accepted and the data computed with it will arrive soon, `false` means it was rejected.
+    pub preprocessor_acknowledged : frp::Source<bool>,
+    /// Set the zoom factor of the visualization, if it supports zooming. Used to synchronize the
+    /// zoom of the visualizations compared side by side.
+    pub set_zoom                  : frp::Source<f32>,
 }
 
 /// Visualization FRP network.
@@ -131,6 +143,10 @@ pub struct Frp {
     pub on_preprocessor_change : frp::Sampler<PreprocessorConfiguration>,
     pub on_data_receive_error  : frp::Stream<Option<DataError>>,
     pub is_active              : frp::Stream<bool>,
+    /// Whether the preprocessor was changed and the visualization waits for the data computed with
+    /// it. The loading ends also when the change is rejected, as no new data will arrive then.
+    /// Visualizations may use it to display a loading state.
+    pub is_loading             : frp::Sampler<bool>,
 
     /// This event should be emitted when the received data are incorrect, or cause an internal
     /// error.
@@ -140,19 +156,27 @@ pub struct Frp {
     /// compression or filtering for the best performance. See also _Lazy Visualization_ section
     /// [here](http://dev.enso.org/docs/ide/product/visualizations.html).
     pub preprocessor_change   : frp::Source<PreprocessorConfiguration>,
+    /// This event should be emitted when the user zoomed the visualization, with the new zoom
+    /// factor. It must not be emitted in response to `set_zoom`.
+    pub zoom_change           : frp::Source<f32>,
+    /// This event should be emitted when the user selected a subset of the displayed data items
+    /// (e.g. the points of a plot), with the indices of the selected items.
+    pub selection_change      : frp::Source<Vec<usize>>,
 }
 
 impl FrpInputs {
     /// Constructor.
     pub fn new(network:&frp::Network) -> Self {
         frp::extend! { network
-            set_size   <- source();
-            send_data  <- source();
-            activate   <- source();
-            deactivate <- source();
-            set_layer  <- source();
+            set_size                  <- source();
+            send_data                 <- source();
+            activate                  <- source();
+            deactivate                <- source();
+            set_layer                 <- source();
+            preprocessor_acknowledged <- source();
+            set_zoom                  <- source();
         };
-        Self {set_size,send_data,activate,deactivate,set_layer}
+        Self {set_size,send_data,activate,deactivate,set_layer,preprocessor_acknowledged,set_zoom}
     }
 }
 
@@ -164,12 +188,21 @@ impl Frp {
             def preprocessor_change = source();
             on_preprocessor_change  <- preprocessor_change.sampler();
             def data_receive_error  = source();
+            def zoom_change         = source();
+            def selection_change    = source();
             is_active               <- bool(&inputs.deactivate,&inputs.activate);
         };
         preprocessor_change.emit(PreprocessorConfiguration::default());
+        // The default preprocessor is set before connecting the loading state, as it is not a
+        // change waiting for a response.
+        frp::extend! { network
+            change_rejected  <- inputs.preprocessor_acknowledged.on_false();
+            loading_finished <- any_(inputs.send_data,change_rejected);
+            is_loading       <- bool(&loading_finished,&preprocessor_change).sampler();
+        };
         let on_data_receive_error  = data_receive_error.clone_ref().into();
-        Self {inputs,on_preprocessor_change,on_data_receive_error,is_active,data_receive_error
-             ,preprocessor_change}
+        Self {inputs,on_preprocessor_change,on_data_receive_error,is_active,is_loading
+             ,data_receive_error,preprocessor_change,zoom_change,selection_change}
     }
 
     /// Extend the FRP network with mechanism of passing all mouse and keyboard event to DOM when
@@ -92,6 +92,8 @@ impl Registry {
     /// Add default visualizations to the registry.
     pub fn add_default_visualizations(&self) {
         self.add(builtin::visualization::native::RawText::definition());
+        self.add(builtin::visualization::native::Heatmap::definition());
+        self.add(builtin::visualization::native::Table::definition());
         self.try_add_java_script(builtin::visualization::java_script::scatter_plot_visualization());
         self.try_add_java_script(builtin::visualization::java_script::histogram_visualization());
         self.try_add_java_script(builtin::visualization::java_script::heatmap_visualization());
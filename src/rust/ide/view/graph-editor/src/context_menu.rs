@@ -0,0 +1,214 @@
+//! The context menu of the graph editor, displayed on a right click on a node, an edge, or the
+//! background. Each kind of target has its own built-in actions. The application can register
+//! additional entries with the `register_context_menu_entry` input. Choosing any entry is
+//! reported by the `context_menu_action` output.
+
+use crate::prelude::*;
+
+use crate::EdgeId;
+use crate::NodeId;
+
+use ensogl::application::Application;
+use ensogl::display;
+use ensogl_gui_components::context_menu as menu;
+use ensogl_gui_components::list_view;
+
+
+
+// ==============
+// === Target ===
+// ==============
+
+/// The kind of the element the context menu was opened for.
+#[derive(Clone,Copy,Debug,Eq,Hash,PartialEq)]
+#[allow(missing_docs)]
+pub enum TargetKind {
+    Node,
+    Edge,
+    Background,
+}
+
+impl Default for TargetKind {
+    fn default() -> Self {
+        Self::Background
+    }
+}
+
+/// The element the context menu was opened for.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum Target {
+    /// The node under the cursor.
+    Node(NodeId),
+    /// The edge under the cursor.
+    Edge(EdgeId),
+    /// The background at the given scene position.
+    Background(Vector2),
+}
+
+impl Default for Target {
+    fn default() -> Self {
+        Self::Background(default())
+    }
+}
+
+impl Target {
+    /// The target under the cursor. The hovered node takes precedence over the hovered edge.
+    pub fn new(node:Option<NodeId>, edge:Option<EdgeId>, position:Vector2) -> Self {
+        match (node,edge) {
+            (Some(node),_)    => Self::Node(node),
+            (None,Some(edge)) => Self::Edge(edge),
+            (None,None)       => Self::Background(position),
+        }
+    }
+
+    /// The kind of the target.
+    pub fn kind(&self) -> TargetKind {
+        match self {
+            Self::Node(_)       => TargetKind::Node,
+            Self::Edge(_)       => TargetKind::Edge,
+            Self::Background(_) => TargetKind::Background,
+        }
+    }
+}
+
+
+
+// ==============
+// === Action ===
+// ==============
+
+/// An entry of the context menu.
+#[derive(Clone,Debug,Eq,PartialEq)]
+pub enum Action {
+    /// Remove the node and its edges.
+    RemoveNode,
+    /// Toggle the freeze state of the node.
+    FreezeNode,
+    /// Toggle the skip state of the node.
+    SkipNode,
+    /// Toggle the visualization of the node.
+    VisualizeNode,
+    /// Pin the visualization of the node to the canvas, see `component::vis_panel`.
+    PinVisualization,
+    /// Collapse the selected nodes, or the node alone if it is not selected.
+    CollapseNodes,
+    /// Remove the edge.
+    DisconnectEdge,
+    /// Insert a new node in the middle of the edge, like the double-click on the edge does.
+    SplitEdge,
+    /// Add a new node at the clicked position.
+    AddNode,
+    /// Add a new node with the expression from the clipboard at the clicked position. Performed
+    /// by the application.
+    Paste,
+    /// Move the camera, so all the nodes are visible.
+    ZoomToFit,
+    /// The entry registered by the application, identified by its label. Performed by the
+    /// application.
+    Custom(ImString),
+}
+
+impl Default for Action {
+    fn default() -> Self {
+        Self::Custom(default())
+    }
+}
+
+impl Action {
+    /// The built-in actions available for the kind of target, in their display order.
+    pub fn builtin(kind:TargetKind) -> Vec<Action> {
+        match kind {
+            TargetKind::Node => vec![
+                Self::RemoveNode,Self::FreezeNode,Self::SkipNode,Self::VisualizeNode,
+                Self::PinVisualization,Self::CollapseNodes
+            ],
+            TargetKind::Edge       => vec![Self::DisconnectEdge,Self::SplitEdge],
+            TargetKind::Background => vec![Self::AddNode,Self::Paste,Self::ZoomToFit],
+        }
+    }
+
+    /// The label displayed in the menu.
+    pub fn label(&self) -> String {
+        match self {
+            Self::RemoveNode       => "Remove".into(),
+            Self::FreezeNode       => "Freeze".into(),
+            Self::SkipNode         => "Skip".into(),
+            Self::VisualizeNode    => "Visualize".into(),
+            Self::PinVisualization => "Pin Visualization".into(),
+            Self::CollapseNodes    => "Collapse".into(),
+            Self::DisconnectEdge   => "Disconnect".into(),
+            Self::SplitEdge        => "Split".into(),
+            Self::AddNode          => "Add Node".into(),
+            Self::Paste            => "Paste".into(),
+            Self::ZoomToFit        => "Zoom to Fit".into(),
+            Self::Custom(label)    => label.to_string(),
+        }
+    }
+}
+
+
+
+// ===================
+// === ContextMenu ===
+// ===================
+
+/// The context menu view together with the registered entries and the actions of the currently
+/// displayed menu.
+#[derive(Clone,CloneRef,Debug)]
+pub struct ContextMenu {
+    view   : menu::ContextMenu,
+    custom : Rc<RefCell<Vec<(TargetKind,ImString)>>>,
+    shown  : Rc<RefCell<Option<(Target,Vec<Action>)>>>,
+}
+
+impl Deref for ContextMenu {
+    type Target = menu::Frp;
+    fn deref(&self) -> &Self::Target {
+        &self.view.frp
+    }
+}
+
+impl ContextMenu {
+    /// Constructor. The menu is displayed in the panel layers, so it is not affected by the
+    /// camera.
+    pub fn new(app:&Application) -> Self {
+        let scene = app.display.scene();
+        let view  = menu::ContextMenu::new(app);
+        scene.layers.panel.add_exclusive(&view);
+        view.set_label_layer(scene.layers.panel_text.id());
+        let custom = default();
+        let shown  = default();
+        Self {view,custom,shown}
+    }
+
+    /// Add the entry to the menus of the given kind of target, after the built-in ones.
+    pub fn register(&self, kind:TargetKind, label:ImString) {
+        self.custom.borrow_mut().push((kind,label));
+    }
+
+    /// Display the menu for the target, with its top left corner at the given screen position.
+    pub fn show(&self, target:Target, screen_position:Vector2) {
+        let kind    = target.kind();
+        let custom  = self.custom.borrow();
+        let custom  = custom.iter().filter(|(k,_)| *k == kind);
+        let custom  = custom.map(|(_,label)| Action::Custom(label.clone()));
+        let actions = Action::builtin(kind).into_iter().chain(custom).collect_vec();
+        let labels  = actions.iter().map(|action| action.label()).collect_vec();
+        self.view.set_entries(list_view::entry::AnyModelProvider::new(labels));
+        self.view.show_at(screen_position);
+        *self.shown.borrow_mut() = Some((target,actions));
+    }
+
+    /// The target and action of the chosen entry of the displayed menu.
+    pub fn action(&self, entry:list_view::entry::Id) -> Option<(Target,Action)> {
+        let shown            = self.shown.borrow();
+        let (target,actions) = shown.as_ref()?;
+        Some((*target,actions.get(entry)?.clone()))
+    }
+}
+
+impl display::Object for ContextMenu {
+    fn display_object(&self) -> &display::object::Instance {
+        self.view.display_object()
+    }
+}
@@ -0,0 +1,418 @@
+//! Viewport culling of large graphs. The nodes lying far outside of the screen have their
+//! expensive parts, like the ports, labels, and visualization containers, hidden, and the edges
+//! connecting only such nodes are hidden entirely. The hidden parts are detached from the scene,
+//! so they are not drawn, but they are not freed: they keep their state and are attached back as
+//! soon as the node gets close to the screen again. The culling is refreshed whenever the camera
+//! moves or the graph changes. Both the nodes and the edges are indexed spatially, so the cost of
+//! the refresh depends on the number of elements around the screen, not on the size of the graph.
+
+use crate::prelude::*;
+
+use crate::EdgeId;
+use crate::NodeId;
+use crate::declutter::CELL_SIZE;
+use crate::declutter::SpatialIndex;
+use crate::declutter::clip_segment;
+use crate::selection::BoundingBox;
+
+use ensogl::display::Scene;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// Margin added around the screen, in scene units. The nodes lying in the margin are fully
+/// displayed, so they are ready before they appear on the screen while panning.
+const CULLING_MARGIN : f32 = 600.0;
+
+
+
+// ============
+// === Area ===
+// ============
+
+/// The area around the screen, in scene coordinates, in which the nodes are fully displayed.
+pub fn area(scene:&Scene) -> BoundingBox {
+    let screen      = scene.camera().screen();
+    let corner      = Vector3(screen.width,screen.height,0.0) / 2.0;
+    let bottom_left = scene.screen_to_scene_coordinates(-corner).xy();
+    let top_right   = scene.screen_to_scene_coordinates(corner).xy();
+    let mut area    = BoundingBox::from_corners(bottom_left,top_right);
+    area.grow_x(2.0 * CULLING_MARGIN);
+    area.grow_y(2.0 * CULLING_MARGIN);
+    area
+}
+
+
+
+// ===============
+// === Changes ===
+// ===============
+
+/// The elements whose culling state has changed.
+#[derive(Clone,Debug)]
+pub struct Changes<T> {
+    /// The elements which should be culled.
+    pub culled   : Vec<T>,
+    /// The elements which should be displayed again.
+    pub restored : Vec<T>,
+}
+
+impl<T:Copy+Eq+Hash> Changes<T> {
+    /// Replace the set of the displayed elements, returning the elements whose state has changed.
+    fn replace(shown:&mut HashSet<T>, visible:HashSet<T>) -> Self {
+        let culled   = shown.difference(&visible).copied().collect();
+        let restored = visible.difference(shown).copied().collect();
+        *shown = visible;
+        Changes {culled,restored}
+    }
+}
+
+
+
+// =================
+// === EdgeIndex ===
+// =================
+
+/// An edge stored in the `EdgeIndex`.
+#[derive(Clone,Debug)]
+struct IndexedEdge {
+    source : NodeId,
+    target : NodeId,
+    start  : Vector2,
+    end    : Vector2,
+    cells  : Vec<(i32,i32)>,
+}
+
+/// Index of the edges connecting two nodes, allowing for efficient queries of the edges crossing
+/// a given area. Every edge is approximated by the segment between its endpoints, and assigned to
+/// all the cells of the `SpatialIndex` grid the segment passes through.
+#[derive(Clone,Debug,Default)]
+pub struct EdgeIndex {
+    cells      : HashMap<(i32,i32),HashSet<EdgeId>>,
+    edges      : HashMap<EdgeId,IndexedEdge>,
+    node_edges : HashMap<NodeId,HashSet<EdgeId>>,
+}
+
+impl EdgeIndex {
+    /// Insert the edge or update its endpoints.
+    pub fn insert(&mut self, edge_id:EdgeId, source:(NodeId,Vector2), target:(NodeId,Vector2)) {
+        let ((source,start),(target,end)) = (source,target);
+        let unchanged = self.edges.get(&edge_id).map_or(false,|edge| {
+            edge.source == source && edge.target == target && edge.start == start && edge.end == end
+        });
+        if !unchanged {
+            self.remove(edge_id);
+            let cells = cells_on_segment(start,end);
+            for cell in &cells {
+                self.cells.entry(*cell).or_default().insert(edge_id);
+            }
+            self.node_edges.entry(source).or_default().insert(edge_id);
+            self.node_edges.entry(target).or_default().insert(edge_id);
+            self.edges.insert(edge_id,IndexedEdge {source,target,start,end,cells});
+        }
+    }
+
+    /// Remove the edge from the index.
+    pub fn remove(&mut self, edge_id:EdgeId) {
+        if let Some(edge) = self.edges.remove(&edge_id) {
+            for cell in edge.cells {
+                remove_from_set(&mut self.cells,cell,edge_id);
+            }
+            remove_from_set(&mut self.node_edges,edge.source,edge_id);
+            remove_from_set(&mut self.node_edges,edge.target,edge_id);
+        }
+    }
+
+    /// Check whether the edge is in the index.
+    pub fn contains(&self, edge_id:EdgeId) -> bool {
+        self.edges.contains_key(&edge_id)
+    }
+
+    /// All the edges in the index.
+    pub fn edges(&self) -> impl Iterator<Item=EdgeId> + '_ {
+        self.edges.keys().copied()
+    }
+
+    /// The edges connected to the node.
+    pub fn node_edges(&self, node_id:NodeId) -> impl Iterator<Item=EdgeId> + '_ {
+        self.node_edges.get(&node_id).into_iter().flat_map(|edges| edges.iter().copied())
+    }
+
+    /// The edges whose segments cross the given area.
+    pub fn edges_crossing(&self, area:&BoundingBox) -> HashSet<EdgeId> {
+        let (left,bottom) = SpatialIndex::cell_of(Vector2(area.left(),area.bottom()));
+        let (right,top)   = SpatialIndex::cell_of(Vector2(area.right(),area.top()));
+        let columns       = (right - left + 1) as usize;
+        let rows          = (top - bottom + 1) as usize;
+        let in_area       = |(x,y):&(i32,i32)| {
+            (left..=right).contains(x) && (bottom..=top).contains(y)
+        };
+        let candidates : HashSet<EdgeId> = if columns.saturating_mul(rows) > self.cells.len() {
+            let cells = self.cells.iter().filter(|(cell,_)| in_area(cell));
+            cells.flat_map(|(_,edges)| edges.iter().copied()).collect()
+        } else {
+            let cells = (left..=right).cartesian_product(bottom..=top);
+            let cells = cells.filter_map(|cell| self.cells.get(&cell));
+            cells.flat_map(|edges| edges.iter().copied()).collect()
+        };
+        candidates.into_iter().filter(|edge_id| {
+            let edge = self.edges.get(edge_id);
+            edge.map_or(false,|edge| clip_segment(area,edge.start,edge.end).is_some())
+        }).collect()
+    }
+}
+
+fn remove_from_set<K:Eq+Hash,T:Eq+Hash>(map:&mut HashMap<K,HashSet<T>>, key:K, item:T) {
+    if let Some(items) = map.get_mut(&key) {
+        items.remove(&item);
+        if items.is_empty() {
+            map.remove(&key);
+        }
+    }
+}
+
+/// The cells of the `SpatialIndex` grid the segment passes through. The segment is traversed
+/// column by column, so only the crossed cells are visited, even for long diagonal segments.
+fn cells_on_segment(start:Vector2, end:Vector2) -> Vec<(i32,i32)> {
+    let (start,end)   = if start.x <= end.x { (start,end) } else { (end,start) };
+    let (first,_)     = SpatialIndex::cell_of(start);
+    let (last,_)      = SpatialIndex::cell_of(end);
+    let width         = end.x - start.x;
+    let y_at          = |x:f32| {
+        if width > 0.0 { start.y + (end.y - start.y) * (x - start.x) / width } else { end.y }
+    };
+    (first..=last).flat_map(|column| {
+        let left       = (column as f32 * CELL_SIZE).max(start.x);
+        let right      = ((column + 1) as f32 * CELL_SIZE).min(end.x);
+        let (y1,y2)    = if width > 0.0 { (y_at(left),y_at(right)) } else { (start.y,end.y) };
+        let (_,bottom) = SpatialIndex::cell_of(Vector2(left,y1.min(y2)));
+        let (_,top)    = SpatialIndex::cell_of(Vector2(left,y1.max(y2)));
+        (bottom..=top).map(move |row| (column,row))
+    }).collect()
+}
+
+
+
+// ===============
+// === Culling ===
+// ===============
+
+/// The state of the viewport culling: the spatial indexes of the nodes and edges, and the sets of
+/// the fully displayed ones.
+#[derive(Clone,CloneRef,Debug,Default)]
+pub struct Culling {
+    nodes       : Rc<RefCell<SpatialIndex>>,
+    edges       : Rc<RefCell<EdgeIndex>>,
+    shown_nodes : Rc<RefCell<HashSet<NodeId>>>,
+    shown_edges : Rc<RefCell<HashSet<EdgeId>>>,
+}
+
+impl Culling {
+    /// Register the newly created node. The new nodes are fully displayed until the next
+    /// `update_nodes` call.
+    pub fn add_node(&self, node_id:NodeId, position:Vector2) {
+        self.shown_nodes.borrow_mut().insert(node_id);
+        self.set_node_position(node_id,position);
+    }
+
+    /// Update the position of the node in the index.
+    pub fn set_node_position(&self, node_id:NodeId, position:Vector2) {
+        self.nodes.borrow_mut().insert(node_id,position);
+    }
+
+    /// Forget the removed node.
+    pub fn remove_node(&self, node_id:NodeId) {
+        self.nodes.borrow_mut().remove(node_id);
+        self.shown_nodes.borrow_mut().remove(&node_id);
+    }
+
+    /// Register the edge connecting two nodes, or update its endpoints, given as the connected
+    /// nodes and their positions. The new edges are displayed until the next `update_edges` call.
+    pub fn set_edge(&self, edge_id:EdgeId, source:(NodeId,Vector2), target:(NodeId,Vector2)) {
+        let mut edges = self.edges.borrow_mut();
+        if !edges.contains(edge_id) {
+            self.shown_edges.borrow_mut().insert(edge_id);
+        }
+        edges.insert(edge_id,source,target);
+    }
+
+    /// Forget the edge, because it was removed or does not connect two nodes anymore. Such edges
+    /// are never culled.
+    pub fn remove_edge(&self, edge_id:EdgeId) {
+        self.edges.borrow_mut().remove(edge_id);
+        self.shown_edges.borrow_mut().remove(&edge_id);
+    }
+
+    /// Check whether the node is culled.
+    pub fn is_node_culled(&self, node_id:NodeId) -> bool {
+        !self.shown_nodes.borrow().contains(&node_id)
+    }
+
+    /// Check whether the edge is culled.
+    pub fn is_edge_culled(&self, edge_id:EdgeId) -> bool {
+        self.edges.borrow().contains(edge_id) && !self.shown_edges.borrow().contains(&edge_id)
+    }
+
+    /// Cull the nodes lying outside of the `area`, and restore the ones lying in it. If there is
+    /// no area, the culling is disabled and all the nodes are restored. Returns the nodes whose
+    /// state has changed.
+    pub fn update_nodes(&self, area:Option<&BoundingBox>) -> Changes<NodeId> {
+        let nodes   = self.nodes.borrow();
+        let visible = match area {
+            Some(area) => nodes.nodes_in(area),
+            None       => nodes.nodes().collect(),
+        };
+        Changes::replace(&mut *self.shown_nodes.borrow_mut(),visible)
+    }
+
+    /// Cull the edges which connect the culled nodes and do not cross the `area`, and restore the
+    /// other ones. If there is no area, the culling is disabled and all the edges are restored.
+    /// Should be called after `update_nodes`. Returns the edges whose state has changed.
+    pub fn update_edges(&self, area:Option<&BoundingBox>) -> Changes<EdgeId> {
+        let edges   = self.edges.borrow();
+        let visible = match area {
+            Some(area) => {
+                let mut visible = edges.edges_crossing(area);
+                for node_id in self.shown_nodes.borrow().iter() {
+                    visible.extend(edges.node_edges(*node_id));
+                }
+                visible
+            }
+            None => edges.edges().collect(),
+        };
+        Changes::replace(&mut *self.shown_edges.borrow_mut(),visible)
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ensogl::display::object::Id;
+
+    fn node_id(id:usize) -> NodeId {
+        NodeId(Id::from(id))
+    }
+
+    fn edge_id(id:usize) -> EdgeId {
+        EdgeId(Id::from(id))
+    }
+
+    fn sorted<T:Ord>(mut items:Vec<T>) -> Vec<T> {
+        items.sort();
+        items
+    }
+
+    fn screen() -> BoundingBox {
+        BoundingBox::from_corners(Vector2(-100.0,-100.0),Vector2(100.0,100.0))
+    }
+
+    #[test]
+    fn culling_nodes_outside_of_the_area() {
+        let culling = Culling::default();
+        let (near,far) = (node_id(1),node_id(2));
+        culling.add_node(near,Vector2(0.0,0.0));
+        culling.add_node(far,Vector2(5000.0,0.0));
+        assert!(!culling.is_node_culled(far),"The new nodes are displayed until the update.");
+
+        let changes = culling.update_nodes(Some(&screen()));
+        assert_eq!(changes.culled,vec![far]);
+        assert!(changes.restored.is_empty());
+        assert!(culling.is_node_culled(far));
+        assert!(!culling.is_node_culled(near));
+
+        culling.set_node_position(far,Vector2(10.0,10.0));
+        culling.set_node_position(near,Vector2(-5000.0,0.0));
+        let changes = culling.update_nodes(Some(&screen()));
+        assert_eq!(changes.culled,vec![near]);
+        assert_eq!(changes.restored,vec![far]);
+
+        let changes = culling.update_nodes(None);
+        assert!(changes.culled.is_empty());
+        assert_eq!(changes.restored,vec![near]);
+
+        culling.remove_node(near);
+        let changes = culling.update_nodes(Some(&screen()));
+        assert!(changes.culled.is_empty());
+        assert!(changes.restored.is_empty());
+    }
+
+    #[test]
+    fn culling_edges_of_culled_nodes() {
+        let culling = Culling::default();
+        let left    = (node_id(1),Vector2(-5000.0,0.0));
+        let right   = (node_id(2),Vector2(5000.0,0.0));
+        let center  = (node_id(3),Vector2(0.0,0.0));
+        let corner  = (node_id(4),Vector2(6000.0,3000.0));
+        for (node,position) in [left,right,center,corner].iter() {
+            culling.add_node(*node,*position);
+        }
+        let crossing = edge_id(1);
+        let to_shown = edge_id(2);
+        let far      = edge_id(3);
+        culling.set_edge(crossing,left,right);
+        culling.set_edge(to_shown,right,center);
+        culling.set_edge(far,right,corner);
+        assert!(!culling.is_edge_culled(far),"The new edges are displayed until the update.");
+
+        culling.update_nodes(Some(&screen()));
+        let changes = culling.update_edges(Some(&screen()));
+        assert_eq!(changes.culled,vec![far]);
+        assert!(changes.restored.is_empty());
+        assert!(culling.is_edge_culled(far));
+        assert!(!culling.is_edge_culled(crossing),"The edge crossing the area was culled.");
+        assert!(!culling.is_edge_culled(to_shown),"The edge of a displayed node was culled.");
+
+        culling.set_edge(far,center,corner);
+        let changes = culling.update_edges(Some(&screen()));
+        assert_eq!(changes.restored,vec![far]);
+
+        culling.set_edge(far,right,corner);
+        culling.update_edges(Some(&screen()));
+        culling.remove_edge(far);
+        assert!(!culling.is_edge_culled(far),"The removed edge is still culled.");
+        let changes = culling.update_edges(None);
+        assert!(changes.culled.is_empty());
+        assert!(changes.restored.is_empty());
+    }
+
+    #[test]
+    fn edge_index_queries() {
+        let mut index = EdgeIndex::default();
+        let diagonal  = edge_id(1);
+        let away      = edge_id(2);
+        let at        = |id,x,y| (node_id(id),Vector2(x,y));
+        index.insert(diagonal,at(1,-3000.0,-3000.0),at(2,3000.0,3000.0));
+        index.insert(away,at(3,-3000.0,3000.0),at(4,-2000.0,3000.0));
+        assert_eq!(index.edges_crossing(&screen()),[diagonal].iter().copied().collect());
+
+        index.insert(away,at(3,-3000.0,0.0),at(4,3000.0,0.0));
+        assert_eq!(index.edges_crossing(&screen()),[diagonal,away].iter().copied().collect());
+        assert_eq!(index.node_edges(node_id(3)).collect_vec(),vec![away]);
+
+        index.remove(diagonal);
+        assert_eq!(index.edges_crossing(&screen()),[away].iter().copied().collect());
+        assert_eq!(index.node_edges(node_id(1)).count(),0);
+        assert!(index.cells.values().all(|edges| !edges.contains(&diagonal)));
+    }
+
+    #[test]
+    fn segment_cells() {
+        let cells = sorted(cells_on_segment(Vector2(10.0,10.0),Vector2(10.0,300.0)));
+        assert_eq!(cells,vec![(0,0),(0,1)]);
+        let cells = sorted(cells_on_segment(Vector2(300.0,10.0),Vector2(10.0,10.0)));
+        assert_eq!(cells,vec![(0,0),(1,0)]);
+        let cells = cells_on_segment(Vector2(1.0,1.0),Vector2(1000.0,1000.0));
+        assert!(cells.len() < 16,"The diagonal visited the whole bounding box: {:?}",cells);
+        assert!(cells.contains(&(0,0)) && cells.contains(&(3,3)));
+    }
+}
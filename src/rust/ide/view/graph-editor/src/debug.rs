@@ -0,0 +1,296 @@
+//! The debug overlay of the graph editor. It displays live counters of the graph editor state: the
+//! number of nodes and edges, the edges with detached sources or targets, the selected nodes, the
+//! current selection mode, and the most recent events emitted by the public FRP outputs. It is
+//! meant for diagnosing the issues like edges stuck in the detached state, and is toggled with
+//! the `toggle_debug_overlay` input, bound to a shortcut in the debug mode.
+//!
+//! The overlay learns about the graph editor exclusively from its public outputs, see the
+//! `logged_outputs!` invocation below, so what it displays is exactly what the controllers see.
+
+use crate::prelude::*;
+
+use crate::EdgeId;
+use crate::FrpEndpoints;
+use crate::NodeId;
+use crate::selection;
+
+use enso_frp as frp;
+use ensogl::application::Application;
+use ensogl::display;
+use ensogl::display::Scene;
+use ensogl::display::shape::*;
+use ensogl::gui::text;
+use ensogl_theme::graph_editor::debug_overlay as theme;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The number of the most recent output events displayed in the overlay.
+pub const MAX_EVENTS : usize = 20;
+
+/// The width of the overlay panel.
+const WIDTH : f32 = 420.0;
+/// The distance between the panel border and the text, and between the panel and the screen
+/// border.
+const PADDING : f32 = 12.0;
+/// The distance between the top screen border and the panel, leaving space for the breadcrumbs.
+const TOP_OFFSET : f32 = 48.0;
+/// The height of a single line of the text.
+const LINE_HEIGHT : f32 = 16.0;
+
+
+
+// =============
+// === Stats ===
+// =============
+
+/// The snapshot of the graph editor state displayed in the overlay.
+#[derive(Clone,Debug,Default,PartialEq)]
+pub struct Stats {
+    /// The number of nodes.
+    pub nodes            : usize,
+    /// The number of edges, including the detached ones.
+    pub edges            : usize,
+    /// The edges whose source is not connected.
+    pub detached_sources : Vec<EdgeId>,
+    /// The edges whose target is not connected.
+    pub detached_targets : Vec<EdgeId>,
+    /// The selected nodes, in the order of selection.
+    pub selected         : Vec<NodeId>,
+    /// The current mode of the node selection.
+    pub selection_mode   : selection::Mode,
+}
+
+fn describe_ids<T:Display>(ids:&[T]) -> String {
+    let ids = ids.iter().map(|id| id.to_string()).collect_vec();
+    format!("{} [{}]",ids.len(),ids.join(","))
+}
+
+
+
+// ================
+// === EventLog ===
+// ================
+
+/// The most recent events emitted by the graph editor outputs, the oldest first.
+#[derive(Clone,Debug,Default)]
+pub struct EventLog {
+    events : VecDeque<String>,
+}
+
+impl EventLog {
+    /// Add the event to the log, dropping the oldest one if there are more than `MAX_EVENTS`.
+    pub fn push(&mut self, event:String) {
+        self.events.push_back(event);
+        while self.events.len() > MAX_EVENTS {
+            self.events.pop_front();
+        }
+    }
+
+    /// The logged events, the oldest first.
+    pub fn events(&self) -> impl Iterator<Item=&String> {
+        self.events.iter()
+    }
+}
+
+/// The text displayed in the overlay.
+pub fn render(stats:&Stats, log:&EventLog) -> String {
+    let mut lines = vec!
+        [ format!("nodes: {}",stats.nodes)
+        , format!("edges: {}",stats.edges)
+        , format!("detached sources: {}",describe_ids(&stats.detached_sources))
+        , format!("detached targets: {}",describe_ids(&stats.detached_targets))
+        , format!("selected: {}",describe_ids(&stats.selected))
+        , format!("selection mode: {:?}",stats.selection_mode)
+        , default()
+        , "recent events:".to_string()
+        ];
+    lines.extend(log.events().cloned());
+    lines.join("\n")
+}
+
+
+
+// ======================
+// === Logged Outputs ===
+// ======================
+
+fn log_output<T:frp::Data>
+( network : &frp::Network
+, name    : &'static str
+, output  : &frp::Sampler<T>
+, visible : &frp::Sampler<bool>
+, log     : &frp::Any<String>
+) {
+    frp::extend! { network
+        logged <- output.gate(visible);
+        log    <+ logged.map(move |value| format!("{} {:?}",name,value));
+    }
+}
+
+/// Defines the function connecting the overlay log to the listed outputs.
+macro_rules! logged_outputs {
+    ($($name:ident),* $(,)?) => {
+        /// Emit the description of every event of the logged graph editor outputs to `log`. The
+        /// events are described only while the overlay is visible, so the hidden overlay costs
+        /// nothing.
+        pub fn log_outputs(network:&frp::Network, outputs:&FrpEndpoints, log:&frp::Any<String>) {
+            let visible = &outputs.debug_overlay_visible;
+            $(log_output(network,stringify!($name),&outputs.$name,visible,log);)*
+        }
+    };
+}
+
+logged_outputs! {
+    // === Edges ===
+    on_edge_add, on_edge_drop, on_edge_source_set, on_edge_target_set, on_edge_source_unset,
+    on_edge_target_unset, on_some_edges_targets_unset, on_some_edges_sources_unset,
+    on_all_edges_endpoints_set, hover_node_input, hover_node_output,
+
+    // === Nodes ===
+    node_added, node_removed, node_selected, node_deselected, node_entered, node_exited,
+    node_editing_started, node_editing_finished,
+
+    // === Modes ===
+    interaction_mode,
+}
+
+
+
+// ==============
+// === Shapes ===
+// ==============
+
+mod background {
+    use super::*;
+
+    pub const CORNER_RADIUS_PX : f32 = 8.0;
+
+    ensogl::define_shape_system! {
+        (style:Style) {
+            let width  = Var::<Pixels>::from("input_size.x");
+            let height = Var::<Pixels>::from("input_size.y");
+            let shape  = Rect((&width,&height)).corners_radius(CORNER_RADIUS_PX.px());
+            shape.fill(style.get_color(theme::background)).into()
+        }
+    }
+}
+
+
+
+// ===============
+// === Overlay ===
+// ===============
+
+/// The debug overlay view, placed in the top left corner of the screen. See the module docs.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Overlay {
+    display_object : display::object::Instance,
+    panel          : display::object::Instance,
+    background     : background::View,
+    label          : text::Area,
+    scene          : Scene,
+    log            : Rc<RefCell<EventLog>>,
+}
+
+impl Overlay {
+    /// Constructor.
+    pub fn new(logger:&Logger, app:&Application) -> Self {
+        let logger         = Logger::sub(logger,"DebugOverlay");
+        let scene          = app.display.scene().clone_ref();
+        let display_object = display::object::Instance::new(&logger);
+        let panel          = display::object::Instance::new(&logger);
+        let background     = background::View::new(&logger);
+        let label          = text::Area::new(app);
+        let log            = default();
+        panel.add_child(&background);
+        panel.add_child(&label);
+        scene.layers.panel.add_exclusive(&panel);
+        label.remove_from_scene_layer(&scene.layers.main);
+        label.add_to_scene_layer(&scene.layers.panel_text);
+        Self {display_object,panel,background,label,scene,log}
+    }
+
+    /// Add the event to the displayed log. The graph editor outputs are described only while the
+    /// overlay is visible, so the log covers the events since the overlay was shown.
+    pub fn log_event(&self, event:&str) {
+        self.log.borrow_mut().push(event.into());
+    }
+
+    /// Show or hide the overlay.
+    pub fn set_visible(&self, visible:bool) {
+        if visible { self.display_object.add_child(&self.panel) }
+        else       { self.panel.unset_parent() }
+    }
+
+    /// Display the given stats together with the logged events.
+    pub fn update(&self, stats:&Stats) {
+        let content    = render(stats,&self.log.borrow());
+        let line_count = content.lines().count() as f32;
+        let height     = line_count * LINE_HEIGHT + 2.0 * PADDING;
+        let screen     = self.scene.shape().value();
+        let x          = -screen.width / 2.0 + PADDING + WIDTH / 2.0;
+        let y          = screen.height / 2.0 - TOP_OFFSET - height / 2.0;
+        let styles     = StyleWatch::new(&self.scene.style_sheet);
+        self.background.size.set(Vector2(WIDTH,height));
+        self.panel.set_position_xy(Vector2(x,y));
+        self.label.set_position_xy(Vector2(-WIDTH / 2.0 + PADDING,height / 2.0 - PADDING));
+        self.label.set_default_color(styles.get_color(theme::text));
+        self.label.set_content(content);
+    }
+}
+
+impl display::Object for Overlay {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.display_object
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ensogl::display::object::Id;
+
+    #[test]
+    fn keeping_only_most_recent_events() {
+        let mut log = EventLog::default();
+        for index in 0..MAX_EVENTS + 5 {
+            log.push(format!("event {}",index));
+        }
+        let events = log.events().cloned().collect_vec();
+        assert_eq!(events.len(),MAX_EVENTS);
+        assert_eq!(events.first(),Some(&"event 5".to_string()));
+        assert_eq!(events.last(),Some(&format!("event {}",MAX_EVENTS + 4)));
+    }
+
+    #[test]
+    fn rendering_stats() {
+        let mut log = EventLog::default();
+        log.push("node_added 3".into());
+        let edge  = EdgeId(Id::from(5));
+        let stats = Stats {
+            nodes            : 2,
+            edges            : 1,
+            detached_sources : vec![],
+            detached_targets : vec![edge],
+            selected         : vec![NodeId(Id::from(3)),NodeId(Id::from(4))],
+            selection_mode   : selection::Mode::Merge,
+        };
+        let lines = render(&stats,&log).lines().map(String::from).collect_vec();
+        assert_eq!(lines[2],"detached sources: 0 []");
+        assert_eq!(lines[3],"detached targets: 1 [5]");
+        assert_eq!(lines[4],"selected: 2 [3,4]");
+        assert_eq!(lines[5],"selection mode: Merge");
+        assert_eq!(lines.last(),Some(&"node_added 3".to_string()));
+    }
+}
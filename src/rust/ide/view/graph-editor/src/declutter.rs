@@ -0,0 +1,334 @@
+//! Edge de-clutter mode. When enabled, the edges whose both endpoints are off-screen are faded out,
+//! and small stubs pointing towards their endpoints are displayed at the screen border instead.
+
+use crate::prelude::*;
+
+use crate::EdgeId;
+use crate::NodeId;
+use crate::selection::BoundingBox;
+
+use ensogl::data::color;
+use ensogl::display;
+use ensogl::display::Scene;
+use ensogl::display::shape::*;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// Size of a single cell of the `SpatialIndex`, in scene units.
+pub const CELL_SIZE : f32 = 256.0;
+
+/// Margin added around the screen when looking for visible nodes, in scene units. It makes up for
+/// the nodes being wider than the single point stored in the index.
+const VISIBILITY_MARGIN : f32 = 300.0;
+
+/// Size of the stub shape, in pixels.
+const STUB_SIZE : f32 = 10.0;
+
+/// Distance between the stubs and the screen border, in pixels.
+const STUB_MARGIN : f32 = 12.0;
+
+
+
+// ====================
+// === SpatialIndex ===
+// ====================
+
+/// Index of the node positions, allowing for efficient queries of the nodes lying in a given area.
+/// The scene is divided into a uniform grid of `CELL_SIZE` squares, and every node is assigned to
+/// the cell its position lies in.
+#[derive(Clone,Debug,Default)]
+pub struct SpatialIndex {
+    cells     : HashMap<(i32,i32),HashSet<NodeId>>,
+    positions : HashMap<NodeId,(i32,i32)>,
+}
+
+impl SpatialIndex {
+    /// The cell the given position lies in.
+    pub fn cell_of(position:Vector2) -> (i32,i32) {
+        let x = (position.x / CELL_SIZE).floor() as i32;
+        let y = (position.y / CELL_SIZE).floor() as i32;
+        (x,y)
+    }
+
+    /// Insert the node or update its position.
+    pub fn insert(&mut self, node_id:NodeId, position:Vector2) {
+        let cell = Self::cell_of(position);
+        if self.positions.get(&node_id) != Some(&cell) {
+            self.remove(node_id);
+            self.cells.entry(cell).or_default().insert(node_id);
+            self.positions.insert(node_id,cell);
+        }
+    }
+
+    /// Remove the node from the index.
+    pub fn remove(&mut self, node_id:NodeId) {
+        if let Some(cell) = self.positions.remove(&node_id) {
+            if let Some(nodes) = self.cells.get_mut(&cell) {
+                nodes.remove(&node_id);
+                if nodes.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        }
+    }
+
+    /// All the nodes in the index.
+    pub fn nodes(&self) -> impl Iterator<Item=NodeId> + '_ {
+        self.positions.keys().copied()
+    }
+
+    /// The nodes in all the cells overlapping with the given area. The result may contain nodes
+    /// lying slightly outside of the area.
+    pub fn nodes_in(&self, area:&BoundingBox) -> HashSet<NodeId> {
+        let (left,bottom) = Self::cell_of(Vector2(area.left(),area.bottom()));
+        let (right,top)   = Self::cell_of(Vector2(area.right(),area.top()));
+        let columns       = (right - left + 1) as usize;
+        let rows          = (top - bottom + 1) as usize;
+        let in_area       = |(x,y):&(i32,i32)| {
+            (left..=right).contains(x) && (bottom..=top).contains(y)
+        };
+        if columns.saturating_mul(rows) > self.cells.len() {
+            let cells = self.cells.iter().filter(|(cell,_)| in_area(cell));
+            cells.flat_map(|(_,nodes)| nodes.iter().copied()).collect()
+        } else {
+            let cells = (left..=right).cartesian_product(bottom..=top);
+            let cells = cells.filter_map(|cell| self.cells.get(&cell));
+            cells.flat_map(|nodes| nodes.iter().copied()).collect()
+        }
+    }
+}
+
+
+
+// ================
+// === Clipping ===
+// ================
+
+/// Clip the segment `start -> end` to the area, using the Liang-Barsky algorithm. Returns the
+/// parameters of the points where the segment enters and leaves the area, where `0.0` is the
+/// `start` and `1.0` is the `end` point.
+pub fn clip_segment(area:&BoundingBox, start:Vector2, end:Vector2) -> Option<(f32,f32)> {
+    let delta  = end - start;
+    let bounds = [
+        (-delta.x , start.x - area.left()),
+        ( delta.x , area.right() - start.x),
+        (-delta.y , start.y - area.bottom()),
+        ( delta.y , area.top() - start.y),
+    ];
+    let mut enter = 0.0_f32;
+    let mut leave = 1.0_f32;
+    for (p,q) in bounds.iter().copied() {
+        if p == 0.0 {
+            if q < 0.0 { return None }
+        } else {
+            let t = q / p;
+            if p < 0.0 { enter = enter.max(t) } else { leave = leave.min(t) }
+        }
+    }
+    if enter <= leave { Some((enter,leave)) } else { None }
+}
+
+
+
+// ============
+// === Stub ===
+// ============
+
+/// A small arrow displayed at the screen border, pointing towards an off-screen node.
+pub mod stub {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (color_rgba:Vector4<f32>) {
+            let width  = Var::<Pixels>::from("input_size.x");
+            let height = Var::<Pixels>::from("input_size.y");
+            let arrow  = Triangle(&width,&height);
+            arrow.fill(color_rgba).into()
+        }
+    }
+}
+
+/// Description of a single stub.
+#[derive(Clone,Copy,Debug)]
+pub struct Stub {
+    /// Position of the stub on the screen.
+    pub position : Vector2,
+    /// Angle of the direction the stub points to, in radians.
+    pub angle    : f32,
+    /// Color of the stub.
+    pub color    : color::Lcha,
+}
+
+impl Stub {
+    /// The stubs for the edge going from `source` to `target`, both given in screen coordinates.
+    /// One stub is placed at every point where the edge crosses the screen border, pointing
+    /// towards the endpoint lying beyond that point. Returns no stubs if the edge does not cross
+    /// the screen.
+    pub fn for_edge
+    (screen:&BoundingBox, source:Vector2, target:Vector2, color:color::Lcha) -> Vec<Stub> {
+        let mut area = *screen;
+        area.grow_x(-2.0 * STUB_MARGIN);
+        area.grow_y(-2.0 * STUB_MARGIN);
+        let (enter,leave) = match clip_segment(&area,source,target) {
+            Some(params) => params,
+            None         => return default(),
+        };
+        let delta  = target - source;
+        let angle  = delta.y.atan2(delta.x);
+        let at     = |t:f32| source + delta * t;
+        let to_src = Stub {position:at(enter), angle:angle + std::f32::consts::PI, color};
+        let to_tgt = Stub {position:at(leave), angle, color};
+        vec![to_src,to_tgt]
+    }
+}
+
+
+
+// =================
+// === Declutter ===
+// =================
+
+/// The state of the edge de-clutter mode: the index of node positions, the set of currently faded
+/// edges and the stubs displayed at the screen border.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Declutter {
+    logger         : Logger,
+    display_object : display::object::Instance,
+    scene          : Scene,
+    index          : Rc<RefCell<SpatialIndex>>,
+    faded          : Rc<RefCell<HashSet<EdgeId>>>,
+    stubs          : Rc<RefCell<Vec<stub::View>>>,
+}
+
+impl Declutter {
+    /// Constructor.
+    pub fn new(logger:&Logger, scene:&Scene) -> Self {
+        let logger         = Logger::sub(logger,"Declutter");
+        let display_object = display::object::Instance::new(&logger);
+        let scene          = scene.clone_ref();
+        let index          = default();
+        let faded          = default();
+        let stubs          = default();
+        Self {logger,display_object,scene,index,faded,stubs}
+    }
+
+    /// Update the position of the node in the index.
+    pub fn set_node_position(&self, node_id:NodeId, position:Vector2) {
+        self.index.borrow_mut().insert(node_id,position);
+    }
+
+    /// Remove the node from the index.
+    pub fn remove_node(&self, node_id:NodeId) {
+        self.index.borrow_mut().remove(node_id);
+    }
+
+    /// Check whether the edge is currently faded.
+    pub fn is_faded(&self, edge_id:EdgeId) -> bool {
+        self.faded.borrow().contains(&edge_id)
+    }
+
+    /// The area of the screen in screen coordinates.
+    pub fn screen_area(&self) -> BoundingBox {
+        let screen = self.scene.camera().screen();
+        let corner = Vector2(screen.width,screen.height) / 2.0;
+        BoundingBox::from_corners(-corner,corner)
+    }
+
+    /// The nodes lying on the screen or close to its border.
+    pub fn visible_nodes(&self) -> HashSet<NodeId> {
+        let screen      = self.scene.camera().screen();
+        let corner      = Vector3(screen.width,screen.height,0.0) / 2.0;
+        let bottom_left = self.scene.screen_to_scene_coordinates(-corner).xy();
+        let top_right   = self.scene.screen_to_scene_coordinates(corner).xy();
+        let mut area    = BoundingBox::from_corners(bottom_left,top_right);
+        area.grow_x(2.0 * VISIBILITY_MARGIN);
+        area.grow_y(2.0 * VISIBILITY_MARGIN);
+        self.index.borrow().nodes_in(&area)
+    }
+
+    /// Transform the scene position to the screen coordinates.
+    pub fn scene_to_screen(&self, position:Vector2) -> Vector2 {
+        let camera   = self.scene.camera();
+        let position = Vector4(position.x,position.y,0.0,1.0);
+        (camera.view_matrix() * position).xy() * camera.zoom()
+    }
+
+    /// Replace the set of faded edges. Returns the edges whose state has changed.
+    pub fn set_faded(&self, faded:HashSet<EdgeId>) -> Vec<EdgeId> {
+        let old = mem::replace(&mut *self.faded.borrow_mut(),faded);
+        old.symmetric_difference(&self.faded.borrow()).copied().collect()
+    }
+
+    /// Display the given stubs, reusing the already created shapes.
+    pub fn set_stubs(&self, stubs:&[Stub]) {
+        let mut views = self.stubs.borrow_mut();
+        while views.len() < stubs.len() {
+            let view = stub::View::new(&self.logger);
+            view.size.set(Vector2(STUB_SIZE,STUB_SIZE));
+            self.scene.layers.panel.add_exclusive(&view);
+            views.push(view);
+        }
+        for view in views.iter().skip(stubs.len()) {
+            view.unset_parent();
+        }
+        for (view,stub) in views.iter().zip(stubs) {
+            let color = color::Rgba::from(stub.color);
+            self.display_object.add_child(view);
+            view.color_rgba.set(Vector4(color.red,color.green,color.blue,color.alpha));
+            view.set_position_xy(stub.position);
+            view.set_rotation_z(stub.angle - std::f32::consts::FRAC_PI_2);
+        }
+    }
+}
+
+impl display::Object for Declutter {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.display_object
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ensogl::display::object::Id;
+
+    #[test]
+    fn spatial_index_queries() {
+        let mut index = SpatialIndex::default();
+        let near      = NodeId(Id::from(1));
+        let far       = NodeId(Id::from(2));
+        index.insert(near,Vector2(10.0,10.0));
+        index.insert(far,Vector2(5000.0,5000.0));
+        let area = BoundingBox::from_corners(Vector2(-100.0,-100.0),Vector2(100.0,100.0));
+        assert_eq!(index.nodes_in(&area),[near].iter().copied().collect());
+
+        index.insert(far,Vector2(-50.0,20.0));
+        assert_eq!(index.nodes_in(&area),[near,far].iter().copied().collect());
+
+        index.remove(near);
+        assert_eq!(index.nodes_in(&area),[far].iter().copied().collect());
+        let huge = BoundingBox::from_corners(Vector2(-1e6,-1e6),Vector2(1e6,1e6));
+        assert_eq!(index.nodes_in(&huge),[far].iter().copied().collect());
+    }
+
+    #[test]
+    fn segment_clipping() {
+        let area = BoundingBox::from_corners(Vector2(-1.0,-1.0),Vector2(1.0,1.0));
+        let clip = |start,end| clip_segment(&area,start,end);
+        assert_eq!(clip(Vector2(-3.0,0.0),Vector2(3.0,0.0)),Some((1.0/3.0,2.0/3.0)));
+        assert_eq!(clip(Vector2(0.0,0.0),Vector2(0.0,2.0)),Some((0.0,0.5)));
+        assert_eq!(clip(Vector2(-3.0,2.0),Vector2(3.0,2.0)),None);
+        assert_eq!(clip(Vector2(-3.0,0.0),Vector2(-2.0,5.0)),None);
+    }
+}
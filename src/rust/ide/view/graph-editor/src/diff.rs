@@ -0,0 +1,204 @@
+//! Applying many changes to the displayed graph at once. The controllers synchronizing the view
+//! with the language server describe the whole change as a [`GraphDiff`], instead of driving the
+//! view node by node. The displayed nodes are reused and their unchanged properties are not set
+//! again, so the synchronized graph does not flicker.
+
+use crate::prelude::*;
+
+use crate::component::node;
+use crate::EdgeEndpoint;
+use crate::EdgeId;
+use crate::GraphEditor;
+use crate::NodeId;
+use crate::Type;
+
+
+
+// ===============
+// === NodeRef ===
+// ===============
+
+/// Reference to a node in the [`GraphDiff`].
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum NodeRef {
+    /// A node already displayed in the graph editor.
+    Existing(NodeId),
+    /// A node added by the diff, given by its index in [`GraphDiff::add_nodes`].
+    Added(usize),
+}
+
+/// An endpoint of the edge added by the [`GraphDiff`].
+#[derive(Clone,Debug)]
+#[allow(missing_docs)]
+pub struct EndpointRef {
+    pub node : NodeRef,
+    pub port : span_tree::Crumbs,
+}
+
+impl EndpointRef {
+    /// Constructor.
+    pub fn new(node:NodeRef, port:span_tree::Crumbs) -> Self {
+        Self {node,port}
+    }
+}
+
+
+
+// ===================
+// === NodeChanges ===
+// ===================
+
+/// The changed properties of a node. `None` leaves the property untouched.
+#[derive(Clone,Debug,Default)]
+#[allow(missing_docs)]
+pub struct NodeChanges {
+    pub expression : Option<node::Expression>,
+    pub comment    : Option<node::Comment>,
+    pub position   : Option<Vector2>,
+    /// The usage types of the expression parts, identified by their AST ids.
+    pub types      : Vec<(ast::Id,Option<Type>)>,
+}
+
+
+
+// =================
+// === GraphDiff ===
+// =================
+
+/// Changes of the graph structure, applied by [`GraphEditor::apply_graph_diff`]. The removals
+/// are applied first, then the nodes are added and updated, and finally the edges are added.
+#[derive(Clone,Debug,Default)]
+#[allow(missing_docs)]
+pub struct GraphDiff {
+    pub add_nodes    : Vec<NodeChanges>,
+    pub update_nodes : Vec<(NodeId,NodeChanges)>,
+    pub remove_nodes : Vec<NodeId>,
+    pub add_edges    : Vec<(EndpointRef,EndpointRef)>,
+    pub remove_edges : Vec<EdgeId>,
+}
+
+
+
+// =========================
+// === GraphSynchronized ===
+// =========================
+
+/// The summary of the applied [`GraphDiff`], emitted by the `graph_synchronized` output.
+#[derive(Clone,Debug,Default)]
+#[allow(missing_docs)]
+pub struct GraphSynchronized {
+    /// The ids of the nodes added by the diff, in the order of [`GraphDiff::add_nodes`].
+    pub added_nodes   : Vec<NodeId>,
+    /// The nodes whose properties were actually changed.
+    pub updated_nodes : Vec<NodeId>,
+    /// The ids of the edges added by the diff, in the order of [`GraphDiff::add_edges`]. The
+    /// edges which were already displayed are reused.
+    pub added_edges   : Vec<EdgeId>,
+    pub removed_nodes : Vec<NodeId>,
+    /// The removed edges, including the edges connected to the removed nodes.
+    pub removed_edges : Vec<EdgeId>,
+}
+
+
+
+// ===================
+// === GraphEditor ===
+// ===================
+
+impl GraphEditor {
+    /// Apply all the changes of the diff and emit a single `graph_synchronized` output. The
+    /// changes referring to nonexistent nodes are skipped. The changes are applied as a single
+    /// batch, see `GraphEditor::batch`, and the `synchronizing` output is set for the whole time,
+    /// so the controller does not take the outputs of the applied changes for the user actions.
+    pub fn apply_graph_diff(&self, diff:GraphDiff) -> GraphSynchronized {
+        let mut summary = GraphSynchronized::default();
+        self.frp.source.synchronizing.emit(true);
+        self.frp.begin_batch.emit(());
+
+        for edge_id in diff.remove_edges {
+            if self.model.edges.contains_key(&edge_id) {
+                self.frp.remove_edge.emit(edge_id);
+                summary.removed_edges.push(edge_id);
+            }
+        }
+        for node_id in diff.remove_nodes {
+            if let Some(node) = self.model.nodes.get_cloned_ref(&node_id) {
+                summary.removed_edges.extend(node.all_edges());
+                self.frp.remove_all_node_edges.emit(node_id);
+                self.frp.remove_node.emit(node_id);
+                summary.removed_nodes.push(node_id);
+            }
+        }
+
+        for changes in &diff.add_nodes {
+            let node_id = self.add_node();
+            self.apply_node_changes(node_id,changes);
+            summary.added_nodes.push(node_id);
+        }
+        for (node_id,changes) in &diff.update_nodes {
+            if self.apply_node_changes(*node_id,changes) {
+                summary.updated_nodes.push(*node_id);
+            }
+        }
+
+        for (source,target) in &diff.add_edges {
+            let endpoint = |endpoint:&EndpointRef| {
+                let node_id = match endpoint.node {
+                    NodeRef::Existing(node_id) => Some(node_id),
+                    NodeRef::Added(index)      => summary.added_nodes.get(index).copied(),
+                };
+                node_id.map(|node_id| EdgeEndpoint::new(node_id,endpoint.port.clone()))
+            };
+            if let (Some(source),Some(target)) = (endpoint(source),endpoint(target)) {
+                let edge_id = self.find_edge(&source,&target).or_else(|| {
+                    self.frp.connect_nodes.emit((source.clone(),target.clone()));
+                    self.find_edge(&source,&target)
+                });
+                summary.added_edges.extend(edge_id);
+            }
+        }
+
+        self.frp.end_batch.emit(());
+        self.frp.source.synchronizing.emit(false);
+        self.frp.source.graph_synchronized.emit(summary.clone());
+        summary
+    }
+
+    /// Set the changed properties of the node. Returns `false` if nothing was changed.
+    fn apply_node_changes(&self, node_id:NodeId, changes:&NodeChanges) -> bool {
+        let node = match self.model.nodes.get_cloned_ref(&node_id) {
+            Some(node) => node,
+            None       => return false,
+        };
+        let mut changed = false;
+        if let Some(expression) = &changes.expression {
+            let displayed = node.expression.value().to_string() == expression.code;
+            if !displayed || node.frp.set_expression.value() != *expression {
+                self.frp.set_node_expression.emit((node_id,expression.clone()));
+                changed = true;
+            }
+        }
+        if let Some(comment) = &changes.comment {
+            if node.comment.value() != *comment {
+                self.frp.set_node_comment.emit((node_id,comment.clone()));
+                changed = true;
+            }
+        }
+        if let Some(position) = changes.position {
+            if node.position().xy() != position {
+                self.frp.set_node_position.emit((node_id,position));
+                changed = true;
+            }
+        }
+        for (ast_id,tp) in &changes.types {
+            self.frp.set_expression_usage_type.emit((node_id,*ast_id,tp.clone()));
+            changed = true;
+        }
+        changed
+    }
+
+    /// Find the displayed edge connecting the given endpoints.
+    fn find_edge(&self, source:&EdgeEndpoint, target:&EdgeEndpoint) -> Option<EdgeId> {
+        self.model.edges_connecting(source,target).first().copied()
+    }
+}
@@ -0,0 +1,162 @@
+//! Splitting an edge by double-clicking it. The press on an edge detaches it, so the split edge is
+//! the one remembered on the first press of the double-click. The remembered press is forgotten
+//! as soon as anything else is pressed or the edge is removed, so a later double-click elsewhere
+//! never re-creates a stale connection.
+
+use crate::prelude::*;
+
+use crate::EdgeEndpoint;
+use crate::EdgeId;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The maximum distance, in pixels, between the two presses of the double-click splitting an edge.
+pub const MAX_CURSOR_DRIFT : f32 = 4.0;
+
+
+
+// =========================
+// === PressedConnection ===
+// =========================
+
+/// The connection of the edge pressed by the user, remembered before the press detaches the edge,
+/// as it may be the first press of the double-click splitting the edge.
+#[derive(Clone,Debug,Default)]
+#[allow(missing_docs)]
+pub struct PressedConnection {
+    pub edge_id  : EdgeId,
+    pub source   : EdgeEndpoint,
+    pub target   : EdgeEndpoint,
+    pub position : Vector2,
+}
+
+
+
+// =============
+// === Event ===
+// =============
+
+/// The events driving the edge splitting state machine.
+#[derive(Clone,Debug)]
+pub enum Event {
+    /// An edge with both endpoints set was pressed.
+    EdgePressed(PressedConnection),
+    /// Something other than a connected edge was pressed.
+    OtherPressed,
+    /// The edge was removed from the graph.
+    EdgeRemoved(EdgeId),
+    /// The double-click splitting the edge was made at the given position.
+    SplitRequested(Vector2),
+    /// The split of the connection was chosen explicitly, e.g. from the context menu. The
+    /// remembered press is kept.
+    SplitChosen(PressedConnection),
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self::OtherPressed
+    }
+}
+
+/// The transition function of the edge splitting state machine, meant to be used with the `fsm`
+/// FRP node. The state is the last pressed connection, and the output is the connection to split.
+pub fn step
+(last:&Option<PressedConnection>, event:&Event)
+-> (Option<PressedConnection>,Option<PressedConnection>) {
+    match event {
+        Event::EdgePressed(pressed) => (Some(pressed.clone()),None),
+        Event::OtherPressed         => (None,None),
+        Event::EdgeRemoved(edge_id) => {
+            let removed = last.as_ref().map_or(false,|pressed| pressed.edge_id == *edge_id);
+            (if removed { None } else { last.clone() },None)
+        }
+        Event::SplitRequested(position) => {
+            let in_place = |pressed:&&PressedConnection| {
+                (pressed.position - position).norm() <= MAX_CURSOR_DRIFT
+            };
+            match last.as_ref().filter(in_place) {
+                Some(pressed) => (None,Some(pressed.clone())),
+                None          => (last.clone(),None),
+            }
+        }
+        Event::SplitChosen(pressed) => (last.clone(),Some(pressed.clone())),
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ensogl::display::object::Id;
+
+    fn pressed(edge_id:usize, x:f32) -> PressedConnection {
+        let edge_id  = EdgeId(Id::from(edge_id));
+        let position = Vector2(x,0.0);
+        PressedConnection {edge_id,position,..default()}
+    }
+
+    fn run(events:Vec<Event>) -> Vec<Option<EdgeId>> {
+        let mut state = None;
+        events.iter().map(|event| {
+            let (new_state,output) = step(&state,event);
+            state = new_state;
+            output.map(|pressed| pressed.edge_id)
+        }).collect()
+    }
+
+    #[test]
+    fn double_click_splits_the_pressed_edge_once() {
+        let split   = Event::SplitRequested(Vector2(1.0,0.0));
+        let outputs = run(vec![Event::EdgePressed(pressed(1,0.0)),split.clone(),split]);
+        assert_eq!(outputs,vec![None,Some(EdgeId(Id::from(1))),None]);
+    }
+
+    #[test]
+    fn chosen_split_splits_the_chosen_edge() {
+        let chosen  = Event::SplitChosen(pressed(2,5.0));
+        let split   = Event::SplitRequested(Vector2(0.0,0.0));
+        let outputs = run(vec![Event::EdgePressed(pressed(1,0.0)),chosen,split]);
+        let edge    = |id:usize| Some(EdgeId(Id::from(id)));
+        assert_eq!(outputs,vec![None,edge(2),edge(1)]);
+    }
+
+    #[test]
+    fn moved_cursor_does_not_split() {
+        let split   = Event::SplitRequested(Vector2(MAX_CURSOR_DRIFT + 1.0,0.0));
+        let outputs = run(vec![Event::EdgePressed(pressed(1,0.0)),split]);
+        assert_eq!(outputs,vec![None,None]);
+    }
+
+    #[test]
+    fn stale_press_does_not_split() {
+        let split = Event::SplitRequested(Vector2(0.0,0.0));
+        let after_other_press = run(vec!
+            [ Event::EdgePressed(pressed(1,0.0))
+            , Event::OtherPressed
+            , split.clone()
+            ]);
+        assert_eq!(after_other_press,vec![None,None,None]);
+        let after_removal = run(vec!
+            [ Event::EdgePressed(pressed(1,0.0))
+            , Event::EdgeRemoved(EdgeId(Id::from(1)))
+            , split.clone()
+            ]);
+        assert_eq!(after_removal,vec![None,None,None]);
+        let after_other_removal = run(vec!
+            [ Event::EdgePressed(pressed(1,0.0))
+            , Event::EdgeRemoved(EdgeId(Id::from(2)))
+            , split
+            ]);
+        assert_eq!(after_other_removal,vec![None,None,Some(EdgeId(Id::from(1)))]);
+    }
+}
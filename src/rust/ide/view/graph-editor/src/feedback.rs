@@ -0,0 +1,109 @@
+//! Feedback on the key user interactions with the graph. The graph editor emits the
+//! [`Event`]s through its `feedback` output, so the embedding layer can attach audio or haptic
+//! feedback to them. The [`AudioFeedback`] is a built-in implementation playing subtle sounds. It
+//! is disabled by default and enabled by the `audio_feedback` startup argument.
+
+use crate::prelude::*;
+
+use ensogl::system::web;
+use web_sys::AudioContext;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The volume of the played sounds, in the range from 0 to 1.
+const VOLUME : f32 = 0.04;
+
+/// The duration of the played sounds, in seconds.
+const DURATION_S : f64 = 0.08;
+
+/// The volume at which the sound fades out. It must be greater than zero, as the fade out is
+/// exponential.
+const SILENCE : f32 = 0.0001;
+
+
+
+// =============
+// === Event ===
+// =============
+
+/// A user interaction worth a feedback.
+#[derive(Clone,Copy,Debug,Eq,Hash,PartialEq)]
+pub enum Event {
+    /// The user connected an edge to a port.
+    EdgeConnected,
+    /// The dragged node snapped to the position of another node.
+    NodeSnapped,
+    /// The user removed the selected nodes.
+    Deleted,
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self::EdgeConnected
+    }
+}
+
+impl Event {
+    /// The frequency of the sound played for the event, in Hz.
+    fn frequency(self) -> f32 {
+        match self {
+            Self::EdgeConnected => 880.0,
+            Self::NodeSnapped   => 1320.0,
+            Self::Deleted       => 440.0,
+        }
+    }
+}
+
+
+
+// =====================
+// === AudioFeedback ===
+// =====================
+
+/// Plays a short, quiet tone for every feedback event. The audio context is created on the first
+/// event, as the browsers allow playing sounds only after the user interacted with the page.
+#[derive(Clone,CloneRef,Debug)]
+pub struct AudioFeedback {
+    logger  : Logger,
+    context : Rc<RefCell<Option<AudioContext>>>,
+}
+
+impl AudioFeedback {
+    /// Constructor.
+    pub fn new(logger:&Logger) -> Self {
+        let logger  = Logger::new_sub(logger,"AudioFeedback");
+        let context = default();
+        Self {logger,context}
+    }
+
+    /// Play the sound of the event.
+    pub fn play(&self, event:Event) {
+        if let Err(err) = self.try_play(event) {
+            warning!(&self.logger,"Cannot play the sound of the {event:?} event: {err:?}.");
+        }
+    }
+
+    fn try_play(&self, event:Event) -> Result<(),web::JsValue> {
+        let existing = self.context.borrow().clone();
+        let context  = match existing {
+            Some(context) => context,
+            None          => AudioContext::new()?,
+        };
+        *self.context.borrow_mut() = Some(context.clone());
+        let oscillator = context.create_oscillator()?;
+        let gain       = context.create_gain()?;
+        let start      = context.current_time();
+        let end        = start + DURATION_S;
+        oscillator.frequency().set_value(event.frequency());
+        gain.gain().set_value_at_time(VOLUME,start)?;
+        gain.gain().exponential_ramp_to_value_at_time(SILENCE,end)?;
+        oscillator.connect_with_audio_node(&gain)?;
+        gain.connect_with_audio_node(&context.destination())?;
+        oscillator.start_with_when(start)?;
+        oscillator.stop_with_when(end)
+    }
+}
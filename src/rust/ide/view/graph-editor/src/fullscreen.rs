@@ -0,0 +1,92 @@
+//! Management of the fullscreen visualization mode. At most one visualization is displayed in
+//! fullscreen at a time. Its fullscreen panel is reparented to the dedicated overlay, which the
+//! embedding view displays above all the other overlays, and the node-attached layout of its
+//! container is remembered, so it can be restored exactly when leaving the mode.
+
+use crate::prelude::*;
+
+use crate::NodeId;
+use crate::component::visualization;
+use crate::component::visualization::container::AttachedLayout;
+use crate::component::visualization::container::fullscreen::Pane;
+
+use ensogl::display;
+
+
+
+// ==============
+// === Active ===
+// ==============
+
+/// The visualization displayed in fullscreen.
+#[derive(Debug)]
+struct Active {
+    node_id   : NodeId,
+    container : visualization::Container,
+    layout    : AttachedLayout,
+}
+
+
+
+// ===============
+// === Manager ===
+// ===============
+
+/// The fullscreen visualization manager. See the module docs to learn more.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Manager {
+    logger  : Logger,
+    overlay : display::object::Instance,
+    active  : Rc<RefCell<Option<Active>>>,
+}
+
+impl Manager {
+    /// Constructor.
+    pub fn new(logger:&Logger) -> Self {
+        let logger  = Logger::new_sub(logger,"FullscreenManager");
+        let overlay = display::object::Instance::new(&logger);
+        let active  = default();
+        Self {logger,overlay,active}
+    }
+
+    /// The overlay containing the panel of the visualization displayed in fullscreen.
+    pub fn overlay(&self) -> &display::object::Instance {
+        &self.overlay
+    }
+
+    /// The node whose visualization is displayed in fullscreen.
+    pub fn node_id(&self) -> Option<NodeId> {
+        self.active.borrow().as_ref().map(|active| active.node_id)
+    }
+
+    /// Check whether any visualization is displayed in fullscreen. The navigation of the graph is
+    /// locked meanwhile.
+    pub fn is_active(&self) -> bool {
+        self.active.borrow().is_some()
+    }
+
+    /// Display the visualization of the node in fullscreen. The visualization displayed in
+    /// fullscreen before, if any, must be exited first.
+    pub fn enter(&self, node_id:NodeId, container:&visualization::Container) {
+        debug_assert!(self.active.borrow().is_none());
+        debug!(self.logger, "Entering the fullscreen mode of {node_id:?}.");
+        let container = container.clone_ref();
+        let layout    = container.attached_layout();
+        container.frp.set_fullscreen_pane.emit(Pane::Whole);
+        container.frp.enable_fullscreen.emit(());
+        self.overlay.add_child(container.fullscreen_visualization());
+        *self.active.borrow_mut() = Some(Active {node_id,container,layout});
+    }
+
+    /// Leave the fullscreen mode, restoring the layout the container had when attached to its node.
+    /// Returns the node whose visualization was displayed in fullscreen, or `None` if there was
+    /// none.
+    pub fn exit(&self) -> Option<NodeId> {
+        let Active {node_id,container,layout} = self.active.borrow_mut().take()?;
+        debug!(self.logger, "Leaving the fullscreen mode of {node_id:?}.");
+        container.fullscreen_visualization().unset_parent();
+        container.frp.disable_fullscreen.emit(());
+        container.restore_attached_layout(layout);
+        Some(node_id)
+    }
+}
@@ -0,0 +1,113 @@
+//! Allocation of the node and edge identifiers. By default, the identifiers are derived from the
+//! display objects of the nodes and edges, which makes them cheap, but dependent on the memory
+//! addresses, so they differ between runs. The deterministic allocation makes the same sequence of
+//! operations produce the same identifiers in every run, so the FRP logs, the debug monitor output,
+//! and the integration test results can be compared across runs.
+
+use crate::prelude::*;
+
+use crate::EdgeId;
+use crate::NodeId;
+use crate::component;
+
+use enso_args::ARGS;
+use ensogl::display::object::Id;
+use ensogl::display;
+
+
+
+// ====================
+// === IdAllocation ===
+// ====================
+
+/// The way the identifiers of the new nodes and edges are allocated.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum IdAllocation {
+    /// Derive the identifiers from the display objects of the nodes and edges.
+    DisplayObject,
+    /// Allocate the identifiers from a monotonic counter, shared by the nodes and edges.
+    Deterministic,
+}
+
+impl Default for IdAllocation {
+    fn default() -> Self {
+        Self::DisplayObject
+    }
+}
+
+impl IdAllocation {
+    /// The deterministic allocation if the `deterministic_ids` startup argument is set, the
+    /// default one otherwise.
+    pub fn from_args() -> Self {
+        if ARGS.deterministic_ids == Some(true) { Self::Deterministic } else { default() }
+    }
+}
+
+
+
+// ==================
+// === IdProvider ===
+// ==================
+
+/// Allocator of the node and edge identifiers, following the chosen [`IdAllocation`].
+#[derive(Clone,CloneRef,Debug)]
+pub struct IdProvider {
+    allocation : IdAllocation,
+    next       : Rc<Cell<usize>>,
+}
+
+impl IdProvider {
+    /// Constructor. The first deterministic identifier is `1`, so it never equals the default one.
+    pub fn new(allocation:IdAllocation) -> Self {
+        let next = Rc::new(Cell::new(1));
+        Self {allocation,next}
+    }
+
+    /// The allocation used by this provider.
+    pub fn allocation(&self) -> IdAllocation {
+        self.allocation
+    }
+
+    /// Allocate the identifier of the new node.
+    pub fn node_id(&self, view:&component::Node) -> NodeId {
+        self.allocate(view).into()
+    }
+
+    /// Allocate the identifier of the new edge.
+    pub fn edge_id(&self, view:&component::Edge) -> EdgeId {
+        self.allocate(view).into()
+    }
+
+    fn allocate(&self, object:&impl display::Object) -> Id {
+        match self.allocation {
+            IdAllocation::DisplayObject => object.id(),
+            IdAllocation::Deterministic => self.next_id(),
+        }
+    }
+
+    fn next_id(&self) -> Id {
+        let id = self.next.get();
+        self.next.set(id + 1);
+        id.into()
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic_ids_are_consecutive() {
+        let provider = IdProvider::new(IdAllocation::Deterministic);
+        let other    = IdProvider::new(IdAllocation::Deterministic);
+        let ids      = (0..3).map(|_| provider.next_id()).collect_vec();
+        assert_eq!(ids,vec![Id::from(1),Id::from(2),Id::from(3)]);
+        assert_eq!(other.next_id(),Id::from(1));
+    }
+}
@@ -0,0 +1,124 @@
+//! Provides the `Mode` enum to represent the current interaction mode of the graph editor, which
+//! determines the available shortcuts.
+
+use crate::prelude::*;
+
+
+
+// ========================
+// === Interaction Mode ===
+// ========================
+
+/// Represents what the user is currently doing in the graph editor. Every shortcut declares the
+/// modes it is valid in, see `Mode::condition`.
+#[derive(Debug,Copy,Clone,CloneRef,PartialEq,Eq,Hash)]
+pub enum Mode {
+    /// Navigating the scene, selecting and moving nodes.
+    Navigate,
+    /// Editing the expression of a node, or typing into a text field outside of the graph editor.
+    Edit,
+    /// Dragging an edge with a detached endpoint in order to connect nodes.
+    Connect,
+    /// Choosing the target port of a new connection with the keyboard. See the
+    /// `keyboard_connect` module docs.
+    KeyboardConnect,
+    /// Viewing the graph which cannot be edited, e.g. while the connection to the backend is lost.
+    /// The nodes can be selected and visualized, but not moved, connected or changed.
+    ReadOnly,
+    /// Presenting the graph, e.g. during a demo. The graph is read-only, and the shortcuts are
+    /// limited to entering the nodes and toggling the visualizations.
+    Present,
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Mode::Navigate
+    }
+}
+
+impl Mode {
+    /// Computes the mode from the state of the graph editor. Presenting the graph takes precedence
+    /// over everything else, followed by the read-only graph. Editing a node takes precedence over
+    /// connecting, and connecting with the keyboard takes precedence over dragging edges.
+    pub fn from_state
+    ( presenting                : bool
+    , read_only                 : bool
+    , node_editing              : bool
+    , some_edge_endpoints_unset : bool
+    , keyboard_connecting       : bool
+    ) -> Self {
+        if      presenting                { Mode::Present }
+        else if read_only                 { Mode::ReadOnly }
+        else if node_editing              { Mode::Edit }
+        else if keyboard_connecting       { Mode::KeyboardConnect }
+        else if some_edge_endpoints_unset { Mode::Connect }
+        else                              { Mode::Navigate }
+    }
+
+    /// Name of the graph editor status output which is `true` when this mode is active. It can be
+    /// used in shortcut conditions.
+    pub fn status_name(self) -> &'static str {
+        match self {
+            Mode::Navigate        => "in_navigate_mode",
+            Mode::Edit            => "in_edit_mode",
+            Mode::Connect         => "in_connect_mode",
+            Mode::KeyboardConnect => "in_keyboard_connect_mode",
+            Mode::ReadOnly        => "in_read_only_mode",
+            Mode::Present         => "in_present_mode",
+        }
+    }
+
+    /// Shortcut condition satisfied when any of the given modes is active.
+    pub fn condition(modes:&[Mode]) -> String {
+        modes.iter().map(|mode| mode.status_name()).join(" | ")
+    }
+
+    /// Shortcut condition satisfied when any of the given modes is active and the given status
+    /// condition holds, e.g. `debug_mode` or `!is_fs_visualization_displayed`. The status must
+    /// not contain alternatives, as the `|` operator has the lowest precedence in conditions.
+    pub fn condition_when(modes:&[Mode], status:&str) -> String {
+        if status.is_empty() {
+            Self::condition(modes)
+        } else {
+            modes.iter().map(|mode| format!("{} & {}",mode.status_name(),status)).join(" | ")
+        }
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mode_precedence() {
+        let mode = |state:[bool;5]| Mode::from_state(state[0],state[1],state[2],state[3],state[4]);
+        assert_eq!(mode([false,false,false,false,false]) , Mode::Navigate);
+        assert_eq!(mode([false,false,false,true,false])  , Mode::Connect);
+        assert_eq!(mode([false,false,false,true,true])   , Mode::KeyboardConnect);
+        assert_eq!(mode([false,false,true,true,true])    , Mode::Edit);
+        assert_eq!(mode([false,true,true,true,true])     , Mode::ReadOnly);
+        assert_eq!(mode([true,true,true,true,true])      , Mode::Present);
+        assert_eq!(mode([true,false,false,false,false])  , Mode::Present);
+    }
+
+    #[test]
+    fn condition_of_modes() {
+        assert_eq!(Mode::condition(&[]),"");
+        assert_eq!(Mode::condition(&[Mode::Present]),"in_present_mode");
+        let condition = Mode::condition(&[Mode::Navigate,Mode::ReadOnly,Mode::Present]);
+        assert_eq!(condition,"in_navigate_mode | in_read_only_mode | in_present_mode");
+    }
+
+    #[test]
+    fn condition_of_modes_with_status() {
+        assert_eq!(Mode::condition_when(&[Mode::Edit],""),"in_edit_mode");
+        let condition = Mode::condition_when(&[Mode::Navigate,Mode::Edit],"!debug_mode");
+        assert_eq!(condition,"in_navigate_mode & !debug_mode | in_edit_mode & !debug_mode");
+    }
+}
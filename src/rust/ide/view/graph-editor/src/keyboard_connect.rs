@@ -0,0 +1,84 @@
+//! Connecting the nodes with the keyboard. In the keyboard connect mode the output of the source
+//! node (the one selected when the mode was entered) is connected to a target input port of
+//! another node. The target is chosen with the arrow keys or by hovering, and the connection is
+//! created with the same `connect_nodes` flow as when dragging the edge with the mouse. The target
+//! ports are visited node by node in the reading order (see [`accessibility::reading_order`]).
+
+use crate::prelude::*;
+
+use crate::EdgeEndpoint;
+use crate::NodeId;
+use crate::accessibility;
+
+
+
+// ===============
+// === Targets ===
+// ===============
+
+/// All the input ports which can be connected to the output of the `source` node, in the order
+/// they are visited with the arrow keys. The nodes are visited in the given `order`, and the ports
+/// of every node are returned by the `ports` function.
+pub fn targets
+(source:NodeId, order:&[NodeId], ports:impl Fn(NodeId)->Vec<span_tree::Crumbs>)
+-> Vec<EdgeEndpoint> {
+    let nodes = order.iter().copied().filter(|node_id| *node_id != source);
+    nodes.flat_map(|node_id| {
+        ports(node_id).into_iter().map(move |port| EdgeEndpoint::new(node_id,port))
+    }).collect()
+}
+
+/// The target following the `current` one, or preceding it if `forward` is `false`. The order
+/// wraps around, and starts from the first target if there is no `current` one.
+pub fn target_after
+(targets:&[EdgeEndpoint], current:Option<&EdgeEndpoint>, forward:bool) -> Option<EdgeEndpoint> {
+    accessibility::cycle(targets,current,forward)
+}
+
+/// Whether the hovered port can become the target of the connection started from `source`.
+pub fn is_valid_target(source:NodeId, target:&EdgeEndpoint) -> bool {
+    target.node_id != source
+}
+
+/// The endpoints of the connection, in the format of the `connect_nodes` input. The whole output
+/// of the source node is connected.
+pub fn connection(source:NodeId, target:EdgeEndpoint) -> (EdgeEndpoint,EdgeEndpoint) {
+    (EdgeEndpoint::new(source,default()),target)
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ensogl::display::object::Id;
+
+    fn node_id(id:usize) -> NodeId {
+        NodeId(Id::from(id))
+    }
+
+    fn port(index:usize) -> span_tree::Crumbs {
+        span_tree::Crumbs::new(vec![index])
+    }
+
+    #[test]
+    fn targets_skip_the_source_node() {
+        let order    = vec![node_id(1),node_id(2),node_id(3)];
+        let ports    = |id| if id == node_id(3) { vec![port(0),port(1)] } else { vec![port(0)] };
+        let targets  = targets(node_id(2),&order,ports);
+        let expected = vec!
+            [ EdgeEndpoint::new(node_id(1),port(0))
+            , EdgeEndpoint::new(node_id(3),port(0))
+            , EdgeEndpoint::new(node_id(3),port(1))
+            ];
+        assert_eq!(targets,expected);
+        assert_eq!(target_after(&targets,None,true),Some(expected[0].clone()));
+        assert_eq!(target_after(&targets,Some(&expected[0]),false),Some(expected[2].clone()));
+        assert!(!is_valid_target(node_id(2),&EdgeEndpoint::new(node_id(2),port(0))));
+    }
+}
@@ -10,6 +10,7 @@
 #![feature(fn_traits)]
 #![feature(option_result_contains)]
 #![feature(specialization)]
+#![feature(total_cmp)]
 #![feature(trait_alias)]
 #![feature(min_type_alias_impl_trait)]
 #![feature(unboxed_closures)]
@@ -24,39 +25,96 @@
 
 #![recursion_limit="1024"]
 
+#[warn(missing_docs)]
+pub mod accessibility;
+#[warn(missing_docs)]
+pub mod api;
+#[warn(missing_docs)]
+pub mod autopan;
+#[warn(missing_docs)]
+pub mod batch;
 #[warn(missing_docs)]
 pub mod component;
 
 pub mod builtin;
+#[warn(missing_docs)]
+pub mod context_menu;
 pub mod data;
 #[warn(missing_docs)]
+pub mod debug;
+#[warn(missing_docs)]
+pub mod diff;
+#[warn(missing_docs)]
+pub mod feedback;
+#[warn(missing_docs)]
+pub mod id_provider;
+#[warn(missing_docs)]
+pub mod interaction;
+#[warn(missing_docs)]
+pub mod keyboard_connect;
+#[warn(missing_docs)]
 pub mod profiling;
 #[warn(missing_docs)]
+pub mod session;
+#[warn(missing_docs)]
+pub mod snapshot;
+#[warn(missing_docs)]
+pub mod split_view;
+#[warn(missing_docs)]
+pub mod template;
+#[warn(missing_docs)]
 pub mod view;
 
+#[warn(missing_docs)]
+mod alignment;
 #[warn(missing_docs)]
 mod selection;
+#[warn(missing_docs)]
+mod culling;
+#[warn(missing_docs)]
+mod declutter;
+#[warn(missing_docs)]
+mod edge_split;
+#[warn(missing_docs)]
+mod fullscreen;
+#[warn(missing_docs)]
+mod lite_mode;
+#[warn(missing_docs)]
+mod tidy;
+#[warn(missing_docs)]
+mod transition;
 
 use crate::component::node;
 pub use crate::node::profiling::Status as NodeProfilingStatus;
+use crate::component::port_tooltip::PortTooltip;
+use crate::component::port_tooltip;
 use crate::component::tooltip::Tooltip;
 use crate::component::visualization::instance::PreprocessorConfiguration;
 use crate::component::tooltip;
 use crate::component::type_coloring;
+use crate::component::vis_panel;
 use crate::component::visualization::MockDataGenerator3D;
+use crate::component::visualization::container::fullscreen::Pane;
 use crate::component::visualization;
 use crate::data::enso;
+use crate::edge_split::PressedConnection;
+use crate::id_provider::IdProvider;
+
+pub use crate::id_provider::IdAllocation;
+pub use crate::template::NodeTemplate;
 
 use enso_args::ARGS;
 use enso_frp as frp;
 use ensogl::Animation;
 use ensogl::DEPRECATED_Animation;
 use ensogl::DEPRECATED_Tween;
+use ensogl::Easing;
 use ensogl::application::Application;
 use ensogl::application::shortcut;
 use ensogl::application;
 use ensogl::data::color;
 use ensogl::display::Scene;
+use ensogl::display::navigation::navigator::FlightEasing;
 use ensogl::display::navigation::navigator::Navigator;
 use ensogl::display::object::Id;
 use ensogl::display::shape::StyleWatch;
@@ -64,10 +122,12 @@ use ensogl::display::shape::StyleWatchFrp;
 use ensogl::display;
 use ensogl::gui::cursor;
 use ensogl::prelude::*;
-use ensogl::system::web;
+use ensogl_text as text;
 use ensogl_theme as theme;
 use ensogl_web::drop;
 use ordered_float::OrderedFloat;
+use std::collections::BTreeSet;
+use std::time::Duration;
 
 
 
@@ -88,7 +148,15 @@ pub mod prelude {
 // =================
 
 const SNAP_DISTANCE_THRESHOLD              : f32 = 10.0;
-const VIZ_PREVIEW_MODE_TOGGLE_TIME_MS      : f32 = 300.0;
+const VIZ_PREVIEW_MODE_TOGGLE_TIME_MS      : u32 = 300;
+/// Default minimal time between two emissions of the `layout_flush_requested` output.
+const DEFAULT_LAYOUT_FLUSH_INTERVAL_S      : f32 = 5.0;
+/// The margin around the nodes, in pixels, kept by the `zoom_to_fit` camera movement.
+const ZOOM_TO_FIT_PADDING                  : f32 = 50.0;
+const ZOOM_TO_FIT_DURATION_MS              : f32 = 500.0;
+/// The maximum distance, in pixels, between pressing and releasing the right mouse button for
+/// the click to open the context menu. Longer drags zoom the scene.
+const CONTEXT_MENU_MAX_DRAG                : f32 = 4.0;
 const MACOS_TRAFFIC_LIGHTS_CONTENT_WIDTH   : f32 = 52.0;
 const MACOS_TRAFFIC_LIGHTS_CONTENT_HEIGHT  : f32 = 12.0;
 /// Horizontal and vertical offset between traffic lights and window border
@@ -385,10 +453,28 @@ ensogl::define_endpoints! {
         /// Toggle nodes inverse selection mode.
         toggle_node_inverse_select(),
 
+        /// Enable the edge area selection mode, in which the area selection selects also the edges
+        /// crossing the selection rectangle, not only the nodes.
+        enable_edge_area_select(),
+        /// Disable the edge area selection mode, see `enable_edge_area_select`.
+        disable_edge_area_select(),
+        /// Toggle the edge area selection mode, see `enable_edge_area_select`.
+        toggle_edge_area_select(),
+
         /// Set the node as selected. Ignores selection mode.
         select_node                  (NodeId),
         /// Set the node as deselected. Ignores selection mode.
         deselect_node                (NodeId),
+        /// Add the nodes to the selection, keeping the order of the already selected ones. Ignores
+        /// selection mode. Emits a single `selection_changed` event.
+        select_nodes                 (Vec<NodeId>),
+        /// Remove the nodes from the selection. Ignores selection mode. Emits a single
+        /// `selection_changed` event.
+        deselect_nodes               (Vec<NodeId>),
+        /// Replace the selection with the given nodes. The nodes which stay selected keep their
+        /// order, and the newly selected ones are appended in the given order. Ignores selection
+        /// mode. Emits a single `selection_changed` event.
+        set_selection                (Vec<NodeId>),
 
 
         // === Navigation ===
@@ -397,8 +483,19 @@ ensogl::define_endpoints! {
         enter_selected_node(),
         /// Enter the node currently under the cursor.
         enter_hovered_node(),
+        /// Split the edge pressed right before into two edges joined by a new pass-through node.
+        /// Bound to the double-click, see the `edge_split` output.
+        split_pressed_edge(),
         /// Steps out of the current node, popping the topmost stack frame from the crumb list.
         exit_node(),
+        /// Set the duration, in milliseconds, of the animated transition played when entering or
+        /// exiting a node. See the `transition` module docs to learn more.
+        set_level_transition_duration (f32),
+        /// Enable the animated transitions between the graph levels. They are enabled by default.
+        enable_level_transitions(),
+        /// Disable the animated transitions between the graph levels. The graph of the new level
+        /// is then displayed at once.
+        disable_level_transitions(),
 
 
         // === Node Editing ===
@@ -407,8 +504,20 @@ ensogl::define_endpoints! {
         add_node(),
         /// Add a new node and place it at the mouse cursor position.
         add_node_at_cursor(),
+        /// Add a new node described by the template at the mouse cursor position, enable its
+        /// visualization, and if the template has placeholders, start editing the node with the
+        /// first placeholder selected. See the `template` module docs.
+        add_node_from_template(NodeTemplate),
         /// Remove all selected nodes from the graph.
         remove_selected_nodes(),
+        /// Highlight the selected nodes, their edges, and the nodes which would lose their inputs
+        /// if the selected nodes were removed.
+        start_removal_preview_for_selected_nodes(),
+        /// Highlight the given nodes, their edges, and the nodes which would lose their inputs if
+        /// the given nodes were removed.
+        start_removal_preview(Vec<NodeId>),
+        /// Remove the highlight set by `start_removal_preview`.
+        stop_removal_preview(),
         /// Remove all nodes from the graph.
         remove_all_nodes(),
         /// Enable mode in which the pressed node will be edited.
@@ -417,12 +526,42 @@ ensogl::define_endpoints! {
         edit_mode_off(),
         /// Stop node editing, whatever node is currently edited.
         stop_editing(),
+        /// Enable the argument editing mode. In this mode, pressing an empty expected argument
+        /// port of the hovered node emits `node_argument_added`, and the optional arguments
+        /// display a remove button emitting `node_argument_removed`.
+        enable_argument_editing(),
+        /// Disable the argument editing mode.
+        disable_argument_editing(),
         /// Remove all nodes from the graph.
         collapse_selected_nodes(),
+        /// Swap the last selected node with the node it takes its input from. Works only if both
+        /// nodes have exactly one input and one output connection.
+        move_selected_node_upstream(),
+        /// Swap the last selected node with the node consuming its output. Works only if both
+        /// nodes have exactly one input and one output connection.
+        move_selected_node_downstream(),
         /// Indicate whether this node had an error or not.
         set_node_error_status(NodeId,Option<node::error::Error>),
         /// Indicate whether this node has finished execution.
         set_node_profiling_status(NodeId,node::profiling::Status),
+        /// Set the time the engine spent on executing the node, as reported by the engine
+        /// profiling. The node is marked as finished, so the time is displayed in the profiling
+        /// mode and in the profiling heatmap.
+        set_node_profiling_data((NodeId,Duration)),
+        /// Set the execution status of the node, mirroring the execution updates of the engine.
+        set_node_execution_status((NodeId,node::ExecutionStatus)),
+        /// Attach a badge, a small auxiliary widget like a profiling time badge or a breakpoint
+        /// toggle, to the node. The interactions are reported by `node_badge_pressed` and
+        /// `node_badge_toggled`.
+        add_node_badge(NodeId,node::BadgeDefinition),
+        /// Remove the badge with the given identifier from the node.
+        remove_node_badge(NodeId,ImString),
+
+
+        // === Layout Persistence ===
+
+        /// Set the minimal time in seconds between two emissions of `layout_flush_requested`.
+        set_layout_flush_interval(f32),
 
 
         // === Visualization ===
@@ -435,8 +574,27 @@ ensogl::define_endpoints! {
         release_visualization_visibility(),
         /// Cycle the visualization for the selected nodes.
         cycle_visualization_for_selected_node(),
-        /// The visualization currently displayed as fullscreen is
+        /// Display the visualization of the node in fullscreen, enabling it if needed. The
+        /// visualization displayed in fullscreen before is closed. While in fullscreen, the scene
+        /// navigation is locked, and pressing escape or clicking the scene background closes the
+        /// visualization.
+        enter_visualization_fullscreen(NodeId),
+        /// Close the visualization displayed in fullscreen, restoring its layout below the node.
         close_fullscreen_visualization(),
+        /// Display the visualizations of the two nodes side by side in the split fullscreen mode.
+        /// The zoom of the visualizations of the same type is synchronized.
+        compare_visualizations((NodeId,NodeId)),
+        /// Leave the side by side comparison of the visualizations.
+        close_visualization_comparison(),
+        /// Pin the visualization of the node to the canvas, enabling it if needed. The pinned
+        /// panel displays the same visualization, but receives its own data, see
+        /// `set_pinned_visualization_data`, and is positioned independently of the node. See the
+        /// `component::vis_panel` module docs.
+        pin_visualization(NodeId),
+        /// Close the pinned visualization panel.
+        unpin_visualization(vis_panel::PanelId),
+        /// Move the pinned visualization panel to the given scene position.
+        set_vis_panel_position((vis_panel::PanelId,Vector2)),
 
 
         // === Scene Navigation ===
@@ -445,11 +603,181 @@ ensogl::define_endpoints! {
         /// Can be used, e.g., if there is a fullscreen visualisation active, or navigation should
         ///only work for a selected visualisation.
         set_navigator_disabled(bool),
+        /// Inform the graph editor that a text field outside of it, like the command palette query,
+        /// has the keyboard focus. The graph editor is then in the `Edit` interaction mode, so the
+        /// shortcuts like removing the selected nodes with backspace do not interfere with typing.
+        set_external_text_focus(bool),
+        /// Make the graph read-only, e.g. while the connection to the backend is lost. The graph
+        /// editor is then in the `ReadOnly` interaction mode: the nodes cannot be moved, connected
+        /// or edited, and the node being edited stops being edited.
+        set_read_only(bool),
+        /// Present the graph, e.g. during a demo. The graph editor is then in the `Present`
+        /// interaction mode: the graph is read-only like with `set_read_only`, and the shortcuts
+        /// are limited to entering the nodes and toggling the visualizations.
+        set_presentation_mode(bool),
+        /// Navigate with the mouse wheel and the trackpad scroll. The vertical scroll pans
+        /// vertically, the scroll with shift pans horizontally, and the scroll with control or
+        /// command zooms towards the cursor. When disabled, the scene navigator handles the wheel.
+        set_scroll_navigation_enabled(bool),
+        /// Smoothly move the camera, so its center is at the given scene position and its zoom
+        /// is the given one, over the given number of milliseconds. The movement is interrupted
+        /// as soon as the user pans or zooms the scene.
+        set_camera_target((Vector2,f32,f32)),
+        /// Set the easing of the camera movements started by `set_camera_target`.
+        set_camera_easing(FlightEasing),
+        /// Move the camera, so all the nodes are visible.
+        zoom_to_fit(),
+        /// Split the workspace into two viewports displaying distant parts of the graph at once,
+        /// each with its own camera. See the `split_view` module docs.
+        enable_split_view(),
+        /// Join the split workspace back into a single viewport.
+        disable_split_view(),
+        /// Toggle splitting the workspace.
+        toggle_split_view(),
+
+
+        // === Connected Components ===
+
+        /// Select all the nodes of the connected component containing the given node, deselecting
+        /// the other ones.
+        select_component_of(NodeId),
+        /// Move the camera, so all the nodes of the connected component containing the given node
+        /// are visible.
+        zoom_to_component(NodeId),
+
+
+        // === Accessibility ===
+
+        /// Move the keyboard focus to the next node in the reading order: top to bottom, then
+        /// left to right. The keyboard focus is independent of the selection. See the
+        /// `accessibility` module docs to learn more.
+        focus_next_node(),
+        /// Move the keyboard focus to the previous node in the reading order.
+        focus_previous_node(),
+        /// Move the keyboard focus to the next input port of the focused node.
+        focus_next_port(),
+        /// Move the keyboard focus to the previous input port of the focused node.
+        focus_previous_port(),
+        /// Move the keyboard focus to the given node.
+        focus_node(NodeId),
+        /// Remove the keyboard focus.
+        clear_focus(),
+
+
+        // === Keyboard Connect ===
+
+        /// Enter the keyboard connect mode with the last selected node as the source of the new
+        /// connection. See the `keyboard_connect` module docs to learn more.
+        start_keyboard_connect(),
+        /// Choose the next target port in the keyboard connect mode.
+        keyboard_connect_next_port(),
+        /// Choose the previous target port in the keyboard connect mode.
+        keyboard_connect_previous_port(),
+        /// Connect the source node to the chosen target port and leave the keyboard connect mode.
+        confirm_keyboard_connect(),
+        /// Leave the keyboard connect mode without connecting anything.
+        cancel_keyboard_connect(),
+
+
+        // === Context Menu ===
+
+        /// Add the entry with the given label to the context menus of the given kind of target.
+        /// Choosing it is reported by the `context_menu_action` output.
+        register_context_menu_entry((context_menu::TargetKind,ImString)),
+        hide_context_menu(),
 
 
         // === Modes ===
 
         toggle_profiling_mode(),
+        /// Show the profiling heatmap, tinting the nodes by their relative execution time and
+        /// displaying the time in a badge, without leaving the normal view mode.
+        show_profiling_heatmap(),
+        /// Hide the profiling heatmap.
+        hide_profiling_heatmap(),
+        /// Toggle the profiling heatmap.
+        toggle_profiling_heatmap(),
+        /// Show the overlay numbering the nodes according to their execution order.
+        show_execution_order(),
+        /// Hide the execution order overlay.
+        hide_execution_order(),
+        /// Toggle the execution order overlay.
+        toggle_execution_order(),
+        /// Fade the edges whose both endpoints are off-screen, displaying small stubs at the
+        /// screen border instead.
+        enable_edge_declutter(),
+        /// Display all the edges normally.
+        disable_edge_declutter(),
+        /// Toggle the edge de-clutter mode.
+        toggle_edge_declutter(),
+        /// Display the guides when the dragged node is aligned with other nodes. See the
+        /// `alignment` module docs. Enabled by default.
+        enable_alignment_guides(),
+        /// Do not display the alignment guides.
+        disable_alignment_guides(),
+        /// Toggle the alignment guides.
+        toggle_alignment_guides(),
+        /// Detach the expensive parts of the nodes lying far outside of the screen, and the edges
+        /// connecting only such nodes. See the `culling` module docs. Enabled by default.
+        enable_culling(),
+        /// Display all the nodes and edges fully.
+        disable_culling(),
+        /// Toggle the viewport culling.
+        toggle_culling(),
+        /// Move the node connected by the user to a tidy position below its source. See the
+        /// `tidy` module docs.
+        enable_tidy_as_you_build(),
+        /// Leave the connected nodes where they are.
+        disable_tidy_as_you_build(),
+        /// Toggle the tidy-as-you-build mode.
+        toggle_tidy_as_you_build(),
+
+
+        // === Ports ===
+
+        /// Set the magnification of the hovered ports. The hovered port and its hover area grow,
+        /// and while an edge is dragged, the hover areas of all input ports grow, so the edge snaps
+        /// to the ports from further away. The scale of `1.0` disables the magnification.
+        set_port_hover_scale (f32),
+
+
+        // === Type Coloring ===
+
+        /// Set the custom color of the type, overriding the color derived from its name. `None`
+        /// removes the custom color.
+        set_type_color ((Type,Option<color::Lcha>)),
+        /// Color the types with the palette distinguishable under color vision deficiencies.
+        enable_color_blind_safe_types(),
+        /// Color the types with the standard palette.
+        disable_color_blind_safe_types(),
+        /// Toggle the color-blind-safe palette of type colors.
+        toggle_color_blind_safe_types(),
+
+
+        // === Node Color Override ===
+
+        /// Set the color of the node chosen by the user or the controller, e.g. to mark the
+        /// outputs, groups, or bookmarked nodes. The color is blended with the node background and
+        /// the type colors of its output ports. `None` removes the override.
+        set_node_color_override ((NodeId,Option<color::Lcha>)),
+        /// Blend the colors of the edges with the color overrides of their source nodes.
+        enable_edge_color_inheritance(),
+        /// Color the edges by their types only.
+        disable_edge_color_inheritance(),
+        /// Toggle the inheritance of the node color overrides by the edges.
+        toggle_edge_color_inheritance(),
+
+
+        // === Data Pulse ===
+
+        /// Play the data pulse on the output edges of the nodes receiving new values, that is
+        /// visualization data or the completed execution status. The pulse travels from the source
+        /// to the target of the edge, visualizing the data flow direction.
+        enable_data_pulse(),
+        /// Stop playing the data pulse on the edges.
+        disable_data_pulse(),
+        /// Toggle the data pulse on the edges.
+        toggle_data_pulse(),
 
 
         // === Debug ===
@@ -460,6 +788,13 @@ ensogl::define_endpoints! {
         debug_pop_breadcrumb(),
         /// Set a test visualization data for the selected nodes. Useful for testing visualizations during their development.
         debug_set_test_visualization_data_for_selected_node(),
+        /// Show the overlay displaying the live counters of the graph editor state and its recent
+        /// output events. See the `debug` module docs.
+        show_debug_overlay(),
+        /// Hide the debug overlay.
+        hide_debug_overlay(),
+        /// Toggle the debug overlay.
+        toggle_debug_overlay(),
 
 
         // === VCS Status ===
@@ -467,6 +802,31 @@ ensogl::define_endpoints! {
         set_node_vcs_status     ((NodeId,Option<node::vcs::Status>)),
 
 
+        // === Node Actions ===
+
+        /// Set the freeze state of the node, e.g. when restoring it from the metadata.
+        set_node_frozen  ((NodeId,bool)),
+        /// Set the skip state of the node, e.g. when restoring it from the metadata.
+        set_node_skipped ((NodeId,bool)),
+        /// Configure which actions are available in the node's action bar.
+        set_node_actions ((NodeId,node::action_bar::Actions)),
+        /// Request the recomputation of the node. The node displays a spinner until its new value
+        /// or error arrives. See `node_recompute_requested`.
+        recompute_node   (NodeId),
+
+
+        // === Masked Ports ===
+
+        /// Mask the code of the node's input port, for example because it contains credentials.
+        /// The masked code is not displayed, and the node cannot be edited until it is revealed.
+        set_port_masked                       ((NodeId,span_tree::Crumbs,bool)),
+        /// Reveal the code of the node's masked ports until its edit mode ends. See
+        /// `node_masked_ports_revealed`.
+        reveal_masked_ports                   (NodeId),
+        /// Reveal the code of the masked ports of all selected nodes.
+        reveal_masked_ports_of_selected_nodes (),
+
+
         set_detached_edge_targets    (EdgeEndpoint),
         set_detached_edge_sources    (EdgeEndpoint),
         set_edge_source              ((EdgeId,EdgeEndpoint)),
@@ -485,15 +845,42 @@ ensogl::define_endpoints! {
         edit_node                    (NodeId),
         collapse_nodes               ((Vec<NodeId>,NodeId)),
         set_node_expression          ((NodeId,node::Expression)),
+        /// Temporarily display the expression in the node, without committing it, e.g. to
+        /// preview the suggestion hovered in the searcher. The preview is not reported by the
+        /// `node_expression_set` output.
+        preview_node_expression      ((NodeId,node::Expression)),
+        /// Restore the expression of the node replaced by `preview_node_expression`. The preview
+        /// is also cleared when the node editing finishes.
+        clear_expression_preview     (NodeId),
         set_node_comment             ((NodeId,node::Comment)),
+        /// Begin a batch of inputs. See `GraphEditor::batch`, which should be preferred over
+        /// emitting this input directly.
+        begin_batch                  (),
+        /// End the batch begun by `begin_batch`.
+        end_batch                    (),
         set_node_position            ((NodeId,Vector2)),
+        /// Pin or unpin the node. Pinned nodes cannot be dragged, but can still be moved with
+        /// `set_node_position`.
+        set_node_pinned              ((NodeId,bool)),
         set_expression_usage_type    ((NodeId,ast::Id,Option<Type>)),
+        /// Set the documentation of the argument of the input port, displayed in the port
+        /// tooltip. An empty string removes the documentation.
+        set_port_documentation       ((NodeId,span_tree::Crumbs,String)),
+        /// Set the method called by the expression. Required for entering the node with this
+        /// expression, see `call_entered`.
         set_method_pointer           ((ast::Id,Option<MethodPointer>)),
         cycle_visualization          (NodeId),
         set_visualization            ((NodeId,Option<visualization::Path>)),
         register_visualization       (Option<visualization::Definition>),
         set_visualization_data       ((NodeId,visualization::Data)),
         set_error_visualization_data ((NodeId,visualization::Data)),
+        /// Pass the data to the pinned visualization panels of the node. The data is attached as
+        /// requested by the `pinned_visualization_changed` output.
+        set_pinned_visualization_data ((NodeId,visualization::Data)),
+        /// Acknowledge the last preprocessor change of the node's visualization. `true` means the
+        /// preprocessor was accepted and the visualization should wait for the new data, `false`
+        /// means it was rejected.
+        acknowledge_visualization_preprocessor ((NodeId,bool)),
         enable_visualization         (NodeId),
         disable_visualization        (NodeId),
 
@@ -551,20 +938,96 @@ ensogl::define_endpoints! {
 
         node_added                (NodeId),
         node_removed              (NodeId),
-        nodes_collapsed           ((Vec<NodeId>,NodeId)),
+        /// Emitted after the selected nodes were collapsed into a single node. See the docs of
+        /// `NodeCollapse` to learn more.
+        nodes_collapsed           (NodeCollapse),
+        /// Emitted after the node was swapped with its neighbor in the dataflow chain. The edges
+        /// are already rewired in the view.
+        node_reordered            (NodeReorder),
+        /// Emitted after the edge was split by a double-click into two edges joined by a new
+        /// pass-through node placed at the click position: the split edge, the new node, the edge
+        /// going into the node, and the edge going out of it. The split edge is already removed
+        /// and the disconnection was reported by the first press of the double-click. The new
+        /// edges are not reported by the edge outputs, as the controller is expected to insert
+        /// the node and the connections at once.
+        edge_split                ((EdgeId,NodeId,EdgeId,EdgeId)),
+        /// Emitted periodically, but only if the layout of nodes (positions or visualizations)
+        /// changed since the last emission. See `set_layout_flush_interval`.
+        layout_flush_requested    (GraphLayoutDescription),
+        /// Emitted once the outermost batch ends with the consolidated changes made during the
+        /// batch, see `GraphEditor::batch`.
+        batch_finished            (batch::Summary),
+        /// Whether a batch of inputs is in progress, see `GraphEditor::batch`.
+        batching                  (bool),
+        /// Emitted once after applying all the changes of `GraphEditor::apply_graph_diff`.
+        graph_synchronized        (diff::GraphSynchronized),
+        /// Whether `GraphEditor::apply_graph_diff` is in progress. The outputs emitted meanwhile
+        /// only reflect the applied diff and should not be reported back to the controller.
+        synchronizing             (bool),
         node_hovered              (Option<Switch<NodeId>>),
         node_selected             (NodeId),
         node_deselected           (NodeId),
+        /// Emitted when the edge was selected with the area selection in the edge area selection
+        /// mode, see the `enable_edge_area_select` input.
+        edge_selected             (EdgeId),
+        edge_deselected           (EdgeId),
+        edge_area_select_enabled  (bool),
+        /// Emitted with all the selected nodes, in the order of selection, whenever the selection
+        /// changed. The bulk selection inputs, like `set_selection`, emit it once per change.
+        selection_changed         (Vec<NodeId>),
         node_position_set         ((NodeId,Vector2)),
         node_position_set_batched ((NodeId,Vector2)),
+        /// Emitted when the node was pinned or unpinned, so the state can be persisted.
+        node_pinned               ((NodeId,bool)),
         node_expression_set       ((NodeId,String)),
         node_comment_set          ((NodeId,String)),
+        /// Emitted when any of the cursors in the edited node expression moved. Contains the
+        /// locations of all the cursors.
+        expression_cursor_moved   ((NodeId,Vec<text::Location>)),
         node_entered              (NodeId),
+        /// Emitted together with `node_entered` when the call of the entered node is known, that
+        /// is, when its method pointer was set with `set_method_pointer`.
+        call_entered              (LocalCall),
         node_exited               (),
+        /// Whether the animated transition between the graph levels is being played.
+        level_transition_running  (bool),
+        /// Emitted in the middle of the transition started by entering or exiting a node, when
+        /// the veil fully covers the scene, or right after `node_entered` and `node_exited` if the
+        /// transitions are disabled. The controllers may defer displaying the graph of the new
+        /// level until then, so it is swapped unnoticed beneath the veil.
+        level_transition_covered  (),
+        /// Emitted when the veil of the transition is fully revealed, or right after
+        /// `level_transition_covered` if the transitions are disabled.
+        level_transition_finished (),
+        /// The calls of the entered nodes, from the outermost one, consistent with the displayed
+        /// breadcrumbs.
+        stack_changed             (Vec<LocalCall>),
         node_editing_started      (NodeId),
         node_editing_finished     (NodeId),
         node_action_freeze        ((NodeId,bool)),
         node_action_skip          ((NodeId,bool)),
+        /// Emitted when the recomputation of the node was requested, either by the node's action
+        /// bar or by the `recompute_node` input.
+        node_recompute_requested  (NodeId),
+        /// Emitted when the masked ports of the node were revealed. Meant for auditing.
+        node_masked_ports_revealed (NodeId),
+        /// Emitted when the badge with the given identifier was pressed.
+        node_badge_pressed         (NodeId,ImString),
+        /// Emitted when the active state of the toggleable badge with the given identifier was
+        /// switched by a press.
+        node_badge_toggled         (NodeId,ImString,bool),
+        /// Emitted when the expected argument port was pressed in the argument editing mode. The
+        /// crumbs point to the expected argument in the input span tree.
+        node_argument_added        ((NodeId,span_tree::Crumbs)),
+        /// Emitted when the remove button of the optional argument was pressed in the argument
+        /// editing mode. The crumbs point to the argument in the input span tree.
+        node_argument_removed      ((NodeId,span_tree::Crumbs)),
+        /// Emitted with the new expression code of the node when its numeric literal port was
+        /// dragged in the argument editing mode. The expression is set in the node already, the
+        /// event is meant to commit it, so the dataflow is recomputed while dragging. It is
+        /// throttled, see the `literal_adjusted` output of the node input area.
+        node_literal_adjusted      ((NodeId,String)),
+        argument_editing           (bool),
         node_edit_mode            (bool),
         nodes_labels_visible      (bool),
 
@@ -574,9 +1037,33 @@ ensogl::define_endpoints! {
         visualization_shown                     (NodeId,visualization::Metadata),
         visualization_hidden                    (NodeId),
         visualization_fullscreen                (Option<NodeId>),
+        /// Emitted when the visualization of the node was displayed in fullscreen.
+        visualization_fullscreen_entered        (NodeId),
+        /// Emitted when the visualization of the node left the fullscreen mode.
+        visualization_fullscreen_exited         (NodeId),
         is_fs_visualization_displayed           (bool),
+        /// The nodes whose visualizations are compared side by side, the left one first.
+        visualizations_compared                 (Option<(NodeId,NodeId)>),
+        is_vis_comparison_displayed             (bool),
         visualization_preprocessor_changed      ((NodeId,PreprocessorConfiguration)),
+        /// Emitted when the user selected some of the data items displayed by the node's
+        /// visualization (e.g. with the lasso of the scatter plot), with the indices of the items.
+        visualization_selection_changed         ((NodeId,Vec<usize>)),
+        /// Emitted when the visualization of the node was pinned to the canvas.
+        visualization_pinned                    ((vis_panel::PanelId,NodeId)),
+        /// Emitted when the pinned visualization panel was closed, by the user or because its node
+        /// was removed.
+        visualization_unpinned                  (vis_panel::PanelId),
+        /// Emitted when the pinned visualization panel was dragged by the user.
+        vis_panel_moved                         ((vis_panel::PanelId,Vector2)),
+        /// The visualization the pinned panels of the node expect the data of, or `None` when the
+        /// last panel of the node was closed. The panels should have their own attachment, kept
+        /// when the visualization of the node is hidden.
+        pinned_visualization_changed            ((NodeId,Option<visualization::Metadata>)),
         visualization_registry_reload_requested (),
+        /// Emitted when the visualization of the node was throttled or suspended because it takes
+        /// too long to update or creates too many DOM nodes.
+        visualization_misbehaving               ((NodeId,visualization::container::watchdog::Report)),
 
         on_visualization_select     (Switch<NodeId>),
         some_visualisation_selected (bool),
@@ -585,9 +1072,92 @@ ensogl::define_endpoints! {
         node_editing (bool),
 
         view_mode (view::Mode),
+        /// The current interaction mode, determining the available shortcuts.
+        interaction_mode (interaction::Mode),
+        in_navigate_mode (bool),
+        in_edit_mode     (bool),
+        in_connect_mode  (bool),
+        in_keyboard_connect_mode (bool),
+        in_read_only_mode        (bool),
+        in_present_mode          (bool),
+        /// Whether the graph is read-only. See the `set_read_only` and `set_presentation_mode`
+        /// inputs.
+        read_only (bool),
+        /// Whether the graph is presented. See the `set_presentation_mode` input.
+        presenting (bool),
+        /// Whether the overlay numbering the nodes according to their execution order is visible.
+        execution_order_visible (bool),
+        /// Whether the profiling heatmap is visible.
+        profiling_heatmap_visible (bool),
+        /// Whether the debug overlay is visible.
+        debug_overlay_visible (bool),
+        /// Whether the edges with no visible endpoints are faded out.
+        edge_declutter_enabled (bool),
+        /// Whether the alignment guides are displayed while dragging nodes.
+        alignment_guides_enabled (bool),
+        /// Whether the nodes and edges far outside of the screen are culled.
+        culling_enabled (bool),
+        /// Whether the nodes connected by the user are moved to tidy positions.
+        tidy_as_you_build_enabled (bool),
+        /// Whether the lite rendering mode is active. It is enabled automatically when the frames
+        /// take too long to render, and makes the rendering cheaper at the cost of the visuals.
+        lite_mode (bool),
+        /// Whether the nodes are displayed simplified, because the lite mode is active and they are
+        /// zoomed out too much to be readable.
+        nodes_simplified (bool),
+        /// The magnification of the hovered ports, see the `set_port_hover_scale` input.
+        port_hover_scale (f32),
+        /// Emitted on the key user interactions, like connecting an edge, so an audio or haptic
+        /// feedback can be attached to them. See the `feedback` module docs.
+        feedback (feedback::Event),
+        /// The input ports connected with the edges of unknown type: neither the type of the port,
+        /// nor the type of the edge source are inferred yet. Such edges are displayed dashed and
+        /// the ports hatched. Emitted whenever the set of these ports changes.
+        unresolved_types_changed (Vec<(NodeId,span_tree::Crumbs)>),
+        /// All the connections of the graph, see `GraphEditorModel::connections`. Emitted at most
+        /// once per frame, whenever an edge was attached, detached, or removed, and the set of the
+        /// connections has changed.
+        connections_changed (Vec<(EdgeEndpoint,EdgeEndpoint)>),
+        /// Emitted when an entry of the context menu was chosen. The built-in actions are already
+        /// performed by the graph editor, except `Paste`, which is left to the application, as
+        /// are the custom entries. The `SplitEdge` action is reported by `edge_split` as well.
+        context_menu_action  ((context_menu::Target,context_menu::Action)),
+        context_menu_visible (bool),
+        /// Whether the types are colored with the color-blind-safe palette.
+        color_blind_safe_types (bool),
+        /// Whether the edges inherit the color overrides of their source nodes.
+        edge_color_inheritance_enabled (bool),
+        /// Whether the data pulse is played on the edges.
+        data_pulse_enabled (bool),
+        /// Emitted after the mapping of types to colors was changed, once all the edges and ports
+        /// were re-colored.
+        type_colors_changed (),
 
         navigator_active (bool),
+        scroll_navigation_enabled (bool),
+        /// The position of the camera center in the scene and the camera zoom, of the hovered
+        /// viewport when the workspace is split. Emitted on every camera change, including the
+        /// animated ones.
+        camera_moved     ((Vector2,f32)),
+        split_view_enabled (bool),
+        /// The viewport of the split workspace under the mouse. Always the primary one when the
+        /// workspace is not split.
+        hovered_viewport (split_view::Viewport),
+        /// The connected components of the graph, as returned by
+        /// [`GraphEditorModel::connected_components`]. Emitted when the membership of the nodes
+        /// in the components changes.
+        connected_components (Rc<Vec<Vec<NodeId>>>),
+        /// The graph element having the keyboard focus.
+        focus            (Option<accessibility::Focus>),
+        /// The source node of the connection made in the keyboard connect mode. `None` outside of
+        /// the mode.
+        keyboard_connect_source (Option<NodeId>),
+        /// The target port chosen in the keyboard connect mode.
+        keyboard_connect_target (Option<EdgeEndpoint>),
         file_dropped     (drop::File,Vector2<f32>),
+        /// A line of a plain-text snippet dropped on the canvas, with the position of the node
+        /// which should be created for it. Multi-line snippets emit one event per line.
+        text_dropped     (String,Vector2<f32>),
 
         default_x_gap_between_nodes (f32),
         default_y_gap_between_nodes (f32),
@@ -614,20 +1184,21 @@ pub struct Node {
     pub view      : component::Node,
     pub in_edges  : SharedHashSet<EdgeId>,
     pub out_edges : SharedHashSet<EdgeId>,
+    id            : NodeId,
 }
 
 #[derive(Clone,CloneRef,Copy,Debug,Default,Eq,From,Hash,Into,PartialEq,Ord,PartialOrd)]
 pub struct NodeId(pub Id);
 
 impl Node {
-    pub fn new(view:component::Node) -> Self {
+    pub fn new(view:component::Node, id:NodeId) -> Self {
         let in_edges  = default();
         let out_edges = default();
-        Self {view,in_edges,out_edges}
+        Self {view,in_edges,out_edges,id}
     }
 
     pub fn id(&self) -> NodeId {
-        self.view.id().into()
+        self.id
     }
 
     /// Return all edges connected to this node. Ingoing and outgoing both.
@@ -661,20 +1232,21 @@ pub struct Edge {
     pub view : component::Edge,
     source   : Rc<RefCell<Option<EdgeEndpoint>>>,
     target   : Rc<RefCell<Option<EdgeEndpoint>>>,
+    id       : EdgeId,
 }
 
 #[derive(Clone,CloneRef,Copy,Debug,Default,Eq,From,Hash,Into,PartialEq)]
 pub struct EdgeId(pub Id);
 
 impl Edge {
-    pub fn new(view:component::Edge) -> Self {
+    pub fn new(view:component::Edge, id:EdgeId) -> Self {
         let source = default();
         let target = default();
-        Self {view,source,target}
+        Self {view,source,target,id}
     }
 
     pub fn id(&self) -> EdgeId {
-        self.view.id().into()
+        self.id
     }
 
     pub fn target(&self) -> Option<EdgeEndpoint> {
@@ -834,7 +1406,7 @@ pub struct LocalCall {
 // === EdgeEndpoint ===
 // ==================
 
-#[derive(Clone,CloneRef,Debug,Default)]
+#[derive(Clone,CloneRef,Debug,Default,Eq,Hash,PartialEq)]
 pub struct EdgeEndpoint {
     pub node_id : NodeId,
     pub port    : span_tree::Crumbs,
@@ -853,25 +1425,173 @@ impl EdgeEndpoint {
 
 
 
+// ===================
+// === NodeReorder ===
+// ===================
+
+/// Direction in which a node is moved along its dataflow chain.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+#[allow(missing_docs)]
+pub enum ReorderDirection { Upstream, Downstream }
+
+/// A request to swap a node with its neighbor in a linear dataflow chain. The view rewires the
+/// edges immediately; the controller is expected to apply the corresponding refactoring to the
+/// code.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub struct NodeReorder {
+    /// The moved node.
+    pub node      : NodeId,
+    /// The node which swapped places with the moved one.
+    pub neighbor  : NodeId,
+    /// The direction in which `node` was moved.
+    pub direction : ReorderDirection,
+    /// The edges going into, between, and out of the swapped nodes, already rewired.
+    pub edges     : [EdgeId;3],
+}
+
+
+
+// ====================
+// === NodeCollapse ===
+// ====================
+
+/// An edge crossing the boundary of a set of collapsed nodes.
+#[derive(Clone,Debug,Eq,PartialEq)]
+pub struct CollapseBoundaryEdge {
+    /// The edge, which after collapsing is connected to the replacement node.
+    pub edge     : EdgeId,
+    /// The endpoint of the edge outside of the collapsed nodes. It is left untouched.
+    pub external : EdgeEndpoint,
+    /// The endpoint of the edge inside of the collapsed nodes, that is, where the edge was
+    /// connected to before collapsing.
+    pub internal : EdgeEndpoint,
+}
+
+/// Nodes collapsed into a single replacement node. The view already removed the collapsed nodes
+/// and reconnected the boundary edges to the replacement node; the controller is expected to
+/// extract the collapsed nodes' code into a new function, using the boundary edges to determine
+/// its arguments and its return value.
+#[derive(Clone,Debug,Default,Eq,PartialEq)]
+pub struct NodeCollapse {
+    /// The removed nodes.
+    pub collapsed : Vec<NodeId>,
+    /// The node replacing the collapsed nodes.
+    pub new_node  : NodeId,
+    /// Edges going from the outside into the collapsed nodes. They become the arguments of the
+    /// new function and are now targeting the replacement node.
+    pub inputs    : Vec<CollapseBoundaryEdge>,
+    /// Edges going from the collapsed nodes to the outside. They become the results of the new
+    /// function and are now sourced at the replacement node.
+    pub outputs   : Vec<CollapseBoundaryEdge>,
+}
+
+
+
+// ======================
+// === RemovalPreview ===
+// ======================
+
+/// Nodes and edges affected by a pending removal of nodes.
+#[derive(Clone,Debug,Default)]
+pub struct RemovalPreview {
+    /// Nodes which are going to be removed.
+    pub removed_nodes  : Vec<NodeId>,
+    /// Nodes which are going to lose some of their inputs.
+    pub affected_nodes : Vec<NodeId>,
+    /// Edges which are going to be removed.
+    pub edges          : Vec<EdgeId>,
+}
+
+
+
+// ==============================
+// === GraphLayoutDescription ===
+// ==============================
+
+/// Layout-related state of a single node.
+#[derive(Clone,Debug,PartialEq)]
+pub struct NodeLayout {
+    /// Position of the node in the scene.
+    pub position      : Vector2,
+    /// The path of the enabled visualization, if any.
+    pub visualization : Option<visualization::Path>,
+}
+
+/// Snapshot of the layout-related state of all nodes in the graph, that is everything the
+/// integration layer needs to store in the nodes' metadata.
+#[derive(Clone,Debug,Default,PartialEq)]
+pub struct GraphLayoutDescription {
+    #[allow(missing_docs)]
+    pub nodes : HashMap<NodeId,NodeLayout>,
+}
+
+
+
 // ============
 // === Grid ===
 // ============
 
-/// Defines a snapping grid for nodes. The grid implementation is currently very simple. For each
-/// node, the grid records its position and allows querying for positions close to the recorded
-/// ones.
+/// The anchor of the node aligned by the snapping grid.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum SnapTarget {
+    /// The origin of the node: its left edge horizontally, and its middle vertically.
+    Origin,
+    /// The right edge of the node.
+    RightEdge,
+    /// The vertical line going through the center of the node.
+    VerticalCenter,
+}
+
+/// The origin of the node snapped by the [`Grid`], with the anchors aligned on each axis.
+#[derive(Clone,Copy,Debug,Default,PartialEq)]
+pub struct Snapped {
+    /// The snapped horizontal origin coordinate and the horizontally aligned anchor.
+    pub x : Option<(f32,SnapTarget)>,
+    /// The snapped vertical origin coordinate and the vertically aligned anchor.
+    pub y : Option<(f32,SnapTarget)>,
+}
+
+impl Snapped {
+    /// The snapped origin coordinates, without the aligned anchors.
+    pub fn position(&self) -> Vector2<Option<f32>> {
+        Vector2(self.x.map(|(x,_)| x),self.y.map(|(y,_)| y))
+    }
+
+    /// Check whether the node was snapped on any axis.
+    pub fn is_snapped(&self) -> bool {
+        self.x.is_some() || self.y.is_some()
+    }
+}
+
+/// Defines a snapping grid for nodes. For each node, the grid records its origin, right edge, and
+/// vertical center line, and allows querying for node positions which align the anchors of the
+/// dragged node with the recorded ones of the same kind.
 #[derive(Debug,Clone,Default)]
 pub struct Grid {
-    sorted_xs : Vec<f32>,
-    sorted_ys : Vec<f32>,
+    sorted_xs      : Vec<f32>,
+    sorted_rights  : Vec<f32>,
+    sorted_centers : Vec<f32>,
+    sorted_ys      : Vec<f32>,
 }
 
 impl Grid {
-    /// Query the grid for a close position to the provided using the provided threshold distance.
-    pub fn close_to(&self, position:Vector2<f32>, threshold:f32) -> Vector2<Option<f32>> {
-        let x = Self::axis_close_to(&self.sorted_xs,position.x,threshold);
+    /// Query the grid for a close position of the node of the given `width` placed at the given
+    /// origin `position`, using the provided threshold distance. On each axis, the closest of the
+    /// aligned anchors wins.
+    pub fn close_to(&self, position:Vector2<f32>, width:f32, threshold:f32) -> Snapped {
+        let candidates =
+            [ (SnapTarget::Origin        , &self.sorted_xs      , 0.0)
+            , (SnapTarget::RightEdge     , &self.sorted_rights  , width)
+            , (SnapTarget::VerticalCenter, &self.sorted_centers , width / 2.0)
+            ];
+        let x = candidates.iter().filter_map(|(target,axis,offset)| {
+            let anchor  = position.x + offset;
+            let snapped = Self::axis_close_to(axis,anchor,threshold)?;
+            Some(((snapped - anchor).abs(),(snapped - offset,*target)))
+        }).min_by(|(a,_),(b,_)| a.total_cmp(b)).map(|(_,snapped)| snapped);
         let y = Self::axis_close_to(&self.sorted_ys,position.y,threshold);
-        Vector2(x,y)
+        let y = y.map(|y| (y,SnapTarget::Origin));
+        Snapped {x,y}
     }
 
     fn axis_close_to(axis:&[f32], pos:f32, threshold:f32) -> Option<f32> {
@@ -911,6 +1631,8 @@ pub struct Nodes {
     pub logger   : Logger,
     pub all      : SharedHashMap<NodeId,Node>,
     pub selected : SharedVec<NodeId>,
+    /// Nodes which cannot be dragged. See `set_node_pinned`.
+    pub pinned   : SharedHashSet<NodeId>,
     pub grid     : Rc<RefCell<Grid>>,
 }
 
@@ -926,8 +1648,9 @@ impl Nodes {
         let logger   = Logger::new_sub(logger,"nodes");
         let all      = default();
         let selected = default();
+        let pinned   = default();
         let grid     = default();
-        Self {logger,all,selected,grid}
+        Self {logger,all,selected,pinned,grid}
     }
 
     pub fn insert(&self, node_id:NodeId, node:Node) {
@@ -935,23 +1658,43 @@ impl Nodes {
         self.recompute_grid(default());
     }
 
+    /// Recompute the snapping grid, skipping the positions of the blacklisted nodes. The pinned
+    /// nodes are never skipped, as they are not moving.
     fn recompute_grid(&self, blacklist:HashSet<NodeId>) {
-        let mut sorted_xs = Vec::new();
-        let mut sorted_ys = Vec::new();
+        let mut sorted_xs      = Vec::new();
+        let mut sorted_rights  = Vec::new();
+        let mut sorted_centers = Vec::new();
+        let mut sorted_ys      = Vec::new();
         for (id,node) in &*self.all.raw.borrow() {
-            if !blacklist.contains(id) {
+            if !blacklist.contains(id) || self.pinned.contains(id) {
                 let position = node.position();
+                let width    = node.bounding_box.value().width();
                 sorted_xs.push(position.x);
+                sorted_rights.push(position.x + width);
+                sorted_centers.push(position.x + width / 2.0);
                 sorted_ys.push(position.y);
             }
         }
         sorted_xs.sort_unstable_by(|a,b|a.partial_cmp(b).unwrap());
+        sorted_rights.sort_unstable_by(|a,b|a.partial_cmp(b).unwrap());
+        sorted_centers.sort_unstable_by(|a,b|a.partial_cmp(b).unwrap());
         sorted_ys.sort_unstable_by(|a,b|a.partial_cmp(b).unwrap());
-        *self.grid.borrow_mut() = Grid {sorted_xs,sorted_ys};
+        *self.grid.borrow_mut() = Grid {sorted_xs,sorted_rights,sorted_centers,sorted_ys};
+    }
+
+    /// Check whether the node is pinned and cannot be dragged.
+    pub fn is_pinned(&self, node_id:&NodeId) -> bool {
+        self.pinned.contains(node_id)
+    }
+
+    /// Filter out the pinned nodes.
+    pub fn unpinned(&self, node_ids:&[NodeId]) -> Vec<NodeId> {
+        node_ids.iter().filter(|id| !self.is_pinned(id)).copied().collect()
     }
 
-    pub fn check_grid_magnet(&self, position:Vector2<f32>) -> Vector2<Option<f32>> {
-        self.grid.borrow().close_to(position,SNAP_DISTANCE_THRESHOLD)
+    /// Snap the origin of the dragged node of the given `width` to the grid. See [`Grid`].
+    pub fn check_grid_magnet(&self, position:Vector2<f32>, width:f32) -> Snapped {
+        self.grid.borrow().close_to(position,width,SNAP_DISTANCE_THRESHOLD)
     }
 
     pub fn set_quick_preview(&self, quick:bool) {
@@ -1016,6 +1759,57 @@ impl Nodes {
         let selected = self.selected.raw.as_ref().clone();
         selected.into_inner().into_iter().for_each(|node_id| self.deselect(node_id))
     }
+
+    /// Mark the nodes as selected, appending them to the selection in the given order. The nodes
+    /// already selected keep their place. Unknown nodes are skipped.
+    fn select_many(&self, node_ids:&[NodeId]) -> SelectionChange {
+        let mut selected = Vec::new();
+        for node_id in node_ids {
+            let is_new = !self.is_selected(*node_id) && !selected.contains(node_id);
+            if let Some(node) = self.get_cloned_ref(node_id).filter(|_| is_new) {
+                self.selected.push(*node_id);
+                node.frp.select.emit(());
+                selected.push(*node_id);
+            }
+        }
+        SelectionChange {selected,..default()}
+    }
+
+    /// Mark the nodes as deselected. The nodes which were not selected are skipped.
+    fn deselect_many(&self, node_ids:&[NodeId]) -> SelectionChange {
+        let mut deselected = Vec::new();
+        for node_id in node_ids {
+            if self.is_selected(*node_id) {
+                self.deselect(*node_id);
+                deselected.push(*node_id);
+            }
+        }
+        SelectionChange {deselected,..default()}
+    }
+
+    /// Make the given nodes the only selected ones. See `select_many` for the resulting order.
+    fn set_selection(&self, node_ids:&[NodeId]) -> SelectionChange {
+        let to_deselect = self.all_selected().into_iter().filter(|id| !node_ids.contains(id));
+        let deselected  = self.deselect_many(&to_deselect.collect_vec()).deselected;
+        let selected    = self.select_many(node_ids).selected;
+        SelectionChange {selected,deselected}
+    }
+}
+
+/// The nodes whose selection state was changed by a single bulk selection operation.
+#[derive(Clone,Debug,Default)]
+pub struct SelectionChange {
+    /// Nodes which became selected, in the order of selection.
+    pub selected   : Vec<NodeId>,
+    /// Nodes which are no longer selected.
+    pub deselected : Vec<NodeId>,
+}
+
+impl SelectionChange {
+    /// Check whether the selection was left unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty() && self.deselected.is_empty()
+    }
 }
 
 
@@ -1064,6 +1858,58 @@ impl Edges {
 
 
 
+// ========================
+// === EdgeRefreshQueue ===
+// ========================
+
+/// Edges scheduled to be refreshed. A single event, like dragging a node with many edges, may
+/// request refreshing the same edge many times, while recomputing the edge shape is expensive. The
+/// requests are collected here and flushed once per animation frame, so every edge is redrawn at
+/// most once per frame. See `GraphEditorModel::flush_edge_refresh`.
+#[derive(Clone,CloneRef,Debug,Default)]
+pub struct EdgeRefreshQueue {
+    /// Edges whose position, size, and shape need to be recomputed.
+    layout           : SharedHashSet<EdgeId>,
+    /// Edges whose color needs to be recomputed, with the neutral color to be used.
+    color            : SharedHashMap<EdgeId,color::Lcha>,
+    /// Whether the set of the edges of unknown type might have changed.
+    unresolved_types : Rc<Cell<bool>>,
+    /// Whether the set of the connections might have changed.
+    connections      : Rc<Cell<bool>>,
+}
+
+impl EdgeRefreshQueue {
+    /// Schedule recomputing the position, size, and shape of the edge.
+    pub fn schedule_layout(&self, edge_id:EdgeId) {
+        self.layout.insert(edge_id);
+    }
+
+    /// Schedule recomputing the color of the edge.
+    pub fn schedule_color(&self, edge_id:EdgeId, neutral_color:color::Lcha) {
+        self.color.insert(edge_id,neutral_color);
+        self.schedule_unresolved_types();
+    }
+
+    /// Schedule recomputing the set of the edges of unknown type.
+    pub fn schedule_unresolved_types(&self) {
+        self.unresolved_types.set(true);
+    }
+
+    /// Schedule recomputing the set of the connections.
+    pub fn schedule_connections(&self) {
+        self.connections.set(true);
+    }
+
+    /// Check whether there are no scheduled refreshes.
+    pub fn is_empty(&self) -> bool {
+        let no_colors = self.color.raw.borrow().is_empty();
+        let no_flags  = !self.unresolved_types.get() && !self.connections.get();
+        self.layout.is_empty() && no_colors && no_flags
+    }
+}
+
+
+
 #[derive(Debug,Clone,CloneRef,Default)]
 struct Visualisations {
     /// This keeps track of the currently selected visualisation. There should only ever be one
@@ -1082,26 +1928,32 @@ struct Visualisations {
 #[derive(Debug,CloneRef,Derivative)]
 #[derivative(Clone(bound=""))]
 pub struct TouchNetwork<T:frp::Data> {
-    pub down     : frp::Source<T>,
-    pub up       : frp::Stream<T>,
-    pub is_down  : frp::Stream<bool>,
-    pub selected : frp::Stream<T>
+    pub down        : frp::Source<T>,
+    pub up          : frp::Stream<T>,
+    pub is_down     : frp::Stream<bool>,
+    pub selected    : frp::Stream<T>,
+    /// Whether the last mouse event was caused by the pointer which started the touch. Allows
+    /// ignoring events of other simultaneous touch pointers.
+    pub own_pointer : frp::Stream<bool>,
 }
 
 impl<T:frp::Data> TouchNetwork<T> {
     pub fn new(network:&frp::Network, mouse:&frp::io::Mouse) -> Self {
         frp::extend! { network
             down          <- source::<T> ();
-            is_down       <- bool(&mouse.up_primary,&down);
+            down_pointer  <- mouse.pointer_id.sample(&down);
+            own_pointer   <- all_with(&mouse.pointer_id,&down_pointer,|t,s| t == s);
+            own_up        <- mouse.up_primary.gate(&own_pointer);
+            is_down       <- bool(&own_up,&down);
             was_down      <- is_down.previous();
-            mouse_up      <- mouse.up_primary.gate(&was_down);
+            mouse_up      <- own_up.gate(&was_down);
             pos_on_down   <- mouse.position.sample(&down);
             pos_on_up     <- mouse.position.sample(&mouse_up);
             should_select <- pos_on_up.map3(&pos_on_down,&mouse.distance,Self::check);
             up            <- down.sample(&mouse_up);
             selected      <- up.gate(&should_select);
         }
-        Self {down,up,is_down,selected}
+        Self {down,up,is_down,selected,own_pointer}
     }
 
     #[allow(clippy::trivially_copy_pass_by_ref)]
@@ -1169,35 +2021,44 @@ struct NodeCreationContext<'a> {
 }
 
 impl GraphEditorModelWithNetwork {
-    pub fn new(app:&Application, cursor:cursor::Cursor, frp:&Frp) -> Self {
+    pub fn new
+    (app:&Application, cursor:cursor::Cursor, frp:&Frp, id_allocation:IdAllocation) -> Self {
         let network = frp.network.clone_ref(); // FIXME make weak
-        let model   = GraphEditorModel::new(app,cursor,frp);
+        let model   = GraphEditorModel::new(app,cursor,frp,id_allocation);
         Self {model,network}
     }
 
     fn new_node(&self, ctx:&NodeCreationContext) -> NodeId {
         let view    = component::Node::new(&self.app,self.vis_registry.clone_ref());
-        let node    = Node::new(view);
-        let node_id = node.id();
+        let node_id = self.ids.node_id(&view);
+        let node    = Node::new(view,node_id);
         self.add_child(&node);
+        self.declutter.set_node_position(node_id,node.position().xy());
+        self.culling.add_node(node_id,node.position().xy());
 
         let touch      = &self.touch_state;
         let model      = &self.model;
+        let logger     = &self.logger;
         let NodeCreationContext {pointer_style,tooltip_update,output_press,input_press,output} = ctx;
 
         frp::new_bridge_network! { [self.network, node.frp.network] graph_node_bridge
             eval_ node.frp.background_press(touch.nodes.down.emit(node_id));
 
-            hovered <- node.output.hover.map (move |t| Some(Switch::new(node_id,*t)));
+            hovered <- node.output.hover.on_change().map(move |t| Some(Switch::new(node_id,*t)));
             output.source.node_hovered <+ hovered;
 
             eval node.comment ([model](comment)
                 model.frp.source.node_comment_set.emit((node_id,comment.clone()))
             );
+            output.source.expression_cursor_moved <+ node.expression_cursors.map(
+                move |cursors| (node_id,cursors.clone())
+            );
 
             node.set_output_expression_visibility <+ self.frp.nodes_labels_visible;
+            node.model.input.set_argument_editing <+ self.frp.argument_editing;
 
             eval node.frp.tooltip ((tooltip) tooltip_update.emit(tooltip));
+            eval node.frp.port_tooltip ([model](tooltip) model.set_port_tooltip(node_id,tooltip));
             eval node.model.input.frp.pointer_style ((style) pointer_style.emit(style));
             eval node.model.output.frp.on_port_press ([output_press](crumbs){
                 let target = EdgeEndpoint::new(node_id,crumbs.clone());
@@ -1209,6 +2070,16 @@ impl GraphEditorModelWithNetwork {
                 input_press.emit(target);
             );
 
+            eval node.model.input.frp.on_port_argument_add ((crumbs)
+                output.source.node_argument_added.emit((node_id,crumbs.clone())));
+            eval node.model.input.frp.on_port_argument_remove ((crumbs)
+                output.source.node_argument_removed.emit((node_id,crumbs.clone())));
+            eval node.model.input.frp.literal_adjusted ([model](code) {
+                let expression = node::Expression::new_plain(code);
+                model.frp.set_node_expression.emit((node_id,expression));
+                model.frp.source.node_literal_adjusted.emit((node_id,code.clone()));
+            });
+
             eval node.model.input.frp.on_port_hover ([model](t) {
                 let crumbs = t.on();
                 let target = crumbs.map(|c| EdgeEndpoint::new(node_id,c.clone()));
@@ -1245,6 +2116,14 @@ impl GraphEditorModelWithNetwork {
                 output.source.node_action_freeze.emit((node_id,*is_frozen));
             });
 
+            eval_ node.view.frp.recompute (output.source.node_recompute_requested.emit(node_id));
+            eval_ node.view.frp.masked_ports_revealed
+                (output.source.node_masked_ports_revealed.emit(node_id));
+            eval node.view.frp.badge_pressed ((id)
+                output.source.node_badge_pressed.emit((node_id,id.clone())));
+            eval node.view.frp.badge_toggled (((id,active))
+                output.source.node_badge_toggled.emit((node_id,id.clone(),*active)));
+
             let set_node_disabled = &node.frp.set_disabled;
             eval node.view.frp.skip ([set_node_disabled,output](is_skipped) {
                 output.source.node_action_skip.emit((node_id,*is_skipped));
@@ -1261,9 +2140,17 @@ impl GraphEditorModelWithNetwork {
 
             selected    <- vis_is_selected.on_true();
             deselected  <- vis_is_selected.on_false();
+            let vis_frp = &node.model.visualization.frp;
+            valid_preprocessor   <- vis_frp.preprocessor.filter(|p| p.is_valid());
+            invalid_preprocessor <- vis_frp.preprocessor.filter(|p| !p.is_valid());
             output.source.visualization_preprocessor_changed <+
-                node.model.visualization.frp.preprocessor.map(move |preprocessor|
-                    (node_id,preprocessor.clone()));
+                valid_preprocessor.map(move |preprocessor| (node_id,preprocessor.clone()));
+            eval invalid_preprocessor ([logger,vis_frp](preprocessor) {
+                warning!(logger,"Rejected invalid visualization preprocessor {preprocessor:?}.");
+                vis_frp.acknowledge_preprocessor.emit(false);
+            });
+            output.source.visualization_selection_changed <+
+                vis_frp.selection.map(move |indices| (node_id,indices.clone()));
             output.source.on_visualization_select <+ selected.constant(Switch::On(node_id));
             output.source.on_visualization_select <+ deselected.constant(Switch::Off(node_id));
 
@@ -1286,6 +2173,9 @@ impl GraphEditorModelWithNetwork {
                 move |_init, is_enabled, path| (node_id, is_enabled.and_option(path.clone()))
             );
             output.source.enabled_visualization_path <+ enabled_visualization_path;
+            output.source.visualization_misbehaving  <+ node.visualization_misbehaving.map(
+                move |report| (node_id,*report)
+            );
 
 
             // === View Mode ===
@@ -1293,6 +2183,17 @@ impl GraphEditorModelWithNetwork {
             node.set_view_mode <+ self.model.frp.view_mode;
 
 
+            // === Lite Mode ===
+
+            node.set_lite_mode  <+ self.model.frp.lite_mode;
+            node.set_simplified <+ self.model.frp.nodes_simplified;
+
+
+            // === Ports ===
+
+            node.set_port_hover_scale <+ self.model.frp.port_hover_scale;
+
+
             // === Profiling ===
 
             let profiling_min_duration              = &self.model.profiling_statuses.min_duration;
@@ -1301,9 +2202,14 @@ impl GraphEditorModelWithNetwork {
             let profiling_max_duration              = &self.model.profiling_statuses.max_duration;
             node.set_profiling_max_global_duration <+ self.model.profiling_statuses.max_duration;
             node.set_profiling_max_global_duration(profiling_max_duration.value());
+            node.set_profiling_heatmap <+ self.model.frp.profiling_heatmap_visible;
         }
 
         node.set_view_mode(self.model.frp.view_mode.value());
+        node.set_profiling_heatmap(self.model.frp.profiling_heatmap_visible.value());
+        node.set_lite_mode(self.model.frp.lite_mode.value());
+        node.set_simplified(self.model.frp.nodes_simplified.value());
+        node.set_port_hover_scale(self.model.frp.port_hover_scale.value());
         let initial_metadata = visualization::Metadata {
             preprocessor : node.model.visualization.frp.preprocessor.value(),
         };
@@ -1338,18 +2244,24 @@ impl GraphEditorModelWithNetwork {
     , edge_over  : &frp::Source<EdgeId>
     , edge_out   : &frp::Source<EdgeId>
     ) -> EdgeId {
-        let edge    = Edge::new(component::Edge::new(&self.app));
-        let edge_id = edge.id();
+        let view    = component::Edge::new(&self.app);
+        let edge_id = self.ids.edge_id(&view);
+        let edge    = Edge::new(view,edge_id);
         self.add_child(&edge);
         self.edges.insert(edge.clone_ref());
 
-        let network = &self.network;
+        let network           = &self.network;
+        let edge_handle_press = &self.edge_handle_press;
 
         frp::extend! { network
             eval_ edge.view.frp.shape_events.mouse_down ( edge_click.emit(edge_id));
+            eval  edge.view.frp.handle_pressed ((port) edge_handle_press.emit((edge_id,*port)));
             eval_ edge.view.frp.shape_events.mouse_over ( edge_over.emit(edge_id));
             eval_ edge.view.frp.shape_events.mouse_out ( edge_out.emit(edge_id));
+            edge.view.frp.set_lite_mode <+ self.model.frp.lite_mode;
+            edge.view.frp.redraw        <+ self.model.frp.lite_mode.constant(());
         }
+        edge.view.frp.set_lite_mode.emit(self.model.frp.lite_mode.value());
 
         edge_id
     }
@@ -1407,6 +2319,7 @@ pub struct GraphEditorModel {
     //  currently work, however, because the `Application` lives in enso-core, and the tooltip
     //  requires enso-text, which in turn depends on enso-core, creating a cyclic dependency.
     tooltip              : Tooltip,
+    port_tooltip         : PortTooltip,
     touch_state          : TouchState,
     visualisations       : Visualisations,
     frp                  : FrpEndpoints,
@@ -1415,7 +2328,28 @@ pub struct GraphEditorModel {
     profiling_button     : component::profiling::Button,
     styles_frp           : StyleWatchFrp,
     selection_controller : selection::Controller,
-
+    removal_preview      : Rc<RefCell<RemovalPreview>>,
+    execution_order      : Rc<RefCell<HashMap<NodeId,usize>>>,
+    edge_refresh         : EdgeRefreshQueue,
+    /// Emitted when the handle at the end of the edge was pressed, with the end to be detached.
+    edge_handle_press    : frp::Source<(EdgeId,component::edge::PortType)>,
+    batch                : batch::State,
+    highlighted_edges    : SharedHashSet<EdgeId>,
+    selected_edges       : SharedHashSet<EdgeId>,
+    declutter            : declutter::Declutter,
+    alignment_guides     : alignment::Guides,
+    culling              : culling::Culling,
+    /// The network synchronizing the zoom of the visualizations compared side by side.
+    vis_comparison       : Rc<RefCell<Option<frp::Network>>>,
+    context_menu         : context_menu::ContextMenu,
+    vis_panels           : vis_panel::Panels,
+    method_pointers      : Rc<RefCell<HashMap<ast::Id,MethodPointer>>>,
+    ids                  : IdProvider,
+    live_region          : accessibility::LiveRegion,
+    fullscreen           : fullscreen::Manager,
+    split_view           : split_view::SplitView,
+    transition_veil      : transition::Veil,
+    debug_overlay        : debug::Overlay,
 }
 
 
@@ -1423,9 +2357,10 @@ pub struct GraphEditorModel {
 
 impl GraphEditorModel {
     pub fn new
-    ( app    : &Application
-    , cursor : cursor::Cursor
-    , frp    : &Frp
+    ( app           : &Application
+    , cursor        : cursor::Cursor
+    , frp           : &Frp
+    , id_allocation : IdAllocation
     ) -> Self {
         let network            = &frp.network;
         let scene              = app.display.scene();
@@ -1441,17 +2376,43 @@ impl GraphEditorModel {
         let frp                = frp.output.clone_ref();
         let navigator          = Navigator::new(scene,&scene.camera());
         let tooltip            = Tooltip::new(&app);
+        let port_tooltip       = PortTooltip::new(&app);
         let profiling_statuses = profiling::Statuses::new();
         let profiling_button   = component::profiling::Button::new(&app);
         let drop_manager       = drop::Manager::new(&scene.dom.root);
         let styles_frp         = StyleWatchFrp::new(&scene.style_sheet);
         let selection_controller = selection::Controller::new(&frp,&app.cursor
             ,&scene.mouse.frp,&touch_state,&nodes);
+        let removal_preview    = default();
+        let execution_order    = default();
+        let edge_refresh       = default();
+        frp::extend! { network
+            edge_handle_press <- source();
+        }
+        let batch              = default();
+        let highlighted_edges  = default();
+        let selected_edges     = default();
+        let declutter          = declutter::Declutter::new(&logger,scene);
+        let alignment_guides   = alignment::Guides::new(&logger,scene);
+        let culling            = default();
+        let vis_comparison     = default();
+        let context_menu       = context_menu::ContextMenu::new(&app);
+        let vis_panels         = vis_panel::Panels::new(&logger,&app,vis_registry.clone_ref());
+        let method_pointers    = default();
+        let ids                = IdProvider::new(id_allocation);
+        let live_region        = accessibility::LiveRegion::new(&logger,&scene.dom.root);
+        let fullscreen         = fullscreen::Manager::new(&logger);
+        let split_view         = split_view::SplitView::new(&logger,scene);
+        let transition_veil    = transition::Veil::new(&logger,scene);
+        let debug_overlay      = debug::Overlay::new(&logger,&app);
 
         Self {
             logger,display_object,app,breadcrumbs,cursor,nodes,edges,vis_registry,drop_manager,
-            tooltip,touch_state,visualisations,frp,navigator,profiling_statuses,profiling_button,
-            styles_frp,selection_controller
+            tooltip,port_tooltip,touch_state,visualisations,frp,navigator,profiling_statuses,
+            profiling_button,styles_frp,selection_controller,removal_preview,execution_order,
+            edge_refresh,edge_handle_press,batch,highlighted_edges,selected_edges,declutter,
+            alignment_guides,culling,vis_comparison,context_menu,vis_panels,method_pointers,ids,
+            live_region,fullscreen,split_view,transition_veil,debug_overlay
         }.init()
     }
 
@@ -1463,7 +2424,14 @@ impl GraphEditorModel {
         self.breadcrumbs.set_position_y(y_offset);
         self.breadcrumbs.gap_width(traffic_lights_gap_width());
         self.scene().add_child(&self.tooltip);
+        self.scene().add_child(&self.port_tooltip);
         self.add_child(&self.profiling_button);
+        self.add_child(&self.declutter);
+        self.add_child(&self.alignment_guides);
+        self.add_child(&self.vis_panels);
+        self.add_child(&self.context_menu);
+        self.add_child(&self.transition_veil);
+        self.add_child(&self.debug_overlay);
         self
     }
 
@@ -1471,138 +2439,902 @@ impl GraphEditorModel {
         self.nodes.all.keys()
     }
 
+    /// The overlay displaying the visualization in fullscreen. The embedding view should display
+    /// it above the graph and all the other overlays.
+    pub fn fullscreen_overlay(&self) -> &display::object::Instance {
+        self.fullscreen.overlay()
+    }
+
     fn scene(&self) -> &Scene {
         self.app.display.scene()
     }
+
+    /// The snapshot of the graph editor state displayed in the debug overlay.
+    fn debug_stats(&self, selection_mode:selection::Mode) -> debug::Stats {
+        let nodes            = self.nodes.all.raw.borrow().len();
+        let edges            = self.edges.all.raw.borrow().len();
+        let detached_sources = self.edges.detached_source.keys();
+        let detached_targets = self.edges.detached_target.keys();
+        let selected         = self.nodes.selected.items();
+        debug::Stats {nodes,edges,detached_sources,detached_targets,selected,selection_mode}
+    }
 }
 
 
-// === Remove ===
+// === Execution Order ===
 
 impl GraphEditorModel {
-    fn remove_edge<E:Into<EdgeId>>(&self, edge_id:E) {
-        let edge_id = edge_id.into();
-        if let Some(edge) = self.edges.remove(&edge_id) {
-            if let Some(source) = edge.take_source() {
-                if let Some(source_node) = self.nodes.get_cloned_ref(&source.node_id) {
-                    source_node.out_edges.remove(&edge_id);
+    /// All the nodes in the topological order of the graph, which is the order in which they are
+    /// evaluated: every node comes after all the nodes it depends on. Nodes not ordered by any
+    /// connection are ordered by their ids, so the result is stable. Nodes forming a cycle are
+    /// placed at the end.
+    pub fn topological_order(&self) -> Vec<NodeId> {
+        let mut nodes = self.nodes.all.keys();
+        nodes.sort();
+        let mut in_degree : HashMap<NodeId,usize>       = nodes.iter().map(|id| (*id,0)).collect();
+        let mut targets   : HashMap<NodeId,Vec<NodeId>> = default();
+        for edge_id in self.edges.keys() {
+            let source = self.edge_source(edge_id).map(|t| t.node_id);
+            let target = self.edge_target(edge_id).map(|t| t.node_id);
+            if let (Some(source),Some(target)) = (source,target) {
+                if let Some(degree) = in_degree.get_mut(&target) {
+                    *degree += 1;
+                    targets.entry(source).or_default().push(target);
                 }
             }
-
-            if let Some(target) = edge.take_target() {
-                self.set_input_connected(&target,None,false); // FIXME None
-                if let Some(target_node) = self.nodes.get_cloned_ref(&target.node_id) {
-                    target_node.in_edges.remove(&edge_id);
+        }
+        let mut ready : BTreeSet<NodeId> =
+            in_degree.iter().filter(|(_,degree)| **degree == 0).map(|(id,_)| *id).collect();
+        let mut order = Vec::with_capacity(nodes.len());
+        while let Some(node_id) = ready.iter().next().copied() {
+            ready.remove(&node_id);
+            order.push(node_id);
+            for target in targets.get(&node_id).into_iter().flatten() {
+                if let Some(degree) = in_degree.get_mut(target) {
+                    *degree -= 1;
+                    if *degree == 0 { ready.insert(*target); }
                 }
             }
         }
-    }
-
-    fn set_input_connected(&self, target:&EdgeEndpoint, tp:Option<Type>, status:bool) {
-        if let Some(node) = self.nodes.get_cloned(&target.node_id) {
-            node.view.set_input_connected(&target.port,tp,status);
+        let cyclic = nodes.into_iter().filter(|id| in_degree.get(id).map_or(false,|d| *d > 0));
+        order.extend(cyclic);
+        order
+    }
+
+    /// Number the nodes according to the execution order if the execution order overlay is
+    /// visible, or remove the numbers otherwise.
+    fn refresh_execution_order(&self) {
+        let visible = self.frp.execution_order_visible.value();
+        let order   = if visible { self.topological_order() } else { default() };
+        let indices = order.into_iter().enumerate().map(|(index,id)| (id,index)).collect();
+        *self.execution_order.borrow_mut() = indices;
+        let indices = self.execution_order.borrow();
+        for node_id in self.nodes.all.keys() {
+            if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+                node.set_execution_order(indices.get(&node_id).copied());
+            }
         }
     }
 
-    fn set_edge_target_connection_status(&self, edge_id:EdgeId, status:bool) {
-        self.with_edge_target(edge_id,|tgt| self.set_endpoint_connection_status(edge_id,&tgt,status));
+    /// The position of the edge source in the execution order, normalized to the `[0,1]` range.
+    /// Returns `None` if the execution order overlay is hidden.
+    fn edge_execution_progress(&self, edge_id:EdgeId) -> Option<f32> {
+        let indices = self.execution_order.borrow();
+        let source  = self.edge_source(edge_id)?;
+        let index   = *indices.get(&source.node_id)?;
+        let last    = indices.len().saturating_sub(1).max(1);
+        Some(index as f32 / last as f32)
     }
+}
 
-    fn set_endpoint_connection_status(&self, edge_id:EdgeId, target:&EdgeEndpoint, status:bool) {
-        let tp = self.edge_source_type(edge_id);
-        self.set_input_connected(target,tp,status);
-    }
 
-    fn enable_visualization(&self, node_id:impl Into<NodeId>) {
-        let node_id = node_id.into();
-        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
-            node.enable_visualization();
+// === Connected Components ===
+
+impl GraphEditorModel {
+    /// The connected components of the graph: the groups of nodes connected with each other by
+    /// edges, regardless of the edge directions. Every node belongs to exactly one component. The
+    /// nodes of a component are sorted by their ids, and the components by their first nodes, so
+    /// the result is stable.
+    pub fn connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut nodes = self.nodes.all.keys();
+        nodes.sort();
+        let mut neighbors : HashMap<NodeId,Vec<NodeId>> = default();
+        for edge_id in self.edges.keys() {
+            let source = self.edge_source(edge_id).map(|t| t.node_id);
+            let target = self.edge_target(edge_id).map(|t| t.node_id);
+            if let (Some(source),Some(target)) = (source,target) {
+                neighbors.entry(source).or_default().push(target);
+                neighbors.entry(target).or_default().push(source);
+            }
+        }
+        let mut visited : HashSet<NodeId> = default();
+        let mut components = Vec::new();
+        for node_id in nodes {
+            if visited.insert(node_id) {
+                let mut component = vec![node_id];
+                let mut next      = 0;
+                while let Some(current) = component.get(next).copied() {
+                    next += 1;
+                    for neighbor in neighbors.get(&current).into_iter().flatten() {
+                        let exists = self.nodes.all.contains_key(neighbor);
+                        if exists && visited.insert(*neighbor) {
+                            component.push(*neighbor);
+                        }
+                    }
+                }
+                component.sort();
+                components.push(component);
+            }
         }
+        components
     }
 
-    fn disable_visualization(&self, node_id:impl Into<NodeId>) {
-        let node_id = node_id.into();
-        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
-            node.disable_visualization();
-        }
+    /// The connected component containing the given node. Empty if there is no such node.
+    pub fn component_of(&self, node_id:NodeId) -> Vec<NodeId> {
+        let components = self.connected_components().into_iter();
+        components.find(|component| component.contains(&node_id)).unwrap_or_default()
     }
+}
 
-    fn enable_visualization_fullscreen(&self, node_id:impl Into<NodeId>) {
-        let node_id = node_id.into();
-        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
-            node.model.visualization.frp.enable_fullscreen.emit(());
+
+// === Accessibility ===
+
+impl GraphEditorModel {
+    /// All the nodes in the order of the keyboard focus. See [`accessibility::reading_order`].
+    fn focus_order(&self) -> Vec<NodeId> {
+        let nodes     = self.nodes.all.raw.borrow();
+        let positions = nodes.iter().map(|(id,node)| (*id,node.position().xy()));
+        accessibility::reading_order(positions)
+    }
+
+    /// The focus after moving it to the next or previous node. `None` if there are no nodes.
+    fn node_focused_after
+    (&self, focus:&Option<accessibility::Focus>, forward:bool) -> Option<accessibility::Focus> {
+        let current = focus.as_ref().map(|focus| focus.node_id());
+        let node_id = accessibility::cycle(&self.focus_order(),current.as_ref(),forward)?;
+        Some(accessibility::Focus::Node(node_id))
+    }
+
+    /// The focus after moving it to the next or previous input port of the focused node. `None`
+    /// if no node is focused or it has no ports.
+    fn port_focused_after
+    (&self, focus:&Option<accessibility::Focus>, forward:bool) -> Option<accessibility::Focus> {
+        let focus   = focus.as_ref()?;
+        let node_id = focus.node_id();
+        let node    = self.nodes.get_cloned_ref(&node_id)?;
+        let ports   = node.model.input.port_crumbs();
+        let port    = accessibility::cycle(&ports,focus.port(),forward)?;
+        Some(accessibility::Focus::Port(EdgeEndpoint::new(node_id,port)))
+    }
+
+    /// Move the focus ring and the port highlight from the `old` focus to the `new` one, and
+    /// announce the new focus in the ARIA live region.
+    fn set_focus
+    (&self, old:&Option<accessibility::Focus>, new:&Option<accessibility::Focus>) {
+        if let Some(old) = old {
+            self.show_focus(old,false);
         }
+        if let Some(new) = new {
+            self.show_focus(new,true);
+        }
+        let description = new.as_ref().and_then(|focus| self.focus_description(focus));
+        self.live_region.announce(&description.unwrap_or_default());
     }
 
-    fn disable_visualization_fullscreen(&self, node_id:impl Into<NodeId>) {
-        let node_id = node_id.into();
-        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
-            node.model.visualization.frp.disable_fullscreen.emit(());
+    fn show_focus(&self, focus:&accessibility::Focus, visible:bool) {
+        if let Some(node) = self.nodes.get_cloned_ref(&focus.node_id()) {
+            node.frp.set_focused.emit(visible);
+            if let Some(port) = focus.port() {
+                node.frp.highlight_input_port.emit(Switch::new(port.clone_ref(),visible));
+            }
         }
     }
 
-    /// Get the visualization on the node, if it is enabled.
-    pub fn enabled_visualization(&self, node_id:impl Into<NodeId>) -> Option<visualization::Metadata> {
-        let frp = &self.nodes.all.get_cloned_ref(&node_id.into())?.model.visualization.frp;
-        frp.visible.value().then(|| {
-            visualization::Metadata::new(&frp.preprocessor.value())
-        })
+    fn focus_description(&self, focus:&accessibility::Focus) -> Option<String> {
+        let node_id    = focus.node_id();
+        let node       = self.nodes.get_cloned_ref(&node_id)?;
+        let expression = node.expression.value().to_string();
+        match focus.port() {
+            None => {
+                let order = self.focus_order();
+                let index = order.iter().position(|id| *id == node_id)?;
+                Some(accessibility::node_description(index,order.len(),&expression))
+            }
+            Some(port) => {
+                let name      = node.model.input.port_name(port);
+                let tp        = node.model.input.port_type(port);
+                let target    = EdgeEndpoint::new(node_id,port.clone_ref());
+                let connected = node.in_edges.keys().into_iter().any(|edge_id| {
+                    self.edge_target(edge_id).as_ref() == Some(&target)
+                });
+                let name = name.as_deref();
+                let tp   = tp.as_ref().map(|tp| tp.as_str());
+                Some(accessibility::port_description(name,tp,connected,&expression))
+            }
+        }
     }
+}
 
-    /// Warning! This function does not remove connected edges. It needs to be handled by the
-    /// implementation.
-    fn remove_node(&self, node_id:impl Into<NodeId>) {
-        let node_id = node_id.into();
-        self.nodes.remove(&node_id);
-        self.nodes.selected.remove_item(&node_id);
-        self.frp.source.on_visualization_select.emit(Switch::Off(node_id));
+
+// === Keyboard Connect ===
+
+impl GraphEditorModel {
+    /// The target port after moving to the next or previous one in the keyboard connect mode.
+    /// `None` if there is no source node or no port to connect it to.
+    fn keyboard_connect_target_after
+    (&self, source:&Option<NodeId>, current:&Option<EdgeEndpoint>, forward:bool)
+    -> Option<EdgeEndpoint> {
+        let source  = (*source)?;
+        let order   = self.focus_order();
+        let targets = keyboard_connect::targets(source,&order,|node_id| {
+            let node = self.nodes.get_cloned_ref(&node_id);
+            node.map(|node| node.model.input.port_crumbs()).unwrap_or_default()
+        });
+        keyboard_connect::target_after(&targets,current.as_ref(),forward)
     }
 
-    fn node_in_edges(&self, node_id:impl Into<NodeId>) -> Vec<EdgeId> {
-        let node_id = node_id.into();
-        self.nodes.get_cloned_ref(&node_id).map(|node| {
-            node.in_edges.keys()
-        }).unwrap_or_default()
+    /// Move the port highlight from the `old` target to the `new` one, and announce the new target
+    /// in the ARIA live region.
+    fn set_keyboard_connect_target(&self, old:&Option<EdgeEndpoint>, new:&Option<EdgeEndpoint>) {
+        if let Some(old) = old {
+            self.highlight_keyboard_connect_target(old,false);
+        }
+        if let Some(new) = new {
+            self.highlight_keyboard_connect_target(new,true);
+            let focus = accessibility::Focus::Port(new.clone());
+            let description = self.focus_description(&focus);
+            self.live_region.announce(&description.unwrap_or_default());
+        }
     }
 
-    fn node_out_edges(&self, node_id:impl Into<NodeId>) -> Vec<EdgeId> {
-        let node_id = node_id.into();
-        self.nodes.get_cloned_ref(&node_id).map(|node| {
-            node.out_edges.keys()
-        }).unwrap_or_default()
+    fn highlight_keyboard_connect_target(&self, target:&EdgeEndpoint, highlighted:bool) {
+        if let Some(node) = self.nodes.get_cloned_ref(&target.node_id) {
+            let port = target.port.clone_ref();
+            node.frp.highlight_input_port.emit(Switch::new(port,highlighted));
+        }
     }
+}
 
-    fn node_in_and_out_edges(&self, node_id:impl Into<NodeId>) -> Vec<EdgeId> {
-        let node_id = node_id.into();
-        let mut edges = self.node_in_edges(node_id);
-        edges.extend(&self.node_out_edges(node_id));
-        edges
+
+// === Edge De-clutter ===
+
+impl GraphEditorModel {
+    /// The attached edges whose both endpoints are off-screen.
+    fn decluttered_edges(&self) -> HashSet<EdgeId> {
+        let visible = self.declutter.visible_nodes();
+        let hidden  = |endpoint:Option<EdgeEndpoint>| {
+            endpoint.map_or(false,|endpoint| !visible.contains(&endpoint.node_id))
+        };
+        let edges = self.edges.all.keys().into_iter();
+        edges.filter(|id| hidden(self.edge_source(*id)) && hidden(self.edge_target(*id))).collect()
     }
 
-    fn set_node_expression(&self, node_id:impl Into<NodeId>, expr:impl Into<node::Expression>) {
-        let node_id = node_id.into();
-        let expr    = expr.into();
-        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
-            node.frp.set_expression.emit(expr);
-        }
-        for edge_id in self.node_out_edges(node_id) {
-            self.refresh_edge_source_size(edge_id);
+    /// The stubs displayed at the screen border for the given edges.
+    fn edge_stubs
+    (&self, edges:&HashSet<EdgeId>, neutral_color:color::Lcha) -> Vec<declutter::Stub> {
+        let screen      = self.declutter.screen_area();
+        let position_of = |endpoint:Option<EdgeEndpoint>| {
+            let position = self.get_node_position(endpoint?.node_id)?.xy();
+            Some(self.declutter.scene_to_screen(position))
+        };
+        let mut stubs = Vec::new();
+        for edge_id in edges {
+            let source = position_of(self.edge_source(*edge_id));
+            let target = position_of(self.edge_target(*edge_id));
+            if let (Some(source),Some(target)) = (source,target) {
+                let color = self.edge_color(*edge_id,neutral_color);
+                stubs.extend(declutter::Stub::for_edge(&screen,source,target,color));
+            }
         }
+        stubs
     }
 
-    fn set_node_comment(&self, node_id:impl Into<NodeId>, comment:impl Into<node::Comment>) {
-        let node_id = node_id.into();
-        let comment = comment.into();
-        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
-            node.frp.set_comment.emit(comment);
+    /// Fade the edges with no visible endpoints and display the stubs for them if the edge
+    /// de-clutter mode is enabled, or restore the faded edges otherwise.
+    fn refresh_edge_declutter(&self, neutral_color:color::Lcha) {
+        if self.frp.edge_declutter_enabled.value() {
+            let faded = self.decluttered_edges();
+            let stubs = self.edge_stubs(&faded,neutral_color);
+            for edge_id in self.declutter.set_faded(faded) {
+                self.refresh_edge_color(edge_id,neutral_color);
+            }
+            self.declutter.set_stubs(&stubs);
+        } else {
+            self.restore_faded_edges(neutral_color);
         }
     }
 
-    fn is_connection(&self, edge_id:impl Into<EdgeId>) -> bool {
-        let edge_id = edge_id.into();
-        match self.edges.get_cloned_ref(&edge_id) {
-            None    => false,
-            Some(e) => e.has_source() && e.has_target()
+    /// Restore the color of the faded edges and hide their stubs. The other edges are untouched.
+    fn restore_faded_edges(&self, neutral_color:color::Lcha) {
+        for edge_id in self.declutter.set_faded(default()) {
+            self.refresh_edge_color(edge_id,neutral_color);
+        }
+        self.declutter.set_stubs(&[]);
+    }
+
+    fn faded_edge_color(&self, color:color::Lcha) -> color::Lcha {
+        let styles = StyleWatch::new(&self.scene().style_sheet);
+        let alpha  = styles.get_number_or(theme::graph_editor::edge::declutter::faded_alpha,0.15);
+        color.opaque.with_alpha(color.alpha * alpha)
+    }
+}
+
+
+// === Culling ===
+
+impl GraphEditorModel {
+    /// Update the culling index with the current endpoints of the edge. The edges which do not
+    /// connect two nodes are never culled.
+    fn update_edge_culling(&self, edge:&Edge) {
+        let endpoint = |endpoint:Option<EdgeEndpoint>| {
+            let node_id = endpoint?.node_id;
+            Some((node_id,self.get_node_position(node_id)?.xy()))
+        };
+        match (endpoint(edge.source()),endpoint(edge.target())) {
+            (Some(source),Some(target)) => self.culling.set_edge(edge.id(),source,target),
+            _ => {
+                if self.culling.is_edge_culled(edge.id()) {
+                    self.add_child(edge);
+                }
+                self.culling.remove_edge(edge.id());
+            }
+        }
+    }
+
+    /// Cull the nodes and edges lying far outside of the screen if the culling is enabled, and
+    /// restore the ones which got close to the screen.
+    ///
+    /// The culling considers only the main camera, so it is suspended while the workspace is split.
+    fn refresh_culling(&self) {
+        let enabled = self.frp.culling_enabled.value() && !self.split_view.is_enabled();
+        let area    = enabled.as_some_from(|| culling::area(self.scene()));
+        let nodes   = self.culling.update_nodes(area.as_ref());
+        for node_id in nodes.culled {
+            self.with_node(node_id,|node| node.set_details_hidden(true));
+        }
+        for node_id in nodes.restored {
+            self.with_node(node_id,|node| node.set_details_hidden(false));
+        }
+        let edges = self.culling.update_edges(area.as_ref());
+        for edge_id in edges.culled {
+            if let Some(edge) = self.edges.get_cloned_ref(&edge_id) {
+                edge.unset_parent();
+            }
+        }
+        for edge_id in edges.restored {
+            if let Some(edge) = self.edges.get_cloned_ref(&edge_id) {
+                self.add_child(&edge);
+            }
+        }
+    }
+}
+
+
+// === Highlight ===
+
+impl GraphEditorModel {
+    /// Highlight the expression spans of the ports connected by the edge, or remove the highlight.
+    fn set_edge_ports_highlight(&self, edge_id:EdgeId, highlighted:bool) {
+        if let Some(source) = self.edge_source(edge_id) {
+            if let Some(node) = self.nodes.get_cloned_ref(&source.node_id) {
+                node.frp.highlight_output_port.emit(Switch::new(source.port,highlighted));
+            }
+        }
+        if let Some(target) = self.edge_target(edge_id) {
+            if let Some(node) = self.nodes.get_cloned_ref(&target.node_id) {
+                node.frp.highlight_input_port.emit(Switch::new(target.port,highlighted));
+            }
+        }
+    }
+
+    /// Highlight the edges connected to the hovered ports and remove the highlight from all the
+    /// other edges.
+    fn highlight_port_edges(&self, input:&Option<EdgeEndpoint>, output:&Option<EdgeEndpoint>) {
+        let mut highlighted = HashSet::new();
+        if let Some(input) = input {
+            for edge_id in self.node_in_edges(input.node_id) {
+                let target = self.edge_target(edge_id);
+                if target.map_or(false,|target| target.port == input.port) {
+                    highlighted.insert(edge_id);
+                }
+            }
+        }
+        if let Some(output) = output {
+            for edge_id in self.node_out_edges(output.node_id) {
+                let source = self.edge_source(edge_id);
+                if source.map_or(false,|source| source.port == output.port) {
+                    highlighted.insert(edge_id);
+                }
+            }
+        }
+        for edge_id in self.highlighted_edges.keys() {
+            if !highlighted.contains(&edge_id) && !self.selected_edges.contains(&edge_id) {
+                self.map_edge(edge_id,|edge| edge.view.frp.set_highlighted.emit(false));
+            }
+        }
+        for edge_id in &highlighted {
+            self.map_edge(*edge_id,|edge| edge.view.frp.set_highlighted.emit(true));
+        }
+        self.highlighted_edges.replace_with(highlighted);
+    }
+}
+
+
+// === Edge Selection ===
+
+impl GraphEditorModel {
+    /// Check whether the drawn line of the attached edge crosses the area.
+    fn edge_crosses_area(&self, edge_id:EdgeId, area:&selection::BoundingBox) -> bool {
+        let edge     = self.edges.get_cloned_ref(&edge_id);
+        let attached = edge.filter(|edge| edge.source().is_some() && edge.target().is_some());
+        attached.map_or(false,|edge| edge.view.segments().into_iter().any(|(start,end)| {
+            declutter::clip_segment(area,start,end).is_some()
+        }))
+    }
+
+    /// The attached edges crossing the area, not only the ones with the endpoints inside it. The
+    /// edges are hit-tested against the segments of their drawn shapes, see `Edge::segments`.
+    fn edges_in_area(&self, area:&selection::BoundingBox) -> HashSet<EdgeId> {
+        let edges = self.edges.all.keys().into_iter();
+        edges.filter(|id| self.edge_crosses_area(*id,area)).collect()
+    }
+
+    fn set_edge_selected(&self, edge_id:EdgeId, selected:bool) {
+        if selected {
+            self.selected_edges.insert(edge_id);
+        } else {
+            self.selected_edges.remove(&edge_id);
+        }
+        let highlighted = selected || self.highlighted_edges.contains(&edge_id);
+        self.map_edge(edge_id,|edge| edge.view.frp.set_highlighted.emit(highlighted));
+    }
+
+    /// Select exactly the given edges. Returns the newly selected and the deselected edges.
+    fn set_selected_edges(&self, edges:&HashSet<EdgeId>) -> (Vec<EdgeId>,Vec<EdgeId>) {
+        let previous   = self.selected_edges.raw.borrow().clone();
+        let selected   = edges.difference(&previous).copied().collect_vec();
+        let deselected = previous.difference(edges).copied().collect_vec();
+        for edge_id in &selected {
+            self.set_edge_selected(*edge_id,true);
+        }
+        for edge_id in &deselected {
+            self.set_edge_selected(*edge_id,false);
+        }
+        (selected,deselected)
+    }
+}
+
+
+// === Remove ===
+
+impl GraphEditorModel {
+    fn remove_edge<E:Into<EdgeId>>(&self, edge_id:E) {
+        let edge_id = edge_id.into();
+        if let Some(edge) = self.edges.remove(&edge_id) {
+            if let Some(source) = edge.take_source() {
+                if let Some(source_node) = self.nodes.get_cloned_ref(&source.node_id) {
+                    source_node.out_edges.remove(&edge_id);
+                }
+            }
+
+            if let Some(target) = edge.take_target() {
+                self.set_input_connected(&target,None,false); // FIXME None
+                if let Some(target_node) = self.nodes.get_cloned_ref(&target.node_id) {
+                    target_node.in_edges.remove(&edge_id);
+                }
+            }
+            self.selected_edges.remove(&edge_id);
+            self.culling.remove_edge(edge_id);
+            self.edge_refresh.schedule_unresolved_types();
+            self.edge_refresh.schedule_connections();
+        }
+    }
+
+    fn set_input_connected(&self, target:&EdgeEndpoint, tp:Option<Type>, status:bool) {
+        if let Some(node) = self.nodes.get_cloned(&target.node_id) {
+            node.view.set_input_connected(&target.port,tp,status);
+        }
+    }
+
+    fn set_edge_target_connection_status(&self, edge_id:EdgeId, status:bool) {
+        self.with_edge_target(edge_id,|tgt| self.set_endpoint_connection_status(edge_id,&tgt,status));
+    }
+
+    fn set_endpoint_connection_status(&self, edge_id:EdgeId, target:&EdgeEndpoint, status:bool) {
+        let tp = self.edge_source_type(edge_id);
+        self.set_input_connected(target,tp,status);
+    }
+
+    fn enable_visualization(&self, node_id:impl Into<NodeId>) {
+        let node_id = node_id.into();
+        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+            if self.frp.lite_mode.value() && !node.visualization_enabled.value() {
+                let nodes   = self.nodes.all.raw.borrow();
+                let enabled = nodes.values().filter(|n| n.visualization_enabled.value()).count();
+                if enabled >= lite_mode::MAX_VISUALIZATIONS {
+                    let max = lite_mode::MAX_VISUALIZATIONS;
+                    warning!(&self.logger,"Cannot enable more than {max} visualizations in the \
+                        lite mode.");
+                    return
+                }
+            }
+            node.enable_visualization();
+        }
+    }
+
+    fn disable_visualization(&self, node_id:impl Into<NodeId>) {
+        let node_id = node_id.into();
+        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+            node.disable_visualization();
+        }
+    }
+
+    /// Display the visualization of the node in fullscreen. Returns `None` if there is no such
+    /// node.
+    fn enter_visualization_fullscreen(&self, node_id:NodeId) -> Option<NodeId> {
+        let node = self.nodes.get_cloned_ref(&node_id)?;
+        self.enable_visualization(node_id);
+        self.fullscreen.enter(node_id,&node.model.visualization);
+        Some(node_id)
+    }
+
+    /// Find the tidy position of the target node of the newly connected edge, see the `tidy`
+    /// module docs. Returns the node with its current and tidy positions, or `None` if the node
+    /// should not be moved.
+    fn tidy_connected_node(&self, edge_id:EdgeId) -> Option<(NodeId,Vector2,Vector2)> {
+        let source_id = self.edge_source(edge_id)?.node_id;
+        let target_id = self.edge_target(edge_id)?.node_id;
+        if source_id == target_id || self.nodes.is_pinned(&target_id) { return None }
+        let source    = self.nodes.get_cloned_ref(&source_id)?.bounding_box.value();
+        let target    = self.nodes.get_cloned_ref(&target_id)?;
+        let target_bb = target.bounding_box.value();
+        let size      = Vector2(target_bb.width(),target_bb.height());
+        let obstacles = self.nodes.all.raw.borrow().iter()
+            .filter(|(id,_)| **id != target_id)
+            .map(|(_,node)| node.bounding_box.value())
+            .collect_vec();
+        let x_gap     = self.frp.default_x_gap_between_nodes.value();
+        let y_gap     = self.frp.default_y_gap_between_nodes.value();
+        let current   = target.position().xy();
+        let tidy      = tidy::slot_below(&source,size,&obstacles,x_gap,y_gap);
+        ((tidy - current).norm() > f32::EPSILON).as_some((target_id,current,tidy))
+    }
+
+    /// Pin or unpin the node. Returns `None` if there is no such node.
+    fn set_node_pinned(&self, node_id:NodeId, pinned:bool) -> Option<(NodeId,bool)> {
+        let node = self.nodes.get_cloned_ref(&node_id)?;
+        if pinned {
+            self.nodes.pinned.insert(node_id);
+        } else {
+            self.nodes.pinned.remove(&node_id);
+        }
+        node.set_pinned(pinned);
+        Some((node_id,pinned))
+    }
+
+    /// Close the visualization displayed in fullscreen. Returns the node it belongs to, or `None`
+    /// if no visualization was displayed in fullscreen.
+    fn exit_visualization_fullscreen(&self) -> Option<NodeId> {
+        let node_id = self.fullscreen.exit()?;
+        if self.nodes.contains_key(&node_id) {
+            self.enable_visualization(node_id);
+        }
+        Some(node_id)
+    }
+
+    /// Display the visualizations of the nodes side by side in the split fullscreen mode. Returns
+    /// `None` if the nodes are the same or any of them does not exist.
+    fn enable_visualization_comparison
+    (&self, left_id:NodeId, right_id:NodeId) -> Option<(NodeId,NodeId)> {
+        if left_id == right_id { return None }
+        let left  = self.nodes.get_cloned_ref(&left_id)?.model.visualization.frp.clone_ref();
+        let right = self.nodes.get_cloned_ref(&right_id)?.model.visualization.frp.clone_ref();
+        let panes = [(left_id,&left,Pane::Left),(right_id,&right,Pane::Right)];
+        for (node_id,vis,pane) in panes.iter() {
+            self.enable_visualization(*node_id);
+            vis.set_fullscreen_pane.emit(*pane);
+            vis.enable_fullscreen.emit(());
+        }
+        let network = frp::Network::new("vis_comparison");
+        frp::extend! { network
+            left_zoom      <- left.zoom.filter(f_!([left,right] same_vis_type(&left,&right)));
+            right_zoom     <- right.zoom.filter(f_!([left,right] same_vis_type(&left,&right)));
+            right.set_zoom <+ left_zoom;
+            left.set_zoom  <+ right_zoom;
+        }
+        self.vis_comparison.replace(Some(network));
+        Some((left_id,right_id))
+    }
+
+    fn set_method_pointer(&self, id:ast::Id, method_pointer:Option<MethodPointer>) {
+        let mut method_pointers = self.method_pointers.borrow_mut();
+        match method_pointer {
+            Some(method_pointer) => { method_pointers.insert(id,method_pointer); }
+            None                 => { method_pointers.remove(&id); }
+        }
+    }
+
+    /// The call performed by the node, identified by the id of its whole expression. Returns
+    /// `None` if the method pointer of the expression was not set.
+    fn local_call(&self, node_id:NodeId) -> Option<LocalCall> {
+        let node       = self.nodes.get_cloned_ref(&node_id);
+        let call       = node.and_then(|node| node.frp.set_expression.value().whole_expression_id);
+        let definition = call.and_then(|call| self.method_pointers.borrow().get(&call).cloned());
+        let local_call = call.zip(definition).map(|(call,definition)| LocalCall {call,definition});
+        local_call.map_none(|| {
+            warning!(&self.logger,"Cannot enter the node {node_id}: its method pointer is unknown.")
+        })
+    }
+
+    /// The navigator of the hovered viewport of the split workspace.
+    fn hovered_navigator(&self) -> &Navigator {
+        match self.split_view.hovered() {
+            split_view::Viewport::Primary   => &self.navigator,
+            split_view::Viewport::Secondary => self.split_view.navigator(),
+        }
+    }
+
+    /// The camera position and zoom at which all the nodes are visible. Returns `None` if there
+    /// are no nodes.
+    fn camera_target_fitting_all_nodes(&self) -> Option<(Vector2,f32)> {
+        self.camera_target_fitting(&self.nodes.all.keys())
+    }
+
+    /// The camera position and zoom at which all the given nodes are visible. Returns `None` if
+    /// none of the nodes exists.
+    fn camera_target_fitting(&self, nodes:&[NodeId]) -> Option<(Vector2,f32)> {
+        let boxes  = nodes.iter().filter_map(|id| self.nodes.get_cloned_ref(id))
+            .map(|node| node.bounding_box.value()).collect_vec();
+        let first  = boxes.first()?;
+        let init   = (first.left(),first.right(),first.bottom(),first.top());
+        let bounds = boxes.iter().fold(init,|(left,right,bottom,top),bb| {
+            (left.min(bb.left()),right.max(bb.right()),bottom.min(bb.bottom()),top.max(bb.top()))
+        });
+        let (left,right,bottom,top) = bounds;
+        let center = Vector2((left + right) / 2.0,(bottom + top) / 2.0);
+        let width  = right - left + 2.0 * ZOOM_TO_FIT_PADDING;
+        let height = top - bottom + 2.0 * ZOOM_TO_FIT_PADDING;
+        let screen = self.scene().shape().value();
+        let zoom   = (screen.width / width).min(screen.height / height).min(1.0);
+        Some((center,zoom))
+    }
+
+    /// Fly the camera for the given effect of the level transition. The `start` is the camera
+    /// position and zoom from before the transition, and `node` is the entered node, if known.
+    fn fly_for_level_transition
+    (&self, effect:transition::Effect, start:(Vector2,f32), node:Option<NodeId>, duration:f32) {
+        use transition::Direction;
+        use transition::Effect;
+        let (position,zoom) = start;
+        let node_center     = node.and_then(|id| self.nodes.get_cloned_ref(&id)).map(|node| {
+            let bounding_box = node.bounding_box.value();
+            let x            = (bounding_box.left() + bounding_box.right()) / 2.0;
+            let y            = (bounding_box.bottom() + bounding_box.top()) / 2.0;
+            Vector2(x,y)
+        });
+        let target = match effect {
+            Effect::Cover(Direction::Enter) =>
+                Some((node_center.unwrap_or(position),zoom * transition::ZOOM_FACTOR)),
+            Effect::Cover(Direction::Exit) => Some((position,zoom / transition::ZOOM_FACTOR)),
+            Effect::Reveal(_)              => Some((position,zoom)),
+            Effect::Finish                 => None,
+        };
+        if let Some((position,zoom)) = target {
+            self.navigator.fly_to(position,zoom,duration,FlightEasing::QuadInOut);
+        }
+    }
+
+    /// Perform the built-in action chosen from the context menu. The other actions are left to
+    /// the application.
+    fn perform_context_menu_action
+    (&self, target:context_menu::Target, action:&context_menu::Action) {
+        use context_menu::Action;
+        use context_menu::Target;
+        match (target,action) {
+            (Target::Node(node_id),Action::RemoveNode) => {
+                self.frp.source.feedback.emit(feedback::Event::Deleted);
+                self.frp.remove_all_node_edges.emit(node_id);
+                self.frp.source.node_removed.emit(node_id);
+            }
+            (Target::Node(node_id),Action::FreezeNode) => {
+                if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+                    node.frp.set_frozen.emit(!node.frp.freeze.value());
+                }
+            }
+            (Target::Node(node_id),Action::SkipNode) => {
+                if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+                    node.frp.set_skipped.emit(!node.frp.skip.value());
+                }
+            }
+            (Target::Node(node_id),Action::VisualizeNode) => {
+                if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+                    if node.visualization_enabled.value() {
+                        self.disable_visualization(node_id);
+                    } else {
+                        self.enable_visualization(node_id);
+                    }
+                }
+            }
+            (Target::Node(node_id),Action::PinVisualization) => {
+                self.frp.pin_visualization.emit(node_id);
+            }
+            (Target::Node(node_id),Action::CollapseNodes) => {
+                if !self.nodes.is_selected(node_id) {
+                    self.frp.deselect_all_nodes.emit(());
+                    self.frp.select_node.emit(node_id);
+                }
+                self.frp.collapse_selected_nodes.emit(());
+            }
+            (Target::Edge(edge_id),Action::DisconnectEdge) => {
+                self.frp.source.on_edge_drop.emit(edge_id);
+            }
+            (Target::Background(position),Action::AddNode) => {
+                self.frp.add_node.emit(());
+                let node_id = self.frp.node_added.value();
+                self.frp.set_node_position.emit((node_id,position));
+            }
+            (_,Action::ZoomToFit) => self.frp.zoom_to_fit.emit(()),
+            _ => {}
+        }
+    }
+
+    /// Pin the visualization of the node to the canvas, next to the node's visualization. Returns
+    /// `None` if there is no such node.
+    fn pin_visualization(&self, node_id:NodeId) -> Option<(vis_panel::PanelId,NodeId)> {
+        let node = self.nodes.get_cloned_ref(&node_id)?;
+        self.enable_visualization(node_id);
+        let definition    = node.model.visualization.frp.visualisation.value();
+        let vis_position  = node.position().xy() + node.model.visualization.position().xy();
+        let (x_off,y_off) = vis_panel::PIN_OFFSET;
+        let position      = vis_position + Vector2(x_off,y_off);
+        let panel_id      = self.vis_panels.add(node_id,definition,position);
+        Some((panel_id,node_id))
+    }
+
+    fn disable_visualization_comparison(&self, left_id:NodeId, right_id:NodeId) {
+        self.vis_comparison.take();
+        for node_id in &[left_id,right_id] {
+            if let Some(node) = self.nodes.get_cloned_ref(node_id) {
+                let vis = &node.model.visualization.frp;
+                vis.disable_fullscreen.emit(());
+                vis.set_fullscreen_pane.emit(Pane::Whole);
+            }
+        }
+    }
+
+    /// Get the visualization on the node, if it is enabled.
+    pub fn enabled_visualization(&self, node_id:impl Into<NodeId>) -> Option<visualization::Metadata> {
+        let frp = &self.nodes.all.get_cloned_ref(&node_id.into())?.model.visualization.frp;
+        frp.visible.value().then(|| {
+            visualization::Metadata::new(&frp.preprocessor.value())
+        })
+    }
+
+    /// Warning! This function does not remove connected edges. It needs to be handled by the
+    /// implementation.
+    fn remove_node(&self, node_id:impl Into<NodeId>) {
+        let node_id = node_id.into();
+        if let Some(node) = self.nodes.remove(&node_id) {
+            if let Some(id) = node.frp.set_expression.value().whole_expression_id {
+                self.method_pointers.borrow_mut().remove(&id);
+            }
+        }
+        self.nodes.selected.remove_item(&node_id);
+        self.nodes.pinned.remove(&node_id);
+        self.declutter.remove_node(node_id);
+        self.culling.remove_node(node_id);
+        self.frp.source.on_visualization_select.emit(Switch::Off(node_id));
+    }
+
+    fn node_in_edges(&self, node_id:impl Into<NodeId>) -> Vec<EdgeId> {
+        let node_id = node_id.into();
+        self.nodes.get_cloned_ref(&node_id).map(|node| {
+            node.in_edges.keys()
+        }).unwrap_or_default()
+    }
+
+    fn node_out_edges(&self, node_id:impl Into<NodeId>) -> Vec<EdgeId> {
+        let node_id = node_id.into();
+        self.nodes.get_cloned_ref(&node_id).map(|node| {
+            node.out_edges.keys()
+        }).unwrap_or_default()
+    }
+
+    fn node_in_and_out_edges(&self, node_id:impl Into<NodeId>) -> Vec<EdgeId> {
+        let node_id = node_id.into();
+        let mut edges = self.node_in_edges(node_id);
+        edges.extend(&self.node_out_edges(node_id));
+        edges
+    }
+
+    fn set_node_expression(&self, node_id:impl Into<NodeId>, expr:impl Into<node::Expression>) {
+        let node_id = node_id.into();
+        let expr    = expr.into();
+        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+            node.frp.set_expression.emit(expr);
+        }
+        for edge_id in self.node_out_edges(node_id) {
+            self.refresh_edge_source_size(edge_id);
+        }
+    }
+
+    fn set_node_expression_preview(&self, node_id:NodeId, preview:Option<node::Expression>) {
+        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+            node.frp.set_expression_preview.emit(preview);
+        }
+        for edge_id in self.node_out_edges(node_id) {
+            self.refresh_edge_source_size(edge_id);
+        }
+    }
+
+    fn set_node_comment(&self, node_id:impl Into<NodeId>, comment:impl Into<node::Comment>) {
+        let node_id = node_id.into();
+        let comment = comment.into();
+        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+            node.frp.set_comment.emit(comment);
+        }
+    }
+
+    fn set_port_documentation
+    (&self, node_id:NodeId, crumbs:&span_tree::Crumbs, documentation:&str) {
+        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+            node.frp.set_port_documentation.emit((crumbs.clone(),documentation.to_owned()));
+        }
+    }
+
+    /// Show the tooltip of the input port of the given node, or hide it if `tooltip` is `None`.
+    /// The tooltip position is relative to the node.
+    fn set_port_tooltip
+    (&self, node_id:NodeId, tooltip:&Option<(port_tooltip::Content,Vector2)>) {
+        match tooltip {
+            Some((content,offset)) => if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+                let position = node.position().xy() + offset;
+                self.port_tooltip.show.emit((content.clone(),position));
+            }
+            None => self.port_tooltip.hide.emit(()),
+        }
+    }
+
+    fn is_connection(&self, edge_id:impl Into<EdgeId>) -> bool {
+        let edge_id = edge_id.into();
+        match self.edges.get_cloned_ref(&edge_id) {
+            None    => false,
+            Some(e) => e.has_source() && e.has_target()
+        }
+    }
+
+    fn pressed_connection(&self, edge_id:EdgeId, position:Vector2) -> Option<PressedConnection> {
+        let source = self.edge_source(edge_id)?;
+        let target = self.edge_target(edge_id)?;
+        Some(PressedConnection {edge_id,source,target,position})
+    }
+
+    /// Replace the pressed connection with the two edges joining its endpoints with the
+    /// pass-through node. The node is placed at the press position and connected with its whole
+    /// expression. The edges are connected directly in the model, without emitting the edge
+    /// outputs.
+    fn split_connection
+    ( &self
+    , pressed       : &PressedConnection
+    , node_id       : NodeId
+    , in_edge       : EdgeId
+    , out_edge      : EdgeId
+    , neutral_color : color::Lcha
+    ) {
+        let edge_id = pressed.edge_id;
+        self.remove_edge(edge_id);
+        self.edges.detached_source.remove(&edge_id);
+        self.edges.detached_target.remove(&edge_id);
+        self.check_edge_attachment_status_and_emit_events();
+        self.set_node_position(node_id,pressed.position);
+        let node_port = EdgeEndpoint::new(node_id,default());
+        self.set_edge_source(in_edge,pressed.source.clone());
+        self.set_edge_target(in_edge,node_port.clone());
+        self.set_edge_source(out_edge,node_port);
+        self.set_edge_target(out_edge,pressed.target.clone());
+        for edge_id in &[in_edge,out_edge] {
+            self.set_edge_target_connection_status(*edge_id,true);
+            self.refresh_edge_color(*edge_id,neutral_color);
         }
     }
 }
@@ -1618,6 +3350,7 @@ impl GraphEditorModel {
                 node.out_edges.insert(edge_id);
                 edge.set_source(target);
                 edge.view.frp.source_attached.emit(true);
+                self.edge_refresh.schedule_connections();
                 // FIXME: both lines require edge to refresh. Let's make it more efficient.
                 self.refresh_edge_position(edge_id);
                 self.refresh_edge_source_size(edge_id);
@@ -1632,6 +3365,7 @@ impl GraphEditorModel {
                 if let Some(node) = self.nodes.get_cloned_ref(&source.node_id) {
                     node.out_edges.remove(&edge_id);
                     edge.view.frp.source_attached.emit(false);
+                    self.edge_refresh.schedule_connections();
                     let first_detached = self.edges.detached_source.is_empty();
                     self.edges.detached_source.insert(edge_id);
                     // FIXME: both lines require edge to refresh. Let's make it more efficient.
@@ -1651,6 +3385,7 @@ impl GraphEditorModel {
             if let Some(node) = self.nodes.get_cloned_ref(&target.node_id) {
                 node.in_edges.insert(edge_id);
                 edge.set_target(target);
+                self.edge_refresh.schedule_connections();
 
                 self.edges.detached_target.remove(&edge_id);
                 let all_attached = self.edges.detached_target.is_empty();
@@ -1659,7 +3394,6 @@ impl GraphEditorModel {
                 }
 
                 edge.view.frp.target_attached.emit(true);
-                edge.view.frp.redraw.emit(());
                 self.refresh_edge_position(edge_id);
             };
         }
@@ -1670,6 +3404,7 @@ impl GraphEditorModel {
             if let Some(target) = edge.take_target() {
                 if let Some(node) = self.nodes.get_cloned_ref(&target.node_id) {
                     node.in_edges.remove(&edge_id);
+                    self.edge_refresh.schedule_connections();
                     let first_detached = self.edges.detached_target.is_empty();
                     self.edges.detached_target.insert(edge_id);
                     edge.view.frp.target_attached.emit(false);
@@ -1743,6 +3478,177 @@ impl GraphEditorModel {
 }
 
 
+// === Reorder ===
+
+impl GraphEditorModel {
+    /// The input and output edge of the node, if it has exactly one of each.
+    fn node_chain_edges(&self, node_id:NodeId) -> Option<(EdgeId,EdgeId)> {
+        let in_edges  = self.node_in_edges(node_id);
+        let out_edges = self.node_out_edges(node_id);
+        match (in_edges.as_slice(),out_edges.as_slice()) {
+            ([in_edge],[out_edge]) => Some((*in_edge,*out_edge)),
+            _                      => None,
+        }
+    }
+
+    /// Swap the node with its neighbor in the given direction. Returns `None` and leaves the graph
+    /// untouched if the nodes do not form a linear chain.
+    fn reorder_node(&self, node:NodeId, direction:ReorderDirection) -> Option<NodeReorder> {
+        let (in_edge,out_edge) = self.node_chain_edges(node)?;
+        let (upstream,downstream,neighbor) = match direction {
+            ReorderDirection::Upstream => {
+                let neighbor = self.edge_source(in_edge)?.node_id;
+                (neighbor,node,neighbor)
+            }
+            ReorderDirection::Downstream => {
+                let neighbor = self.edge_target(out_edge)?.node_id;
+                (node,neighbor,neighbor)
+            }
+        };
+        let edges = self.swap_chained_nodes(upstream,downstream)?;
+        Some(NodeReorder {node,neighbor,direction,edges})
+    }
+
+    /// Rewire the `prev -> upstream -> downstream -> next` chain into
+    /// `prev -> downstream -> upstream -> next`. The input and output ports of both nodes are
+    /// preserved. Returns the rewired edges, in the chain order.
+    fn swap_chained_nodes(&self, upstream:NodeId, downstream:NodeId) -> Option<[EdgeId;3]> {
+        let (prev_edge,middle_edge) = self.node_chain_edges(upstream)?;
+        let (down_in,next_edge)     = self.node_chain_edges(downstream)?;
+        if middle_edge != down_in || prev_edge == next_edge { return None }
+        let upstream_input    = self.edge_target(prev_edge)?;
+        let downstream_input  = self.edge_target(middle_edge)?;
+        let upstream_output   = self.edge_source(middle_edge)?;
+        let downstream_output = self.edge_source(next_edge)?;
+        let upstream_node     = self.nodes.get_cloned_ref(&upstream)?;
+        let downstream_node   = self.nodes.get_cloned_ref(&downstream)?;
+        upstream_node.in_edges.clear();
+        upstream_node.out_edges.clear();
+        downstream_node.in_edges.clear();
+        downstream_node.out_edges.clear();
+        self.set_edge_target(prev_edge,downstream_input);
+        self.set_edge_source(middle_edge,downstream_output);
+        self.set_edge_target(middle_edge,upstream_input);
+        self.set_edge_source(next_edge,upstream_output);
+        Some([prev_edge,middle_edge,next_edge])
+    }
+}
+
+
+// === Collapse ===
+
+impl GraphEditorModel {
+    /// Replace the given nodes with the `new_node`. The edges between the collapsed nodes are
+    /// removed and the edges crossing the boundary of the collapsed set are reconnected to the new
+    /// node, which is placed at the mean position of the collapsed ones.
+    ///
+    /// This function does not emit `node_removed` nor any edge events, as the code is going to be
+    /// updated as a whole by the controller in response to the `nodes_collapsed` event.
+    fn collapse_nodes(&self, node_ids:&[NodeId], new_node:NodeId) -> NodeCollapse {
+        let collapsed    = node_ids.iter().copied().unique().collect_vec();
+        let is_collapsed = |id:&NodeId| collapsed.contains(id);
+        let edges        = collapsed.iter().flat_map(|id| self.node_in_and_out_edges(*id));
+        let edges        = edges.unique().collect_vec();
+        let mut inputs   = Vec::new();
+        let mut outputs  = Vec::new();
+        for edge in edges {
+            let source = self.edge_source(edge);
+            let target = self.edge_target(edge);
+            match (source,target) {
+                (Some(source),Some(target)) => {
+                    match (is_collapsed(&source.node_id),is_collapsed(&target.node_id)) {
+                        (false,true) => inputs.push(CollapseBoundaryEdge {
+                            edge, external:source, internal:target
+                        }),
+                        (true,false) => outputs.push(CollapseBoundaryEdge {
+                            edge, external:target, internal:source
+                        }),
+                        _ => self.remove_edge(edge),
+                    }
+                }
+                _ => self.remove_edge(edge),
+            }
+        }
+
+        let positions = collapsed.iter().filter_map(|id| self.get_node_position(*id));
+        let positions = positions.map(|pos| pos.xy()).collect_vec();
+        if !positions.is_empty() {
+            let sum = positions.iter().fold(Vector2::zeros(),|sum,pos| sum + pos);
+            self.set_node_position(new_node,sum / positions.len() as f32);
+        }
+        for boundary in &inputs {
+            self.set_edge_target(boundary.edge,EdgeEndpoint::new(new_node,default()));
+        }
+        for boundary in &outputs {
+            self.set_edge_source(boundary.edge,EdgeEndpoint::new(new_node,default()));
+        }
+        for node_id in &collapsed {
+            self.remove_node(*node_id);
+        }
+        NodeCollapse {collapsed,new_node,inputs,outputs}
+    }
+}
+
+
+// === Removal Preview ===
+
+impl GraphEditorModel {
+    fn removal_preview_of(&self, node_ids:&[NodeId]) -> RemovalPreview {
+        let removed_nodes  = node_ids.iter().copied().unique().collect_vec();
+        let edges          = removed_nodes.iter().flat_map(|id| self.node_in_and_out_edges(*id));
+        let edges          = edges.unique().collect_vec();
+        let affected_nodes = edges.iter().filter_map(|id| self.edge_target(*id));
+        let affected_nodes = affected_nodes.map(|target| target.node_id);
+        let affected_nodes = affected_nodes.filter(|id| !removed_nodes.contains(id));
+        let affected_nodes = affected_nodes.unique().collect();
+        RemovalPreview {removed_nodes,affected_nodes,edges}
+    }
+
+    fn show_removal_preview(&self, node_ids:&[NodeId], neutral_color:color::Lcha) {
+        self.hide_removal_preview(neutral_color);
+        let preview = self.removal_preview_of(node_ids);
+        *self.removal_preview.borrow_mut() = preview.clone();
+        self.highlight_removal_preview(&preview,true,neutral_color);
+    }
+
+    fn hide_removal_preview(&self, neutral_color:color::Lcha) {
+        let preview = self.removal_preview.take();
+        self.highlight_removal_preview(&preview,false,neutral_color);
+    }
+
+    fn highlight_removal_preview
+    (&self, preview:&RemovalPreview, highlight:bool, neutral_color:color::Lcha) {
+        let set_role = |node_id:&NodeId, role:node::RemovalRole| {
+            if let Some(node) = self.nodes.get_cloned_ref(node_id) {
+                node.set_removal_preview.emit(if highlight { Some(role) } else { None });
+            }
+        };
+        preview.removed_nodes.iter().for_each(|id| set_role(id,node::RemovalRole::Removed));
+        preview.affected_nodes.iter().for_each(|id| set_role(id,node::RemovalRole::Affected));
+        for edge_id in &preview.edges {
+            self.refresh_edge_color(*edge_id,neutral_color);
+        }
+    }
+}
+
+
+// === Layout ===
+
+impl GraphEditorModel {
+    fn layout_description(&self) -> GraphLayoutDescription {
+        let nodes = self.nodes.all.keys().into_iter().filter_map(|node_id| {
+            self.nodes.get_cloned_ref(&node_id).map(|node| {
+                let position      = node.position().xy();
+                let enabled       = node.visualization_enabled.value();
+                let visualization = node.visualization_path.value().filter(|_| enabled);
+                (node_id,NodeLayout{position,visualization})
+            })
+        }).collect();
+        GraphLayoutDescription {nodes}
+    }
+}
+
+
 // === Position ===
 
 impl GraphEditorModel {
@@ -1753,6 +3659,8 @@ impl GraphEditorModel {
                 t.x = position.x;
                 t.y = position.y;
             });
+            self.declutter.set_node_position(node_id,position);
+            self.culling.set_node_position(node_id,position);
             for edge_id in self.node_in_and_out_edges(node_id) {
                 self.refresh_edge_position(edge_id);
             }
@@ -1781,6 +3689,32 @@ impl GraphEditorModel {
         self.nodes.recompute_grid(node_ids.iter().cloned().collect());
     }
 
+    /// Index the anchors of the nodes which are not dragged, for the alignment guides.
+    fn start_alignment_guides(&self, dragged:&[NodeId]) {
+        let boxes = self.nodes.all.raw.borrow().iter()
+            .filter(|(id,_)| !dragged.contains(id))
+            .map(|(_,node)| node.bounding_box.value())
+            .collect_vec();
+        self.alignment_guides.set_index(alignment::Index::new(boxes));
+    }
+
+    /// Display the alignment guides of the dragged node moved to the given position.
+    fn update_alignment_guides(&self, node_id:NodeId, position:Vector2) {
+        if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+            let current = node.bounding_box.value();
+            let size    = Vector2(current.width(),current.height());
+            let origin  = position - Vector2(0.0,size.y / 2.0);
+            let bbox    = selection::BoundingBox::from_position_and_size(origin,size);
+            self.alignment_guides.update(&bbox);
+        }
+    }
+
+    /// The width of the node, or zero if it does not exist.
+    pub fn node_width(&self, node_id:NodeId) -> f32 {
+        let node = self.nodes.get_cloned_ref(&node_id);
+        node.map(|node| node.bounding_box.value().width()).unwrap_or_default()
+    }
+
     pub fn node_position(&self, node_id:impl Into<NodeId>) -> Vector2<f32> {
         let node_id = node_id.into();
         self.nodes.get_cloned_ref(&node_id).map(|node| node.position().xy()).unwrap_or_default()
@@ -1797,28 +3731,146 @@ impl GraphEditorModel {
         (node_id,new_position)
     }
 
+    /// Schedule refreshing the edge position. The edge is updated and redrawn in the next
+    /// animation frame, see `EdgeRefreshQueue`.
     pub fn refresh_edge_position(&self, edge_id:EdgeId) {
-        self.refresh_edge_source_position(edge_id);
-        self.refresh_edge_target_position(edge_id);
+        self.edge_refresh.schedule_layout(edge_id);
     }
 
+    /// Schedule refreshing the edge source size. The edge is updated and redrawn in the next
+    /// animation frame, see `EdgeRefreshQueue`.
     pub fn refresh_edge_source_size(&self, edge_id:EdgeId) {
-        if let Some(edge) = self.edges.get_cloned_ref(&edge_id) {
-            if let Some(edge_source) = edge.source() {
-                if let Some(node) = self.nodes.get_cloned_ref(&edge_source.node_id) {
-                    edge.view.frp.source_width.emit(node.model.width());
-                    edge.view.frp.source_height.emit(node.model.height());
+        self.edge_refresh.schedule_layout(edge_id);
+    }
+
+    /// Schedule redrawing the edge in the next animation frame, see `EdgeRefreshQueue`.
+    pub fn redraw_edge(&self, edge_id:EdgeId) {
+        self.edge_refresh.schedule_layout(edge_id);
+    }
+
+    /// Schedule refreshing the edge color in the next animation frame, see `EdgeRefreshQueue`.
+    pub fn refresh_edge_color(&self, edge_id:EdgeId, neutral_color:color::Lcha) {
+        self.edge_refresh.schedule_color(edge_id,neutral_color);
+    }
+
+    /// Apply all the refreshes scheduled since the last flush. Every edge is redrawn at most once.
+    /// The refreshes are deferred while a batch is in progress, see `GraphEditor::batch`.
+    pub fn flush_edge_refresh(&self) {
+        if !self.edge_refresh.is_empty() && !self.batch.is_active() {
+            for (edge_id,neutral_color) in self.edge_refresh.color.mem_take() {
+                self.update_edge_color(edge_id,neutral_color);
+            }
+            for edge_id in self.edge_refresh.layout.mem_take() {
+                if let Some(edge) = self.edges.get_cloned_ref(&edge_id) {
+                    self.update_edge_source_position(&edge);
+                    self.update_edge_target_position(&edge);
+                    self.update_edge_source_size(&edge);
+                    self.update_edge_culling(&edge);
                     edge.view.frp.redraw.emit(());
                 }
             }
-        };
+            if self.edge_refresh.unresolved_types.take() {
+                self.refresh_unresolved_types();
+            }
+            if self.edge_refresh.connections.take() {
+                self.refresh_connections();
+            }
+        }
     }
 
-    pub fn refresh_edge_color(&self, edge_id:EdgeId, neutral_color:color::Lcha) {
+    fn update_edge_color(&self, edge_id:EdgeId, neutral_color:color::Lcha) {
         if let Some(edge) = self.edges.get_cloned_ref(&edge_id) {
             let color = self.edge_color(edge_id, neutral_color);
+            let color = if self.declutter.is_faded(edge_id) {
+                self.faded_edge_color(color)
+            } else { color };
             edge.view.frp.set_color.emit(color);
+            edge.view.frp.set_dashed.emit(self.is_edge_type_unknown(edge_id));
+        };
+    }
+
+    /// Check whether the edge connects two ports, but neither of their types is known.
+    fn is_edge_type_unknown(&self, edge_id:EdgeId) -> bool {
+        let connected = self.edges.get_cloned_ref(&edge_id).map(|edge| {
+            edge.has_source() && edge.has_target()
+        }).unwrap_or(false);
+        let types_unknown = || {
+            self.edge_target_type(edge_id).is_none() && self.edge_source_type(edge_id).is_none()
+        };
+        connected && types_unknown()
+    }
+
+    /// Emit `unresolved_types_changed` if the set of the input ports connected with the edges of
+    /// unknown type has changed.
+    fn refresh_unresolved_types(&self) {
+        let edges      = self.edges.keys().into_iter();
+        let unresolved = edges.filter(|edge_id| self.is_edge_type_unknown(*edge_id));
+        let ports      = unresolved.filter_map(|edge_id| {
+            self.edges.get_cloned_ref(&edge_id)?.target().map(|t| (t.node_id,t.port))
+        });
+        let mut ports  = ports.collect_vec();
+        ports.sort_by_key(|(node_id,_)| *node_id);
+        let previous   = self.frp.unresolved_types_changed.value();
+        let changed    = ports.len() != previous.len()
+            || ports.iter().collect::<HashSet<_>>() != previous.iter().collect::<HashSet<_>>();
+        if changed {
+            self.frp.source.unresolved_types_changed.emit(ports);
+        }
+    }
+
+    /// All the connections of the graph: the sources and the targets of the edges attached at both
+    /// ends, sorted by the source and the target node. The edges being dragged are not included.
+    pub fn connections(&self) -> Vec<(EdgeEndpoint,EdgeEndpoint)> {
+        let edges           = self.edges.keys().into_iter();
+        let connections     = edges.filter_map(|edge_id| {
+            let edge = self.edges.get_cloned_ref(&edge_id)?;
+            Some((edge.source()?,edge.target()?))
+        });
+        let mut connections = connections.collect_vec();
+        connections.sort_by_key(|(source,target)| (source.node_id,target.node_id));
+        connections
+    }
+
+    /// The edges connecting the given endpoints, in no particular order.
+    pub fn edges_connecting(&self, source:&EdgeEndpoint, target:&EdgeEndpoint) -> Vec<EdgeId> {
+        let edges = self.edges.all.raw.borrow();
+        edges.values().filter_map(|edge| {
+            let connects = edge.source().as_ref() == Some(source)
+                && edge.target().as_ref() == Some(target);
+            connects.as_some(edge.id())
+        }).collect()
+    }
+
+    /// Emit `connections_changed` if the set of the connections has changed.
+    fn refresh_connections(&self) {
+        let connections = self.connections();
+        let previous    = self.frp.connections_changed.value();
+        let as_set      = |connections:&[(EdgeEndpoint,EdgeEndpoint)]| {
+            connections.iter().cloned().collect::<HashSet<_>>()
         };
+        let changed     = connections.len() != previous.len()
+            || as_set(&connections) != as_set(&previous);
+        if changed {
+            self.frp.source.connections_changed.emit(connections);
+        }
+    }
+
+    fn update_edge_source_size(&self, edge:&Edge) {
+        if let Some(edge_source) = edge.source() {
+            if let Some(node) = self.nodes.get_cloned_ref(&edge_source.node_id) {
+                edge.view.frp.source_width.emit(node.model.width());
+                edge.view.frp.source_height.emit(node.model.height());
+            }
+        }
+    }
+
+    /// Play the data pulse on all the output edges of the node.
+    fn play_data_pulse(&self, node_id:NodeId) {
+        for edge_id in self.node_out_edges(node_id) {
+            if let Some(edge) = self.edges.get_cloned_ref(&edge_id) {
+                edge.view.frp.pulse.emit(());
+            }
+        }
     }
 
     fn refresh_all_edge_colors(&self, neutral_color:color::Lcha) {
@@ -1827,30 +3879,35 @@ impl GraphEditorModel {
         }
     }
 
-    pub fn refresh_edge_source_position(&self, edge_id:EdgeId) {
-        if let Some(edge) = self.edges.get_cloned_ref(&edge_id) {
-            if let Some(edge_source) = edge.source() {
-                if let Some(node) = self.nodes.get_cloned_ref(&edge_source.node_id) {
-                    edge.mod_position(|p| {
-                        p.x = node.position().x + node.model.width()/2.0;
-                        p.y = node.position().y;
-                    });
-                }
+    /// Re-color all the edges and ports, e.g. after the mapping of types to colors was changed.
+    fn refresh_type_colors(&self, neutral_color:color::Lcha) {
+        for node_id in self.nodes.all.keys() {
+            if let Some(node) = self.nodes.get_cloned_ref(&node_id) {
+                node.refresh_type_colors.emit(());
             }
-        };
+        }
+        self.refresh_all_edge_colors(neutral_color);
     }
 
-    pub fn refresh_edge_target_position(&self, edge_id:EdgeId) {
-        if let Some(edge) = self.edges.get_cloned_ref(&edge_id) {
-            if let Some(edge_target) = edge.target() {
-                if let Some(node) = self.nodes.get_cloned_ref(&edge_target.node_id) {
-                    let offset = node.model.input.port_offset(&edge_target.port).unwrap_or_default();
-                    let pos = node.position().xy() + offset;
-                    edge.view.frp.target_position.emit(pos);
-                    edge.view.frp.redraw.emit(());
-                }
+    fn update_edge_source_position(&self, edge:&Edge) {
+        if let Some(edge_source) = edge.source() {
+            if let Some(node) = self.nodes.get_cloned_ref(&edge_source.node_id) {
+                edge.mod_position(|p| {
+                    p.x = node.position().x + node.model.width()/2.0;
+                    p.y = node.position().y;
+                });
             }
-        };
+        }
+    }
+
+    fn update_edge_target_position(&self, edge:&Edge) {
+        if let Some(edge_target) = edge.target() {
+            if let Some(node) = self.nodes.get_cloned_ref(&edge_target.node_id) {
+                let offset = node.model.input.port_offset(&edge_target.port).unwrap_or_default();
+                let pos = node.position().xy() + offset;
+                edge.view.frp.target_position.emit(pos);
+            }
+        }
     }
 
     fn map_node<T>(&self, id:NodeId, f:impl FnOnce(Node)->T) -> Option<T> {
@@ -1969,11 +4026,17 @@ impl GraphEditorModel {
     ///
     /// In profiling mode, this is just a neutral gray.
     ///
+    /// If the execution order overlay is visible, the color computed below is additionally mixed
+    /// with a color ranging from `early` to `late` (see `theme::graph_editor::execution_order`),
+    /// depending on the position of the edge source in the execution order.
+    ///
     /// In normal mode, the algorithm works as follow:
     /// 1. We query the type of the currently hovered port, if any.
     /// 2. In case the previous point returns None, we query the edge target type, if any.
     /// 3. In case the previous point returns None, we query the edge source type, if any.
     /// 4. In case the previous point returns None, we use the generic type (gray color).
+    /// 5. If the edges inherit the node color overrides, the color is blended with the override of
+    ///    the source node, if any.
     ///
     /// This might need to be more sophisticated in the case of polymorphic types. For example,
     /// consider the edge source type to be `(a,Number)`, and target to be `(Text,a)`. These unify
@@ -1981,13 +4044,30 @@ impl GraphEditorModel {
     fn edge_color(&self, edge_id:EdgeId, neutral_color:color::Lcha) -> color::Lcha {
         // FIXME : StyleWatch is unsuitable here, as it was designed as an internal tool for shape system (#795)
         let styles = StyleWatch::new(&self.scene().style_sheet);
+        if self.removal_preview.borrow().edges.contains(&edge_id) {
+            return styles.get_color(theme::graph_editor::edge::removal_preview).into()
+        }
         match self.frp.view_mode.value() {
             view::Mode::Normal => {
                 let edge_type = self.edge_hover_type()
                     .or_else(|| self.edge_target_type(edge_id))
                     .or_else(|| self.edge_source_type(edge_id));
                 let opt_color = edge_type.map(|t|type_coloring::compute(&t,&styles));
-                opt_color.unwrap_or(neutral_color)
+                let color     = opt_color.unwrap_or(neutral_color);
+                let override_mix_path = theme::graph_editor::node::color_override::edge_mix;
+                let override_mix      = styles.get_number(override_mix_path);
+                let color_override    = self.edge_inherited_color_override(edge_id);
+                let color = type_coloring::blend_override(color,color_override,override_mix);
+                match self.edge_execution_progress(edge_id) {
+                    Some(progress) => {
+                        use theme::graph_editor::execution_order as theme_path;
+                        let early    = styles.get_color(theme_path::early).into();
+                        let late     = styles.get_color(theme_path::late).into();
+                        let edge_mix = styles.get_number_or(theme_path::edge_mix,0.35);
+                        color::mix(color,color::mix(early,late,progress),edge_mix)
+                    }
+                    None => color
+                }
             },
             view::Mode::Profiling => {
                 neutral_color
@@ -1995,6 +4075,13 @@ impl GraphEditorModel {
         }
     }
 
+    /// The color override of the edge source node, if the edges inherit the overrides.
+    fn edge_inherited_color_override(&self, edge_id:EdgeId) -> Option<color::Lcha> {
+        if !self.frp.edge_color_inheritance_enabled.value() { return None }
+        let source = self.edge_source(edge_id)?;
+        self.nodes.get_cloned_ref(&source.node_id)?.color_override.value()
+    }
+
     fn first_detached_edge(&self) -> Option<EdgeId> {
         self.edges.detached_edges_iter().next()
     }
@@ -2041,8 +4128,9 @@ impl display::Object for GraphEditorModel {
 
 #[derive(Debug,Clone,CloneRef)]
 pub struct GraphEditor {
-    pub model : GraphEditorModelWithNetwork,
-    pub frp   : Frp,
+    pub model      : GraphEditorModelWithNetwork,
+    pub frp        : Frp,
+    emission_depth : session::EmissionDepth,
 }
 
 impl Deref for GraphEditor {
@@ -2053,6 +4141,17 @@ impl Deref for GraphEditor {
 }
 
 impl GraphEditor {
+    /// Constructor allocating the node and edge identifiers in the given way. The views created by
+    /// `Application::new_view` follow the `deterministic_ids` startup argument instead.
+    pub fn new_with_id_allocation(app:&Application, id_allocation:IdAllocation) -> Self {
+        new_graph_editor(app,id_allocation)
+    }
+
+    /// The way the node and edge identifiers are allocated.
+    pub fn id_allocation(&self) -> IdAllocation {
+        self.model.ids.allocation()
+    }
+
     /// Add a new node and returns its ID.
     pub fn add_node(&self) -> NodeId {
         self.frp.add_node.emit(());
@@ -2108,7 +4207,7 @@ impl application::View for GraphEditor {
     }
 
     fn new(app:&Application) -> Self {
-        new_graph_editor(app)
+        new_graph_editor(app,IdAllocation::from_args())
     }
 
     fn app(&self) -> &Application {
@@ -2117,61 +4216,117 @@ impl application::View for GraphEditor {
 
     fn default_shortcuts() -> Vec<application::shortcut::Shortcut> {
         use shortcut::ActionType::*;
+        use interaction::Mode::*;
+        // The releases of the keys, and the toggles bound to both the press and the release, are
+        // handled in every mode, so a mode change made while a key is held does not leave the
+        // state changed by its press.
+        let any_mode    : &[interaction::Mode] =
+            &[Navigate,Edit,Connect,KeyboardConnect,ReadOnly,Present];
+        let not_editing : &[interaction::Mode] = &[Navigate,Connect];
+        let viewing     : &[interaction::Mode] = &[Navigate,Connect,ReadOnly];
+        let presenting  : &[interaction::Mode] = &[Navigate,Connect,ReadOnly,Present];
+        let editable    : &[interaction::Mode] = &[Navigate,Edit,Connect,KeyboardConnect];
+        let unlocked    : &[interaction::Mode] = &[Navigate,Edit,Connect,KeyboardConnect,ReadOnly];
+        let navigating  : &[interaction::Mode] = &[Navigate];
+        let kb_connect  : &[interaction::Mode] = &[KeyboardConnect];
+        let fs_vis      = "is_fs_visualization_displayed";
+        let no_fs_vis   = "!is_fs_visualization_displayed";
+        let comparison  = "is_vis_comparison_displayed";
+        let debug       = "debug_mode";
         (&[
           // === Drag ===
-            (Press   , ""              , "left-mouse-button" , "node_press")
-          , (Release , ""              , "left-mouse-button" , "node_release")
-          , (Press   , "!node_editing" , "backspace"         , "remove_selected_nodes")
-          , (Press   , "!node_editing" , "delete"         , "remove_selected_nodes")
-          , (Press   , ""              , "cmd g"             , "collapse_selected_nodes")
+            (Press   , any_mode    , ""  , "left-mouse-button" , "node_press")
+          , (Release , any_mode    , ""  , "left-mouse-button" , "node_release")
+          , (Press   , editable    , ""  , "cmd g"             , "collapse_selected_nodes")
+          , (Press   , not_editing , ""  , "backspace"         , "start_removal_preview_for_selected_nodes")
+          , (Release , not_editing , ""  , "backspace"         , "remove_selected_nodes")
+          , (Press   , not_editing , ""  , "delete"            , "remove_selected_nodes")
+          , (Press   , not_editing , ""  , "cmd alt up"        , "move_selected_node_upstream")
+          , (Press   , not_editing , ""  , "cmd alt down"      , "move_selected_node_downstream")
+          , (Press   , not_editing , ""  , "cmd shift u"       , "reveal_masked_ports_of_selected_nodes")
 
           // === Visualization ===
-          , (Press       , "!node_editing"                 , "space" , "press_visualization_visibility"       )
-          , (DoublePress , "!node_editing"                 , "space" , "double_press_visualization_visibility")
-          , (Release     , "!node_editing"                 , "space" , "release_visualization_visibility"     )
-          , (Press       , ""                              , "cmd i" , "reload_visualization_registry"        )
-          , (Press       , "is_fs_visualization_displayed" , "space" , "close_fullscreen_visualization"       )
-          , (Press       , ""              , "cmd" , "enable_quick_visualization_preview")
-          , (Release     , ""              , "cmd" , "disable_quick_visualization_preview")
-
-
-            // === Selection ===
-          , (Press   , "" , "shift"                   , "enable_node_multi_select")
-          , (Press   , "" , "shift left-mouse-button" , "enable_node_multi_select")
-          , (Release , "" , "shift"                   , "disable_node_multi_select")
-          , (Release , "" , "shift left-mouse-button" , "disable_node_multi_select")
-          , (Press   , "" , "shift ctrl"              , "toggle_node_merge_select")
-          , (Release , "" , "shift ctrl"              , "toggle_node_merge_select")
-          , (Press   , "" , "shift alt"               , "toggle_node_subtract_select")
-          , (Release , "" , "shift alt"               , "toggle_node_subtract_select")
-          , (Press   , "" , "shift ctrl alt"          , "toggle_node_inverse_select")
-          , (Release , "" , "shift ctrl alt"          , "toggle_node_inverse_select")
+          , (Press       , unlocked   , ""         , "cmd i"  , "reload_visualization_registry")
+          , (Press       , presenting , ""         , "space"  , "press_visualization_visibility")
+          , (DoublePress , presenting , ""         , "space"  , "double_press_visualization_visibility")
+          , (Release     , any_mode   , ""         , "space"  , "release_visualization_visibility")
+          , (Press       , any_mode   , fs_vis     , "space"  , "close_fullscreen_visualization")
+          , (Press       , any_mode   , fs_vis     , "escape" , "close_fullscreen_visualization")
+          , (Press       , any_mode   , comparison , "escape" , "close_visualization_comparison")
+          , (Press       , presenting , ""         , "cmd"    , "enable_quick_visualization_preview")
+          , (Release     , any_mode   , ""         , "cmd"    , "disable_quick_visualization_preview")
+
+          // === Selection ===
+          , (Press   , viewing  , "" , "shift"                   , "enable_node_multi_select")
+          , (Press   , viewing  , "" , "shift left-mouse-button" , "enable_node_multi_select")
+          , (Release , any_mode , "" , "shift"                   , "disable_node_multi_select")
+          , (Release , any_mode , "" , "shift left-mouse-button" , "disable_node_multi_select")
+          , (Press   , any_mode , "" , "shift ctrl"              , "toggle_node_merge_select")
+          , (Release , any_mode , "" , "shift ctrl"              , "toggle_node_merge_select")
+          , (Press   , any_mode , "" , "shift alt"               , "toggle_node_subtract_select")
+          , (Release , any_mode , "" , "shift alt"               , "toggle_node_subtract_select")
+          , (Press   , any_mode , "" , "shift ctrl alt"          , "toggle_node_inverse_select")
+          , (Release , any_mode , "" , "shift ctrl alt"          , "toggle_node_inverse_select")
+          , (Press   , viewing  , "" , "e"                       , "enable_edge_area_select")
+          , (Release , any_mode , "" , "e"                       , "disable_edge_area_select")
+
+          // === Accessibility ===
+          , (Press , viewing , "" , "alt down"  , "focus_next_node")
+          , (Press , viewing , "" , "alt up"    , "focus_previous_node")
+          , (Press , viewing , "" , "alt right" , "focus_next_port")
+          , (Press , viewing , "" , "alt left"  , "focus_previous_port")
+
+          // === Argument Editing ===
+          , (Press   , editable , "" , "alt"                   , "enable_argument_editing")
+          , (Press   , editable , "" , "alt left-mouse-button" , "enable_argument_editing")
+          , (Release , any_mode , "" , "alt"                   , "disable_argument_editing")
+          , (Release , any_mode , "" , "alt left-mouse-button" , "disable_argument_editing")
 
           // === Navigation ===
-          , (Press       , "!is_fs_visualization_displayed"              , "ctrl space"        , "cycle_visualization_for_selected_node")
-          , (DoublePress , ""              , "left-mouse-button" , "enter_hovered_node")
-          , (Press       , "!node_editing" , "enter"             , "enter_selected_node")
-          , (Press       , ""              , "alt enter"         , "exit_node")
+          , (Press       , presenting , no_fs_vis , "ctrl space"        , "cycle_visualization_for_selected_node")
+          , (Press       , presenting , ""        , "enter"             , "enter_selected_node")
+          , (DoublePress , any_mode   , ""        , "left-mouse-button" , "enter_hovered_node")
+          , (DoublePress , editable   , ""        , "left-mouse-button" , "split_pressed_edge")
+          , (Press       , any_mode   , ""        , "alt enter"         , "exit_node")
 
           // === Node Editing ===
-          , (Press   , "" , "cmd"                   , "edit_mode_on")
-          , (Release , "" , "cmd"                   , "edit_mode_off")
-          , (Press   , "" , "cmd enter"             , "edit_selected_node")
-          , (Press   , "" , "cmd left-mouse-button" , "edit_mode_on")
-          , (Release , "" , "cmd left-mouse-button" , "edit_mode_off")
-          , (Release , "" , "enter"                 , "stop_editing")
+          , (Press   , editable , "" , "cmd"                   , "edit_mode_on")
+          , (Release , any_mode , "" , "cmd"                   , "edit_mode_off")
+          , (Press   , editable , "" , "cmd enter"             , "edit_selected_node")
+          , (Press   , editable , "" , "cmd left-mouse-button" , "edit_mode_on")
+          , (Release , any_mode , "" , "cmd left-mouse-button" , "edit_mode_off")
+          , (Release , any_mode , "" , "enter"                 , "stop_editing")
+
+          // === Keyboard Connect ===
+          , (Press , navigating , "" , "cmd shift l" , "start_keyboard_connect")
+          , (Press , kb_connect , "" , "right"       , "keyboard_connect_next_port")
+          , (Press , kb_connect , "" , "down"        , "keyboard_connect_next_port")
+          , (Press , kb_connect , "" , "left"        , "keyboard_connect_previous_port")
+          , (Press , kb_connect , "" , "up"          , "keyboard_connect_previous_port")
+          , (Press , kb_connect , "" , "enter"       , "confirm_keyboard_connect")
+          , (Press , kb_connect , "" , "escape"      , "cancel_keyboard_connect")
 
           // === Profiling Mode ===
-          , (Press   , "" , "cmd p"                 , "toggle_profiling_mode")
+          , (Press , unlocked , "" , "cmd p"     , "toggle_profiling_mode")
+          , (Press , unlocked , "" , "cmd alt p" , "toggle_profiling_heatmap")
 
-          // === Debug ===
-          , (Press , "debug_mode" , "ctrl d"           , "debug_set_test_visualization_data_for_selected_node")
-          , (Press , "debug_mode" , "ctrl shift enter" , "debug_push_breadcrumb")
-          , (Press , "debug_mode" , "ctrl shift up"    , "debug_pop_breadcrumb")
-          , (Press , "debug_mode" , "ctrl n"           , "add_node_at_cursor")
+          // === Execution Order ===
+          , (Press , unlocked , "" , "cmd shift e" , "toggle_execution_order")
 
-        ]).iter().map(|(a,b,c,d)|Self::self_shortcut_when(*a,*c,*d,*b)).collect()
+          // === Edge De-clutter ===
+          , (Press , unlocked , "" , "cmd shift d" , "toggle_edge_declutter")
 
+          // === Debug ===
+          , (Press , any_mode , debug , "ctrl d"           , "debug_set_test_visualization_data_for_selected_node")
+          , (Press , any_mode , debug , "ctrl shift enter" , "debug_push_breadcrumb")
+          , (Press , any_mode , debug , "ctrl shift up"    , "debug_pop_breadcrumb")
+          , (Press , any_mode , debug , "ctrl n"           , "add_node_at_cursor")
+          , (Press , any_mode , debug , "ctrl shift o"     , "toggle_debug_overlay")
+
+        ]).iter().map(|(action_type,modes,status,keys,action)| {
+            let condition = interaction::Mode::condition_when(modes,status);
+            Self::self_shortcut_when(*action_type,*keys,*action,condition.as_str())
+        }).collect()
     }
 }
 
@@ -2195,13 +4350,30 @@ pub fn enable_disable_toggle
     out.into()
 }
 
+/// Check whether the visualization containers display visualizations of the same type.
+fn same_vis_type
+(left:&visualization::container::Frp, right:&visualization::container::Frp) -> bool {
+    let path = |vis:Option<visualization::Definition>| vis.map(|vis| vis.signature.path);
+    let left = path(left.visualisation.value());
+    left.is_some() && left == path(right.visualisation.value())
+}
+
+/// Return a function wrapping a cursor style in a named request of the given priority.
+fn style_request
+(name:&'static str, priority:i32) -> impl Fn(&cursor::Style) -> cursor::StyleRequest {
+    move |style| cursor::StyleRequest::new(name,priority,style.clone())
+}
+
 #[allow(unused_parens)]
-fn new_graph_editor(app:&Application) -> GraphEditor {
+fn new_graph_editor(app:&Application, id_allocation:IdAllocation) -> GraphEditor {
     let world                = &app.display;
     let scene                = world.scene();
     let cursor               = &app.cursor;
     let frp                  = Frp::new();
-    let model                = GraphEditorModelWithNetwork::new(app,cursor.clone_ref(),&frp);
+    let emission_depth       = session::EmissionDepth::default();
+    emission_depth.begin(&frp.network,&frp.input);
+    let model                = GraphEditorModelWithNetwork::new
+        (app,cursor.clone_ref(),&frp,id_allocation);
     let network              = &frp.network;
     let nodes                = &model.nodes;
     let edges                = &model.edges;
@@ -2222,6 +4394,37 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     // === Scene Navigation ===
     // ========================
 
+    // === Split View ===
+
+    let split_view_enabled = enable_disable_toggle
+        ( network
+        , &inputs.enable_split_view
+        , &inputs.disable_split_view
+        , &inputs.toggle_split_view
+        );
+    frp::extend! { network
+        out.source.split_view_enabled <+ split_view_enabled;
+        eval out.split_view_enabled ((enabled)
+            if *enabled { model.split_view.enable() } else { model.split_view.disable() });
+        eval_ scene.frp.shape (model.split_view.update_layout());
+
+        hovered_viewport <- all_with(&mouse.position,&out.split_view_enabled,|position,enabled| {
+            if *enabled { split_view::Viewport::at(position.x) }
+            else        { split_view::Viewport::Primary }
+        });
+        hovered_viewport <- hovered_viewport.on_change();
+        eval hovered_viewport ((viewport) model.split_view.set_hovered(*viewport));
+        out.source.hovered_viewport <+ hovered_viewport;
+        primary_hovered   <- out.hovered_viewport.map(|v| *v == split_view::Viewport::Primary);
+        secondary_hovered <- primary_hovered.map(|t| !t);
+
+        // The scene reports the changes of its main camera only.
+        secondary_camera_changed <- scene.frp.frame_time.filter(f_!(
+            model.split_view.update_camera()
+        ));
+        camera_changed <- any_(scene.frp.camera_changed,secondary_camera_changed);
+    }
+
     frp::extend! { network
         no_vis_selected   <- out.some_visualisation_selected.on_false();
         some_vis_selected <- out.some_visualisation_selected.on_true();
@@ -2229,14 +4432,81 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
         set_navigator_false  <- inputs.set_navigator_disabled.on_true();
         set_navigator_true   <- inputs.set_navigator_disabled.on_false();
 
-        disable_navigator <- any_(&set_navigator_false,&some_vis_selected);
-        enable_navigator  <- any_(&set_navigator_true,&no_vis_selected);
+        // The navigation is locked while the fullscreen manager displays a visualization. Leaving
+        // the fullscreen unlocks it, unless it is disabled for another reason.
+        fs_lock           <- out.visualization_fullscreen_entered.constant(());
+        fs_unlock         <- out.visualization_fullscreen_exited.gate_not(&out.navigator_active);
+        disable_navigator <- any_(&set_navigator_false,&some_vis_selected,&fs_lock);
+        enable_navigator  <- any_(&set_navigator_true,&no_vis_selected,&fs_unlock);
+        enable_navigator  <- enable_navigator.filter(f_!(!model.fullscreen.is_active()));
+        enable_navigator  <- enable_navigator.gate(&primary_hovered);
 
         eval_ disable_navigator ( model.navigator.disable() );
         eval_ enable_navigator  ( model.navigator.enable()  );
 
         out.source.navigator_active <+ inputs.set_navigator_disabled
                                     || out.some_visualisation_selected;
+
+        // Only the navigator of the hovered viewport reacts to the mouse.
+        leave_primary <- primary_hovered.on_false();
+        enter_primary <- primary_hovered.on_true().gate_not(&out.navigator_active);
+        enter_primary <- enter_primary.filter(f_!(!model.fullscreen.is_active()));
+        eval_ leave_primary ( model.navigator.disable() );
+        eval_ enter_primary ( model.navigator.enable()  );
+        secondary_navigable <- all_with3(&secondary_hovered,&out.navigator_active
+            ,&out.is_fs_visualization_displayed,|hovered,locked,fs| *hovered && !locked && !fs);
+        eval secondary_navigable ((enabled) model.split_view.set_navigator_enabled(*enabled));
+
+
+        // === Scroll Navigation ===
+
+        // The wheel over the breadcrumbs scrolls them instead of navigating the scene.
+        out.source.scroll_navigation_enabled <+ inputs.set_scroll_navigation_enabled;
+        breadcrumbs_hovered <- model.breadcrumbs.hovered.sampler();
+        navigator_wheel     <- all_with(&out.scroll_navigation_enabled,&breadcrumbs_hovered,
+            |scroll,crumbs_hovered| !scroll && !crumbs_hovered);
+        eval navigator_wheel ([model](enabled) {
+            model.navigator.set_wheel_enabled(*enabled);
+            model.split_view.navigator().set_wheel_enabled(*enabled);
+        });
+
+        scroll_navigation <- all_with(&out.scroll_navigation_enabled,&breadcrumbs_hovered,
+            |scroll,crumbs_hovered| *scroll && !crumbs_hovered);
+        scroll_pan  <- mouse.wheel_pan.gate(&scroll_navigation);
+        scroll_zoom <- mouse.wheel_zoom.gate(&scroll_navigation);
+        eval scroll_pan ((delta) model.hovered_navigator().pan(Vector2(-delta.x,delta.y)));
+        eval scroll_zoom ([model,mouse,scene](amount) {
+            let focus = mouse.position.value() + scene.shape().value().center();
+            model.hovered_navigator().zoom(focus,*amount);
+        });
+
+
+        // === Camera Target ===
+
+        camera_flight <- inputs.set_camera_target.map2(&inputs.set_camera_easing,|t,e| (*t,*e));
+        eval camera_flight ([model](((position,zoom,duration),easing))
+            model.navigator.fly_to(*position,*zoom,*duration,*easing));
+        camera_moved <- any_(camera_changed,out.hovered_viewport).map(f_!([model] {
+            let camera = model.split_view.hovered_camera();
+            (camera.position().xy(),camera.zoom())
+        }));
+        out.source.camera_moved <+ camera_moved;
+        camera_fit <- inputs.zoom_to_fit.filter_map(f_!(model.camera_target_fitting_all_nodes()));
+        inputs.set_camera_target <+ camera_fit.map(|(position,zoom)|
+            (*position,*zoom,ZOOM_TO_FIT_DURATION_MS));
+
+
+        // === Connected Components ===
+
+        component_to_select <- inputs.select_component_of.map(f!((id) model.component_of(*id)));
+        inputs.deselect_all_nodes <+ component_to_select.constant(());
+        node_to_select <= component_to_select;
+        inputs.select_node <+ node_to_select;
+        component_fit <- inputs.zoom_to_component.filter_map(f!((id)
+            model.camera_target_fitting(&model.component_of(*id))
+        ));
+        inputs.set_camera_target <+ component_fit.map(|(position,zoom)|
+            (*position,*zoom,ZOOM_TO_FIT_DURATION_MS));
     }
 
 
@@ -2272,17 +4542,88 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
         target_to_enter <- inputs.enter_hovered_node.map(f_!(scene.mouse.target.get()));
 
         // Go level up on background click.
-        enter_on_background    <= target_to_enter.map(|target| target.is_background().as_some(()));
+        enter_on_background    <- target_to_enter.match(target if target.is_background() => ());
         out.source.node_exited <+ enter_on_background;
 
         // Go level down on node double click.
-        enter_node <= target_to_enter.map(|target| target.is_symbol().as_some(()));
+        enter_node <- target_to_enter.match(target if target.is_symbol() => ());
         node_switch_to_enter    <- out.node_hovered.sample(&enter_node).unwrap();
         node_to_enter           <- node_switch_to_enter.map(|switch| switch.on().cloned()).unwrap();
         out.source.node_entered <+ node_to_enter;
     }
 
 
+    // === Entered Calls ===
+
+    frp::extend! { network
+        eval inputs.set_method_pointer (((id,method_pointer))
+            model.set_method_pointer(*id,method_pointer.clone()));
+        out.source.call_entered  <+ out.node_entered.filter_map(f!((id) model.local_call(*id)));
+        out.source.stack_changed <+ model.breadcrumbs.stack_changed;
+    }
+
+
+
+    // =========================
+    // === Level Transitions ===
+    // =========================
+
+    let half_transition = Easing::new(network);
+    let background      = model.styles_frp.get_color(theme::application::background);
+    frp::extend! { network
+        transitions_disabled <- bool(&inputs.enable_level_transitions,
+                                     &inputs.disable_level_transitions);
+        half_duration        <- inputs.set_level_transition_duration.map(|t| *t / 2.0);
+
+        // The breadcrumbs change the level without emitting `node_entered` or `node_exited`.
+        entered_node <- any(...);
+        entered_node <+ out.node_entered.map(|id| Some(*id));
+        entered_node <+ model.breadcrumbs.breadcrumb_push.constant(None);
+        level_entered <- any_(&out.node_entered,&model.breadcrumbs.breadcrumb_push);
+        level_exited  <- any_(&out.node_exited,&model.breadcrumbs.breadcrumb_pop);
+        level_changed <- any_(&level_entered,&level_exited);
+        level_changed_immediately <- level_changed.gate(&transitions_disabled);
+        out.source.level_transition_covered  <+ level_changed_immediately;
+        out.source.level_transition_finished <+ level_changed_immediately;
+
+        transition_event <- any_mut::<transition::Event>();
+        transition_event <+ level_entered.gate_not(&transitions_disabled).constant(
+            transition::Event::Start(transition::Direction::Enter));
+        transition_event <+ level_exited.gate_not(&transitions_disabled).constant(
+            transition::Event::Start(transition::Direction::Exit));
+        transition <- transition_event.fsm(default(),transition::step);
+        phases     <- transition.zip_with_previous();
+        effect     <- transition._1().unwrap();
+        covered    <- effect.filter(|effect| matches!(effect,transition::Effect::Reveal(_)));
+        finished   <- effect.filter(|effect| *effect == transition::Effect::Finish);
+        half_start <- effect.filter(|effect| *effect != transition::Effect::Finish);
+        out.source.level_transition_running  <+ transition.map(|(p,_)| p.is_running()).on_change();
+        out.source.level_transition_covered  <+ covered.constant(());
+        out.source.level_transition_finished <+ finished.constant(());
+
+        // Each half of the transition is animated from 0 to 1.
+        half_transition.set_duration    <+ half_duration;
+        half_transition.stop_and_rewind <+ effect.constant(0.0);
+        half_transition.target          <+ half_start.constant(1.0);
+        half_finished    <- half_transition.on_end.filter(|status| status.is_normal());
+        transition_event <+ half_finished.constant(transition::Event::HalfFinished);
+
+        camera_start <- phases.filter(|((previous,_),_)| !previous.is_running());
+        camera_start <- camera_start.map(f_!([scene] {
+            let camera = scene.camera();
+            (camera.position().xy(),camera.zoom())
+        }));
+        flight <- effect.map4(&camera_start,&entered_node,&half_duration,
+            |effect,start,node,duration| (*effect,*start,*node,*duration));
+        eval flight (((effect,start,node,duration))
+            model.fly_for_level_transition(*effect,*start,*node,*duration));
+
+        veil_alpha <- half_transition.value.map2(&transition,|t,(phase,_)| phase.veil_alpha(*t));
+        _eval <- all_with3(&veil_alpha,&background,&out.camera_moved,
+            f!((alpha,color,_) model.transition_veil.update(*color,*alpha)));
+    }
+
+
 
     // ============================
     // === Project Name Editing ===
@@ -2313,8 +4654,12 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
 
     // === Mouse Cursor Transform ===
     frp::extend! { network
-        cursor_pos_in_scene <- cursor.frp.screen_position.map(f!((position)
-            scene.screen_to_scene_coordinates(*position).xy()
+        // The scene position of the cursor changes also when the camera moves, e.g. when
+        // auto-panning.
+        cursor_pos_on_camera_change <- cursor.frp.screen_position.sample(&camera_changed);
+        cursor_screen_pos   <- any(&cursor.frp.screen_position,&cursor_pos_on_camera_change);
+        cursor_pos_in_scene <- cursor_screen_pos.map(f!((position)
+            model.split_view.screen_to_scene_coordinates(*position).xy()
         ));
     }
 
@@ -2324,9 +4669,7 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     frp::extend! { network
         mouse_down_target <- mouse.down_primary.map(f_!(model.scene().mouse.target.get()));
         mouse_up_target   <- mouse.up_primary.map(f_!(model.scene().mouse.target.get()));
-        background_up     <= mouse_up_target.map(
-            |t| (t==&display::scene::PointerTarget::Background).as_some(())
-        );
+        background_up     <- mouse_up_target.match(display::scene::PointerTarget::Background => ());
 
         eval mouse_down_target([touch,model](target) {
             match target {
@@ -2359,8 +4702,10 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
         edit_mode             <- bool(&inputs.edit_mode_off,&inputs.edit_mode_on);
         node_to_edit          <- touch.nodes.down.gate(&edit_mode);
         edit_node             <- any(&node_to_edit,&inputs.edit_node);
+        edit_node             <- edit_node.gate_not(&out.read_only);
         stop_edit_on_bg_click <- touch.background.selected.gate(&node_in_edit_mode);
-        stop_edit             <- any(&stop_edit_on_bg_click,&inputs.stop_editing);
+        stop_edit_on_lock     <- out.read_only.on_true().gate(&node_in_edit_mode);
+        stop_edit             <- any(&stop_edit_on_bg_click,&inputs.stop_editing,&stop_edit_on_lock);
         edit_switch           <- edit_node.gate(&node_in_edit_mode);
         node_being_edited     <- out.node_being_edited.map(|n| n.unwrap_or_default());
 
@@ -2388,6 +4733,13 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
         });
     }
 
+    // === Argument Editing ===
+
+    frp::extend! { network
+        out.source.argument_editing <+ bool(&inputs.disable_argument_editing,
+            &inputs.enable_argument_editing);
+    }
+
     // === Add Node ===
     frp::extend! { network
 
@@ -2435,7 +4787,7 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     eval set_edge_hover ([model]((edge_id,pos)) {
          if let Some(edge) = model.edges.get_cloned_ref(edge_id){
             edge.frp.hover_position.emit(Some(*pos));
-            edge.frp.redraw.emit(());
+            model.redraw_edge(*edge_id);
         }
     });
 
@@ -2443,11 +4795,15 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     eval remove_split ([model](edge_id) {
          if let Some(edge) = model.edges.get_cloned_ref(edge_id){
             edge.frp.hover_position.emit(None);
-            edge.frp.redraw.emit(());
+            model.redraw_edge(*edge_id);
         }
     });
     edge_click <- map2(&edge_mouse_down,&cursor_pos_in_scene,|edge_id,pos|(*edge_id,*pos));
-    valid_edge_disconnect_click <- edge_click.gate_not(&has_detached_edge);
+    editable_edge_click         <- edge_click.gate_not(&out.read_only);
+    valid_edge_disconnect_click <- editable_edge_click.gate_not(&has_detached_edge);
+    // Must be evaluated before the press detaches the edge. See the Edge Splitting section.
+    pressed_connection <- valid_edge_disconnect_click.map(f!(((id,pos))
+        model.pressed_connection(*id,*pos)));
 
     edge_is_source_click <- valid_edge_disconnect_click.map(f!([model]((edge_id,pos)) {
         if let Some(edge) = model.edges.get_cloned_ref(edge_id){
@@ -2457,13 +4813,32 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
         }
     }));
 
-    edge_source_click <- valid_edge_disconnect_click.gate(&edge_is_source_click);
-    edge_target_click <- valid_edge_disconnect_click.gate_not(&edge_is_source_click);
+    // Pressing the handle at the edge end detaches that end, regardless of the edge layout.
+    handle_press        <- model.edge_handle_press.gate_not(&has_detached_edge);
+    handle_press        <- handle_press.gate_not(&out.read_only);
+    handle_click        <- map2(&handle_press,&cursor_pos_in_scene,|(id,port),pos|(*id,*port,*pos));
+    source_handle_click <- handle_click.filter_map(|(id,port,pos)|
+        (*port == component::edge::PortType::OutputPort).as_some((*id,*pos)));
+    target_handle_click <- handle_click.filter_map(|(id,port,pos)|
+        (*port == component::edge::PortType::InputPort).as_some((*id,*pos)));
+
+    edge_source_press <- valid_edge_disconnect_click.gate(&edge_is_source_click);
+    edge_target_press <- valid_edge_disconnect_click.gate_not(&edge_is_source_click);
+    edge_source_click <- any(edge_source_press,source_handle_click);
+    edge_target_click <- any(edge_target_press,target_handle_click);
 
     on_edge_source_unset <= edge_source_click.map(f!(((id,_)) model.with_edge_source(*id,|t|(*id,t))));
     on_edge_target_unset <= edge_target_click.map(f!(((id,_)) model.with_edge_target(*id,|t|(*id,t))));
     out.source.on_edge_source_unset <+ on_edge_source_unset;
     out.source.on_edge_target_unset <+ on_edge_target_unset;
+
+
+    // === Highlight ===
+
+    eval edge_over ((id) model.set_edge_ports_highlight(*id,true));
+    eval edge_out  ((id) model.set_edge_ports_highlight(*id,false));
+    hovered_ports <- all(out.hover_node_input,out.hover_node_output);
+    eval hovered_ports (((input,output)) model.highlight_port_edges(input,output));
     }
 
 
@@ -2482,13 +4857,15 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     attach_all_edge_inputs  <- any (port_input_mouse_up, inputs.press_node_input, inputs.set_detached_edge_targets);
     attach_all_edge_outputs <- any (port_output_mouse_up, inputs.press_node_output, inputs.set_detached_edge_sources);
 
-    create_edge_from_output <- node_output_touch.down.gate_not(&has_detached_edge_on_output_down);
-    create_edge_from_input  <- node_input_touch.down.map(|value| value.clone());
+    output_edge_down        <- node_output_touch.down.gate_not(&out.read_only);
+    create_edge_from_output <- output_edge_down.gate_not(&has_detached_edge_on_output_down);
+    create_edge_from_input  <- node_input_touch.down.gate_not(&out.read_only);
 
 
     // === Edge creation  ===
 
-    on_new_edge    <- any(&output_down,&input_down);
+    on_port_down   <- any(&output_down,&input_down);
+    on_new_edge    <- on_port_down.gate_not(&out.read_only);
     let selection_mode = selection::get_mode(network,inputs);
     keep_selection <- selection_mode.map(|t| *t != selection::Mode::Normal);
     deselect_edges <- on_new_edge.gate_not(&keep_selection);
@@ -2513,6 +4890,12 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     out.source.on_edge_target_set <+ new_edge_target;
 
 
+    // === Pointer Capture ===
+
+    mouse.capture_pointer <+ on_new_edge;
+    mouse.release_pointer <+ out.on_all_edges_endpoints_set;
+
+
 
     // ======================
     // === Node Creation  ===
@@ -2536,6 +4919,27 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     out.source.node_position_set         <+ node_with_position;
     out.source.node_position_set_batched <+ node_with_position;
 
+
+    // === Node Templates ===
+
+    eval inputs.add_node_from_template ([model,inputs,out](template) {
+        inputs.add_node_at_cursor.emit(());
+        let node_id = out.node_added.value();
+        inputs.set_node_expression.emit((node_id,template.expression.clone()));
+        if let Some(path) = &template.visualization {
+            inputs.set_visualization.emit((node_id,Some(path.clone())));
+        }
+        inputs.enable_visualization.emit(node_id);
+        model.with_node(node_id,|node| {
+            node.model.visualization.frp.set_size.emit(template.visualization_size())
+        });
+        if let Some(placeholder) = template.first_placeholder() {
+            inputs.edit_node.emit(node_id);
+            model.with_node(node_id,|node| node.model.input.select_code(placeholder.range));
+        }
+    });
+
+
     // === Event Propagation ===
     // See the docs of `Node` to learn about how the graph - nodes event propagation works.
 
@@ -2593,6 +4997,8 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
 
     on_new_edge_source <- new_edge_source.constant(());
     on_new_edge_target <- new_edge_target.constant(());
+    edge_connected     <- any(new_edge_source._0(),new_edge_target._0());
+    out.source.feedback <+ edge_connected.constant(feedback::Event::EdgeConnected);
 
     overlapping_edges       <= out.on_edge_target_set._1().map(f!((t) model.overlapping_edges(t)));
     out.source.on_edge_drop <+ overlapping_edges;
@@ -2619,6 +5025,8 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     // === Remove Node ===
     frp::extend! { network
 
+    some_selected   <- inputs.remove_selected_nodes . filter(f_!(!model.nodes.selected.is_empty()));
+    out.source.feedback <+ some_selected.constant(feedback::Event::Deleted);
     all_nodes       <= inputs.remove_all_nodes      . map(f_!(model.all_nodes()));
     selected_nodes  <= inputs.remove_selected_nodes . map(f_!(model.nodes.all_selected()));
     nodes_to_remove <- any (all_nodes, selected_nodes);
@@ -2630,15 +5038,37 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
 
     // === Collapse Nodes ===
     frp::extend! { network
-    // TODO [mwu] https://github.com/enso-org/ide/issues/760
-    //   This is currently the provisional code to enable collapse nodes refactoring. While the APIs
-    //   are as-intended, their behavior isn't. Please refer to the issue for details.
-    let empty_id       = NodeId::default();
-    let model_clone    = model.clone_ref();
-    nodes_to_collapse <- inputs.collapse_selected_nodes . map(move |_|
-        (model_clone.nodes.all_selected(),empty_id)
-    );
-    out.source.nodes_collapsed <+ nodes_to_collapse;
+
+    nodes_to_collapse <- inputs.collapse_selected_nodes.map(f_!(model.nodes.all_selected()));
+    nodes_to_collapse <- nodes_to_collapse.filter(|nodes| !nodes.is_empty());
+    nodes_collapsed   <- nodes_to_collapse.map(f!([model,inputs,out](nodes) {
+        inputs.add_node.emit(());
+        model.collapse_nodes(nodes,out.node_added.value())
+    }));
+    out.source.nodes_collapsed <+ nodes_collapsed;
+    }
+
+
+    // === Reorder Nodes ===
+    frp::extend! { network
+
+    let move_upstream       = inputs.move_selected_node_upstream.clone_ref();
+    let move_downstream     = inputs.move_selected_node_downstream.clone_ref();
+    node_to_move_upstream   <= move_upstream.map(f_!(model.nodes.last_selected()));
+    node_to_move_downstream <= move_downstream.map(f_!(model.nodes.last_selected()));
+    reorder_upstream        <= node_to_move_upstream.map(f!((id)
+        model.reorder_node(*id,ReorderDirection::Upstream)));
+    reorder_downstream      <= node_to_move_downstream.map(f!((id)
+        model.reorder_node(*id,ReorderDirection::Downstream)));
+    node_reordered          <- any(reorder_upstream,reorder_downstream);
+    swapped_positions       <= node_reordered.map(f!((reorder) {
+        let node_position     = model.node_position(reorder.node);
+        let neighbor_position = model.node_position(reorder.neighbor);
+        vec![(reorder.node,neighbor_position),(reorder.neighbor,node_position)]
+    }));
+    out.source.node_position_set         <+ swapped_positions;
+    out.source.node_position_set_batched <+ swapped_positions;
+    out.source.node_reordered            <+ node_reordered;
     }
 
 
@@ -2679,6 +5109,10 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
                 node.set_profiling_status(status);
             }
         });
+        inputs.set_node_profiling_status <+ inputs.set_node_profiling_data.map(|(node_id,time)| {
+            let duration = time.as_secs_f32() * 1000.0;
+            (*node_id,node::profiling::Status::Finished {duration})
+        });
 
     }
 
@@ -2691,10 +5125,19 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
 
     mouse_pos <- mouse.position.map(|p| Vector2(p.x,p.y));
 
+    // === Pinning ===
+
+    node_pinned <- inputs.set_node_pinned.filter_map(f!(((id,pinned))
+        model.set_node_pinned(*id,*pinned)
+    ));
+    out.source.node_pinned <+ node_pinned;
+
+
     // === Discovering drag targets ===
 
     let node_down      = touch.nodes.down.clone_ref();
     let node_is_down   = touch.nodes.is_down.clone_ref();
+    let node_pointer   = touch.nodes.own_pointer.clone_ref();
     node_in_edit_mode <- node_down.map2(&out.node_being_edited,|t,s| Some(*t) == *s);
     node_was_selected <- node_down.map(f!((id) model.nodes.selected.contains(id)));
     tgts_if_non_sel   <- node_down.map(|id|vec![*id]).gate_not(&node_was_selected);
@@ -2702,16 +5145,39 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     tgts_if_non_edit  <- any(tgts_if_non_sel,tgts_if_sel).gate_not(&node_in_edit_mode);
     tgts_if_edit      <- node_down.map(|_|default()).gate(&node_in_edit_mode);
     drag_tgts         <- any(tgts_if_non_edit,tgts_if_edit);
+    drag_tgts         <- drag_tgts.map(f!((ids) model.nodes.unpinned(ids)));
+    drag_tgts         <- drag_tgts.map2(&out.read_only,|ids,read_only|
+        if *read_only { default() } else { ids.clone() });
     any_drag_tgt      <- drag_tgts.map(|t|!t.is_empty());
     node_pos_on_down  <- node_down.map(f!((id) model.node_position(id)));
+    node_width        <- node_down.map(f!((id) model.node_width(*id)));
     mouse_pos_on_down <- mouse_pos.sample(&node_down);
-    mouse_pos_diff    <- mouse_pos.map2(&mouse_pos_on_down,|t,s|t-s).gate(&node_is_down);
-    node_pos_diff     <- mouse_pos_diff.map(f!([scene](t) t / scene.camera().zoom()));
+    // The dragged nodes follow the camera of the viewport they were pressed in, even if the mouse
+    // leaves it.
+    viewport_on_down  <- node_down.map(f_!(model.split_view.hovered()));
+    camera_on_down    <- viewport_on_down.map(f!((viewport)
+        model.split_view.camera_of(*viewport).position().xy()));
+    mouse_pos_on_pan  <- mouse_pos.sample(&camera_changed);
+    node_drag_pos     <- any(&mouse_pos,&mouse_pos_on_pan);
+    node_drag_pos     <- node_drag_pos.gate_with2(&node_pointer,&node_is_down,
+        |own_pointer,is_down| *own_pointer && *is_down);
+    mouse_pos_diff    <- node_drag_pos.map2(&mouse_pos_on_down,|t,s|t-s);
+    node_pos_diff     <- mouse_pos_diff.map3(&camera_on_down,&viewport_on_down,
+        f!([model](t,camera_on_down,viewport) {
+            let camera = model.split_view.camera_of(*viewport);
+            t / camera.zoom() + camera.position().xy() - camera_on_down
+        }));
     node_tgt_pos_rt   <- node_pos_diff.map2(&node_pos_on_down,|t,s|t+s);
     just_pressed      <- bool (&node_tgt_pos_rt,&node_pos_on_down);
     node_tgt_pos_rt   <- any  (&node_tgt_pos_rt,&node_pos_on_down);
 
 
+    // === Pointer Capture ===
+
+    mouse.capture_pointer <+ node_down.constant(());
+    mouse.release_pointer <+ touch.nodes.up.constant(());
+
+
     // === Snapping ===
 
     eval drag_tgts ((ids) model.disable_grid_snapping_for(ids));
@@ -2721,9 +5187,9 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     x_snap_strength.set_duration(300.0);
     y_snap_strength.set_duration(300.0);
 
-    _eval <- node_tgt_pos_rt.map2(&just_pressed,
-        f!([model,x_snap_strength,y_snap_strength,node_tgt_pos_anim](pos,just_pressed) {
-            let snapped = model.nodes.check_grid_magnet(*pos);
+    _eval <- node_tgt_pos_rt.map3(&just_pressed,&node_width,
+        f!([model,x_snap_strength,y_snap_strength,node_tgt_pos_anim](pos,just_pressed,width) {
+            let snapped = model.nodes.check_grid_magnet(*pos,*width).position();
             let x = snapped.x.unwrap_or(pos.x);
             let y = snapped.y.unwrap_or(pos.y);
             x_snap_strength.set_target_value(if snapped.x.is_none() { 0.0 } else { 1.0 });
@@ -2737,6 +5203,12 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
             }
     }));
 
+    snapped        <- node_tgt_pos_rt.map2(&node_width,
+        f!((pos,width) model.nodes.check_grid_magnet(*pos,*width)));
+    is_snapped     <- snapped.map(|snapped| snapped.is_snapped());
+    snap_started   <- is_snapped.on_change().on_true().gate_not(&just_pressed);
+    out.source.feedback <+ snap_started.constant(feedback::Event::NodeSnapped);
+
     node_tgt_pos <- all_with4
         ( &node_tgt_pos_rt
         , &node_tgt_pos_anim.value
@@ -2749,6 +5221,19 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
         });
 
 
+    // === Alignment Guides ===
+
+    guided_drag_tgts <- drag_tgts.gate(&out.alignment_guides_enabled);
+    eval guided_drag_tgts ((ids) model.start_alignment_guides(ids));
+    guided_pos       <- node_tgt_pos.gate(&node_is_down).gate(&any_drag_tgt);
+    guided_pos       <- guided_pos.gate(&out.alignment_guides_enabled).gate_not(&just_pressed);
+    guided_node      <- node_down.sampler();
+    _eval <- guided_pos.map2(&guided_node,f!((pos,id) model.update_alignment_guides(*id,*pos)));
+    guides_disabled  <- out.alignment_guides_enabled.on_false();
+    guides_hidden    <- any_(touch.nodes.up,guides_disabled);
+    eval_ guides_hidden (model.alignment_guides.clear());
+
+
     // === Update All Target Nodes Positions ===
 
     main_tgt_pos_prev <- node_tgt_pos.previous();
@@ -2783,6 +5268,25 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     }
 
 
+    // === Auto-Panning ===
+
+    frp::extend! { network
+        edge_dragged   <- has_detached_edge && connect_drag_mode;
+        node_dragged   <- all_with3(&node_is_down,&any_drag_tgt,&just_pressed,
+            |down,any_tgt,just_pressed| *down && *any_tgt && !*just_pressed);
+        autopan_frame  <- scene.frp.frame_time.gate_with2(&edge_dragged,&node_dragged,
+            |edge_dragged,node_dragged| *edge_dragged || *node_dragged);
+        autopan_cursor <- cursor.frp.screen_position.sample(&autopan_frame);
+        autopan        <- autopan_cursor.map(f!([scene](position) {
+            let screen = scene.shape().value();
+            autopan::pan_per_frame(position.xy(),Vector2(screen.width,screen.height))
+        }));
+        eval autopan ((movement) if *movement != Vector2::zeros() {
+            model.navigator.pan(-*movement)
+        });
+    }
+
+
     // === Set Expression Type ===
     frp::extend! { network
 
@@ -2807,7 +5311,7 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     cursor_pos_on_update    <- cursor_pos_in_scene.sample(&update_edge);
     edge_refresh_cursor_pos <- any(cursor_pos_on_update,cursor_pos_in_scene);
 
-    is_hovering_output <- inputs.hover_node_output.map(|target| target.is_some()).sampler();
+    is_hovering_output <- inputs.hover_node_output.map(|target| target.is_some());
     hover_node         <- inputs.hover_node_output.unwrap();
 
     edge_refresh_on_node_hover        <- all(edge_refresh_cursor_pos,hover_node).gate(&is_hovering_output);
@@ -2818,11 +5322,11 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     let refresh_source  = edge_refresh_cursor_pos_no_hover.clone_ref();
     snap_source_to_node <- edge_refresh_on_node_hover._1();
 
-    eval refresh_target ([edges](position) {
+    eval refresh_target ([edges,model](position) {
        edges.detached_target.for_each(|id| {
             if let Some(edge) = edges.get_cloned_ref(id) {
                 edge.view.frp.target_position.emit(position.xy());
-                edge.view.frp.redraw.emit(());
+                model.redraw_edge(*id);
             }
         });
     });
@@ -2833,7 +5337,6 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
                 edge.view.frp.source_width.emit(cursor::DEFAULT_RADIUS);
                 edge.view.frp.source_height.emit(cursor::DEFAULT_RADIUS);
                 edge.view.frp.target_position.emit(-position.xy());
-                edge.view.frp.redraw.emit(());
                 edge.mod_position(|p| {
                     p.x = position.x;
                     p.y = position.y;
@@ -2854,7 +5357,6 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
                     edge.view.frp.source_width.emit(node_width);
                     edge.view.frp.source_height.emit(node_height);
                     edge.view.frp.target_position.emit(-node_pos.xy());
-                    edge.view.frp.redraw.emit(());
                     edge.mod_position(|p| {
                         p.x = node_pos.x + node_width/2.0;
                         p.y = node_pos.y;
@@ -2868,6 +5370,13 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     }
 
 
+    // === Edge Refresh ===
+
+    frp::extend! { network
+    eval_ scene.frp.frame_time (model.flush_edge_refresh());
+    }
+
+
    // === Vis Set ===
    frp::extend! { network
 
@@ -2903,6 +5412,7 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
 
     // === Vis Update Data ===
 
+    let viz_preview_timeout = frp::io::timer::timeout(network,VIZ_PREVIEW_MODE_TOGGLE_TIME_MS);
     frp::extend! { network
     // TODO remove this once real data is available.
     let sample_data_generator = MockDataGenerator3D::default();
@@ -2915,12 +5425,21 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
         }
     }));
 
-    eval inputs.set_visualization_data ([nodes]((node_id,data)) {
+    eval inputs.set_visualization_data ([nodes,model]((node_id,data)) {
         if let Some(node) = nodes.get_cloned(node_id) {
             node.model.visualization.frp.set_data.emit(data);
         }
     });
 
+    eval inputs.set_pinned_visualization_data (((node_id,data))
+        model.vis_panels.set_data(*node_id,data));
+
+    eval inputs.acknowledge_visualization_preprocessor ([nodes]((node_id,accepted)) {
+        if let Some(node) = nodes.get_cloned(node_id) {
+            node.model.visualization.frp.acknowledge_preprocessor.emit(accepted);
+        }
+    });
+
     eval inputs.set_error_visualization_data ([nodes]((node_id,data)) {
         if let Some(node) = nodes.get_cloned(node_id) {
             node.model.error_visualization.send_data.emit(data);
@@ -2942,7 +5461,8 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     //     - Press key. If all selected nodes have enabled vis, disable them.
     //     - If not, enable vis on missing nodes.
     //     - Release key. If the time passed from key press was short, do nothing.
-    //     - If it was long, disable vis which were disabled (preview mode).
+    //     - If it was long, disable vis which were disabled (preview mode). The time is measured by
+    //       the `viz_preview_timeout`, restarted on every press.
 
     let viz_press_ev      = inputs.press_visualization_visibility.clone_ref();
     let viz_d_press_ev    = inputs.double_press_visualization_visibility.clone_ref();
@@ -2951,10 +5471,9 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     viz_was_pressed      <- viz_pressed.previous();
     viz_press            <- viz_press_ev.gate_not(&viz_was_pressed);
     viz_release          <- viz_release_ev.gate(&viz_was_pressed);
-    viz_press_time       <- viz_press   . map(|_| web::performance().now() as f32);
-    viz_release_time     <- viz_release . map(|_| web::performance().now() as f32);
-    viz_press_time_diff  <- viz_release_time.map2(&viz_press_time,|t1,t0| t1-t0);
-    viz_preview_mode     <- viz_press_time_diff.map(|t| *t > VIZ_PREVIEW_MODE_TOGGLE_TIME_MS);
+    viz_preview_timeout.restart <+ viz_press;
+    viz_preview_timeout.cancel  <+ viz_release;
+    viz_preview_mode     <- bool(&viz_press,&viz_preview_timeout.expired);
     viz_preview_mode_end <- viz_release.gate(&viz_preview_mode).gate_not(&out.is_fs_visualization_displayed);
     viz_tgt_nodes        <- viz_press.gate_not(&out.is_fs_visualization_displayed).map(f_!(model.nodes.all_selected()));
     viz_tgt_nodes_off    <- viz_tgt_nodes.map(f!([model](node_ids) {
@@ -2972,26 +5491,119 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     viz_disable          <- any(viz_disable_by_press,inputs.disable_visualization);
     viz_preview_disable  <= viz_tgt_nodes_off.sample(&viz_preview_mode_end);
     viz_fullscreen_on    <= viz_d_press_ev.map(f_!(model.nodes.last_selected()));
+    inputs.enter_visualization_fullscreen <+ viz_fullscreen_on;
 
     eval viz_enable          ((id) model.enable_visualization(id));
     eval viz_disable         ((id) model.disable_visualization(id));
     eval viz_preview_disable ((id) model.disable_visualization(id));
-    eval viz_fullscreen_on   ((id) model.enable_visualization_fullscreen(id));
 
-    viz_fs_to_close <- out.visualization_fullscreen.sample(&inputs.close_fullscreen_visualization);
-    eval viz_fs_to_close ([model](vis) {
-        if let Some(vis) = vis {
-            model.disable_visualization_fullscreen(vis);
-            model.enable_visualization(vis);
-        }
-    });
 
-    out.source.visualization_fullscreen <+ viz_fullscreen_on.map(|id| Some(*id));
-    out.source.visualization_fullscreen <+ inputs.close_fullscreen_visualization.constant(None);
+    // === Fullscreen Visualization ===
+
+    let fs_enter         = inputs.enter_visualization_fullscreen.clone_ref();
+    let fs_displayed     = out.is_fs_visualization_displayed.clone_ref();
+    fs_click_outside    <- touch.background.down.gate(&fs_displayed);
+    fs_node_removed     <- out.node_removed.map2(&out.visualization_fullscreen,
+        |node_id,fullscreen| *fullscreen == Some(*node_id)
+    ).on_true();
+    let fs_close_input   = inputs.close_fullscreen_visualization.clone_ref();
+    fs_close            <- any_(fs_close_input,fs_click_outside,fs_node_removed,fs_enter);
+    fs_exited           <= fs_close.map(f_!(model.exit_visualization_fullscreen()));
+    fs_entered          <= fs_enter.map(f!((id) model.enter_visualization_fullscreen(*id)));
+
+    out.source.visualization_fullscreen         <+ fs_exited.constant(None);
+    out.source.visualization_fullscreen_exited  <+ fs_exited;
+    out.source.visualization_fullscreen         <+ fs_entered.map(|id| Some(*id));
+    out.source.visualization_fullscreen_entered <+ fs_entered;
 
     out.source.is_fs_visualization_displayed <+ out.visualization_fullscreen.map(Option::is_some);
 
 
+    // === Visualization Comparison ===
+
+    compare_vis      <- inputs.compare_visualizations.gate_not(&out.is_fs_visualization_displayed);
+    compared_removed <- out.node_removed.map2(&out.visualizations_compared,|node_id,compared| {
+        compared.map_or(false,|(left,right)| left == *node_id || right == *node_id)
+    }).on_true();
+    close_comparison <- any_(inputs.close_visualization_comparison,compare_vis,compared_removed);
+    comparison_to_close <- out.visualizations_compared.sample(&close_comparison);
+    eval comparison_to_close ([model](compared) {
+        if let Some((left,right)) = compared {
+            model.disable_visualization_comparison(*left,*right);
+        }
+    });
+    out.source.visualizations_compared <+ close_comparison.constant(None);
+    compared <- compare_vis.map(f!(((left,right))
+        model.enable_visualization_comparison(*left,*right)
+    ));
+    out.source.visualizations_compared <+ compared;
+    out.source.is_vis_comparison_displayed <+ out.visualizations_compared.map(Option::is_some);
+
+
+    // === Pinned Visualizations ===
+
+    let vis_panels = &model.vis_panels;
+    out.source.visualization_pinned <+ inputs.pin_visualization.filter_map(f!((node_id)
+        model.pin_visualization(*node_id)));
+    unpin_requested <- any(inputs.unpin_visualization,vis_panels.events.close_requested);
+    unpinned_panel  <- unpin_requested.filter_map(f!((id)
+        vis_panels.remove(*id).map(|node_id| (*id,node_id))));
+    removed_panels  <- out.node_removed.map(f!((node_id)
+        (*node_id,vis_panels.remove_node_panels(*node_id))));
+    node_panels     <= removed_panels._1();
+    out.source.visualization_unpinned <+ unpinned_panel._0();
+    out.source.visualization_unpinned <+ node_panels;
+    eval inputs.set_vis_panel_position (((id,position)) vis_panels.set_position(*id,*position));
+    out.source.vis_panel_moved <+ vis_panels.moved;
+
+    // The panels of the node share a single attachment, detached with the last of them.
+    pinned_preprocessor <- out.visualization_pinned.filter_map(f!(((id,node_id))
+        vis_panels.preprocessor(*id).map(|preprocessor| (*node_id,preprocessor))));
+    pinned_preprocessor <- any(pinned_preprocessor,vis_panels.events.preprocessor_changed);
+    pinned_preprocessor <- pinned_preprocessor.filter(|(_,preprocessor)| preprocessor.is_valid());
+    last_panel_closed   <- unpinned_panel._1().filter(f!((node_id)
+        vis_panels.node_panels(*node_id).is_empty()));
+    last_panel_removed  <- removed_panels.filter(|(_,panels)| !panels.is_empty())._0();
+    last_panel_gone     <- any(last_panel_closed,last_panel_removed);
+    out.source.pinned_visualization_changed <+ pinned_preprocessor.map(|(node_id,preprocessor)|
+        (*node_id,Some(visualization::Metadata::new(preprocessor))));
+    out.source.pinned_visualization_changed <+ last_panel_gone.map(|node_id| (*node_id,None));
+
+
+    // === Batching ===
+
+    eval_ inputs.begin_batch (model.batch.begin());
+    out.source.batching       <+ inputs.begin_batch.constant(true);
+    batch_summary             <- inputs.end_batch.map(f_!(model.batch.end()));
+    out.source.batching       <+ batch_summary.map(f_!(model.batch.is_active()));
+    out.source.batch_finished <+ batch_summary.filter_map(|summary| summary.clone());
+    eval out.node_added  ((node_id) model.batch.record_node(*node_id));
+    eval out.on_edge_add ((edge_id) model.batch.record_edge(*edge_id));
+    eval out.node_position_set_batched (((node_id,position))
+        model.batch.record_position(*node_id,*position));
+    eval out.node_expression_set (((node_id,expression))
+        model.batch.record_expression(*node_id,expression.clone()));
+    // The edge refreshes deferred during the batch are applied at once, without waiting for the
+    // next frame.
+    eval_ out.batch_finished (model.flush_edge_refresh());
+
+
+    // === Layout Flush ===
+
+    let frame_time      = scene.frp.frame_time.clone_ref();
+    let batched_pos_set = out.node_position_set_batched.clone_ref();
+    let vis_path_set    = out.enabled_visualization_path.clone_ref();
+    layout_changed     <- any_(batched_pos_set,vis_path_set,out.node_added,out.node_removed);
+    layout_dirty       <- bool(&out.layout_flush_requested,&layout_changed);
+    flush_interval_ms  <- inputs.set_layout_flush_interval.map(|t| t * 1000.0);
+    last_flush_time    <- frame_time.sample(&out.layout_flush_requested);
+    flush_time         <- frame_time.gate(&layout_dirty).map3(&last_flush_time,&flush_interval_ms,
+        |time,last_flush,interval| *time - *last_flush >= *interval
+    );
+    flush              <- flush_time.on_true();
+    out.source.layout_flush_requested <+ flush.map(f_!(model.layout_description()));
+
+
     // === Register Visualization ===
 
     eval inputs.register_visualization ([vis_registry](handle) {
@@ -3017,6 +5629,29 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
 
 
 
+    // =============================
+    // === Node Execution Status ===
+    // =============================
+
+    eval inputs.set_node_execution_status(((node_id,status))
+         model.with_node(*node_id, |node| node.set_execution_status.emit(status))
+     );
+
+
+
+    // ===================
+    // === Node Badges ===
+    // ===================
+
+    eval inputs.add_node_badge(((node_id,definition))
+         model.with_node(*node_id, |node| node.add_badge.emit(definition))
+     );
+    eval inputs.remove_node_badge(((node_id,id))
+         model.with_node(*node_id, |node| node.remove_badge.emit(id))
+     );
+
+
+
     // ================
     // === Node VCS ===
     // ================
@@ -3027,6 +5662,39 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
 
 
 
+    // ====================
+    // === Node Actions ===
+    // ====================
+
+    eval inputs.set_node_frozen(((node_id,is_frozen))
+         model.with_node(*node_id, |node| node.set_frozen.emit(is_frozen))
+     );
+    eval inputs.set_node_skipped(((node_id,is_skipped))
+         model.with_node(*node_id, |node| node.set_skipped.emit(is_skipped))
+     );
+    eval inputs.set_node_actions(((node_id,actions))
+         model.with_node(*node_id, |node| node.set_actions.emit(actions))
+     );
+    out.source.node_recompute_requested <+ inputs.recompute_node;
+    eval out.node_recompute_requested((node_id)
+         model.with_node(*node_id, |node| node.set_recompute_pending.emit(true))
+     );
+
+
+    // === Masked Ports ===
+
+    eval inputs.set_port_masked(((node_id,crumbs,masked))
+         model.with_node(*node_id, |node| node.set_port_masked.emit((crumbs.clone(),*masked)))
+     );
+    let reveal_selected = inputs.reveal_masked_ports_of_selected_nodes.clone_ref();
+    selected_to_reveal <= reveal_selected.map(f_!(model.nodes.all_selected()));
+    node_to_reveal     <- any(inputs.reveal_masked_ports,selected_to_reveal);
+    eval node_to_reveal((node_id)
+         model.with_node(*node_id, |node| node.reveal_masked_ports.emit(()))
+     );
+
+
+
     // ==================
     // === Edge Binds ===
     // ==================
@@ -3065,7 +5733,8 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
         model.refresh_edge_color(*id,neutral_color.value().into()));
     eval neutral_color ((neutral_color) model.refresh_all_edge_colors(neutral_color.into()));
 
-    edge_to_refresh_on_hover <= out.hover_node_input.map(f_!(model.edges_with_detached_targets()));
+    hovered_input            <- out.hover_node_input.on_change();
+    edge_to_refresh_on_hover <= hovered_input.map(f_!(model.edges_with_detached_targets()));
     eval edge_to_refresh_on_hover ([model,neutral_color](id)
         model.refresh_edge_color(*id,neutral_color.value().into()));
 
@@ -3098,6 +5767,40 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     eval out.on_edge_drop    ((id) model.remove_edge(id));
 
 
+    // === Edge Splitting ===
+
+    // See the docs of the `edge_split` module. The press on the background drops the edge detached
+    // by the first press of the double-click, so only the background presses made without a
+    // detached edge forget the pressed one.
+    background_pressed <- touch.background.down.gate_not(&has_detached_edge);
+    node_pressed       <- any_(touch.nodes.down,node_output_touch.down,node_input_touch.down);
+    other_pressed      <- any_(node_pressed,background_pressed);
+    split_requested    <- inputs.split_pressed_edge.map2(&cursor_pos_in_scene,|_,pos| *pos);
+    edge_split_event   <- any_mut::<edge_split::Event>();
+    edge_split_event   <+ pressed_connection.map(|pressed| pressed.clone().map_or_else
+        (|| edge_split::Event::OtherPressed,edge_split::Event::EdgePressed));
+    edge_split_event   <+ other_pressed.constant(edge_split::Event::OtherPressed);
+    edge_split_event   <+ out.on_edge_drop.map(|id| edge_split::Event::EdgeRemoved(*id));
+    edge_split_event   <+ split_requested.map(|pos| edge_split::Event::SplitRequested(*pos));
+    edge_to_split      <- edge_split_event.fsm(default(),edge_split::step)._1().unwrap();
+    edge_split         <- edge_to_split.map(
+        f!([model,inputs,out,edge_mouse_down,edge_over,edge_out,neutral_color](pressed) {
+            inputs.add_node.emit(());
+            let node_id  = out.node_added.value();
+            let in_edge  = model.create_edge(&edge_mouse_down,&edge_over,&edge_out);
+            let out_edge = model.create_edge(&edge_mouse_down,&edge_over,&edge_out);
+            let color    = neutral_color.value().into();
+            model.split_connection(pressed,node_id,in_edge,out_edge,color);
+            (pressed.edge_id,node_id,in_edge,out_edge)
+        })
+    );
+    out.source.edge_split <+ edge_split;
+    node_split_position   <- edge_split.map2(&edge_to_split,|(_,id,_,_),pressed|
+        (*id,pressed.position));
+    out.source.node_position_set         <+ node_split_position;
+    out.source.node_position_set_batched <+ node_split_position;
+
+
 
     // ===================
     // === Other Binds ===
@@ -3105,11 +5808,34 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
 
     eval out.node_selected   ((id) model.nodes.select(id));
     eval out.node_deselected ((id) model.nodes.deselect(id));
+
+    // Bulk selection changes are applied to the model at once, and only then reported node by
+    // node, so the per-node events do not emit intermediate `selection_changed` events.
+    selection_set         <- inputs.set_selection.map(f!((ids) model.nodes.set_selection(ids)));
+    selection_added       <- inputs.select_nodes.map(f!((ids) model.nodes.select_many(ids)));
+    selection_removed     <- inputs.deselect_nodes.map(f!((ids) model.nodes.deselect_many(ids)));
+    selection_bulk_change <- any(selection_set,selection_added,selection_removed);
+    selection_bulk_change <- selection_bulk_change.filter(|change| !change.is_empty());
+    selection_bulk_start  <- selection_bulk_change.constant(());
+    out.source.node_deselected <= selection_bulk_change.map(|change| change.deselected.clone());
+    out.source.node_selected   <= selection_bulk_change.map(|change| change.selected.clone());
+    selection_bulk_end    <- selection_bulk_change.constant(());
+    selection_in_bulk     <- bool(&selection_bulk_end,&selection_bulk_start);
+    selection_single      <- any(&out.node_selected,&out.node_deselected);
+    selection_single      <- selection_single.gate_not(&selection_in_bulk).constant(());
+    selection_changed     <- any(selection_single,selection_bulk_end);
+    out.source.selection_changed <+ selection_changed.map(f_!(model.nodes.all_selected()));
     eval out.node_removed    ((id) model.remove_node(id));
     model.profiling_statuses.remove <+ out.node_removed;
     out.source.on_visualization_select <+ out.node_removed.map(|&id| Switch::Off(id));
 
     eval inputs.set_node_expression (((id,expr)) model.set_node_expression(id,expr));
+    eval inputs.preview_node_expression (((id,expr))
+        model.set_node_expression_preview(*id,Some(expr.clone())));
+    preview_cleared <- any(&inputs.clear_expression_preview,&out.node_editing_finished);
+    eval preview_cleared ((id) model.set_node_expression_preview(*id,None));
+    eval inputs.set_port_documentation (((id,crumbs,doc))
+        model.set_port_documentation(*id,crumbs,doc));
     port_to_refresh <= inputs.set_node_expression.map(f!(((id,_))model.node_in_edges(id)));
     eval port_to_refresh ((id) model.set_edge_target_connection_status(*id,true));
 
@@ -3130,6 +5856,20 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     }
 
 
+    // === Removal Preview ===
+    frp::extend! { network
+
+    let preview_selected = inputs.start_removal_preview_for_selected_nodes.clone_ref();
+    selected_to_preview <- preview_selected.map(f_!(model.nodes.all_selected()));
+    nodes_to_preview    <- any(selected_to_preview,inputs.start_removal_preview);
+    removal_preview_end <- any_(inputs.stop_removal_preview,inputs.remove_selected_nodes);
+    eval nodes_to_preview ([model,neutral_color](nodes)
+        model.show_removal_preview(nodes,neutral_color.value().into()));
+    eval_ removal_preview_end ([model,neutral_color]
+        model.hide_removal_preview(neutral_color.value().into()));
+    }
+
+
 
     // =====================
     // === Pointer Style ===
@@ -3153,6 +5893,13 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     let breadcrumb_style = model.breadcrumbs.pointer_style.clone_ref();
     let selection_style  = selection_controller.cursor_style.clone_ref();
 
+
+    // === Style Request ===
+    //
+    // The styles of the graph editor components are folded into a single style, the earlier ones
+    // taking precedence, which is registered in the cursor style registry as a single request. See
+    // `cursor::StyleRegistry`.
+
     pointer_style <- all
         [ pointer_on_drag
         , selection_style
@@ -3160,8 +5907,7 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
         , cursor_style_edge_drag
         , breadcrumb_style
         ].fold();
-
-    eval pointer_style ((style) cursor.frp.set_style.emit(style));
+    cursor.frp.set_style_request <+ pointer_style.map(style_request("graph-editor",0));
 
     }
 
@@ -3176,6 +5922,31 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
         eval selection_controller.area_selection ((area_selection) nodes.show_quick_actions(!area_selection));
     }
 
+    // === Edges + Selection ===
+
+    // In the edge area selection mode, the area selection selects exactly the edges crossing the
+    // selection rectangle. All the edges are deselected when a new area selection starts, or when
+    // all the nodes are deselected.
+    frp::extend! { network
+        let edge_area_select = enable_disable_toggle
+            ( network
+            , &inputs.enable_edge_area_select
+            , &inputs.disable_edge_area_select
+            , &inputs.toggle_edge_area_select
+            );
+        out.source.edge_area_select_enabled <+ edge_area_select;
+
+        area_select_start     <- selection_controller.area_selection.on_true();
+        clear_edge_selection  <- any_(area_select_start,inputs.deselect_all_nodes);
+        no_edges              <- clear_edge_selection.constant(HashSet::<EdgeId>::new());
+        edges_in_area         <- selection_controller.area.gate(&edge_area_select);
+        edges_in_area         <- edges_in_area.map(f!((area) model.edges_in_area(area)));
+        edges_to_select       <- any(no_edges,edges_in_area);
+        edge_selection_change <- edges_to_select.map(f!((edges) model.set_selected_edges(edges)));
+        out.source.edge_selected   <= edge_selection_change._0();
+        out.source.edge_deselected <= edge_selection_change._1();
+    }
+
     // === Visualisation + Selection ===
 
     // Do not allow area selection while we show a fullscreen visualisation.
@@ -3202,6 +5973,48 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
 
 
 
+    // ====================
+    // === Context Menu ===
+    // ====================
+
+    let context_menu = &model.context_menu;
+    frp::extend! { network
+        eval inputs.register_context_menu_entry ([context_menu]((kind,label))
+            context_menu.register(*kind,label.clone()));
+
+        hovered_node <- out.node_hovered.map(|hover|
+            hover.as_ref().and_then(|hover| hover.is_on().as_some(hover.value)));
+        secondary_down_position <- mouse.position.sample(&mouse.down_secondary);
+        secondary_drag <- mouse.up_secondary.map3(&secondary_down_position,&mouse.position,
+            |_,down,up| (up - down).norm());
+        secondary_click      <- secondary_drag.filter(|drag| *drag < CONTEXT_MENU_MAX_DRAG);
+        context_menu_request <- secondary_click.map3(&hovered_node,&edge_hover,
+            |_,node,edge| (*node,*edge));
+        context_menu_position <- cursor_pos_in_scene.sample(&context_menu_request);
+        context_menu_target   <- context_menu_request.map2(&context_menu_position,
+            |(node,edge),position| context_menu::Target::new(*node,*edge,*position));
+        eval context_menu_target ([context_menu,mouse](target)
+            context_menu.show(*target,mouse.position.value()));
+        context_menu.hide <+ inputs.hide_context_menu;
+
+        context_menu_action <- context_menu.chosen_entry.filter_map(
+            f!([context_menu](entry) context_menu.action(*entry)));
+        eval context_menu_action ([model]((target,action))
+            model.perform_context_menu_action(*target,action));
+        split_chosen <- context_menu_action.map2(&context_menu_position,
+            f!([model]((target,action),position) match (target,action) {
+                (context_menu::Target::Edge(edge_id),context_menu::Action::SplitEdge) =>
+                    model.pressed_connection(*edge_id,*position),
+                _ => None,
+            })).unwrap();
+        edge_split_event <+ split_chosen.map(|pressed|
+            edge_split::Event::SplitChosen(pressed.clone()));
+        out.source.context_menu_action  <+ context_menu_action;
+        out.source.context_menu_visible <+ context_menu.visible;
+    }
+
+
+
     // =====================
     // === Dropped Files ===
     // =====================
@@ -3209,6 +6022,7 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     use theme::graph_editor::default_y_gap_between_nodes as gap_path;
     let default_gap    = model.styles_frp.get_number_or(gap_path,0.0);
     let files_received = model.drop_manager.files_received().clone_ref();
+    let text_received  = model.drop_manager.text_received().clone_ref();
     frp::extend! { network
         files_with_positions <- files_received.map3(&cursor_pos_in_scene,&default_gap,
             |files,cursor_pos,default_gap| {
@@ -3221,6 +6035,19 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
         );
         file_dropped            <= files_with_positions;
         out.source.file_dropped <+ file_dropped;
+
+        lines_with_positions <- text_received.map3(&cursor_pos_in_scene,&default_gap,
+            |text,cursor_pos,default_gap| {
+                let single_offset = default_gap + node::HEIGHT;
+                let lines         = text::component::area::decode_paste_lines(text);
+                lines.into_iter().enumerate().map(|(index,line)| {
+                    let offset = Vector2(0.0, single_offset * index as f32);
+                    (line,cursor_pos+offset)
+                }).collect_vec()
+            }
+        );
+        text_dropped            <= lines_with_positions;
+        out.source.text_dropped <+ text_dropped;
     }
 
 
@@ -3247,6 +6074,371 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
         eval profiling_mode_transition.value ((&v) scene.dom.layers.back.filter_grayscale(v));
     }
 
+    let profiling_heatmap_visible = enable_disable_toggle
+        ( network
+        , &inputs.show_profiling_heatmap
+        , &inputs.hide_profiling_heatmap
+        , &inputs.toggle_profiling_heatmap
+        );
+    frp::extend! { network
+        out.source.profiling_heatmap_visible <+ profiling_heatmap_visible;
+    }
+
+
+
+    // ===============================
+    // === Execution Order Overlay ===
+    // ===============================
+
+    let execution_order_visible = enable_disable_toggle
+        ( network
+        , &inputs.show_execution_order
+        , &inputs.hide_execution_order
+        , &inputs.toggle_execution_order
+        );
+    frp::extend! { network
+        out.source.execution_order_visible <+ execution_order_visible;
+
+        topology_changed <- any_(out.on_edge_endpoint_set,out.on_edge_endpoint_unset
+            ,out.on_edge_drop,out.node_added,out.node_reordered);
+        execution_order_changed <- any_(topology_changed,out.node_removed
+            ,out.execution_order_visible);
+        execution_order_changed <- execution_order_changed.gate_not(&out.batching);
+        execution_order_changed <- any_(execution_order_changed,out.batch_finished);
+        eval_ execution_order_changed ([model,neutral_color] {
+            model.refresh_execution_order();
+            model.refresh_all_edge_colors(neutral_color.value().into());
+        });
+    }
+
+
+
+    // =======================
+    // === Edge De-clutter ===
+    // =======================
+
+    let edge_declutter_enabled = enable_disable_toggle
+        ( network
+        , &inputs.enable_edge_declutter
+        , &inputs.disable_edge_declutter
+        , &inputs.toggle_edge_declutter
+        );
+    frp::extend! { network
+        out.source.edge_declutter_enabled <+ edge_declutter_enabled;
+
+        layout_moved      <- any_(scene.frp.camera_changed,out.node_position_set,out.node_removed);
+        declutter_changed <- any_(layout_moved,topology_changed);
+        declutter_changed <- declutter_changed.gate(&out.edge_declutter_enabled);
+        declutter_changed <- any_(declutter_changed,out.edge_declutter_enabled);
+        declutter_changed <- declutter_changed.gate_not(&out.batching);
+        declutter_changed <- any_(declutter_changed,out.batch_finished);
+        eval_ declutter_changed ([model,neutral_color]
+            model.refresh_edge_declutter(neutral_color.value().into())
+        );
+    }
+
+
+
+    // ========================
+    // === Alignment Guides ===
+    // ========================
+
+    let alignment_guides_enabled = enable_disable_toggle
+        ( network
+        , &inputs.enable_alignment_guides
+        , &inputs.disable_alignment_guides
+        , &inputs.toggle_alignment_guides
+        );
+    frp::extend! { network
+        out.source.alignment_guides_enabled <+ alignment_guides_enabled;
+    }
+
+
+
+    // ===============
+    // === Culling ===
+    // ===============
+
+    let culling_enabled = enable_disable_toggle
+        ( network
+        , &inputs.enable_culling
+        , &inputs.disable_culling
+        , &inputs.toggle_culling
+        );
+    frp::extend! { network
+        out.source.culling_enabled <+ culling_enabled;
+
+        // While the culling is disabled, everything is displayed, so nothing has to be refreshed
+        // when the graph changes.
+        graph_changed   <- any_(layout_moved,topology_changed);
+        graph_changed   <- graph_changed.gate(&out.culling_enabled);
+        culling_changed <- any_(graph_changed,out.culling_enabled,out.split_view_enabled);
+        eval_ culling_changed (model.refresh_culling());
+    }
+
+
+
+    // ============================
+    // === Connected Components ===
+    // ============================
+
+    frp::extend! { network
+        components_changed <- any_(topology_changed,out.node_removed);
+        components <- components_changed.map(f_!(Rc::new(model.connected_components())));
+        out.source.connected_components <+ components.on_change();
+    }
+
+
+
+    // =====================
+    // === Accessibility ===
+    // =====================
+
+    frp::extend! { network
+        let focus     = out.focus.clone_ref();
+        next_node    <- inputs.focus_next_node.map2(&focus,
+            f!((_,focus) model.node_focused_after(focus,true)));
+        prev_node    <- inputs.focus_previous_node.map2(&focus,
+            f!((_,focus) model.node_focused_after(focus,false)));
+        next_port    <- inputs.focus_next_port.map2(&focus,
+            f!((_,focus) model.port_focused_after(focus,true))).filter(|focus| focus.is_some());
+        prev_port    <- inputs.focus_previous_port.map2(&focus,
+            f!((_,focus) model.port_focused_after(focus,false))).filter(|focus| focus.is_some());
+        chosen_node  <- inputs.focus_node.filter(f!((id) model.nodes.contains_key(id)));
+        chosen_node  <- chosen_node.map(|id| Some(accessibility::Focus::Node(*id)));
+        cleared      <- inputs.clear_focus.constant(None);
+        focus_lost   <- out.node_removed.map2(&focus,|id,focus| {
+            focus.as_ref().map(|focus| focus.node_id()) == Some(*id)
+        }).on_true().constant(None);
+        focus_moved  <- any(next_node,prev_node,next_port,prev_port);
+        focus_reset  <- any(chosen_node,cleared,focus_lost);
+        new_focus    <- any(focus_moved,focus_reset);
+        eval new_focus ([model,focus](new) model.set_focus(&focus.value(),new));
+        out.source.focus <+ new_focus;
+    }
+
+
+
+    // ========================
+    // === Keyboard Connect ===
+    // ========================
+
+    frp::extend! { network
+        let kb_source    = out.keyboard_connect_source.clone_ref();
+        let kb_target    = out.keyboard_connect_target.clone_ref();
+        kb_active       <- kb_source.map(|source| source.is_some());
+        kb_state        <- all(&kb_source,&kb_target);
+        kb_started      <- inputs.start_keyboard_connect.gate_not(&kb_active);
+        kb_started      <- kb_started.filter_map(f_!(model.nodes.last_selected()));
+        kb_first_target <- kb_started.map(f!((source)
+            model.keyboard_connect_target_after(&Some(*source),&None,true)
+        ));
+        kb_next         <- inputs.keyboard_connect_next_port.gate(&kb_active).map2(&kb_state,
+            f!((_,state) model.keyboard_connect_target_after(&state.0,&state.1,true)));
+        kb_previous     <- inputs.keyboard_connect_previous_port.gate(&kb_active).map2(&kb_state,
+            f!((_,state) model.keyboard_connect_target_after(&state.0,&state.1,false)));
+        kb_hovered      <- out.hover_node_input.gate(&kb_active).unwrap();
+        kb_hovered      <- kb_hovered.map2(&kb_source,|target,source| {
+            source.filter(|source| keyboard_connect::is_valid_target(*source,target))?;
+            Some(target.clone())
+        }).filter(|target| target.is_some());
+
+        kb_confirmed    <- inputs.confirm_keyboard_connect.gate(&kb_active);
+        kb_connection   <- kb_confirmed.map2(&kb_state,|_,(source,target)| {
+            Some(keyboard_connect::connection((*source)?,target.clone()?))
+        }).unwrap();
+        inputs.connect_nodes <+ kb_connection;
+
+        kb_source_lost  <- out.node_removed.map2(&kb_source,|id,source| *source == Some(*id));
+        kb_source_lost  <- kb_source_lost.on_true();
+        kb_ended        <- any_(kb_confirmed,inputs.cancel_keyboard_connect,kb_source_lost);
+        out.source.keyboard_connect_source <+ kb_started.map(|source| Some(*source));
+        out.source.keyboard_connect_source <+ kb_ended.constant(None);
+
+        kb_moved        <- any(kb_next,kb_previous,kb_hovered);
+        kb_target_reset <- kb_ended.constant(None);
+        kb_new_target   <- any(kb_first_target,kb_moved,kb_target_reset);
+        eval kb_new_target ([model,kb_target](new)
+            model.set_keyboard_connect_target(&kb_target.value(),new)
+        );
+        out.source.keyboard_connect_target <+ kb_new_target;
+    }
+
+
+
+    // =================
+    // === Lite Mode ===
+    // =================
+
+    let frame_time_monitor = Rc::new(RefCell::new(lite_mode::FrameTimeMonitor::new()));
+    frp::extend! { network
+        lite_mode_changed <- scene.frp.frame_time.filter_map(
+            f!((time) frame_time_monitor.borrow_mut().on_frame(*time))
+        );
+        out.source.lite_mode <+ lite_mode_changed;
+
+        zoom             <- scene.frp.camera_changed.map(f_!(scene.camera().zoom()));
+        nodes_simplified <- all_with(&out.lite_mode,&zoom,|lite,zoom|
+            lite_mode::nodes_simplified(*lite,*zoom)
+        );
+        out.source.nodes_simplified <+ nodes_simplified.on_change();
+    }
+
+
+
+    // =============
+    // === Ports ===
+    // =============
+
+    frp::extend! { network
+        out.source.port_hover_scale <+ inputs.set_port_hover_scale;
+    }
+
+
+
+    // =========================
+    // === Tidy As You Build ===
+    // =========================
+
+    let tidy_as_you_build_enabled = enable_disable_toggle
+        ( network
+        , &inputs.enable_tidy_as_you_build
+        , &inputs.disable_tidy_as_you_build
+        , &inputs.toggle_tidy_as_you_build
+        );
+    let tidy_progress = Animation::<f32>::new(network);
+    frp::extend! { network
+        out.source.tidy_as_you_build_enabled <+ tidy_as_you_build_enabled;
+
+        tidy_edge <- edge_connected.gate(&out.tidy_as_you_build_enabled);
+        tidy_move <- tidy_edge.filter_map(f!((edge_id) model.tidy_connected_node(*edge_id)));
+        tidy_progress.target <+ tidy_move.constant(0.0);
+        tidy_progress.skip   <+ tidy_move.constant(());
+        tidy_progress.target <+ tidy_move.constant(1.0);
+        tidy_position <- tidy_progress.value.map2(&tidy_move,|progress,(node_id,from,to)| {
+            (*node_id,from + (to - from) * *progress)
+        });
+        out.source.node_position_set         <+ tidy_position.gate_not(&node_is_down);
+        out.source.node_position_set_batched <+ tidy_move.map(|(node_id,_,to)| (*node_id,*to));
+    }
+
+
+
+    // =====================
+    // === Type Coloring ===
+    // =====================
+
+    let color_blind_safe_types = enable_disable_toggle
+        ( network
+        , &inputs.enable_color_blind_safe_types
+        , &inputs.disable_color_blind_safe_types
+        , &inputs.toggle_color_blind_safe_types
+        );
+    frp::extend! { network
+        out.source.color_blind_safe_types <+ color_blind_safe_types;
+
+        eval out.color_blind_safe_types ((enabled)
+            type_coloring::set_color_blind_safe(&scene.style_sheet,*enabled));
+        eval inputs.set_type_color (((tp,color))
+            type_coloring::set_custom_color(&scene.style_sheet,tp,*color));
+        type_colors_changed <- any_(out.color_blind_safe_types,inputs.set_type_color);
+        eval_ type_colors_changed ([model,neutral_color]
+            model.refresh_type_colors(neutral_color.value().into())
+        );
+        out.source.type_colors_changed <+ type_colors_changed;
+    }
+
+
+
+    // ===========================
+    // === Node Color Override ===
+    // ===========================
+
+    let edge_color_inheritance_enabled = enable_disable_toggle
+        ( network
+        , &inputs.enable_edge_color_inheritance
+        , &inputs.disable_edge_color_inheritance
+        , &inputs.toggle_edge_color_inheritance
+        );
+    frp::extend! { network
+        out.source.edge_color_inheritance_enabled <+ edge_color_inheritance_enabled;
+
+        eval inputs.set_node_color_override ([model,neutral_color]((node_id,color_override)) {
+            model.with_node(*node_id,|node| node.set_color_override.emit(color_override));
+            for edge_id in model.node_out_edges(*node_id) {
+                model.refresh_edge_color(edge_id,neutral_color.value().into());
+            }
+        });
+        eval_ out.edge_color_inheritance_enabled ([model,neutral_color]
+            model.refresh_all_edge_colors(neutral_color.value().into())
+        );
+    }
+
+
+
+    // ==================
+    // === Data Pulse ===
+    // ==================
+
+    let data_pulse_enabled = enable_disable_toggle
+        ( network
+        , &inputs.enable_data_pulse
+        , &inputs.disable_data_pulse
+        , &inputs.toggle_data_pulse
+        );
+    frp::extend! { network
+        out.source.data_pulse_enabled <+ data_pulse_enabled;
+
+        node_computed <- inputs.set_node_execution_status.filter_map(|(node_id,status)|
+            (*status == node::ExecutionStatus::Completed).as_some(*node_id));
+        node_data_set <- inputs.set_visualization_data._0();
+        pulse_node    <- any(node_computed,node_data_set).gate(&out.data_pulse_enabled);
+        eval pulse_node ((node_id) model.play_data_pulse(*node_id));
+    }
+
+
+
+    // ================
+    // === Feedback ===
+    // ================
+
+    if ARGS.audio_feedback == Some(true) {
+        let audio = feedback::AudioFeedback::new(&model.logger);
+        frp::extend! { network
+            eval out.feedback ((event) audio.play(*event));
+        }
+    }
+
+
+
+    // ========================
+    // === Interaction Mode ===
+    // ========================
+
+    frp::extend! { network
+        out.source.presenting <+ inputs.set_presentation_mode.on_change();
+        read_only             <- inputs.set_read_only || out.presenting;
+        out.source.read_only  <+ read_only.on_change();
+        keyboard_connecting   <- out.keyboard_connect_source.map(|source| source.is_some());
+        text_editing          <- out.node_editing || inputs.set_external_text_focus;
+        interaction_mode      <- all_with5(&out.presenting,&out.read_only,&text_editing,
+            &out.some_edge_endpoints_unset,&keyboard_connecting,
+            |&presenting,&read_only,&editing,&connecting,&keyboard_connecting| {
+                interaction::Mode::from_state
+                    (presenting,read_only,editing,connecting,keyboard_connecting)
+            }
+        );
+        out.source.interaction_mode <+ interaction_mode.on_change();
+        let mode = &out.interaction_mode;
+        let keyboard_connect_mode = interaction::Mode::KeyboardConnect;
+        out.source.in_navigate_mode         <+ mode.map(|m| *m == interaction::Mode::Navigate);
+        out.source.in_edit_mode             <+ mode.map(|m| *m == interaction::Mode::Edit);
+        out.source.in_connect_mode          <+ mode.map(|m| *m == interaction::Mode::Connect);
+        out.source.in_keyboard_connect_mode <+ mode.map(move |m| *m == keyboard_connect_mode);
+        out.source.in_read_only_mode        <+ mode.map(|m| *m == interaction::Mode::ReadOnly);
+        out.source.in_present_mode          <+ mode.map(|m| *m == interaction::Mode::Present);
+    }
+
 
 
     // =========================
@@ -3270,10 +6462,44 @@ fn new_graph_editor(app:&Application) -> GraphEditor {
     frp.source.default_y_gap_between_nodes.emit(default_y_gap.value());
     frp.source.min_x_spacing_for_new_nodes.emit(min_x_spacing.value());
 
+    // =====================
+    // === Debug Overlay ===
+    // =====================
+
+    let debug_overlay_visible = enable_disable_toggle
+        ( network
+        , &inputs.show_debug_overlay
+        , &inputs.hide_debug_overlay
+        , &inputs.toggle_debug_overlay
+        );
+    frp::extend! { network
+        out.source.debug_overlay_visible <+ debug_overlay_visible;
+        eval out.debug_overlay_visible ((visible) model.debug_overlay.set_visible(*visible));
+
+        debug_event <- any_mut::<String>();
+        eval debug_event ((event) model.debug_overlay.log_event(event));
+        debug_refresh <- any_(debug_event,out.debug_overlay_visible,selection_mode);
+        debug_refresh <- debug_refresh.gate(&out.debug_overlay_visible);
+        debug_stats   <- debug_refresh.map2(&selection_mode,
+            f!((_,mode) model.debug_stats(*mode))
+        );
+        eval debug_stats ((stats) model.debug_overlay.update(stats));
+    }
+    debug::log_outputs(network,&frp.output,&debug_event);
+    emission_depth.end(network,&frp.input);
+
+
+
     // Init defaults
     frp.edit_mode_off.emit(());
-
-    GraphEditor {model,frp}
+    frp.enable_culling.emit(());
+    frp.enable_alignment_guides.emit(());
+    frp.set_port_hover_scale.emit(node::DEFAULT_PORT_HOVER_SCALE);
+    frp.source.interaction_mode.emit(interaction::Mode::default());
+    frp.set_layout_flush_interval.emit(DEFAULT_LAYOUT_FLUSH_INTERVAL_S);
+    frp.set_level_transition_duration.emit(transition::DEFAULT_DURATION_MS);
+
+    GraphEditor {model,frp,emission_depth}
 }
 
 
@@ -3284,3 +6510,57 @@ impl display::Object for GraphEditor {
         self.model.display_object()
     }
 }
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDTH     : f32 = 100.0;
+    const THRESHOLD : f32 = 10.0;
+
+    fn grid(xs:&[f32], rights:&[f32], centers:&[f32], ys:&[f32]) -> Grid {
+        let sorted_xs      = xs.to_vec();
+        let sorted_rights  = rights.to_vec();
+        let sorted_centers = centers.to_vec();
+        let sorted_ys      = ys.to_vec();
+        Grid {sorted_xs,sorted_rights,sorted_centers,sorted_ys}
+    }
+
+    fn snapped_x(grid:&Grid, x:f32) -> Option<(f32,SnapTarget)> {
+        grid.close_to(Vector2(x,0.0),WIDTH,THRESHOLD).x
+    }
+
+    #[test]
+    fn snapping_to_each_target() {
+        let origins = grid(&[0.0],&[],&[],&[]);
+        assert_eq!(snapped_x(&origins,3.0),Some((0.0,SnapTarget::Origin)));
+        assert_eq!(snapped_x(&origins,30.0),None);
+
+        let right_edges = grid(&[],&[200.0],&[],&[]);
+        assert_eq!(snapped_x(&right_edges,97.0),Some((100.0,SnapTarget::RightEdge)));
+        assert_eq!(snapped_x(&right_edges,197.0),None);
+
+        let centers = grid(&[],&[],&[150.0],&[]);
+        assert_eq!(snapped_x(&centers,98.0),Some((100.0,SnapTarget::VerticalCenter)));
+        assert_eq!(snapped_x(&centers,148.0),None);
+
+        let ys      = grid(&[],&[],&[],&[50.0]);
+        let snapped = ys.close_to(Vector2(0.0,45.0),WIDTH,THRESHOLD);
+        assert_eq!(snapped.x,None);
+        assert_eq!(snapped.y,Some((50.0,SnapTarget::Origin)));
+    }
+
+    #[test]
+    fn snapping_to_the_closest_target() {
+        let grid = grid(&[0.0],&[105.0],&[],&[]);
+        assert_eq!(snapped_x(&grid,2.0),Some((0.0,SnapTarget::Origin)));
+        assert_eq!(snapped_x(&grid,4.0),Some((5.0,SnapTarget::RightEdge)));
+        assert_eq!(snapped_x(&grid,-6.0),Some((0.0,SnapTarget::Origin)));
+    }
+}
@@ -0,0 +1,171 @@
+//! Lite mode, a cheaper rendering profile for slow GPUs. It is enabled automatically when the
+//! measured frame times stay too long, and disabled once they are short again. In the lite mode
+//! the node shadows and edge animations are disabled, the edges are drawn with simplified shapes,
+//! the zoomed out nodes are simplified, and the number of visualizations is capped.
+
+use crate::prelude::*;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The number of frames whose times are averaged before deciding about the mode.
+const SAMPLE_FRAMES : usize = 60;
+
+/// The average frame time enabling the lite mode, in milliseconds (around 20 FPS).
+const ENABLE_FRAME_TIME_MS : f32 = 50.0;
+
+/// The average frame time disabling the lite mode, in milliseconds (around 40 FPS). It is much
+/// shorter than `ENABLE_FRAME_TIME_MS`, so the mode does not flicker when the rendering becomes
+/// faster thanks to the lite mode itself.
+const DISABLE_FRAME_TIME_MS : f32 = 25.0;
+
+/// The number of consecutive samples of short frame times needed to disable the lite mode.
+const DISABLE_SAMPLES : usize = 5;
+
+/// Frames longer than this, in milliseconds, are not caused by the rendering, but by the browser
+/// throttling the page, e.g. when the tab is in the background. They discard the current sample.
+const MAX_FRAME_TIME_MS : f32 = 1000.0;
+
+/// The maximum number of enabled visualizations in the lite mode.
+pub const MAX_VISUALIZATIONS : usize = 5;
+
+/// The camera zoom below which the nodes are simplified in the lite mode.
+const SIMPLIFIED_NODES_ZOOM : f32 = 0.7;
+
+
+
+// ========================
+// === FrameTimeMonitor ===
+// ========================
+
+/// Decides whether the lite mode should be active, based on the measured frame times. The frame
+/// times are averaged over `SAMPLE_FRAMES` frames. A single sample of long frames enables the
+/// lite mode, while `DISABLE_SAMPLES` consecutive samples of short frames disable it.
+#[derive(Clone,Debug,Default)]
+pub struct FrameTimeMonitor {
+    last_frame_time : Option<f32>,
+    sample_sum      : f32,
+    sample_frames   : usize,
+    short_samples   : usize,
+    lite_mode       : bool,
+}
+
+impl FrameTimeMonitor {
+    /// Constructor.
+    pub fn new() -> Self {
+        default()
+    }
+
+    /// Check whether the lite mode is active.
+    pub fn lite_mode(&self) -> bool {
+        self.lite_mode
+    }
+
+    /// Register the next frame, given the time of its start in milliseconds. Returns the new mode
+    /// if it has changed.
+    pub fn on_frame(&mut self, time:f32) -> Option<bool> {
+        let duration = self.last_frame_time.replace(time).map(|last| time - last)?;
+        self.on_frame_duration(duration)
+    }
+
+    /// Register the duration of a frame in milliseconds. Returns the new mode if it has changed.
+    pub fn on_frame_duration(&mut self, duration:f32) -> Option<bool> {
+        if !(0.0..=MAX_FRAME_TIME_MS).contains(&duration) {
+            self.sample_sum    = 0.0;
+            self.sample_frames = 0;
+            return None
+        }
+        self.sample_sum    += duration;
+        self.sample_frames += 1;
+        if self.sample_frames < SAMPLE_FRAMES {
+            return None
+        }
+        let average        = self.sample_sum / self.sample_frames as f32;
+        self.sample_sum    = 0.0;
+        self.sample_frames = 0;
+        if !self.lite_mode && average > ENABLE_FRAME_TIME_MS {
+            self.lite_mode = true;
+            Some(true)
+        } else if self.lite_mode && average < DISABLE_FRAME_TIME_MS {
+            self.short_samples += 1;
+            (self.short_samples >= DISABLE_SAMPLES).as_some_from(|| {
+                self.short_samples = 0;
+                self.lite_mode     = false;
+                false
+            })
+        } else {
+            self.short_samples = 0;
+            None
+        }
+    }
+}
+
+
+
+// ========================
+// === Simplified Nodes ===
+// ========================
+
+/// Check whether the nodes should be simplified at the given camera zoom. Outside of the lite mode
+/// the nodes are always displayed in full.
+pub fn nodes_simplified(lite_mode:bool, zoom:f32) -> bool {
+    lite_mode && zoom < SIMPLIFIED_NODES_ZOOM
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(monitor:&mut FrameTimeMonitor, duration:f32) -> Option<bool> {
+        (0..SAMPLE_FRAMES).filter_map(|_| monitor.on_frame_duration(duration)).last()
+    }
+
+    #[test]
+    fn switching_lite_mode_with_hysteresis() {
+        let mut monitor = FrameTimeMonitor::new();
+        assert_eq!(sample(&mut monitor,16.0),None);
+        assert_eq!(sample(&mut monitor,30.0),None);
+        assert_eq!(sample(&mut monitor,60.0),Some(true));
+        assert!(monitor.lite_mode());
+        assert_eq!(sample(&mut monitor,60.0),None);
+        assert_eq!(sample(&mut monitor,30.0),None);
+        for _ in 1..DISABLE_SAMPLES {
+            assert_eq!(sample(&mut monitor,16.0),None);
+        }
+        assert_eq!(sample(&mut monitor,30.0),None);
+        for _ in 1..DISABLE_SAMPLES {
+            assert_eq!(sample(&mut monitor,16.0),None);
+        }
+        assert_eq!(sample(&mut monitor,16.0),Some(false));
+        assert!(!monitor.lite_mode());
+    }
+
+    #[test]
+    fn ignoring_throttled_frames() {
+        let mut monitor = FrameTimeMonitor::new();
+        assert_eq!(monitor.on_frame(0.0),None);
+        for frame in 1..SAMPLE_FRAMES {
+            assert_eq!(monitor.on_frame(frame as f32 * 16.0),None);
+        }
+        assert_eq!(monitor.on_frame(60_000.0),None);
+        assert_eq!(sample(&mut monitor,MAX_FRAME_TIME_MS - 1.0),Some(true));
+    }
+
+    #[test]
+    fn simplifying_nodes_only_in_lite_mode() {
+        assert!(!nodes_simplified(false,0.1));
+        assert!(!nodes_simplified(false,1.0));
+        assert!( nodes_simplified(true,SIMPLIFIED_NODES_ZOOM - 0.1));
+        assert!(!nodes_simplified(true,SIMPLIFIED_NODES_ZOOM));
+        assert!(!nodes_simplified(true,1.0));
+    }
+}
@@ -297,6 +297,8 @@ pub struct Controller {
 
     pub cursor_style       : frp::stream::Stream<cursor::Style>,
     pub area_selection     : frp::stream::Stream<bool>,
+    /// The area covered by the ongoing area selection, in the scene coordinates.
+    pub area               : frp::stream::Stream<BoundingBox>,
 }
 
 impl Controller {
@@ -343,8 +345,8 @@ impl Controller {
             cursor_selection       <- any (cursor_selection_start,cursor_selection_end);
 
             cursor_on_down_position <- cursor.frp.scene_position.sample(&mouse.down_primary);
-            should_update_drag      <- is_dragging && touch.background.is_down;
-            cursor_drag_position    <- cursor.frp.scene_position.gate(&should_update_drag).on_change();
+            cursor_drag_position    <- cursor.frp.scene_position.gate_with2(&is_dragging
+                ,&touch.background.is_down,|dragging,down| *dragging && *down).on_change();
 
             scene_bounding_box      <- cursor_drag_position.map2(&cursor_on_down_position,
                 |&m,&n|{
@@ -360,6 +362,7 @@ impl Controller {
                 }
             );
 
+            let area    = scene_bounding_box.clone_ref();
             nodes_in_bb <- scene_bounding_box.map(f!([nodes](bb) get_nodes_in_bounding_box(bb,&nodes)));
             nodes_in_bb <- nodes_in_bb.map(f!([nodes](nodes_selected) {
                 nodes_selected.clone().into_iter().map(|node|{
@@ -410,14 +413,6 @@ impl Controller {
                                            node_to_select_edit);
             node_was_selected       <- node_to_select.map(f!((id) nodes.selected.contains(id)));
 
-            should_select <- node_to_select.map3(&selection_mode,&node_was_selected,
-                |_,mode,was_selected| mode.single_should_select(*was_selected)
-            );
-
-            should_deselect <- node_to_select.map3(&selection_mode,&node_was_selected,
-                |_,mode,was_selected| mode.single_should_deselect(*was_selected)
-            );
-
             deselect_on_select      <- node_to_select.gate_not(&keep_selection);
             deselect_all_nodes      <+ deselect_on_select;
             deselect_all_nodes      <+ editor.deselect_all_nodes;
@@ -427,8 +422,12 @@ impl Controller {
             all_nodes_to_deselect   <= deselect_all_nodes.map(f_!(nodes.selected.mem_take()));
             editor.source.node_deselected <+ all_nodes_to_deselect;
 
-            node_selected           <- node_to_select.gate(&should_select);
-            node_deselected         <- node_to_select.gate(&should_deselect);
+            node_selected           <- node_to_select.gate_with2(&selection_mode,&node_was_selected,
+                |mode,was_selected| mode.single_should_select(*was_selected)
+            );
+            node_deselected         <- node_to_select.gate_with2(&selection_mode,&node_was_selected,
+                |mode,was_selected| mode.single_should_deselect(*was_selected)
+            );
             editor.source.node_selected   <+ node_selected;
             editor.source.node_deselected <+ node_deselected;
 
@@ -441,6 +440,6 @@ impl Controller {
         enable_area_selection.emit(true);
 
         Controller { network,cursor_selection_nodes,enable_area_selection,cursor_style,
-                     area_selection }
+                     area_selection,area }
     }
 }
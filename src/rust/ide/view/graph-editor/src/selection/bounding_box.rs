@@ -46,6 +46,26 @@ impl BoundingBox {
         y > self.bottom && y < self.top
     }
 
+    /// Return the top edge of the bounding box.
+    pub fn top(&self) -> f32 {
+        self.top
+    }
+
+    /// Return the bottom edge of the bounding box.
+    pub fn bottom(&self) -> f32 {
+        self.bottom
+    }
+
+    /// Return the left edge of the bounding box.
+    pub fn left(&self) -> f32 {
+        self.left
+    }
+
+    /// Return the right edge of the bounding box.
+    pub fn right(&self) -> f32 {
+        self.right
+    }
+
     /// Return the width of the bounding box.
     pub fn width(&self) -> f32 {
         self.right - self.left
@@ -0,0 +1,543 @@
+//! Recording and replaying the sessions of the graph editor. The [`Recorder`] captures the events
+//! emitted to the graph editor FRP inputs, together with their timestamps and payloads, into
+//! a [`Script`], which can be saved as JSON. The [`Replayer`] feeds the script back to the inputs
+//! at the original or accelerated speed, and [`replay_immediately`] emits all of its events at
+//! once, which is what the integration tests of the whole graph editor network need.
+//!
+//! Only the inputs whose payloads can be serialized are recorded, see the `recorded_inputs!`
+//! invocation below. The payloads of foreign types are recorded through the local types they
+//! convert to, like [`RecordedColorOverride`]. The interactions handled by the scene directly, like dragging the nodes with
+//! the mouse, do not pass through the inputs and are not recorded. Neither are the events the
+//! graph editor network emits to its own inputs while handling another event, as the replay of the
+//! outer event emits them again, see [`EmissionDepth`]. The recorded node and edge identifiers are
+//! valid in the replay only if the graph editor uses the deterministic [`IdAllocation`] in both
+//! sessions, and the replay starts with the same graph.
+//!
+//! [`IdAllocation`]: crate::IdAllocation
+
+use crate::prelude::*;
+
+use crate::EdgeEndpoint;
+use crate::EdgeId;
+use crate::FrpInputs;
+use crate::GraphEditor;
+use crate::NodeId;
+use crate::component::node;
+
+use enso_frp as frp;
+use ensogl::data::color;
+use ensogl::system::web;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+
+
+// ===================
+// === ReplayError ===
+// ===================
+
+/// Error of replaying the recorded event.
+#[derive(Clone,Debug,Fail)]
+#[fail(display="Cannot replay the event of the `{}` input: {}.",input,reason)]
+pub struct ReplayError {
+    input  : String,
+    reason : String,
+}
+
+impl ReplayError {
+    fn new(input:impl Into<String>, reason:impl Into<String>) -> Self {
+        let input  = input.into();
+        let reason = reason.into();
+        Self {input,reason}
+    }
+}
+
+
+
+// ==============
+// === Script ===
+// ==============
+
+/// A single recorded input event.
+#[derive(Clone,Debug,Deserialize,PartialEq,Serialize)]
+pub struct RecordedEvent {
+    /// The time of the event, in milliseconds from the recording start.
+    pub time    : f64,
+    /// The name of the graph editor input, like `add_node`.
+    pub input   : String,
+    /// The serialized payload of the event.
+    pub payload : serde_json::Value,
+}
+
+/// The recorded session: the input events in the order of their emission.
+#[derive(Clone,Debug,Default,Deserialize,PartialEq,Serialize)]
+pub struct Script {
+    /// The recorded events.
+    pub events : Vec<RecordedEvent>,
+}
+
+impl Script {
+    /// The script serialized to JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Deserialize the script saved with `to_json`.
+    pub fn from_json(json:&str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// The time of the last event, in milliseconds from the recording start.
+    pub fn duration(&self) -> f64 {
+        self.events.last().map(|event| event.time).unwrap_or_default()
+    }
+}
+
+
+
+// =====================
+// === EmissionDepth ===
+// =====================
+
+/// The number of the recorded inputs whose events are being handled at the moment. The graph
+/// editor network emits some of its own inputs while handling the events, and only the top-level
+/// events, emitted from outside of the network, are recorded.
+///
+/// The depth is increased by the first handler of every recorded input and decreased by its last
+/// one. The handlers connected by `begin` must therefore be the first ones connected to the
+/// inputs, and the ones connected by `end` must be connected after the whole graph editor network
+/// is built. The recorder handlers are connected even later, so they see the depth of zero for the
+/// top-level events only.
+#[derive(Clone,CloneRef,Debug,Default)]
+pub struct EmissionDepth {
+    depth : Rc<Cell<usize>>,
+}
+
+impl EmissionDepth {
+    /// Connect the handlers increasing the depth. See the struct docs.
+    pub fn begin(&self, network:&frp::Network, inputs:&FrpInputs) {
+        track_inputs(network,inputs,self,true)
+    }
+
+    /// Connect the handlers decreasing the depth. See the struct docs.
+    pub fn end(&self, network:&frp::Network, inputs:&FrpInputs) {
+        track_inputs(network,inputs,self,false)
+    }
+
+    /// Check whether no event of the recorded inputs is being handled.
+    pub fn is_top_level(&self) -> bool {
+        self.depth.get() == 0
+    }
+
+    fn change(&self, increase:bool) {
+        let depth = self.depth.get();
+        self.depth.set(if increase { depth + 1 } else { depth.saturating_sub(1) });
+    }
+}
+
+
+
+// =======================
+// === Recorded Inputs ===
+// =======================
+
+fn track_input<T:frp::Data>
+(network:&frp::Network, depth:&EmissionDepth, increase:bool, input:&frp::Any<T>) {
+    let depth = depth.clone_ref();
+    frp::extend! { network
+        eval_ input (depth.change(increase));
+    }
+}
+
+fn record_input<T:frp::Data+Serialize>
+(network:&frp::Network, model:&Rc<RecorderModel>, name:&'static str, input:&frp::Any<T>) {
+    let model = model.clone_ref();
+    frp::extend! { network
+        eval input ((payload) model.record(name,payload));
+    }
+}
+
+fn emit_input<T:DeserializeOwned>(input:&frp::Any<T>, event:&RecordedEvent)
+-> Result<(),ReplayError> {
+    let payload = serde_json::from_value(event.payload.clone());
+    let payload = payload.map_err(|err| ReplayError::new(&event.input,err.to_string()))?;
+    input.emit(payload);
+    Ok(())
+}
+
+fn record_converted_input<T,R>
+(network:&frp::Network, model:&Rc<RecorderModel>, name:&'static str, input:&frp::Any<T>)
+where T:frp::Data, R:Serialize+for<'t> From<&'t T> {
+    let model = model.clone_ref();
+    frp::extend! { network
+        eval input ((payload) model.record(name,&R::from(payload)));
+    }
+}
+
+fn emit_converted_input<T,R>(input:&frp::Any<T>, event:&RecordedEvent) -> Result<(),ReplayError>
+where T:frp::Data, R:DeserializeOwned+Into<T> {
+    let payload = serde_json::from_value::<R>(event.payload.clone());
+    let payload = payload.map_err(|err| ReplayError::new(&event.input,err.to_string()))?;
+    input.emit(payload.into());
+    Ok(())
+}
+
+/// Defines the functions connecting the recorder to the listed inputs and replaying their events.
+/// The inputs listed after the semicolon are recorded with their payloads converted to the given
+/// types.
+macro_rules! recorded_inputs {
+    ( $($name:ident),* $(,)?
+    ; $($converted:ident as $payload:ty),* $(,)?
+    ) => {
+        fn track_inputs
+        (network:&frp::Network, inputs:&FrpInputs, depth:&EmissionDepth, increase:bool) {
+            $(track_input(network,depth,increase,&inputs.$name);)*
+            $(track_input(network,depth,increase,&inputs.$converted);)*
+        }
+
+        fn record_inputs(network:&frp::Network, model:&Rc<RecorderModel>, inputs:&FrpInputs) {
+            $(record_input(network,model,stringify!($name),&inputs.$name);)*
+            $(record_converted_input::<_,$payload>
+                (network,model,stringify!($converted),&inputs.$converted);)*
+        }
+
+        fn replay_event(inputs:&FrpInputs, event:&RecordedEvent) -> Result<(),ReplayError> {
+            match event.input.as_str() {
+                $(stringify!($name) => emit_input(&inputs.$name,event),)*
+                $(stringify!($converted) =>
+                    emit_converted_input::<_,$payload>(&inputs.$converted,event),)*
+                _ => Err(ReplayError::new(&event.input,"the input is not recorded")),
+            }
+        }
+    };
+}
+
+recorded_inputs! {
+    cancel, space_for_window_buttons,
+
+    // === Node Selection ===
+    node_press, node_release, enable_node_multi_select, disable_node_multi_select,
+    toggle_node_multi_select, enable_node_merge_select, disable_node_merge_select,
+    toggle_node_merge_select, enable_node_subtract_select, disable_node_subtract_select,
+    toggle_node_subtract_select, enable_node_inverse_select, disable_node_inverse_select,
+    toggle_node_inverse_select, select_node, deselect_node, deselect_all_nodes, select_nodes,
+    deselect_nodes, set_selection, enable_edge_area_select, disable_edge_area_select,
+    toggle_edge_area_select,
+
+    // === Navigation ===
+    enter_selected_node, enter_hovered_node, exit_node, enable_level_transitions,
+    disable_level_transitions, set_presentation_mode,
+
+    // === Node Editing ===
+    add_node, add_node_at_cursor, remove_selected_nodes, start_removal_preview_for_selected_nodes,
+    stop_removal_preview, remove_all_nodes, edit_mode_on, edit_mode_off, stop_editing,
+    collapse_selected_nodes, move_selected_node_upstream, move_selected_node_downstream,
+    remove_node, edit_node, clear_expression_preview, set_node_position, set_node_expression,
+    set_node_pinned, set_node_frozen, set_node_skipped, recompute_node, reveal_masked_ports,
+    reveal_masked_ports_of_selected_nodes, enable_argument_editing, disable_argument_editing,
+
+    // === Edges ===
+    connect_nodes, split_pressed_edge, unset_edge_source, unset_edge_target, remove_all_node_edges,
+    remove_all_node_input_edges, remove_all_node_output_edges, remove_edge,
+
+    // === Visualization ===
+    press_visualization_visibility, double_press_visualization_visibility,
+    release_visualization_visibility, cycle_visualization_for_selected_node,
+    enter_visualization_fullscreen, close_fullscreen_visualization, compare_visualizations,
+    close_visualization_comparison, cycle_visualization, enable_visualization,
+    disable_visualization, enable_quick_visualization_preview,
+    disable_quick_visualization_preview, pin_visualization, set_visualization,
+
+    // === Scene Navigation ===
+    set_navigator_disabled, set_scroll_navigation_enabled, zoom_to_fit, select_component_of,
+    zoom_to_component, enable_split_view, disable_split_view, toggle_split_view,
+
+    // === Accessibility ===
+    focus_next_node, focus_previous_node, focus_next_port, focus_previous_port, focus_node,
+    clear_focus,
+
+    // === Keyboard Connect ===
+    start_keyboard_connect, keyboard_connect_next_port, keyboard_connect_previous_port,
+    confirm_keyboard_connect, cancel_keyboard_connect,
+
+    // === Modes ===
+    hide_context_menu, toggle_profiling_mode, show_profiling_heatmap, hide_profiling_heatmap,
+    toggle_profiling_heatmap, show_execution_order, hide_execution_order,
+    toggle_execution_order, enable_edge_declutter, disable_edge_declutter, toggle_edge_declutter,
+    enable_alignment_guides, disable_alignment_guides, toggle_alignment_guides,
+    enable_culling, disable_culling, toggle_culling, enable_tidy_as_you_build,
+    disable_tidy_as_you_build, toggle_tidy_as_you_build, set_port_hover_scale,
+    enable_color_blind_safe_types, disable_color_blind_safe_types, toggle_color_blind_safe_types,
+    set_layout_flush_interval, enable_edge_color_inheritance, disable_edge_color_inheritance,
+    toggle_edge_color_inheritance;
+
+    // === Converted Payloads ===
+    set_node_color_override as RecordedColorOverride,
+}
+
+
+
+// ================
+// === Recorder ===
+// ================
+
+/// Records the events of the graph editor inputs until dropped. See the module docs.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Recorder {
+    network : frp::Network,
+    model   : Rc<RecorderModel>,
+}
+
+#[derive(Debug)]
+struct RecorderModel {
+    logger : Logger,
+    depth  : EmissionDepth,
+    start  : f64,
+    events : RefCell<Vec<RecordedEvent>>,
+}
+
+impl RecorderModel {
+    fn record<T:Serialize>(&self, input:&str, payload:&T) {
+        if !self.depth.is_top_level() { return }
+        match serde_json::to_value(payload) {
+            Ok(payload) => {
+                let time  = web::time_from_start() - self.start;
+                let input = input.into();
+                self.events.borrow_mut().push(RecordedEvent {time,input,payload});
+            }
+            Err(err) => warning!(self.logger, "Cannot record the event of `{input}`: {err}."),
+        }
+    }
+}
+
+impl Recorder {
+    /// Start recording the events of the graph editor inputs.
+    pub fn new(logger:&Logger, graph_editor:&GraphEditor) -> Self {
+        let network = frp::Network::new("GraphEditorRecorder");
+        let logger  = Logger::new_sub(logger,"Recorder");
+        let depth   = graph_editor.emission_depth.clone_ref();
+        let start   = web::time_from_start();
+        let events  = default();
+        let model   = Rc::new(RecorderModel {logger,depth,start,events});
+        record_inputs(&network,&model,&graph_editor.frp.input);
+        Self {network,model}
+    }
+
+    /// The events recorded so far.
+    pub fn script(&self) -> Script {
+        let events = self.model.events.borrow().clone();
+        Script {events}
+    }
+}
+
+
+
+// ================
+// === Replayer ===
+// ================
+
+/// Emit all the events of the script to the inputs at once, in the recorded order. Stops at the
+/// first event which cannot be replayed.
+pub fn replay_immediately(inputs:&FrpInputs, script:&Script) -> Result<(),ReplayError> {
+    script.events.iter().try_for_each(|event| replay_event(inputs,event))
+}
+
+/// Replays the script on the animation frames, emitting the events when their time comes. The
+/// replay stops when dropped. The events which cannot be replayed are skipped with a warning.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Replayer {
+    network      : frp::Network,
+    /// Emitted once, after the last event of the script was replayed.
+    pub finished : frp::Stream,
+    model        : Rc<ReplayerModel>,
+    frames       : frp::io::Frames,
+}
+
+#[derive(Debug)]
+struct ReplayerModel {
+    logger : Logger,
+    inputs : FrpInputs,
+    script : Script,
+    speed  : f64,
+    start  : Cell<Option<f64>>,
+    next   : Cell<usize>,
+}
+
+impl ReplayerModel {
+    /// Replay the events due at the given frame time. Returns `true` if all the events were
+    /// replayed.
+    fn replay_until(&self, frame_time:f64) -> bool {
+        let start   = self.start.get().unwrap_or(frame_time);
+        let elapsed = (frame_time - start) * self.speed;
+        self.start.set(Some(start));
+        while let Some(event) = self.script.events.get(self.next.get()) {
+            if event.time > elapsed { break }
+            self.next.set(self.next.get() + 1);
+            if let Err(err) = replay_event(&self.inputs,event) {
+                warning!(self.logger, "{err}");
+            }
+        }
+        self.next.get() >= self.script.events.len()
+    }
+}
+
+impl Replayer {
+    /// Start replaying the script. The `speed` of `1.0` replays the events with their original
+    /// timing, the `speed` of `2.0` twice as fast, and so on.
+    pub fn new(logger:&Logger, inputs:&FrpInputs, script:Script, speed:f64) -> Self {
+        let network = frp::Network::new("GraphEditorReplayer");
+        let frames  = frp::io::Frames::new(&network);
+        let logger  = Logger::new_sub(logger,"Replayer");
+        let inputs  = inputs.clone_ref();
+        let speed   = speed.max(f64::EPSILON);
+        let start   = default();
+        let next    = default();
+        let model   = Rc::new(ReplayerModel {logger,inputs,script,speed,start,next});
+        frp::extend! { network
+            all_replayed <- frames.time.map(f!((time) model.replay_until(*time as f64)));
+            finished     <- all_replayed.on_change().on_true();
+        }
+        let finished = finished.into();
+        Self {network,finished,model,frames}
+    }
+
+    /// Check whether all the events of the script were replayed.
+    pub fn is_finished(&self) -> bool {
+        self.model.next.get() >= self.model.script.events.len()
+    }
+}
+
+
+
+// =============================
+// === Payloads Serialization ===
+// =============================
+
+impl Serialize for NodeId {
+    fn serialize<S:serde::Serializer>(&self, serializer:S) -> Result<S::Ok,S::Error> {
+        usize::from(self.0).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NodeId {
+    fn deserialize<D:serde::Deserializer<'de>>(deserializer:D) -> Result<Self,D::Error> {
+        usize::deserialize(deserializer).map(|id| Self(id.into()))
+    }
+}
+
+impl Serialize for EdgeId {
+    fn serialize<S:serde::Serializer>(&self, serializer:S) -> Result<S::Ok,S::Error> {
+        usize::from(self.0).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EdgeId {
+    fn deserialize<D:serde::Deserializer<'de>>(deserializer:D) -> Result<Self,D::Error> {
+        usize::deserialize(deserializer).map(|id| Self(id.into()))
+    }
+}
+
+impl Serialize for EdgeEndpoint {
+    fn serialize<S:serde::Serializer>(&self, serializer:S) -> Result<S::Ok,S::Error> {
+        (self.node_id,self.port.vec.as_ref()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EdgeEndpoint {
+    fn deserialize<D:serde::Deserializer<'de>>(deserializer:D) -> Result<Self,D::Error> {
+        let (node_id,port) = <(NodeId,Vec<span_tree::Crumb>)>::deserialize(deserializer)?;
+        Ok(Self::new(node_id,span_tree::Crumbs::new(port)))
+    }
+}
+
+/// The recorded part of the node expression. The span trees are generated again from the code in
+/// the replay, so the ports of the expressions set by the controller may differ.
+#[derive(Deserialize,Serialize)]
+struct RecordedExpression {
+    pattern             : Option<String>,
+    code                : String,
+    whole_expression_id : Option<ast::Id>,
+}
+
+impl Serialize for node::Expression {
+    fn serialize<S:serde::Serializer>(&self, serializer:S) -> Result<S::Ok,S::Error> {
+        let pattern             = self.pattern.clone();
+        let code                = self.code.clone();
+        let whole_expression_id = self.whole_expression_id;
+        RecordedExpression {pattern,code,whole_expression_id}.serialize(serializer)
+    }
+}
+
+/// The recorded payload of the `set_node_color_override` input: the node and the lightness,
+/// chroma, hue, and alpha of the override color.
+#[derive(Deserialize,Serialize)]
+struct RecordedColorOverride(NodeId,Option<(f32,f32,f32,f32)>);
+
+impl From<&(NodeId,Option<color::Lcha>)> for RecordedColorOverride {
+    fn from((node_id,color):&(NodeId,Option<color::Lcha>)) -> Self {
+        let components = color.map(|color| {
+            let lch = color.opaque;
+            (lch.lightness,lch.chroma,lch.hue,color.alpha)
+        });
+        Self(*node_id,components)
+    }
+}
+
+impl From<RecordedColorOverride> for (NodeId,Option<color::Lcha>) {
+    fn from(RecordedColorOverride(node_id,components):RecordedColorOverride) -> Self {
+        let color = components.map(|(lightness,chroma,hue,alpha)| {
+            color::Lcha::new(lightness,chroma,hue,alpha)
+        });
+        (node_id,color)
+    }
+}
+
+impl<'de> Deserialize<'de> for node::Expression {
+    fn deserialize<D:serde::Deserializer<'de>>(deserializer:D) -> Result<Self,D::Error> {
+        let recorded       = RecordedExpression::deserialize(deserializer)?;
+        let mut expression = Self::new_plain(recorded.code);
+        expression.pattern             = recorded.pattern;
+        expression.whole_expression_id = recorded.whole_expression_id;
+        Ok(expression)
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ensogl::display::object::Id;
+
+    #[test]
+    fn script_round_trips_through_json() {
+        let node_id = NodeId(Id::from(7));
+        let payload = serde_json::to_value(&(node_id,Vector2(1.0,2.0))).unwrap();
+        let input   = "set_node_position".into();
+        let events  = vec![RecordedEvent {time:12.5,input,payload}];
+        let script  = Script {events};
+        let parsed  = Script::from_json(&script.to_json()).unwrap();
+        assert_eq!(parsed,script);
+        let (id,_) : (NodeId,Vector2) = serde_json::from_value(parsed.events[0].payload.clone())
+            .unwrap();
+        assert_eq!(id,node_id);
+        assert_eq!(parsed.duration(),12.5);
+    }
+
+    #[test]
+    fn color_override_round_trips_through_json() {
+        let node_id  = NodeId(Id::from(3));
+        let color    = color::Lcha::new(0.5,0.25,0.75,1.0);
+        let payload  = serde_json::to_value(&RecordedColorOverride::from(&(node_id,Some(color))));
+        let recorded = serde_json::from_value::<RecordedColorOverride>(payload.unwrap()).unwrap();
+        let (id,parsed_color) : (NodeId,Option<color::Lcha>) = recorded.into();
+        assert_eq!(id,node_id);
+        assert_eq!(parsed_color,Some(color));
+    }
+}
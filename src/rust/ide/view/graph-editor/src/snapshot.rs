@@ -0,0 +1,211 @@
+//! Utilities for snapshot testing of the graph editor components. The [`Harness`] instantiates the
+//! components in an off-screen scene of a fixed size, renders them to a texture, and compares the
+//! rendered images with the golden ones.
+//!
+//! The golden images are stored in the PAM format (see [`Snapshot::to_pam`]) in the `tests/golden`
+//! directory, and embedded with `include_bytes!`. To record or update a golden image, run the tests
+//! in a visible browser (`wasm-pack test --chrome -- --features snapshots` in the crate directory)
+//! and open the test page. Every image failing the comparison is saved in the
+//! `window.ensoSnapshots` object under the test name, and can be downloaded from the developer
+//! console, e.g. with `URL.createObjectURL(new Blob([window.ensoSnapshots['node_default']]))`.
+//! The images of the goldens not recorded yet are saved there as well, see
+//! [`Harness::assert_matches_recorded_golden`].
+//!
+//! Taking the snapshots needs the render pass reading the whole image, which is added to the
+//! rendering pipeline only with the `snapshots` feature of this crate. Without the feature, the
+//! harness can still be used to instantiate the components.
+
+use crate::prelude::*;
+
+use crate::component::breadcrumbs::Breadcrumbs;
+use crate::component::edge::Edge;
+use crate::component::node::Node;
+use crate::component::visualization;
+
+use ensogl::application::Application;
+use ensogl::display;
+#[cfg(feature = "snapshots")]
+use ensogl::display::render::passes::Comparison;
+#[cfg(feature = "snapshots")]
+use ensogl::display::render::passes::Snapshot;
+#[cfg(feature = "snapshots")]
+use ensogl::display::render::passes::Tolerance;
+use ensogl::system::web;
+use ensogl::system::web::NodeInserter;
+use ensogl::system::web::StyleSetter;
+use ensogl_text as text;
+use std::time::Duration;
+
+#[cfg(feature = "snapshots")]
+pub use ensogl::display::render::passes::InvalidSnapshot;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// Default width of the rendered scene, in pixels.
+pub const DEFAULT_WIDTH : usize = 320;
+/// Default height of the rendered scene, in pixels.
+pub const DEFAULT_HEIGHT : usize = 240;
+/// The time given to the animations to finish before taking a snapshot.
+const SETTLE_TIME : Duration = Duration::from_millis(1000);
+/// Name of the global JS object collecting the mismatched snapshots.
+#[cfg(feature = "snapshots")]
+const MISMATCHED_SNAPSHOTS : &str = "ensoSnapshots";
+
+
+
+// ===============
+// === Harness ===
+// ===============
+
+/// An application rendering to an off-screen canvas of a fixed size. The components created by
+/// the harness are added to the scene and stay there for the lifetime of the harness.
+#[derive(Debug)]
+#[allow(missing_docs)]
+pub struct Harness {
+    pub app      : Application,
+    pub registry : visualization::Registry,
+    logger       : Logger,
+    root         : web::HtmlDivElement,
+    components   : RefCell<Vec<display::object::Instance>>,
+}
+
+impl Harness {
+    /// Constructor. Waits for the font rendering to be initialized.
+    pub async fn new(width:usize, height:usize) -> Self {
+        ensogl_text_msdf_sys::initialized().await;
+        let root = web::create_div();
+        root.set_style_or_panic("position","fixed");
+        root.set_style_or_panic("left","-10000px");
+        root.set_style_or_panic("top","0px");
+        web::body().append_or_panic(&root);
+        let app   = Application::new(&root);
+        let scene = app.display.scene();
+        scene.dom.root.set_style_or_panic("width",format!("{}px",width));
+        scene.dom.root.set_style_or_panic("height",format!("{}px",height));
+        scene.dom.recompute_shape_with_reflow();
+        ensogl_theme::builtin::light::register(&app);
+        ensogl_theme::builtin::light::enable(&app);
+        // The cursor follows the mouse, so it is hidden to keep the snapshots deterministic.
+        app.cursor.unset_parent();
+        app.views.register::<text::Area>();
+        let registry   = visualization::Registry::with_default_visualizations();
+        let logger     = Logger::new("SnapshotHarness");
+        let components = default();
+        Self {app,registry,logger,root,components}
+    }
+
+    fn add(&self, component:&impl display::Object) {
+        self.app.display.add_child(component);
+        self.components.borrow_mut().push(component.display_object().clone_ref());
+    }
+
+    /// Create a node placed in the center of the scene.
+    pub fn node(&self) -> Node {
+        let node = Node::new(&self.app,self.registry.clone_ref());
+        self.add(&node);
+        node
+    }
+
+    /// Create an edge placed in the center of the scene.
+    pub fn edge(&self) -> Edge {
+        let edge = Edge::new(&self.app);
+        self.add(&edge);
+        edge
+    }
+
+    /// Create a breadcrumbs panel placed in the top left corner of the scene.
+    pub fn breadcrumbs(&self) -> Breadcrumbs {
+        let breadcrumbs = Breadcrumbs::new(self.app.clone_ref());
+        let shape       = self.app.display.scene().shape().value();
+        breadcrumbs.set_position_xy(Vector2(-shape.width,shape.height) / 2.0);
+        self.add(&breadcrumbs);
+        breadcrumbs
+    }
+
+    /// Wait for the animations started by the previous FRP inputs to finish.
+    pub async fn settle(&self) {
+        web::sleep(SETTLE_TIME).await
+    }
+
+    /// Render the scene and read the rendered image.
+    #[cfg(feature = "snapshots")]
+    pub fn snapshot(&self) -> Snapshot {
+        self.app.display.snapshot().expect("The scene was not rendered.")
+    }
+
+    /// Render only the given component. The other components created by the harness are hidden
+    /// for the time of rendering.
+    #[cfg(feature = "snapshots")]
+    pub fn snapshot_of(&self, component:&impl display::Object) -> Snapshot {
+        let components = self.components.borrow();
+        let hidden     = components.iter().filter(|other| other.id() != component.id());
+        let hidden     = hidden.collect_vec();
+        for other in &hidden { other.unset_parent() }
+        let snapshot = self.snapshot();
+        for other in &hidden { self.app.display.add_child(*other) }
+        snapshot
+    }
+
+    /// Compare the snapshot with the golden image saved with [`Snapshot::to_pam`]. If the images
+    /// do not match, the snapshot is saved in the `window.ensoSnapshots` object under the given
+    /// name, so it can be inspected or recorded as the new golden image.
+    #[cfg(feature = "snapshots")]
+    pub fn compare_with_golden
+    (&self, name:&str, snapshot:&Snapshot, golden:&[u8], tolerance:Tolerance)
+    -> Result<Comparison,InvalidSnapshot> {
+        let comparison = snapshot.compare(&Snapshot::from_pam(golden)?,tolerance);
+        if !comparison.matches() {
+            self.save_mismatched_snapshot(name,snapshot);
+        }
+        Ok(comparison)
+    }
+
+    /// Assert that the snapshot matches the golden image within the default tolerance.
+    #[cfg(feature = "snapshots")]
+    pub fn assert_matches_golden(&self, name:&str, snapshot:&Snapshot, golden:&[u8]) {
+        let comparison = self.compare_with_golden(name,snapshot,golden,default());
+        let comparison = comparison.unwrap_or_else(|err| panic!("{}: {}",name,err));
+        assert!(comparison.matches(),"Snapshot `{}` does not match the golden image: {:?}. The \
+            rendered image was saved in `window.{}`.",name,comparison,MISMATCHED_SNAPSHOTS);
+    }
+
+    /// Like [`Self::assert_matches_golden`], but the golden image may be not recorded yet. Then
+    /// the snapshot is only saved in the `window.ensoSnapshots` object, so it can be recorded.
+    #[cfg(feature = "snapshots")]
+    pub fn assert_matches_recorded_golden
+    (&self, name:&str, snapshot:&Snapshot, golden:Option<&[u8]>) {
+        match golden {
+            Some(golden) => self.assert_matches_golden(name,snapshot,golden),
+            None         => {
+                warning!(&self.logger,"The golden image `{name}` is not recorded yet.");
+                self.save_mismatched_snapshot(name,snapshot);
+            }
+        }
+    }
+
+    /// Save the snapshot in the global JS object, as it can't be written to a file from the
+    /// browser.
+    #[cfg(feature = "snapshots")]
+    fn save_mismatched_snapshot(&self, name:&str, snapshot:&Snapshot) {
+        let window    = web::window();
+        let key       = web::JsValue::from_str(MISMATCHED_SNAPSHOTS);
+        let existing  = js_sys::Reflect::get(&window,&key).ok().filter(|t| t.is_object());
+        let snapshots = existing.unwrap_or_else(|| js_sys::Object::new().into());
+        let pam       = js_sys::Uint8Array::from(&snapshot.to_pam()[..]);
+        let saved     = js_sys::Reflect::set(&snapshots,&web::JsValue::from_str(name),&pam)
+            .and_then(|_| js_sys::Reflect::set(&window,&key,&snapshots));
+        if saved.is_err() {
+            warning!(&self.logger,"Cannot save the mismatched snapshot `{name}`.");
+        }
+    }
+}
+
+impl Drop for Harness {
+    fn drop(&mut self) {
+        self.root.remove();
+    }
+}
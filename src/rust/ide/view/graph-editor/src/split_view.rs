@@ -0,0 +1,227 @@
+//! Splitting the workspace into two viewports displaying distant parts of the same graph at once.
+//! The primary viewport is the scene itself, clipped to the left half of the screen. The secondary
+//! one mirrors all the layers using the main camera (see [`Layer::set_mirrored`]) with its own
+//! camera, clipped to the right half of the screen. Both viewports display the very same nodes and
+//! edges, and each has its own [`Navigator`], active when the viewport is hovered.
+//!
+//! Please note that the DOM layers cannot use multi-camera setups now, so the DOM-based
+//! visualizations are displayed in the primary viewport only.
+
+use crate::prelude::*;
+
+use ensogl::display::Scene;
+use ensogl::display::camera::Camera2d;
+use ensogl::display::navigation::navigator::Navigator;
+use ensogl::display::scene::layer::Layer;
+use ensogl::display::scene::layer::ScissorBox;
+use ensogl::system::web::dom;
+
+
+
+// ================
+// === Viewport ===
+// ================
+
+/// One of the viewports of the split workspace.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum Viewport {
+    /// The left viewport, displayed with the main camera of the scene.
+    Primary,
+    /// The right viewport, displayed with the camera of the [`SplitView`].
+    Secondary,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self::Primary
+    }
+}
+
+impl Viewport {
+    /// The viewport under the given horizontal mouse position, measured from the screen center.
+    pub fn at(x:f32) -> Self {
+        if x < 0.0 { Self::Primary } else { Self::Secondary }
+    }
+}
+
+/// The scissor boxes of the primary and the secondary viewports on the screen of the given shape,
+/// in device pixels.
+pub fn scissor_boxes(shape:dom::Shape) -> (ScissorBox,ScissorBox) {
+    let shape     = shape.device_pixels();
+    let width     = shape.width  as i32;
+    let height    = shape.height as i32;
+    let half      = width / 2;
+    let primary   = ScissorBox::new_with_position_and_size(Vector2(0,0),Vector2(half,height));
+    let secondary = ScissorBox::new_with_position_and_size
+        (Vector2(half,0),Vector2(width-half,height));
+    (primary,secondary)
+}
+
+
+
+// =================
+// === SplitView ===
+// =================
+
+/// The secondary viewport of the split workspace. See the module docs to learn more.
+#[derive(Clone,CloneRef,Debug)]
+pub struct SplitView {
+    logger    : Logger,
+    scene     : Scene,
+    camera    : Camera2d,
+    navigator : Navigator,
+    mirrors   : Rc<Vec<(Layer,Layer)>>,
+    enabled   : Rc<Cell<bool>>,
+    hovered   : Rc<Cell<Viewport>>,
+}
+
+impl SplitView {
+    /// Constructor. The workspace is not split until [`enable`] is called.
+    pub fn new(logger:&Logger, scene:&Scene) -> Self {
+        let logger    = Logger::new_sub(logger,"SplitView");
+        let scene     = scene.clone_ref();
+        let camera    = Camera2d::new(&logger);
+        let navigator = Navigator::new(&scene,&camera);
+        let layers    = &scene.layers;
+        let sources   = [&layers.viz,&layers.below_main,&layers.main,&layers.label
+                        ,&layers.above_nodes,&layers.above_nodes_text];
+        let mirrors   = sources.iter().map(|source| {
+            let mirror = Layer::new_with_cam(logger.sub("mirror"),&camera);
+            ((*source).clone_ref(),mirror)
+        }).collect();
+        let mirrors   = Rc::new(mirrors);
+        let enabled   = default();
+        let hovered   = default();
+        navigator.disable();
+        Self {logger,scene,camera,navigator,mirrors,enabled,hovered}
+    }
+
+    /// Whether the workspace is split.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.get()
+    }
+
+    /// The camera of the secondary viewport.
+    pub fn camera(&self) -> &Camera2d {
+        &self.camera
+    }
+
+    /// The navigator of the secondary viewport.
+    pub fn navigator(&self) -> &Navigator {
+        &self.navigator
+    }
+
+    /// Split the workspace. The secondary viewport starts displaying the same part of the graph
+    /// as the primary one.
+    pub fn enable(&self) {
+        if !self.enabled.replace(true) {
+            debug!(self.logger, "Splitting the workspace.");
+            let main_camera = self.scene.camera();
+            let screen      = main_camera.screen();
+            self.camera.set_screen(screen.width,screen.height);
+            self.camera.set_position(main_camera.position());
+            let mirrors = self.mirrors.iter().map(|(_,mirror)| mirror).collect_vec();
+            for (source,mirror) in self.mirrors.iter() {
+                mirror.set_mirrored(Some(source));
+            }
+            self.scene.layers.split.set_sublayers(&mirrors);
+            self.update_layout();
+        }
+    }
+
+    /// Join the viewports back, leaving only the primary one.
+    pub fn disable(&self) {
+        if self.enabled.replace(false) {
+            debug!(self.logger, "Joining the workspace.");
+            self.scene.layers.split.set_sublayers(&[]);
+            for (source,mirror) in self.mirrors.iter() {
+                mirror.set_mirrored(None);
+                source.set_scissor_box(None);
+            }
+            self.navigator.disable();
+            self.hovered.set(Viewport::Primary);
+        }
+    }
+
+    /// Update the clipping of the viewports and the screen of the secondary camera to the current
+    /// shape of the screen. The scene resizes only its main camera, so this should be called on
+    /// every screen resize.
+    pub fn update_layout(&self) {
+        if self.is_enabled() {
+            let shape = self.scene.shape().value();
+            self.camera.set_screen(shape.width,shape.height);
+            let (primary,secondary) = scissor_boxes(shape);
+            for (source,mirror) in self.mirrors.iter() {
+                source.set_scissor_box(Some(&primary));
+                mirror.set_scissor_box(Some(&secondary));
+            }
+        }
+    }
+
+    /// The hovered viewport. It is always the primary one when the workspace is not split.
+    pub fn hovered(&self) -> Viewport {
+        self.hovered.get()
+    }
+
+    /// Set the hovered viewport. It is ignored when the workspace is not split.
+    pub fn set_hovered(&self, viewport:Viewport) {
+        let viewport = if self.is_enabled() { viewport } else { Viewport::Primary };
+        self.hovered.set(viewport);
+    }
+
+    /// Enable or disable the navigator of the secondary viewport. It should be enabled only when
+    /// the viewport is hovered and the scene navigation is not locked.
+    pub fn set_navigator_enabled(&self, enabled:bool) {
+        if enabled { self.navigator.enable() } else { self.navigator.disable() }
+    }
+
+    /// Update the camera of the secondary viewport, returning whether it changed. The scene
+    /// updates it as well, but reports the changes of its main camera only, so this should be
+    /// called on every frame, before the scene update.
+    pub fn update_camera(&self) -> bool {
+        self.camera.update(&self.scene)
+    }
+
+    /// The camera of the given viewport.
+    pub fn camera_of(&self, viewport:Viewport) -> Camera2d {
+        match viewport {
+            Viewport::Primary   => self.scene.camera(),
+            Viewport::Secondary => self.camera.clone_ref(),
+        }
+    }
+
+    /// The camera of the hovered viewport.
+    pub fn hovered_camera(&self) -> Camera2d {
+        self.camera_of(self.hovered())
+    }
+
+    /// Convert the screen position to the scene coordinates, using the camera of the hovered
+    /// viewport.
+    pub fn screen_to_scene_coordinates(&self, position:Vector3<f32>) -> Vector3<f32> {
+        let camera   = self.hovered_camera();
+        let position = position / camera.zoom();
+        let position = Vector4(position.x,position.y,position.z,1.0);
+        (camera.inversed_view_matrix() * position).xyz()
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splitting_the_screen() {
+        let shape = dom::Shape {width:101.0,height:50.0,pixel_ratio:2.0};
+        let (primary,secondary) = scissor_boxes(shape);
+        assert_eq!((primary.min_x,primary.max_x,primary.max_y),(0,101,100));
+        assert_eq!((secondary.min_x,secondary.max_x,secondary.max_y),(101,202,100));
+        assert_eq!(Viewport::at(-1.0),Viewport::Primary);
+        assert_eq!(Viewport::at(1.0),Viewport::Secondary);
+    }
+}
@@ -0,0 +1,83 @@
+//! Node templates: ready-made expressions inserted as new nodes, e.g. by a snippet panel or the
+//! onboarding flows. A template may mark the parts of its code which the user is expected to fill
+//! in, and describe how its visualization should be displayed.
+
+use crate::prelude::*;
+
+use crate::component::node;
+use crate::component::visualization;
+use crate::component::visualization::container::DEFAULT_SIZE;
+
+use ensogl_text as text;
+
+
+
+// ===================
+// === Placeholder ===
+// ===================
+
+/// A part of the template code which the user is expected to replace, like the `_` standing for
+/// a missing argument.
+#[derive(Clone,Debug,Default,PartialEq)]
+pub struct Placeholder {
+    /// The byte range of the placeholder in the template code.
+    pub range : text::Range<text::Bytes>,
+    /// The name of the argument expected in place of the placeholder, if known.
+    pub name  : Option<ImString>,
+}
+
+impl Placeholder {
+    /// Constructor.
+    pub fn new(range:impl Into<text::Range<text::Bytes>>, name:Option<ImString>) -> Self {
+        let range = range.into();
+        Self {range,name}
+    }
+}
+
+
+
+// ====================
+// === NodeTemplate ===
+// ====================
+
+/// The description of a node created with `add_node_from_template`. The node is placed at the
+/// mouse cursor, with its visualization enabled, and if the template has any placeholders, the
+/// node is edited with the first placeholder selected.
+#[derive(Clone,Debug,PartialEq)]
+pub struct NodeTemplate {
+    /// The expression of the node.
+    pub expression    : node::Expression,
+    /// The placeholders of the expression, in the order in which they should be filled.
+    pub placeholders  : Vec<Placeholder>,
+    /// The visualization to enable. The default one is enabled if not provided.
+    pub visualization : Option<visualization::Path>,
+    /// The size of the visualization, relative to the default one.
+    pub relative_size : Vector2,
+}
+
+impl NodeTemplate {
+    /// Constructor of the template without placeholders, displaying the default visualization.
+    pub fn new(expression:impl Into<node::Expression>) -> Self {
+        let expression    = expression.into();
+        let placeholders  = default();
+        let visualization = default();
+        let relative_size = Vector2(1.0,1.0);
+        Self {expression,placeholders,visualization,relative_size}
+    }
+
+    /// The placeholder to be filled first.
+    pub fn first_placeholder(&self) -> Option<&Placeholder> {
+        self.placeholders.first()
+    }
+
+    /// The size of the visualization of the created node.
+    pub fn visualization_size(&self) -> Vector2 {
+        Vector2(DEFAULT_SIZE.0,DEFAULT_SIZE.1).component_mul(&self.relative_size)
+    }
+}
+
+impl Default for NodeTemplate {
+    fn default() -> Self {
+        Self::new(node::Expression::default())
+    }
+}
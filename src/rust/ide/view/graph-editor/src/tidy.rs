@@ -0,0 +1,83 @@
+//! Tidy-as-you-build mode. When enabled, the node connected by the user to the output of another
+//! node is moved to a tidy position below its source, so linear pipelines stay neat without
+//! running the full auto-layout. The nodes connected to the same source are placed in the lanes
+//! next to each other, keeping a consistent horizontal gap.
+
+use crate::prelude::*;
+
+use crate::selection::BoundingBox;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The maximum number of lanes checked for a free slot. If all of them are occupied, the node is
+/// placed in the last one.
+const MAX_LANES : usize = 32;
+
+
+
+// ============
+// === Slot ===
+// ============
+
+/// Find the tidy position of the node of the given size connected to the `source` node. The
+/// returned position is the position of the node, that is, the middle of its left edge.
+///
+/// The node is placed below the source, left-aligned with it, with the `y_gap` between them. If
+/// the slot is occupied by any of the `obstacles`, the node is moved to the right of the
+/// obstacle, to the next lane, keeping the `x_gap` from it.
+pub fn slot_below
+(source:&BoundingBox, size:Vector2, obstacles:&[BoundingBox], x_gap:f32, y_gap:f32) -> Vector2 {
+    let y     = source.bottom() - y_gap - size.y / 2.0;
+    let mut x = source.left();
+    for _ in 0..MAX_LANES {
+        let mut slot = BoundingBox::from_position_and_size(Vector2(x,y - size.y / 2.0),size);
+        slot.grow_x(x_gap);
+        slot.grow_y(y_gap);
+        let blocking = obstacles.iter().filter(|obstacle| obstacle.intersects(&slot));
+        let blocking = blocking.map(|obstacle| obstacle.right()).fold(None,|max:Option<f32>,t| {
+            Some(max.map_or(t,|max| max.max(t)))
+        });
+        match blocking {
+            Some(right) => x = right + x_gap,
+            None        => break,
+        }
+    }
+    Vector2(x,y)
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(x:f32, y:f32, width:f32) -> BoundingBox {
+        BoundingBox::from_position_and_size(Vector2(x,y - 14.0),Vector2(width,28.0))
+    }
+
+    #[test]
+    fn placing_below_source() {
+        let source = node(100.0,0.0,80.0);
+        let size   = Vector2(60.0,28.0);
+        assert_eq!(slot_below(&source,size,&[source],10.0,20.0),Vector2(100.0,-48.0));
+    }
+
+    #[test]
+    fn resolving_occupied_slots() {
+        let source    = node(100.0,0.0,80.0);
+        let size      = Vector2(60.0,28.0);
+        let first     = node(100.0,-48.0,50.0);
+        let second    = node(165.0,-50.0,40.0);
+        let unrelated = node(100.0,-300.0,500.0);
+        let obstacles = [source,first,second,unrelated];
+        assert_eq!(slot_below(&source,size,&obstacles,10.0,20.0),Vector2(215.0,-48.0));
+    }
+}
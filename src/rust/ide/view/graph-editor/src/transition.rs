@@ -0,0 +1,217 @@
+//! The animated transition between the levels of the graph, played when a node is entered or
+//! exited. The transition consists of two halves. While covering, the camera zooms into the
+//! entered node (or out of the exited graph) and a veil in the background color fades in. Once the
+//! veil is opaque, the graph of the new level is displayed beneath it, and while revealing, the
+//! veil fades out and the camera flies back to where it started.
+
+use crate::prelude::*;
+
+use ensogl::data::color;
+use ensogl::display;
+use ensogl::display::Scene;
+use ensogl::display::shape::*;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The default duration of the whole transition, in milliseconds.
+pub const DEFAULT_DURATION_MS : f32 = 600.0;
+
+/// The factor by which the camera zoom changes while covering.
+pub const ZOOM_FACTOR : f32 = 4.0;
+
+
+
+// =============
+// === Phase ===
+// =============
+
+/// The direction of the transition.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+#[allow(missing_docs)]
+pub enum Direction { Enter, Exit }
+
+impl Default for Direction {
+    fn default() -> Self {
+        Self::Enter
+    }
+}
+
+/// The phase of the transition, the state of its state machine. See the module docs to learn
+/// more.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+#[allow(missing_docs)]
+pub enum Phase { Idle, Covering(Direction), Revealing(Direction) }
+
+impl Default for Phase {
+    fn default() -> Self {
+        Self::Idle
+    }
+}
+
+impl Phase {
+    /// Check whether the transition is being played.
+    pub fn is_running(self) -> bool {
+        self != Self::Idle
+    }
+
+    /// The opacity of the veil at the given progress of the current half of the transition.
+    pub fn veil_alpha(self, progress:f32) -> f32 {
+        match self {
+            Self::Idle         => 0.0,
+            Self::Covering(_)  => progress,
+            Self::Revealing(_) => 1.0 - progress,
+        }
+    }
+}
+
+/// The events driving the transition.
+#[derive(Clone,Copy,Debug)]
+pub enum Event {
+    /// The level of the graph has changed. Interrupts the ongoing transition, if any.
+    Start(Direction),
+    /// The current half of the transition has finished.
+    HalfFinished,
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Self::HalfFinished
+    }
+}
+
+/// The actions to be taken when the phase of the transition changes.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum Effect {
+    /// Start covering the scene: fade the veil in and zoom the camera.
+    Cover(Direction),
+    /// Start revealing the new level: fade the veil out and fly the camera back. The veil fully
+    /// covers the scene at this moment.
+    Reveal(Direction),
+    /// The transition has finished.
+    Finish,
+}
+
+impl Default for Effect {
+    fn default() -> Self {
+        Self::Finish
+    }
+}
+
+/// The transition function of the transition state machine, meant to be used with the `fsm` FRP
+/// node.
+pub fn step(phase:&Phase, event:&Event) -> (Phase,Option<Effect>) {
+    match (*phase,*event) {
+        (_,Event::Start(direction)) =>
+            (Phase::Covering(direction),Some(Effect::Cover(direction))),
+        (Phase::Covering(direction),Event::HalfFinished) =>
+            (Phase::Revealing(direction),Some(Effect::Reveal(direction))),
+        (Phase::Revealing(_),Event::HalfFinished) => (Phase::Idle,Some(Effect::Finish)),
+        (Phase::Idle,Event::HalfFinished)         => (Phase::Idle,None),
+    }
+}
+
+
+
+// ============
+// === Veil ===
+// ============
+
+/// The shape covering the whole screen during the transition.
+pub mod veil {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (color_rgba:Vector4<f32>) {
+            let width  = Var::<Pixels>::from("input_size.x");
+            let height = Var::<Pixels>::from("input_size.y");
+            let rect   = Rect((&width,&height));
+            rect.fill(color_rgba).into()
+        }
+    }
+}
+
+/// The veil covering the scene during the transition. It is placed in the scene space above the
+/// nodes, but below the panels like the breadcrumbs, so the breadcrumbs stay visible during the
+/// whole transition.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Veil {
+    display_object : display::object::Instance,
+    scene          : Scene,
+    view           : veil::View,
+}
+
+impl Veil {
+    /// Constructor.
+    pub fn new(logger:&Logger, scene:&Scene) -> Self {
+        let logger         = Logger::sub(logger,"TransitionVeil");
+        let display_object = display::object::Instance::new(&logger);
+        let scene          = scene.clone_ref();
+        let view           = veil::View::new(&logger);
+        scene.layers.above_nodes_text.add_exclusive(&view);
+        Self {display_object,scene,view}
+    }
+
+    /// Update the veil, so it covers the whole screen with the given color, multiplied by `alpha`.
+    /// The veil is hidden when it is fully transparent.
+    pub fn update(&self, color:color::Rgba, alpha:f32) {
+        if alpha <= 0.0 {
+            self.view.unset_parent();
+            return
+        }
+        let camera = self.scene.camera();
+        let screen = camera.screen();
+        let zoom   = camera.zoom().max(std::f32::EPSILON);
+        self.display_object.add_child(&self.view);
+        self.view.size.set(Vector2(screen.width,screen.height) / zoom);
+        self.view.set_position_xy(camera.position().xy());
+        let alpha = color.alpha * alpha.min(1.0);
+        self.view.color_rgba.set(Vector4(color.red,color.green,color.blue,alpha));
+    }
+}
+
+impl display::Object for Veil {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.display_object
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn playing_transition() {
+        let enter          = Direction::Enter;
+        let (phase,effect) = step(&Phase::Idle,&Event::Start(enter));
+        assert_eq!(phase,Phase::Covering(enter));
+        assert_eq!(effect,Some(Effect::Cover(enter)));
+        let (phase,effect) = step(&phase,&Event::HalfFinished);
+        assert_eq!(phase,Phase::Revealing(enter));
+        assert_eq!(effect,Some(Effect::Reveal(enter)));
+        let (phase,effect) = step(&phase,&Event::HalfFinished);
+        assert_eq!(phase,Phase::Idle);
+        assert_eq!(effect,Some(Effect::Finish));
+        assert_eq!(step(&phase,&Event::HalfFinished),(Phase::Idle,None));
+    }
+
+    #[test]
+    fn interrupting_transition() {
+        let (phase,_)      = step(&Phase::Idle,&Event::Start(Direction::Enter));
+        let (phase,_)      = step(&phase,&Event::HalfFinished);
+        let (phase,effect) = step(&phase,&Event::Start(Direction::Exit));
+        assert_eq!(phase,Phase::Covering(Direction::Exit));
+        assert_eq!(effect,Some(Effect::Cover(Direction::Exit)));
+        assert_eq!(phase.veil_alpha(0.25),0.25);
+        assert_eq!(Phase::Revealing(Direction::Exit).veil_alpha(0.25),0.75);
+    }
+}
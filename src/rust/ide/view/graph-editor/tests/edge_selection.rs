@@ -0,0 +1,83 @@
+//! Tests of the edge geometry used by the area selection of edges.
+
+use ensogl::prelude::*;
+
+use enso_frp as frp;
+use ide_view_graph_editor::GraphEditor;
+use ide_view_graph_editor::snapshot::DEFAULT_HEIGHT;
+use ide_view_graph_editor::snapshot::DEFAULT_WIDTH;
+use ide_view_graph_editor::snapshot::Harness;
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+
+
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+
+
+// =============
+// === Utils ===
+// =============
+
+fn distance_to_segment(point:Vector2, (start,end):(Vector2,Vector2)) -> f32 {
+    let delta  = end - start;
+    let length = delta.norm_squared();
+    let t      = if length == 0.0 { 0.0 } else { (point - start).dot(&delta) / length };
+    (start + delta * t.clamp(0.0,1.0) - point).norm()
+}
+
+fn distance_to_segments(point:Vector2, segments:&[(Vector2,Vector2)]) -> f32 {
+    let distances = segments.iter().map(|segment| distance_to_segment(point,*segment));
+    distances.fold(f32::INFINITY,f32::min)
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[wasm_bindgen_test(async)]
+async fn edge_segments_follow_the_drawn_line() {
+    let harness = Harness::new(DEFAULT_WIDTH,DEFAULT_HEIGHT).await;
+    let edge    = harness.edge();
+    edge.frp.source_width.emit(100.0);
+    edge.frp.source_height.emit(28.0);
+    edge.frp.target_attached.emit(true);
+    edge.frp.target_position.emit(Vector2(0.0,-80.0));
+    edge.frp.redraw.emit(());
+    harness.settle().await;
+    let segments = edge.segments();
+
+    // The edge goes straight down from the node center, far from the chord between the right end
+    // of the source node and the target.
+    assert!(distance_to_segments(Vector2(0.0,-50.0),&segments) < 1.0,"{:?}",segments);
+    assert!(distance_to_segments(Vector2(25.0,-40.0),&segments) > 10.0,"{:?}",segments);
+
+    edge.frp.target_position.emit(Vector2(120.0,-150.0));
+    edge.frp.redraw.emit(());
+    harness.settle().await;
+    let segments = edge.segments();
+    assert!(distance_to_segments(Vector2(120.0,-140.0),&segments) < 1.0,"{:?}",segments);
+    assert!(distance_to_segments(Vector2(0.0,-50.0),&segments) > 10.0,"{:?}",segments);
+}
+
+#[wasm_bindgen_test(async)]
+async fn edge_area_selection_mode_follows_the_inputs() {
+    let harness      = Harness::new(DEFAULT_WIDTH,DEFAULT_HEIGHT).await;
+    harness.app.views.register::<GraphEditor>();
+    let graph_editor = harness.app.new_view::<GraphEditor>();
+    let frp          = &graph_editor.frp;
+    let changes      = Rc::new(RefCell::new(Vec::new()));
+    frp::new_network! { network
+        eval frp.output.edge_area_select_enabled ([changes] (enabled)
+            changes.borrow_mut().push(*enabled));
+    }
+
+    frp.enable_edge_area_select.emit(());
+    frp.disable_edge_area_select.emit(());
+    frp.toggle_edge_area_select.emit(());
+    assert_eq!(*changes.borrow(),vec![true,false,true]);
+    assert!(frp.output.edge_area_select_enabled.value());
+}
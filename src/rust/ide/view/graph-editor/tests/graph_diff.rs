@@ -0,0 +1,89 @@
+//! Tests of applying the `GraphDiff` to the graph editor.
+
+use ensogl::prelude::*;
+
+use enso_frp as frp;
+use ensogl::application::Application;
+use ensogl::display::object::ObjectOps;
+use ensogl::system::web;
+use ensogl::system::web::NodeInserter;
+use ensogl_text as text;
+use ide_view_graph_editor::EdgeEndpoint;
+use ide_view_graph_editor::GraphEditor;
+use ide_view_graph_editor::component::node;
+use ide_view_graph_editor::diff::EndpointRef;
+use ide_view_graph_editor::diff::GraphDiff;
+use ide_view_graph_editor::diff::NodeChanges;
+use ide_view_graph_editor::diff::NodeRef;
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+
+
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[wasm_bindgen_test(async)]
+async fn diff_is_applied_without_reporting_the_changes() {
+    ensogl_text_msdf_sys::initialized().await;
+    let root = web::create_div();
+    web::body().append_or_panic(&root);
+    let app = Application::new(&root);
+    app.views.register::<text::Area>();
+    app.views.register::<GraphEditor>();
+    let graph_editor = app.new_view::<GraphEditor>();
+    app.display.add_child(&graph_editor);
+    let out      = &graph_editor.frp.output;
+    let removed  = graph_editor.add_node();
+    let updated  = graph_editor.add_node();
+    let reported = Rc::new(Cell::new(0));
+    let synced   = Rc::new(Cell::new(0));
+    // Count the outputs the controller would take for the user actions.
+    frp::new_network! { network
+        changed <- any_(out.node_removed,out.node_position_set_batched,out.node_expression_set);
+        changed <- any_(changed,out.on_edge_endpoints_set);
+        reported_change <- changed.gate_not(&out.synchronizing);
+        eval_ reported_change ([reported] reported.set(reported.get() + 1));
+        eval_ out.graph_synchronized ([synced] synced.set(synced.get() + 1));
+    }
+
+    let expression = node::Expression::new_plain("foo");
+    let position   = Vector2(100.0,50.0);
+    let new_node   = NodeChanges {expression:Some(expression),..default()};
+    let changes    = NodeChanges {position:Some(position),..default()};
+    let source     = EndpointRef::new(NodeRef::Existing(updated),default());
+    let target     = EndpointRef::new(NodeRef::Added(0),default());
+    let diff       = GraphDiff {
+        add_nodes    : vec![new_node],
+        update_nodes : vec![(updated,changes)],
+        remove_nodes : vec![removed],
+        add_edges    : vec![(source,target)],
+        ..default()
+    };
+    let summary = graph_editor.apply_graph_diff(diff);
+
+    assert_eq!(reported.get(),0,"The applied changes were reported as the user actions.");
+    assert_eq!(synced.get(),1);
+    assert!(!out.synchronizing.value());
+    assert_eq!(summary.removed_nodes,vec![removed]);
+    assert_eq!(summary.updated_nodes,vec![updated]);
+    let added = summary.added_nodes[0];
+    assert_eq!(graph_editor.model.node_position(updated),position);
+    let connection = (EdgeEndpoint::new(updated,default()),EdgeEndpoint::new(added,default()));
+    assert_eq!(graph_editor.model.connections(),vec![connection]);
+    assert_eq!(summary.added_edges.len(),1);
+
+    let repeated = GraphDiff {
+        add_edges : vec![(EndpointRef::new(NodeRef::Existing(updated),default()),
+                          EndpointRef::new(NodeRef::Existing(added),default()))],
+        ..default()
+    };
+    let summary_of_repeated = graph_editor.apply_graph_diff(repeated);
+    assert_eq!(summary_of_repeated.added_edges,summary.added_edges,"The edge was not reused.");
+    graph_editor.unset_parent();
+}
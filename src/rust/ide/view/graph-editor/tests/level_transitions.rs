@@ -0,0 +1,51 @@
+//! Tests of the outputs reporting the transitions between the graph levels.
+
+use ensogl::prelude::*;
+
+use enso_frp as frp;
+use ensogl::application::Application;
+use ensogl::display::object::ObjectOps;
+use ensogl::system::web;
+use ensogl::system::web::NodeInserter;
+use ensogl_text as text;
+use ide_view_graph_editor::GraphEditor;
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+
+
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[wasm_bindgen_test(async)]
+async fn disabled_transition_is_covered_and_finished_at_once() {
+    ensogl_text_msdf_sys::initialized().await;
+    let root = web::create_div();
+    web::body().append_or_panic(&root);
+    let app = Application::new(&root);
+    app.views.register::<text::Area>();
+    app.views.register::<GraphEditor>();
+    let graph_editor = app.new_view::<GraphEditor>();
+    app.display.add_child(&graph_editor);
+    let out    = &graph_editor.frp.output;
+    let events = Rc::new(RefCell::new(Vec::new()));
+    frp::new_network! { network
+        eval_ out.level_transition_covered  ([events] events.borrow_mut().push("covered"));
+        eval_ out.level_transition_finished ([events] events.borrow_mut().push("finished"));
+    }
+
+    graph_editor.frp.disable_level_transitions.emit(());
+    let node_id = graph_editor.add_node();
+    graph_editor.frp.select_node.emit(node_id);
+    graph_editor.frp.enter_selected_node.emit(());
+    assert_eq!(*events.borrow(),vec!["covered","finished"]);
+    graph_editor.frp.exit_node.emit(());
+    assert_eq!(*events.borrow(),vec!["covered","finished","covered","finished"]);
+    assert!(!out.level_transition_running.value());
+    graph_editor.unset_parent();
+}
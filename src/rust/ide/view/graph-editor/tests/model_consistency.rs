@@ -0,0 +1,215 @@
+//! Property-based tests of the `GraphEditor` model consistency. Randomized sequences of FRP inputs
+//! are fed into a graph editor and the model invariants are checked after every step.
+
+use ensogl::prelude::*;
+
+use ensogl::application::Application;
+use ensogl::display::object::ObjectOps;
+use ensogl::system::web;
+use ensogl::system::web::NodeInserter;
+use ensogl_text as text;
+use ide_view_graph_editor::EdgeEndpoint;
+use ide_view_graph_editor::GraphEditor;
+use ide_view_graph_editor::GraphEditorModel;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::RngAlgorithm;
+use proptest::test_runner::TestCaseError;
+use proptest::test_runner::TestRng;
+use proptest::test_runner::TestRunner;
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+
+
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// Number of random input sequences checked by the test.
+const CASES : u32 = 64;
+/// Maximum length of a single input sequence.
+const MAX_ACTIONS : usize = 40;
+
+
+
+// ==============
+// === Action ===
+// ==============
+
+/// A single FRP input passed to the graph editor. Nodes and edges are referred by indices into the
+/// sorted lists of existing ones, taken modulo their count, so every generated sequence is valid.
+#[derive(Clone,Debug)]
+enum Action {
+    AddNode,
+    RemoveNode(usize),
+    Connect(usize,usize),
+    RemoveEdge(usize),
+    DetachEdgeTarget(usize),
+    DetachEdgeSource(usize),
+    MoveNode(usize,f32,f32),
+    SelectNode(usize),
+    SetSelection(Vec<usize>),
+    DeselectAll,
+    EnterNode(usize),
+    ExitNode,
+}
+
+fn action() -> impl Strategy<Value=Action> {
+    prop_oneof![
+        3 => Just(Action::AddNode),
+        1 => any::<usize>().prop_map(Action::RemoveNode),
+        3 => (any::<usize>(),any::<usize>()).prop_map(|(s,t)| Action::Connect(s,t)),
+        1 => any::<usize>().prop_map(Action::RemoveEdge),
+        1 => any::<usize>().prop_map(Action::DetachEdgeTarget),
+        1 => any::<usize>().prop_map(Action::DetachEdgeSource),
+        2 => (any::<usize>(),-500.0..500.0_f32,-500.0..500.0_f32)
+            .prop_map(|(n,x,y)| Action::MoveNode(n,x,y)),
+        1 => any::<usize>().prop_map(Action::SelectNode),
+        1 => vec(any::<usize>(),0..4).prop_map(Action::SetSelection),
+        1 => Just(Action::DeselectAll),
+        1 => any::<usize>().prop_map(Action::EnterNode),
+        1 => Just(Action::ExitNode),
+    ]
+}
+
+fn nth<T:Copy>(items:&[T], index:usize) -> Option<T> {
+    if items.is_empty() { None } else { Some(items[index % items.len()]) }
+}
+
+impl Action {
+    fn run(&self, graph_editor:&GraphEditor) {
+        let frp   = &graph_editor.frp;
+        let model = &graph_editor.model;
+        let mut nodes = model.nodes.keys();
+        let mut edges = model.edges.keys();
+        nodes.sort();
+        edges.sort_by_key(|id| id.0);
+        match *self {
+            Action::AddNode => { graph_editor.add_node(); }
+            Action::RemoveNode(n) => if let Some(node) = nth(&nodes,n) {
+                frp.remove_node.emit(node)
+            }
+            Action::Connect(s,t) => if let (Some(s),Some(t)) = (nth(&nodes,s),nth(&nodes,t)) {
+                let source = EdgeEndpoint::new(s,default());
+                let target = EdgeEndpoint::new(t,default());
+                frp.connect_nodes.emit((source,target))
+            }
+            Action::RemoveEdge(e) => if let Some(edge) = nth(&edges,e) {
+                frp.remove_edge.emit(edge)
+            }
+            Action::DetachEdgeTarget(e) => if let Some(edge) = nth(&edges,e) {
+                frp.unset_edge_target.emit(edge)
+            }
+            Action::DetachEdgeSource(e) => if let Some(edge) = nth(&edges,e) {
+                frp.unset_edge_source.emit(edge)
+            }
+            Action::MoveNode(n,x,y) => if let Some(node) = nth(&nodes,n) {
+                frp.set_node_position.emit((node,Vector2(x,y)))
+            }
+            Action::SelectNode(n) => if let Some(node) = nth(&nodes,n) {
+                frp.select_node.emit(node)
+            }
+            Action::SetSelection(ns) => {
+                let selection = ns.iter().filter_map(|n| nth(&nodes,*n)).collect_vec();
+                frp.set_selection.emit(selection)
+            }
+            Action::DeselectAll => frp.deselect_all_nodes.emit(()),
+            Action::EnterNode(n) => if let Some(node) = nth(&nodes,n) {
+                frp.select_node.emit(node);
+                frp.enter_selected_node.emit(());
+            }
+            Action::ExitNode => frp.exit_node.emit(()),
+        }
+    }
+}
+
+
+
+// ==================
+// === Invariants ===
+// ==================
+
+fn check_invariants(model:&GraphEditorModel) -> Result<(),TestCaseError> {
+    let nodes = model.nodes.keys();
+    let edges = model.edges.keys();
+
+    for edge_id in &edges {
+        let edge = model.edges.get_cloned_ref(edge_id).unwrap();
+        if let Some(source) = edge.source() {
+            prop_assert!(nodes.contains(&source.node_id),"{:?} has a removed source.",edge_id);
+            let node = model.nodes.get_cloned_ref(&source.node_id).unwrap();
+            prop_assert!(node.out_edges.contains(edge_id),"{:?} not in out edges.",edge_id);
+        }
+        if let Some(target) = edge.target() {
+            prop_assert!(nodes.contains(&target.node_id),"{:?} has a removed target.",edge_id);
+            let node = model.nodes.get_cloned_ref(&target.node_id).unwrap();
+            prop_assert!(node.in_edges.contains(edge_id),"{:?} not in in edges.",edge_id);
+        }
+    }
+
+    for node_id in &nodes {
+        let node = model.nodes.get_cloned_ref(node_id).unwrap();
+        for edge_id in node.in_edges.keys() {
+            let target = model.edges.get_cloned_ref(&edge_id).and_then(|edge| edge.target());
+            prop_assert_eq!(target.map(|t| t.node_id),Some(*node_id));
+        }
+        for edge_id in node.out_edges.keys() {
+            let source = model.edges.get_cloned_ref(&edge_id).and_then(|edge| edge.source());
+            prop_assert_eq!(source.map(|t| t.node_id),Some(*node_id));
+        }
+    }
+
+    for edge_id in model.edges.detached_target.keys() {
+        let edge = model.edges.get_cloned_ref(&edge_id);
+        prop_assert!(edge.map_or(false,|edge| !edge.has_target()),"{:?} not detached.",edge_id);
+    }
+    for edge_id in model.edges.detached_source.keys() {
+        let edge = model.edges.get_cloned_ref(&edge_id);
+        prop_assert!(edge.map_or(false,|edge| !edge.has_source()),"{:?} not detached.",edge_id);
+    }
+
+    let selected = model.nodes.selected.items();
+    for node_id in &selected {
+        prop_assert!(nodes.contains(node_id),"Removed {:?} is selected.",node_id);
+    }
+    prop_assert_eq!(selected.iter().unique().count(),selected.len(),"Duplicated selection.");
+    Ok(())
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[wasm_bindgen_test(async)]
+async fn model_stays_consistent() {
+    ensogl_text_msdf_sys::initialized().await;
+    let root = web::create_div();
+    web::body().append_or_panic(&root);
+    let app = Application::new(&root);
+    app.views.register::<text::Area>();
+    app.views.register::<GraphEditor>();
+
+    let config = ProptestConfig {cases:CASES,failure_persistence:None,..ProptestConfig::default()};
+    let rng        = TestRng::deterministic_rng(RngAlgorithm::ChaCha);
+    let mut runner = TestRunner::new_with_rng(config,rng);
+    let result     = runner.run(&vec(action(),0..MAX_ACTIONS),|actions| {
+        let graph_editor = app.new_view::<GraphEditor>();
+        app.display.add_child(&graph_editor);
+        for action in &actions {
+            action.run(&graph_editor);
+            check_invariants(&graph_editor.model)?;
+        }
+        graph_editor.unset_parent();
+        Ok(())
+    });
+    if let Err(err) = result {
+        panic!("{}",err)
+    }
+}
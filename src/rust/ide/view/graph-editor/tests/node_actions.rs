@@ -0,0 +1,49 @@
+//! Tests of the actions available in the node action bar.
+
+use ensogl::prelude::*;
+
+use enso_frp as frp;
+use ide_view_graph_editor::snapshot::DEFAULT_HEIGHT;
+use ide_view_graph_editor::snapshot::DEFAULT_WIDTH;
+use ide_view_graph_editor::snapshot::Harness;
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+
+
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[wasm_bindgen_test(async)]
+async fn recompute_click_makes_one_request() {
+    let harness  = Harness::new(DEFAULT_WIDTH,DEFAULT_HEIGHT).await;
+    let node     = harness.node();
+    let icon     = node.model.action_bar.recompute_icon_events();
+    let requests = Rc::new(Cell::new(0));
+    // Mark the recomputation pending on request, like the graph editor does.
+    frp::new_network! { network
+        eval_ node.frp.recompute ([node,requests] {
+            requests.set(requests.get() + 1);
+            node.frp.set_recompute_pending.emit(true);
+        });
+    }
+
+    icon.mouse_down.emit(());
+    icon.mouse_up.emit(());
+    assert_eq!(requests.get(),1);
+
+    icon.mouse_down.emit(());
+    icon.mouse_up.emit(());
+    assert_eq!(requests.get(),1,"The press while the recomputation is pending was reported.");
+
+    node.frp.set_recompute_pending.emit(false);
+    assert_eq!(requests.get(),1);
+    icon.mouse_down.emit(());
+    icon.mouse_up.emit(());
+    assert_eq!(requests.get(),2);
+}
@@ -0,0 +1,104 @@
+//! Tests of recording the graph editor session and replaying it on another graph editor.
+
+use ensogl::prelude::*;
+
+use ensogl::application::Application;
+use ensogl::display::object::ObjectOps;
+use ensogl::system::web;
+use ensogl::system::web::NodeInserter;
+use ensogl_text as text;
+use ide_view_graph_editor::EdgeEndpoint;
+use ide_view_graph_editor::GraphEditor;
+use ide_view_graph_editor::IdAllocation;
+use ide_view_graph_editor::NodeId;
+use ide_view_graph_editor::component::node;
+use ide_view_graph_editor::session;
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+
+
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+
+
+// ===============
+// === Helpers ===
+// ===============
+
+/// The state of the graph compared between the recorded and the replayed graph editors.
+#[derive(Debug,PartialEq)]
+struct GraphState {
+    nodes    : Vec<(String,Vector2<f32>)>,
+    edges    : Vec<(Option<EdgeEndpoint>,Option<EdgeEndpoint>)>,
+    selected : Vec<String>,
+}
+
+impl GraphState {
+    fn new(graph_editor:&GraphEditor) -> Self {
+        let model     = &graph_editor.model;
+        let mut nodes = model.nodes.keys();
+        let mut edges = model.edges.keys();
+        nodes.sort();
+        edges.sort_by_key(|id| id.0);
+        let position = |id| (format!("{:?}",id),model.node_position(id));
+        let nodes    = nodes.into_iter().map(position).collect();
+        let edge     = |id| model.edges.get_cloned_ref(&id).map(|e| (e.source(),e.target()));
+        let edges    = edges.into_iter().filter_map(edge).collect();
+        let mut selected = model.nodes.selected.items();
+        selected.sort();
+        let selected = selected.iter().map(|id| format!("{:?}",id)).collect();
+        Self {nodes,edges,selected}
+    }
+}
+
+fn endpoint(node_id:NodeId) -> EdgeEndpoint {
+    EdgeEndpoint::new(node_id,default())
+}
+
+fn new_graph_editor(app:&Application) -> GraphEditor {
+    let graph_editor = GraphEditor::new_with_id_allocation(app,IdAllocation::Deterministic);
+    app.display.add_child(&graph_editor);
+    graph_editor
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[wasm_bindgen_test(async)]
+async fn replayed_session_reproduces_the_graph() {
+    ensogl_text_msdf_sys::initialized().await;
+    let root = web::create_div();
+    web::body().append_or_panic(&root);
+    let app = Application::new(&root);
+    app.views.register::<text::Area>();
+    app.views.register::<GraphEditor>();
+    let logger = Logger::new("SessionReplayTest");
+
+    let recorded = new_graph_editor(&app);
+    let recorder = session::Recorder::new(&logger,&recorded);
+    let frp      = &recorded.frp;
+    let node_1   = recorded.add_node();
+    let node_2   = recorded.add_node();
+    let node_3   = recorded.add_node();
+    frp.set_node_position.emit((node_2,Vector2(200.0,0.0)));
+    frp.set_node_expression.emit((node_3,node::Expression::new_plain("foo bar")));
+    frp.connect_nodes.emit((endpoint(node_1),endpoint(node_2)));
+    frp.connect_nodes.emit((endpoint(node_2),endpoint(node_3)));
+    frp.select_node.emit(node_2);
+    frp.set_visualization.emit((node_1,None));
+    frp.remove_node.emit(node_1);
+    let script = recorder.script();
+    const EMITTED_EVENTS : usize = 10;
+    assert_eq!(script.events.len(),EMITTED_EVENTS,"Nested or missing events: {:?}",script.events);
+
+    let replayed = new_graph_editor(&app);
+    let script   = session::Script::from_json(&script.to_json()).unwrap();
+    session::replay_immediately(&replayed.frp.input,&script).unwrap();
+    assert_eq!(GraphState::new(&replayed),GraphState::new(&recorded));
+    recorded.unset_parent();
+    replayed.unset_parent();
+}
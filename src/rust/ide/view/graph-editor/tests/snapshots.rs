@@ -0,0 +1,139 @@
+//! Snapshot tests of the graph editor components. The components are rendered off-screen by the
+//! `snapshot::Harness` and the rendered images are compared with the golden ones from the `golden`
+//! directory, and with each other, checking that every visual state is rendered deterministically
+//! and differs from the other states.
+//!
+//! See the `ide_view_graph_editor::snapshot` module docs for the instructions of recording the
+//! golden images. These tests need the `snapshots` feature.
+
+use ensogl::prelude::*;
+
+use ensogl::display::render::passes::Snapshot;
+use ide_view_graph_editor::component::node::Expression;
+use ide_view_graph_editor::snapshot::DEFAULT_HEIGHT;
+use ide_view_graph_editor::snapshot::DEFAULT_WIDTH;
+use ide_view_graph_editor::snapshot::Harness;
+use wasm_bindgen_test::wasm_bindgen_test;
+use wasm_bindgen_test::wasm_bindgen_test_configure;
+
+
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+
+
+// =====================
+// === Golden Images ===
+// =====================
+
+/// The scene with no components, cleared to the transparent black.
+const EMPTY_SCENE : &[u8] = include_bytes!("golden/empty_scene.pam");
+
+// The goldens of the components are rendered by the browser, so they are recorded as described in
+// the `snapshot` module docs, and included as `Some(include_bytes!("golden/<name>.pam"))`. Until
+// then, the tests check only that the components render deterministically.
+
+/// The node with the `foo + 2` expression.
+const NODE_DEFAULT : Option<&[u8]> = None;
+/// The selected node with the `foo + 2` expression.
+const NODE_SELECTED : Option<&[u8]> = None;
+/// The edge bent towards the target on the bottom right.
+const EDGE_BENT : Option<&[u8]> = None;
+/// The straight edge with the target right below the source.
+const EDGE_STRAIGHT : Option<&[u8]> = None;
+/// The breadcrumbs with the `Project` name.
+const BREADCRUMBS_PROJECT : Option<&[u8]> = None;
+
+
+
+// =============
+// === Utils ===
+// =============
+
+fn assert_same(name:&str, snapshot:&Snapshot, other:&Snapshot) {
+    let comparison = snapshot.compare(other,default());
+    assert!(comparison.matches(),"{}: the images differ: {:?}.",name,comparison);
+}
+
+fn assert_different(name:&str, snapshot:&Snapshot, other:&Snapshot) {
+    let comparison = snapshot.compare(other,default());
+    assert!(!comparison.matches(),"{}: the images do not differ: {:?}.",name,comparison);
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[wasm_bindgen_test(async)]
+async fn empty_scene() {
+    let harness = Harness::new(DEFAULT_WIDTH,DEFAULT_HEIGHT).await;
+    harness.settle().await;
+    harness.assert_matches_golden("empty_scene",&harness.snapshot(),EMPTY_SCENE);
+}
+
+#[wasm_bindgen_test(async)]
+async fn node_states() {
+    let harness = Harness::new(DEFAULT_WIDTH,DEFAULT_HEIGHT).await;
+    let empty   = harness.snapshot();
+    let node    = harness.node();
+    node.frp.set_expression.emit(Expression::new_plain("foo + 2"));
+    harness.settle().await;
+    let default = harness.snapshot_of(&node);
+    harness.assert_matches_recorded_golden("node_default",&default,NODE_DEFAULT);
+    assert_same("node rendering",&default,&harness.snapshot_of(&node));
+    assert_different("node visibility",&default,&empty);
+
+    node.frp.select.emit(());
+    harness.settle().await;
+    let selected = harness.snapshot_of(&node);
+    harness.assert_matches_recorded_golden("node_selected",&selected,NODE_SELECTED);
+    assert_different("node selection",&selected,&default);
+    node.frp.deselect.emit(());
+    harness.settle().await;
+    assert_same("node deselection",&harness.snapshot_of(&node),&default);
+
+    node.frp.set_frozen.emit(true);
+    harness.settle().await;
+    assert_different("frozen node",&harness.snapshot_of(&node),&default);
+}
+
+#[wasm_bindgen_test(async)]
+async fn edge_shapes() {
+    let harness = Harness::new(DEFAULT_WIDTH,DEFAULT_HEIGHT).await;
+    let empty   = harness.snapshot();
+    let edge    = harness.edge();
+    edge.frp.source_width.emit(100.0);
+    edge.frp.source_height.emit(28.0);
+    edge.frp.target_attached.emit(true);
+    edge.frp.target_position.emit(Vector2(60.0,-80.0));
+    edge.frp.redraw.emit(());
+    harness.settle().await;
+    let bent = harness.snapshot_of(&edge);
+    harness.assert_matches_recorded_golden("edge_bent",&bent,EDGE_BENT);
+    assert_same("edge rendering",&bent,&harness.snapshot_of(&edge));
+    assert_different("edge visibility",&bent,&empty);
+
+    edge.frp.target_position.emit(Vector2(0.0,-80.0));
+    edge.frp.redraw.emit(());
+    harness.settle().await;
+    let straight = harness.snapshot_of(&edge);
+    harness.assert_matches_recorded_golden("edge_straight",&straight,EDGE_STRAIGHT);
+    assert_different("straight edge",&straight,&bent);
+}
+
+#[wasm_bindgen_test(async)]
+async fn breadcrumbs_project_name() {
+    let harness     = Harness::new(DEFAULT_WIDTH,DEFAULT_HEIGHT).await;
+    let breadcrumbs = harness.breadcrumbs();
+    breadcrumbs.project_name.emit("Project".to_string());
+    harness.settle().await;
+    let short = harness.snapshot_of(&breadcrumbs);
+    harness.assert_matches_recorded_golden("breadcrumbs_project",&short,BREADCRUMBS_PROJECT);
+    assert_same("breadcrumbs rendering",&short,&harness.snapshot_of(&breadcrumbs));
+
+    breadcrumbs.project_name.emit("Project With A Long Name".to_string());
+    harness.settle().await;
+    assert_different("renamed project",&harness.snapshot_of(&breadcrumbs),&short);
+}
@@ -0,0 +1,326 @@
+//! The command palette, listing all the commands of the living view components with their docs.
+//! The commands are discovered at runtime through the application command registry, filtered with
+//! the fuzzy search as the user types, and the chosen one is evaluated in its view, the same way
+//! a shortcut does.
+
+use crate::prelude::*;
+
+use enso_frp as frp;
+use ensogl::application;
+use ensogl::application::Application;
+use ensogl::application::command::CommandInfo;
+use ensogl::application::shortcut;
+use ensogl::display;
+use ensogl::display::shape::*;
+use ensogl_gui_components::list_view;
+use ensogl_text as text;
+use ensogl_theme::application::command_palette as theme;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The commands defined by `define_endpoints!` for every view, which are not worth listing.
+const HIDDEN_COMMANDS : &[&str] = &["focus","defocus"];
+
+
+
+// =============
+// === Entry ===
+// =============
+
+/// The entry in the command list, with the characters matching the query highlighted.
+pub type Entry = list_view::entry::GlyphHighlightedLabel;
+
+fn entry_model(found:&fuzzly::Match<CommandInfo>) -> list_view::entry::GlyphHighlightedLabelModel {
+    let label       = found.item.label.clone();
+    let spans       = found.subsequence.highlight_spans(&label).into_iter();
+    let highlighted = spans.map(|span| {
+        let start = text::Bytes(span.start as i32);
+        let end   = text::Bytes(span.end as i32);
+        text::Range::new(start,end)
+    }).collect();
+    list_view::entry::GlyphHighlightedLabelModel {label,highlighted}
+}
+
+
+
+// ==============
+// === Shapes ===
+// ==============
+
+mod background {
+    use super::*;
+
+    pub const CORNER_RADIUS_PX : f32 = 8.0;
+
+    ensogl::define_shape_system! {
+        (style:Style) {
+            let width  = Var::<Pixels>::from("input_size.x");
+            let height = Var::<Pixels>::from("input_size.y");
+            let shape  = Rect((&width,&height)).corners_radius(CORNER_RADIUS_PX.px());
+            shape.fill(style.get_color(theme::background)).into()
+        }
+    }
+}
+
+
+
+// ===========
+// === Frp ===
+// ===========
+
+ensogl::define_endpoints! {
+    Input {
+        /// Show the palette with the empty query.
+        show(),
+        /// Hide the palette.
+        hide(),
+        /// Toggle the palette visibility.
+        toggle(),
+    }
+
+    Output {
+        is_visible       (bool),
+        /// The view label and the name of the last command evaluated from the palette.
+        executed_command (String,String),
+    }
+}
+
+
+
+// =============
+// === Model ===
+// =============
+
+#[derive(Clone,CloneRef,Debug)]
+struct Model {
+    app            : Application,
+    logger         : Logger,
+    display_object : display::object::Instance,
+    panel          : display::object::Instance,
+    background     : background::View,
+    query          : text::Area,
+    list           : list_view::ListView<Entry>,
+    doc            : text::Area,
+    commands       : Rc<RefCell<Vec<CommandInfo>>>,
+    matches        : Rc<RefCell<Vec<fuzzly::Match<CommandInfo>>>>,
+}
+
+impl Model {
+    fn new(app:&Application) -> Self {
+        let app            = app.clone_ref();
+        let scene          = app.display.scene();
+        let logger         = Logger::new("CommandPalette");
+        let display_object = display::object::Instance::new(&logger);
+        let panel          = display::object::Instance::new(&logger);
+        let background     = background::View::new(&logger);
+        let query          = app.new_view::<text::Area>();
+        let list           = app.new_view::<list_view::ListView<Entry>>();
+        let doc            = app.new_view::<text::Area>();
+        let commands       = default();
+        let matches        = default();
+        panel.add_child(&background);
+        panel.add_child(&query);
+        panel.add_child(&list);
+        panel.add_child(&doc);
+        scene.layers.panel.add_exclusive(&panel);
+        list.set_label_layer(scene.layers.panel_text.id());
+        for area in &[&query,&doc] {
+            area.remove_from_scene_layer(&scene.layers.main);
+            area.add_to_scene_layer(&scene.layers.panel_text);
+        }
+        query.single_line(true);
+
+        ensogl::shapes_order_dependencies! {
+            scene => {
+                background            -> list_view::selection;
+                list_view::background -> background;
+            }
+        }
+
+        Self {app,logger,display_object,panel,background,query,list,doc,commands,matches}
+    }
+
+    fn set_visible(&self, visible:bool) {
+        if visible {
+            self.display_object.add_child(&self.panel);
+            self.reload();
+            self.query.set_content("");
+            self.query.set_focus(true);
+            self.set_query("");
+        } else {
+            self.query.set_focus(false);
+            self.panel.unset_parent();
+        }
+    }
+
+    fn set_layout
+    (&self, width:f32, list_height:f32, doc_height:f32, padding:f32, scene_height:f32) {
+        let query_height = text::component::area::LINE_HEIGHT;
+        let height       = query_height + list_height + doc_height + 4.0 * padding;
+        let top          = height / 2.0;
+        let left         = -width / 2.0 + padding;
+        let list_y       = top - 2.0 * padding - query_height - list_height / 2.0;
+        self.background.size.set(Vector2(width,height));
+        self.query.set_position_xy(Vector2(left,top - padding));
+        self.list.resize(Vector2(width - 2.0 * padding,list_height));
+        self.list.set_position_y(list_y);
+        self.doc.set_position_xy(Vector2(left,list_y - list_height / 2.0 - padding));
+        self.panel.set_position_y(scene_height / 2.0 - padding - height / 2.0);
+    }
+
+    /// Read the available commands from the registry.
+    fn reload(&self) {
+        let commands = self.app.commands.commands().into_iter().filter(|command| {
+            let own    = command.view == <View as application::View>::label();
+            let hidden = HIDDEN_COMMANDS.contains(&command.name.as_str());
+            !own && !hidden
+        });
+        *self.commands.borrow_mut() = commands.collect();
+    }
+
+    fn set_query(&self, query:&str) {
+        let commands = self.commands.borrow().clone();
+        let metric   = fuzzly::metric::default();
+        let matches  = fuzzly::search_by(commands,query,metric,|command| command.label.as_str());
+        let entries  = matches.iter().map(entry_model).collect_vec();
+        let is_empty = entries.is_empty();
+        *self.matches.borrow_mut() = matches;
+        self.list.set_entries(list_view::entry::AnyModelProvider::new(entries));
+        if !is_empty {
+            self.list.select_entry(0);
+        }
+    }
+
+    fn command(&self, entry:list_view::entry::Id) -> Option<CommandInfo> {
+        self.matches.borrow().get(entry).map(|found| found.item.clone())
+    }
+
+    fn show_doc(&self, entry:Option<list_view::entry::Id>) {
+        let doc = entry.and_then(|entry| self.command(entry)).map(|command| command.doc);
+        self.doc.set_content(doc.unwrap_or_default());
+    }
+
+    fn execute(&self, command:&CommandInfo) {
+        let view = &command.view;
+        let name = &command.name;
+        debug!(self.logger, "Executing {name} in {view}.");
+        self.app.commands.execute(&command.view,&command.name);
+    }
+}
+
+
+
+// ============
+// === View ===
+// ============
+
+/// The command palette. See the module docs to learn more.
+#[derive(Clone,CloneRef,Debug)]
+pub struct View {
+    model : Model,
+    frp   : Frp,
+}
+
+impl Deref for View {
+    type Target = Frp;
+    fn deref(&self) -> &Self::Target {
+        &self.frp
+    }
+}
+
+impl View {
+    /// Constructor.
+    pub fn new(app:&Application) -> Self {
+        let model       = Model::new(app);
+        let frp         = Frp::new();
+        let network     = &frp.network;
+        let scene       = app.display.scene();
+        let scene_shape = scene.shape();
+        let style       = StyleWatchFrp::new(&scene.style_sheet);
+        let list        = &model.list;
+
+        frp::extend! { network
+
+            // === Visibility ===
+
+            let is_visible     =  frp.output.is_visible.clone_ref();
+            chosen_entry      <- list.chosen_entry.filter_map(|entry| *entry).gate(&is_visible);
+            chosen            <- chosen_entry.filter_map(f!((entry) model.command(*entry)));
+            show_after_toggle <- frp.toggle.gate_not(&is_visible);
+            hide_after_toggle <- frp.toggle.gate(&is_visible);
+            show              <- any(frp.input.show,show_after_toggle);
+            hide_after_choice <- chosen.constant(());
+            hide              <- any(frp.input.hide,hide_after_toggle,hide_after_choice);
+            frp.source.is_visible <+ bool(&hide,&show);
+            eval frp.output.is_visible ((visible) model.set_visible(*visible));
+
+
+            // === Searching ===
+
+            query <- model.query.content.map(|content| content.to_string());
+            query <- query.gate(&is_visible);
+            eval query ((query) model.set_query(query));
+            eval list.selected_entry ((entry) model.show_doc(*entry));
+
+
+            // === Execution ===
+
+            eval chosen ((command) model.execute(command));
+            frp.source.executed_command <+ chosen.map(|c| (c.view.clone(),c.name.clone()));
+
+
+            // === Layout ===
+
+            init <- source::<()>();
+            let width       = style.get_number(theme::width);
+            let list_height = style.get_number(theme::list_height);
+            let doc_height  = style.get_number(theme::doc_height);
+            let padding     = style.get_number(theme::padding);
+            let text_color  = style.get_color(theme::text);
+            _layout <- all_with6(&width,&list_height,&doc_height,&padding,scene_shape,&init,
+                f!((width,list_height,doc_height,padding,shape,_)
+                    model.set_layout(*width,*list_height,*doc_height,*padding,shape.height)
+                )
+            );
+            text_color <- all(&text_color,&init)._0();
+            eval text_color ([model](color) {
+                model.query.set_default_color(color);
+                model.doc.set_default_color(color);
+            });
+        }
+        init.emit(());
+
+        Self {model,frp}
+    }
+}
+
+impl display::Object for View {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.model.display_object
+    }
+}
+
+impl application::command::FrpNetworkProvider for View {
+    fn network(&self) -> &frp::Network { &self.frp.network }
+}
+
+impl application::View for View {
+    fn label() -> &'static str { "CommandPalette" }
+
+    fn new(app:&Application) -> Self { Self::new(app) }
+
+    fn app(&self) -> &Application {
+        &self.model.app
+    }
+
+    fn default_shortcuts() -> Vec<shortcut::Shortcut> {
+        use shortcut::ActionType::*;
+        (&[ (Press , ""           , "cmd shift p" , "toggle")
+          , (Press , "is_visible" , "escape"      , "hide")
+          ]).iter().map(|(a,b,c,d)|Self::self_shortcut_when(*a,*c,*d,*b)).collect()
+    }
+}
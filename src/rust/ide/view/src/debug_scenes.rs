@@ -1,4 +1,5 @@
 //! A set of debug scenes
 
+pub mod graph_editor_benchmark;
 pub mod interface;
 pub mod visualization;
@@ -0,0 +1,192 @@
+//! A debug scene stress-testing the graph editor. It creates many nodes and edges using only the
+//! public FRP inputs of the `GraphEditor`, measures the latency of every operation and the frame
+//! times afterwards, and logs a machine-readable (JSON) report. It is meant to track performance
+//! regressions of the `new_graph_editor` FRP network.
+
+use crate::prelude::*;
+
+use crate::graph_editor;
+use crate::graph_editor::GraphEditor;
+use crate::debug_scenes::interface::expression_mock_string;
+
+use ensogl::application::Application;
+use ensogl::display::navigation::navigator::Navigator;
+use ensogl::system::web;
+use ensogl_text as text;
+use ensogl_text_msdf_sys::run_once_initialized;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// Number of nodes created by the benchmark.
+const NODE_COUNT : usize = 200;
+/// Number of edges created by the benchmark.
+const EDGE_COUNT : usize = 300;
+/// Number of frames measured after the graph was created.
+const MEASURED_FRAMES : usize = 300;
+/// Distance between the nodes laid out in a grid.
+const GRID_SPACING : f32 = 120.0;
+
+
+
+// ===============
+// === Samples ===
+// ===============
+
+/// Summary of a series of time measurements.
+#[derive(Clone,Copy,Debug,Default,Serialize)]
+struct Stats {
+    count    : usize,
+    total_ms : f64,
+    mean_ms  : f64,
+    max_ms   : f64,
+}
+
+/// A series of time measurements in milliseconds.
+#[derive(Clone,Debug,Default)]
+struct Samples {
+    values : Vec<f64>,
+}
+
+impl Samples {
+    fn push(&mut self, value:f64) {
+        self.values.push(value);
+    }
+
+    /// Run `f` and record the time it took. As the FRP network is evaluated synchronously, this is
+    /// the time the graph editor needed to process the event.
+    fn measure<T>(&mut self, f:impl FnOnce()->T) -> T {
+        let start  = web::performance().now();
+        let result = f();
+        self.push(web::performance().now() - start);
+        result
+    }
+
+    fn stats(&self) -> Stats {
+        let count    = self.values.len();
+        let total_ms = self.values.iter().sum::<f64>();
+        let mean_ms  = if count == 0 { 0.0 } else { total_ms / count as f64 };
+        let max_ms   = self.values.iter().cloned().fold(0.0,f64::max);
+        Stats {count,total_ms,mean_ms,max_ms}
+    }
+}
+
+
+
+// ==============
+// === Report ===
+// ==============
+
+/// The benchmark results.
+#[derive(Clone,Copy,Debug,Serialize)]
+struct Report {
+    node_count          : usize,
+    edge_count          : usize,
+    add_node            : Stats,
+    set_node_expression : Stats,
+    set_node_position   : Stats,
+    connect_nodes       : Stats,
+    frame_time          : Stats,
+}
+
+
+
+// ===================
+// === Entry Point ===
+// ===================
+
+#[wasm_bindgen]
+#[allow(dead_code)]
+#[allow(missing_docs)]
+pub fn entry_point_graph_editor_benchmark() {
+    web::forward_panic_hook_to_console();
+    web::set_stack_trace_limit();
+    run_once_initialized(|| {
+        let app = Application::new(&web::get_html_element_by_id("root").unwrap());
+        init(&app);
+        mem::forget(app);
+    });
+}
+
+fn init(app:&Application) {
+    let logger    = Logger::new("GraphEditorBenchmark");
+    let world     = &app.display;
+    let scene     = world.scene();
+    let camera    = scene.camera();
+    let navigator = Navigator::new(scene,&camera);
+
+    app.views.register::<text::Area>();
+    app.views.register::<GraphEditor>();
+    let graph_editor = app.new_view::<GraphEditor>();
+    world.add_child(&graph_editor);
+
+    let mut add_node            = Samples::default();
+    let mut set_node_expression = Samples::default();
+    let mut set_node_position   = Samples::default();
+    let mut connect_nodes       = Samples::default();
+
+
+    // === Nodes ===
+
+    let columns  = (NODE_COUNT as f32).sqrt().ceil() as usize;
+    let node_ids = (0..NODE_COUNT).map(|index| {
+        let node_id    = add_node.measure(|| graph_editor.add_node());
+        let expression = expression_mock_string(&format!("node {}",index));
+        let column     = (index % columns) as f32;
+        let row        = (index / columns) as f32;
+        let position   = Vector2(column * GRID_SPACING, -row * GRID_SPACING);
+        set_node_expression.measure(|| {
+            graph_editor.frp.set_node_expression.emit((node_id,expression))
+        });
+        set_node_position.measure(|| graph_editor.frp.set_node_position.emit((node_id,position)));
+        node_id
+    }).collect_vec();
+
+
+    // === Edges ===
+
+    if NODE_COUNT > 1 {
+        for index in 0..EDGE_COUNT {
+            let source = index % NODE_COUNT;
+            let target = (source + 1 + index / NODE_COUNT) % NODE_COUNT;
+            let target = if target == source { (target + 1) % NODE_COUNT } else { target };
+            let source = graph_editor::EdgeEndpoint::new(node_ids[source],default());
+            let target = graph_editor::EdgeEndpoint::new(node_ids[target],default());
+            connect_nodes.measure(|| graph_editor.frp.connect_nodes.emit((source,target)));
+        }
+    }
+
+
+    // === Frame Times ===
+
+    let mut frame_time = Samples::default();
+    let mut reported   = false;
+    world.on_frame(move |time| {
+        let _keep_alive = &navigator;
+        let _keep_alive = &graph_editor;
+        if !reported {
+            frame_time.push(time.frame as f64);
+            if frame_time.values.len() >= MEASURED_FRAMES {
+                reported = true;
+                let report = Report {
+                    node_count          : NODE_COUNT,
+                    edge_count          : EDGE_COUNT,
+                    add_node            : add_node.stats(),
+                    set_node_expression : set_node_expression.stats(),
+                    set_node_position   : set_node_position.stats(),
+                    connect_nodes       : connect_nodes.stats(),
+                    frame_time          : frame_time.stats(),
+                };
+                match serde_json::to_string(&report) {
+                    Ok(json) => info!(logger,"{json}"),
+                    Err(err) => error!(logger,"Cannot serialize the benchmark report: {err}"),
+                }
+            }
+        }
+    }).forget();
+}
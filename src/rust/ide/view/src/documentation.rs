@@ -15,7 +15,6 @@ use ensogl::display::shape::primitive::system::DynamicShape;
 use ensogl::display;
 use ensogl::system::web::AttributeSetter;
 use ensogl::system::web::StyleSetter;
-use ensogl::system::web::clipboard;
 use ensogl::system::web;
 use ensogl_gui_components::shadow;
 use wasm_bindgen::JsCast;
@@ -47,7 +46,8 @@ const COPY_BUTTON_CLASS : &str = "doc-copy-btn";
 // === Model ===
 // =============
 
-type CodeCopyClosure = Closure<dyn FnMut(MouseEvent)>;
+type CodeCopyClosure  = Closure<dyn FnMut(MouseEvent)>;
+type LinkClickClosure = Closure<dyn FnMut(MouseEvent)>;
 
 /// Model of Native visualization that generates documentation for given Enso code and embeds
 /// it in a HTML container.
@@ -62,12 +62,18 @@ pub struct Model {
     /// to EnsoGL shapes, and pass them to the DOM instead.
     overlay            : overlay::View,
     display_object     : display::object::Instance,
-    code_copy_closures : Rc<CloneCell<Vec<CodeCopyClosure>>>
+    code_copy_closures : Rc<CloneCell<Vec<CodeCopyClosure>>>,
+    /// Emits the code copied with the copy buttons, see the `code_copied` output.
+    code_copied        : frp::Source<String>,
+    link_click_closure : Rc<RefCell<Option<LinkClickClosure>>>,
+    /// The documentation set while the view was hidden or during the current frame, waiting to be
+    /// rendered. See `set_documentation`.
+    pending            : Rc<RefCell<Option<String>>>,
 }
 
 impl Model {
     /// Constructor.
-    fn new(scene:&Scene) -> Self {
+    fn new(scene:&Scene, code_copied:&frp::Source<String>) -> Self {
         let logger         = Logger::new("DocumentationView");
         let display_object = display::object::Instance::new(&logger);
         let outer_div      = web::create_div();
@@ -109,7 +115,11 @@ impl Model {
         scene.dom.layers.front.manage(&inner_dom);
 
         let code_copy_closures = default();
-        Model {logger,outer_dom,inner_dom,size,overlay,display_object,code_copy_closures}.init()
+        let code_copied        = code_copied.clone_ref();
+        let link_click_closure = default();
+        let pending            = default();
+        Model {logger,outer_dom,inner_dom,size,overlay,display_object,code_copy_closures
+              ,code_copied,link_click_closure,pending}.init()
     }
 
     fn init(self) -> Self {
@@ -146,9 +156,9 @@ impl Model {
             let create_closures = || -> Option<CodeCopyClosure> {
                 let copy_button = copy_buttons.get_with_index(i)?.dyn_into::<HtmlElement>().ok()?;
                 let code_block  = code_blocks.get_with_index(i)?.dyn_into::<HtmlElement>().ok()?;
+                let code_copied = self.code_copied.clone_ref();
                 let closure     = Box::new(move |_event: MouseEvent| {
-                    let inner_code = code_block.inner_text();
-                    clipboard::write_text(inner_code);
+                    code_copied.emit(code_block.inner_text());
                 });
                 let closure: Closure<dyn FnMut(MouseEvent)> = Closure::wrap(closure);
                 let callback = closure.as_ref().unchecked_ref();
@@ -171,6 +181,44 @@ impl Model {
         self.code_copy_closures.set(ok_closures)
     }
 
+    /// Call `on_click` with the target of every link leaving the documentation, instead of
+    /// following the link. The in-page anchors are followed as usual, scrolling the documentation.
+    /// A single listener is attached to the container, so it handles also the links of the
+    /// documentation rendered later.
+    fn attach_link_listener(&self, on_click:impl Fn(String) + 'static) {
+        let closure = Box::new(move |event:MouseEvent| {
+            let target = event.target().and_then(|t| t.dyn_into::<web_sys::Element>().ok());
+            let link   = target.and_then(|target| target.closest("a").ok().flatten());
+            let href   = link.and_then(|link| link.get_attribute("href"));
+            if let Some(href) = href.filter(|href| !href.starts_with('#')) {
+                event.prevent_default();
+                on_click(href);
+            }
+        });
+        let closure : LinkClickClosure = Closure::wrap(closure);
+        let callback = closure.as_ref().unchecked_ref();
+        if let Err(e) = self.inner_dom.dom().add_event_listener_with_callback("click",callback) {
+            error!(&self.logger,"Unable to add event listener to documentation links: {e:?}");
+        }
+        *self.link_click_closure.borrow_mut() = Some(closure);
+    }
+
+    /// Schedule the documentation to be displayed. The rendering is deferred until the next frame
+    /// in which the view is visible, so when the documentation changes many times in a row, e.g.
+    /// while scrolling the searcher suggestions, only the last one is rendered.
+    fn set_documentation(&self, content:&str) {
+        *self.pending.borrow_mut() = Some(content.into());
+    }
+
+    /// Render the scheduled documentation, if any, provided the view is visible.
+    fn render_pending(&self) {
+        if self.display_object.is_visible() {
+            if let Some(content) = self.pending.borrow_mut().take() {
+                self.display_doc(&content);
+            }
+        }
+    }
+
     /// Receive data, process and present it in the documentation view.
     fn receive_data(&self, data:&visualization::Data) -> Result<(),visualization::DataError> {
         let string = match data {
@@ -182,7 +230,7 @@ impl Model {
                     return Err(visualization::DataError::InternalComputationError);
                 }
             }
-            visualization::Data::Binary =>
+            visualization::Data::Binary {..} =>
                 return Err(visualization::DataError::BinaryNotSupported),
         };
         self.display_doc(&string);
@@ -220,13 +268,18 @@ impl Model {
 
 ensogl::define_endpoints! {
     Input {
-        /// Display documentation of the entity represented by given code.
-        display_documentation (String)
+        /// Display documentation of the entity represented by given code. The documentation is
+        /// rendered lazily, in the next frame in which the panel is visible.
+        set_documentation (String)
     }
     Output {
         /// Indicates whether the documentation panel has been selected through clicking into
         /// it, or deselected by clicking somewhere else.
         is_selected(bool),
+        /// The target of the link clicked in the documentation. The link is not followed.
+        documentation_link_clicked (String),
+        /// The code of the example copied with its copy button. It is written to the clipboard.
+        code_copied (String),
     }
 }
 
@@ -265,7 +318,7 @@ impl View {
     pub fn new(scene:&Scene) -> Self {
         let frp               = Frp::new();
         let visualization_frp = visualization::instance::Frp::new(&frp.network);
-        let model             = Model::new(scene);
+        let model             = Model::new(scene,&frp.source.code_copied);
         model.load_waiting_screen();
         Self {model,visualization_frp,frp} . init(scene)
     }
@@ -275,11 +328,13 @@ impl View {
         let model         = &self.model;
         let visualization = &self.visualization_frp;
         let frp           = &self.frp;
+        let clipboard     = frp::io::Clipboard::new(network);
         frp::extend! { network
 
             // === Displaying documentation ===
 
-            eval frp.display_documentation ((cont) model.display_doc(cont));
+            eval frp.set_documentation ((cont) model.set_documentation(cont));
+            eval_ scene.frp.frame_time (model.render_pending());
             eval visualization.send_data([visualization,model](data) {
                 if let Err(error) = model.receive_data(data) {
                     visualization.data_receive_error.emit(error)
@@ -308,8 +363,15 @@ impl View {
                 (new != old).as_some(new)
             });
             frp.source.is_selected <+ is_selected_changed;
+
+
+            // === Copying Examples ===
+
+            clipboard.copy <+ frp.code_copied;
         }
         visualization.pass_events_to_dom_if_active(scene,network);
+        let link_clicked = frp.source.documentation_link_clicked.clone_ref();
+        model.attach_link_listener(move |href| link_clicked.emit(href));
         self
     }
 }
@@ -0,0 +1,470 @@
+//! The FRP inspector panel, a debugging tool available in debug builds. It lists the living FRP
+//! networks, shows the event counts of their nodes live, traces the recent values of the chosen
+//! node, and points at the view component owning the inspected network. See the
+//! `enso_frp::inspector` module to learn where the data comes from.
+
+use crate::prelude::*;
+
+use enso_frp as frp;
+use ensogl::application;
+use ensogl::application::Application;
+use ensogl::application::shortcut;
+use ensogl::control::callback;
+use ensogl::debug::frp_owner;
+use ensogl::display;
+use ensogl::display::shape::*;
+use ensogl_gui_components::list_view;
+use ensogl_text as text;
+use ensogl_theme::application::frp_inspector as theme;
+use frp::inspector;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The event counts and the traced values are refreshed every this many frames.
+const REFRESH_PERIOD : usize = 30;
+/// The number of the busiest nodes of the inspected network whose event counts are displayed.
+const BUSIEST_NODES_COUNT : usize = 10;
+/// The radius of the marker pointing at the owner of the inspected network.
+const MARKER_RADIUS : f32 = 24.0;
+/// The width of the marker ring.
+const MARKER_WIDTH : f32 = 3.0;
+/// The label of the list entry going back to the network list.
+const BACK_ENTRY : &str = "..";
+
+
+
+// =============
+// === Entry ===
+// =============
+
+/// The entry in the inspector list.
+pub type Entry = list_view::entry::Label;
+
+/// The node label without the module path and the line number added by the FRP macros.
+fn short_label(label:&str) -> &str {
+    let name = label.rsplit("::").next().unwrap_or(label);
+    name.split(':').next().unwrap_or(name)
+}
+
+
+
+// ==============
+// === Shapes ===
+// ==============
+
+mod background {
+    use super::*;
+
+    pub const CORNER_RADIUS_PX : f32 = 8.0;
+
+    ensogl::define_shape_system! {
+        (style:Style) {
+            let width  = Var::<Pixels>::from("input_size.x");
+            let height = Var::<Pixels>::from("input_size.y");
+            let shape  = Rect((&width,&height)).corners_radius(CORNER_RADIUS_PX.px());
+            shape.fill(style.get_color(theme::background)).into()
+        }
+    }
+}
+
+/// A ring displayed around the origin of the display object owning the inspected network.
+mod marker {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (style:Style) {
+            let outer = Circle(MARKER_RADIUS.px());
+            let inner = Circle((MARKER_RADIUS - MARKER_WIDTH).px());
+            let ring  = outer - inner;
+            ring.fill(style.get_color(theme::highlight)).into()
+        }
+    }
+}
+
+
+
+// ===========
+// === Frp ===
+// ===========
+
+ensogl::define_endpoints! {
+    Input {
+        /// Show the inspector panel and start counting the events.
+        show(),
+        /// Hide the inspector panel and stop counting the events.
+        hide(),
+        /// Toggle the inspector panel visibility.
+        toggle(),
+        /// List only the networks whose label contains the given text, ignoring the letter case.
+        set_filter (String),
+        /// Go back from the node list to the network list.
+        back(),
+        /// Reload the list of networks or nodes, e.g. after new components were created.
+        reload(),
+    }
+
+    Output {
+        is_visible        (bool),
+        /// The network whose nodes are listed, if any.
+        inspected_network (Option<frp::NetworkId>),
+        /// The node whose recent values are traced, if any.
+        traced_node       (Option<frp::Id>),
+    }
+}
+
+
+
+// ===============
+// === Listing ===
+// ===============
+
+/// The content of the inspector list.
+#[derive(Clone,Debug)]
+enum Listing {
+    /// The networks matching the filter.
+    Networks(Vec<frp::WeakNetwork>),
+    /// The nodes of the inspected network. The first list entry goes back to the network list.
+    Nodes(frp::WeakNetwork,Vec<inspector::NodeInfo>),
+}
+
+impl Default for Listing {
+    fn default() -> Self {
+        Self::Networks(default())
+    }
+}
+
+
+
+// =============
+// === Model ===
+// =============
+
+#[derive(Clone,CloneRef,Debug)]
+struct Model {
+    app            : Application,
+    logger         : Logger,
+    display_object : display::object::Instance,
+    panel          : display::object::Instance,
+    background     : background::View,
+    list           : list_view::ListView<Entry>,
+    details        : text::Area,
+    marker         : marker::View,
+    listing        : Rc<RefCell<Listing>>,
+    filter         : Rc<RefCell<String>>,
+    traced         : Rc<Cell<Option<frp::Id>>>,
+}
+
+impl Model {
+    fn new(app:&Application) -> Self {
+        let app            = app.clone_ref();
+        let scene          = app.display.scene();
+        let logger         = Logger::new("FrpInspector");
+        let display_object = display::object::Instance::new(&logger);
+        let panel          = display::object::Instance::new(&logger);
+        let background     = background::View::new(&logger);
+        let list           = app.new_view::<list_view::ListView<Entry>>();
+        let details        = app.new_view::<text::Area>();
+        let marker         = marker::View::new(&logger);
+        let listing        = default();
+        let filter         = default();
+        let traced         = default();
+        panel.add_child(&background);
+        panel.add_child(&list);
+        panel.add_child(&details);
+        scene.layers.panel.add_exclusive(&panel);
+        list.set_label_layer(scene.layers.panel_text.id());
+        details.remove_from_scene_layer(&scene.layers.main);
+        details.add_to_scene_layer(&scene.layers.panel_text);
+        let marker_size = 2.0 * MARKER_RADIUS;
+        marker.size.set(Vector2(marker_size,marker_size));
+
+        ensogl::shapes_order_dependencies! {
+            scene => {
+                background            -> list_view::selection;
+                list_view::background -> background;
+            }
+        }
+
+        Self {app,logger,display_object,panel,background,list,details,marker,listing,filter,traced}
+    }
+
+    fn set_visible(&self, visible:bool) {
+        inspector::set_enabled(visible);
+        if visible {
+            self.display_object.add_child(&self.panel);
+            self.reload();
+        } else {
+            self.traced.set(None);
+            self.panel.unset_parent();
+            self.marker.unset_parent();
+        }
+    }
+
+    fn set_layout
+    (&self, width:f32, list_height:f32, trace_height:f32, padding:f32, scene_width:f32) {
+        let height = list_height + trace_height + 3.0 * padding;
+        let list_y = height / 2.0 - padding - list_height / 2.0;
+        let x      = scene_width / 2.0 - padding - width / 2.0;
+        self.background.size.set(Vector2(width,height));
+        self.list.resize(Vector2(width - 2.0 * padding,list_height));
+        self.list.set_position_y(list_y);
+        let details_y = list_y - list_height / 2.0 - padding;
+        self.details.set_position_xy(Vector2(-width / 2.0 + padding,details_y));
+        self.panel.set_position_x(x);
+    }
+
+    fn inspected_network(&self) -> Option<frp::NetworkId> {
+        match &*self.listing.borrow() {
+            Listing::Networks(_)      => None,
+            Listing::Nodes(network,_) => Some(network.id()),
+        }
+    }
+
+
+    // === Listing ===
+
+    fn set_filter(&self, filter:&str) {
+        *self.filter.borrow_mut() = filter.into();
+        if self.inspected_network().is_none() {
+            self.list_networks();
+        }
+    }
+
+    /// Reload the current list, going back to the network list if the inspected network was
+    /// dropped.
+    fn reload(&self) {
+        let inspected = match &*self.listing.borrow() {
+            Listing::Networks(_)      => None,
+            Listing::Nodes(network,_) => network.upgrade(),
+        };
+        match inspected {
+            Some(network) => self.list_nodes(&network),
+            None          => self.back(),
+        }
+    }
+
+    fn list_networks(&self) {
+        let networks = inspector::find_networks(&self.filter.borrow());
+        let entries  = networks.iter().map(|network| {
+            format!("{} ({} nodes)",network.label(),network.topology().len())
+        }).collect_vec();
+        let networks = networks.iter().map(|network| network.downgrade()).collect();
+        *self.listing.borrow_mut() = Listing::Networks(networks);
+        self.list.set_entries(list_view::entry::AnyModelProvider::new(entries));
+        self.refresh_details();
+    }
+
+    fn list_nodes(&self, network:&frp::Network) {
+        let nodes   = network.topology();
+        let back    = std::iter::once(BACK_ENTRY.to_string());
+        let entries = nodes.iter().map(|node| {
+            format!("{} : {}",short_label(node.label),node.output_type)
+        });
+        let entries = back.chain(entries).collect_vec();
+        *self.listing.borrow_mut() = Listing::Nodes(network.downgrade(),nodes);
+        self.list.set_entries(list_view::entry::AnyModelProvider::new(entries));
+        self.refresh_details();
+    }
+
+    fn back(&self) {
+        self.set_traced(None);
+        self.list_networks();
+    }
+
+    fn choose_entry(&self, entry:list_view::entry::Id) {
+        let listing = self.listing.borrow().clone();
+        match listing {
+            Listing::Networks(networks) => {
+                if let Some(network) = networks.get(entry).and_then(|n| n.upgrade()) {
+                    self.list_nodes(&network);
+                }
+            }
+            Listing::Nodes(_,nodes) => match entry.checked_sub(1) {
+                None        => self.back(),
+                Some(index) => if let Some(node) = nodes.get(index) {
+                    let is_traced = self.traced.get() == Some(node.id);
+                    self.set_traced((!is_traced).as_some(node.id));
+                    self.refresh_details();
+                }
+            }
+        }
+    }
+
+    fn set_traced(&self, node:Option<frp::Id>) {
+        if let Some(old) = self.traced.replace(node) {
+            inspector::untrace(old);
+        }
+        if let Some(new) = node {
+            inspector::trace(new);
+        }
+    }
+
+
+    // === Details ===
+
+    /// Refresh the event counts, the traced values, and the marker position.
+    fn refresh_details(&self) {
+        let (content,owner) = match &*self.listing.borrow() {
+            Listing::Networks(_)          => ("Choose a network to inspect its nodes.".into(),None),
+            Listing::Nodes(network,nodes) => {
+                (self.describe_nodes(nodes),frp_owner::owner(network.id()))
+            }
+        };
+        self.set_marker_position(owner.map(|owner| owner.global_position().xy()));
+        self.details.set_content(content);
+    }
+
+    fn describe_nodes(&self, nodes:&[inspector::NodeInfo]) -> String {
+        let count_of   = |node:&inspector::NodeInfo| inspector::event_count(node.id);
+        let mut counts = nodes.iter().map(|node| (count_of(node),node)).collect_vec();
+        counts.sort_by_key(|(count,_)| std::cmp::Reverse(*count));
+        let mut lines  = vec!["Busiest nodes:".to_string()];
+        for (count,node) in counts.iter().take(BUSIEST_NODES_COUNT) {
+            lines.push(format!("{:>8}  {}",count,short_label(node.label)));
+        }
+        if let Some(traced) = self.traced.get() {
+            let label = nodes.iter().find(|node| node.id == traced).map(|node| node.label);
+            lines.push(default());
+            lines.push(format!("Recent values of {}:",short_label(label.unwrap_or_default())));
+            lines.extend(inspector::recent_values(traced));
+        }
+        lines.join("\n")
+    }
+
+    fn set_marker_position(&self, position:Option<Vector2>) {
+        match position {
+            Some(position) => {
+                self.app.add_child(&self.marker);
+                self.marker.set_position_xy(position);
+            }
+            None => self.marker.unset_parent(),
+        }
+    }
+}
+
+
+
+// ============
+// === View ===
+// ============
+
+/// The FRP inspector panel. See the module docs to learn more.
+#[derive(Clone,CloneRef,Debug)]
+pub struct View {
+    model        : Model,
+    frp          : Frp,
+    frame_handle : callback::Handle,
+}
+
+impl Deref for View {
+    type Target = Frp;
+    fn deref(&self) -> &Self::Target {
+        &self.frp
+    }
+}
+
+impl View {
+    /// Constructor.
+    pub fn new(app:&Application) -> Self {
+        let model       = Model::new(app);
+        let frp         = Frp::new();
+        let network     = &frp.network;
+        let scene       = app.display.scene();
+        let scene_shape = scene.shape();
+        let style       = StyleWatchFrp::new(&scene.style_sheet);
+        let list        = &model.list;
+
+        frp::extend! { network
+            let is_visible     =  frp.output.is_visible.clone_ref();
+            show_after_toggle <- frp.toggle.gate_not(&is_visible);
+            hide_after_toggle <- frp.toggle.gate(&is_visible);
+            show              <- any(frp.input.show,show_after_toggle);
+            hide              <- any(frp.input.hide,hide_after_toggle);
+            frp.source.is_visible <+ bool(&hide,&show);
+            eval frp.output.is_visible ((visible) model.set_visible(*visible));
+
+
+            // === Browsing ===
+
+            eval  frp.set_filter ((filter) model.set_filter(filter));
+            eval_ frp.back       (model.back());
+            eval_ frp.reload     (model.reload());
+            chosen_entry <- list.chosen_entry.filter_map(|entry| *entry);
+            eval chosen_entry ((entry) model.choose_entry(*entry));
+
+            browsed         <- any_(chosen_entry,frp.back,frp.reload,frp.set_filter);
+            listing_changed <- any_(browsed,show);
+            frp.source.inspected_network <+ listing_changed.map(f_!(model.inspected_network()));
+            frp.source.traced_node       <+ listing_changed.map(f_!(model.traced.get()));
+            frp.source.traced_node       <+ hide.constant(None);
+
+
+            // === Live Refresh ===
+
+            frame_tick <- source::<()>();
+            refresh    <- frame_tick.gate(&is_visible);
+            eval_ refresh (model.refresh_details());
+
+
+            // === Layout ===
+
+            init         <- source::<()>();
+            let width        = style.get_number(theme::width);
+            let list_height  = style.get_number(theme::list_height);
+            let trace_height = style.get_number(theme::trace_height);
+            let padding      = style.get_number(theme::padding);
+            let text_color   = style.get_color(theme::text);
+            _layout <- all_with6(&width,&list_height,&trace_height,&padding,scene_shape,&init,
+                f!((width,list_height,trace_height,padding,shape,_)
+                    model.set_layout(*width,*list_height,*trace_height,*padding,shape.width)
+                )
+            );
+            text_color <- all(&text_color,&init)._0();
+            eval text_color ((color) model.details.set_default_color(color));
+        }
+        init.emit(());
+
+        let frame_count  = Cell::new(0);
+        let frame_handle = app.display.on_frame(move |_| {
+            frame_count.set(frame_count.get() + 1);
+            if frame_count.get() % REFRESH_PERIOD == 0 {
+                frame_tick.emit(());
+            }
+        });
+        Self {model,frp,frame_handle}
+    }
+}
+
+impl display::Object for View {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.model.display_object
+    }
+}
+
+impl application::command::FrpNetworkProvider for View {
+    fn network(&self) -> &frp::Network { &self.frp.network }
+}
+
+impl application::View for View {
+    fn label() -> &'static str { "FrpInspector" }
+
+    fn new(app:&Application) -> Self { Self::new(app) }
+
+    fn app(&self) -> &Application {
+        &self.model.app
+    }
+
+    fn default_shortcuts() -> Vec<shortcut::Shortcut> {
+        use shortcut::ActionType::*;
+        if cfg!(debug_assertions) {
+            (&[ (Press, "ctrl shift `", "toggle")
+            ]).iter().map(|(a,b,c)|Self::self_shortcut(*a,*b,*c)).collect()
+        } else {
+            default()
+        }
+    }
+}
@@ -28,11 +28,14 @@
 #[allow(clippy::option_map_unit_fn)]
 
 pub mod code_editor;
+pub mod command_palette;
 pub mod debug_scenes;
 pub mod documentation;
+pub mod frp_inspector;
 pub mod open_dialog;
 pub mod project;
 pub mod searcher;
+pub mod shortcut_cheat_sheet;
 pub mod status_bar;
 pub mod window_control_buttons;
 
@@ -3,9 +3,11 @@
 use crate::prelude::*;
 
 use crate::code_editor;
+use crate::command_palette;
+use crate::frp_inspector;
+use crate::shortcut_cheat_sheet;
 use crate::graph_editor::component::node;
 use crate::graph_editor::component::node::Expression;
-use crate::graph_editor::component::visualization;
 use crate::graph_editor::GraphEditor;
 use crate::graph_editor::NodeId;
 use crate::open_dialog::OpenDialog;
@@ -27,6 +29,19 @@ use ensogl_theme::Theme as Theme;
 
 
 
+// =================
+// === Constants ===
+// =================
+
+/// The status bar message displayed when the graph editor switched to the lite rendering mode.
+const LITE_MODE_ENABLED_MESSAGE : &str =
+    "Lite mode enabled: the visuals were simplified, as the rendering was too slow.";
+
+/// The status bar message displayed when the graph editor switched back from the lite mode.
+const LITE_MODE_DISABLED_MESSAGE : &str = "Lite mode disabled: the rendering is fast enough again.";
+
+
+
 // ===========
 // === FRP ===
 // ===========
@@ -111,8 +126,11 @@ struct Model {
     graph_editor           : Rc<GraphEditor>,
     searcher               : searcher::View,
     code_editor            : code_editor::View,
+    frp_inspector          : frp_inspector::View,
+    command_palette        : command_palette::View,
+    shortcut_cheat_sheet   : shortcut_cheat_sheet::View,
     status_bar             : status_bar::View,
-    fullscreen_vis         : Rc<RefCell<Option<visualization::fullscreen::Panel>>>,
+    fullscreen_vis         : Rc<Cell<bool>>,
     prompt_background      : prompt_background::View,
     prompt                 : ensogl_text::Area,
     open_dialog            : Rc<OpenDialog>,
@@ -126,6 +144,9 @@ impl Model {
         let searcher               = app.new_view::<searcher::View>();
         let graph_editor           = app.new_view::<GraphEditor>();
         let code_editor            = app.new_view::<code_editor::View>();
+        let frp_inspector          = app.new_view::<frp_inspector::View>();
+        let command_palette        = app.new_view::<command_palette::View>();
+        let shortcut_cheat_sheet   = app.new_view::<shortcut_cheat_sheet::View>();
         let status_bar             = status_bar::View::new(app);
         let fullscreen_vis         = default();
         let prompt_background      = prompt_background::View::new(&logger);
@@ -146,6 +167,9 @@ impl Model {
 
         display_object.add_child(&graph_editor);
         display_object.add_child(&code_editor);
+        display_object.add_child(&frp_inspector);
+        display_object.add_child(&command_palette);
+        display_object.add_child(&shortcut_cheat_sheet);
         display_object.add_child(&searcher);
         display_object.add_child(&status_bar);
         display_object.add_child(&prompt_background);
@@ -154,7 +178,8 @@ impl Model {
         let app          = app.clone_ref();
         let graph_editor = Rc::new(graph_editor);
         Self{app,logger,display_object,window_control_buttons,graph_editor,searcher,code_editor
-            ,status_bar,fullscreen_vis,prompt_background,prompt,open_dialog}
+            ,frp_inspector,command_palette,shortcut_cheat_sheet,status_bar,fullscreen_vis
+            ,prompt_background,prompt,open_dialog}
     }
 
     /// Sets style of IDE to the one defined by parameter `theme`.
@@ -226,19 +251,16 @@ impl Model {
         node_id
     }
 
-    fn show_fullscreen_visualization(&self, node_id:NodeId) {
-        let node = self.graph_editor.model.model.nodes.all.get_cloned_ref(&node_id);
-        if let Some(node) = node {
-            let visualization = node.view.model.visualization.fullscreen_visualization().clone_ref();
+    fn show_fullscreen_visualization(&self) {
+        if !self.fullscreen_vis.replace(true) {
             self.display_object.remove_child(&*self.graph_editor);
-            self.display_object.add_child(&visualization);
-            *self.fullscreen_vis.borrow_mut() = Some(visualization);
+            self.display_object.add_child(self.graph_editor.model.fullscreen_overlay());
         }
     }
 
     fn hide_fullscreen_visualization(&self) {
-        if let Some(visualization) = std::mem::take(&mut *self.fullscreen_vis.borrow_mut()) {
-            self.display_object.remove_child(&visualization);
+        if self.fullscreen_vis.replace(false) {
+            self.display_object.remove_child(self.graph_editor.model.fullscreen_overlay());
             self.display_object.add_child(&*self.graph_editor);
         }
     }
@@ -497,13 +519,8 @@ impl View {
             //
             //     The workaround should be replaced with proper solution being a part of
             //     https://github.com/enso-org/ide/issues/526
-            eval  graph.visualization_fullscreen ([model](node_id) {
-                if let Some(node_id) = node_id {
-                    model.show_fullscreen_visualization(*node_id)
-                } else {
-                    model.hide_fullscreen_visualization()
-                }
-            });
+            eval_ graph.visualization_fullscreen_entered (model.show_fullscreen_visualization());
+            eval_ graph.visualization_fullscreen_exited  (model.hide_fullscreen_visualization());
 
 
             // === Prompt ===
@@ -553,10 +570,35 @@ impl View {
             disable_navigation           <- searcher.is_selected || frp.open_dialog_shown;
             graph.set_navigator_disabled <+ disable_navigation;
 
+            // === Command Palette ===
+
+            graph.set_external_text_focus <+ model.command_palette.is_visible;
+
             // === Disabling Dropping ===
 
             frp.source.drop_files_enabled <+ init.constant(true);
             frp.source.drop_files_enabled <+ frp.open_dialog_shown.map(|v| !v);
+
+
+            // === Pending Shortcut Chord ===
+
+            let status            = &model.status_bar;
+            let pending_chord     = &app.shortcuts.pending_chord;
+            chord_process         <- any(...);
+            status.finish_process <+ pending_chord.map2(&chord_process,|_,id| *id).unwrap();
+            chord_label           <- pending_chord.filter_map(|chord| chord.as_ref().map(|chord|
+                status_bar::process::Label::new(format!("{}, ...",chord))
+            ));
+            status.add_process    <+ chord_label;
+            chord_process         <+ status.last_process.sample(&chord_label).map(|id| Some(*id));
+            chord_process         <+ pending_chord.filter(|chord| chord.is_none()).constant(None);
+
+
+            // === Lite Mode ===
+
+            status.add_event <+ graph.lite_mode.map(|&enabled| status_bar::event::Label::new(
+                if enabled { LITE_MODE_ENABLED_MESSAGE } else { LITE_MODE_DISABLED_MESSAGE }
+            ));
         }
         init.emit(());
         std::mem::forget(prompt_visibility);
@@ -576,6 +618,17 @@ impl View {
     /// Code Editor View.
     pub fn code_editor(&self) -> &code_editor::View { &self.model.code_editor }
 
+    /// FRP Inspector View. Its shortcut is available in debug builds only.
+    pub fn frp_inspector(&self) -> &frp_inspector::View { &self.model.frp_inspector }
+
+    /// Command Palette View.
+    pub fn command_palette(&self) -> &command_palette::View { &self.model.command_palette }
+
+    /// Shortcut Cheat Sheet View.
+    pub fn shortcut_cheat_sheet(&self) -> &shortcut_cheat_sheet::View {
+        &self.model.shortcut_cheat_sheet
+    }
+
     /// Status Bar View.
     pub fn status_bar(&self) -> &status_bar::View { &self.model.status_bar }
 
@@ -165,6 +165,8 @@ ensogl::define_endpoints! {
         is_visible         (bool),
         is_selected        (bool),
         is_empty           (bool),
+        /// The target of the link clicked in the documentation panel.
+        documentation_link_clicked (String),
     }
 }
 
@@ -241,7 +243,8 @@ impl View {
             opt_chosen_id             <- model.new_view.entry_chosen.map(|id| id.last().copied());
             source.editing_committed  <+ opt_chosen_id.gate(&is_selected);
 
-            eval displayed_doc ((data) model.documentation.frp.display_documentation(data));
+            eval displayed_doc ((data) model.documentation.frp.set_documentation(data));
+            source.documentation_link_clicked <+ model.documentation.frp.documentation_link_clicked;
         };
 
         self
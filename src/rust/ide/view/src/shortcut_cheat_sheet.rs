@@ -0,0 +1,304 @@
+//! The shortcut cheat sheet, an overlay listing the currently active shortcuts grouped by the views
+//! they evaluate the commands in. The list is generated at runtime from the application shortcut
+//! registry (see `shortcut::RegistryModel::shortcuts`), with the docs of the commands and the
+//! conditions the shortcuts are enabled in, so it never gets out of sync with the actual bindings.
+
+use crate::prelude::*;
+
+use enso_frp as frp;
+use ensogl::application;
+use ensogl::application::Application;
+use ensogl::application::command;
+use ensogl::application::shortcut;
+use ensogl::application::shortcut::ShortcutInfo;
+use ensogl::display;
+use ensogl::display::shape::*;
+use ensogl_text as text;
+use ensogl_theme::application::shortcut_cheat_sheet as theme;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The width of the pattern column, in characters.
+const PATTERN_COLUMN_WIDTH : usize = 24;
+/// The width of the description column, in characters.
+const DOC_COLUMN_WIDTH : usize = 48;
+
+
+
+// =============
+// === Sheet ===
+// =============
+
+/// The description of the shortcut: the first line of its command docs, or the command name if it
+/// is undocumented.
+fn description(shortcut:&ShortcutInfo) -> String {
+    let doc = shortcut.doc.lines().map(|line| line.trim()).find(|line| !line.is_empty());
+    let doc = doc.map(|doc| doc.to_string()).unwrap_or_else(|| shortcut.command.replace('_'," "));
+    if doc.chars().count() <= DOC_COLUMN_WIDTH { doc } else {
+        let shortened = doc.chars().take(DOC_COLUMN_WIDTH - 1).collect::<String>();
+        format!("{}…",shortened)
+    }
+}
+
+/// The text of the cheat sheet, listing the active `shortcuts` grouped by their views.
+fn sheet(shortcuts:&[ShortcutInfo]) -> String {
+    let mut lines = Vec::<String>::new();
+    let active    = shortcuts.iter().filter(|shortcut| shortcut.active);
+    for (view,group) in &active.group_by(|shortcut| shortcut.view.clone()) {
+        if !lines.is_empty() {
+            lines.push(default());
+        }
+        lines.push(command::view_title(&view));
+        for shortcut in group {
+            let pattern = &shortcut.pattern;
+            let doc     = description(shortcut);
+            let line    = format!("    {:pw$}{:dw$}",pattern,doc,pw=PATTERN_COLUMN_WIDTH
+                ,dw=DOC_COLUMN_WIDTH);
+            let line    = if shortcut.condition.is_empty() { line } else {
+                format!("{}when {}",line,shortcut.condition)
+            };
+            lines.push(line.trim_end().to_string());
+        }
+    }
+    lines.join("\n")
+}
+
+
+
+// ==============
+// === Shapes ===
+// ==============
+
+mod background {
+    use super::*;
+
+    pub const CORNER_RADIUS_PX : f32 = 8.0;
+
+    ensogl::define_shape_system! {
+        (style:Style) {
+            let width  = Var::<Pixels>::from("input_size.x");
+            let height = Var::<Pixels>::from("input_size.y");
+            let shape  = Rect((&width,&height)).corners_radius(CORNER_RADIUS_PX.px());
+            shape.fill(style.get_color(theme::background)).into()
+        }
+    }
+}
+
+
+
+// ===========
+// === Frp ===
+// ===========
+
+ensogl::define_endpoints! {
+    Input {
+        /// Show the cheat sheet with the shortcuts active at the moment.
+        show(),
+        /// Hide the cheat sheet.
+        hide(),
+        /// Toggle the cheat sheet visibility.
+        toggle(),
+    }
+
+    Output {
+        is_visible (bool),
+    }
+}
+
+
+
+// =============
+// === Model ===
+// =============
+
+#[derive(Clone,CloneRef,Debug)]
+struct Model {
+    app            : Application,
+    logger         : Logger,
+    display_object : display::object::Instance,
+    panel          : display::object::Instance,
+    background     : background::View,
+    content        : text::Area,
+}
+
+impl Model {
+    fn new(app:&Application) -> Self {
+        let app            = app.clone_ref();
+        let scene          = app.display.scene();
+        let logger         = Logger::new("ShortcutCheatSheet");
+        let display_object = display::object::Instance::new(&logger);
+        let panel          = display::object::Instance::new(&logger);
+        let background     = background::View::new(&logger);
+        let content        = app.new_view::<text::Area>();
+        panel.add_child(&background);
+        panel.add_child(&content);
+        scene.layers.panel.add_exclusive(&panel);
+        content.remove_from_scene_layer(&scene.layers.main);
+        content.add_to_scene_layer(&scene.layers.panel_text);
+        Self {app,logger,display_object,panel,background,content}
+    }
+
+    fn set_visible(&self, visible:bool) {
+        if visible {
+            let shortcuts = self.app.shortcuts.shortcuts();
+            let count     = shortcuts.len();
+            debug!(self.logger, "Listing {count} shortcuts.");
+            self.content.set_content(sheet(&shortcuts));
+            self.display_object.add_child(&self.panel);
+        } else {
+            self.panel.unset_parent();
+        }
+    }
+
+    fn set_layout(&self, width:f32, padding:f32) {
+        let lines  = self.content.content.value().to_string().lines().count();
+        let height = lines as f32 * text::component::area::LINE_HEIGHT + 2.0 * padding;
+        self.background.size.set(Vector2(width,height));
+        self.content.set_position_xy(Vector2(-width / 2.0 + padding,height / 2.0 - padding));
+    }
+}
+
+
+
+// ============
+// === View ===
+// ============
+
+/// The shortcut cheat sheet. See the module docs to learn more.
+#[derive(Clone,CloneRef,Debug)]
+pub struct View {
+    model : Model,
+    frp   : Frp,
+}
+
+impl Deref for View {
+    type Target = Frp;
+    fn deref(&self) -> &Self::Target {
+        &self.frp
+    }
+}
+
+impl View {
+    /// Constructor.
+    pub fn new(app:&Application) -> Self {
+        let model   = Model::new(app);
+        let frp     = Frp::new();
+        let network = &frp.network;
+        let scene   = app.display.scene();
+        let style   = StyleWatchFrp::new(&scene.style_sheet);
+
+        frp::extend! { network
+
+            // === Visibility ===
+
+            let is_visible     =  frp.output.is_visible.clone_ref();
+            show_after_toggle <- frp.toggle.gate_not(&is_visible);
+            hide_after_toggle <- frp.toggle.gate(&is_visible);
+            show              <- any(frp.input.show,show_after_toggle);
+            hide              <- any(frp.input.hide,hide_after_toggle);
+            frp.source.is_visible <+ bool(&hide,&show);
+            eval frp.output.is_visible ((visible) model.set_visible(*visible));
+
+
+            // === Layout ===
+
+            init <- source::<()>();
+            let width      = style.get_number(theme::width);
+            let padding    = style.get_number(theme::padding);
+            let text_color = style.get_color(theme::text);
+            shown   <- frp.output.is_visible.on_true();
+            _layout <- all_with4(&width,&padding,&shown,&init,
+                f!((width,padding,_,_) model.set_layout(*width,*padding))
+            );
+            text_color <- all(&text_color,&init)._0();
+            eval text_color ((color) model.content.set_default_color(color));
+        }
+        init.emit(());
+
+        Self {model,frp}
+    }
+}
+
+impl display::Object for View {
+    fn display_object(&self) -> &display::object::Instance {
+        &self.model.display_object
+    }
+}
+
+impl application::command::FrpNetworkProvider for View {
+    fn network(&self) -> &frp::Network { &self.frp.network }
+}
+
+impl application::View for View {
+    fn label() -> &'static str { "ShortcutCheatSheet" }
+
+    fn new(app:&Application) -> Self { Self::new(app) }
+
+    fn app(&self) -> &Application {
+        &self.model.app
+    }
+
+    fn default_shortcuts() -> Vec<shortcut::Shortcut> {
+        use shortcut::ActionType::*;
+        (&[ (Press , ""           , "cmd /"  , "toggle")
+          , (Press , "is_visible" , "escape" , "hide")
+          ]).iter().map(|(a,b,c,d)|Self::self_shortcut_when(*a,*c,*d,*b)).collect()
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shortcut(view:&str, command:&str, condition:&str, active:bool, doc:&str) -> ShortcutInfo {
+        let view      = view.into();
+        let command   = command.into();
+        let pattern   = "cmd x".into();
+        let tp        = shortcut::ActionType::Press;
+        let condition = condition.into();
+        let doc       = doc.into();
+        ShortcutInfo {view,command,pattern,tp,condition,active,doc}
+    }
+
+    #[test]
+    fn description_is_the_first_doc_line() {
+        let documented = shortcut("View","cut","",true,"\n  Cut the selection.\n  More text.");
+        assert_eq!(description(&documented),"Cut the selection.");
+        let undocumented = shortcut("View","cut_selection","",true,"");
+        assert_eq!(description(&undocumented),"cut selection");
+        let long = "a".repeat(DOC_COLUMN_WIDTH + 1);
+        let long = description(&shortcut("View","cut","",true,&long));
+        assert_eq!(long.chars().count(),DOC_COLUMN_WIDTH);
+        assert!(long.ends_with('…'));
+    }
+
+    #[test]
+    fn sheet_lists_active_shortcuts_by_view() {
+        let shortcuts = vec!
+            [ shortcut("GraphEditor","cut","",true,"Cut.")
+            , shortcut("GraphEditor","paste","!node_editing",true,"Paste.")
+            , shortcut("GraphEditor","copy","",false,"Copy.")
+            , shortcut("CodeEditor","undo","",true,"Undo.")
+            ];
+        let lines = sheet(&shortcuts);
+        let lines = lines.lines().map(|line| line.split_whitespace().join(" ")).collect_vec();
+        assert_eq!(lines,vec!
+            [ "Graph Editor"
+            , "cmd x Cut."
+            , "cmd x Paste. when !node_editing"
+            , ""
+            , "Code Editor"
+            , "cmd x Undo."
+            ]);
+    }
+}
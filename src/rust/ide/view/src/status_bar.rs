@@ -1,10 +1,9 @@
 //! A module containing IDE status bar component definitions (frp, model, view, etc.)
 //!
-//! The component is currently rather a stub: it has endpoints for setting many events and
-//! processes and keep them in a list, but it shows only a label of the last event/process
-//! added.
-//TODO[ao] Implement the status bar according to https://github.com/enso-org/ide/issues/1193
-//    description
+//! The status bar consists of two areas. The left one displays the last event (e.g. "Project
+//! saved") for a while, and the right one displays the running processes (e.g. compilation) with
+//! a spinner. When many processes run at once, only one of them is displayed, chosen according to
+//! the [`process::Stacking`] policy, and the number of the other ones is appended to its label.
 use crate::prelude::*;
 
 use crate::graph_editor::component::node::input::area::TEXT_SIZE;
@@ -34,6 +33,14 @@ pub const PADDING         : f32 = 12.0;
 const MARGIN              : f32 = 12.0;
 /// This should be as large as the shadow around the background.
 const MAGIC_SHADOW_MARGIN : f32 = 40.0;
+/// The size of the spinner displayed next to the running process label.
+const SPINNER_SIZE        : f32 = 12.0;
+/// The gap between the spinner and the process label.
+const SPINNER_GAP         : f32 = 8.0;
+/// The number of full turns the spinner makes per second.
+const SPINNER_SPEED       : f32 = 1.0;
+/// The time an event stays displayed after being added, in milliseconds.
+const EVENT_DISPLAY_TIME_MS : u32 = 5000;
 
 
 
@@ -80,6 +87,22 @@ pub mod process {
         /// A label assigned to some process displayed in a status bar.
         Label
     }
+
+    /// Decides which process is displayed when many of them run at once.
+    #[derive(Clone,Copy,Debug,Eq,PartialEq)]
+    pub enum Stacking {
+        /// Display the most recently started process. Once it finishes, the previously started
+        /// one is displayed again.
+        Latest,
+        /// Display the process which has been running for the longest time.
+        Earliest,
+    }
+
+    impl Default for Stacking {
+        fn default() -> Self {
+            Self::Latest
+        }
+    }
 }
 
 
@@ -117,22 +140,45 @@ mod background {
 
 
 
+// ===============
+// === Spinner ===
+// ===============
+
+mod spinner {
+    use super::*;
+
+    ensogl::define_shape_system! {
+        (style:Style) {
+            let width  = Var::<Pixels>::from("input_size.x");
+            let radius = &width / 2.0 - 1.0.px();
+            let angle  = (std::f32::consts::PI * 1.5).radians();
+            let arc    = RoundedArc(radius,angle,1.5.px());
+            let color  = style.get_color(ensogl_theme::application::status_bar::text);
+            arc.fill(color).into()
+        }
+    }
+}
+
+
+
 // ===========
 // === FRP ===
 // ===========
 
 ensogl::define_endpoints! {
     Input {
-        add_event      (event::Label),
-        add_process    (process::Label),
-        finish_process (process::Id),
-        clear_all      (),
+        add_event            (event::Label),
+        add_process          (process::Label),
+        finish_process       (process::Id),
+        set_process_stacking (process::Stacking),
+        clear_all            (),
     }
     Output {
         last_event        (event::Id),
         last_process      (process::Id),
         displayed_event   (Option<event::Id>),
         displayed_process (Option<process::Id>),
+        running_processes (usize),
     }
 }
 
@@ -145,48 +191,66 @@ ensogl::define_endpoints! {
 /// An internal model of Status Bar component
 #[derive(Clone,CloneRef,Debug)]
 struct Model {
-    logger          : Logger,
-    display_object  : display::object::Instance,
-    root            : display::object::Instance,
-    background      : background::View,
-    label           : text::Area,
-    events          : Rc<RefCell<Vec<event::Label>>>,
-    processes       : Rc<RefCell<HashMap<process::Id,process::Label>>>,
-    next_process_id : Rc<RefCell<process::Id>>,
-    camera          : Camera2d,
+    logger             : Logger,
+    display_object     : display::object::Instance,
+    event_root         : display::object::Instance,
+    event_background   : background::View,
+    event_label        : text::Area,
+    process_root       : display::object::Instance,
+    process_background : background::View,
+    process_label      : text::Area,
+    spinner            : spinner::View,
+    events             : Rc<RefCell<Vec<event::Label>>>,
+    processes          : Rc<RefCell<Vec<(process::Id,process::Label)>>>,
+    next_process_id    : Rc<RefCell<process::Id>>,
+    stacking           : Rc<Cell<process::Stacking>>,
+    camera             : Camera2d,
 }
 
 impl Model {
     fn new(app:&Application) -> Self {
-        let scene           = app.display.scene();
-        let logger          = Logger::new("StatusBar");
-        let display_object  = display::object::Instance::new(&logger);
-        let root            = display::object::Instance::new(&logger);
-        let background      = background::View::new(&logger);
-        let label           = text::Area::new(app);
-        let events          = default();
-        let processes       = default();
-        let next_process_id = Rc::new(RefCell::new(process::Id(1)));
-        let camera          = scene.camera();
-
-        scene.layers.panel.add_exclusive(&background);
-        label.remove_from_scene_layer(&scene.layers.main);
-        label.add_to_scene_layer(&scene.layers.panel_text);
-
+        let scene              = app.display.scene();
+        let logger             = Logger::new("StatusBar");
+        let display_object     = display::object::Instance::new(&logger);
+        let event_root         = display::object::Instance::new(&logger);
+        let event_background   = background::View::new(&logger);
+        let event_label        = text::Area::new(app);
+        let process_root       = display::object::Instance::new(&logger);
+        let process_background = background::View::new(&logger);
+        let process_label      = text::Area::new(app);
+        let spinner            = spinner::View::new(&logger);
+        let events             = default();
+        let processes          = default();
+        let next_process_id    = Rc::new(RefCell::new(process::Id(1)));
+        let stacking           = default();
+        let camera             = scene.camera();
+
+        scene.layers.panel.add_exclusive(&event_background);
+        scene.layers.panel.add_exclusive(&process_background);
+        scene.layers.panel.add_exclusive(&spinner);
         let text_color_path = theme::application::status_bar::text;
         let style           = StyleWatch::new(&app.display.scene().style_sheet);
         let text_color      = style.get_color(text_color_path);
-        label.frp.set_color_all.emit(text_color);
-        label.frp.set_default_color.emit(text_color);
+        for label in &[&event_label,&process_label] {
+            label.remove_from_scene_layer(&scene.layers.main);
+            label.add_to_scene_layer(&scene.layers.panel_text);
+            label.frp.set_color_all.emit(text_color);
+            label.frp.set_default_color.emit(text_color);
+        }
 
-        Self {logger,display_object,root,background,label,events,processes,next_process_id,camera}
-            .init()
+        Self {logger,display_object,event_root,event_background,event_label,process_root
+            ,process_background,process_label,spinner,events,processes,next_process_id,stacking
+            ,camera}.init()
     }
 
     fn init(self) -> Self {
-        self.display_object.add_child(&self.root);
-        self.root.add_child(&self.background);
-        self.root.add_child(&self.label);
+        self.display_object.add_child(&self.event_root);
+        self.event_root.add_child(&self.event_background);
+        self.event_root.add_child(&self.event_label);
+        self.display_object.add_child(&self.process_root);
+        self.process_root.add_child(&self.process_background);
+        self.process_root.add_child(&self.process_label);
+        self.process_root.add_child(&self.spinner);
 
         self.update_layout();
         self.camera_changed();
@@ -196,25 +260,42 @@ impl Model {
 
     fn camera_changed(&self) {
         let screen = self.camera.screen();
-        let x = -screen.width/2.0 + MARGIN;
-        let y = -screen.height/2.0 + MARGIN;
-        self.root.set_position_x(x.round());
-        self.root.set_position_y(y.round());
+        let left   = -screen.width/2.0 + MARGIN;
+        let right  = screen.width/2.0 - MARGIN;
+        let bottom = -screen.height/2.0 + MARGIN;
+        self.event_root.set_position_xy(Vector2(left.round(),bottom.round()));
+        self.process_root.set_position_xy(Vector2(right.round(),bottom.round()));
     }
 
     fn update_layout(&self) {
-        self.label.set_position_x(PADDING);
-        self.label.set_position_y(HEIGHT/2.0 + TEXT_SIZE/2.0);
-
-        let bg_width = if self.label.width.value() > 0.0 {
-            PADDING + self.label.width.value() + PADDING
+        self.event_label.set_position_x(PADDING);
+        self.event_label.set_position_y(HEIGHT/2.0 + TEXT_SIZE/2.0);
+        let event_width = self.event_label.width.value();
+        let event_width = if event_width > 0.0 { PADDING + event_width + PADDING } else { 0.0 };
+        Self::set_background_size(&self.event_background,event_width);
+        self.event_background.set_position_x(event_width/2.0);
+
+        // The process area is aligned to the right edge of the screen.
+        let any_process   = !self.processes.borrow().is_empty();
+        let process_width = if any_process {
+            PADDING + SPINNER_SIZE + SPINNER_GAP + self.process_label.width.value() + PADDING
         } else {
             0.0
         };
-        let bg_height = HEIGHT;
-        self.background.size.set(Vector2(bg_width+2.0*MAGIC_SHADOW_MARGIN,
-            bg_height+2.0*MAGIC_SHADOW_MARGIN));
-        self.background.set_position(Vector3(bg_width/2.0,bg_height/2.0,0.0));
+        let spinner_size = if any_process { SPINNER_SIZE } else { 0.0 };
+        self.spinner.size.set(Vector2(spinner_size,spinner_size));
+        let spinner_x    = PADDING + SPINNER_SIZE/2.0 - process_width;
+        self.spinner.set_position_xy(Vector2(spinner_x,HEIGHT/2.0));
+        self.process_label.set_position_x(PADDING + SPINNER_SIZE + SPINNER_GAP - process_width);
+        self.process_label.set_position_y(HEIGHT/2.0 + TEXT_SIZE/2.0);
+        Self::set_background_size(&self.process_background,process_width);
+        self.process_background.set_position_x(-process_width/2.0);
+    }
+
+    fn set_background_size(background:&background::View, width:f32) {
+        let shadow_margin = 2.0 * MAGIC_SHADOW_MARGIN;
+        background.size.set(Vector2(width + shadow_margin,HEIGHT + shadow_margin));
+        background.set_position_y(HEIGHT/2.0);
     }
 
     fn add_event(&self, label:&event::Label) -> event::Id {
@@ -224,23 +305,63 @@ impl Model {
         new_id
     }
 
+    fn set_event_label(&self, label:String) {
+        self.event_label.set_content(label);
+    }
+
     fn add_process(&self, label:&process::Label) -> process::Id {
         let mut processes       = self.processes.borrow_mut();
         let mut next_process_id = self.next_process_id.borrow_mut();
         let new_id              = *next_process_id;
         *next_process_id        = next_process_id.next();
-        processes.insert(new_id,label.clone_ref());
+        processes.push((new_id,label.clone_ref()));
         new_id
     }
 
     /// Returns true if there was process with given id.
     fn finish_process(&self, id:process::Id) -> bool {
-        self.processes.borrow_mut().remove(&id).is_some()
+        let mut processes = self.processes.borrow_mut();
+        let index         = processes.iter().position(|(process_id,_)| *process_id == id);
+        index.map(|index| processes.remove(index)).is_some()
     }
 
-    /// Returns empty string if no event received so far.
-    fn last_event_message(&self) -> event::Label {
-        self.events.borrow().last().cloned().unwrap_or_default()
+    fn set_process_stacking(&self, stacking:process::Stacking) {
+        self.stacking.set(stacking);
+    }
+
+    fn running_processes(&self) -> usize {
+        self.processes.borrow().len()
+    }
+
+    /// Choose the displayed process according to the stacking policy and update the process label.
+    /// Returns the id of the displayed process, or `None` if no process is running.
+    fn update_displayed_process(&self) -> Option<process::Id> {
+        let displayed = {
+            let processes = self.processes.borrow();
+            let displayed = match self.stacking.get() {
+                process::Stacking::Latest   => processes.last(),
+                process::Stacking::Earliest => processes.first(),
+            };
+            let others = processes.len().saturating_sub(1);
+            displayed.map(|(id,label)| {
+                let label   = AsRef::<ImString>::as_ref(label);
+                let content = if others > 0 {
+                    format!("{} (+{} more)",label,others)
+                } else {
+                    label.to_string()
+                };
+                (*id,content)
+            })
+        };
+        let content = displayed.as_ref().map(|(_,content)| content.clone()).unwrap_or_default();
+        self.process_label.set_content(content);
+        self.update_layout();
+        displayed.map(|(id,_)| id)
+    }
+
+    fn spin(&self, time:f32) {
+        let turns = time / 1000.0 * SPINNER_SPEED;
+        self.spinner.set_rotation_z(-turns * 2.0 * std::f32::consts::PI);
     }
 
     fn clear_all(&self) {
@@ -258,7 +379,6 @@ impl Model {
 /// The StatusBar component view.
 ///
 /// The status bar gathers information about events and processes occurring in the Application.
-// TODO: This is a stub. Extend it when doing https://github.com/enso-org/ide/issues/1193
 #[derive(Clone,CloneRef,Debug)]
 pub struct View {
     frp   : Frp,
@@ -273,43 +393,52 @@ impl View {
         let network     = &frp.network;
         let scene       = app.display.scene();
 
+        let event_timeout = enso_frp::io::timer::timeout(network,EVENT_DISPLAY_TIME_MS);
+
         enso_frp::extend! { network
-            event_added       <- frp.add_event.map(f!((label) model.add_event(label)));
-            process_added     <- frp.add_process.map(f!((label) model.add_process(label)));
-            _process_finished <- frp.finish_process.filter_map(f!((id)
-                model.finish_process(*id).as_some(*id)
-            ));
-            displayed_process_finished <- frp.finish_process
-                .map2(&frp.output.displayed_process, |fin,dis|(*fin,*dis))
-                .filter(|(fin,dis)| dis.contains(fin));
+            eval_ frp.clear_all (model.clear_all());
 
-            label_after_adding_event <- frp.add_event.map(
-                |label| AsRef::<ImString>::as_ref(label).clone_ref()
-            );
-            label_after_adding_process <- frp.add_process.map(
-                |label| AsRef::<ImString>::as_ref(label).clone_ref()
-            );
-            label_after_finishing_process <- displayed_process_finished.map(
-                f_!([model] AsRef::<ImString>::as_ref(&model.last_event_message()).clone_ref())
+
+            // === Events ===
+
+            event_added  <- frp.add_event.map(f!((label) model.add_event(label)));
+            event_hidden <- any(&event_timeout.expired,&frp.clear_all);
+            eval frp.add_event ((label)
+                model.set_event_label(AsRef::<ImString>::as_ref(label).to_string())
             );
+            eval_ event_hidden (model.set_event_label(default()));
+            event_timeout.restart <+ event_added.constant(());
+            event_timeout.cancel  <+ frp.clear_all;
 
-            label <- any(label_after_adding_event,label_after_adding_process,label_after_finishing_process);
-            eval label ((label) model.label.set_content(label.to_string()));
+            frp.source.last_event      <+ event_added;
+            frp.source.displayed_event <+ event_added.map(|id| Some(*id));
+            frp.source.displayed_event <+ event_hidden.constant(None);
 
-            eval_ frp.clear_all (model.clear_all());
 
-            frp.source.last_event   <+ event_added;
-            frp.source.last_process <+ process_added;
+            // === Processes ===
 
-            frp.source.displayed_event <+ event_added.map(|id| Some(*id));
-            frp.source.displayed_event <+ process_added.constant(None);
-            frp.source.displayed_event <+ frp.clear_all.constant(None);
-            frp.source.displayed_process <+ process_added.map(|id| Some(*id));
-            frp.source.displayed_process <+ event_added.constant(None);
-            frp.source.displayed_process <+ displayed_process_finished.constant(None);
-            frp.source.displayed_process <+ frp.clear_all.constant(None);
-
-            eval_ model.label.output.width (model.update_layout());
+            process_added     <- frp.add_process.map(f!((label) model.add_process(label)));
+            process_finished  <- frp.finish_process.filter(f!((id) model.finish_process(*id)));
+            stacking_changed  <- frp.set_process_stacking.map(f!((stacking)
+                model.set_process_stacking(*stacking)
+            ));
+            processes_changed <- any_(process_added,process_finished,stacking_changed,
+                frp.clear_all);
+            frp.source.last_process      <+ process_added;
+            frp.source.displayed_process <+ processes_changed.map(f_!(
+                model.update_displayed_process()
+            ));
+            frp.source.running_processes <+ processes_changed.map(f_!(model.running_processes()));
+
+            any_process_running <- frp.running_processes.map(|count| *count > 0);
+            spin_time           <- scene.frp.frame_time.gate(&any_process_running);
+            eval spin_time ((time) model.spin(*time));
+
+
+            // === Layout ===
+
+            eval_ model.event_label.output.width   (model.update_layout());
+            eval_ model.process_label.output.width (model.update_layout());
             eval_ scene.frp.camera_changed (model.camera_changed());
         }
 
@@ -0,0 +1,146 @@
+//! Benchmarks of the emit throughput of the core FRP nodes. They measure the cost of propagating a
+//! single event through chains of nodes, deep and wide networks, and the overhead of caching the
+//! propagated values, so the changes to `NodeData::emit_event` can be validated against
+//! performance regressions.
+//!
+//! Run them with `cargo bench -p enso-frp`.
+
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use enso_frp as frp;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The numbers of nodes in the benchmarked chains.
+const CHAIN_LENGTHS : &[usize] = &[1,10,100];
+/// The number of nodes in the deep network.
+const DEEP_NETWORK_DEPTH : usize = 1000;
+/// The numbers of nodes directly connected to the source of the fan-out network.
+const FAN_OUT_WIDTHS : &[usize] = &[10,100,1000];
+/// The number of nodes in the chains comparing the caching policies.
+const CACHING_CHAIN_LENGTH : usize = 100;
+
+
+
+// ===============
+// === Helpers ===
+// ===============
+
+/// Create a chain of `length` nodes, each created with the `node` function from the previous one.
+/// Returns the source of the chain.
+fn chain
+( network : &frp::Network
+, length  : usize
+, node    : impl Fn(&frp::Network,&frp::Stream<usize>) -> frp::Stream<usize>
+) -> frp::Source<usize> {
+    let source     = network.source::<usize>("source");
+    let mut stream = network.map("start",&source,|value| *value);
+    for _ in 0..length {
+        stream = node(network,&stream);
+    }
+    source
+}
+
+/// Benchmark emitting an event from the sources of the chains of all the `CHAIN_LENGTHS`.
+fn bench_chains
+( c     : &mut Criterion
+, name  : &str
+, setup : impl Fn(&frp::Network,usize) -> frp::Source<usize>
+) {
+    let mut group = c.benchmark_group(name);
+    for length in CHAIN_LENGTHS {
+        group.bench_with_input(BenchmarkId::from_parameter(length),length,|b,length| {
+            let network = frp::Network::new("bench");
+            let source  = setup(&network,*length);
+            b.iter(|| source.emit(black_box(1)))
+        });
+    }
+    group.finish();
+}
+
+
+
+// ==================
+// === Benchmarks ===
+// ==================
+
+fn map_chain(c:&mut Criterion) {
+    bench_chains(c,"map_chain",|network,length| {
+        chain(network,length,|network,stream| network.map("map",stream,|value| value + 1))
+    })
+}
+
+fn merge_chain(c:&mut Criterion) {
+    bench_chains(c,"merge_chain",|network,length| {
+        let idle = network.source::<usize>("idle");
+        chain(network,length,move |network,stream| network.any("merge",stream,&idle))
+    })
+}
+
+fn gate_chain(c:&mut Criterion) {
+    bench_chains(c,"gate_chain",|network,length| {
+        let open   = network.source::<bool>("open");
+        let source = chain(network,length,|network,stream| network.gate("gate",stream,&open));
+        open.emit(true);
+        source
+    })
+}
+
+fn sample_chain(c:&mut Criterion) {
+    bench_chains(c,"sample_chain",|network,length| {
+        let value  = network.source::<usize>("value");
+        let source = chain(network,length,|network,stream| network.sample("sample",&value,stream));
+        value.emit(1);
+        source
+    })
+}
+
+fn deep_network(c:&mut Criterion) {
+    let network = frp::Network::new("bench");
+    let source  = chain(&network,DEEP_NETWORK_DEPTH,|network,stream| {
+        network.map("map",stream,|value| value + 1)
+    });
+    c.bench_function("deep_network",|b| b.iter(|| source.emit(black_box(1))));
+}
+
+fn wide_fan_out(c:&mut Criterion) {
+    let mut group = c.benchmark_group("wide_fan_out");
+    for width in FAN_OUT_WIDTHS {
+        group.bench_with_input(BenchmarkId::from_parameter(width),width,|b,width| {
+            let network = frp::Network::new("bench");
+            let source  = network.source::<usize>("source");
+            for _ in 0..*width {
+                network.map("map",&source,|value| value + 1);
+            }
+            b.iter(|| source.emit(black_box(1)))
+        });
+    }
+    group.finish();
+}
+
+fn caching(c:&mut Criterion) {
+    let mut group = c.benchmark_group("caching");
+    for enabled in &[false,true] {
+        group.bench_with_input(BenchmarkId::from_parameter(enabled),enabled,|b,enabled| {
+            let network = frp::Network::new("bench");
+            let source  = chain(&network,CACHING_CHAIN_LENGTH,|network,stream| {
+                let stream = network.map("map",stream,|value| value + 1);
+                stream.cache_latest(*enabled);
+                stream
+            });
+            b.iter(|| source.emit(black_box(1)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches,map_chain,merge_chain,gate_chain,sample_chain,deep_network,wide_fan_out
+    ,caching);
+criterion_main!(benches);
@@ -0,0 +1,74 @@
+//! Control over caching the values flowing through the FRP nodes.
+//!
+//! By default, a node clones every emitted value into its cache only while some other node is
+//! watching it (see the docs of `NodeData`). The [`CachePolicy`] allows keeping the latest value
+//! cached regardless of the watchers, which is what the nodes sampled from outside of the network
+//! (like `Sampler`) need. In debug builds, the clones made to fill the caches can be counted per
+//! node label, to find the hot paths which clone large values needlessly.
+
+use crate::prelude::*;
+
+use crate::node::*;
+
+
+
+// ===================
+// === CachePolicy ===
+// ===================
+
+/// Describes when a node clones the emitted values into its cache.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum CachePolicy {
+    /// Cache the values only while the node is watched by other nodes.
+    Watched,
+    /// Always cache the latest value.
+    Always,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self::Watched
+    }
+}
+
+
+
+// =====================
+// === Clone Counter ===
+// =====================
+
+thread_local! {
+    static CLONE_COUNTS : RefCell<Option<HashMap<Label,usize>>> = RefCell::new(None);
+}
+
+/// Start counting the clones made to fill the node caches, dropping the unfinished counts, if any.
+pub fn start_counting_clones() {
+    if cfg!(debug_assertions) {
+        CLONE_COUNTS.with(|counts| *counts.borrow_mut() = Some(default()))
+    }
+}
+
+/// Stop counting the clones and return their numbers by the node labels, the most cloning nodes
+/// first.
+pub fn stop_counting_clones() -> Vec<(Label,usize)> {
+    let counts     = CLONE_COUNTS.with(|counts| counts.borrow_mut().take());
+    let mut counts = counts.unwrap_or_default().into_iter().collect_vec();
+    counts.sort_by(|(lhs_label,lhs),(rhs_label,rhs)| rhs.cmp(lhs).then(lhs_label.cmp(rhs_label)));
+    counts
+}
+
+/// Check whether the clones are being counted.
+pub fn is_counting_clones() -> bool {
+    CLONE_COUNTS.with(|counts| counts.borrow().is_some())
+}
+
+/// Count a clone of the value emitted by the node of the given label.
+pub(crate) fn record_clone(label:Label) {
+    if cfg!(debug_assertions) {
+        CLONE_COUNTS.with(|counts| {
+            if let Some(counts) = counts.borrow_mut().as_mut() {
+                *counts.entry(label).or_default() += 1;
+            }
+        })
+    }
+}
@@ -1,4 +1,5 @@
 //! Generic data utilities.
 
 pub mod bitfield;
+pub mod fallible;
 pub mod watch;
@@ -0,0 +1,78 @@
+//! Results passed through the FRP streams.
+//!
+//! The data passed through the streams must have a default value, which `Result` does not have.
+//! The `Fallible` wraps it, so the operations which may fail can emit their results, instead of
+//! panicking or dropping the failed events silently. The `Network` provides the combinators
+//! working on the `Fallible` streams, like `map_ok`, `unwrap_or_else`, or `split_result`.
+//!
+//! By convention, a component exposing a fallible operation emits the successful results in one
+//! output and the errors in a separate output, named like the first one with the `_error` suffix,
+//! for example `project_opened` and `project_opened_error`. The `split_result` creates such a pair
+//! of streams from a `Fallible` stream.
+
+use crate::prelude::*;
+
+
+
+// ================
+// === Fallible ===
+// ================
+
+/// A `Result` which can be passed through the FRP streams. The default value is `Ok` with the
+/// default value of `T`.
+#[derive(Clone,Debug,Eq,PartialEq)]
+pub struct Fallible<T,E> {
+    result : Result<T,E>
+}
+
+impl<T,E> Fallible<T,E> {
+    /// Constructor.
+    pub fn new(result:Result<T,E>) -> Self {
+        Self {result}
+    }
+
+    /// Constructor of the successful result.
+    pub fn ok(value:T) -> Self {
+        Self::new(Ok(value))
+    }
+
+    /// Constructor of the failed result.
+    pub fn err(error:E) -> Self {
+        Self::new(Err(error))
+    }
+
+    /// The wrapped result.
+    pub fn as_result(&self) -> &Result<T,E> {
+        &self.result
+    }
+
+    /// Unwrap the result.
+    pub fn into_result(self) -> Result<T,E> {
+        self.result
+    }
+}
+
+impl<T,E> Deref for Fallible<T,E> {
+    type Target = Result<T,E>;
+    fn deref(&self) -> &Self::Target {
+        &self.result
+    }
+}
+
+impl<T:Default,E> Default for Fallible<T,E> {
+    fn default() -> Self {
+        Self::ok(default())
+    }
+}
+
+impl<T,E> From<Result<T,E>> for Fallible<T,E> {
+    fn from(result:Result<T,E>) -> Self {
+        Self::new(result)
+    }
+}
+
+impl<T,E> From<Fallible<T,E>> for Result<T,E> {
+    fn from(fallible:Fallible<T,E>) -> Self {
+        fallible.into_result()
+    }
+}
@@ -0,0 +1,190 @@
+//! Runtime inspection of FRP networks, used by the debugging tools.
+//!
+//! In debug builds every network registers itself here when created. While the inspection is
+//! enabled, every event emitted by a node is counted, and the recent values of the traced nodes
+//! are remembered. In release builds the registry stays empty and the hooks are no-ops, so they
+//! cost nothing.
+
+use crate::prelude::*;
+
+use crate::network::*;
+use crate::node::*;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The number of recent values remembered for every traced node.
+pub const TRACE_LENGTH : usize = 16;
+
+
+
+// ================
+// === NodeInfo ===
+// ================
+
+/// Description of a single node of the network topology.
+#[derive(Clone,Debug)]
+#[allow(missing_docs)]
+pub struct NodeInfo {
+    pub id          : Id,
+    pub label       : Label,
+    pub output_type : String,
+    /// Nodes sending events or behaviors to this node. Filled only for the links registered in the
+    /// network, see `Network::register_link`.
+    pub inputs      : Vec<Id>,
+}
+
+
+
+// ================
+// === Registry ===
+// ================
+
+#[derive(Debug,Default)]
+struct Registry {
+    networks : Vec<WeakNetwork>,
+    counts   : HashMap<Id,usize>,
+    traces   : HashMap<Id,VecDeque<String>>,
+}
+
+thread_local! {
+    static ENABLED  : Cell<bool>        = Cell::new(false);
+    static REGISTRY : RefCell<Registry> = RefCell::new(default());
+}
+
+
+// === Networks ===
+
+/// Register a newly created network. Called by `Network::new`.
+pub fn register_network(network:&Network) {
+    if cfg!(debug_assertions) {
+        REGISTRY.with(|registry| {
+            let mut registry = registry.borrow_mut();
+            registry.networks.retain(|network| network.upgrade().is_some());
+            registry.networks.push(network.downgrade());
+        })
+    }
+}
+
+/// All the living networks, in the order of their creation.
+pub fn networks() -> Vec<Network> {
+    REGISTRY.with(|registry| {
+        registry.borrow().networks.iter().filter_map(|network| network.upgrade()).collect()
+    })
+}
+
+/// The living networks whose label contains the given pattern, ignoring the letter case.
+pub fn find_networks(pattern:&str) -> Vec<Network> {
+    let pattern = pattern.to_lowercase();
+    networks().into_iter().filter(|network| network.label().to_lowercase().contains(&pattern))
+        .collect()
+}
+
+
+// === Profiling ===
+
+/// Enable or disable counting and tracing the events. Disabling the inspection forgets all the
+/// gathered counts and traces.
+pub fn set_enabled(enabled:bool) {
+    ENABLED.with(|t| t.set(enabled));
+    if !enabled {
+        REGISTRY.with(|registry| {
+            let mut registry = registry.borrow_mut();
+            registry.counts.clear();
+            registry.traces.clear();
+        })
+    }
+}
+
+/// Check whether the events are being counted and traced.
+pub fn is_enabled() -> bool {
+    ENABLED.with(|t| t.get())
+}
+
+/// Record the event emitted by the node. Called by every node of every network on emitting an
+/// event.
+pub fn record_event<T:Debug>(id:Id, value:&T) {
+    if cfg!(debug_assertions) && is_enabled() {
+        REGISTRY.with(|registry| {
+            let mut registry = registry.borrow_mut();
+            *registry.counts.entry(id).or_default() += 1;
+            if let Some(trace) = registry.traces.get_mut(&id) {
+                if trace.len() >= TRACE_LENGTH {
+                    trace.pop_front();
+                }
+                trace.push_back(format!("{:?}",value));
+            }
+        })
+    }
+}
+
+/// The number of events emitted by the node since the inspection was enabled.
+pub fn event_count(id:Id) -> usize {
+    REGISTRY.with(|registry| registry.borrow().counts.get(&id).copied().unwrap_or_default())
+}
+
+/// Start remembering the recent values emitted by the node.
+pub fn trace(id:Id) {
+    REGISTRY.with(|registry| { registry.borrow_mut().traces.entry(id).or_default(); })
+}
+
+/// Stop remembering the recent values emitted by the node and forget the remembered ones.
+pub fn untrace(id:Id) {
+    REGISTRY.with(|registry| { registry.borrow_mut().traces.remove(&id); })
+}
+
+/// Check whether the recent values emitted by the node are remembered.
+pub fn is_traced(id:Id) -> bool {
+    REGISTRY.with(|registry| registry.borrow().traces.contains_key(&id))
+}
+
+/// The recent values emitted by the traced node, from the oldest to the newest one, formatted
+/// with their `Debug` implementation.
+pub fn recent_values(id:Id) -> Vec<String> {
+    REGISTRY.with(|registry| {
+        let registry = registry.borrow();
+        registry.traces.get(&id).map(|trace| trace.iter().cloned().collect()).unwrap_or_default()
+    })
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(all(test,debug_assertions))]
+mod tests {
+    use super::*;
+    use crate as frp;
+
+    #[test]
+    fn counting_and_tracing_events() {
+        set_enabled(true);
+        frp::new_network! { network
+            source <- source::<usize>();
+            double <- source.map(|t| t * 2);
+        }
+        assert!(find_networks("NET").iter().any(|t| t.id() == network.id()));
+        let topology  = network.topology();
+        let double_id = topology.iter().find(|node| node.label.contains("::double:")).unwrap().id;
+        trace(double_id);
+        for value in 0..TRACE_LENGTH + 2 {
+            source.emit(value);
+        }
+        assert_eq!(event_count(double_id),TRACE_LENGTH + 2);
+        let values = recent_values(double_id);
+        assert_eq!(values.len(),TRACE_LENGTH);
+        assert_eq!(values.last().map(|t| t.as_str()),Some("34"));
+
+        untrace(double_id);
+        assert!(recent_values(double_id).is_empty());
+        set_enabled(false);
+        assert_eq!(event_count(double_id),0);
+        drop(network);
+        assert!(networks().is_empty());
+    }
+}
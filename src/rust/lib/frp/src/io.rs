@@ -1,7 +1,12 @@
 //! Root module for Input / Output FRP bindings
 
+pub mod clipboard;
+pub mod frames;
 pub mod js;
 pub mod keyboard;
 pub mod mouse;
+pub mod timer;
 
+pub use clipboard::Clipboard;
+pub use frames::Frames;
 pub use mouse::Mouse;
@@ -0,0 +1,48 @@
+//! Clipboard FRP bindings. Bridges the asynchronous browser
+//! [Clipboard API](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard_API) into FRP
+//! streams, so the components do not need to talk to the web APIs directly. When the Clipboard API
+//! is not available or fails, the deprecated `document.execCommand` is used for writing, and the
+//! text of the last `paste` event for reading. See the `ensogl_system_web::clipboard` module docs
+//! for the details and limitations.
+
+use crate::prelude::*;
+
+use crate as frp;
+
+use ensogl_system_web::clipboard;
+
+
+
+// =================
+// === Clipboard ===
+// =================
+
+/// Clipboard FRP bindings. The endpoints are created in the network passed to the constructor,
+/// and live as long as that network. Please note that the browsers allow accessing the clipboard
+/// only from the user-initiated event callbacks, like mouse or key presses, so `copy` and
+/// `request_paste` should be emitted as a direct reaction to such events.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Clipboard {
+    /// Write the text to the clipboard. The text may appear in the clipboard with a delay, for
+    /// example when the browser asks the user for the permission first.
+    pub copy          : frp::Any<String>,
+    /// Read the text from the clipboard. The text will be emitted by `paste` once it is read.
+    pub request_paste : frp::Any,
+    /// The text read from the clipboard in response to `request_paste`.
+    pub paste         : frp::Stream<String>,
+}
+
+impl Clipboard {
+    /// Constructor.
+    pub fn new(network:&frp::Network) -> Self {
+        frp::extend! { network
+            copy          <- any_mut::<String>();
+            request_paste <- any_mut::<()>();
+            pasted        <- source::<String>();
+            eval copy ((text) clipboard::write_text(text));
+            eval_ request_paste (clipboard::read_text(f!((text) pasted.emit(text))));
+        }
+        let paste = pasted.into();
+        Self {copy,request_paste,paste}
+    }
+}
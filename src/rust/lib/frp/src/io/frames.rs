@@ -0,0 +1,85 @@
+//! Animation frame FRP bindings. Emits an event on every
+//! [requestAnimationFrame](https://developer.mozilla.org/en-US/docs/Web/API/window/requestAnimationFrame)
+//! tick, so the animation-coupled logic, like redrawing the shapes or tracking the cursor, can be
+//! declared in FRP. Use it together with the `sample_on_frame` node to process the behavior
+//! changes at most once per frame.
+
+use crate::prelude::*;
+
+use crate as frp;
+
+use ensogl_system_web as web;
+use wasm_bindgen::prelude::Closure;
+
+
+
+// =================
+// === FrameLoop ===
+// =================
+
+/// The `requestAnimationFrame` loop, running until dropped.
+#[derive(Default)]
+struct FrameLoop {
+    on_frame  : RefCell<Option<Closure<dyn FnMut(f64)>>>,
+    handle_id : Cell<i32>,
+}
+
+impl Debug for FrameLoop {
+    fn fmt(&self, f:&mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,"FrameLoop")
+    }
+}
+
+impl FrameLoop {
+    fn request_frame(&self) {
+        if let Some(on_frame) = &*self.on_frame.borrow() {
+            self.handle_id.set(web::request_animation_frame(on_frame));
+        }
+    }
+}
+
+impl Drop for FrameLoop {
+    fn drop(&mut self) {
+        web::cancel_animation_frame(self.handle_id.get());
+    }
+}
+
+
+
+// ==============
+// === Frames ===
+// ==============
+
+/// Animation frame FRP bindings. The endpoints are created in the network passed to the
+/// constructor. The frame loop runs as long as this structure (or any of its clones) is alive.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Frames {
+    /// The time of the current frame, in milliseconds, emitted on every frame.
+    pub time   : frp::Stream<f32>,
+    /// Emitted on every frame.
+    pub tick   : frp::Stream,
+    frame_loop : Rc<FrameLoop>,
+}
+
+impl Frames {
+    /// Constructor. Starts the frame loop.
+    pub fn new(network:&frp::Network) -> Self {
+        frp::extend! { network
+            time <- source::<f32>();
+            tick <- time.constant(());
+        }
+        let frame_loop = Rc::new(FrameLoop::default());
+        let weak_loop  = Rc::downgrade(&frame_loop);
+        let frame_time = time.clone_ref();
+        let on_frame   = move |time:f64| {
+            if let Some(frame_loop) = weak_loop.upgrade() {
+                frame_time.emit(time as f32);
+                frame_loop.request_frame();
+            }
+        };
+        *frame_loop.on_frame.borrow_mut() = Some(Closure::new(on_frame));
+        frame_loop.request_frame();
+        let time = time.into();
+        Self {time,tick,frame_loop}
+    }
+}
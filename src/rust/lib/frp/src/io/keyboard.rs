@@ -245,6 +245,11 @@ impl KeyboardModel {
         self.is_down(&Key::Alt(Side::Left)) || self.is_down(&Key::Alt(Side::Right))
     }
 
+    /// Check whether the shift key is currently pressed.
+    pub fn is_shift_down(&self) -> bool {
+        self.is_down(&Key::Shift(Side::Left)) || self.is_down(&Key::Shift(Side::Right))
+    }
+
     /// Checks whether the provided key is currently pressed.
     pub fn is_down(&self, key:&Key) -> bool {
         self.pressed_keys.borrow().contains(key)
@@ -340,6 +345,7 @@ pub struct Keyboard {
     pub is_meta_down     : frp::Stream<bool>,
     pub is_control_down  : frp::Stream<bool>,
     pub is_alt_down      : frp::Stream<bool>,
+    pub is_shift_down    : frp::Stream<bool>,
     pub is_modifier_down : frp::Stream<bool>,
 }
 
@@ -361,12 +367,13 @@ impl Keyboard {
             change           <- any(&down,&up).constant(());
             is_control_down  <- change.map(f_!(model.is_control_down()));
             is_alt_down      <- change.map(f_!(model.is_alt_down()));
+            is_shift_down    <- change.map(f_!(model.is_shift_down()));
             is_modifier_down <- all_with3(&is_meta_down,&is_control_down,&is_alt_down,
                 |m,c,a| *m || *c || *a
             );
         }
         Keyboard {model,network,source,down,up,is_meta_down,is_control_down,is_alt_down
-            ,is_modifier_down}
+            ,is_shift_down,is_modifier_down}
     }
 }
 
@@ -78,6 +78,45 @@ impl Default for Button {
 
 
 
+// =================
+// === PointerId ===
+// =================
+
+/// Identifier of the pointer (a mouse, a pen, or a single touch contact) which caused an event.
+/// It allows distinguishing between events of simultaneous touch pointers. See
+/// https://developer.mozilla.org/en-US/docs/Web/API/PointerEvent/pointerId
+#[derive(Clone,Copy,Debug,Default,Eq,Hash,PartialEq)]
+pub struct PointerId(pub i32);
+
+
+
+// =============
+// === Wheel ===
+// =============
+
+/// A single scroll of the mouse wheel or the trackpad. The `delta` is given in pixels, in the
+/// screen coordinates, so positive values mean scrolling right and down. The `shift` and `ctrl`
+/// describe the modifier keys held during the scroll. The `ctrl` is set also for the command key
+/// and for the trackpad pinch gestures, which the browsers report as scrolls with control.
+#[derive(Clone,Copy,Debug,Default,PartialEq)]
+#[allow(missing_docs)]
+pub struct Wheel {
+    pub delta : Vector2<f32>,
+    pub shift : bool,
+    pub ctrl  : bool,
+}
+
+impl Wheel {
+    /// The scroll distance along both axes. A vertical scroll with shift scrolls horizontally, as
+    /// the mouse wheels cannot scroll horizontally on their own. Some browsers swap the axes
+    /// themselves, in which case the delta is left unchanged.
+    pub fn pan(&self) -> Vector2<f32> {
+        if self.shift && self.delta.x == 0.0 { Vector2::new(self.delta.y,0.0) } else { self.delta }
+    }
+}
+
+
+
 // ==================
 // === ButtonMask ===
 // ==================
@@ -136,13 +175,40 @@ impl From<&ButtonMask> for ButtonMask { fn from(t:&ButtonMask) -> Self {*t} }
 // =============
 
 /// Mouse FRP bindings.
+///
+/// The `pointer_id` is emitted before every `position`, `down`, and `up` event, so it always
+/// describes the pointer which caused the event. Emitting `capture_pointer` requests all further
+/// events of that pointer to be delivered even if it leaves the canvas, until `release_pointer` is
+/// emitted or the pointer is released.
+///
+/// Emitting `lock_pointer` requests hiding the cursor and locking it in place, until
+/// `unlock_pointer` is emitted or the user presses escape. The platform bindings report the lock
+/// state changes with `pointer_locked`. While the pointer is locked, the `position` does not
+/// change, but the `movement` is still emitted on every move. The `movement` gives the raw distance
+/// the mouse moved since the previous event, in the same units and direction as the `translation`,
+/// but is not clamped by the screen edges, so it allows implementing drags of unlimited length.
+///
+/// The `wheel` is emitted on every scroll, after the `wheel_scroll` describing it. The scrolls
+/// with control are split into `wheel_zoom`, giving their vertical distance, while all the other
+/// scrolls are emitted by `wheel_pan`. See the `Wheel` docs.
 #[derive(Clone,CloneRef,Debug)]
 #[allow(missing_docs)]
 pub struct Mouse {
     pub network           : frp::Network,
+    pub pointer_id        : frp::Source<PointerId>,
+    pub capture_pointer   : frp::Source,
+    pub release_pointer   : frp::Source,
+    pub lock_pointer      : frp::Source,
+    pub unlock_pointer    : frp::Source,
+    pub pointer_locked    : frp::Source<bool>,
+    pub is_pointer_locked : frp::Stream<bool>,
+    pub movement          : frp::Source<Vector2<f32>>,
     pub up                : frp::Source<Button>,
     pub down              : frp::Source<Button>,
     pub wheel             : frp::Source,
+    pub wheel_scroll      : frp::Source<Wheel>,
+    pub wheel_pan         : frp::Stream<Vector2<f32>>,
+    pub wheel_zoom        : frp::Stream<f32>,
     pub up_0              : frp::Stream,
     pub up_1              : frp::Stream,
     pub up_2              : frp::Stream,
@@ -233,9 +299,20 @@ impl Mouse {
 impl Default for Mouse {
     fn default() -> Self {
         frp::new_network! { network
+            pointer_id    <- source();
+            capture_pointer <- source();
+            release_pointer <- source();
+            lock_pointer      <- source();
+            unlock_pointer    <- source();
+            pointer_locked    <- source();
+            is_pointer_locked <- pointer_locked.on_change();
+            movement      <- source();
             up            <- source();
             down          <- source();
             wheel         <- source();
+            wheel_scroll  <- source();
+            wheel_pan     <- wheel_scroll.filter(|t:&Wheel| !t.ctrl).map(|t|t.pan());
+            wheel_zoom    <- wheel_scroll.filter(|t:&Wheel| t.ctrl).map(|t|t.delta.y);
             position      <- source();
             prev_position <- position.previous();
             translation   <- position.map2(&prev_position,|t,s|t-s);
@@ -296,8 +373,13 @@ impl Default for Mouse {
 
             prev_button_mask <- button_mask.previous();
         };
+        // The position is sampled directly by the mouse users, not only through the network.
+        position.cache_latest(true);
         let button_mask = button_mask.into();
-        Self { network,up,down,wheel,up_0,up_1,up_2,up_3,up_4,up_primary,up_middle,up_secondary
+        Self { network,pointer_id,capture_pointer,release_pointer
+             , lock_pointer,unlock_pointer,pointer_locked,is_pointer_locked,movement
+             , up,down,wheel,wheel_scroll,wheel_pan,wheel_zoom
+             , up_0,up_1,up_2,up_3,up_4,up_primary,up_middle,up_secondary
              , down_0,down_1,down_2,down_3,down_4,down_primary,down_middle,down_secondary
              , is_up_0,is_up_1,is_up_2,is_up_3,is_up_4,is_up_primary,is_up_middle,is_up_secondary
              , is_down_0, is_down_1,is_down_2,is_down_3,is_down_4,is_down_primary,is_down_middle
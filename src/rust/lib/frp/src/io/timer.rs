@@ -0,0 +1,292 @@
+//! Timer FRP bindings. Provides the `interval` and `timeout` streams, so the time-dependent logic,
+//! like delaying an action until a key is held long enough, can be declared in FRP instead of
+//! comparing the timestamps of events. The timers are backed by the web
+//! [timers](https://developer.mozilla.org/en-US/docs/Web/API/WindowOrWorkerGlobalScope/setTimeout)
+//! and are stored in the network passed to the constructors, so they are cleared together with it.
+//!
+//! Native builds have no event loop to run the timers, so there the timers are checked by calling
+//! `run_due_timers`, which is meant to be used in tests.
+
+use crate::prelude::*;
+
+use crate as frp;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use backend::run_due_timers;
+
+
+
+// ===============
+// === Backend ===
+// ===============
+
+#[cfg(target_arch = "wasm32")]
+mod backend {
+    use ensogl_system_web as web;
+    use wasm_bindgen::prelude::Closure;
+
+    pub type Callback = Closure<dyn FnMut()>;
+
+    pub fn callback(f:impl FnMut()+'static) -> Callback {
+        Closure::new(f)
+    }
+
+    pub fn set_timer(callback:&Callback, ms:u32, repeat:bool) -> i32 {
+        let ms = ms as i32;
+        if repeat { web::set_interval(callback,ms) } else { web::set_timeout(callback,ms) }
+    }
+
+    pub fn clear_timer(handle:i32, repeat:bool) {
+        if repeat { web::clear_interval(handle) } else { web::clear_timeout(handle) }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod backend {
+    use crate::prelude::*;
+
+    use std::time::Duration;
+    use std::time::Instant;
+
+    pub type Callback = Rc<RefCell<dyn FnMut()>>;
+
+    struct Pending {
+        handle   : i32,
+        deadline : Instant,
+        period   : Option<Duration>,
+        callback : Weak<RefCell<dyn FnMut()>>,
+    }
+
+    thread_local! {
+        static PENDING     : RefCell<Vec<Pending>> = default();
+        static NEXT_HANDLE : Cell<i32>             = default();
+    }
+
+    pub fn callback(f:impl FnMut()+'static) -> Callback {
+        Rc::new(RefCell::new(f))
+    }
+
+    pub fn set_timer(callback:&Callback, ms:u32, repeat:bool) -> i32 {
+        let handle   = NEXT_HANDLE.with(|next| next.replace(next.get() + 1));
+        let duration = Duration::from_millis(ms.into());
+        let deadline = Instant::now() + duration;
+        let period   = repeat.as_some(duration);
+        let callback = Rc::downgrade(callback);
+        let timer    = Pending {handle,deadline,period,callback};
+        PENDING.with(|pending| pending.borrow_mut().push(timer));
+        handle
+    }
+
+    pub fn clear_timer(handle:i32, _repeat:bool) {
+        PENDING.with(|pending| pending.borrow_mut().retain(|timer| timer.handle != handle));
+    }
+
+    /// Run the callbacks of all the timers which are due, in the order of their deadlines. The
+    /// interval timers are scheduled again.
+    pub fn run_due_timers() {
+        let now = Instant::now();
+        let mut due = PENDING.with(|pending| {
+            let mut pending = pending.borrow_mut();
+            let mut due     = Vec::new();
+            for timer in pending.iter_mut().filter(|timer| timer.deadline <= now) {
+                due.push((timer.deadline,timer.callback.clone()));
+                if let Some(period) = timer.period {
+                    timer.deadline += period;
+                }
+            }
+            pending.retain(|timer| timer.deadline > now || timer.period.is_some());
+            due
+        });
+        due.sort_by_key(|(deadline,_)| *deadline);
+        for (_,callback) in due {
+            if let Some(callback) = callback.upgrade() {
+                (&mut *callback.borrow_mut())()
+            }
+        }
+    }
+}
+
+
+
+// =============
+// === Timer ===
+// =============
+
+/// A single timer, cleared when dropped.
+struct Timer {
+    ms       : u32,
+    repeat   : bool,
+    handle   : Cell<Option<i32>>,
+    callback : RefCell<Option<backend::Callback>>,
+}
+
+impl Debug for Timer {
+    fn fmt(&self, f:&mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,"Timer")
+    }
+}
+
+impl Timer {
+    /// Constructor. The timer calls `on_time` after the given time once started, or every given
+    /// time if `repeat` is set.
+    fn new(ms:u32, repeat:bool, mut on_time:impl FnMut()+'static) -> Rc<Self> {
+        let handle   = default();
+        let callback = default();
+        let timer    = Rc::new(Self {ms,repeat,handle,callback});
+        let weak     = Rc::downgrade(&timer);
+        let on_time  = move || {
+            if let Some(timer) = weak.upgrade() {
+                if !timer.repeat {
+                    timer.handle.set(None);
+                }
+                on_time()
+            }
+        };
+        *timer.callback.borrow_mut() = Some(backend::callback(on_time));
+        timer
+    }
+
+    /// Start the timer. If it is already running, it is started again.
+    fn start(&self) {
+        self.stop();
+        if let Some(callback) = &*self.callback.borrow() {
+            self.handle.set(Some(backend::set_timer(callback,self.ms,self.repeat)));
+        }
+    }
+
+    /// Stop the timer if it is running.
+    fn stop(&self) {
+        if let Some(handle) = self.handle.take() {
+            backend::clear_timer(handle,self.repeat);
+        }
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        self.stop()
+    }
+}
+
+
+
+// ================
+// === Interval ===
+// ================
+
+/// Stream emitting an event every given time. See `interval`.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Interval {
+    /// Emitted every time the interval passes.
+    pub tick : frp::Stream,
+}
+
+/// Create a stream emitting an event every `ms` milliseconds, starting from now. The timer is
+/// stored in the `network` and runs as long as the network is alive.
+pub fn interval(network:&frp::Network, ms:u32) -> Interval {
+    frp::extend! { network
+        tick <- source_();
+    }
+    let on_tick = tick.clone_ref();
+    let timer   = Timer::new(ms,true,move || on_tick.emit(()));
+    timer.start();
+    network.store(&timer);
+    let tick = tick.into();
+    Interval {tick}
+}
+
+
+
+// ===============
+// === Timeout ===
+// ===============
+
+/// Timeout FRP bindings. See `timeout`.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Timeout {
+    /// Start counting down the time. If the timeout is already running, the count starts again.
+    pub restart    : frp::Any,
+    /// Stop counting down the time without emitting `expired`.
+    pub cancel     : frp::Any,
+    /// Emitted once the time passes after the last `restart`, unless cancelled in the meantime.
+    pub expired    : frp::Stream,
+    /// Whether the time is being counted down.
+    pub is_running : frp::Stream<bool>,
+}
+
+/// Create a timeout emitting the `expired` event `ms` milliseconds after being restarted. The
+/// timer is stored in the `network` and is cleared together with it.
+pub fn timeout(network:&frp::Network, ms:u32) -> Timeout {
+    frp::extend! { network
+        expired <- source_();
+    }
+    let on_expired = expired.clone_ref();
+    let timer      = Timer::new(ms,false,move || on_expired.emit(()));
+    network.store(&timer);
+    frp::extend! { network
+        restart    <- any_mut::<()>();
+        cancel     <- any_mut::<()>();
+        eval_ restart (timer.start());
+        eval_ cancel  (timer.stop());
+        stopped    <- any(&cancel,&expired);
+        is_running <- bool(&stopped,&restart);
+    }
+    let expired = expired.into();
+    Timeout {restart,cancel,expired,is_running}
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(all(test,not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    #[test]
+    fn timeout_expires_once_after_restart() {
+        let network = frp::Network::new("network");
+        let timeout = timeout(&network,10);
+        frp::extend! { network
+            expired_count <- timeout.expired.count().sampler();
+            is_running    <- timeout.is_running.sampler();
+        }
+        sleep(Duration::from_millis(20));
+        run_due_timers();
+        assert_eq!(expired_count.value(),0);
+        timeout.restart.emit(());
+        assert!(is_running.value());
+        sleep(Duration::from_millis(20));
+        run_due_timers();
+        run_due_timers();
+        assert_eq!(expired_count.value(),1);
+        assert!(!is_running.value());
+        timeout.restart.emit(());
+        timeout.cancel.emit(());
+        sleep(Duration::from_millis(20));
+        run_due_timers();
+        assert_eq!(expired_count.value(),1);
+    }
+
+    #[test]
+    fn interval_is_cleared_with_network() {
+        let network  = frp::Network::new("network");
+        let interval = interval(&network,5);
+        let counter  = frp::Network::new("counter");
+        frp::extend! { counter
+            tick_count <- interval.tick.count().sampler();
+        }
+        sleep(Duration::from_millis(10));
+        run_due_timers();
+        assert_eq!(tick_count.value(),1);
+        drop(network);
+        sleep(Duration::from_millis(10));
+        run_due_timers();
+        assert_eq!(tick_count.value(),1);
+    }
+}
@@ -161,18 +161,28 @@
 
 #![recursion_limit="512"]
 
+pub mod cache;
 pub mod debug;
 pub mod data;
+pub mod inspector;
 pub mod io;
 pub mod macros;
 pub mod network;
 pub mod node;
 pub mod nodes;
+pub mod reentrancy;
+pub mod respawn;
 pub mod stream;
+pub mod trace;
 
+pub use cache::CachePolicy;
+pub use data::fallible::Fallible;
 pub use network::*;
 pub use node::*;
 pub use nodes::*;
+pub use reentrancy::ReentrancyPolicy;
+pub use respawn::Endpoint;
+pub use respawn::Respawnable;
 
 pub use ensogl_system_web as web;
 pub use stream::Stream;
@@ -210,6 +220,227 @@ mod network_mode_tests {
         source.emit(());
         assert_eq!(sampler.value(),2);
     }
+
+    #[test]
+    fn weak_source_lifetime() {
+        frp::new_network! { network
+            def source  = source::<usize>();
+            def sampler = source.sampler();
+        }
+        let weak_source = frp::WeakSource::new(&source);
+        assert!(weak_source.is_alive());
+        assert!(weak_source.emit_if_alive(1));
+        assert_eq!(sampler.value(),1);
+        drop(network);
+        assert!(!weak_source.is_alive());
+        assert!(!weak_source.emit_if_alive(2));
+    }
+
+    #[test]
+    fn sample_on_frame() {
+        frp::new_network! { network
+            value   <- source::<i32>();
+            frame   <- source::<()>();
+            sampled <- value.sample_on_frame(&frame);
+            count   <- sampled.count();
+            sampled <- sampled.sampler();
+            count   <- count.sampler();
+        }
+        frame.emit(());
+        assert_eq!(count.value(),0);
+        value.emit(1);
+        value.emit(2);
+        assert_eq!(count.value(),0);
+        frame.emit(());
+        assert_eq!((sampled.value(),count.value()),(2,1));
+        frame.emit(());
+        assert_eq!(count.value(),1);
+        value.emit(3);
+        frame.emit(());
+        assert_eq!((sampled.value(),count.value()),(3,2));
+    }
+
+    #[test]
+    fn high_arity_combinators() {
+        frp::new_network! { network
+            s1 <- source::<i32>();
+            s2 <- source::<i32>();
+            s3 <- source::<i32>();
+            s4 <- source::<i32>();
+            s5 <- source::<i32>();
+            s6 <- source::<i32>();
+            s7 <- source::<i32>();
+            s8 <- source::<i32>();
+            map8 <- s1.map8(&s2,&s3,&s4,&s5,&s6,&s7,&s8,
+                |a,b,c,d,e,f,g,h| a+b+c+d+e+f+g+h);
+            all_with7 <- all_with7(&s1,&s2,&s3,&s4,&s5,&s6,&s7,
+                |a,b,c,d,e,f,g| a+b+c+d+e+f+g);
+            all8 <- all(s1,s2,s3,s4,s5,s6,s7,s8);
+            map8      <- map8.sampler();
+            all_with7 <- all_with7.sampler();
+            all8      <- all8.sampler();
+        }
+        s2.emit(2);
+        s3.emit(3);
+        assert_eq!(map8.value(),0);
+        assert_eq!(all_with7.value(),5);
+        s8.emit(8);
+        s1.emit(1);
+        assert_eq!(map8.value(),14);
+        assert_eq!(all_with7.value(),6);
+        assert_eq!(all8.value(),(1,2,3,0,0,0,0,8));
+    }
+
+    #[test]
+    fn fallible_combinators() {
+        frp::new_network! { network
+            src     <- source::<frp::Fallible<i32,String>>();
+            doubled <- src.map_ok(|t| t * 2);
+            value   <- doubled.unwrap_or_else(|e| -(e.len() as i32));
+            value   <- value.sampler();
+        }
+        let (oks,errs) = network.split_result("split",&src);
+        frp::extend! { network
+            oks       <- oks.sampler();
+            err_count <- errs.count();
+            err_count <- err_count.sampler();
+        }
+        src.emit(frp::Fallible::ok(2));
+        assert_eq!(value.value(),4);
+        assert_eq!(oks.value(),2);
+        assert_eq!(err_count.value(),0);
+        src.emit(frp::Fallible::err("fail".into()));
+        assert_eq!(value.value(),-4);
+        assert_eq!(oks.value(),2);
+        assert_eq!(err_count.value(),1);
+    }
+
+    #[test]
+    fn on_change_skips_repeated_values() {
+        frp::new_network! { network
+            src     <- source::<i32>();
+            changed <- src.on_change();
+            count   <- changed.count();
+            count   <- count.sampler();
+        }
+        for value in &[0,1,1,2,2,2,1] {
+            src.emit(*value);
+        }
+        assert_eq!(count.value(),3);
+    }
+
+    #[test]
+    fn sampler_clones_every_value_once() {
+        frp::new_network! { network
+            src     <- source::<i32>();
+            sampled <- src.sampler();
+        }
+        frp::cache::start_counting_clones();
+        src.emit(1);
+        src.emit(2);
+        let clones = frp::cache::stop_counting_clones();
+        assert_eq!(sampled.value(),2);
+        assert_eq!(clones.iter().map(|(_,count)| count).sum::<usize>(),2);
+    }
+
+    #[test]
+    fn cache_latest_keeps_unwatched_values() {
+        use frp::stream::ValueProvider;
+        frp::new_network! { network
+            src <- source::<i32>();
+        }
+        src.cache_latest(true);
+        src.emit(7);
+        assert_eq!(src.value(),7);
+    }
+
+    #[test]
+    fn zip_with_previous_pairs_consecutive_values() {
+        frp::new_network! { network
+            src    <- source::<i32>();
+            zipped <- src.zip_with_previous();
+            zipped <- zipped.sampler();
+        }
+        src.emit(1);
+        assert_eq!(zipped.value(),(0,1));
+        src.emit(5);
+        assert_eq!(zipped.value(),(1,5));
+    }
+
+    #[test]
+    fn fsm_transitions_and_outputs() {
+        #[derive(Clone,Copy,Debug,PartialEq)]
+        enum Drag { Idle, Dragging {start:i32} }
+        impl Default for Drag { fn default() -> Self { Drag::Idle } }
+        #[derive(Clone,Copy,Debug,Default)]
+        struct Input { pressed:bool, position:i32 }
+
+        frp::new_network! { network
+            input   <- source::<Input>();
+            probe   <- source::<()>();
+            machine <- input.fsm(Drag::Idle,|state,event| match (*state,event.pressed) {
+                (Drag::Idle,true)              => (Drag::Dragging {start:event.position},None),
+                (Drag::Dragging {start},false) => (Drag::Idle,Some(event.position - start)),
+                (other,_)                      => (other,None),
+            });
+            state    <- machine.sample(&probe)._0().sampler();
+            distance <- machine._1().unwrap().sampler();
+        }
+        probe.emit(());
+        assert_eq!(state.value(),Drag::Idle);
+        input.emit(Input {pressed:true,position:3});
+        probe.emit(());
+        assert_eq!(state.value(),Drag::Dragging {start:3});
+        input.emit(Input {pressed:true,position:5});
+        assert_eq!(distance.value(),0);
+        input.emit(Input {pressed:false,position:10});
+        probe.emit(());
+        assert_eq!(state.value(),Drag::Idle);
+        assert_eq!(distance.value(),7);
+    }
+
+    /// The events seen by the two targets of a source, which re-emits the incremented value from
+    /// its first target until it reaches 2.
+    fn reentrant_events(policy:frp::ReentrancyPolicy) -> Vec<String> {
+        use frp::prelude::*;
+        let log = Rc::new(RefCell::new(Vec::new()));
+        frp::new_network! { network
+            value <- source::<usize>();
+            eval value ([value,log](v) {
+                log.borrow_mut().push(format!("a{}",v));
+                if *v < 2 { value.emit(v + 1) }
+            });
+            eval value ((v) log.borrow_mut().push(format!("b{}",v)));
+        }
+        network.set_reentrancy_policy(policy);
+        value.emit(0);
+        let events = log.borrow().clone();
+        events
+    }
+
+    #[test]
+    fn reentrancy_policies() {
+        use frp::ReentrancyPolicy::*;
+        assert_eq!(reentrant_events(Allow),vec!["a0","a1","a2","b2","b1","b0"]);
+        assert_eq!(reentrant_events(Queue),vec!["a0","b0","a1","b1","a2","b2"]);
+        assert_eq!(reentrant_events(Drop),vec!["a0","b0"]);
+    }
+
+    #[test]
+    fn deferring_events() {
+        use frp::prelude::*;
+        let log = Rc::new(RefCell::new(Vec::new()));
+        frp::new_network! { network
+            value    <- source::<usize>();
+            deferred <- value.defer();
+            eval deferred ((v) log.borrow_mut().push(v * 10));
+            eval value    ((v) log.borrow_mut().push(*v));
+        }
+        value.emit(1);
+        value.emit(2);
+        assert_eq!(*log.borrow(),vec![1,10,2,20]);
+        assert!(!frp::reentrancy::is_propagating());
+    }
 }
 
 #[cfg(test)]
@@ -328,6 +559,27 @@ mod dynamic_mode_tests {
         assert_eq!(passed_events.get(),true_count);
     }
 
+    #[test]
+    fn test_gate_with() {
+        let passed_values = Rc::new(RefCell::new(Vec::new()));
+        frp::new_network! { network
+            source <- source::<usize>();
+            min    <- source::<usize>();
+            max    <- source::<usize>();
+            passed <- source.gate_with2(&min,&max,|min,max| min < max);
+            eval passed ([passed_values](value) passed_values.borrow_mut().push(*value));
+        };
+
+        source.emit(1);
+        max.emit(2);
+        source.emit(2);
+        min.emit(3);
+        source.emit(3);
+        max.emit(4);
+        source.emit(4);
+        assert_eq!(*passed_values.borrow(),vec![2,4]);
+    }
+
     #[test]
     fn test_filter_map() {
         let passed_events = Rc::new(Cell::new(0));
@@ -344,4 +596,42 @@ mod dynamic_mode_tests {
         let true_count = input.iter().filter(|&&val| val == true).count();
         assert_eq!(passed_events.get(),true_count);
     }
+
+    #[test]
+    fn test_match() {
+        let passed_values = Rc::new(RefCell::new(Vec::new()));
+        frp::new_network! { network
+            source  <- source::<Option<usize>>();
+            matched <- source.match(Some(value) if *value > 1 => value * 10, None => 0);
+            eval matched ([passed_values](value) passed_values.borrow_mut().push(*value));
+        };
+
+        for value in &[Some(1),Some(2),None,Some(3)] {
+            source.emit(*value);
+        }
+        assert_eq!(*passed_values.borrow(),vec![20,0,30]);
+    }
+
+    #[test]
+    fn test_route_by_key() {
+        let passed_values = Rc::new(RefCell::new(Vec::new()));
+        frp::new_network! { network
+            source <- source::<(usize,char)>();
+            router <- source.route_by_key();
+        };
+        let route = router.route(&1);
+        frp::extend! { network
+            eval route ([passed_values](value) passed_values.borrow_mut().push(*value));
+        }
+
+        for event in &[(1,'a'),(2,'b'),(1,'c')] {
+            source.emit(*event);
+        }
+        assert_eq!(*passed_values.borrow(),vec!['a','c']);
+        assert!(router.has_route(&1));
+        assert!(!router.has_route(&2));
+        router.remove_route(&1);
+        source.emit((1,'d'));
+        assert_eq!(*passed_values.borrow(),vec!['a','c']);
+    }
 }
@@ -99,6 +99,21 @@
 ///   ```
 ///
 ///
+/// - Pattern matching. Passes on the values produced by the first matching arm and drops the
+///   events matching no arm. The arms may have guards. The bindings refer to the event value, so
+///   the produced values are cloned.
+///   ```compile_fail
+///   node_to_enter <- target.match(Target::Node(id) if id.is_valid() => id);
+///   ```
+///   Desugars to:
+///   ```compile_fail
+///   def node_to_enter = target.filter_map(|value| match value {
+///       Target::Node(id) if id.is_valid() => Some(id.clone()),
+///       _ => None,
+///   });
+///   ```
+///
+///
 /// - Stream iteration.
 ///   ```compile_fail
 ///   each_node <= all_nodes;
@@ -211,6 +226,11 @@ macro_rules! extend_line1 {
 #[macro_export]
 macro_rules! extend_line2 {
     ([$($lines:tt)*] $net:ident def $name:ident = $name2:ident) => { $($lines)* };
+    ([$($lines:tt)*] $net:ident def $name:ident $(:$ty:ty)? = $tgt1:ident                                                         . match ($($arms:tt)*) $($ts:tt)*) => { $crate::extend_line2! { [$($lines)* let $name $(:$ty)? = $net.filter_map(concat!(module_path!(),"::",stringify!($name),":",line!()),&$tgt1,$crate::match_arms!($($arms)*))                                ;] $net def $name = $name $($ts)* } };
+    ([$($lines:tt)*] $net:ident def $name:ident $(:$ty:ty)? = $tgt1:ident . $tgt2:ident                                           . match ($($arms:tt)*) $($ts:tt)*) => { $crate::extend_line2! { [$($lines)* let $name $(:$ty)? = $net.filter_map(concat!(module_path!(),"::",stringify!($name),":",line!()),&$tgt1.$tgt2,$crate::match_arms!($($arms)*))                         ;] $net def $name = $name $($ts)* } };
+    ([$($lines:tt)*] $net:ident def $name:ident $(:$ty:ty)? = $tgt1:ident . $tgt2:ident . $tgt3:ident                             . match ($($arms:tt)*) $($ts:tt)*) => { $crate::extend_line2! { [$($lines)* let $name $(:$ty)? = $net.filter_map(concat!(module_path!(),"::",stringify!($name),":",line!()),&$tgt1.$tgt2.$tgt3,$crate::match_arms!($($arms)*))                   ;] $net def $name = $name $($ts)* } };
+    ([$($lines:tt)*] $net:ident def $name:ident $(:$ty:ty)? = $tgt1:ident . $tgt2:ident . $tgt3:ident . $tgt4:ident               . match ($($arms:tt)*) $($ts:tt)*) => { $crate::extend_line2! { [$($lines)* let $name $(:$ty)? = $net.filter_map(concat!(module_path!(),"::",stringify!($name),":",line!()),&$tgt1.$tgt2.$tgt3.$tgt4,$crate::match_arms!($($arms)*))             ;] $net def $name = $name $($ts)* } };
+    ([$($lines:tt)*] $net:ident def $name:ident $(:$ty:ty)? = $tgt1:ident . $tgt2:ident . $tgt3:ident . $tgt4:ident . $tgt5:ident . match ($($arms:tt)*) $($ts:tt)*) => { $crate::extend_line2! { [$($lines)* let $name $(:$ty)? = $net.filter_map(concat!(module_path!(),"::",stringify!($name),":",line!()),&$tgt1.$tgt2.$tgt3.$tgt4.$tgt5,$crate::match_arms!($($arms)*))       ;] $net def $name = $name $($ts)* } };
     ([$($lines:tt)*] $net:ident def $name:ident $(:$ty:ty)? =                                                                       $base:ident$(::<$param:ty>)?($($arg:tt)*) $($ts:tt)*) => { $crate::extend_line2! { [$($lines)* let $name $(:$ty)? = $net.$base$(::<$param>)?(concat!(module_path!(),"::",stringify!($name),":",line!()),$($arg)*)                                ;] $net def $name = $name $($ts)* } };
     ([$($lines:tt)*] $net:ident def $name:ident $(:$ty:ty)? = $tgt1:ident                                                         . $base:ident$(::<$param:ty>)?($($arg:tt)*) $($ts:tt)*) => { $crate::extend_line2! { [$($lines)* let $name $(:$ty)? = $net.$base$(::<$param>)?(concat!(module_path!(),"::",stringify!($name),":",line!()),&$tgt1,$($arg)*)                         ;] $net def $name = $name $($ts)* } };
     ([$($lines:tt)*] $net:ident def $name:ident $(:$ty:ty)? = $tgt1:ident . $tgt2:ident                                           . $base:ident$(::<$param:ty>)?($($arg:tt)*) $($ts:tt)*) => { $crate::extend_line2! { [$($lines)* let $name $(:$ty)? = $net.$base$(::<$param>)?(concat!(module_path!(),"::",stringify!($name),":",line!()),&$tgt1.$tgt2,$($arg)*)                   ;] $net def $name = $name $($ts)* } };
@@ -239,6 +259,10 @@ macro_rules! extend_line2 {
     ([] $net:ident $name:ident <- all ( $($arg1:ident).+ , $($arg2:ident).+ )                                       $($ts:tt)* ) => {$crate::extend_line2! { [] $net def $name = all2(&$($arg1).+,&$($arg2).+)                           $($ts)* } };
     ([] $net:ident $name:ident <- all ( $($arg1:ident).+ , $($arg2:ident).+ , $($arg3:ident).+ )                    $($ts:tt)* ) => {$crate::extend_line2! { [] $net def $name = all3(&$($arg1).+,&$($arg2).+,&$($arg3).+)               $($ts)* } };
     ([] $net:ident $name:ident <- all ( $($arg1:ident).+ , $($arg2:ident).+ , $($arg3:ident).+ , $($arg4:ident).+ ) $($ts:tt)* ) => {$crate::extend_line2! { [] $net def $name = all4(&$($arg1).+,&$($arg2).+,&$($arg3).+,&$($arg4).+)   $($ts)* } };
+    ([] $net:ident $name:ident <- all ( $($arg1:ident).+ , $($arg2:ident).+ , $($arg3:ident).+ , $($arg4:ident).+ , $($arg5:ident).+ ) $($ts:tt)* ) => {$crate::extend_line2! { [] $net def $name = all5(&$($arg1).+,&$($arg2).+,&$($arg3).+,&$($arg4).+,&$($arg5).+) $($ts)* } };
+    ([] $net:ident $name:ident <- all ( $($arg1:ident).+ , $($arg2:ident).+ , $($arg3:ident).+ , $($arg4:ident).+ , $($arg5:ident).+ , $($arg6:ident).+ ) $($ts:tt)* ) => {$crate::extend_line2! { [] $net def $name = all6(&$($arg1).+,&$($arg2).+,&$($arg3).+,&$($arg4).+,&$($arg5).+,&$($arg6).+) $($ts)* } };
+    ([] $net:ident $name:ident <- all ( $($arg1:ident).+ , $($arg2:ident).+ , $($arg3:ident).+ , $($arg4:ident).+ , $($arg5:ident).+ , $($arg6:ident).+ , $($arg7:ident).+ ) $($ts:tt)* ) => {$crate::extend_line2! { [] $net def $name = all7(&$($arg1).+,&$($arg2).+,&$($arg3).+,&$($arg4).+,&$($arg5).+,&$($arg6).+,&$($arg7).+) $($ts)* } };
+    ([] $net:ident $name:ident <- all ( $($arg1:ident).+ , $($arg2:ident).+ , $($arg3:ident).+ , $($arg4:ident).+ , $($arg5:ident).+ , $($arg6:ident).+ , $($arg7:ident).+ , $($arg8:ident).+ ) $($ts:tt)* ) => {$crate::extend_line2! { [] $net def $name = all8(&$($arg1).+,&$($arg2).+,&$($arg3).+,&$($arg4).+,&$($arg5).+,&$($arg6).+,&$($arg7).+,&$($arg8).+) $($ts)* } };
 
     ([] $net:ident $name:ident <- all [...]                                                                         $($ts:tt)* ) => {$crate::extend_line2! { [] $net $name <- all_mut()                                                  $($ts)* } };
     ([] $net:ident $name:ident <- all [ $($arg1:ident).+ ]                                                                     ) => { let $name = $($arg1).+.clone_ref(); };
@@ -249,7 +273,7 @@ macro_rules! extend_line2 {
     ([] $net:ident $name:ident <- all [ $($arg1:ident).+ , $($arg2:ident).+ , $($arg3:ident).+ , $($arg4:ident).+ , $($arg5:ident).+ , $($arg6:ident).+ ] $($ts:tt)* ) => {$crate::extend_line2! { [] $net def $name = all_vec6(&$($arg1).+,&$($arg2).+,&$($arg3).+,&$($arg4).+,&$($arg5).+,&$($arg6).+) $($ts)* } };
     ([] $net:ident $name:ident <- all [ $($arg1:ident).+ , $($arg2:ident).+ , $($arg3:ident).+ , $($arg4:ident).+ , $($arg5:ident).+ , $($arg6:ident).+ , $($arg7:ident).+ ] $($ts:tt)* ) => {$crate::extend_line2! { [] $net def $name = all_vec7(&$($arg1).+,&$($arg2).+,&$($arg3).+,&$($arg4).+,&$($arg5).+,&$($arg6).+,&$($arg7).+) $($ts)* } };
     ([] $net:ident $name:ident <- all [ $($arg1:ident).+ , $($arg2:ident).+ , $($arg3:ident).+ , $($arg4:ident).+ , $($arg5:ident).+ , $($arg6:ident).+ , $($arg7:ident).+, $($arg8:ident).+ ] $($ts:tt)* ) => {$crate::extend_line2! { [] $net def $name = all_vec8(&$($arg1).+,&$($arg2).+,&$($arg3).+,&$($arg4).+,&$($arg5).+,&$($arg6).+,&$($arg7).+,&$($arg8).+) $($ts)* } };
-    ([] $net:ident $name:ident <- all [ $($arg1:ident).+ , $($arg2:ident).+ , $($arg3:ident).+ , $($arg4:ident).+ , $($arg5:ident).+ , $($arg6:ident).+ , $($arg7:ident).+, $($arg8:ident).+, ($arg9:ident).+ ] $($ts:tt)* ) => {$crate::extend_line2! { [] $net def $name = all_vec9(&$($arg1).+,&$($arg2).+,&$($arg3).+,&$($arg4).+,&$($arg5).+,&$($arg6).+,&$($arg7).+,&$($arg8).+,&$($arg9).+) $($ts)* } };
+    ([] $net:ident $name:ident <- all [ $($arg1:ident).+ , $($arg2:ident).+ , $($arg3:ident).+ , $($arg4:ident).+ , $($arg5:ident).+ , $($arg6:ident).+ , $($arg7:ident).+, $($arg8:ident).+, $($arg9:ident).+ ] $($ts:tt)* ) => {$crate::extend_line2! { [] $net def $name = all_vec9(&$($arg1).+,&$($arg2).+,&$($arg3).+,&$($arg4).+,&$($arg5).+,&$($arg6).+,&$($arg7).+,&$($arg8).+,&$($arg9).+) $($ts)* } };
 
     ([] $net:ident $name:ident <- all_ (...)                                                                         $($ts:tt)* ) => {$crate::extend_line2! { [] $net $name <- all_mut_()                                                $($ts)* } };
     ([] $net:ident $name:ident <- all_ ( $($arg1:ident).+ )                                                                     ) => { let $name = $($arg1).+.constant(()); };
@@ -285,6 +309,23 @@ macro_rules! extend_line2 {
 
 // === Utils ===
 
+/// Internal helper for `extend` macro. Lowers the arms of the `match` syntax to a closure for the
+/// `filter_map` node.
+#[macro_export]
+macro_rules! match_arms {
+    ($($pat:pat $(if $guard:expr)? => $out:expr),+ $(,)?) => {
+        move |value| match value {
+            $($pat $(if $guard)? => {
+                #[allow(clippy::clone_on_copy)]
+                let out = ($out).clone();
+                Some(out)
+            })+
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    };
+}
+
 /// Internal helper for `extend` macro.
 #[macro_export]
 macro_rules! divide_on_terminator {
@@ -5,6 +5,9 @@ use crate::stream;
 use crate::stream::Stream;
 use crate::node::*;
 use crate::debug;
+use crate::inspector;
+use crate::reentrancy::ReentrancyPolicy;
+use crate::trace;
 
 
 
@@ -39,8 +42,9 @@ pub struct WeakNetwork {
 }
 
 /// Network item.
-pub trait Item : HasId + HasLabel + stream::HasOutputTypeLabel {}
-impl<T> Item for T where T : HasId + HasLabel + stream::HasOutputTypeLabel {}
+pub trait Item : HasId + HasLabel + stream::HasOutputTypeLabel + stream::HasReentrancyPolicy {}
+impl<T> Item for T
+where T : HasId + HasLabel + stream::HasOutputTypeLabel + stream::HasReentrancyPolicy {}
 
 /// Internal data of `Network`.
 #[derive(Derivative)]
@@ -54,6 +58,8 @@ pub struct NetworkData {
     bridges : RefCell<Vec<BridgeNetwork>>,
     /// Used as a convenient storage of data associated with network, like animation instances.
     storage : RefCell<Vec<Box<dyn Any>>>,
+    /// The reentrancy policy set for all the nodes of the network, if any.
+    reentrancy_policy : Cell<Option<ReentrancyPolicy>>,
 }
 
 
@@ -67,7 +73,8 @@ impl NetworkData {
         let links   = default();
         let bridges = default();
         let storage = default();
-        Self {label,nodes,links,bridges,storage}
+        let reentrancy_policy = default();
+        Self {label,nodes,links,bridges,storage,reentrancy_policy}
     }
 }
 
@@ -80,8 +87,15 @@ impl Drop for NetworkData {
 impl Network {
     /// Constructor.
     pub fn new(label:impl Into<String>) -> Self {
-        let data = Rc::new(NetworkData::new(label));
-        Self {data}
+        let data    = Rc::new(NetworkData::new(label));
+        let network = Self {data};
+        inspector::register_network(&network);
+        network
+    }
+
+    /// Label of the network.
+    pub fn label(&self) -> &str {
+        &self.data.label
     }
 
     /// Get the weak version.
@@ -101,22 +115,37 @@ impl Network {
         self.data.storage.borrow_mut().push(Box::new(item));
     }
 
+    /// Set the policy of handling the re-entrant events of all the nodes of the network, including
+    /// the nodes registered later. The policy of a single node can be changed afterwards. See the
+    /// `reentrancy` module docs.
+    pub fn set_reentrancy_policy(&self, policy:ReentrancyPolicy) {
+        self.data.reentrancy_policy.set(Some(policy));
+        for node in self.data.nodes.borrow().iter() {
+            node.set_reentrancy_policy(policy);
+        }
+    }
+
     /// Register the node and return it's weak reference.
     pub fn register_raw<T:HasOutputStatic>(&self, node:stream::Node<T>) -> stream::WeakNode<T> {
         let weak = node.downgrade();
-        let node = Box::new(node);
-        self.data.nodes.borrow_mut().push(node);
+        self.push_node(node);
         weak
     }
 
     /// Register the node and return a new `Stream` reference.
     pub fn register<Def:HasOutputStatic>(&self, node:stream::Node<Def>) -> Stream<Output<Def>> {
         let stream = node.clone_ref().into();
-        let node   = Box::new(node);
-        self.data.nodes.borrow_mut().push(node);
+        self.push_node(node);
         stream
     }
 
+    fn push_node<Def:HasOutputStatic>(&self, node:stream::Node<Def>) {
+        if let Some(policy) = self.data.reentrancy_policy.get() {
+            node.set_reentrancy_policy(policy);
+        }
+        self.data.nodes.borrow_mut().push(Box::new(node));
+    }
+
     /// Register a new link between nodes. Visualization purposes only.
     pub fn register_link(&self, target:Id, link:Link) {
         self.data.links.borrow_mut().insert(target,link);
@@ -127,6 +156,19 @@ impl Network {
         self.data.bridges.borrow_mut().push(sub_network.clone_ref())
     }
 
+    /// Description of all the nodes of this network. Used by the debugging tools, see the
+    /// `inspector` module.
+    pub fn topology(&self) -> Vec<inspector::NodeInfo> {
+        let links = self.data.links.borrow();
+        self.data.nodes.borrow().iter().map(|node| {
+            let id          = node.id();
+            let label       = node.label();
+            let output_type = node.output_type_label();
+            let inputs      = links.get(&id).map(|link| link.source).into_iter().collect();
+            inspector::NodeInfo {id,label,output_type,inputs}
+        }).collect()
+    }
+
     /// Draw the network using GraphViz.
     pub fn draw(&self) {
         let mut viz = debug::Graphviz::default();
@@ -135,6 +177,11 @@ impl Network {
         });
         debug::display_graphviz(viz);
     }
+
+    /// The topology of the network as a JSON string. See `trace::topology_json`.
+    pub fn to_json(&self) -> String {
+        trace::topology_json(&[self.clone_ref()])
+    }
 }
 
 impl WeakNetwork {
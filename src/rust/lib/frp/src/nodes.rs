@@ -9,9 +9,11 @@
 
 use crate::prelude::*;
 
+use crate::data::fallible::Fallible;
 use crate::data::watch;
 use crate::network::*;
 use crate::node::*;
+use crate::reentrancy;
 use crate::stream::EventOutput;
 use crate::stream::ValueProvider;
 use crate::stream::CallStack;
@@ -23,6 +25,51 @@ use enso_generics::traits::*;
 
 
 
+// ========================
+// === Node API Helpers ===
+// ========================
+
+/// Defines the `Network` and `DynamicNetwork` methods creating the `all` nodes. Every method is
+/// given by its name and node type, followed by the `[type argument]` identifiers of its inputs.
+macro_rules! define_all_methods {
+    (network $($(#[$meta:meta])* $name:ident $node:ident $([$t:ident $arg:ident])*;)*) => {$(
+        $(#[$meta])*
+        pub fn $name<$($t),*>(&self, label:Label, $($arg:&$t),*) -> Stream<($(Output<$t>),*)>
+        where $($t:EventOutput),* {
+            self.register($node::new(label,$($arg),*))
+        }
+    )*};
+    (dynamic $($(#[$meta:meta])* $name:ident $node:ident $([$t:ident $arg:ident])*;)*) => {$(
+        $(#[$meta])*
+        pub fn $name<$($t),*>(self, label:Label, $($arg:&$t),*) -> OwnedStream<($(Output<$t>),*)>
+        where $($t:EventOutput),* {
+            $node::new(label,$($arg),*).into()
+        }
+    )*};
+}
+
+/// Defines the `Network` and `DynamicNetwork` methods creating the nodes applying a function to
+/// the values of their inputs, like `map` or `all_with`. Every method is given by its name and
+/// node type, followed by the `[type argument]` identifiers of its inputs.
+macro_rules! define_function_methods {
+    (network $($(#[$meta:meta])* $name:ident $node:ident $([$t:ident $arg:ident])*;)*) => {$(
+        $(#[$meta])*
+        pub fn $name<$($t,)*F,T>(&self, label:Label, $($arg:&$t,)* f:F) -> Stream<T>
+        where $($t:EventOutput,)* T:Data, F:'static+Fn($(&Output<$t>),*)->T {
+            self.register($node::new(label,$($arg,)*f))
+        }
+    )*};
+    (dynamic $($(#[$meta:meta])* $name:ident $node:ident $([$t:ident $arg:ident])*;)*) => {$(
+        $(#[$meta])*
+        pub fn $name<$($t,)*F,T>(self, label:Label, $($arg:&$t,)* f:F) -> OwnedStream<T>
+        where $($t:EventOutput,)* T:Data, F:'static+Fn($(&Output<$t>),*)->T {
+            $node::new(label,$($arg,)*f).into()
+        }
+    )*};
+}
+
+
+
 // ========================
 // === Network Node API ===
 // ========================
@@ -51,6 +98,12 @@ impl Network {
         self.register(OwnedTrace::new(label,src))
     }
 
+    /// Pass the incoming events to output after the current propagation finishes, in the order of
+    /// their arrival. See the `reentrancy` module docs.
+    pub fn defer<T:EventOutput>(&self, label:Label, src:&T) -> Stream<Output<T>> {
+        self.register(OwnedDefer::new(label,src))
+    }
+
     /// Emits `true`, `false`, `true`, `false`, ... on every incoming event. Initialized with false
     /// value.
     pub fn toggle<T:EventOutput>(&self, label:Label, src:&T) -> Stream<bool> {
@@ -78,6 +131,23 @@ impl Network {
         self.register(OwnedPrevious::new(label,src))
     }
 
+    /// Remembers the value of the input stream and outputs it together with the previously
+    /// received one, as `(previous,current)`. The first event is paired with the default value.
+    pub fn zip_with_previous<T:EventOutput>
+    (&self, label:Label, src:&T) -> Stream<(Output<T>,Output<T>)> {
+        self.register(OwnedZipWithPrevious::new(label,src))
+    }
+
+    /// State machine. Holds a state, initialized with `init`, and on every incoming event passes
+    /// the current state and the event to the transition function `f`. The returned state replaces
+    /// the current one and is emitted together with the optional output event. The node is
+    /// initialized with `(init,None)`, so it can be sampled as a behavior of the current state
+    /// before any event arrives.
+    pub fn fsm<T,S,F,Out>(&self, label:Label, src:&T, init:S, f:F) -> Stream<(S,Option<Out>)>
+    where T:EventOutput, S:Data, Out:Data, F:'static+Fn(&S,&Output<T>)->(S,Option<Out>) {
+        self.register(OwnedFsm::new(label,src,init,f))
+    }
+
     /// Samples the first stream (behavior) on every incoming event of the second stream. The
     /// incoming event is dropped and a new event with the behavior's value is emitted.
     pub fn sample<T1:EventOutput,T2:EventOutput>
@@ -85,6 +155,15 @@ impl Network {
         self.register(OwnedSample::new(label,behavior,event))
     }
 
+    /// Samples the first stream (behavior) on every incoming event of the second stream (usually
+    /// the animation frames, see `io::Frames`), but only if the behavior has changed since the
+    /// previous sampling. This way, many behavior changes within one frame result in a single
+    /// event, emitted in sync with the frame.
+    pub fn sample_on_frame<T1:EventOutput,T2:EventOutput>
+    (&self, label:Label, behavior:&T1, frames:&T2) -> Stream<Output<T1>> {
+        self.register(OwnedSampleOnFrame::new(label,behavior,frames))
+    }
+
     /// Passes the incoming event of the first stream only if the value of the second stream is
     /// true.
     pub fn gate<T1,T2>(&self, label:Label, event:&T1, behavior:&T2) -> Stream<Output<T1>>
@@ -98,6 +177,32 @@ impl Network {
         self.register(OwnedGateNot::new(label,event,behavior))
     }
 
+    /// Passes the incoming event of the first stream only if the predicate applied to the sampled
+    /// value of the second stream is true. Unlike `gate` combined with `map`, it does not create
+    /// any intermediate node.
+    pub fn gate_with<T,B1,F>(&self, label:Label, event:&T, b1:&B1, f:F) -> Stream<Output<T>>
+    where T:EventOutput, B1:EventOutput, F:'static+Fn(&Output<B1>)->bool {
+        self.register(OwnedGateWith1::new(label,event,b1,f))
+    }
+
+    /// Specialized version of `gate_with`, replacing the common pattern of `all_with` followed by
+    /// `gate`.
+    pub fn gate_with2<T,B1,B2,F>
+    (&self, label:Label, event:&T, b1:&B1, b2:&B2, f:F) -> Stream<Output<T>>
+    where T:EventOutput, B1:EventOutput, B2:EventOutput,
+          F:'static+Fn(&Output<B1>,&Output<B2>)->bool {
+        self.register(OwnedGateWith2::new(label,event,b1,b2,f))
+    }
+
+    /// Specialized version of `gate_with`, replacing the common pattern of `all_with3` followed by
+    /// `gate`.
+    pub fn gate_with3<T,B1,B2,B3,F>
+    (&self, label:Label, event:&T, b1:&B1, b2:&B2, b3:&B3, f:F) -> Stream<Output<T>>
+    where T:EventOutput, B1:EventOutput, B2:EventOutput, B3:EventOutput,
+          F:'static+Fn(&Output<B1>,&Output<B2>,&Output<B3>)->bool {
+        self.register(OwnedGateWith3::new(label,event,b1,b2,b3,f))
+    }
+
     pub fn unwrap<T,S>(&self, label:Label, event:&T) -> Stream<S>
         where T:EventOutput<Output=Option<S>>, S:Data {
         self.register(OwnedUnwrap::new(label,event))
@@ -129,12 +234,11 @@ impl Network {
     }
 
     /// Only if the input event has changed, emit the input event. This will hide multiple
-    /// consecutive events with the same value.
+    /// consecutive events with the same value. The first event is compared with the default
+    /// value. The event is cloned only when it differs from the previous one.
     pub fn on_change<T,V>(&self, label:Label, t:&T) -> Stream<V>
         where T:EventOutput<Output=V>, V:Data+PartialEq {
-        let prev    = self.previous(label,t);
-        let changed = self.map2(label,t,&prev,|t1,t2| t1!=t2);
-        self.gate(label,t,&changed)
+        self.register(OwnedOnChange::new(label,t))
     }
 
 
@@ -430,6 +534,14 @@ impl Network {
         self.register(OwnedAll5::new(label,t1,t2,t3,t4,t5))
     }
 
+    define_all_methods! { network
+        /// Specialized version of `all`.
+        all6 OwnedAll6 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6];
+        /// Specialized version of `all`.
+        all7 OwnedAll7 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6] [T7 t7];
+        /// Specialized version of `all`.
+        all8 OwnedAll8 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6] [T7 t7] [T8 t8];
+    }
 
     // === Filter ===
 
@@ -450,6 +562,63 @@ impl Network {
     }
 
 
+    // === Routing ===
+
+    /// Routes the values of the incoming `(key,value)` events to the per-key streams obtained by
+    /// `RouteByKey::route`. The per-key streams are created on the first request and live as long
+    /// as the node, unless removed by `RouteByKey::remove_route`. The events of keys without a
+    /// route are dropped. All the events are also passed on unchanged.
+    pub fn route_by_key<T,K,V>(&self, label:Label, src:&T) -> RouteByKey<K,V>
+    where T:EventOutput<Output=(K,V)>, K:Data+Eq+Hash, V:Data {
+        self.register_raw(OwnedRouteByKey::new(label,src))
+    }
+
+
+    // === Fallible ===
+
+    /// Applies the function `f` to the values of the successful results. The errors are passed on
+    /// unchanged. See the `data::fallible` module docs.
+    pub fn map_ok<T,F,S,E,Out>(&self, label:Label, src:&T, f:F) -> Stream<Fallible<Out,E>>
+    where T:EventOutput<Output=Fallible<S,E>>, S:Data, E:Data, Out:Data, F:'static+Fn(&S)->Out {
+        self.register(OwnedMap::new(label,src,move |t:&Fallible<S,E>| {
+            Fallible::new(t.as_result().as_ref().map(&f).map_err(|e| e.clone()))
+        }))
+    }
+
+    /// Passes the values of the successful results, dropping the errors.
+    pub fn ok<T,S,E>(&self, label:Label, src:&T) -> Stream<S>
+    where T:EventOutput<Output=Fallible<S,E>>, S:Data, E:Data {
+        self.register(OwnedFilterMap::new(label,src,|t:&Fallible<S,E>| {
+            t.as_result().as_ref().ok().cloned()
+        }))
+    }
+
+    /// Passes the errors, dropping the successful results.
+    pub fn err<T,S,E>(&self, label:Label, src:&T) -> Stream<E>
+    where T:EventOutput<Output=Fallible<S,E>>, S:Data, E:Data {
+        self.register(OwnedFilterMap::new(label,src,|t:&Fallible<S,E>| {
+            t.as_result().as_ref().err().cloned()
+        }))
+    }
+
+    /// Passes the values of the successful results, and replaces the errors with the values
+    /// computed by `f`.
+    pub fn unwrap_or_else<T,F,S,E>(&self, label:Label, src:&T, f:F) -> Stream<S>
+    where T:EventOutput<Output=Fallible<S,E>>, S:Data, E:Data, F:'static+Fn(&E)->S {
+        self.register(OwnedMap::new(label,src,move |t:&Fallible<S,E>| match t.as_result().as_ref() {
+            Ok(value)  => value.clone(),
+            Err(error) => f(error),
+        }))
+    }
+
+    /// Splits the stream into the stream of the values of successful results and the stream of
+    /// errors. It is not available in the `frp::extend!` syntax, as it creates two streams.
+    pub fn split_result<T,S,E>(&self, label:Label, src:&T) -> (Stream<S>,Stream<E>)
+    where T:EventOutput<Output=Fallible<S,E>>, S:Data, E:Data {
+        (self.ok(label,src),self.err(label,src))
+    }
+
+
     // === Map ===
 
     /// On every event from the first input stream, sample all other input streams and run the
@@ -482,6 +651,16 @@ impl Network {
         self.register(OwnedMap4::new(label,t1,t2,t3,t4,f))
     }
 
+    define_function_methods! { network
+        /// Specialized version of `map`.
+        map5 OwnedMap5 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5];
+        /// Specialized version of `map`.
+        map6 OwnedMap6 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6];
+        /// Specialized version of `map`.
+        map7 OwnedMap7 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6] [T7 t7];
+        /// Specialized version of `map`.
+        map8 OwnedMap8 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6] [T7 t7] [T8 t8];
+    }
 
     // === AllWith ===
 
@@ -517,24 +696,13 @@ impl Network {
         self.register(OwnedAllWith5::new(label,t1,t2,t3,t4,t5,f))
     }
 
-    /// Specialized version `all_with`.
-    pub fn all_with6<T1,T2,T3,T4,T5,T6,F,T>
-    (&self, label:Label, t1:&T1, t2:&T2, t3:&T3, t4:&T4, t5:&T5, t6:&T6, f:F) -> Stream<T>
-    where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput,
-          T6:EventOutput, T:Data,
-          F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>,&Output<T5>,&Output<T6>)->T {
-        self.register(OwnedAllWith6::new(label,t1,t2,t3,t4,t5,t6,f))
-    }
-
-    /// Specialized version `all_with`.
-    pub fn all_with8<T1,T2,T3,T4,T5,T6,T7,T8,F,T>
-    (&self, label:Label, t1:&T1, t2:&T2, t3:&T3, t4:&T4, t5:&T5, t6:&T6, t7:&T7, t8:&T8, f:F)
-    -> Stream<T>
-    where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput,
-          T6:EventOutput, T7:EventOutput, T8:EventOutput, T:Data,
-          F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>,&Output<T5>,&Output<T6>
-              ,&Output<T7>,&Output<T8>)->T {
-        self.register(OwnedAllWith8::new(label,t1,t2,t3,t4,t5,t6,t7,t8,f))
+    define_function_methods! { network
+        /// Specialized version `all_with`.
+        all_with6 OwnedAllWith6 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6];
+        /// Specialized version `all_with`.
+        all_with7 OwnedAllWith7 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6] [T7 t7];
+        /// Specialized version `all_with`.
+        all_with8 OwnedAllWith8 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6] [T7 t7] [T8 t8];
     }
 }
 
@@ -575,6 +743,10 @@ impl DynamicNetwork {
         OwnedTrace::new(label,src).into()
     }
 
+    pub fn defer<T:EventOutput>(self, label:Label, src:&T) -> OwnedStream<Output<T>> {
+        OwnedDefer::new(label,src).into()
+    }
+
     pub fn toggle<T:EventOutput>(self, label:Label, src:&T) -> OwnedStream<bool> {
         OwnedToggle::new(label,src).into()
     }
@@ -591,11 +763,31 @@ impl DynamicNetwork {
         OwnedPrevious::new(label,src).into()
     }
 
+    pub fn zip_with_previous<T:EventOutput>
+    (self, label:Label, src:&T) -> OwnedStream<(Output<T>,Output<T>)> {
+        OwnedZipWithPrevious::new(label,src).into()
+    }
+
+    pub fn fsm<T,S,F,Out>(self, label:Label, src:&T, init:S, f:F) -> OwnedStream<(S,Option<Out>)>
+    where T:EventOutput, S:Data, Out:Data, F:'static+Fn(&S,&Output<T>)->(S,Option<Out>) {
+        OwnedFsm::new(label,src,init,f).into()
+    }
+
+    pub fn on_change<T,V>(self, label:Label, t:&T) -> OwnedStream<V>
+    where T:EventOutput<Output=V>, V:Data+PartialEq {
+        OwnedOnChange::new(label,t).into()
+    }
+
     pub fn sample<T1:EventOutput,T2:EventOutput>
     (self, label:Label, behavior:&T1, event:&T2) -> OwnedStream<Output<T1>> {
         OwnedSample::new(label,behavior,event).into()
     }
 
+    pub fn sample_on_frame<T1:EventOutput,T2:EventOutput>
+    (self, label:Label, behavior:&T1, frames:&T2) -> OwnedStream<Output<T1>> {
+        OwnedSampleOnFrame::new(label,behavior,frames).into()
+    }
+
     pub fn gate<T1,T2>(self, label:Label, event:&T1, behavior:&T2) -> OwnedStream<Output<T1>>
     where T1:EventOutput, T2:EventOutput<Output=bool> {
         OwnedGate::new(label,event,behavior).into()
@@ -606,6 +798,25 @@ impl DynamicNetwork {
         OwnedGateNot::new(label,event,behavior).into()
     }
 
+    pub fn gate_with<T,B1,F>(self, label:Label, event:&T, b1:&B1, f:F) -> OwnedStream<Output<T>>
+    where T:EventOutput, B1:EventOutput, F:'static+Fn(&Output<B1>)->bool {
+        OwnedGateWith1::new(label,event,b1,f).into()
+    }
+
+    pub fn gate_with2<T,B1,B2,F>
+    (self, label:Label, event:&T, b1:&B1, b2:&B2, f:F) -> OwnedStream<Output<T>>
+    where T:EventOutput, B1:EventOutput, B2:EventOutput,
+          F:'static+Fn(&Output<B1>,&Output<B2>)->bool {
+        OwnedGateWith2::new(label,event,b1,b2,f).into()
+    }
+
+    pub fn gate_with3<T,B1,B2,B3,F>
+    (self, label:Label, event:&T, b1:&B1, b2:&B2, b3:&B3, f:F) -> OwnedStream<Output<T>>
+    where T:EventOutput, B1:EventOutput, B2:EventOutput, B3:EventOutput,
+          F:'static+Fn(&Output<B1>,&Output<B2>,&Output<B3>)->bool {
+        OwnedGateWith3::new(label,event,b1,b2,b3,f).into()
+    }
+
     pub fn iter<T1,X>(self, label:Label, event:&T1) -> OwnedStream<X>
     where T1:EventOutput, for<'t> &'t T1::Output:IntoIterator<Item=&'t X>, X:Data {
         OwnedIter::new(label,event).into()
@@ -739,6 +950,11 @@ impl DynamicNetwork {
         OwnedAll5::new(label,t1,t2,t3,t4,t5).into()
     }
 
+    define_all_methods! { dynamic
+        all6 OwnedAll6 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6];
+        all7 OwnedAll7 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6] [T7 t7];
+        all8 OwnedAll8 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6] [T7 t7] [T8 t8];
+    }
 
     // === Filter ===
     pub fn filter<T,P>(self, label:Label, src:&T, p:P) -> Stream<Output<T>>
@@ -755,6 +971,46 @@ impl DynamicNetwork {
     }
 
 
+    // === Routing ===
+
+    pub fn route_by_key<T,K,V>(self, label:Label, src:&T) -> OwnedRouteByKey<K,V>
+    where T:EventOutput<Output=(K,V)>, K:Data+Eq+Hash, V:Data {
+        OwnedRouteByKey::new(label,src)
+    }
+
+
+    // === Fallible ===
+
+    pub fn map_ok<T,F,S,E,Out>(self, label:Label, src:&T, f:F) -> OwnedStream<Fallible<Out,E>>
+    where T:EventOutput<Output=Fallible<S,E>>, S:Data, E:Data, Out:Data, F:'static+Fn(&S)->Out {
+        OwnedMap::new(label,src,move |t:&Fallible<S,E>| {
+            Fallible::new(t.as_result().as_ref().map(&f).map_err(|e| e.clone()))
+        }).into()
+    }
+
+    pub fn ok<T,S,E>(self, label:Label, src:&T) -> OwnedStream<S>
+    where T:EventOutput<Output=Fallible<S,E>>, S:Data, E:Data {
+        OwnedFilterMap::new(label,src,|t:&Fallible<S,E>| {
+            t.as_result().as_ref().ok().cloned()
+        }).into()
+    }
+
+    pub fn err<T,S,E>(self, label:Label, src:&T) -> OwnedStream<E>
+    where T:EventOutput<Output=Fallible<S,E>>, S:Data, E:Data {
+        OwnedFilterMap::new(label,src,|t:&Fallible<S,E>| {
+            t.as_result().as_ref().err().cloned()
+        }).into()
+    }
+
+    pub fn unwrap_or_else<T,F,S,E>(self, label:Label, src:&T, f:F) -> OwnedStream<S>
+    where T:EventOutput<Output=Fallible<S,E>>, S:Data, E:Data, F:'static+Fn(&E)->S {
+        OwnedMap::new(label,src,move |t:&Fallible<S,E>| match t.as_result().as_ref() {
+            Ok(value)  => value.clone(),
+            Err(error) => f(error),
+        }).into()
+    }
+
+
     // === Map ===
 
     pub fn map<T,F,Out>(self, label:Label, src:&T, f:F) -> OwnedStream<Out>
@@ -781,6 +1037,12 @@ impl DynamicNetwork {
         OwnedMap4::new(label,t1,t2,t3,t4,f).into()
     }
 
+    define_function_methods! { dynamic
+        map5 OwnedMap5 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5];
+        map6 OwnedMap6 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6];
+        map7 OwnedMap7 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6] [T7 t7];
+        map8 OwnedMap8 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6] [T7 t7] [T8 t8];
+    }
 
     // === AllWith ===
 
@@ -809,6 +1071,12 @@ impl DynamicNetwork {
           T:Data, F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>,&Output<T5>)->T {
         OwnedAllWith5::new(label,t1,t2,t3,t4,t5,f).into()
     }
+
+    define_function_methods! { dynamic
+        apply6 OwnedAllWith6 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6];
+        apply7 OwnedAllWith7 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6] [T7 t7];
+        apply8 OwnedAllWith8 [T1 t1] [T2 t2] [T3 t3] [T4 t4] [T5 t5] [T6 t6] [T7 t7] [T8 t8];
+    }
 }
 
 
@@ -860,6 +1128,45 @@ impl<Out:Data> Source<Out> {
     }
 }
 
+
+
+// ==================
+// === WeakSource ===
+// ==================
+
+/// A handle for emitting events into the network from the outside, meant for the long-lived
+/// emitters, like web callbacks, outliving the network. Emitting with `Source::emit` after the
+/// network was dropped silently discards the event, which may mask lifetime bugs. The
+/// `WeakSource` allows emitting only with `emit_if_alive`, reporting whether the network still
+/// exists. The handle does not keep the network alive.
+#[derive(CloneRef,Derivative)]
+#[derivative(Clone(bound=""),Debug(bound=""))]
+pub struct WeakSource<Out=()> {
+    stream : Stream<Out>,
+}
+
+impl<Out:Data> WeakSource<Out> {
+    /// Constructor. The `target` is usually a `Source` or an `Any` node.
+    pub fn new(target:impl Into<Stream<Out>>) -> Self {
+        let stream = target.into();
+        Self {stream}
+    }
+
+    /// Check whether the network owning the target node still exists.
+    pub fn is_alive(&self) -> bool {
+        self.stream.upgrade().is_some()
+    }
+
+    /// Emit new event if the network owning the target node still exists. Returns `false` if the
+    /// network was dropped and the event was discarded.
+    pub fn emit_if_alive<T:IntoParam<Out>>(&self, value:T) -> bool {
+        match self.stream.upgrade() {
+            Some(stream) => { stream.emit_event(&default(),&value.into_param()); true }
+            None         => false,
+        }
+    }
+}
+
 /// The parameter of FRP system. It allows passing wide range of values to the `emit` function for
 /// easy of use.
 #[allow(missing_docs)]
@@ -876,15 +1183,13 @@ impl          IntoParam<String>    for &str {fn into_param(self) -> String {self
 // === Sampler ===
 // ===============
 
+/// Remembers the last value of the source, so it can be sampled at any time, even before the node
+/// is connected to any other node. The value is cached by the node itself (see
+/// `Node::cache_latest`), so every event is cloned only once.
 #[derive(Debug)]
 pub struct SamplerData  <Out=()> {
-    src        : Box<dyn std::any::Any>,
-    value      : RefCell<Out>,
-    /// Used to cache the output even if no connection is present YET. Consider the following
-    /// example: first we create `Sampler`, then we emit value to it, then we connect it to some
-    /// output FRP network, Without `self_watch` the value would not be cached internally, and thus
-    /// would not be available to the new sub-network until re-emitted.
-    self_watch : RefCell<Option<Box<dyn std::any::Any>>>
+    src     : Box<dyn std::any::Any>,
+    phantom : PhantomData<Out>,
 }
 pub type   OwnedSampler <Out=()> = stream::Node     <SamplerData<Out>>;
 pub type   Sampler      <Out=()> = stream::WeakNode <SamplerData<Out>>;
@@ -898,11 +1203,10 @@ impl<Out:Data> OwnedSampler<Out> {
     pub fn new<T1>(label:Label, src1:&T1) -> Self
     where T1:EventOutput<Output=Out> {
         let src        = Box::new(src1.clone_ref());
-        let value      = default();
-        let self_watch = default();
-        let definition = SamplerData {src,value,self_watch};
+        let phantom    = default();
+        let definition = SamplerData {src,phantom};
         let out        = Self::construct_and_connect(label,src1,definition);
-        *out.self_watch.borrow_mut() = Some(Box::new(watch_stream(&out)));
+        out.cache_latest(true);
         out
     }
 }
@@ -910,20 +1214,19 @@ impl<Out:Data> OwnedSampler<Out> {
 impl<Out:Data> OwnedSampler<Out> {
     /// Sample the value.
     pub fn value(&self) -> Out {
-        self.value.borrow().clone()
+        ValueProvider::value(self)
     }
 }
 
 impl<Out:Data> Sampler<Out> {
     /// Sample the value.
     pub fn value(&self) -> Out {
-        self.upgrade().map(|t| t.value.borrow().clone()).unwrap_or_default()
+        ValueProvider::value(self)
     }
 }
 
 impl<Out:Data> stream::EventConsumer<Out> for OwnedSampler<Out> {
     fn on_event(&self, stack:CallStack, event:&Out) {
-        *self.value.borrow_mut() = event.clone();
         self.emit_event(stack,event);
     }
 }
@@ -962,6 +1265,38 @@ impl<T:EventOutput> stream::EventConsumer<Output<T>> for OwnedTrace<T> {
 
 
 
+// =============
+// === Defer ===
+// =============
+
+#[derive(Clone,Debug)]
+pub struct DeferData  <T> { src:T }
+pub type   OwnedDefer <T> = stream::Node     <DeferData<T>>;
+pub type   Defer      <T> = stream::WeakNode <DeferData<T>>;
+
+impl<T:EventOutput> HasOutput for DeferData<T> {
+    type Output = Output<T>;
+}
+
+impl<T:EventOutput> OwnedDefer<T> {
+    /// Constructor.
+    pub fn new(label:Label, src1:&T) -> Self {
+        let src = src1.clone_ref();
+        let def = DeferData {src};
+        Self::construct_and_connect(label,src1,def)
+    }
+}
+
+impl<T:EventOutput> stream::EventConsumer<Output<T>> for OwnedDefer<T> {
+    fn on_event(&self, _:CallStack, event:&Output<T>) {
+        let this  = self.downgrade();
+        let event = event.clone();
+        reentrancy::defer(move || this.emit_event(&default(),&event));
+    }
+}
+
+
+
 // ==============
 // === Toggle ===
 // ==============
@@ -1095,6 +1430,116 @@ impl<T:EventOutput> stream::EventConsumer<Output<T>> for OwnedPrevious<T> {
 
 
 
+// =======================
+// === ZipWithPrevious ===
+// =======================
+
+#[derive(Debug)]
+pub struct ZipWithPreviousData  <T:EventOutput> { src:T, previous:RefCell<Output<T>> }
+pub type   OwnedZipWithPrevious <T> = stream::Node     <ZipWithPreviousData<T>>;
+pub type   ZipWithPrevious      <T> = stream::WeakNode <ZipWithPreviousData<T>>;
+
+impl<T:EventOutput> HasOutput for ZipWithPreviousData<T> {
+    type Output = (Output<T>,Output<T>);
+}
+
+impl<T:EventOutput> OwnedZipWithPrevious<T> {
+    /// Constructor.
+    pub fn new(label:Label, src1:&T) -> Self {
+        let src      = src1.clone_ref();
+        let previous = default();
+        let def      = ZipWithPreviousData {src,previous};
+        Self::construct_and_connect(label,src1,def)
+    }
+}
+
+impl<T:EventOutput> stream::EventConsumer<Output<T>> for OwnedZipWithPrevious<T> {
+    fn on_event(&self, stack:CallStack, event:&Output<T>) {
+        let previous = mem::replace(&mut *self.previous.borrow_mut(),event.clone());
+        self.emit_event(stack,&(previous,event.clone()));
+    }
+}
+
+
+
+// ===========
+// === Fsm ===
+// ===========
+
+pub struct FsmData  <T,S,F> { phantom:PhantomData<T>, state:RefCell<S>, function:F }
+pub type   OwnedFsm <T,S,F> = stream::Node     <FsmData<T,S,F>>;
+pub type   Fsm      <T,S,F> = stream::WeakNode <FsmData<T,S,F>>;
+
+impl<T,S,F,Out> HasOutput for FsmData<T,S,F>
+where T:EventOutput, S:Data, Out:Data, F:'static+Fn(&S,&Output<T>)->(S,Option<Out>) {
+    type Output = (S,Option<Out>);
+}
+
+impl<T,S,F,Out> OwnedFsm<T,S,F>
+where T:EventOutput, S:Data, Out:Data, F:'static+Fn(&S,&Output<T>)->(S,Option<Out>) {
+    /// Constructor.
+    pub fn new(label:Label, src:&T, init:S, function:F) -> Self {
+        let state      = RefCell::new(init.clone());
+        let definition = FsmData {phantom:PhantomData,state,function};
+        Self::construct_and_connect_with_init_value(label,src,definition,(init,None))
+    }
+}
+
+impl<T,S,F,Out> stream::EventConsumer<Output<T>> for OwnedFsm<T,S,F>
+where T:EventOutput, S:Data, Out:Data, F:'static+Fn(&S,&Output<T>)->(S,Option<Out>) {
+    fn on_event(&self, stack:CallStack, event:&Output<T>) {
+        let (state,out) = (self.function)(&*self.state.borrow(),event);
+        *self.state.borrow_mut() = state.clone();
+        self.emit_event(stack,&(state,out));
+    }
+}
+
+impl<T,S,F> Debug for FsmData<T,S,F>
+where S:Debug {
+    fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"FsmData({:?})",self.state.borrow())
+    }
+}
+
+
+
+// ================
+// === OnChange ===
+// ================
+
+#[derive(Debug)]
+pub struct OnChangeData  <T:EventOutput> { src:T, previous:RefCell<Output<T>> }
+pub type   OwnedOnChange <T> = stream::Node     <OnChangeData<T>>;
+pub type   OnChange      <T> = stream::WeakNode <OnChangeData<T>>;
+
+impl<T:EventOutput> HasOutput for OnChangeData<T> {
+    type Output = Output<T>;
+}
+
+impl<T> OwnedOnChange<T>
+where T:EventOutput, Output<T>:PartialEq {
+    /// Constructor.
+    pub fn new(label:Label, src1:&T) -> Self {
+        let src      = src1.clone_ref();
+        let previous = default();
+        let def      = OnChangeData {src,previous};
+        Self::construct_and_connect(label,src1,def)
+    }
+}
+
+impl<T> stream::EventConsumer<Output<T>> for OwnedOnChange<T>
+where T:EventOutput, Output<T>:PartialEq {
+    fn on_event(&self, stack:CallStack, event:&Output<T>) {
+        let changed = *self.previous.borrow() != *event;
+        if changed {
+            *self.previous.borrow_mut() = event.clone();
+            self.emit_event(stack,event);
+        }
+    }
+}
+
+
+
 // ==============
 // === Sample ===
 // ==============
@@ -1132,6 +1577,78 @@ impl<T1:EventOutput,T2> stream::InputBehaviors for SampleData<T1,T2> {
 
 
 
+// =====================
+// === SampleOnFrame ===
+// =====================
+
+/// Sets the flag on every incoming event. Used by `SampleOnFrame` to learn about the changes of
+/// the sampled behavior.
+#[derive(Debug)]
+pub struct ChangeFlagData  <T> { flag:Rc<Cell<bool>>, phantom:PhantomData<T> }
+pub type   OwnedChangeFlag <T> = stream::Node     <ChangeFlagData<T>>;
+
+impl<T:EventOutput> HasOutput for ChangeFlagData<T> {
+    type Output = ();
+}
+
+impl<T:EventOutput> OwnedChangeFlag<T> {
+    /// Constructor.
+    pub fn new(label:Label, src:&T, flag:Rc<Cell<bool>>) -> Self {
+        let phantom    = PhantomData;
+        let definition = ChangeFlagData {flag,phantom};
+        Self::construct_and_connect(label,src,definition)
+    }
+}
+
+impl<T:EventOutput> stream::EventConsumer<Output<T>> for OwnedChangeFlag<T> {
+    fn on_event(&self, _:CallStack, _:&Output<T>) {
+        self.flag.set(true);
+    }
+}
+
+#[derive(Debug)]
+pub struct SampleOnFrameData<T1,T2> {
+    behavior    : watch::Ref<T1>,
+    frames      : T2,
+    changed     : Rc<Cell<bool>>,
+    change_flag : OwnedChangeFlag<T1>,
+}
+pub type OwnedSampleOnFrame <T1,T2> = stream::Node     <SampleOnFrameData<T1,T2>>;
+pub type SampleOnFrame      <T1,T2> = stream::WeakNode <SampleOnFrameData<T1,T2>>;
+
+impl<T1:HasOutput,T2> HasOutput for SampleOnFrameData<T1,T2> {
+    type Output = Output<T1>;
+}
+
+impl<T1:EventOutput,T2:EventOutput> OwnedSampleOnFrame<T1,T2> {
+    /// Constructor.
+    pub fn new(label:Label, behavior:&T1, src:&T2) -> Self {
+        let frames      = src.clone_ref();
+        let changed     = Rc::new(Cell::new(false));
+        let change_flag = OwnedChangeFlag::new(label,behavior,changed.clone_ref());
+        let behavior    = watch_stream(behavior);
+        let definition  = SampleOnFrameData {behavior,frames,changed,change_flag};
+        Self::construct_and_connect(label,src,definition)
+    }
+}
+
+impl<T1:EventOutput,T2:EventOutput> stream::EventConsumer<Output<T2>>
+for OwnedSampleOnFrame<T1,T2> {
+    fn on_event(&self, stack:CallStack, _:&Output<T2>) {
+        if self.changed.replace(false) {
+            self.emit_event(stack,&self.behavior.value());
+        }
+    }
+}
+
+impl<T1:EventOutput,T2> stream::InputBehaviors for SampleOnFrameData<T1,T2> {
+    fn input_behaviors(&self) -> Vec<Link> {
+        vec![Link::behavior(&self.behavior)]
+    }
+}
+
+
+
 // ============
 // === Gate ===
 // ============
@@ -1216,6 +1733,76 @@ impl<T1,T2> stream::InputBehaviors for GateNotData<T1,T2>
 
 
 
+// ================
+// === GateWith ===
+// ================
+
+/// Defines a node which, on event from its input, samples all the behaviors and passes the event
+/// only if the predicate applied to the gathered values is true. The arguments are the names of
+/// the node data, owned node, and weak node types, followed by the `[type field argument value]`
+/// identifiers for each behavior.
+macro_rules! define_gate_with_node {
+    ( $data:ident $owned:ident $weak:ident $([$t:ident $src:ident $arg:ident $value:ident])*
+    ) => {
+        pub struct $data  <T,$($t,)*F> { _event:T, $($src:watch::Ref<$t>,)* function:F }
+        pub type   $owned <T,$($t,)*F> = stream::Node     <$data<T,$($t,)*F>>;
+        pub type   $weak  <T,$($t,)*F> = stream::WeakNode <$data<T,$($t,)*F>>;
+
+        impl<T,$($t,)*F> HasOutput for $data<T,$($t,)*F>
+        where T:EventOutput, $($t:EventOutput,)* F:'static+Fn($(&Output<$t>),*)->bool {
+            type Output = Output<T>;
+        }
+
+        impl<T,$($t,)*F> $owned<T,$($t,)*F>
+        where T:EventOutput, $($t:EventOutput,)* F:'static+Fn($(&Output<$t>),*)->bool {
+            /// Constructor.
+            pub fn new(label:Label, event:&T, $($arg:&$t,)* function:F) -> Self {
+                let _event = event.clone_ref();
+                $(let $src = watch_stream($arg);)*
+                let def    = $data {_event,$($src,)*function};
+                Self::construct_and_connect(label,event,def)
+            }
+        }
+
+        impl<T,$($t,)*F> stream::EventConsumer<Output<T>> for $owned<T,$($t,)*F>
+        where T:EventOutput, $($t:EventOutput,)* F:'static+Fn($(&Output<$t>),*)->bool {
+            fn on_event(&self, stack:CallStack, event:&Output<T>) {
+                $(let $value = self.$src.value();)*
+                if (self.function)($(&$value),*) {
+                    self.emit_event(stack,event)
+                }
+            }
+        }
+
+        impl<T,$($t,)*F> stream::InputBehaviors for $data<T,$($t,)*F>
+        where T:EventOutput, $($t:EventOutput),* {
+            fn input_behaviors(&self) -> Vec<Link> {
+                vec![$(Link::behavior(&self.$src)),*]
+            }
+        }
+
+        impl<T,$($t,)*F> Debug for $data<T,$($t,)*F> {
+            fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f,"{}",stringify!($data))
+            }
+        }
+    };
+}
+
+define_gate_with_node! { GateWith1Data OwnedGateWith1 GateWith1
+    [B1 src1 b1 value1]
+}
+
+define_gate_with_node! { GateWith2Data OwnedGateWith2 GateWith2
+    [B1 src1 b1 value1] [B2 src2 b2 value2]
+}
+
+define_gate_with_node! { GateWith3Data OwnedGateWith3 GateWith3
+    [B1 src1 b1 value1] [B2 src2 b2 value2] [B3 src3 b3 value3]
+}
+
+
+
 // ==============
 // === Unwrap ===
 // ==============
@@ -1757,218 +2344,89 @@ impl<Out:Data> stream::EventConsumer<Out> for OwnedAllMut<Out> {
 
 
 // ============
-// === All2 ===
+// === AllN ===
 // ============
 
-#[derive(Debug)]
-pub struct All2Data  <T1,T2> { src1:watch::Ref<T1>, src2:watch::Ref<T2> }
-pub type   OwnedAll2 <T1,T2> = stream::Node     <All2Data<T1,T2>>;
-pub type   All2      <T1,T2> = stream::WeakNode <All2Data<T1,T2>>;
+/// Defines a node which, on event from any of its inputs, samples all of them and emits a tuple of
+/// the gathered values. The arguments are the names of the node data, owned node, and weak node
+/// types, followed by the `[type field argument value]` identifiers for each input.
+macro_rules! define_all_node {
+    ($data:ident $owned:ident $weak:ident $([$t:ident $src:ident $arg:ident $value:ident])*) => {
+        pub struct $data  <$($t),*> { $($src:watch::Ref<$t>),* }
+        pub type   $owned <$($t),*> = stream::Node     <$data<$($t),*>>;
+        pub type   $weak  <$($t),*> = stream::WeakNode <$data<$($t),*>>;
+
+        impl<$($t),*> HasOutput for $data<$($t),*>
+        where $($t:EventOutput),* {
+            type Output = ($(Output<$t>),*);
+        }
 
-impl<T1,T2> HasOutput for All2Data<T1,T2>
-where T1:EventOutput, T2:EventOutput {
-    type Output = (Output<T1>,Output<T2>);
-}
+        impl<$($t),*> $owned<$($t),*>
+        where $($t:EventOutput),* {
+            /// Constructor.
+            pub fn new(label:Label, $($arg:&$t),*) -> Self {
+                $(let $src = watch_stream($arg);)*
+                let def  = $data {$($src),*};
+                let this = Self::construct(label,def);
+                let weak = this.downgrade();
+                $($arg.register_target(weak.clone_ref().into());)*
+                this
+            }
+        }
 
-impl<T1,T2> OwnedAll2<T1,T2>
-where T1:EventOutput, T2:EventOutput {
-    /// Constructor.
-    pub fn new(label:Label, t1:&T1, t2:&T2) -> Self {
-        let src1 = watch_stream(t1);
-        let src2 = watch_stream(t2);
-        let def   = All2Data {src1,src2};
-        let this  = Self::construct(label,def);
-        let weak  = this.downgrade();
-        t1.register_target(weak.clone_ref().into());
-        t2.register_target(weak.into());
-        this
-    }
-}
+        impl<$($t,)*Out> stream::EventConsumer<Out> for $owned<$($t),*>
+        where $($t:EventOutput),* {
+            fn on_event(&self, stack:CallStack, _:&Out) {
+                $(let $value = self.$src.value();)*
+                self.emit_event(stack,&($($value),*));
+            }
+        }
 
-impl<T1,T2,Out> stream::EventConsumer<Out> for OwnedAll2<T1,T2>
-where T1:EventOutput, T2:EventOutput {
-    fn on_event(&self, stack:CallStack, _:&Out) {
-        let value1 = self.src1.value();
-        let value2 = self.src2.value();
-        self.emit_event(stack,&(value1,value2));
-    }
-}
+        impl<$($t),*> stream::InputBehaviors for $data<$($t),*>
+        where $($t:EventOutput),* {
+            fn input_behaviors(&self) -> Vec<Link> {
+                vec![$(Link::mixed(&self.$src)),*]
+            }
+        }
 
-impl<T1,T2> stream::InputBehaviors for All2Data<T1,T2>
-where T1:EventOutput, T2:EventOutput {
-    fn input_behaviors(&self) -> Vec<Link> {
-        vec![Link::mixed(&self.src1), Link::mixed(&self.src2)]
-    }
+        impl<$($t),*> Debug for $data<$($t),*> {
+            fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f,"{}",stringify!($data))
+            }
+        }
+    };
 }
 
-
-
-// ============
-// === All3 ===
-// ============
-
-#[derive(Debug)]
-pub struct All3Data  <T1,T2,T3>
-    { src1:watch::Ref<T1>, src2:watch::Ref<T2>, src3:watch::Ref<T3> }
-pub type   OwnedAll3 <T1,T2,T3> = stream::Node     <All3Data<T1,T2,T3>>;
-pub type   All3      <T1,T2,T3> = stream::WeakNode <All3Data<T1,T2,T3>>;
-
-impl<T1,T2,T3> HasOutput for All3Data<T1,T2,T3>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput {
-    type Output = (Output<T1>,Output<T2>,Output<T3>);
+define_all_node! { All2Data OwnedAll2 All2
+    [T1 src1 t1 value1] [T2 src2 t2 value2]
 }
 
-impl<T1,T2,T3> OwnedAll3<T1,T2,T3>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput {
-    /// Constructor.
-    pub fn new(label:Label, t1:&T1, t2:&T2, t3:&T3) -> Self {
-        let src1 = watch_stream(t1);
-        let src2 = watch_stream(t2);
-        let src3 = watch_stream(t3);
-        let def   = All3Data {src1,src2,src3};
-        let this  = Self::construct(label,def);
-        let weak  = this.downgrade();
-        t1.register_target(weak.clone_ref().into());
-        t2.register_target(weak.clone_ref().into());
-        t3.register_target(weak.into());
-        this
-    }
+define_all_node! { All3Data OwnedAll3 All3
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3]
 }
 
-impl<T1,T2,T3,Out> stream::EventConsumer<Out> for OwnedAll3<T1,T2,T3>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput {
-    fn on_event(&self, stack:CallStack, _:&Out) {
-        let value1 = self.src1.value();
-        let value2 = self.src2.value();
-        let value3 = self.src3.value();
-        self.emit_event(stack,&(value1,value2,value3));
-    }
+define_all_node! { All4Data OwnedAll4 WeakAll4
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
 }
 
-impl<T1,T2,T3> stream::InputBehaviors for All3Data<T1,T2,T3>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput {
-    fn input_behaviors(&self) -> Vec<Link> {
-        vec![Link::mixed(&self.src1), Link::mixed(&self.src2), Link::mixed(&self.src3)]
-    }
+define_all_node! { All5Data OwnedAll5 WeakAll5
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
+    [T5 src5 t5 value5]
 }
 
-
-
-// ============
-// === All4 ===
-// ============
-
-#[derive(Debug)]
-pub struct All4Data <T1,T2,T3,T4>
-    {src1:watch::Ref<T1>, src2:watch::Ref<T2>, src3:watch::Ref<T3>, src4:watch::Ref<T4>}
-pub type   OwnedAll4 <T1,T2,T3,T4> = stream::Node     <All4Data<T1,T2,T3,T4>>;
-pub type   WeakAll4  <T1,T2,T3,T4> = stream::WeakNode <All4Data<T1,T2,T3,T4>>;
-
-impl<T1,T2,T3,T4> HasOutput for All4Data<T1,T2,T3,T4>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput {
-    type Output = (Output<T1>,Output<T2>,Output<T3>,Output<T4>);
+define_all_node! { All6Data OwnedAll6 All6
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
+    [T5 src5 t5 value5] [T6 src6 t6 value6]
 }
 
-impl<T1,T2,T3,T4> OwnedAll4<T1,T2,T3,T4>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput {
-    /// Constructor.
-    pub fn new(label:Label, t1:&T1, t2:&T2, t3:&T3, t4:&T4) -> Self {
-        let src1 = watch_stream(t1);
-        let src2 = watch_stream(t2);
-        let src3 = watch_stream(t3);
-        let src4 = watch_stream(t4);
-        let def   = All4Data {src1,src2,src3,src4};
-        let this  = Self::construct(label,def);
-        let weak  = this.downgrade();
-        t1.register_target(weak.clone_ref().into());
-        t2.register_target(weak.clone_ref().into());
-        t3.register_target(weak.clone_ref().into());
-        t4.register_target(weak.into());
-        this
-    }
-}
-
-impl<T1,T2,T3,T4,Out> stream::EventConsumer<Out> for OwnedAll4<T1,T2,T3,T4>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput {
-    fn on_event(&self, stack:CallStack, _:&Out) {
-        let value1 = self.src1.value();
-        let value2 = self.src2.value();
-        let value3 = self.src3.value();
-        let value4 = self.src4.value();
-        self.emit_event(stack,&(value1,value2,value3,value4));
-    }
-}
-
-impl<T1,T2,T3,T4> stream::InputBehaviors for All4Data<T1,T2,T3,T4>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput {
-    fn input_behaviors(&self) -> Vec<Link> {
-        vec![ Link::mixed(&self.src1)
-            , Link::mixed(&self.src2)
-            , Link::mixed(&self.src3)
-            , Link::mixed(&self.src4)
-            ]
-    }
+define_all_node! { All7Data OwnedAll7 All7
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
+    [T5 src5 t5 value5] [T6 src6 t6 value6] [T7 src7 t7 value7]
 }
 
-
-
-// ============
-// === All5 ===
-// ============
-
-#[derive(Debug)]
-pub struct All5Data <T1,T2,T3,T4,T5>
-{src1:watch::Ref<T1>, src2:watch::Ref<T2>, src3:watch::Ref<T3>, src4:watch::Ref<T4>, src5:watch::Ref<T5>}
-pub type   OwnedAll5 <T1,T2,T3,T4,T5> = stream::Node     <All5Data<T1,T2,T3,T4,T5>>;
-pub type   WeakAll5  <T1,T2,T3,T4,T5> = stream::WeakNode <All5Data<T1,T2,T3,T4,T5>>;
-
-impl<T1,T2,T3,T4,T5> HasOutput for All5Data<T1,T2,T3,T4,T5>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput {
-    type Output = (Output<T1>,Output<T2>,Output<T3>,Output<T4>,Output<T5>);
-}
-
-impl<T1,T2,T3,T4,T5> OwnedAll5<T1,T2,T3,T4,T5>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput {
-    /// Constructor.
-    pub fn new(label:Label, t1:&T1, t2:&T2, t3:&T3, t4:&T4, t5:&T5) -> Self {
-        let src1 = watch_stream(t1);
-        let src2 = watch_stream(t2);
-        let src3 = watch_stream(t3);
-        let src4 = watch_stream(t4);
-        let src5 = watch_stream(t5);
-        let def   = All5Data {src1,src2,src3,src4,src5};
-        let this  = Self::construct(label,def);
-        let weak  = this.downgrade();
-        t1.register_target(weak.clone_ref().into());
-        t2.register_target(weak.clone_ref().into());
-        t3.register_target(weak.clone_ref().into());
-        t4.register_target(weak.into());
-        this
-    }
-}
-
-impl<T1,T2,T3,T4,T5,Out> stream::EventConsumer<Out> for OwnedAll5<T1,T2,T3,T4,T5>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput {
-    fn on_event(&self, stack:CallStack, _:&Out) {
-        let value1 = self.src1.value();
-        let value2 = self.src2.value();
-        let value3 = self.src3.value();
-        let value4 = self.src4.value();
-        let value5 = self.src5.value();
-        self.emit_event(stack,&(value1,value2,value3,value4,value5));
-    }
-}
-
-impl<T1,T2,T3,T4,T5> stream::InputBehaviors for All5Data<T1,T2,T3,T4,T5>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput {
-    fn input_behaviors(&self) -> Vec<Link> {
-        vec![ Link::mixed(&self.src1)
-            , Link::mixed(&self.src2)
-            , Link::mixed(&self.src3)
-            , Link::mixed(&self.src4)
-            , Link::mixed(&self.src5)
-            ]
-    }
+define_all_node! { All8Data OwnedAll8 All8
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
+    [T5 src5 t5 value5] [T6 src6 t6 value6] [T7 src7 t7 value7] [T8 src8 t8 value8]
 }
 
 
@@ -2051,575 +2509,300 @@ impl<T,F> Debug for FilterMapData<T,F> {
 
 
 
-// ===========
-// === Map ===
-// ===========
+// ==================
+// === RouteByKey ===
+// ==================
 
-pub struct MapData  <T,F> { phantom:PhantomData<T>, function:F }
-pub type   OwnedMap <T,F> = stream::Node     <MapData<T,F>>;
-pub type   Map      <T,F> = stream::WeakNode <MapData<T,F>>;
+pub struct RouteByKeyData  <K,V> { label:Label, routes:RefCell<HashMap<K,OwnedSource<V>>> }
+pub type   OwnedRouteByKey <K,V> = stream::Node     <RouteByKeyData<K,V>>;
+pub type   RouteByKey      <K,V> = stream::WeakNode <RouteByKeyData<K,V>>;
 
-impl<T,F,Out> HasOutput for MapData<T,F>
-where T:EventOutput, Out:Data, F:'static+Fn(&Output<T>)->Out {
-    type Output = Out;
+impl<K:Data+Eq+Hash,V:Data> HasOutput for RouteByKeyData<K,V> {
+    type Output = (K,V);
 }
 
-impl<T,F,Out> OwnedMap<T,F>
-where T:EventOutput, Out:Data, F:'static+Fn(&Output<T>)->Out {
+impl<K:Data+Eq+Hash,V:Data> OwnedRouteByKey<K,V> {
     /// Constructor.
-    pub fn new(label:Label, src:&T, function:F) -> Self {
-        let definition = MapData {phantom:PhantomData,function};
+    pub fn new<T>(label:Label, src:&T) -> Self
+    where T:EventOutput<Output=(K,V)> {
+        let routes     = default();
+        let definition = RouteByKeyData {label,routes};
         Self::construct_and_connect(label,src,definition)
     }
 }
 
-impl<T,F,Out> stream::EventConsumer<Output<T>> for OwnedMap<T,F>
-where T:EventOutput, Out:Data, F:'static+Fn(&Output<T>)->Out {
-    fn on_event(&self, stack:CallStack, value:&Output<T>) {
-        let out = (self.function)(value);
-        self.emit_event(stack,&out);
+impl<K:Data+Eq+Hash,V:Data> RouteByKeyData<K,V> {
+    /// The stream of the values routed to the key. The stream is created on the first request.
+    pub fn route(&self, key:&K) -> Stream<V> {
+        let mut routes = self.routes.borrow_mut();
+        let route      = routes.entry(key.clone()).or_insert_with(|| OwnedSource::new(self.label));
+        route.downgrade().into()
     }
-}
 
-impl<T,F> Debug for MapData<T,F> {
-    fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"MapData")
+    /// Remove the stream of the key. The values routed to the key are dropped until it is
+    /// requested again.
+    pub fn remove_route(&self, key:&K) {
+        self.routes.borrow_mut().remove(key);
     }
-}
 
-
-
-// ============
-// === Map2 ===
-// ============
-
-pub struct Map2Data  <T1,T2,F> { _src1:T1, src2:watch::Ref<T2>, function:F }
-pub type   OwnedMap2 <T1,T2,F> = stream::Node     <Map2Data<T1,T2,F>>;
-pub type   Map2      <T1,T2,F> = stream::WeakNode <Map2Data<T1,T2,F>>;
-
-impl<T1,T2,F,Out> HasOutput for Map2Data<T1,T2,F>
-where T1:EventOutput, T2:EventOutput, Out:Data, F:'static+Fn(&Output<T1>,&Output<T2>)->Out {
-    type Output = Out;
-}
-
-impl<T1,T2,F,Out> OwnedMap2<T1,T2,F>
-where T1:EventOutput, T2:EventOutput, Out:Data, F:'static+Fn(&Output<T1>,&Output<T2>)->Out {
-    /// Constructor.
-    pub fn new(label:Label, t1:&T1, t2:&T2, function:F) -> Self {
-        let _src1 = t1.clone_ref();
-        let src2  = watch_stream(t2);
-        let def   = Map2Data {_src1,src2,function};
-        let this  = Self::construct(label,def);
-        let weak  = this.downgrade();
-        t1.register_target(weak.into());
-        this
-    }
-}
-
-impl<T1,T2,F,Out> stream::EventConsumer<Output<T1>> for OwnedMap2<T1,T2,F>
-where T1:EventOutput, T2:EventOutput, Out:Data, F:'static+Fn(&Output<T1>,&Output<T2>)->Out {
-    fn on_event(&self, stack:CallStack, value1:&Output<T1>) {
-        let value2 = self.src2.value();
-        let out    = (self.function)(value1,&value2);
-        self.emit_event(stack,&out);
+    /// Check whether the stream of the key was requested and not removed.
+    pub fn has_route(&self, key:&K) -> bool {
+        self.routes.borrow().contains_key(key)
     }
 }
 
-impl<T1,T2,F> Debug for Map2Data<T1,T2,F> {
-    fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"Map2Data")
+impl<K:Data+Eq+Hash,V:Data> RouteByKey<K,V> {
+    /// The stream of the values routed to the key. If the node was already dropped, the returned
+    /// stream never emits.
+    pub fn route(&self, key:&K) -> Stream<V> {
+        match self.upgrade() {
+            Some(node) => node.route(key),
+            None       => OwnedSource::new("route").downgrade().into(),
+        }
     }
-}
 
-impl<T1,T2,F> stream::InputBehaviors for Map2Data<T1,T2,F>
-where T1:EventOutput, T2:EventOutput {
-    fn input_behaviors(&self) -> Vec<Link> {
-        vec![Link::behavior(&self.src2)]
+    /// Remove the stream of the key. See `RouteByKeyData::remove_route`.
+    pub fn remove_route(&self, key:&K) {
+        if let Some(node) = self.upgrade() { node.remove_route(key) }
     }
-}
-
-
-
-// ============
-// === Map3 ===
-// ============
 
-pub struct Map3Data <T1,T2,T3,F>
-    { _src1:T1, src2:watch::Ref<T2>, src3:watch::Ref<T3>, function:F }
-pub type   OwnedMap3 <T1,T2,T3,F> = stream::Node     <Map3Data<T1,T2,T3,F>>;
-pub type   Map3      <T1,T2,T3,F> = stream::WeakNode <Map3Data<T1,T2,T3,F>>;
-
-impl<T1,T2,T3,F,Out> HasOutput for Map3Data<T1,T2,T3,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>)->Out {
-    type Output = Out;
-}
-
-impl<T1,T2,T3,F,Out> OwnedMap3<T1,T2,T3,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>)->Out {
-    /// Constructor.
-    pub fn new(label:Label, t1:&T1, t2:&T2, t3:&T3, function:F) -> Self {
-        let _src1 = t1.clone_ref();
-        let src2  = watch_stream(t2);
-        let src3  = watch_stream(t3);
-        let def   = Map3Data {_src1,src2,src3,function};
-        let this  = Self::construct(label,def);
-        let weak  = this.downgrade();
-        t1.register_target(weak.into());
-        this
-    }
-}
-
-impl<T1,T2,T3,F,Out> stream::EventConsumer<Output<T1>> for OwnedMap3<T1,T2,T3,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>)->Out {
-    fn on_event(&self, stack:CallStack, value1:&Output<T1>) {
-        let value2 = self.src2.value();
-        let value3 = self.src3.value();
-        let out    = (self.function)(value1,&value2,&value3);
-        self.emit_event(stack,&out);
+    /// Check whether the stream of the key was requested and not removed.
+    pub fn has_route(&self, key:&K) -> bool {
+        self.upgrade().map_or(false,|node| node.has_route(key))
     }
 }
 
-impl<T1,T2,T3,F> Debug for Map3Data<T1,T2,T3,F> {
-    fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"Map3Data")
+impl<K:Data+Eq+Hash,V:Data> stream::EventConsumer<(K,V)> for OwnedRouteByKey<K,V> {
+    fn on_event(&self, stack:CallStack, event:&(K,V)) {
+        self.emit_event(stack,event);
+        let (key,value) = event;
+        let route       = self.routes.borrow().get(key).map(|route| route.clone_ref());
+        if let Some(route) = route {
+            route.emit_event(stack,value);
+        }
     }
 }
 
-impl<T1,T2,T3,F> stream::InputBehaviors for Map3Data<T1,T2,T3,F>
-    where T1:EventOutput, T2:EventOutput, T3:EventOutput {
-    fn input_behaviors(&self) -> Vec<Link> {
-        vec![Link::behavior(&self.src2), Link::behavior(&self.src3)]
+impl<K,V> Debug for RouteByKeyData<K,V> {
+    fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"RouteByKeyData")
     }
 }
 
 
 
-// ============
-// === Map4 ===
-// ============
+// ===========
+// === Map ===
+// ===========
 
-pub struct Map4Data <T1,T2,T3,T4,F>
-    { _src1:T1
-    , src2:watch::Ref<T2>, src3:watch::Ref<T3>, src4:watch::Ref<T4>, function:F }
-pub type   OwnedMap4 <T1,T2,T3,T4,F> = stream::Node     <Map4Data<T1,T2,T3,T4,F>>;
-pub type   Map4      <T1,T2,T3,T4,F> = stream::WeakNode <Map4Data<T1,T2,T3,T4,F>>;
+pub struct MapData  <T,F> { phantom:PhantomData<T>, function:F }
+pub type   OwnedMap <T,F> = stream::Node     <MapData<T,F>>;
+pub type   Map      <T,F> = stream::WeakNode <MapData<T,F>>;
 
-impl<T1,T2,T3,T4,F,Out> HasOutput for Map4Data<T1,T2,T3,T4,F>
-    where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, Out:Data,
-          F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>)->Out {
+impl<T,F,Out> HasOutput for MapData<T,F>
+where T:EventOutput, Out:Data, F:'static+Fn(&Output<T>)->Out {
     type Output = Out;
 }
 
-impl<T1,T2,T3,T4,F,Out> OwnedMap4<T1,T2,T3,T4,F>
-    where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, Out:Data,
-          F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>)->Out {
+impl<T,F,Out> OwnedMap<T,F>
+where T:EventOutput, Out:Data, F:'static+Fn(&Output<T>)->Out {
     /// Constructor.
-    pub fn new(label:Label, t1:&T1, t2:&T2, t3:&T3, t4:&T4, function:F) -> Self {
-        let _src1 = t1.clone_ref();
-        let src2  = watch_stream(t2);
-        let src3  = watch_stream(t3);
-        let src4  = watch_stream(t4);
-        let def   = Map4Data {_src1,src2,src3,src4,function};
-        let this  = Self::construct(label,def);
-        let weak  = this.downgrade();
-        t1.register_target(weak.into());
-        this
-    }
-}
-
-impl<T1,T2,T3,T4,F,Out> stream::EventConsumer<Output<T1>> for OwnedMap4<T1,T2,T3,T4,F>
-    where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, Out:Data,
-          F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>)->Out {
-    fn on_event(&self, stack:CallStack, value1:&Output<T1>) {
-        let value2 = self.src2.value();
-        let value3 = self.src3.value();
-        let value4 = self.src4.value();
-        let out    = (self.function)(value1,&value2,&value3,&value4);
-        self.emit_event(stack,&out);
+    pub fn new(label:Label, src:&T, function:F) -> Self {
+        let definition = MapData {phantom:PhantomData,function};
+        Self::construct_and_connect(label,src,definition)
     }
 }
 
-impl<T1,T2,T3,T4,F> stream::InputBehaviors for Map4Data<T1,T2,T3,T4,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput {
-    fn input_behaviors(&self) -> Vec<Link> {
-        vec![ Link::behavior(&self.src2)
-            , Link::behavior(&self.src3)
-            , Link::behavior(&self.src4)
-            ]
+impl<T,F,Out> stream::EventConsumer<Output<T>> for OwnedMap<T,F>
+where T:EventOutput, Out:Data, F:'static+Fn(&Output<T>)->Out {
+    fn on_event(&self, stack:CallStack, value:&Output<T>) {
+        let out = (self.function)(value);
+        self.emit_event(stack,&out);
     }
 }
 
-impl<T1,T2,T3,T4,F> Debug for Map4Data<T1,T2,T3,T4,F> {
+impl<T,F> Debug for MapData<T,F> {
     fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"Map4Data")
+        write!(f,"MapData")
     }
 }
 
 
 
-// ================
-// === AllWith2 ===
-// ================
+// ============
+// === MapN ===
+// ============
 
-pub struct AllWith2Data  <T1,T2,F> { src1:watch::Ref<T1>, src2:watch::Ref<T2>, function:F }
-pub type   OwnedAllWith2 <T1,T2,F> = stream::Node     <AllWith2Data<T1,T2,F>>;
-pub type   AllWith2      <T1,T2,F> = stream::WeakNode <AllWith2Data<T1,T2,F>>;
+/// Defines a node which, on event from its first input, samples all the other inputs and emits the
+/// result of the function applied to all gathered values. The arguments are the names of the node
+/// data, owned node, and weak node types, followed by the `[type field argument value]`
+/// identifiers for each input.
+macro_rules! define_map_node {
+    ( $data:ident $owned:ident $weak:ident [$t1:ident $src1:ident $arg1:ident $value1:ident]
+      $([$t:ident $src:ident $arg:ident $value:ident])*
+    ) => {
+        pub struct $data  <$t1,$($t,)*F> { _src1:$t1, $($src:watch::Ref<$t>,)* function:F }
+        pub type   $owned <$t1,$($t,)*F> = stream::Node     <$data<$t1,$($t,)*F>>;
+        pub type   $weak  <$t1,$($t,)*F> = stream::WeakNode <$data<$t1,$($t,)*F>>;
+
+        impl<$t1,$($t,)*F,Out> HasOutput for $data<$t1,$($t,)*F>
+        where $t1:EventOutput, $($t:EventOutput,)* Out:Data,
+              F:'static+Fn(&Output<$t1>,$(&Output<$t>),*)->Out {
+            type Output = Out;
+        }
 
-impl<T1,T2,F,Out> HasOutput for AllWith2Data<T1,T2,F>
-where T1:EventOutput, T2:EventOutput, Out:Data, F:'static+Fn(&Output<T1>,&Output<T2>)->Out {
-    type Output = Out;
-}
+        impl<$t1,$($t,)*F,Out> $owned<$t1,$($t,)*F>
+        where $t1:EventOutput, $($t:EventOutput,)* Out:Data,
+              F:'static+Fn(&Output<$t1>,$(&Output<$t>),*)->Out {
+            /// Constructor.
+            pub fn new(label:Label, $arg1:&$t1, $($arg:&$t,)* function:F) -> Self {
+                let _src1 = $arg1.clone_ref();
+                $(let $src = watch_stream($arg);)*
+                let def   = $data {_src1,$($src,)*function};
+                let this  = Self::construct(label,def);
+                let weak  = this.downgrade();
+                $arg1.register_target(weak.into());
+                this
+            }
+        }
 
-impl<T1,T2,F,Out> OwnedAllWith2<T1,T2,F>
-where T1:EventOutput, T2:EventOutput, Out:Data, F:'static+Fn(&Output<T1>,&Output<T2>)->Out {
-    /// Constructor.
-    pub fn new(label:Label, t1:&T1, t2:&T2, function:F) -> Self {
-        let src1 = watch_stream(t1);
-        let src2 = watch_stream(t2);
-        let def     = AllWith2Data {src1,src2,function};
-        let this    = Self::construct(label,def);
-        let weak    = this.downgrade();
-        t1.register_target(weak.clone_ref().into());
-        t2.register_target(weak.into());
-        this
-    }
-}
+        impl<$t1,$($t,)*F,Out> stream::EventConsumer<Output<$t1>> for $owned<$t1,$($t,)*F>
+        where $t1:EventOutput, $($t:EventOutput,)* Out:Data,
+              F:'static+Fn(&Output<$t1>,$(&Output<$t>),*)->Out {
+            fn on_event(&self, stack:CallStack, $value1:&Output<$t1>) {
+                $(let $value = self.$src.value();)*
+                let out = (self.function)($value1,$(&$value),*);
+                self.emit_event(stack,&out);
+            }
+        }
 
-impl<T1,T2,F,Out,T> stream::EventConsumer<T> for OwnedAllWith2<T1,T2,F>
-where T1:EventOutput, T2:EventOutput, Out:Data, F:'static+Fn(&Output<T1>,&Output<T2>)->Out {
-    fn on_event(&self, stack:CallStack, _:&T) {
-        let value1 = self.src1.value();
-        let value2 = self.src2.value();
-        let out    = (self.function)(&value1,&value2);
-        self.emit_event(stack,&out);
-    }
-}
+        impl<$t1,$($t,)*F> stream::InputBehaviors for $data<$t1,$($t,)*F>
+        where $t1:EventOutput, $($t:EventOutput),* {
+            fn input_behaviors(&self) -> Vec<Link> {
+                vec![$(Link::behavior(&self.$src)),*]
+            }
+        }
 
-impl<T1,T2,F> Debug for AllWith2Data<T1,T2,F> {
-    fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"AllWith2Data")
-    }
+        impl<$t1,$($t,)*F> Debug for $data<$t1,$($t,)*F> {
+            fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f,"{}",stringify!($data))
+            }
+        }
+    };
 }
 
-
-
-// ================
-// === AllWith3 ===
-// ================
-
-pub struct AllWith3Data <T1,T2,T3,F>
-    { src1:watch::Ref<T1>, src2:watch::Ref<T2>, src3:watch::Ref<T3>, function:F }
-pub type   OwnedAllWith3 <T1,T2,T3,F> = stream::Node     <AllWith3Data<T1,T2,T3,F>>;
-pub type   AllWith3      <T1,T2,T3,F> = stream::WeakNode <AllWith3Data<T1,T2,T3,F>>;
-
-impl<T1,T2,T3,F,Out> HasOutput for AllWith3Data<T1,T2,T3,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>)->Out {
-    type Output = Out;
+define_map_node! { Map2Data OwnedMap2 Map2
+    [T1 src1 t1 value1] [T2 src2 t2 value2]
 }
 
-impl<T1,T2,T3,F,Out> OwnedAllWith3<T1,T2,T3,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>)->Out {
-    /// Constructor.
-    pub fn new(label:Label, t1:&T1, t2:&T2, t3:&T3, function:F) -> Self {
-        let src1 = watch_stream(t1);
-        let src2 = watch_stream(t2);
-        let src3 = watch_stream(t3);
-        let def     = AllWith3Data {src1,src2,src3,function};
-        let this    = Self::construct(label,def);
-        let weak    = this.downgrade();
-        t1.register_target(weak.clone_ref().into());
-        t2.register_target(weak.clone_ref().into());
-        t3.register_target(weak.into());
-        this
-    }
-}
-
-impl<T1,T2,T3,F,Out,T> stream::EventConsumer<T> for OwnedAllWith3<T1,T2,T3,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>)->Out {
-    fn on_event(&self, stack:CallStack, _:&T) {
-        let value1 = self.src1.value();
-        let value2 = self.src2.value();
-        let value3 = self.src3.value();
-        let out    = (self.function)(&value1,&value2,&value3);
-        self.emit_event(stack,&out);
-    }
+define_map_node! { Map3Data OwnedMap3 Map3
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3]
 }
 
-impl<T1,T2,T3,F> Debug for AllWith3Data<T1,T2,T3,F> {
-    fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"AllWith3Data")
-    }
+define_map_node! { Map4Data OwnedMap4 Map4
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
 }
 
+define_map_node! { Map5Data OwnedMap5 Map5
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
+    [T5 src5 t5 value5]
+}
 
-
-// ================
-// === AllWith4 ===
-// ================
-
-pub struct AllWith4Data <T1,T2,T3,T4,F>
-    { src1:watch::Ref<T1>, src2:watch::Ref<T2>, src3:watch::Ref<T3>, src4:watch::Ref<T4>
-    , function:F }
-pub type   OwnedAllWith4 <T1,T2,T3,T4,F> = stream::Node     <AllWith4Data<T1,T2,T3,T4,F>>;
-pub type   AllWith4      <T1,T2,T3,T4,F> = stream::WeakNode <AllWith4Data<T1,T2,T3,T4,F>>;
-
-impl<T1,T2,T3,T4,F,Out> HasOutput for AllWith4Data<T1,T2,T3,T4,F>
-    where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, Out:Data,
-          F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>)->Out {
-    type Output = Out;
+define_map_node! { Map6Data OwnedMap6 Map6
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
+    [T5 src5 t5 value5] [T6 src6 t6 value6]
 }
 
-impl<T1,T2,T3,T4,F,Out> OwnedAllWith4<T1,T2,T3,T4,F>
-    where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, Out:Data,
-          F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>)->Out {
-    /// Constructor.
-    pub fn new(label:Label, t1:&T1, t2:&T2, t3:&T3, t4:&T4, function:F) -> Self {
-        let src1 = watch_stream(t1);
-        let src2 = watch_stream(t2);
-        let src3 = watch_stream(t3);
-        let src4 = watch_stream(t4);
-        let def     = AllWith4Data {src1,src2,src3,src4,function};
-        let this    = Self::construct(label,def);
-        let weak    = this.downgrade();
-        t1.register_target(weak.clone_ref().into());
-        t2.register_target(weak.clone_ref().into());
-        t3.register_target(weak.clone_ref().into());
-        t4.register_target(weak.into());
-        this
-    }
-}
-
-impl<T1,T2,T3,T4,F,Out,T> stream::EventConsumer<T> for OwnedAllWith4<T1,T2,T3,T4,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>)->Out {
-    fn on_event(&self, stack:CallStack, _:&T) {
-        let value1 = self.src1.value();
-        let value2 = self.src2.value();
-        let value3 = self.src3.value();
-        let value4 = self.src4.value();
-        let out    = (self.function)(&value1,&value2,&value3,&value4);
-        self.emit_event(stack,&out);
-    }
+define_map_node! { Map7Data OwnedMap7 Map7
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
+    [T5 src5 t5 value5] [T6 src6 t6 value6] [T7 src7 t7 value7]
 }
 
-impl<T1,T2,T3,T4,F> Debug for AllWith4Data<T1,T2,T3,T4,F> {
-    fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"AllWith4Data")
-    }
+define_map_node! { Map8Data OwnedMap8 Map8
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
+    [T5 src5 t5 value5] [T6 src6 t6 value6] [T7 src7 t7 value7] [T8 src8 t8 value8]
 }
 
 
 
+
 // ================
-// === AllWith5 ===
+// === AllWithN ===
 // ================
 
-pub struct AllWith5Data <T1,T2,T3,T4,T5,F>
-{ src1:watch::Ref<T1>, src2:watch::Ref<T2>, src3:watch::Ref<T3>, src4:watch::Ref<T4>
-    , src5:watch::Ref<T5>, function:F }
-pub type   OwnedAllWith5 <T1,T2,T3,T4,T5,F> = stream::Node     <AllWith5Data<T1,T2,T3,T4,T5,F>>;
-pub type   AllWith5      <T1,T2,T3,T4,T5,F> = stream::WeakNode <AllWith5Data<T1,T2,T3,T4,T5,F>>;
+/// Defines a node which, on event from any of its inputs, samples all of them and emits the result
+/// of the function applied to all gathered values. The arguments are the names of the node data,
+/// owned node, and weak node types, followed by the `[type field argument value]` identifiers for
+/// each input.
+macro_rules! define_all_with_node {
+    ($data:ident $owned:ident $weak:ident $([$t:ident $src:ident $arg:ident $value:ident])*) => {
+        pub struct $data  <$($t,)*F> { $($src:watch::Ref<$t>,)* function:F }
+        pub type   $owned <$($t,)*F> = stream::Node     <$data<$($t,)*F>>;
+        pub type   $weak  <$($t,)*F> = stream::WeakNode <$data<$($t,)*F>>;
+
+        impl<$($t,)*F,Out> HasOutput for $data<$($t,)*F>
+        where $($t:EventOutput,)* Out:Data, F:'static+Fn($(&Output<$t>),*)->Out {
+            type Output = Out;
+        }
 
-impl<T1,T2,T3,T4,T5,F,Out> HasOutput for AllWith5Data<T1,T2,T3,T4,T5,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>,&Output<T5>)->Out {
-    type Output = Out;
-}
+        impl<$($t,)*F,Out> $owned<$($t,)*F>
+        where $($t:EventOutput,)* Out:Data, F:'static+Fn($(&Output<$t>),*)->Out {
+            /// Constructor.
+            pub fn new(label:Label, $($arg:&$t,)* function:F) -> Self {
+                $(let $src = watch_stream($arg);)*
+                let def  = $data {$($src,)*function};
+                let this = Self::construct(label,def);
+                let weak = this.downgrade();
+                $($arg.register_target(weak.clone_ref().into());)*
+                this
+            }
+        }
 
-impl<T1,T2,T3,T4,T5,F,Out> OwnedAllWith5<T1,T2,T3,T4,T5,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>,&Output<T5>)->Out {
-    /// Constructor.
-    pub fn new(label:Label, t1:&T1, t2:&T2, t3:&T3, t4:&T4, t5:&T5, function:F) -> Self {
-        let src1 = watch_stream(t1);
-        let src2 = watch_stream(t2);
-        let src3 = watch_stream(t3);
-        let src4 = watch_stream(t4);
-        let src5 = watch_stream(t5);
-        let def     = AllWith5Data {src1,src2,src3,src4,src5,function};
-        let this    = Self::construct(label,def);
-        let weak    = this.downgrade();
-        t1.register_target(weak.clone_ref().into());
-        t2.register_target(weak.clone_ref().into());
-        t3.register_target(weak.clone_ref().into());
-        t4.register_target(weak.clone_ref().into());
-        t5.register_target(weak.into());
-        this
-    }
-}
-
-impl<T1,T2,T3,T4,T5,F,Out,T> stream::EventConsumer<T> for OwnedAllWith5<T1,T2,T3,T4,T5,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>,&Output<T5>)->Out {
-    fn on_event(&self, stack:CallStack, _:&T) {
-        let value1 = self.src1.value();
-        let value2 = self.src2.value();
-        let value3 = self.src3.value();
-        let value4 = self.src4.value();
-        let value5 = self.src5.value();
-        let out    = (self.function)(&value1,&value2,&value3,&value4,&value5);
-        self.emit_event(stack,&out);
-    }
-}
+        impl<$($t,)*F,Out,T> stream::EventConsumer<T> for $owned<$($t,)*F>
+        where $($t:EventOutput,)* Out:Data, F:'static+Fn($(&Output<$t>),*)->Out {
+            fn on_event(&self, stack:CallStack, _:&T) {
+                $(let $value = self.$src.value();)*
+                let out = (self.function)($(&$value),*);
+                self.emit_event(stack,&out);
+            }
+        }
 
-impl<T1,T2,T3,T4,T5,F> Debug for AllWith5Data<T1,T2,T3,T4,T5,F> {
-    fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"AllWith5Data")
-    }
+        impl<$($t,)*F> Debug for $data<$($t,)*F> {
+            fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f,"{}",stringify!($data))
+            }
+        }
+    };
 }
 
-
-
-// ================
-// === AllWith6 ===
-// ================
-
-pub struct AllWith6Data <T1,T2,T3,T4,T5,T6,F>
-{ src1:watch::Ref<T1>, src2:watch::Ref<T2>, src3:watch::Ref<T3>, src4:watch::Ref<T4>
-    , src5:watch::Ref<T5>, src6:watch::Ref<T6>, function:F }
-pub type   OwnedAllWith6 <T1,T2,T3,T4,T5,T6,F> =
-    stream::Node     <AllWith6Data<T1,T2,T3,T4,T5,T6,F>>;
-pub type   AllWith6      <T1,T2,T3,T4,T5,T6,F> =
-    stream::WeakNode <AllWith6Data<T1,T2,T3,T4,T5,T6,F>>;
-
-impl<T1,T2,T3,T4,T5,T6,F,Out> HasOutput for AllWith6Data<T1,T2,T3,T4,T5,T6,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput,
-      T6:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>,&Output<T5>,&Output<T6>)->Out {
-    type Output = Out;
+define_all_with_node! { AllWith2Data OwnedAllWith2 AllWith2
+    [T1 src1 t1 value1] [T2 src2 t2 value2]
 }
 
-impl<T1,T2,T3,T4,T5,T6,F,Out> OwnedAllWith6<T1,T2,T3,T4,T5,T6,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput,
-      T6:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>,&Output<T5>,&Output<T6>)->Out {
-    /// Constructor.
-    pub fn new(label:Label, t1:&T1, t2:&T2, t3:&T3, t4:&T4, t5:&T5, t6:&T6, function:F) -> Self {
-        let src1 = watch_stream(t1);
-        let src2 = watch_stream(t2);
-        let src3 = watch_stream(t3);
-        let src4 = watch_stream(t4);
-        let src5 = watch_stream(t5);
-        let src6 = watch_stream(t6);
-        let def     = AllWith6Data {src1,src2,src3,src4,src5,src6,function};
-        let this    = Self::construct(label,def);
-        let weak    = this.downgrade();
-        t1.register_target(weak.clone_ref().into());
-        t2.register_target(weak.clone_ref().into());
-        t3.register_target(weak.clone_ref().into());
-        t4.register_target(weak.clone_ref().into());
-        t5.register_target(weak.clone_ref().into());
-        t6.register_target(weak.into());
-        this
-    }
-}
-
-impl<T1,T2,T3,T4,T5,T6,F,Out,T> stream::EventConsumer<T> for OwnedAllWith6<T1,T2,T3,T4,T5,T6,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput,
-      T6:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>,&Output<T5>,&Output<T6>)->Out {
-    fn on_event(&self, stack:CallStack, _:&T) {
-        let value1 = self.src1.value();
-        let value2 = self.src2.value();
-        let value3 = self.src3.value();
-        let value4 = self.src4.value();
-        let value5 = self.src5.value();
-        let value6 = self.src6.value();
-        let out    = (self.function)(&value1,&value2,&value3,&value4,&value5,&value6);
-        self.emit_event(stack,&out);
-    }
+define_all_with_node! { AllWith3Data OwnedAllWith3 AllWith3
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3]
 }
 
-impl<T1,T2,T3,T4,T5,T6,F> Debug for AllWith6Data<T1,T2,T3,T4,T5,T6,F> {
-    fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"AllWith6Data")
-    }
+define_all_with_node! { AllWith4Data OwnedAllWith4 AllWith4
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
 }
 
+define_all_with_node! { AllWith5Data OwnedAllWith5 AllWith5
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
+    [T5 src5 t5 value5]
+}
 
-
-// ================
-// === AllWith8 ===
-// ================
-
-pub struct AllWith8Data <T1,T2,T3,T4,T5,T6,T7,T8,F>
-{ src1:watch::Ref<T1>, src2:watch::Ref<T2>, src3:watch::Ref<T3>, src4:watch::Ref<T4>
-    , src5:watch::Ref<T5>, src6:watch::Ref<T6>, src7:watch::Ref<T7>, src8:watch::Ref<T8>
-    , function:F }
-pub type   OwnedAllWith8 <T1,T2,T3,T4,T5,T6,T7,T8,F> =
-    stream::Node     <AllWith8Data<T1,T2,T3,T4,T5,T6,T7,T8,F>>;
-pub type   AllWith8      <T1,T2,T3,T4,T5,T6,T7,T8,F> =
-    stream::WeakNode <AllWith8Data<T1,T2,T3,T4,T5,T6,T7,T8,F>>;
-
-impl<T1,T2,T3,T4,T5,T6,T7,T8,F,Out> HasOutput for AllWith8Data<T1,T2,T3,T4,T5,T6,T7,T8,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput,
-      T6:EventOutput, T7:EventOutput, T8:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>,&Output<T5>,&Output<T6>
-          ,&Output<T7>,&Output<T8>)->Out {
-    type Output = Out;
+define_all_with_node! { AllWith6Data OwnedAllWith6 AllWith6
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
+    [T5 src5 t5 value5] [T6 src6 t6 value6]
 }
 
-impl<T1,T2,T3,T4,T5,T6,T7,T8,F,Out> OwnedAllWith8<T1,T2,T3,T4,T5,T6,T7,T8,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput,
-      T6:EventOutput, T7:EventOutput, T8:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>,&Output<T5>,&Output<T6>
-          ,&Output<T7>,&Output<T8>)->Out {
-    /// Constructor.
-    pub fn new
-    (label:Label, t1:&T1, t2:&T2, t3:&T3, t4:&T4, t5:&T5, t6:&T6, t7:&T7, t8:&T8, function:F)
-    -> Self {
-        let src1 = watch_stream(t1);
-        let src2 = watch_stream(t2);
-        let src3 = watch_stream(t3);
-        let src4 = watch_stream(t4);
-        let src5 = watch_stream(t5);
-        let src6 = watch_stream(t6);
-        let src7 = watch_stream(t7);
-        let src8 = watch_stream(t8);
-        let def  = AllWith8Data {src1,src2,src3,src4,src5,src6,src7,src8,function};
-        let this = Self::construct(label,def);
-        let weak = this.downgrade();
-        t1.register_target(weak.clone_ref().into());
-        t2.register_target(weak.clone_ref().into());
-        t3.register_target(weak.clone_ref().into());
-        t4.register_target(weak.clone_ref().into());
-        t5.register_target(weak.clone_ref().into());
-        t6.register_target(weak.clone_ref().into());
-        t7.register_target(weak.clone_ref().into());
-        t8.register_target(weak.into());
-        this
-    }
-}
-
-impl<T1,T2,T3,T4,T5,T6,T7,T8,F,Out,T> stream::EventConsumer<T>
-for OwnedAllWith8<T1,T2,T3,T4,T5,T6,T7,T8,F>
-where T1:EventOutput, T2:EventOutput, T3:EventOutput, T4:EventOutput, T5:EventOutput,
-      T6:EventOutput, T7:EventOutput, T8:EventOutput, Out:Data,
-      F:'static+Fn(&Output<T1>,&Output<T2>,&Output<T3>,&Output<T4>,&Output<T5>,&Output<T6>
-          ,&Output<T7>,&Output<T8>)->Out {
-    fn on_event(&self, stack:CallStack, _:&T) {
-        let value1 = self.src1.value();
-        let value2 = self.src2.value();
-        let value3 = self.src3.value();
-        let value4 = self.src4.value();
-        let value5 = self.src5.value();
-        let value6 = self.src6.value();
-        let value7 = self.src7.value();
-        let value8 = self.src8.value();
-
-        let out = (self.function)(&value1,&value2,&value3,&value4,&value5,&value6,&value7,&value8);
-        self.emit_event(stack,&out);
-    }
+define_all_with_node! { AllWith7Data OwnedAllWith7 AllWith7
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
+    [T5 src5 t5 value5] [T6 src6 t6 value6] [T7 src7 t7 value7]
 }
 
-impl<T1,T2,T3,T4,T5,T6,T7,T8,F> Debug for AllWith8Data<T1,T2,T3,T4,T5,T6,T7,T8,F> {
-    fn fmt(&self, f:&mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"AllWith8Data")
-    }
+define_all_with_node! { AllWith8Data OwnedAllWith8 AllWith8
+    [T1 src1 t1 value1] [T2 src2 t2 value2] [T3 src3 t3 value3] [T4 src4 t4 value4]
+    [T5 src5 t5 value5] [T6 src6 t6 value6] [T7 src7 t7 value7] [T8 src8 t8 value8]
 }
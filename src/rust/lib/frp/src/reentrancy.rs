@@ -0,0 +1,93 @@
+//! Control over the re-entrant events and the order of their delivery.
+//!
+//! An event is re-entrant when a node emits it while its previous event is still propagating,
+//! which happens in recursive networks. By default such events are propagated immediately, up to
+//! the evaluations limit of the node (see the docs of `NodeData`). The [`ReentrancyPolicy`] allows
+//! choosing a stricter behavior per node or per network, so the unexpected loops do not go
+//! unnoticed. The [`defer`] function, and the `defer` node built on top of it, postpone the work
+//! until the whole current propagation finishes, keeping the order in which it was deferred.
+
+use crate::prelude::*;
+
+
+
+// ========================
+// === ReentrancyPolicy ===
+// ========================
+
+/// Describes what a node does with the events emitted while its previous event is propagating.
+#[derive(Clone,Copy,Debug,Eq,PartialEq)]
+pub enum ReentrancyPolicy {
+    /// Propagate the event immediately, nested in the propagation of the previous one.
+    Allow,
+    /// Drop the event silently.
+    Drop,
+    /// Queue the event and propagate it after the previous event finishes propagating. The queued
+    /// events are propagated in the order of their emission.
+    Queue,
+    /// Panic in debug builds, as the loop is considered a logic error. In release builds the event
+    /// is dropped.
+    PanicInDebug,
+}
+
+impl Default for ReentrancyPolicy {
+    fn default() -> Self {
+        Self::Allow
+    }
+}
+
+
+
+// ===================
+// === Propagation ===
+// ===================
+
+/// The state of the event propagation of the current thread.
+#[derive(Default)]
+struct Propagation {
+    depth    : Cell<usize>,
+    flushing : Cell<bool>,
+    deferred : RefCell<VecDeque<Box<dyn FnOnce()>>>,
+}
+
+thread_local! {
+    static PROPAGATION : Propagation = default();
+}
+
+/// Check whether any event is being propagated at the moment.
+pub fn is_propagating() -> bool {
+    PROPAGATION.with(|propagation| propagation.depth.get() > 0 || propagation.flushing.get())
+}
+
+/// Run the task after the current propagation finishes, or immediately if no event is being
+/// propagated. The deferred tasks are run in the order they were deferred. The events emitted by
+/// the tasks are propagated in full before the next task is run.
+pub fn defer(task:impl FnOnce() + 'static) {
+    if is_propagating() {
+        PROPAGATION.with(|propagation| propagation.deferred.borrow_mut().push_back(Box::new(task)))
+    } else {
+        task()
+    }
+}
+
+/// Mark the beginning of propagating an event.
+pub(crate) fn begin_propagation() {
+    PROPAGATION.with(|propagation| propagation.depth.set(propagation.depth.get() + 1))
+}
+
+/// Mark the end of propagating an event. Ending the outermost propagation runs the deferred tasks.
+pub(crate) fn end_propagation() {
+    let should_flush = PROPAGATION.with(|propagation| {
+        let depth = propagation.depth.get().saturating_sub(1);
+        propagation.depth.set(depth);
+        depth == 0 && !propagation.flushing.replace(true)
+    });
+    if should_flush {
+        while let Some(task) = next_deferred_task() { task() }
+        PROPAGATION.with(|propagation| propagation.flushing.set(false));
+    }
+}
+
+fn next_deferred_task() -> Option<Box<dyn FnOnce()>> {
+    PROPAGATION.with(|propagation| propagation.deferred.borrow_mut().pop_front())
+}
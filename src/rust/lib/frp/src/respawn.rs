@@ -0,0 +1,203 @@
+//! Respawnable sub-networks with stable endpoints.
+//!
+//! FRP networks are static – you are not allowed to remove nodes from a network after it was
+//! defined. Sometimes, however, a part of the logic has to be redefined at runtime, for example
+//! when a component changes its shape and its internal connections have to be rebuilt. Dropping
+//! the whole network and defining a new one would require every external listener to reconnect.
+//!
+//! `Respawnable` solves this problem by splitting the logic into two parts. The endpoints live in a
+//! stable network owned by the `Respawnable` and are never redefined, so external networks can
+//! freely connect to them. The logic between the endpoints lives in a sub-network which can be
+//! dropped and defined again with `Respawnable::respawn` as many times as needed:
+//!
+//! ```compile_fail
+//! let respawnable = Respawnable::new("node_bridge");
+//! let input       = respawnable.endpoint::<usize>("input");
+//! let output      = respawnable.endpoint::<usize>("output");
+//! respawnable.respawn(|network| {
+//!     frp::extend! { network
+//!         output <+ input.map(|t| t * 2);
+//!     }
+//! });
+//! ```
+
+use crate::prelude::*;
+
+use crate::data::watch;
+use crate::network::*;
+use crate::node::*;
+use crate::nodes::*;
+use crate::stream::CallStack;
+use crate::stream::EventEmitter;
+use crate::stream::EventInput;
+use crate::stream::EventOutput;
+use crate::stream::ValueProvider;
+
+
+
+// ===================
+// === Respawnable ===
+// ===================
+
+/// Owner of a stable set of endpoints and a sub-network connecting them, which can be redefined at
+/// runtime. See the module docs to learn more.
+#[derive(Clone,CloneRef,Debug)]
+pub struct Respawnable {
+    label    : Rc<String>,
+    network  : Network,
+    spawned  : Rc<RefCell<Option<Network>>>,
+    building : Rc<RefCell<Option<Network>>>,
+}
+
+impl Respawnable {
+    /// Constructor.
+    pub fn new(label:impl Into<String>) -> Self {
+        let label    = Rc::new(label.into());
+        let network  = Network::new(label.as_str());
+        let spawned  = default();
+        let building = default();
+        Self {label,network,spawned,building}
+    }
+
+    /// The stable network owning the endpoints.
+    pub fn network(&self) -> &Network {
+        &self.network
+    }
+
+    /// Create a new endpoint. The endpoint lives as long as this `Respawnable`, regardless of how
+    /// many times the sub-network is respawned.
+    pub fn endpoint<T:Data>(&self, label:Label) -> Endpoint<T> {
+        let node     = self.network.any_mut(label);
+        let building = self.building.clone_ref();
+        Endpoint {node,building}
+    }
+
+    /// Drop the current sub-network and define a new one with the provided function. All the
+    /// streams attached to the endpoints while the function is running belong to the new
+    /// sub-network and will be detached by the next respawn.
+    pub fn respawn(&self, f:impl FnOnce(&Network)) {
+        self.spawned.replace(None);
+        let network = Network::new(format!("{}::spawned",self.label));
+        *self.building.borrow_mut() = Some(network.clone_ref());
+        f(&network);
+        *self.building.borrow_mut() = None;
+        *self.spawned.borrow_mut()  = Some(network);
+    }
+
+    /// Drop the current sub-network, leaving the endpoints disconnected.
+    pub fn despawn(&self) {
+        self.spawned.replace(None);
+    }
+
+    /// Check whether a sub-network is currently defined.
+    pub fn is_spawned(&self) -> bool {
+        self.spawned.borrow().is_some()
+    }
+}
+
+
+
+// ================
+// === Endpoint ===
+// ================
+
+/// A stable FRP node of a `Respawnable`. It can be used just like any other FRP stream: external
+/// networks can listen to it, emit events to it, or attach their streams to it. Streams attached
+/// while the sub-network is being respawned are connected through the new sub-network, so they are
+/// detached as soon as it gets dropped. Streams attached at any other time stay attached forever.
+#[derive(CloneRef,Debug,Derivative)]
+#[derivative(Clone(bound=""))]
+pub struct Endpoint<T:Data> {
+    node     : Any<T>,
+    building : Rc<RefCell<Option<Network>>>,
+}
+
+impl<T:Data> Endpoint<T> {
+    /// Attach a new source stream to this endpoint. See the docs of `Endpoint` to learn how long
+    /// the connection lives.
+    pub fn attach<S>(&self, src:&S)
+    where S:EventOutput<Output=T> {
+        match &*self.building.borrow() {
+            None          => self.node.attach(src),
+            Some(network) => {
+                let node = self.node.clone_ref();
+                network.map("endpoint_forward",src,move |value| node.emit(value));
+            }
+        }
+    }
+
+    /// Emit new event.
+    pub fn emit<V:IntoParam<T>>(&self, value:V) {
+        self.node.emit(value)
+    }
+}
+
+impl<T:Data> HasOutput for Endpoint<T> {
+    type Output = T;
+}
+
+impl<T:Data> EventEmitter for Endpoint<T> {
+    fn emit_event (&self, stack:CallStack, value:&T)  {self.node.emit_event(stack,value)}
+    fn register_target (&self,tgt:EventInput<T>)       {self.node.register_target(tgt)}
+    fn register_watch  (&self) -> watch::Handle        {self.node.register_watch()}
+}
+
+impl<T:Data> ValueProvider for Endpoint<T> {
+    fn value(&self) -> T {
+        self.node.value()
+    }
+}
+
+impl<T:Data> HasId for Endpoint<T> {
+    fn id(&self) -> Id {
+        self.node.id()
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as frp;
+
+    #[test]
+    fn endpoints_survive_respawn() {
+        let respawnable = Respawnable::new("test");
+        let input       = respawnable.endpoint::<usize>("input");
+        let output      = respawnable.endpoint::<usize>("output");
+        frp::new_network! { network
+            source  <- source::<usize>();
+            sampler <- output.sampler();
+            count   <- output.count().sampler();
+        }
+        input.attach(&source);
+
+        respawnable.respawn(|network| {
+            frp::extend! { network
+                output <+ input.map(|t| t * 2);
+            }
+        });
+        source.emit(1);
+        assert_eq!(sampler.value(),2);
+
+        respawnable.respawn(|network| {
+            frp::extend! { network
+                output <+ input.map(|t| t * 10);
+            }
+        });
+        source.emit(2);
+        assert_eq!(sampler.value(),20);
+        assert_eq!(count.value(),2);
+
+        respawnable.despawn();
+        source.emit(3);
+        assert_eq!(sampler.value(),20);
+        assert_eq!(count.value(),2);
+        drop(network);
+    }
+}
@@ -1,9 +1,15 @@
 //! FRP event stream implementation.
 
 use crate::prelude::*;
+use crate::cache;
+use crate::cache::CachePolicy;
 use crate::network::*;
 use crate::node::*;
 use crate::data::watch;
+use crate::inspector;
+use crate::reentrancy;
+use crate::reentrancy::ReentrancyPolicy;
+use crate::trace;
 
 
 
@@ -88,6 +94,25 @@ pub trait HasOutputTypeLabel {
 
 
 
+// ===========================
+// === HasReentrancyPolicy ===
+// ===========================
+
+/// FRP nodes whose policy of handling the re-entrant events can be set without knowing their
+/// output type. Used to set the policy of all the nodes of a network.
+pub trait HasReentrancyPolicy {
+    /// Set the policy of handling the re-entrant events. See the `reentrancy` module docs.
+    fn set_reentrancy_policy(&self, policy:ReentrancyPolicy);
+}
+
+impl<Def:HasOutputStatic> HasReentrancyPolicy for Node<Def> {
+    fn set_reentrancy_policy(&self, policy:ReentrancyPolicy) {
+        self.stream.set_reentrancy_policy(policy)
+    }
+}
+
+
+
 // ======================
 // === InputBehaviors ===
 // ======================
@@ -210,7 +235,10 @@ const EVALUATIONS_LIMIT:usize = 100;
 /// event targets (the `targets` field), but are watching this node and can ask it for the last
 /// value any time. If the number of such nodes is zero, the value propagated trough this node does
 /// not need to be cached, and it will not be cloned. This minimizes the amount of clones in FRP
-/// networks drastically.
+/// networks drastically. The `cache_policy` field allows caching the values regardless of the
+/// watchers, see the `cache` module docs. The `reentrancy_policy` field describes what happens with
+/// the events emitted while the previous one is still propagating, see the `reentrancy` module
+/// docs. The events queued by the `ReentrancyPolicy::Queue` are kept in the `queued` field.
 #[derive(Debug)]
 pub struct NodeData<Out=()> {
     /// Please be very careful when working with this field. When an event is emitted, this field
@@ -220,6 +248,9 @@ pub struct NodeData<Out=()> {
     targets             : RefCell<Vec<EventInput<Out>>>,
     new_targets         : RefCell<Vec<EventInput<Out>>>,
     value_cache         : RefCell<Out>,
+    cache_policy        : Cell<CachePolicy>,
+    reentrancy_policy   : Cell<ReentrancyPolicy>,
+    queued              : RefCell<VecDeque<Out>>,
     ongoing_evaluations : Cell<usize>,
     watch_counter       : watch::Counter,
     label               : Label,
@@ -228,16 +259,39 @@ pub struct NodeData<Out=()> {
 impl<Out:Default> NodeData<Out> {
     /// Constructor.
     pub fn new(label:Label) -> Self {
-        let targets       = default();
-        let new_targets   = default();
-        let value_cache   = default();
-        let evaluations   = default();
-        let watch_counter = default();
-        Self {targets,new_targets,value_cache, ongoing_evaluations: evaluations,watch_counter,label}
+        let targets             = default();
+        let new_targets         = default();
+        let value_cache         = default();
+        let cache_policy        = default();
+        let reentrancy_policy   = default();
+        let queued              = default();
+        let ongoing_evaluations = default();
+        let watch_counter       = default();
+        Self {targets,new_targets,value_cache,cache_policy,reentrancy_policy,queued
+             ,ongoing_evaluations,watch_counter,label}
+    }
+}
+
+impl<Out> NodeData<Out> {
+    /// Set the policy of caching the emitted values.
+    pub fn set_cache_policy(&self, policy:CachePolicy) {
+        self.cache_policy.set(policy)
+    }
+
+    /// Set the policy of handling the re-entrant events.
+    pub fn set_reentrancy_policy(&self, policy:ReentrancyPolicy) {
+        self.reentrancy_policy.set(policy)
     }
 
     fn use_caching(&self) -> bool {
-        !self.watch_counter.is_zero()
+        self.cache_policy.get() == CachePolicy::Always || !self.watch_counter.is_zero()
+    }
+}
+
+impl<Out> HasId for NodeData<Out> {
+    fn id(&self) -> Id {
+        let raw = self as *const Self as *const() as usize;
+        raw.into()
     }
 }
 
@@ -245,9 +299,42 @@ impl<Out:Data> HasOutput for NodeData<Out> {
     type Output = Out;
 }
 
+impl<Out:Data> NodeData<Out> {
+    /// Check whether the event should be propagated now, handling it according to the reentrancy
+    /// policy otherwise.
+    fn accept_event(&self, value:&Out) -> bool {
+        if self.ongoing_evaluations.get() == 0 { return true }
+        match self.reentrancy_policy.get() {
+            ReentrancyPolicy::Allow        => true,
+            ReentrancyPolicy::Drop         => false,
+            ReentrancyPolicy::Queue        => {
+                self.queued.borrow_mut().push_back(value.clone());
+                false
+            }
+            ReentrancyPolicy::PanicInDebug => {
+                debug_assert!(false,"Re-entrant event emitted by the FRP node '{}'.",self.label);
+                false
+            }
+        }
+    }
+
+    /// Propagate the events queued during the propagation of the previous ones.
+    fn emit_queued_events(&self, stack:CallStack) {
+        if self.ongoing_evaluations.get() > 0 { return }
+        loop {
+            let value = self.queued.borrow_mut().pop_front();
+            match value {
+                Some(value) => self.emit_event(stack,&value),
+                None        => break,
+            }
+        }
+    }
+}
+
 impl<Out:Data> EventEmitter for NodeData<Out> {
     fn emit_event(&self, stack:CallStack, value:&Out) {
         let new_stack = stack.sub(self.label);
+        if !self.accept_event(value) { return }
         if self.ongoing_evaluations.get() > EVALUATIONS_LIMIT {
             let logger : Logger = Logger::new("frp");
             warning!(logger,"The recursive evaluations limit exceeded.", || {
@@ -256,7 +343,11 @@ impl<Out:Data> EventEmitter for NodeData<Out> {
             WARNING!("{backtrace()}")
         } else {
             self.ongoing_evaluations.set(self.ongoing_evaluations.get() + 1);
+            reentrancy::begin_propagation();
+            inspector::record_event(self.id(),value);
+            let _span = trace::EventSpan::begin(self.id(),self.label);
             if self.use_caching() {
+                cache::record_clone(self.label);
                 *self.value_cache.borrow_mut() = value.clone();
             }
             if let Ok(mut targets) = self.targets.try_borrow_mut() {
@@ -272,7 +363,10 @@ impl<Out:Data> EventEmitter for NodeData<Out> {
                     targets.extend(mem::take(new_targets_ref));
                 }
             }
+            drop(new_targets);
             self.ongoing_evaluations.set(self.ongoing_evaluations.get() - 1);
+            self.emit_queued_events(stack);
+            reentrancy::end_propagation();
         }
     }
 
@@ -402,6 +496,16 @@ impl<Def:HasOutputStatic> Node<Def> {
         let definition = Rc::downgrade(&self.definition);
         WeakNode {stream,definition}
     }
+
+    /// Keep the latest emitted value cached. See the docs of `OwnedStream::cache_latest`.
+    pub fn cache_latest(&self, enabled:bool) {
+        self.stream.cache_latest(enabled)
+    }
+
+    /// Set the policy of handling the re-entrant events. See the `reentrancy` module docs.
+    pub fn set_reentrancy_policy(&self, policy:ReentrancyPolicy) {
+        self.stream.set_reentrancy_policy(policy)
+    }
 }
 
 impl<T:HasOutputStatic> WeakNode<T> {
@@ -413,6 +517,16 @@ impl<T:HasOutputStatic> WeakNode<T> {
             })
         })
     }
+
+    /// Keep the latest emitted value cached. See the docs of `OwnedStream::cache_latest`.
+    pub fn cache_latest(&self, enabled:bool) {
+        self.stream.cache_latest(enabled)
+    }
+
+    /// Set the policy of handling the re-entrant events. See the `reentrancy` module docs.
+    pub fn set_reentrancy_policy(&self, policy:ReentrancyPolicy) {
+        self.stream.set_reentrancy_policy(policy)
+    }
 }
 
 impl<Out> OwnedStream<Out> {
@@ -420,6 +534,19 @@ impl<Out> OwnedStream<Out> {
     pub fn downgrade(&self) -> Stream<Out> {
         Stream {data:Rc::downgrade(&self.data)}
     }
+
+    /// Keep the latest emitted value cached even if the stream is not watched, so it can be read
+    /// with `value` at any time. Disabling it restores the default policy of caching the values
+    /// only while the stream is watched.
+    pub fn cache_latest(&self, enabled:bool) {
+        let policy = if enabled { CachePolicy::Always } else { CachePolicy::Watched };
+        self.data.set_cache_policy(policy)
+    }
+
+    /// Set the policy of handling the re-entrant events. See the `reentrancy` module docs.
+    pub fn set_reentrancy_policy(&self, policy:ReentrancyPolicy) {
+        self.data.set_reentrancy_policy(policy)
+    }
 }
 
 impl<Out> Stream<Out> {
@@ -427,6 +554,16 @@ impl<Out> Stream<Out> {
     pub fn upgrade(&self) -> Option<OwnedStream<Out>> {
         self.data.upgrade().map(|data| OwnedStream {data})
     }
+
+    /// Keep the latest emitted value cached. See the docs of `OwnedStream::cache_latest`.
+    pub fn cache_latest(&self, enabled:bool) {
+        if let Some(stream) = self.upgrade() { stream.cache_latest(enabled) }
+    }
+
+    /// Set the policy of handling the re-entrant events. See the `reentrancy` module docs.
+    pub fn set_reentrancy_policy(&self, policy:ReentrancyPolicy) {
+        if let Some(stream) = self.upgrade() { stream.set_reentrancy_policy(policy) }
+    }
 }
 
 impl<Def> From<WeakNode<Def>> for Stream<Def::Output>
@@ -0,0 +1,272 @@
+//! Recording the timings of the FRP events and exporting the networks for the external tools.
+//!
+//! While the recording is active, every event emitted by a node is remembered together with its
+//! start time and the time it took to propagate it through all the dependent nodes. The recorded
+//! events can be exported to the Chrome tracing JSON format and opened in `chrome://tracing` (or
+//! https://ui.perfetto.dev), where the nested propagation of an FRP cascade is displayed as
+//! a flame graph. The topology of the networks can be exported to a plain JSON as well.
+//!
+//! Like the `inspector`, the recording is available in debug builds only. In release builds the
+//! hooks are no-ops.
+
+use crate::prelude::*;
+
+use crate::inspector;
+use crate::network::*;
+use crate::node::*;
+
+use serde_json::json;
+use serde_json::Value;
+
+
+
+// =================
+// === Constants ===
+// =================
+
+/// The maximum number of events remembered by a single recording. The events emitted after the
+/// limit was reached are not recorded.
+pub const MAX_RECORDED_EVENTS : usize = 100_000;
+
+
+
+// ===================
+// === EventTiming ===
+// ===================
+
+/// A single recorded event.
+#[derive(Clone,Debug)]
+pub struct EventTiming {
+    /// The node emitting the event.
+    pub node     : Id,
+    /// The label of the node emitting the event.
+    pub label    : Label,
+    /// The time the event was emitted, in milliseconds from the program start.
+    pub start    : f64,
+    /// The time of propagating the event through all the dependent nodes, in milliseconds.
+    pub duration : f64,
+    /// The number of events being propagated when this event was emitted. The events emitted
+    /// directly by the user have the depth of zero.
+    pub depth    : usize,
+}
+
+
+
+// =================
+// === Recording ===
+// =================
+
+/// Events recorded between the `start_recording` and `stop_recording` calls, ordered by their
+/// start time.
+#[derive(Clone,Debug,Default)]
+pub struct Recording {
+    /// The recorded events.
+    pub events : Vec<EventTiming>,
+}
+
+#[derive(Debug,Default)]
+struct RecordingData {
+    events : Vec<EventTiming>,
+    depth  : usize,
+}
+
+thread_local! {
+    static RECORDING : RefCell<Option<RecordingData>> = RefCell::new(None);
+}
+
+/// Start recording the events, dropping the events of the unfinished recording, if any.
+pub fn start_recording() {
+    if cfg!(debug_assertions) {
+        RECORDING.with(|recording| *recording.borrow_mut() = Some(default()))
+    }
+}
+
+/// Stop recording the events and return the recorded ones.
+pub fn stop_recording() -> Recording {
+    let data       = RECORDING.with(|recording| recording.borrow_mut().take());
+    let mut events = data.unwrap_or_default().events;
+    events.sort_by(|a,b| {
+        let start = a.start.partial_cmp(&b.start).unwrap_or(std::cmp::Ordering::Equal);
+        start.then(a.depth.cmp(&b.depth))
+    });
+    Recording {events}
+}
+
+/// Check whether the events are being recorded.
+pub fn is_recording() -> bool {
+    RECORDING.with(|recording| recording.borrow().is_some())
+}
+
+
+// === EventSpan ===
+
+/// Measures the propagation of a single event. Created by the node emitting the event, and
+/// recorded when dropped, after the event was propagated.
+#[derive(Debug)]
+pub struct EventSpan {
+    started : Option<(Id,Label,f64,usize)>,
+}
+
+impl EventSpan {
+    /// Start measuring the event emitted by the node.
+    pub fn begin(node:Id, label:Label) -> Self {
+        let started = if cfg!(debug_assertions) {
+            RECORDING.with(|recording| {
+                recording.borrow_mut().as_mut().map(|data| {
+                    let depth = data.depth;
+                    data.depth += 1;
+                    (node,label,crate::web::time_from_start(),depth)
+                })
+            })
+        } else { None };
+        Self {started}
+    }
+}
+
+impl Drop for EventSpan {
+    fn drop(&mut self) {
+        if let Some((node,label,start,depth)) = self.started.take() {
+            let duration = crate::web::time_from_start() - start;
+            RECORDING.with(|recording| {
+                if let Some(data) = recording.borrow_mut().as_mut() {
+                    data.depth = data.depth.saturating_sub(1);
+                    if data.events.len() < MAX_RECORDED_EVENTS {
+                        data.events.push(EventTiming {node,label,start,duration,depth});
+                    }
+                }
+            })
+        }
+    }
+}
+
+
+
+// ==============
+// === Export ===
+// ==============
+
+/// The indexes of the networks owning the nodes, by the node ids.
+fn node_networks(networks:&[Network]) -> HashMap<Id,usize> {
+    let mut map = HashMap::new();
+    for (index,network) in networks.iter().enumerate() {
+        for node in network.topology() {
+            map.insert(node.id,index);
+        }
+    }
+    map
+}
+
+/// The topology of the networks as a JSON value. Every network is described by its label and
+/// the list of its nodes, with their ids, labels, output types, and ids of their input nodes.
+pub fn topology_json_value(networks:&[Network]) -> Value {
+    let networks = networks.iter().map(|network| {
+        let nodes = network.topology().into_iter().map(|node| {
+            let id     : usize      = node.id.into();
+            let inputs : Vec<usize> = node.inputs.into_iter().map(|t| t.into()).collect();
+            json!({"id":id,"label":node.label,"type":node.output_type,"inputs":inputs})
+        }).collect_vec();
+        json!({"id":usize::from(network.id()),"label":network.label(),"nodes":nodes})
+    }).collect_vec();
+    json!({"networks":networks})
+}
+
+/// The topology of the networks as a JSON string. See `topology_json_value`.
+pub fn topology_json(networks:&[Network]) -> String {
+    topology_json_value(networks).to_string()
+}
+
+/// The topology of all the living networks as a JSON string. The networks are available in debug
+/// builds only, see the `inspector` module.
+pub fn all_networks_topology_json() -> String {
+    topology_json(&inspector::networks())
+}
+
+impl Recording {
+    /// The recorded events in the Chrome tracing format, as a JSON value. Every event is exported
+    /// as a complete event, and the networks are exported as processes, so the events of each
+    /// network are displayed in a separate group. The events of nodes not belonging to any of the
+    /// given networks are grouped together at the end.
+    pub fn chrome_trace_value(&self, networks:&[Network]) -> Value {
+        let node_networks    = node_networks(networks);
+        let unknown_pid      = networks.len();
+        let mut trace_events = networks.iter().enumerate().map(|(pid,network)| {
+            json!({"name":"process_name","ph":"M","pid":pid,"args":{"name":network.label()}})
+        }).collect_vec();
+        trace_events.push(json!({
+            "name":"process_name","ph":"M","pid":unknown_pid,"args":{"name":"Unknown network"}
+        }));
+        trace_events.extend(self.events.iter().map(|event| {
+            let pid   = node_networks.get(&event.node).copied().unwrap_or(unknown_pid);
+            let node  = usize::from(event.node);
+            let start = event.start * 1000.0;
+            let dur   = event.duration * 1000.0;
+            json!({
+                "name" : event.label,
+                "cat"  : "frp",
+                "ph"   : "X",
+                "ts"   : start,
+                "dur"  : dur,
+                "pid"  : pid,
+                "tid"  : 0,
+                "args" : {"node":node,"depth":event.depth}
+            })
+        }));
+        json!({"traceEvents":trace_events,"displayTimeUnit":"ms"})
+    }
+
+    /// The recorded events in the Chrome tracing format, as a JSON string. See
+    /// `chrome_trace_value`.
+    pub fn to_chrome_trace(&self, networks:&[Network]) -> String {
+        self.chrome_trace_value(networks).to_string()
+    }
+
+    /// The recorded events in the Chrome tracing format, with the events grouped by all the
+    /// living networks.
+    pub fn to_chrome_trace_of_all_networks(&self) -> String {
+        self.to_chrome_trace(&inspector::networks())
+    }
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(all(test,debug_assertions))]
+mod tests {
+    use super::*;
+    use crate as frp;
+
+    #[test]
+    fn recording_nested_events() {
+        frp::new_network! { network
+            source <- source::<usize>();
+            double <- source.map(|t| t * 2);
+        }
+        source.emit(1);
+        assert!(!is_recording());
+        start_recording();
+        source.emit(2);
+        let recording = stop_recording();
+        assert!(!is_recording());
+
+        assert_eq!(recording.events.len(),2);
+        assert_eq!(recording.events[0].node,source.id());
+        assert_eq!(recording.events[1].node,double.id());
+        assert_eq!(recording.events[0].depth,0);
+        assert_eq!(recording.events[1].depth,1);
+        assert!(recording.events[0].duration >= recording.events[1].duration);
+
+        let trace  = recording.chrome_trace_value(&[network.clone_ref()]);
+        let events = trace["traceEvents"].as_array().unwrap();
+        let spans  = events.iter().filter(|event| event["ph"] == "X").collect_vec();
+        assert_eq!(spans.len(),2);
+        assert!(spans.iter().all(|event| event["pid"] == 0));
+        assert_eq!(events[0]["args"]["name"],network.label());
+
+        let topology = topology_json_value(&[network.clone_ref()]);
+        let nodes    = topology["networks"][0]["nodes"].as_array().unwrap();
+        assert_eq!(nodes.len(),2);
+    }
+}
@@ -7,7 +7,11 @@
 //!
 //! The metrics used for scoring may be adjusted by implementing `Metric` trait, or by customizing
 //! parameters of metrics defined in `metric` module.
+//!
+//! The `search` module builds on the above, ranking whole lists of items, and the found
+//! subsequences provide the highlight spans, so the views can mark the matched characters.
 #![feature(option_result_contains)]
+#![feature(test)]
 
 #![warn(missing_docs)]
 #![warn(trivial_casts)]
@@ -18,9 +22,12 @@
 #![warn(missing_copy_implementations)]
 #![warn(missing_debug_implementations)]
 
+extern crate test;
+
 pub mod subsequence_graph;
 pub mod metric;
 pub mod score;
+pub mod search;
 
 pub use enso_prelude as prelude;
 pub use metric::Metric;
@@ -28,3 +35,6 @@ pub use subsequence_graph::Graph as SubsequenceGraph;
 pub use score::Subsequence;
 pub use score::matches;
 pub use score::find_best_subsequence;
+pub use search::Match;
+pub use search::search;
+pub use search::search_by;
@@ -7,6 +7,7 @@ use crate::subsequence_graph;
 use crate::SubsequenceGraph;
 
 use std::collections::hash_map::Entry;
+use std::ops::Range;
 
 
 
@@ -172,6 +173,27 @@ impl Subsequence {
         else if self.score > rhs.score                    { std::cmp::Ordering::Greater }
         else                                              { std::cmp::Ordering::Equal   }
     }
+
+    /// The byte ranges of the `text` characters which belong to the subsequence, with the adjacent
+    /// characters merged into a single range. The `text` must be the one the subsequence was found
+    /// in. The views use the ranges to highlight the matched characters.
+    pub fn highlight_spans(&self, text:&str) -> Vec<Range<usize>> {
+        let mut indices = self.indices.iter().copied().peekable();
+        let mut spans   = Vec::<Range<usize>>::new();
+        for (char_index,(byte_index,char)) in text.char_indices().enumerate() {
+            match indices.peek() {
+                None                                => break,
+                Some(index) if *index != char_index => continue,
+                Some(_)                             => { indices.next(); }
+            }
+            let end = byte_index + char.len_utf8();
+            match spans.last_mut() {
+                Some(span) if span.end == byte_index => span.end = end,
+                _                                    => spans.push(byte_index..end),
+            }
+        }
+        spans
+    }
 }
 
 /// Find best subsequence in `text` which case-insensitively equals to `pattern` in terms of given
@@ -296,6 +318,14 @@ mod test {
         assert_eq!(find_best_subsequence(text,pattern,mock_metric::Sum::default()), None);
     }
 
+    #[test]
+    fn highlight_spans() {
+        let subsequence = Subsequence {score:0.0, indices:vec![0,1,3,5]};
+        assert_eq!(subsequence.highlight_spans("abcdef"),vec![0..2,3..4,5..6]);
+        assert_eq!(subsequence.highlight_spans("ąbcdéf"),vec![0..3,4..5,6..8]);
+        assert_eq!(Subsequence::default().highlight_spans("abc"),vec![]);
+    }
+
     #[test]
     fn finding_best_subsequence_corner_cases() {
         let pattern = "";
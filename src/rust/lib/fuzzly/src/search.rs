@@ -0,0 +1,163 @@
+//! Filtering and ranking the lists of items by how well their labels match the given pattern. This
+//! is the engine behind the pickers displaying the best matches first, like the node searcher, the
+//! visualization chooser, or the command palette.
+
+use crate::prelude::*;
+
+use crate::metric::Metric;
+use crate::score;
+use crate::score::Subsequence;
+
+use std::ops::Range;
+
+
+
+// =============
+// === Match ===
+// =============
+
+/// An item matching the pattern, along with the best subsequence of its label.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Match<T> {
+    /// The matched item.
+    pub item        : T,
+    /// The best subsequence of the item label being the pattern.
+    pub subsequence : Subsequence,
+}
+
+impl<T:AsRef<str>> Match<T> {
+    /// The byte ranges of the item label to be highlighted. See
+    /// [`Subsequence::highlight_spans`].
+    pub fn highlight_spans(&self) -> Vec<Range<usize>> {
+        self.subsequence.highlight_spans(self.item.as_ref())
+    }
+}
+
+
+
+// ==============
+// === Search ===
+// ==============
+
+/// Match every item against the `pattern`, using the item itself as the label. See
+/// [`search_by`].
+pub fn search<T>
+(items:impl IntoIterator<Item=T>, pattern:&str, metric:impl Metric) -> Vec<Match<T>>
+where T:AsRef<str> {
+    search_by(items,pattern,metric,|item| item.as_ref())
+}
+
+/// Match the label of every item against the `pattern`, and return the matching items, the best
+/// matches first. The items with equal scores keep their original order. The empty pattern matches
+/// every item with the same score, so the whole list is returned unchanged.
+pub fn search_by<T>
+( items   : impl IntoIterator<Item=T>
+, pattern : &str
+, metric  : impl Metric
+, label   : impl Fn(&T) -> &str
+) -> Vec<Match<T>> {
+    let matches = items.into_iter().filter_map(|item| {
+        let text        = label(&item);
+        let subsequence = score::matches(text,pattern).and_option_from(|| {
+            score::find_best_subsequence(text,pattern,&metric)
+        })?;
+        Some(Match {item,subsequence})
+    });
+    let mut matches = matches.collect_vec();
+    matches.sort_by(|lhs,rhs| lhs.subsequence.compare_scores(&rhs.subsequence).reverse());
+    matches
+}
+
+
+
+// =============
+// === Tests ===
+// =============
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::metric;
+
+    #[test]
+    fn best_matches_go_first() {
+        let items  = vec!["format_text","to_text","map","text"];
+        let found  = search(items,"text",metric::default());
+        let labels  = found.iter().map(|m| m.item).collect_vec();
+        assert_eq!(labels,vec!["text","to_text","format_text"]);
+        assert_eq!(found[1].highlight_spans(),vec![3..7]);
+    }
+
+    #[test]
+    fn empty_pattern_keeps_order() {
+        let items = vec!["b","a","c"];
+        let found = search(items.clone(),"",metric::default());
+        assert_eq!(found.into_iter().map(|m| m.item).collect_vec(),items);
+    }
+
+    #[test]
+    fn searching_by_label() {
+        let items = vec![(1,"Scatter Plot"),(2,"Table"),(3,"Histogram")];
+        let found = search_by(items,"tab",metric::default(),|(_,label)| *label);
+        assert_eq!(found.into_iter().map(|m| m.item.0).collect_vec(),vec![2]);
+    }
+}
+
+
+
+// ==================
+// === Benchmarks ===
+// ==================
+
+#[cfg(test)]
+mod benchmarks {
+    use super::*;
+
+    use crate::metric;
+
+    use test::Bencher;
+
+    const ITEMS_COUNT : usize = 1000;
+
+    fn items() -> Vec<String> {
+        let words = ["text","map","filter","table","column","read","write","sort","plot","join"];
+        (0..ITEMS_COUNT).map(|i| {
+            let first  = words[i % words.len()];
+            let second = words[(i / words.len()) % words.len()];
+            format!("{}_{}_{}",first,second,i)
+        }).collect()
+    }
+
+    fn search_bench(pattern:&str, bencher:&mut Bencher) {
+        let items = items();
+        bencher.iter(|| search(items.iter(),pattern,metric::default()));
+    }
+
+    #[bench]
+    fn search_short_pattern(bencher:&mut Bencher) {
+        search_bench("tm",bencher);
+    }
+
+    #[bench]
+    fn search_long_pattern(bencher:&mut Bencher) {
+        search_bench("filtertable",bencher);
+    }
+
+    #[bench]
+    fn search_not_matching_pattern(bencher:&mut Bencher) {
+        search_bench("xyz",bencher);
+    }
+
+    #[bench]
+    fn best_subsequence_in_long_text(bencher:&mut Bencher) {
+        let text = items().join(" ");
+        bencher.iter(|| score::find_best_subsequence(&text[..2000],"mapcolumn",metric::default()));
+    }
+
+    #[bench]
+    fn highlight_spans(bencher:&mut Bencher) {
+        let found = search(items(),"sort",metric::default());
+        bencher.iter(|| found.iter().map(|m| m.highlight_spans()).collect_vec());
+    }
+}
@@ -73,6 +73,28 @@ lazy_static! {
 
 const DOUBLE_EVENT_TIME_MS : f32 = 300.0;
 
+/// Separator of the steps of a chord sequence, like "ctrl k, ctrl c".
+const CHORD_SEPARATOR : char = ',';
+
+/// The maximum time between the steps of a chord sequence. If the next step is not pressed in this
+/// time, the pending chord is cancelled.
+pub const CHORD_TIMEOUT_MS : f32 = 2000.0;
+
+/// Checks whether the key is a side key, like "ctrl" or "shift-left".
+fn is_side_key(key:&str) -> bool {
+    let base = key.strip_suffix("-left").or_else(|| key.strip_suffix("-right")).unwrap_or(key);
+    SIDE_KEYS_SET.contains(&base)
+}
+
+/// Human readable representation of a step of a chord sequence. The key sides are skipped, as
+/// chords are defined without them.
+fn chord_step_label(expr:&str) -> String {
+    expr.split(' ').map(|key| {
+        let base = key.strip_suffix("-left").or_else(|| key.strip_suffix("-right"));
+        base.filter(|base| SIDE_KEYS_SET.contains(base)).unwrap_or(key)
+    }).join(" ")
+}
+
 
 
 // ==================
@@ -114,6 +136,14 @@ pub trait Registry<T> : Default {
     /// Some engines might implement a separate optimization stage. This is intended to force the
     /// optimization at a given point in time. Used mainly in benchmarks.
     fn optimize (&self) {}
+
+    /// The already pressed steps of a chord sequence waiting for its next step, like "ctrl k".
+    /// Engines not supporting chord sequences never have a pending chord.
+    fn pending_chord (&self) -> Option<String> { None }
+
+    /// Cancel the pending chord if the next step was not pressed in `CHORD_TIMEOUT_MS`. Returns
+    /// true if the chord was cancelled.
+    fn cancel_expired_chord (&self) -> bool { false }
 }
 
 
@@ -457,26 +487,37 @@ pub trait HashSetRegistryItem = Clone+Debug+Eq+Hash;
 /// Internal model for `HashSetRegistry`.
 #[derive(Debug)]
 pub struct HashSetRegistryModel<T> {
-    current_expr  : String,
-    actions       : HashMap<ActionType,HashMap<String,Vec<T>>>,
-    pressed       : HashSet<String>,
-    press_times   : HashMap<String,f32>,
-    release_times : HashMap<String,f32>,
-    side_keys     : HashMap<String,Vec<String>>,
-    key_aliases   : HashMap<String,String>,
+    current_expr       : String,
+    actions            : HashMap<ActionType,HashMap<String,Vec<T>>>,
+    pressed            : HashSet<String>,
+    press_times        : HashMap<String,f32>,
+    release_times      : HashMap<String,f32>,
+    side_keys          : HashMap<String,Vec<String>>,
+    key_aliases        : HashMap<String,String>,
+    /// Actions of the chord sequences, indexed by all their steps separated by `CHORD_SEPARATOR`.
+    chord_actions      : HashMap<String,Vec<T>>,
+    /// All the proper prefixes of the registered chord sequences.
+    chord_prefixes     : HashSet<String>,
+    pending_chord      : Vec<String>,
+    pending_chord_time : f32,
 }
 
 impl<T> HashSetRegistryModel<T> {
     /// Constructor.
     pub fn new() -> Self {
-        let current_expr  = default();
-        let actions       = default();
-        let pressed       = default();
-        let press_times   = default();
-        let release_times = default();
-        let side_keys     = default();
-        let key_aliases   = key_aliases();
-        Self {current_expr,actions,pressed,press_times,release_times,side_keys,key_aliases} . init()
+        let current_expr       = default();
+        let actions            = default();
+        let pressed            = default();
+        let press_times        = default();
+        let release_times      = default();
+        let side_keys          = default();
+        let key_aliases        = key_aliases();
+        let chord_actions      = default();
+        let chord_prefixes     = default();
+        let pending_chord      = default();
+        let pending_chord_time = default();
+        Self {current_expr,actions,pressed,press_times,release_times,side_keys,key_aliases
+             ,chord_actions,chord_prefixes,pending_chord,pending_chord_time} . init()
     }
 
     fn init(mut self) -> Self {
@@ -493,15 +534,90 @@ impl<T> HashSetRegistryModel<T> {
 }
 
 impl<T:HashSetRegistryItem> HashSetRegistryModel<T> {
-    /// Add a new shortcut definition.
+    /// Add a new shortcut definition. If the input consists of several steps separated by commas,
+    /// like "ctrl k, ctrl c", it defines a chord sequence. Chord sequences are triggered on the
+    /// press of their last step, so they are registered only for the `Press` action type.
     pub fn add(&mut self, action_type:ActionType, input:impl AsRef<str>, action:impl Into<T>) {
         let input  = input.as_ref();
         let action = action.into();
-        let exprs  = self.possible_exprs(input);
-        let map    = self.actions.entry(action_type).or_default();
-        for expr in exprs {
-            map.entry(expr).or_default().push(action.clone());
+        let steps  = input.split(CHORD_SEPARATOR).collect_vec();
+        if steps.len() > 1 {
+            if action_type == Press {
+                self.add_chord(&steps,action);
+            }
+        } else {
+            let exprs = self.possible_exprs(input);
+            let map   = self.actions.entry(action_type).or_default();
+            for expr in exprs {
+                map.entry(expr).or_default().push(action.clone());
+            }
+        }
+    }
+
+    fn add_chord(&mut self, steps:&[&str], action:T) {
+        for prefix_length in 1..steps.len() {
+            let prefixes = self.possible_chord_exprs(&steps[..prefix_length]);
+            self.chord_prefixes.extend(prefixes);
+        }
+        for expr in self.possible_chord_exprs(steps) {
+            self.chord_actions.entry(expr).or_default().push(action.clone());
+        }
+    }
+
+    /// Return all possible expressions of the chord sequence, see `possible_exprs` to learn more.
+    fn possible_chord_exprs(&self, steps:&[&str]) -> Vec<String> {
+        steps.iter().fold(vec![],|prefixes,step| {
+            let exprs = self.possible_exprs(step);
+            if prefixes.is_empty() { exprs } else {
+                let sequences = prefixes.iter().cartesian_product(&exprs);
+                sequences.map(|(prefix,expr)| format!("{}{} {}",prefix,CHORD_SEPARATOR,expr))
+                    .collect()
+            }
+        })
+    }
+
+    /// Process the press event as a step of a chord sequence. Returns `None` if the event is not
+    /// a part of any chord sequence and should be processed as a regular shortcut. Pressing a key
+    /// not continuing the pending chord cancels it, and the key press is ignored.
+    fn process_chord_step(&mut self) -> Option<Vec<T>> {
+        self.cancel_expired_chord();
+        let is_modifier_only = self.pressed.iter().all(|key| is_side_key(key));
+        if is_modifier_only { return None }
+        let expr     = &self.current_expr;
+        let steps    = self.pending_chord.iter().chain(std::iter::once(expr));
+        let sequence = steps.join(&format!("{} ",CHORD_SEPARATOR));
+        if let Some(actions) = self.chord_actions.get(&sequence) {
+            self.pending_chord.clear();
+            Some(actions.clone())
+        } else if self.chord_prefixes.contains(&sequence) {
+            self.pending_chord.push(expr.clone());
+            self.pending_chord_time = web::time_from_start() as f32;
+            Some(default())
+        } else if !self.pending_chord.is_empty() {
+            self.pending_chord.clear();
+            Some(default())
+        } else {
+            None
+        }
+    }
+
+    /// The already pressed steps of the pending chord sequence.
+    pub fn pending_chord(&self) -> Option<String> {
+        if self.pending_chord.is_empty() { None } else {
+            let steps = self.pending_chord.iter().map(|step| chord_step_label(step));
+            Some(steps.join(&format!("{} ",CHORD_SEPARATOR)))
+        }
+    }
+
+    /// Cancel the pending chord if its timeout elapsed. Returns true if it was cancelled.
+    pub fn cancel_expired_chord(&mut self) -> bool {
+        let time    = web::time_from_start() as f32;
+        let expired = time - self.pending_chord_time > CHORD_TIMEOUT_MS;
+        let cancel  = expired && !self.pending_chord.is_empty();
+        if cancel {
+            self.pending_chord.clear();
         }
+        cancel
     }
 
     #[allow(clippy::collapsible_else_if)]
@@ -514,8 +630,12 @@ impl<T:HashSetRegistryItem> HashSetRegistryModel<T> {
             if press { self.pressed.insert(input); }
             else     { self.pressed.remove(&input); }
             self.current_expr = self.current_expr();
-            out.extended(self.process_event(Press))
-               .extended(self.process_event(PressAndRepeat))
+            let chord_actions = if press { self.process_chord_step() } else { None };
+            match chord_actions {
+                Some(chord_actions) => out.extended(chord_actions),
+                None => out.extended(self.process_event(Press))
+                           .extended(self.process_event(PressAndRepeat))
+            }
         } else {
             if press {
                 self.process_event(PressAndRepeat)
@@ -636,6 +756,10 @@ fn key_aliases() -> HashMap<String,String> {
 /// Shortcut registry implementation based on hash sets. The implementation is way less flexible
 /// than `AutomataRegistry`, but it is suitable for all of common use cases. In contrast to the
 /// `AutomataRegistry` implementation, this registry do not implement any optimization stage.
+///
+/// This registry supports emacs-style chord sequences, like "ctrl k, ctrl c", which are triggered
+/// by pressing "ctrl k" and then "ctrl c" in `CHORD_TIMEOUT_MS`. Pressing the first steps of a
+/// chord does not trigger the shortcuts registered for them.
 #[derive(CloneRef,Debug,Derivative)]
 #[derivative(Clone(bound=""))]
 #[derivative(Default(bound=""))]
@@ -662,6 +786,14 @@ impl<T:HashSetRegistryItem> Registry<T> for HashSetRegistry<T> {
     fn on_release(&self, input:impl AsRef<str>) -> Vec<T> {
         self.rc.borrow_mut().on_release(input)
     }
+
+    fn pending_chord(&self) -> Option<String> {
+        self.rc.borrow().pending_chord()
+    }
+
+    fn cancel_expired_chord(&self) -> bool {
+        self.rc.borrow_mut().cancel_expired_chord()
+    }
 }
 
 
@@ -818,6 +950,53 @@ mod tests {
     }
 
 
+    // === Chord Sequences ===
+
+    #[test] fn hash_set_registry_chords() { chords::<HashSetRegistry<i32>>(); }
+    fn chords<T:Registry<i32>>() -> T {
+        let nothing  = Vec::<i32>::new();
+        let registry = <T>::default();
+        registry.add(Press, "ctrl k, ctrl c", 0);
+        registry.add(Press, "ctrl k, x", 1);
+        registry.add(Press, "ctrl c", 2);
+        // Chord with modifiers in both steps.
+        assert_eq!(registry.on_press("ctrl-left"),nothing);
+        assert_eq!(registry.on_press("k"),nothing);
+        assert_eq!(registry.pending_chord(),Some("ctrl k".into()));
+        assert_eq!(registry.on_release("k"),nothing);
+        assert_eq!(registry.on_press("c"),vec![0]);
+        assert_eq!(registry.pending_chord(),None);
+        assert_eq!(registry.on_release("c"),nothing);
+        // Chord with a plain key in the last step.
+        assert_eq!(registry.on_press("k"),nothing);
+        assert_eq!(registry.on_release("k"),nothing);
+        assert_eq!(registry.on_release("ctrl-left"),nothing);
+        assert_eq!(registry.on_press("x"),vec![1]);
+        assert_eq!(registry.on_release("x"),nothing);
+        // Regular shortcut sharing the keys with a chord.
+        assert_eq!(registry.on_press("ctrl-right"),nothing);
+        assert_eq!(registry.on_press("c"),vec![2]);
+        assert_eq!(registry.on_release("c"),nothing);
+        // Unknown step cancels the chord.
+        assert_eq!(registry.on_press("k"),nothing);
+        assert_eq!(registry.on_release("k"),nothing);
+        assert_eq!(registry.on_press("z"),nothing);
+        assert_eq!(registry.pending_chord(),None);
+        assert_eq!(registry.on_release("z"),nothing);
+        // Timeout cancels the chord.
+        assert_eq!(registry.on_press("k"),nothing);
+        assert_eq!(registry.on_release("k"),nothing);
+        assert!(!registry.cancel_expired_chord());
+        web::simulate_sleep(CHORD_TIMEOUT_MS as f64 + 100.0);
+        assert!(registry.cancel_expired_chord());
+        assert_eq!(registry.pending_chord(),None);
+        assert_eq!(registry.on_press("c"),vec![2]);
+        assert_eq!(registry.on_release("c"),nothing);
+        assert_eq!(registry.on_release("ctrl-right"),nothing);
+        registry
+    }
+
+
     // === Valid States ===
 
     #[test] fn automata_registry_valid_states() { valid_states::<AutomataRegistry<i32>>(true); }
@@ -328,6 +328,28 @@ pub fn cancel_animation_frame(id:i32) {
     window().cancel_animation_frame(id).unwrap();
 }
 
+/// Call the function once, after the given number of milliseconds. Returns the handle to be passed
+/// to `clear_timeout`.
+pub fn set_timeout(f:&Closure<dyn FnMut()>, ms:i32) -> i32 {
+    let f = f.as_ref().unchecked_ref();
+    window().set_timeout_with_callback_and_timeout_and_arguments_0(f,ms).unwrap()
+}
+
+pub fn clear_timeout(id:i32) {
+    window().clear_timeout_with_handle(id);
+}
+
+/// Call the function repeatedly, every given number of milliseconds. Returns the handle to be
+/// passed to `clear_interval`.
+pub fn set_interval(f:&Closure<dyn FnMut()>, ms:i32) -> i32 {
+    let f = f.as_ref().unchecked_ref();
+    window().set_interval_with_callback_and_timeout_and_arguments_0(f,ms).unwrap()
+}
+
+pub fn clear_interval(id:i32) {
+    window().clear_interval_with_handle(id);
+}
+
 
 
 // =====================
@@ -10,6 +10,12 @@ const PACKAGE_BLACKLIST:[&str;2] = [
     "ide/file-manager/mock-server"
 ];
 
+/// Features enabled when testing the given workspace members, e.g. to run the tests requiring
+/// them.
+const PACKAGE_FEATURES:[(&str,&str);1] = [
+    ("ide/view/graph-editor", "snapshots"),
+];
+
 /// Attributes that denote WASM tests.
 const WASM_TEST_ATTRIBUTES:[&str;2] = ["#[wasm_bindgen_test]", "#[wasm_bindgen_test(async)]"];
 
@@ -68,6 +74,11 @@ fn to_be_tested(member:&str) -> bool {
     has_wasm_tests(member) && !blacklisted(member) && !is_proc_macro_crate(member)
 }
 
+/// Features to be enabled when testing the given workspace member.
+fn features(member:&str) -> Option<&'static str> {
+    PACKAGE_FEATURES.iter().find(|(package,_)| *package == member).map(|(_,features)| *features)
+}
+
 /// Checks if given workspace member is a proc-macro crate.
 fn is_proc_macro_crate(member:&str) -> bool {
     let cargo_toml_path = PathBuf::from(member).join("Cargo.toml");
@@ -96,6 +107,9 @@ fn main() {
         command.arg("test")
             .args(&wasm_pack_args)
             .arg(&member);
+        if let Some(features) = features(member) {
+            command.args(&["--","--features",features]);
+        }
         println!("{:?}",command);
         let status = command.status().unwrap();
         if !status.success() {